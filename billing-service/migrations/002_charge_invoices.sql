@@ -0,0 +1,7 @@
+-- Charge Invoices
+-- Each charge records the invoicing-service invoice it was billed on, so a
+-- retried billing pass adds only the charges not yet on the invoice.
+
+ALTER TABLE charges ADD COLUMN invoice_id UUID;
+
+CREATE INDEX idx_charges_invoice ON charges(invoice_id) WHERE invoice_id IS NOT NULL;
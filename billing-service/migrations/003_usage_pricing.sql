@@ -0,0 +1,13 @@
+-- Usage Pricing Models
+-- Usage components price units beyond included_units per unit (the default),
+-- through graduated or volume tiers, or per package of package_size units.
+-- tiers is a JSON array of {up_to, unit_price, flat_fee}; the last tier has no up_to.
+-- minimum_amount and maximum_amount bound the component's charge per cycle.
+
+ALTER TABLE usage_components
+    ADD COLUMN pricing_model VARCHAR(20) NOT NULL DEFAULT 'per_unit'
+        CHECK (pricing_model IN ('per_unit', 'graduated', 'volume', 'package')),
+    ADD COLUMN tiers JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN package_size DECIMAL(19,4) CHECK (package_size > 0),
+    ADD COLUMN minimum_amount DECIMAL(19,4) CHECK (minimum_amount >= 0),
+    ADD COLUMN maximum_amount DECIMAL(19,4) CHECK (maximum_amount >= 0);
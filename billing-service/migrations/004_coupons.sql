@@ -0,0 +1,86 @@
+-- Coupons and Discounts
+-- A coupon defines a percent-off or amount-off discount and how many cycles it
+-- lasts. Promotion codes are customer-facing codes that redeem a coupon.
+-- Redeeming a coupon onto a subscription, or onto a single charge, creates a
+-- discount; billing runs turn active discounts into discount charges.
+
+-- coupons: Discount definitions
+CREATE TABLE IF NOT EXISTS coupons (
+    coupon_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    discount_type VARCHAR(20) NOT NULL CHECK (discount_type IN ('percent_off', 'amount_off')),
+    percent_off DECIMAL(7,4) CHECK (percent_off > 0 AND percent_off <= 100),
+    amount_off DECIMAL(19,4) CHECK (amount_off > 0),
+    currency VARCHAR(3),
+    duration VARCHAR(20) NOT NULL CHECK (duration IN ('once', 'repeating', 'forever')),
+    duration_cycles INTEGER CHECK (duration_cycles > 0),
+    max_redemptions INTEGER CHECK (max_redemptions > 0),
+    times_redeemed INTEGER NOT NULL DEFAULT 0,
+    expires_utc TIMESTAMPTZ,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    metadata JSONB,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((discount_type = 'percent_off') = (percent_off IS NOT NULL)),
+    CHECK ((discount_type = 'amount_off') = (amount_off IS NOT NULL AND currency IS NOT NULL)),
+    CHECK ((duration = 'repeating') = (duration_cycles IS NOT NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_coupons_tenant ON coupons(tenant_id);
+
+-- promotion_codes: Redeemable codes for a coupon
+CREATE TABLE IF NOT EXISTS promotion_codes (
+    promotion_code_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    coupon_id UUID NOT NULL REFERENCES coupons(coupon_id),
+    code VARCHAR(50) NOT NULL,
+    max_redemptions INTEGER CHECK (max_redemptions > 0),
+    times_redeemed INTEGER NOT NULL DEFAULT 0,
+    expires_utc TIMESTAMPTZ,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_promotion_codes_code UNIQUE (tenant_id, code)
+);
+
+CREATE INDEX IF NOT EXISTS idx_promotion_codes_coupon ON promotion_codes(coupon_id);
+
+-- discounts: Coupons redeemed onto a subscription or a single charge
+CREATE TABLE IF NOT EXISTS discounts (
+    discount_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    coupon_id UUID NOT NULL REFERENCES coupons(coupon_id),
+    promotion_code_id UUID REFERENCES promotion_codes(promotion_code_id),
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    charge_id UUID REFERENCES charges(charge_id) ON DELETE CASCADE,
+    cycles_applied INTEGER NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ended_utc TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_discounts_subscription ON discounts(subscription_id);
+CREATE INDEX IF NOT EXISTS idx_discounts_active ON discounts(subscription_id) WHERE is_active = TRUE;
+
+-- Discount charges record the discount that produced them
+ALTER TABLE charges DROP CONSTRAINT IF EXISTS charges_charge_type_check;
+ALTER TABLE charges ADD CONSTRAINT charges_charge_type_check
+    CHECK (charge_type IN ('recurring', 'usage', 'one_time', 'proration', 'discount'));
+ALTER TABLE charges ADD COLUMN discount_id UUID REFERENCES discounts(discount_id);
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_charges_cycle_discount ON charges(cycle_id, discount_id) WHERE discount_id IS NOT NULL;
+
+-- Trigger to update updated_utc on coupons
+CREATE OR REPLACE FUNCTION update_coupons_updated_utc()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_utc = NOW();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_coupons_updated ON coupons;
+CREATE TRIGGER trg_coupons_updated
+    BEFORE UPDATE ON coupons
+    FOR EACH ROW
+    EXECUTE FUNCTION update_coupons_updated_utc();
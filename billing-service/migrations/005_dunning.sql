@@ -0,0 +1,65 @@
+-- Dunning
+-- A cycle that fails to bill, or whose invoice goes unpaid past its due date,
+-- opens a dunning case and moves the subscription to past_due. The tenant's
+-- dunning policy decides when billing is retried, when reminders go out and
+-- how long the grace period lasts before the subscription is paused or
+-- cancelled.
+
+-- Subscriptions with an open dunning case are past due
+ALTER TABLE subscriptions DROP CONSTRAINT IF EXISTS subscriptions_status_check;
+ALTER TABLE subscriptions ADD CONSTRAINT subscriptions_status_check
+    CHECK (status IN ('trial', 'active', 'past_due', 'paused', 'cancelled', 'expired'));
+
+-- dunning_policies: One policy per tenant; tenants without one use the defaults
+CREATE TABLE IF NOT EXISTS dunning_policies (
+    tenant_id UUID PRIMARY KEY,
+    retry_days INTEGER[] NOT NULL DEFAULT '{1,3,5}',
+    reminder_days INTEGER[] NOT NULL DEFAULT '{0,3,6}',
+    grace_period_days INTEGER NOT NULL DEFAULT 7 CHECK (grace_period_days >= 0),
+    final_action VARCHAR(20) NOT NULL DEFAULT 'pause' CHECK (final_action IN ('none', 'pause', 'cancel')),
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- dunning_cases: Recovery of one failed or unpaid billing cycle
+CREATE TABLE IF NOT EXISTS dunning_cases (
+    case_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    cycle_id UUID NOT NULL REFERENCES billing_cycles(cycle_id) ON DELETE CASCADE,
+    reason VARCHAR(20) NOT NULL CHECK (reason IN ('billing_failed', 'invoice_unpaid')),
+    status VARCHAR(20) NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'recovered', 'resolved', 'exhausted')),
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    reminders_sent INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    resolution_note TEXT,
+    opened_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    closed_utc TIMESTAMPTZ,
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dunning_cases_tenant ON dunning_cases(tenant_id, status);
+CREATE INDEX IF NOT EXISTS idx_dunning_cases_subscription ON dunning_cases(subscription_id);
+CREATE INDEX IF NOT EXISTS idx_dunning_cases_cycle ON dunning_cases(cycle_id);
+CREATE UNIQUE INDEX IF NOT EXISTS uq_dunning_cases_open_cycle ON dunning_cases(cycle_id) WHERE status = 'open';
+
+-- Trigger to update updated_utc on dunning policies and cases
+CREATE OR REPLACE FUNCTION update_dunning_updated_utc()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_utc = NOW();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_dunning_policies_updated ON dunning_policies;
+CREATE TRIGGER trg_dunning_policies_updated
+    BEFORE UPDATE ON dunning_policies
+    FOR EACH ROW
+    EXECUTE FUNCTION update_dunning_updated_utc();
+
+DROP TRIGGER IF EXISTS trg_dunning_cases_updated ON dunning_cases;
+CREATE TRIGGER trg_dunning_cases_updated
+    BEFORE UPDATE ON dunning_cases
+    FOR EACH ROW
+    EXECUTE FUNCTION update_dunning_updated_utc();
@@ -0,0 +1,62 @@
+-- Customer Credits
+-- Prepaid credit is granted to a customer in one currency, optionally with an
+-- expiry. Billing runs consume available grants, lowest priority number first,
+-- before a cycle is invoiced. Every grant, consumption and expiry is recorded
+-- as a credit transaction against the grant's liability ledger account.
+
+-- credit_grants: Prepaid credit granted to a customer
+CREATE TABLE IF NOT EXISTS credit_grants (
+    grant_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    amount DECIMAL(19,4) NOT NULL CHECK (amount > 0),
+    remaining DECIMAL(19,4) NOT NULL CHECK (remaining >= 0 AND remaining <= amount),
+    priority INTEGER NOT NULL DEFAULT 0 CHECK (priority >= 0 AND priority <= 100),
+    description VARCHAR(255) NOT NULL,
+    ledger_account_id UUID,
+    expires_utc TIMESTAMPTZ,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_credit_grants_customer ON credit_grants(tenant_id, customer_id, currency);
+CREATE INDEX IF NOT EXISTS idx_credit_grants_expiring ON credit_grants(expires_utc) WHERE remaining > 0;
+
+-- credit_transactions: Movements of credit; grants are positive, consumption and expiry negative
+CREATE TABLE IF NOT EXISTS credit_transactions (
+    transaction_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    grant_id UUID NOT NULL REFERENCES credit_grants(grant_id) ON DELETE CASCADE,
+    transaction_type VARCHAR(20) NOT NULL CHECK (transaction_type IN ('grant', 'consume', 'expire')),
+    amount DECIMAL(19,4) NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    ledger_account_id UUID,
+    cycle_id UUID REFERENCES billing_cycles(cycle_id) ON DELETE SET NULL,
+    charge_id UUID REFERENCES charges(charge_id) ON DELETE SET NULL,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_credit_transactions_customer ON credit_transactions(tenant_id, customer_id, created_utc);
+CREATE INDEX IF NOT EXISTS idx_credit_transactions_grant ON credit_transactions(grant_id);
+
+-- Credit charges apply a grant's balance to a cycle
+ALTER TABLE charges DROP CONSTRAINT IF EXISTS charges_charge_type_check;
+ALTER TABLE charges ADD CONSTRAINT charges_charge_type_check
+    CHECK (charge_type IN ('recurring', 'usage', 'one_time', 'proration', 'discount', 'credit'));
+
+-- Trigger to update updated_utc on credit grants
+CREATE OR REPLACE FUNCTION update_credit_grants_updated_utc()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_utc = NOW();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_credit_grants_updated ON credit_grants;
+CREATE TRIGGER trg_credit_grants_updated
+    BEFORE UPDATE ON credit_grants
+    FOR EACH ROW
+    EXECUTE FUNCTION update_credit_grants_updated_utc();
@@ -0,0 +1,56 @@
+-- Usage Counters and Thresholds
+-- Each billing cycle keeps a running total per usage component, updated in the
+-- same transaction as the usage record. Thresholds watch those totals: alert
+-- thresholds record an alert the first time a cycle reaches them, and limit
+-- thresholds also reject usage that would take the total past them.
+
+-- usage_counters: Running usage total per cycle and component
+CREATE TABLE IF NOT EXISTS usage_counters (
+    cycle_id UUID NOT NULL REFERENCES billing_cycles(cycle_id) ON DELETE CASCADE,
+    component_id UUID NOT NULL REFERENCES usage_components(component_id),
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    quantity DECIMAL(19,4) NOT NULL DEFAULT 0,
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (cycle_id, component_id)
+);
+
+-- Usage recorded before counters existed
+INSERT INTO usage_counters (cycle_id, component_id, subscription_id, quantity)
+SELECT cycle_id, component_id, subscription_id, SUM(quantity)
+FROM usage_records
+WHERE cycle_id IS NOT NULL
+GROUP BY cycle_id, component_id, subscription_id
+ON CONFLICT (cycle_id, component_id) DO NOTHING;
+
+-- usage_thresholds: Alerts and hard limits on a subscription's component usage per cycle
+CREATE TABLE IF NOT EXISTS usage_thresholds (
+    threshold_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    component_id UUID NOT NULL REFERENCES usage_components(component_id),
+    threshold_type VARCHAR(20) NOT NULL CHECK (threshold_type IN ('alert', 'limit')),
+    quantity DECIMAL(19,4) CHECK (quantity > 0),
+    percent_of_included DECIMAL(7,4) CHECK (percent_of_included > 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((quantity IS NULL) <> (percent_of_included IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_usage_thresholds_component ON usage_thresholds(subscription_id, component_id) WHERE is_active = TRUE;
+
+-- usage_alerts: A threshold reached within a cycle; at most once per threshold and cycle
+CREATE TABLE IF NOT EXISTS usage_alerts (
+    alert_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    threshold_id UUID NOT NULL REFERENCES usage_thresholds(threshold_id) ON DELETE CASCADE,
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    component_id UUID NOT NULL REFERENCES usage_components(component_id),
+    cycle_id UUID NOT NULL REFERENCES billing_cycles(cycle_id) ON DELETE CASCADE,
+    threshold_type VARCHAR(20) NOT NULL CHECK (threshold_type IN ('alert', 'limit')),
+    threshold_quantity DECIMAL(19,4) NOT NULL,
+    usage_quantity DECIMAL(19,4) NOT NULL,
+    triggered_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT uq_usage_alerts_cycle UNIQUE (threshold_id, cycle_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_usage_alerts_subscription ON usage_alerts(subscription_id, triggered_utc);
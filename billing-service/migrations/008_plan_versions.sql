@@ -0,0 +1,58 @@
+-- Plan Versions and Subscriber Migrations
+-- A plan's price and tax rate are versioned: changing either adds a new
+-- version instead of repricing existing subscribers. Subscriptions stay on the
+-- version they signed up to until they change plan or are migrated. A
+-- migration moves a plan's subscribers from one version to another, each at
+-- their first renewal on or after the migration's effective date.
+
+ALTER TABLE billing_plans ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+
+-- billing_plan_versions: Immutable pricing of each plan version
+CREATE TABLE IF NOT EXISTS billing_plan_versions (
+    plan_id UUID NOT NULL REFERENCES billing_plans(plan_id) ON DELETE CASCADE,
+    version INTEGER NOT NULL CHECK (version > 0),
+    base_price DECIMAL(19,4) NOT NULL,
+    tax_rate_id UUID,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (plan_id, version)
+);
+
+-- Plans created before versioning
+INSERT INTO billing_plan_versions (plan_id, version, base_price, tax_rate_id, created_utc)
+SELECT plan_id, version, base_price, tax_rate_id, created_utc
+FROM billing_plans
+ON CONFLICT (plan_id, version) DO NOTHING;
+
+ALTER TABLE subscriptions ADD COLUMN IF NOT EXISTS plan_version INTEGER NOT NULL DEFAULT 1;
+
+-- plan_migrations: A scheduled move of a plan's subscribers between versions
+CREATE TABLE IF NOT EXISTS plan_migrations (
+    migration_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    plan_id UUID NOT NULL REFERENCES billing_plans(plan_id),
+    from_version INTEGER NOT NULL,
+    to_version INTEGER NOT NULL,
+    effective_date DATE NOT NULL,
+    notice_days INTEGER NOT NULL DEFAULT 0 CHECK (notice_days >= 0),
+    subscription_count INTEGER NOT NULL DEFAULT 0,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (from_version <> to_version)
+);
+
+CREATE INDEX IF NOT EXISTS idx_plan_migrations_plan ON plan_migrations(tenant_id, plan_id, created_utc);
+
+-- plan_migration_subscriptions: Each subscription a migration moves, with the
+-- prices it was scheduled at
+CREATE TABLE IF NOT EXISTS plan_migration_subscriptions (
+    migration_id UUID NOT NULL REFERENCES plan_migrations(migration_id) ON DELETE CASCADE,
+    subscription_id UUID NOT NULL REFERENCES subscriptions(subscription_id) ON DELETE CASCADE,
+    customer_id UUID NOT NULL,
+    current_price DECIMAL(19,4) NOT NULL,
+    new_price DECIMAL(19,4) NOT NULL,
+    effective_date DATE NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'scheduled' CHECK (status IN ('scheduled', 'applied', 'skipped')),
+    applied_utc TIMESTAMPTZ,
+    PRIMARY KEY (migration_id, subscription_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_plan_migration_subscriptions_scheduled ON plan_migration_subscriptions(subscription_id) WHERE status = 'scheduled';
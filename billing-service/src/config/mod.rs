@@ -13,7 +13,9 @@ pub struct BillingConfig {
     pub otlp_endpoint: Option<String>,
     pub database: DatabaseConfig,
     pub invoicing_service: InvoicingServiceConfig,
+    pub notification_service: NotificationServiceConfig,
     pub auth: AuthConfig,
+    pub scheduler: SchedulerConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +28,14 @@ pub struct DatabaseConfig {
 #[derive(Debug, Clone)]
 pub struct InvoicingServiceConfig {
     pub url: String,
+    /// Issue invoices as soon as a cycle is billed instead of leaving drafts.
+    pub issue_invoices: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationServiceConfig {
+    /// Where dunning reminders are sent from.
+    pub url: String,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +43,12 @@ pub struct AuthConfig {
     pub auth_service_endpoint: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    /// How often the billing scheduler runs. 0 disables it.
+    pub interval_secs: u64,
+}
+
 impl BillingConfig {
     pub fn from_env() -> Result<Self, AppError> {
         let common = core_config::Config::load()?;
@@ -61,11 +77,25 @@ impl BillingConfig {
             invoicing_service: InvoicingServiceConfig {
                 url: env::var("INVOICING_SERVICE_URL")
                     .unwrap_or_else(|_| "http://invoicing-service:3001".to_string()),
+                issue_invoices: env::var("INVOICING_ISSUE_INVOICES")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(false),
+            },
+            notification_service: NotificationServiceConfig {
+                url: env::var("NOTIFICATION_SERVICE_URL")
+                    .unwrap_or_else(|_| "http://notification-service:8081".to_string()),
             },
             auth: AuthConfig {
                 auth_service_endpoint: env::var("AUTH_SERVICE_ENDPOINT")
                     .unwrap_or_else(|_| "http://auth-service:3001".to_string()),
             },
+            scheduler: SchedulerConfig {
+                interval_secs: env::var("SCHEDULER_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(3600),
+            },
         })
     }
 }
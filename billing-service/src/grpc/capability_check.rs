@@ -16,6 +16,9 @@ pub mod capabilities {
     /// Update billing plans.
     pub const BILLING_PLAN_UPDATE: &str = "billing.plan:update";
 
+    /// Move a plan's subscribers between plan versions.
+    pub const BILLING_PLAN_MIGRATE: &str = "billing.plan:migrate";
+
     /// Create subscriptions.
     pub const BILLING_SUBSCRIPTION_CREATE: &str = "billing.subscription:create";
 
@@ -34,6 +37,9 @@ pub mod capabilities {
     /// Read usage records.
     pub const BILLING_USAGE_READ: &str = "billing.usage:read";
 
+    /// Create and delete usage thresholds.
+    pub const BILLING_USAGE_MANAGE: &str = "billing.usage:manage";
+
     /// Read billing cycles.
     pub const BILLING_CYCLE_READ: &str = "billing.cycle:read";
 
@@ -43,6 +49,30 @@ pub mod capabilities {
     /// Create charges.
     pub const BILLING_CHARGE_CREATE: &str = "billing.charge:create";
 
+    /// Create coupons and promotion codes.
+    pub const BILLING_COUPON_CREATE: &str = "billing.coupon:create";
+
+    /// Read coupons and promotion codes.
+    pub const BILLING_COUPON_READ: &str = "billing.coupon:read";
+
+    /// Archive coupons.
+    pub const BILLING_COUPON_UPDATE: &str = "billing.coupon:update";
+
+    /// Apply and remove subscription discounts.
+    pub const BILLING_DISCOUNT_MANAGE: &str = "billing.discount:manage";
+
+    /// Read dunning policies and cases.
+    pub const BILLING_DUNNING_READ: &str = "billing.dunning:read";
+
+    /// Set the dunning policy and retry or resolve dunning cases.
+    pub const BILLING_DUNNING_MANAGE: &str = "billing.dunning:manage";
+
+    /// Grant customer credit.
+    pub const BILLING_CREDIT_GRANT: &str = "billing.credit:grant";
+
+    /// Read customer credit balances and transactions.
+    pub const BILLING_CREDIT_READ: &str = "billing.credit:read";
+
     /// Execute billing runs.
     pub const BILLING_RUN_EXECUTE: &str = "billing.run:execute";
 
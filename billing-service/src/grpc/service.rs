@@ -5,39 +5,304 @@ use crate::grpc::proto::billing_service_server::BillingService;
 use crate::grpc::proto::*;
 use crate::models::{
     BillingCycleStatus, BillingInterval, BillingRunStatus, BillingRunType, ChargeType,
-    CreateCharge, CreatePlan, CreateSubscription, CreateUsageComponent, ListBillingCyclesFilter,
-    ListBillingRunsFilter, ListChargesFilter, ListPlansFilter, ListSubscriptionsFilter,
-    ListUsageFilter, ProrationMode, RecordUsage, SubscriptionStatus, UpdatePlan,
+    CouponDuration, CreateCharge, CreateCoupon, CreateCreditGrant, CreateDiscount, CreatePlan,
+    CreatePlanMigration, CreatePromotionCode, CreateSubscription, CreateUsageComponent,
+    CreateUsageThreshold, CreditTransactionType, DiscountType, DunningCaseStatus,
+    DunningFinalAction, DunningReason, ListBillingCyclesFilter, ListBillingRunsFilter,
+    ListChargesFilter, ListCouponsFilter, ListCreditTransactionsFilter, ListDunningCasesFilter,
+    ListPlansFilter, ListSubscriptionsFilter, ListUsageAlertsFilter, ListUsageFilter,
+    PlanMigrationStatus, PricingModel, ProrationMode, RecordUsage, RecordUsageOutcome,
+    SubscriptionStatus, UpdateDunningPolicy, UpdatePlan, UsageThresholdType,
 };
+use crate::services::database::calculate_period_end;
+use crate::services::pricing::validate_pricing;
 use crate::services::{
     record_billing_run, record_charge_amount, record_charge_created, record_error,
     record_grpc_request, record_grpc_request_duration, record_plan_operation,
-    record_subscription_operation, record_usage_operation, Database,
+    record_subscription_operation, record_usage_alert, record_usage_operation, BillingEngine,
+    BillingError, Database, DunningManager,
 };
 use chrono::{Datelike, NaiveDate, Utc};
 use prost_types::Timestamp;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use service_core::grpc::NotificationClient;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use uuid::Uuid;
 
+/// Most usage records written in one database transaction.
+const USAGE_CHUNK_SIZE: usize = 500;
+
+/// Most records accepted by one RecordUsageBatch call.
+const MAX_USAGE_BATCH: usize = 5000;
+
+/// Subscriptions seen while ingesting usage, with their plan's component IDs;
+/// `None` for subscriptions not found for the tenant.
+type UsageSubscriptions = HashMap<Uuid, Option<(crate::models::Subscription, HashSet<Uuid>)>>;
+
 /// BillingService implementation.
 pub struct BillingServiceImpl {
     db: Arc<Database>,
+    billing: BillingEngine,
+    dunning: DunningManager,
+    notifications: Option<NotificationClient>,
     capability_checker: Arc<CapabilityChecker>,
 }
 
 impl BillingServiceImpl {
     /// Create a new BillingServiceImpl.
     pub fn new(db: Arc<Database>, capability_checker: Arc<CapabilityChecker>) -> Self {
+        Self::with_billing_engine(db.clone(), capability_checker, BillingEngine::new(db))
+    }
+
+    /// Create a new BillingServiceImpl billing through the given engine.
+    pub fn with_billing_engine(
+        db: Arc<Database>,
+        capability_checker: Arc<CapabilityChecker>,
+        billing: BillingEngine,
+    ) -> Self {
         Self {
+            dunning: DunningManager::new(db.clone(), billing.clone()),
             db,
+            billing,
+            notifications: None,
             capability_checker,
         }
     }
+
+    /// Email usage alerts and dunning reminders through the notification
+    /// service to the subscription's `billing_email` metadata.
+    pub fn with_notifications(mut self, notifications: NotificationClient) -> Self {
+        self.dunning = DunningManager::with_notifications(
+            self.db.clone(),
+            self.billing.clone(),
+            notifications.clone(),
+        );
+        self.notifications = Some(notifications);
+        self
+    }
+
+    /// Count usage alerts and email them to the subscription's billing
+    /// contact in the background, so notification latency never holds up
+    /// usage ingestion.
+    fn notify_usage_alerts(
+        &self,
+        subscription: &crate::models::Subscription,
+        alerts: &[crate::models::UsageAlert],
+    ) {
+        for alert in alerts {
+            record_usage_alert(&alert.tenant_id.to_string(), &alert.threshold_type);
+        }
+
+        let (Some(client), Some(email)) = (&self.notifications, subscription.billing_email())
+        else {
+            return;
+        };
+
+        for alert in alerts {
+            let subject = match UsageThresholdType::from_string(&alert.threshold_type) {
+                UsageThresholdType::Alert => "Usage alert",
+                UsageThresholdType::Limit => "Usage limit reached",
+            };
+            let body = format!(
+                "Your usage for the current billing period has reached {} of the {} threshold.",
+                alert.usage_quantity.normalize(),
+                alert.threshold_quantity.normalize()
+            );
+            let metadata = HashMap::from([
+                ("usage_alert_id".to_string(), alert.alert_id.to_string()),
+                (
+                    "subscription_id".to_string(),
+                    alert.subscription_id.to_string(),
+                ),
+                ("component_id".to_string(), alert.component_id.to_string()),
+            ]);
+
+            let mut client = client.clone();
+            let email = email.to_string();
+            let alert_id = alert.alert_id;
+            tokio::spawn(async move {
+                if let Err(e) = client
+                    .send_email(
+                        email,
+                        subject.to_string(),
+                        Some(body),
+                        None,
+                        None,
+                        None,
+                        metadata,
+                    )
+                    .await
+                {
+                    tracing::warn!(alert_id = %alert_id, error = %e, "Failed to send usage alert");
+                }
+            });
+        }
+    }
+
+    /// Email each migrated subscription's billing contact the price it moves
+    /// to and when, in the background like usage alerts.
+    fn notify_plan_migration(
+        &self,
+        plan: &crate::models::BillingPlan,
+        subscribers: &[crate::models::Subscription],
+        scheduled: &[crate::models::PlanMigrationSubscription],
+    ) {
+        let Some(client) = &self.notifications else {
+            return;
+        };
+
+        for (subscription, migration) in subscribers.iter().zip(scheduled) {
+            let Some(email) = subscription.billing_email() else {
+                continue;
+            };
+            let body = format!(
+                "The price of your {} plan changes from {} {} to {} {} from the billing period starting {}.",
+                plan.name,
+                migration.current_price.normalize(),
+                plan.currency,
+                migration.new_price.normalize(),
+                plan.currency,
+                migration.effective_date
+            );
+            let metadata = HashMap::from([
+                (
+                    "subscription_id".to_string(),
+                    subscription.subscription_id.to_string(),
+                ),
+                ("plan_id".to_string(), plan.plan_id.to_string()),
+            ]);
+
+            let mut client = client.clone();
+            let email = email.to_string();
+            let subscription_id = subscription.subscription_id;
+            tokio::spawn(async move {
+                if let Err(e) = client
+                    .send_email(
+                        email,
+                        "Upcoming price change".to_string(),
+                        Some(body),
+                        None,
+                        None,
+                        None,
+                        metadata,
+                    )
+                    .await
+                {
+                    tracing::warn!(
+                        subscription_id = %subscription_id,
+                        error = %e,
+                        "Failed to send price change notice"
+                    );
+                }
+            });
+        }
+    }
+
+    /// Validate and record a chunk of a usage batch or stream, returning one
+    /// result per record. `offset` is the chunk's position in the batch or
+    /// stream; `subscriptions` caches the tenant's subscriptions and their
+    /// plan's component IDs across chunks.
+    async fn ingest_usage(
+        &self,
+        tenant_id: Uuid,
+        records: Vec<UsageRecordInput>,
+        offset: usize,
+        subscriptions: &mut UsageSubscriptions,
+    ) -> Result<Vec<UsageRecordResult>, service_core::error::AppError> {
+        let mut results: Vec<Option<UsageRecordResult>> = vec![None; records.len()];
+        let mut indexes = Vec::new();
+        let mut inputs = Vec::new();
+
+        for (position, record) in records.into_iter().enumerate() {
+            let index = offset + position;
+            let input = match usage_input_from_proto(record) {
+                Ok(input) => input,
+                Err(status) => {
+                    results[position] = Some(rejected_usage(index, status.message()));
+                    continue;
+                }
+            };
+
+            if let Entry::Vacant(slot) = subscriptions.entry(input.subscription_id) {
+                let entry = match self
+                    .db
+                    .get_subscription(tenant_id, input.subscription_id)
+                    .await?
+                {
+                    Some(subscription) => {
+                        let components = self
+                            .db
+                            .get_usage_components(subscription.plan_id)
+                            .await?
+                            .into_iter()
+                            .map(|c| c.component_id)
+                            .collect();
+                        Some((subscription, components))
+                    }
+                    None => None,
+                };
+                slot.insert(entry);
+            }
+            match &subscriptions[&input.subscription_id] {
+                None => {
+                    results[position] = Some(rejected_usage(index, "Subscription not found"));
+                }
+                Some((_, components)) if !components.contains(&input.component_id) => {
+                    results[position] = Some(rejected_usage(
+                        index,
+                        "Usage component is not part of the subscription's plan",
+                    ));
+                }
+                Some(_) => {
+                    indexes.push(position);
+                    inputs.push(input);
+                }
+            }
+        }
+
+        let outcomes = self.db.record_usage_batch(&inputs).await?;
+
+        for ((position, input), outcome) in indexes.into_iter().zip(&inputs).zip(outcomes) {
+            let index = (offset + position) as i32;
+            let result = match outcome {
+                RecordUsageOutcome::Recorded(record, alerts) => {
+                    if let Some((subscription, _)) = &subscriptions[&input.subscription_id] {
+                        self.notify_usage_alerts(subscription, &alerts);
+                    }
+                    record_usage_operation(&tenant_id.to_string(), &input.component_id.to_string());
+                    UsageRecordResult {
+                        index,
+                        status: UsageRecordStatus::Recorded as i32,
+                        usage_record: Some(usage_record_to_proto(record)),
+                        error: String::new(),
+                        alerts: alerts.into_iter().map(usage_alert_to_proto).collect(),
+                    }
+                }
+                RecordUsageOutcome::Duplicate(record) => UsageRecordResult {
+                    index,
+                    status: UsageRecordStatus::Duplicate as i32,
+                    usage_record: Some(usage_record_to_proto(record)),
+                    ..Default::default()
+                },
+                RecordUsageOutcome::LimitExceeded(message) => UsageRecordResult {
+                    index,
+                    status: UsageRecordStatus::LimitExceeded as i32,
+                    error: message,
+                    ..Default::default()
+                },
+                RecordUsageOutcome::Rejected(message) => rejected_usage(index as usize, message),
+            };
+            results[position] = Some(result);
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
 }
 
 // Helper functions for type conversions
@@ -51,6 +316,15 @@ fn parse_decimal(s: &str) -> Result<Decimal, Status> {
     Decimal::from_str(s).map_err(|_| Status::invalid_argument(format!("Invalid decimal: {}", s)))
 }
 
+#[allow(clippy::result_large_err)]
+fn parse_optional_decimal(s: &str) -> Result<Option<Decimal>, Status> {
+    if s.is_empty() {
+        Ok(None)
+    } else {
+        parse_decimal(s).map(Some)
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn parse_date(s: &str) -> Result<NaiveDate, Status> {
     NaiveDate::parse_from_str(s, "%Y-%m-%d")
@@ -98,6 +372,45 @@ fn plan_to_proto(
         metadata: plan.metadata.map(|m| m.to_string()).unwrap_or_default(),
         created_at: datetime_to_timestamp(plan.created_utc),
         updated_at: datetime_to_timestamp(plan.updated_utc),
+        version: plan.version,
+    }
+}
+
+fn plan_version_to_proto(v: crate::models::PlanVersion) -> PlanVersion {
+    PlanVersion {
+        plan_id: v.plan_id.to_string(),
+        version: v.version,
+        base_price: v.base_price.to_string(),
+        tax_rate_id: v.tax_rate_id.map(|id| id.to_string()).unwrap_or_default(),
+        created_at: datetime_to_timestamp(v.created_utc),
+    }
+}
+
+fn plan_migration_to_proto(m: crate::models::PlanMigration) -> PlanMigration {
+    PlanMigration {
+        migration_id: m.migration_id.to_string(),
+        tenant_id: m.tenant_id.to_string(),
+        plan_id: m.plan_id.to_string(),
+        from_version: m.from_version,
+        to_version: m.to_version,
+        effective_date: m.effective_date.to_string(),
+        notice_days: m.notice_days,
+        subscription_count: m.subscription_count,
+        created_at: datetime_to_timestamp(m.created_utc),
+    }
+}
+
+fn plan_migration_subscription_to_proto(
+    s: crate::models::PlanMigrationSubscription,
+) -> PlanMigrationSubscription {
+    PlanMigrationSubscription {
+        subscription_id: s.subscription_id.to_string(),
+        customer_id: s.customer_id.to_string(),
+        current_price: s.current_price.to_string(),
+        new_price: s.new_price.to_string(),
+        effective_date: s.effective_date.to_string(),
+        status: PlanMigrationStatus::from_string(&s.status).to_proto(),
+        applied_at: s.applied_utc.and_then(datetime_to_timestamp),
     }
 }
 
@@ -110,9 +423,71 @@ fn component_to_proto(c: crate::models::UsageComponent) -> UsageComponent {
         unit_price: c.unit_price.to_string(),
         included_units: c.included_units,
         is_active: c.is_active,
+        pricing_model: PricingModel::from_string(&c.pricing_model).to_proto(),
+        tiers: c
+            .tiers
+            .0
+            .into_iter()
+            .map(|t| PricingTier {
+                up_to: t.up_to.map(|u| u.to_string()).unwrap_or_default(),
+                unit_price: t.unit_price.to_string(),
+                flat_fee: t.flat_fee.to_string(),
+            })
+            .collect(),
+        package_size: c.package_size.map(|p| p.to_string()).unwrap_or_default(),
+        minimum_amount: c.minimum_amount.map(|m| m.to_string()).unwrap_or_default(),
+        maximum_amount: c.maximum_amount.map(|m| m.to_string()).unwrap_or_default(),
     }
 }
 
+/// Parse and validate a usage component for a plan.
+#[allow(clippy::result_large_err)]
+fn usage_component_from_proto(
+    plan_id: Uuid,
+    c: CreateUsageComponentInput,
+) -> Result<CreateUsageComponent, Status> {
+    let pricing_model = PricingModel::from_proto(c.pricing_model);
+
+    // Tiered components price through their tiers alone
+    let unit_price = match pricing_model {
+        PricingModel::Graduated | PricingModel::Volume => {
+            parse_optional_decimal(&c.unit_price)?.unwrap_or_default()
+        }
+        PricingModel::PerUnit | PricingModel::Package => parse_decimal(&c.unit_price)?,
+    };
+
+    let tiers = c
+        .tiers
+        .iter()
+        .map(|t| {
+            Ok(crate::models::PricingTier {
+                up_to: parse_optional_decimal(&t.up_to)?,
+                unit_price: parse_decimal(&t.unit_price)?,
+                flat_fee: parse_optional_decimal(&t.flat_fee)?.unwrap_or_default(),
+            })
+        })
+        .collect::<Result<Vec<_>, Status>>()?;
+
+    let input = CreateUsageComponent {
+        plan_id,
+        name: c.name,
+        unit_name: c.unit_name,
+        unit_price,
+        included_units: c.included_units,
+        pricing_model,
+        tiers,
+        package_size: parse_optional_decimal(&c.package_size)?,
+        minimum_amount: parse_optional_decimal(&c.minimum_amount)?,
+        maximum_amount: parse_optional_decimal(&c.maximum_amount)?,
+    };
+
+    validate_pricing((&input).into()).map_err(|e| {
+        Status::invalid_argument(format!("Invalid usage component {}: {}", input.name, e))
+    })?;
+
+    Ok(input)
+}
+
 fn subscription_to_proto(s: crate::models::Subscription) -> Subscription {
     Subscription {
         subscription_id: s.subscription_id.to_string(),
@@ -134,6 +509,7 @@ fn subscription_to_proto(s: crate::models::Subscription) -> Subscription {
         metadata: s.metadata.map(|m| m.to_string()).unwrap_or_default(),
         created_at: datetime_to_timestamp(s.created_utc),
         updated_at: datetime_to_timestamp(s.updated_utc),
+        plan_version: s.plan_version,
     }
 }
 
@@ -168,7 +544,317 @@ fn charge_to_proto(c: crate::models::Charge) -> Charge {
         component_id: c.component_id.map(|id| id.to_string()).unwrap_or_default(),
         metadata: c.metadata.map(|m| m.to_string()).unwrap_or_default(),
         created_at: datetime_to_timestamp(c.created_utc),
+        invoice_id: c.invoice_id.map(|id| id.to_string()).unwrap_or_default(),
+        discount_id: c.discount_id.map(|id| id.to_string()).unwrap_or_default(),
+    }
+}
+
+fn preview_charge_to_proto(c: crate::models::PreviewCharge) -> PreviewCharge {
+    PreviewCharge {
+        charge_id: c.charge_id.map(|id| id.to_string()).unwrap_or_default(),
+        charge_type: c.charge.charge_type.to_proto(),
+        description: c.charge.description,
+        quantity: c.charge.quantity.to_string(),
+        unit_price: c.charge.unit_price.to_string(),
+        amount: c.charge.amount.to_string(),
+        is_prorated: c.charge.is_prorated,
+        proration_factor: c
+            .charge
+            .proration_factor
+            .map(|f| f.to_string())
+            .unwrap_or_default(),
+        component_id: c
+            .charge
+            .component_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        metadata: c.charge.metadata.map(|m| m.to_string()).unwrap_or_default(),
+    }
+}
+
+fn coupon_to_proto(c: crate::models::Coupon) -> Coupon {
+    Coupon {
+        coupon_id: c.coupon_id.to_string(),
+        tenant_id: c.tenant_id.to_string(),
+        name: c.name,
+        discount_type: DiscountType::from_string(&c.discount_type).to_proto(),
+        percent_off: c.percent_off.map(|p| p.to_string()).unwrap_or_default(),
+        amount_off: c.amount_off.map(|a| a.to_string()).unwrap_or_default(),
+        currency: c.currency.unwrap_or_default(),
+        duration: CouponDuration::from_string(&c.duration).to_proto(),
+        duration_cycles: c.duration_cycles.unwrap_or_default(),
+        max_redemptions: c.max_redemptions.unwrap_or_default(),
+        times_redeemed: c.times_redeemed,
+        expires_at: c.expires_utc.and_then(datetime_to_timestamp),
+        is_active: c.is_active,
+        metadata: c.metadata.map(|m| m.to_string()).unwrap_or_default(),
+        created_at: datetime_to_timestamp(c.created_utc),
+        updated_at: datetime_to_timestamp(c.updated_utc),
+    }
+}
+
+fn promotion_code_to_proto(p: crate::models::PromotionCode) -> PromotionCode {
+    PromotionCode {
+        promotion_code_id: p.promotion_code_id.to_string(),
+        tenant_id: p.tenant_id.to_string(),
+        coupon_id: p.coupon_id.to_string(),
+        code: p.code,
+        max_redemptions: p.max_redemptions.unwrap_or_default(),
+        times_redeemed: p.times_redeemed,
+        expires_at: p.expires_utc.and_then(datetime_to_timestamp),
+        is_active: p.is_active,
+        created_at: datetime_to_timestamp(p.created_utc),
+    }
+}
+
+fn discount_to_proto(d: crate::models::Discount) -> Discount {
+    Discount {
+        discount_id: d.discount_id.to_string(),
+        tenant_id: d.tenant_id.to_string(),
+        coupon_id: d.coupon_id.to_string(),
+        promotion_code_id: d
+            .promotion_code_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        subscription_id: d.subscription_id.to_string(),
+        charge_id: d.charge_id.map(|id| id.to_string()).unwrap_or_default(),
+        cycles_applied: d.cycles_applied,
+        is_active: d.is_active,
+        created_at: datetime_to_timestamp(d.created_utc),
+        ended_at: d.ended_utc.and_then(datetime_to_timestamp),
+    }
+}
+
+fn dunning_policy_to_proto(p: crate::models::DunningPolicy, is_default: bool) -> DunningPolicy {
+    DunningPolicy {
+        tenant_id: p.tenant_id.to_string(),
+        retry_days: p.retry_days,
+        reminder_days: p.reminder_days,
+        grace_period_days: p.grace_period_days,
+        final_action: DunningFinalAction::from_string(&p.final_action).to_proto(),
+        is_default,
+        updated_at: if is_default {
+            None
+        } else {
+            datetime_to_timestamp(p.updated_utc)
+        },
+    }
+}
+
+fn dunning_case_to_proto(c: crate::models::DunningCase) -> DunningCase {
+    DunningCase {
+        case_id: c.case_id.to_string(),
+        tenant_id: c.tenant_id.to_string(),
+        subscription_id: c.subscription_id.to_string(),
+        cycle_id: c.cycle_id.to_string(),
+        reason: DunningReason::from_string(&c.reason).to_proto(),
+        status: DunningCaseStatus::from_string(&c.status).to_proto(),
+        retry_count: c.retry_count,
+        reminders_sent: c.reminders_sent,
+        last_error: c.last_error.unwrap_or_default(),
+        resolution_note: c.resolution_note.unwrap_or_default(),
+        opened_at: datetime_to_timestamp(c.opened_utc),
+        closed_at: c.closed_utc.and_then(datetime_to_timestamp),
+    }
+}
+
+/// Parse and validate a dunning policy.
+#[allow(clippy::result_large_err)]
+fn dunning_policy_from_proto(
+    tenant_id: Uuid,
+    req: UpdateDunningPolicyRequest,
+) -> Result<UpdateDunningPolicy, Status> {
+    for (field, days) in [
+        ("retry_days", &req.retry_days),
+        ("reminder_days", &req.reminder_days),
+    ] {
+        if days.iter().any(|&d| d < 0) {
+            return Err(Status::invalid_argument(format!(
+                "{} cannot be negative",
+                field
+            )));
+        }
+        if days.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(Status::invalid_argument(format!(
+                "{} must be increasing",
+                field
+            )));
+        }
+    }
+    if req.grace_period_days < 0 {
+        return Err(Status::invalid_argument(
+            "grace_period_days cannot be negative",
+        ));
+    }
+    if req.final_action == 0 {
+        return Err(Status::invalid_argument("final_action is required"));
+    }
+
+    Ok(UpdateDunningPolicy {
+        tenant_id,
+        retry_days: req.retry_days,
+        reminder_days: req.reminder_days,
+        grace_period_days: req.grace_period_days,
+        final_action: DunningFinalAction::from_proto(req.final_action),
+    })
+}
+
+/// Parse and validate a coupon definition.
+#[allow(clippy::result_large_err)]
+fn coupon_from_proto(tenant_id: Uuid, req: CreateCouponRequest) -> Result<CreateCoupon, Status> {
+    if req.name.trim().is_empty() {
+        return Err(Status::invalid_argument("name is required"));
+    }
+
+    let discount_type = match req.discount_type {
+        1 | 2 => DiscountType::from_proto(req.discount_type),
+        _ => return Err(Status::invalid_argument("discount_type is required")),
+    };
+    let (percent_off, amount_off, currency) = match discount_type {
+        DiscountType::PercentOff => {
+            let percent_off = parse_decimal(&req.percent_off)?;
+            if percent_off <= Decimal::ZERO || percent_off > Decimal::ONE_HUNDRED {
+                return Err(Status::invalid_argument(
+                    "percent_off must be greater than 0 and at most 100",
+                ));
+            }
+            (Some(percent_off), None, None)
+        }
+        DiscountType::AmountOff => {
+            let amount_off = parse_decimal(&req.amount_off)?;
+            if amount_off <= Decimal::ZERO {
+                return Err(Status::invalid_argument("amount_off must be positive"));
+            }
+            if req.currency.len() != 3 {
+                return Err(Status::invalid_argument(
+                    "currency is required for amount_off coupons",
+                ));
+            }
+            (None, Some(amount_off), Some(req.currency.to_uppercase()))
+        }
+    };
+
+    let duration = match req.duration {
+        1..=3 => CouponDuration::from_proto(req.duration),
+        _ => return Err(Status::invalid_argument("duration is required")),
+    };
+    let duration_cycles = match duration {
+        CouponDuration::Repeating if req.duration_cycles > 0 => Some(req.duration_cycles),
+        CouponDuration::Repeating => {
+            return Err(Status::invalid_argument(
+                "duration_cycles must be positive for repeating coupons",
+            ))
+        }
+        _ => None,
+    };
+
+    if req.max_redemptions < 0 {
+        return Err(Status::invalid_argument(
+            "max_redemptions cannot be negative",
+        ));
+    }
+
+    Ok(CreateCoupon {
+        tenant_id,
+        name: req.name,
+        discount_type,
+        percent_off,
+        amount_off,
+        currency,
+        duration,
+        duration_cycles,
+        max_redemptions: (req.max_redemptions > 0).then_some(req.max_redemptions),
+        expires_utc: req.expires_at.map(|ts| timestamp_to_datetime(Some(ts))),
+        metadata: if req.metadata.is_empty() {
+            None
+        } else {
+            serde_json::from_str(&req.metadata).ok()
+        },
+    })
+}
+
+fn credit_grant_to_proto(g: crate::models::CreditGrant) -> CreditGrant {
+    CreditGrant {
+        grant_id: g.grant_id.to_string(),
+        tenant_id: g.tenant_id.to_string(),
+        customer_id: g.customer_id.to_string(),
+        currency: g.currency,
+        amount: g.amount.to_string(),
+        remaining: g.remaining.to_string(),
+        priority: g.priority,
+        description: g.description,
+        ledger_account_id: g
+            .ledger_account_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        expires_at: g.expires_utc.and_then(datetime_to_timestamp),
+        created_at: datetime_to_timestamp(g.created_utc),
+    }
+}
+
+fn credit_transaction_to_proto(t: crate::models::CreditTransaction) -> CreditTransaction {
+    CreditTransaction {
+        transaction_id: t.transaction_id.to_string(),
+        tenant_id: t.tenant_id.to_string(),
+        customer_id: t.customer_id.to_string(),
+        grant_id: t.grant_id.to_string(),
+        transaction_type: CreditTransactionType::from_string(&t.transaction_type).to_proto(),
+        amount: t.amount.to_string(),
+        currency: t.currency,
+        ledger_account_id: t
+            .ledger_account_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        cycle_id: t.cycle_id.map(|id| id.to_string()).unwrap_or_default(),
+        charge_id: t.charge_id.map(|id| id.to_string()).unwrap_or_default(),
+        created_at: datetime_to_timestamp(t.created_utc),
+    }
+}
+
+/// Parse and validate a credit grant.
+#[allow(clippy::result_large_err)]
+fn credit_grant_from_proto(
+    tenant_id: Uuid,
+    req: GrantCreditRequest,
+) -> Result<CreateCreditGrant, Status> {
+    let customer_id = parse_uuid(&req.customer_id)?;
+
+    let amount = parse_decimal(&req.amount)?;
+    if amount <= Decimal::ZERO {
+        return Err(Status::invalid_argument("amount must be positive"));
+    }
+    if req.currency.len() != 3 {
+        return Err(Status::invalid_argument("currency is required"));
+    }
+    if !(0..=100).contains(&req.priority) {
+        return Err(Status::invalid_argument(
+            "priority must be between 0 and 100",
+        ));
     }
+
+    let expires_utc = req.expires_at.map(|ts| timestamp_to_datetime(Some(ts)));
+    if expires_utc.is_some_and(|expires| expires <= Utc::now()) {
+        return Err(Status::invalid_argument("expires_at must be in the future"));
+    }
+
+    Ok(CreateCreditGrant {
+        tenant_id,
+        customer_id,
+        currency: req.currency.to_uppercase(),
+        amount,
+        priority: req.priority,
+        description: if req.description.trim().is_empty() {
+            "Prepaid credit".to_string()
+        } else {
+            req.description
+        },
+        ledger_account_id: if req.ledger_account_id.is_empty() {
+            None
+        } else {
+            Some(parse_uuid(&req.ledger_account_id)?)
+        },
+        expires_utc,
+    })
 }
 
 fn usage_record_to_proto(r: crate::models::UsageRecord) -> UsageRecord {
@@ -186,6 +872,126 @@ fn usage_record_to_proto(r: crate::models::UsageRecord) -> UsageRecord {
     }
 }
 
+fn usage_threshold_to_proto(t: crate::models::UsageThreshold) -> UsageThreshold {
+    UsageThreshold {
+        threshold_id: t.threshold_id.to_string(),
+        subscription_id: t.subscription_id.to_string(),
+        component_id: t.component_id.to_string(),
+        threshold_type: UsageThresholdType::from_string(&t.threshold_type).to_proto(),
+        quantity: t.quantity.map(|q| q.to_string()).unwrap_or_default(),
+        percent_of_included: t
+            .percent_of_included
+            .map(|p| p.to_string())
+            .unwrap_or_default(),
+        created_at: datetime_to_timestamp(t.created_utc),
+    }
+}
+
+fn usage_alert_to_proto(a: crate::models::UsageAlert) -> UsageAlert {
+    UsageAlert {
+        alert_id: a.alert_id.to_string(),
+        threshold_id: a.threshold_id.to_string(),
+        subscription_id: a.subscription_id.to_string(),
+        component_id: a.component_id.to_string(),
+        cycle_id: a.cycle_id.to_string(),
+        threshold_type: UsageThresholdType::from_string(&a.threshold_type).to_proto(),
+        threshold_quantity: a.threshold_quantity.to_string(),
+        usage_quantity: a.usage_quantity.to_string(),
+        triggered_at: datetime_to_timestamp(a.triggered_utc),
+    }
+}
+
+/// Parse and validate one record of a usage batch or stream.
+#[allow(clippy::result_large_err)]
+fn usage_input_from_proto(r: UsageRecordInput) -> Result<RecordUsage, Status> {
+    if r.idempotency_key.is_empty() || r.idempotency_key.len() > 255 {
+        return Err(Status::invalid_argument(
+            "idempotency_key is required and at most 255 characters",
+        ));
+    }
+    let metadata = if r.metadata.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::from_str(&r.metadata)
+                .map_err(|_| Status::invalid_argument("metadata must be JSON"))?,
+        )
+    };
+
+    Ok(RecordUsage {
+        subscription_id: parse_uuid(&r.subscription_id)?,
+        component_id: parse_uuid(&r.component_id)?,
+        quantity: parse_decimal(&r.quantity)?,
+        timestamp: timestamp_to_datetime(r.timestamp),
+        idempotency_key: r.idempotency_key,
+        metadata,
+    })
+}
+
+fn rejected_usage(index: usize, error: impl Into<String>) -> UsageRecordResult {
+    UsageRecordResult {
+        index: index as i32,
+        status: UsageRecordStatus::Rejected as i32,
+        error: error.into(),
+        ..Default::default()
+    }
+}
+
+/// Count recorded, duplicate and rejected results.
+fn usage_result_counts(results: &[UsageRecordResult]) -> (i32, i32, i32) {
+    results
+        .iter()
+        .fold(
+            (0, 0, 0),
+            |(recorded, duplicate, rejected), r| match UsageRecordStatus::try_from(r.status) {
+                Ok(UsageRecordStatus::Recorded) => (recorded + 1, duplicate, rejected),
+                Ok(UsageRecordStatus::Duplicate) => (recorded, duplicate + 1, rejected),
+                _ => (recorded, duplicate, rejected + 1),
+            },
+        )
+}
+
+/// Parse and validate a usage threshold. Whether the component belongs to
+/// the subscription's plan is checked against the database by the caller.
+#[allow(clippy::result_large_err)]
+fn usage_threshold_from_proto(
+    tenant_id: Uuid,
+    req: CreateUsageThresholdRequest,
+) -> Result<CreateUsageThreshold, Status> {
+    let threshold_type = match req.threshold_type {
+        1 | 2 => UsageThresholdType::from_proto(req.threshold_type),
+        _ => return Err(Status::invalid_argument("threshold_type is required")),
+    };
+
+    let quantity = parse_optional_decimal(&req.quantity)?;
+    let percent_of_included = parse_optional_decimal(&req.percent_of_included)?;
+    match (quantity, percent_of_included) {
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(Status::invalid_argument(
+                "Set exactly one of quantity and percent_of_included",
+            ));
+        }
+        (Some(q), None) if q <= Decimal::ZERO => {
+            return Err(Status::invalid_argument("quantity must be positive"));
+        }
+        (None, Some(p)) if p <= Decimal::ZERO || p >= Decimal::from(1000) => {
+            return Err(Status::invalid_argument(
+                "percent_of_included must be positive and below 1000",
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(CreateUsageThreshold {
+        tenant_id,
+        subscription_id: parse_uuid(&req.subscription_id)?,
+        component_id: parse_uuid(&req.component_id)?,
+        threshold_type,
+        quantity,
+        percent_of_included,
+    })
+}
+
 fn billing_run_to_proto(
     r: crate::models::BillingRun,
     results: Vec<BillingRunResult>,
@@ -272,6 +1078,18 @@ impl BillingService for BillingServiceImpl {
             },
         };
 
+        // Validate usage components before anything is stored
+        #[allow(clippy::result_large_err)]
+        let component_inputs = req
+            .usage_components
+            .into_iter()
+            .map(|comp| usage_component_from_proto(Uuid::nil(), comp))
+            .collect::<Result<Vec<_>, Status>>()
+            .inspect_err(|_| {
+                record_grpc_request(method, "invalid_argument");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            })?;
+
         let plan = self.db.create_plan(&input).await.map_err(|e| {
             tracing::error!(error = %e, "Failed to create plan");
             record_error("database", method);
@@ -282,14 +1100,8 @@ impl BillingService for BillingServiceImpl {
 
         // Create usage components
         let mut components = Vec::new();
-        for comp in req.usage_components {
-            let comp_input = CreateUsageComponent {
-                plan_id: plan.plan_id,
-                name: comp.name,
-                unit_name: comp.unit_name,
-                unit_price: parse_decimal(&comp.unit_price)?,
-                included_units: comp.included_units,
-            };
+        for mut comp_input in component_inputs {
+            comp_input.plan_id = plan.plan_id;
             let component = self
                 .db
                 .create_usage_component(&comp_input)
@@ -569,71 +1381,309 @@ impl BillingService for BillingServiceImpl {
         }))
     }
 
-    // =========================================================================
-    // Subscription Management
-    // =========================================================================
-
-    #[tracing::instrument(skip(self, request), fields(method = "CreateSubscription"))]
-    async fn create_subscription(
+    #[tracing::instrument(skip(self, request), fields(method = "ListPlanVersions"))]
+    async fn list_plan_versions(
         &self,
-        request: Request<CreateSubscriptionRequest>,
-    ) -> Result<Response<CreateSubscriptionResponse>, Status> {
+        request: Request<ListPlanVersionsRequest>,
+    ) -> Result<Response<ListPlanVersionsResponse>, Status> {
         let start = Instant::now();
-        let method = "CreateSubscription";
+        let method = "ListPlanVersions";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_SUBSCRIPTION_CREATE)
+            .require_capability(&request, capabilities::BILLING_PLAN_READ)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
         let req = request.into_inner();
-        tracing::info!(
-            tenant_id = %tenant_id,
-            customer_id = %req.customer_id,
-            plan_id = %req.plan_id,
-            "Creating subscription"
-        );
-
-        let start_date = if req.start_date.is_empty() {
-            Utc::now().date_naive()
-        } else {
-            parse_date(&req.start_date)?
-        };
-
-        let input = CreateSubscription {
-            tenant_id,
-            customer_id: parse_uuid(&req.customer_id)?,
-            plan_id: parse_uuid(&req.plan_id)?,
-            billing_anchor_day: if req.billing_anchor_day > 0 && req.billing_anchor_day <= 31 {
-                req.billing_anchor_day
-            } else {
-                start_date.day() as i32
-            },
-            start_date,
-            trial_end_date: if req.trial_end_date.is_empty() {
-                None
-            } else {
-                Some(parse_date(&req.trial_end_date)?)
-            },
-            proration_mode: ProrationMode::from_proto(req.proration_mode),
-            metadata: if req.metadata.is_empty() {
-                None
-            } else {
-                serde_json::from_str(&req.metadata).ok()
-            },
-        };
+        let plan_id = parse_uuid(&req.plan_id)?;
 
-        let subscription = self.db.create_subscription(&input).await.map_err(|e| {
-            tracing::error!(error = %e, "Failed to create subscription");
+        let db_error = |e: service_core::error::AppError| {
             record_error("database", method);
             record_grpc_request(method, "error");
             record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            match e {
-                service_core::error::AppError::NotFound(_) => Status::not_found("Plan not found"),
-                _ => Status::internal(e.to_string()),
-            }
-        })?;
+            Status::internal(e.to_string())
+        };
+
+        if self
+            .db
+            .get_plan(tenant_id, plan_id)
+            .await
+            .map_err(db_error)?
+            .is_none()
+        {
+            record_grpc_request(method, "not_found");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            return Err(Status::not_found("Plan not found"));
+        }
+
+        let versions = self
+            .db
+            .list_plan_versions(plan_id)
+            .await
+            .map_err(db_error)?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ListPlanVersionsResponse {
+            versions: versions.into_iter().map(plan_version_to_proto).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "MigrateSubscribers"))]
+    async fn migrate_subscribers(
+        &self,
+        request: Request<MigrateSubscribersRequest>,
+    ) -> Result<Response<MigrateSubscribersResponse>, Status> {
+        let start = Instant::now();
+        let method = "MigrateSubscribers";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_PLAN_MIGRATE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let plan_id = parse_uuid(&req.plan_id)?;
+        let effective_date = parse_date(&req.effective_date)?;
+        let invalid = |message: &str| {
+            record_grpc_request(method, "invalid_argument");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::invalid_argument(message)
+        };
+        if req.from_version == req.to_version {
+            return Err(invalid("from_version and to_version must differ"));
+        }
+        if req.notice_days < 0 {
+            return Err(invalid("notice_days must not be negative"));
+        }
+        let notice_end = Utc::now()
+            .date_naive()
+            .checked_add_days(chrono::Days::new(req.notice_days as u64))
+            .ok_or_else(|| invalid("notice_days is out of range"))?;
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            plan_id = %plan_id,
+            from_version = req.from_version,
+            to_version = req.to_version,
+            dry_run = req.dry_run,
+            "Migrating subscribers"
+        );
+
+        let db_error = |e: service_core::error::AppError| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
+
+        let plan = self
+            .db
+            .get_plan(tenant_id, plan_id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Plan not found")
+            })?;
+
+        let versions = self
+            .db
+            .list_plan_versions(plan_id)
+            .await
+            .map_err(db_error)?;
+        let find_version = |version: i32| versions.iter().find(|v| v.version == version);
+        let (from, to) = match (find_version(req.from_version), find_version(req.to_version)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return Err(invalid("Plan version not found")),
+        };
+
+        let subscribers = self
+            .db
+            .list_version_subscribers(tenant_id, plan_id, req.from_version)
+            .await
+            .map_err(db_error)?;
+
+        // Each subscription moves at its first renewal that is on or after the
+        // effective date and leaves it the full notice period
+        let earliest = effective_date.max(notice_end);
+        let interval = BillingInterval::from_string(&plan.billing_interval);
+        let scheduled: Vec<crate::models::PlanMigrationSubscription> = subscribers
+            .iter()
+            .map(|subscription| {
+                let mut renewal = subscription.current_period_end;
+                while renewal < earliest {
+                    renewal = calculate_period_end(renewal, interval, plan.interval_count);
+                }
+                crate::models::PlanMigrationSubscription {
+                    subscription_id: subscription.subscription_id,
+                    customer_id: subscription.customer_id,
+                    current_price: from.base_price,
+                    new_price: to.base_price,
+                    effective_date: renewal,
+                    status: PlanMigrationStatus::Scheduled.as_str().to_string(),
+                    applied_utc: None,
+                }
+            })
+            .collect();
+
+        let migration = if req.dry_run {
+            None
+        } else {
+            let migration = self
+                .db
+                .create_plan_migration(
+                    &CreatePlanMigration {
+                        tenant_id,
+                        plan_id,
+                        from_version: req.from_version,
+                        to_version: req.to_version,
+                        effective_date,
+                        notice_days: req.notice_days,
+                    },
+                    &scheduled,
+                )
+                .await
+                .map_err(db_error)?;
+            self.notify_plan_migration(&plan, &subscribers, &scheduled);
+            record_plan_operation(&tenant_id.to_string(), "migration_scheduled");
+            Some(plan_migration_to_proto(migration))
+        };
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(MigrateSubscribersResponse {
+            migration,
+            subscriptions: scheduled
+                .into_iter()
+                .map(plan_migration_subscription_to_proto)
+                .collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetPlanMigration"))]
+    async fn get_plan_migration(
+        &self,
+        request: Request<GetPlanMigrationRequest>,
+    ) -> Result<Response<GetPlanMigrationResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetPlanMigration";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_PLAN_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let migration_id = parse_uuid(&req.migration_id)?;
+
+        let db_error = |e: service_core::error::AppError| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
+
+        let migration = self
+            .db
+            .get_plan_migration(tenant_id, migration_id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Plan migration not found")
+            })?;
+
+        let subscriptions = self
+            .db
+            .list_plan_migration_subscriptions(migration_id)
+            .await
+            .map_err(db_error)?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(GetPlanMigrationResponse {
+            migration: Some(plan_migration_to_proto(migration)),
+            subscriptions: subscriptions
+                .into_iter()
+                .map(plan_migration_subscription_to_proto)
+                .collect(),
+        }))
+    }
+
+    // =========================================================================
+    // Subscription Management
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "CreateSubscription"))]
+    async fn create_subscription(
+        &self,
+        request: Request<CreateSubscriptionRequest>,
+    ) -> Result<Response<CreateSubscriptionResponse>, Status> {
+        let start = Instant::now();
+        let method = "CreateSubscription";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_SUBSCRIPTION_CREATE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        tracing::info!(
+            tenant_id = %tenant_id,
+            customer_id = %req.customer_id,
+            plan_id = %req.plan_id,
+            "Creating subscription"
+        );
+
+        let start_date = if req.start_date.is_empty() {
+            Utc::now().date_naive()
+        } else {
+            parse_date(&req.start_date)?
+        };
+
+        let input = CreateSubscription {
+            tenant_id,
+            customer_id: parse_uuid(&req.customer_id)?,
+            plan_id: parse_uuid(&req.plan_id)?,
+            billing_anchor_day: if req.billing_anchor_day > 0 && req.billing_anchor_day <= 31 {
+                req.billing_anchor_day
+            } else {
+                start_date.day() as i32
+            },
+            start_date,
+            trial_end_date: if req.trial_end_date.is_empty() {
+                None
+            } else {
+                Some(parse_date(&req.trial_end_date)?)
+            },
+            proration_mode: ProrationMode::from_proto(req.proration_mode),
+            metadata: if req.metadata.is_empty() {
+                None
+            } else {
+                serde_json::from_str(&req.metadata).ok()
+            },
+        };
+
+        let subscription = self.db.create_subscription(&input).await.map_err(|e| {
+            tracing::error!(error = %e, "Failed to create subscription");
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            match e {
+                service_core::error::AppError::NotFound(_) => Status::not_found("Plan not found"),
+                _ => Status::internal(e.to_string()),
+            }
+        })?;
 
         // Create initial billing cycle
         let initial_cycle = self
@@ -1212,7 +2262,7 @@ impl BillingService for BillingServiceImpl {
         // Validate currency matches
         let old_plan = self
             .db
-            .get_plan(tenant_id, existing.plan_id)
+            .get_subscription_plan(&existing)
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -1258,54 +2308,9 @@ impl BillingService for BillingServiceImpl {
 
             if let Some(cycle) = current_cycle {
                 let today = Utc::now().date_naive();
-                let total_days = (cycle.period_end - cycle.period_start).num_days() as f64;
-                let days_remaining = (cycle.period_end - today).num_days().max(0) as f64;
-                let proration_factor =
-                    Decimal::from_f64_retain(days_remaining / total_days).unwrap_or(Decimal::ZERO);
-
-                // Credit for unused old plan
-                let old_credit = -(old_plan.base_price * proration_factor);
-                // Charge for new plan remaining days
-                let new_charge = new_plan.base_price * proration_factor;
-
-                if old_credit != Decimal::ZERO {
-                    let credit_input = CreateCharge {
-                        cycle_id: cycle.cycle_id,
-                        charge_type: ChargeType::Proration,
-                        description: format!("Credit for unused {} plan", old_plan.name),
-                        quantity: Decimal::ONE,
-                        unit_price: old_credit,
-                        amount: old_credit,
-                        is_prorated: true,
-                        proration_factor: Some(proration_factor),
-                        component_id: None,
-                        metadata: None,
-                    };
-                    self.db.create_charge(&credit_input).await.map_err(|e| {
-                        record_error("database", method);
-                        record_grpc_request(method, "error");
-                        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-                        Status::internal(e.to_string())
-                    })?;
-                    proration_charges.push(ProrationCharge {
-                        description: credit_input.description,
-                        amount: old_credit.to_string(),
-                    });
-                }
-
-                if new_charge != Decimal::ZERO {
-                    let charge_input = CreateCharge {
-                        cycle_id: cycle.cycle_id,
-                        charge_type: ChargeType::Proration,
-                        description: format!("Charge for {} plan (prorated)", new_plan.name),
-                        quantity: Decimal::ONE,
-                        unit_price: new_charge,
-                        amount: new_charge,
-                        is_prorated: true,
-                        proration_factor: Some(proration_factor),
-                        component_id: None,
-                        metadata: None,
-                    };
+                for charge_input in
+                    crate::services::proration_charges(&cycle, &old_plan, &new_plan, today)
+                {
                     self.db.create_charge(&charge_input).await.map_err(|e| {
                         record_error("database", method);
                         record_grpc_request(method, "error");
@@ -1314,7 +2319,7 @@ impl BillingService for BillingServiceImpl {
                     })?;
                     proration_charges.push(ProrationCharge {
                         description: charge_input.description,
-                        amount: new_charge.to_string(),
+                        amount: charge_input.amount.to_string(),
                     });
                 }
             }
@@ -1347,87 +2352,375 @@ impl BillingService for BillingServiceImpl {
         }))
     }
 
-    // =========================================================================
-    // Usage Tracking
-    // =========================================================================
-
-    #[tracing::instrument(skip(self, request), fields(method = "RecordUsage"))]
-    async fn record_usage(
+    #[tracing::instrument(skip(self, request), fields(method = "PreviewUpcomingCharges"))]
+    async fn preview_upcoming_charges(
         &self,
-        request: Request<RecordUsageRequest>,
-    ) -> Result<Response<RecordUsageResponse>, Status> {
+        request: Request<PreviewUpcomingChargesRequest>,
+    ) -> Result<Response<PreviewUpcomingChargesResponse>, Status> {
         let start = Instant::now();
-        let method = "RecordUsage";
+        let method = "PreviewUpcomingCharges";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_USAGE_WRITE)
+            .require_capability(&request, capabilities::BILLING_SUBSCRIPTION_READ)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
         let req = request.into_inner();
         let subscription_id = parse_uuid(&req.subscription_id)?;
-        let component_id = parse_uuid(&req.component_id)?;
+        let new_plan_id = if req.new_plan_id.is_empty() {
+            None
+        } else {
+            Some(parse_uuid(&req.new_plan_id)?)
+        };
+        let as_of = if req.as_of.is_empty() {
+            Utc::now().date_naive()
+        } else {
+            parse_date(&req.as_of)?
+        };
 
-        tracing::debug!(
-            tenant_id = %tenant_id,
-            subscription_id = %subscription_id,
-            component_id = %component_id,
-            quantity = %req.quantity,
-            "Recording usage"
-        );
+        let db_error = |e: service_core::error::AppError| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
 
-        // Validate subscription exists and belongs to tenant
         let subscription = self
             .db
             .get_subscription(tenant_id, subscription_id)
             .await
-            .map_err(|e| {
-                record_error("database", method);
-                record_grpc_request(method, "error");
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
                 record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-                Status::internal(e.to_string())
+                Status::not_found("Subscription not found")
             })?;
 
-        subscription.ok_or_else(|| {
-            record_grpc_request(method, "not_found");
+        if subscription.status != SubscriptionStatus::Active.as_str() {
+            record_grpc_request(method, "failed_precondition");
             record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::not_found("Subscription not found")
-        })?;
+            return Err(Status::failed_precondition("Subscription must be active"));
+        }
 
-        let input = RecordUsage {
-            subscription_id,
-            component_id,
-            quantity: parse_decimal(&req.quantity)?,
-            timestamp: timestamp_to_datetime(req.timestamp),
-            idempotency_key: req.idempotency_key,
-            metadata: if req.metadata.is_empty() {
-                None
-            } else {
-                serde_json::from_str(&req.metadata).ok()
-            },
-        };
+        let new_plan = match new_plan_id {
+            Some(new_plan_id) => {
+                let new_plan = self
+                    .db
+                    .get_plan(tenant_id, new_plan_id)
+                    .await
+                    .map_err(db_error)?
+                    .ok_or_else(|| {
+                        record_grpc_request(method, "not_found");
+                        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                        Status::not_found("New plan not found")
+                    })?;
 
-        let record = self.db.record_usage(&input).await.map_err(|e| {
-            tracing::error!(error = %e, "Failed to record usage");
-            record_error("database", method);
-            record_grpc_request(method, "error");
-            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::internal(e.to_string())
-        })?;
+                if new_plan.is_archived {
+                    record_grpc_request(method, "failed_precondition");
+                    record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                    return Err(Status::failed_precondition(
+                        "Cannot change to archived plan",
+                    ));
+                }
 
-        record_usage_operation(&tenant_id.to_string(), &component_id.to_string());
-        record_grpc_request(method, "ok");
-        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                let old_plan = self
+                    .db
+                    .get_plan(tenant_id, subscription.plan_id)
+                    .await
+                    .map_err(db_error)?
+                    .ok_or_else(|| {
+                        record_grpc_request(method, "error");
+                        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                        Status::internal("Current plan not found")
+                    })?;
 
-        Ok(Response::new(RecordUsageResponse {
-            usage_record: Some(usage_record_to_proto(record)),
-        }))
-    }
+                if old_plan.currency != new_plan.currency {
+                    record_grpc_request(method, "invalid_argument");
+                    record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                    return Err(Status::invalid_argument(
+                        "Cannot change to plan with different currency",
+                    ));
+                }
 
-    #[tracing::instrument(skip(self, request), fields(method = "GetUsage"))]
-    async fn get_usage(
-        &self,
+                Some(new_plan)
+            }
+            None => None,
+        };
+
+        let mode = if req.proration_mode == 0 {
+            ProrationMode::from_string(&subscription.proration_mode)
+        } else {
+            ProrationMode::from_proto(req.proration_mode)
+        };
+
+        let upcoming = self
+            .billing
+            .preview_upcoming_charges(&subscription, new_plan.as_ref(), mode, as_of)
+            .await
+            .map_err(|e| {
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                match e {
+                    BillingError::NoPendingCycle => {
+                        record_grpc_request(method, "failed_precondition");
+                        Status::failed_precondition(e.to_string())
+                    }
+                    BillingError::Database(_) => {
+                        record_error("database", method);
+                        record_grpc_request(method, "error");
+                        Status::internal(e.to_string())
+                    }
+                    _ => {
+                        record_grpc_request(method, "error");
+                        Status::internal(e.to_string())
+                    }
+                }
+            })?;
+
+        let discount_total: Decimal = upcoming
+            .charges
+            .iter()
+            .filter(|c| c.charge.charge_type == ChargeType::Discount)
+            .map(|c| c.charge.amount)
+            .sum();
+        let total: Decimal = upcoming.charges.iter().map(|c| c.charge.amount).sum();
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(PreviewUpcomingChargesResponse {
+            cycle: Some(cycle_to_proto(upcoming.cycle, vec![])),
+            plan_id: upcoming.plan.plan_id.to_string(),
+            currency: upcoming.plan.currency,
+            charges: upcoming
+                .charges
+                .into_iter()
+                .map(preview_charge_to_proto)
+                .collect(),
+            subtotal: (total - discount_total).to_string(),
+            discount_total: discount_total.to_string(),
+            total: total.to_string(),
+        }))
+    }
+
+    // =========================================================================
+    // Usage Tracking
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "RecordUsage"))]
+    async fn record_usage(
+        &self,
+        request: Request<RecordUsageRequest>,
+    ) -> Result<Response<RecordUsageResponse>, Status> {
+        let start = Instant::now();
+        let method = "RecordUsage";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_USAGE_WRITE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+        let component_id = parse_uuid(&req.component_id)?;
+
+        tracing::debug!(
+            tenant_id = %tenant_id,
+            subscription_id = %subscription_id,
+            component_id = %component_id,
+            quantity = %req.quantity,
+            "Recording usage"
+        );
+
+        // Validate subscription exists and belongs to tenant
+        let subscription = self
+            .db
+            .get_subscription(tenant_id, subscription_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let subscription = subscription.ok_or_else(|| {
+            record_grpc_request(method, "not_found");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::not_found("Subscription not found")
+        })?;
+
+        let input = RecordUsage {
+            subscription_id,
+            component_id,
+            quantity: parse_decimal(&req.quantity)?,
+            timestamp: timestamp_to_datetime(req.timestamp),
+            idempotency_key: req.idempotency_key,
+            metadata: if req.metadata.is_empty() {
+                None
+            } else {
+                serde_json::from_str(&req.metadata).ok()
+            },
+        };
+
+        let (record, alerts) = self.db.record_usage(&input).await.map_err(|e| match e {
+            service_core::error::AppError::TooManyRequests(msg, _) => {
+                record_grpc_request(method, "resource_exhausted");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::resource_exhausted(msg)
+            }
+            service_core::error::AppError::BadRequest(e) => {
+                record_grpc_request(method, "invalid_argument");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::invalid_argument(e.to_string())
+            }
+            e => {
+                tracing::error!(error = %e, "Failed to record usage");
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            }
+        })?;
+
+        self.notify_usage_alerts(&subscription, &alerts);
+
+        record_usage_operation(&tenant_id.to_string(), &component_id.to_string());
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(RecordUsageResponse {
+            usage_record: Some(usage_record_to_proto(record)),
+            alerts: alerts.into_iter().map(usage_alert_to_proto).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "RecordUsageBatch"))]
+    async fn record_usage_batch(
+        &self,
+        request: Request<RecordUsageBatchRequest>,
+    ) -> Result<Response<RecordUsageBatchResponse>, Status> {
+        let start = Instant::now();
+        let method = "RecordUsageBatch";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_USAGE_WRITE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let mut records = request.into_inner().records;
+        if records.len() > MAX_USAGE_BATCH {
+            record_grpc_request(method, "invalid_argument");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            return Err(Status::invalid_argument(format!(
+                "A batch holds at most {} records",
+                MAX_USAGE_BATCH
+            )));
+        }
+
+        tracing::debug!(tenant_id = %tenant_id, records = records.len(), "Recording usage batch");
+
+        let mut subscriptions = UsageSubscriptions::new();
+        let mut results = Vec::with_capacity(records.len());
+        while !records.is_empty() {
+            let rest = records.split_off(records.len().min(USAGE_CHUNK_SIZE));
+            let chunk = std::mem::replace(&mut records, rest);
+            let chunk_results = self
+                .ingest_usage(tenant_id, chunk, results.len(), &mut subscriptions)
+                .await
+                .map_err(|e| {
+                    tracing::error!(error = %e, "Failed to record usage batch");
+                    record_error("database", method);
+                    record_grpc_request(method, "error");
+                    record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                    Status::internal(e.to_string())
+                })?;
+            results.extend(chunk_results);
+        }
+
+        let (recorded_count, duplicate_count, rejected_count) = usage_result_counts(&results);
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(RecordUsageBatchResponse {
+            results,
+            recorded_count,
+            duplicate_count,
+            rejected_count,
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "StreamUsage"))]
+    async fn stream_usage(
+        &self,
+        request: Request<Streaming<StreamUsageRequest>>,
+    ) -> Result<Response<StreamUsageResponse>, Status> {
+        let start = Instant::now();
+        let method = "StreamUsage";
+
+        // The stream can't be held across the capability check's awaits
+        let (metadata, extensions, mut stream) = request.into_parts();
+        let request = Request::from_parts(metadata, extensions, ());
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_USAGE_WRITE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        tracing::debug!(tenant_id = %tenant_id, "Streaming usage");
+
+        let db_error = |e: service_core::error::AppError| {
+            tracing::error!(error = %e, "Failed to record streamed usage");
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
+
+        // Records are written in chunks as they arrive; chunks already
+        // written stay recorded if the stream fails later.
+        let mut subscriptions = UsageSubscriptions::new();
+        let mut pending = Vec::new();
+        let mut results = Vec::new();
+        while let Some(message) = stream.message().await? {
+            pending.extend(message.records);
+            while pending.len() >= USAGE_CHUNK_SIZE {
+                let rest = pending.split_off(USAGE_CHUNK_SIZE);
+                let chunk = std::mem::replace(&mut pending, rest);
+                let chunk_results = self
+                    .ingest_usage(tenant_id, chunk, results.len(), &mut subscriptions)
+                    .await
+                    .map_err(db_error)?;
+                results.extend(chunk_results);
+            }
+        }
+        if !pending.is_empty() {
+            let chunk_results = self
+                .ingest_usage(tenant_id, pending, results.len(), &mut subscriptions)
+                .await
+                .map_err(db_error)?;
+            results.extend(chunk_results);
+        }
+
+        let (recorded_count, duplicate_count, rejected_count) = usage_result_counts(&results);
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(StreamUsageResponse {
+            results,
+            recorded_count,
+            duplicate_count,
+            rejected_count,
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetUsage"))]
+    async fn get_usage(
+        &self,
         request: Request<GetUsageRequest>,
     ) -> Result<Response<GetUsageResponse>, Status> {
         let start = Instant::now();
@@ -1582,6 +2875,17 @@ impl BillingService for BillingServiceImpl {
                 included_units: s.included_units,
                 billable_units: s.billable_units.to_string(),
                 amount: s.amount.to_string(),
+                pricing_model: PricingModel::from_string(&s.pricing_model).to_proto(),
+                breakdown: s
+                    .breakdown
+                    .into_iter()
+                    .map(|l| PriceBreakdownLine {
+                        description: l.description,
+                        quantity: l.quantity.to_string(),
+                        unit_price: l.unit_price.to_string(),
+                        amount: l.amount.to_string(),
+                    })
+                    .collect(),
             })
             .collect();
 
@@ -1593,49 +2897,109 @@ impl BillingService for BillingServiceImpl {
         }))
     }
 
-    // =========================================================================
-    // Billing Cycles
-    // =========================================================================
-
-    #[tracing::instrument(skip(self, request), fields(method = "GetBillingCycle"))]
-    async fn get_billing_cycle(
+    #[tracing::instrument(skip(self, request), fields(method = "CreateUsageThreshold"))]
+    async fn create_usage_threshold(
         &self,
-        request: Request<GetBillingCycleRequest>,
-    ) -> Result<Response<GetBillingCycleResponse>, Status> {
+        request: Request<CreateUsageThresholdRequest>,
+    ) -> Result<Response<CreateUsageThresholdResponse>, Status> {
         let start = Instant::now();
-        let method = "GetBillingCycle";
+        let method = "CreateUsageThreshold";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_CYCLE_READ)
+            .require_capability(&request, capabilities::BILLING_USAGE_MANAGE)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
-        let req = request.into_inner();
-        let cycle_id = parse_uuid(&req.cycle_id)?;
+        let input =
+            usage_threshold_from_proto(tenant_id, request.into_inner()).inspect_err(|_| {
+                record_grpc_request(method, "invalid_argument");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            })?;
 
-        tracing::debug!(tenant_id = %tenant_id, cycle_id = %cycle_id, "Getting billing cycle");
+        tracing::info!(
+            tenant_id = %tenant_id,
+            subscription_id = %input.subscription_id,
+            component_id = %input.component_id,
+            threshold_type = input.threshold_type.as_str(),
+            "Creating usage threshold"
+        );
 
-        let cycle = self
+        let db_error = |e: service_core::error::AppError| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
+
+        let subscription = self
             .db
-            .get_billing_cycle(tenant_id, cycle_id)
+            .get_subscription(tenant_id, input.subscription_id)
             .await
-            .map_err(|e| {
-                record_error("database", method);
-                record_grpc_request(method, "error");
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
                 record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-                Status::internal(e.to_string())
+                Status::not_found("Subscription not found")
             })?;
 
-        let cycle = cycle.ok_or_else(|| {
-            record_grpc_request(method, "not_found");
+        let components = self
+            .db
+            .get_usage_components(subscription.plan_id)
+            .await
+            .map_err(db_error)?;
+        let component = components
+            .iter()
+            .find(|c| c.component_id == input.component_id)
+            .ok_or_else(|| {
+                record_grpc_request(method, "invalid_argument");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::invalid_argument("Usage component is not part of the subscription's plan")
+            })?;
+        if input.percent_of_included.is_some() && component.included_units <= 0 {
+            record_grpc_request(method, "invalid_argument");
             record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::not_found("Billing cycle not found")
-        })?;
+            return Err(Status::invalid_argument(
+                "percent_of_included requires a component with included units",
+            ));
+        }
 
-        let charges = self
+        let threshold = self
             .db
-            .list_charges(tenant_id, cycle_id, &ListChargesFilter::default())
+            .create_usage_threshold(&input)
+            .await
+            .map_err(db_error)?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(CreateUsageThresholdResponse {
+            threshold: Some(usage_threshold_to_proto(threshold)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListUsageThresholds"))]
+    async fn list_usage_thresholds(
+        &self,
+        request: Request<ListUsageThresholdsRequest>,
+    ) -> Result<Response<ListUsageThresholdsResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListUsageThresholds";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_USAGE_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, subscription_id = %subscription_id, "Listing usage thresholds");
+
+        let thresholds = self
+            .db
+            .list_usage_thresholds(tenant_id, subscription_id)
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -1647,25 +3011,190 @@ impl BillingService for BillingServiceImpl {
         record_grpc_request(method, "ok");
         record_grpc_request_duration(method, start.elapsed().as_secs_f64());
 
-        Ok(Response::new(GetBillingCycleResponse {
-            billing_cycle: Some(cycle_to_proto(
-                cycle,
-                charges.into_iter().map(charge_to_proto).collect(),
-            )),
+        Ok(Response::new(ListUsageThresholdsResponse {
+            thresholds: thresholds
+                .into_iter()
+                .map(usage_threshold_to_proto)
+                .collect(),
         }))
     }
 
-    #[tracing::instrument(skip(self, request), fields(method = "ListBillingCycles"))]
-    async fn list_billing_cycles(
+    #[tracing::instrument(skip(self, request), fields(method = "DeleteUsageThreshold"))]
+    async fn delete_usage_threshold(
         &self,
-        request: Request<ListBillingCyclesRequest>,
-    ) -> Result<Response<ListBillingCyclesResponse>, Status> {
+        request: Request<DeleteUsageThresholdRequest>,
+    ) -> Result<Response<DeleteUsageThresholdResponse>, Status> {
         let start = Instant::now();
-        let method = "ListBillingCycles";
+        let method = "DeleteUsageThreshold";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_CYCLE_READ)
+            .require_capability(&request, capabilities::BILLING_USAGE_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let threshold_id = parse_uuid(&req.threshold_id)?;
+
+        tracing::info!(tenant_id = %tenant_id, threshold_id = %threshold_id, "Deleting usage threshold");
+
+        let threshold = self
+            .db
+            .delete_usage_threshold(tenant_id, threshold_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let threshold = threshold.ok_or_else(|| {
+            record_grpc_request(method, "not_found");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::not_found("Usage threshold not found")
+        })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(DeleteUsageThresholdResponse {
+            threshold: Some(usage_threshold_to_proto(threshold)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListUsageAlerts"))]
+    async fn list_usage_alerts(
+        &self,
+        request: Request<ListUsageAlertsRequest>,
+    ) -> Result<Response<ListUsageAlertsResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListUsageAlerts";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_USAGE_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, subscription_id = %subscription_id, "Listing usage alerts");
+
+        let filter = ListUsageAlertsFilter {
+            cycle_id: if req.cycle_id.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.cycle_id)?)
+            },
+            page_size: if req.page_size > 0 { req.page_size } else { 50 },
+            page_token: if req.page_token.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.page_token)?)
+            },
+        };
+
+        let alerts = self
+            .db
+            .list_usage_alerts(tenant_id, subscription_id, &filter)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let proto_alerts: Vec<_> = alerts.into_iter().map(usage_alert_to_proto).collect();
+        let next_page_token = proto_alerts
+            .last()
+            .map(|a| a.alert_id.clone())
+            .unwrap_or_default();
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ListUsageAlertsResponse {
+            alerts: proto_alerts,
+            next_page_token,
+        }))
+    }
+
+    // =========================================================================
+    // Billing Cycles
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetBillingCycle"))]
+    async fn get_billing_cycle(
+        &self,
+        request: Request<GetBillingCycleRequest>,
+    ) -> Result<Response<GetBillingCycleResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetBillingCycle";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_CYCLE_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let cycle_id = parse_uuid(&req.cycle_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, cycle_id = %cycle_id, "Getting billing cycle");
+
+        let cycle = self
+            .db
+            .get_billing_cycle(tenant_id, cycle_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let cycle = cycle.ok_or_else(|| {
+            record_grpc_request(method, "not_found");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::not_found("Billing cycle not found")
+        })?;
+
+        let charges = self
+            .db
+            .list_charges(tenant_id, cycle_id, &ListChargesFilter::default())
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(GetBillingCycleResponse {
+            billing_cycle: Some(cycle_to_proto(
+                cycle,
+                charges.into_iter().map(charge_to_proto).collect(),
+            )),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListBillingCycles"))]
+    async fn list_billing_cycles(
+        &self,
+        request: Request<ListBillingCyclesRequest>,
+    ) -> Result<Response<ListBillingCyclesResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListBillingCycles";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_CYCLE_READ)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
@@ -1984,44 +3513,84 @@ impl BillingService for BillingServiceImpl {
     }
 
     // =========================================================================
-    // Billing Runs
+    // Coupons and Discounts
     // =========================================================================
 
-    #[tracing::instrument(skip(self, request), fields(method = "RunBilling"))]
-    async fn run_billing(
+    #[tracing::instrument(skip(self, request), fields(method = "CreateCoupon"))]
+    async fn create_coupon(
         &self,
-        request: Request<RunBillingRequest>,
-    ) -> Result<Response<RunBillingResponse>, Status> {
+        request: Request<CreateCouponRequest>,
+    ) -> Result<Response<CreateCouponResponse>, Status> {
         let start = Instant::now();
-        let method = "RunBilling";
+        let method = "CreateCoupon";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_RUN_EXECUTE)
+            .require_capability(&request, capabilities::BILLING_COUPON_CREATE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let input = coupon_from_proto(tenant_id, request.into_inner()).inspect_err(|_| {
+            record_grpc_request(method, "invalid_argument");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+        })?;
+
+        tracing::info!(tenant_id = %tenant_id, name = %input.name, "Creating coupon");
+
+        let coupon = self.db.create_coupon(&input).await.map_err(|e| {
+            tracing::error!(error = %e, "Failed to create coupon");
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(CreateCouponResponse {
+            coupon: Some(coupon_to_proto(coupon)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetCoupon"))]
+    async fn get_coupon(
+        &self,
+        request: Request<GetCouponRequest>,
+    ) -> Result<Response<GetCouponResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetCoupon";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_COUPON_READ)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
         let req = request.into_inner();
-        let run_type = BillingRunType::from_proto(req.run_type);
+        let coupon_id = parse_uuid(&req.coupon_id)?;
 
-        tracing::info!(tenant_id = %tenant_id, run_type = ?run_type, "Starting billing run");
+        tracing::debug!(tenant_id = %tenant_id, coupon_id = %coupon_id, "Getting coupon");
 
-        // Create billing run record
-        let billing_run = self
+        let coupon = self
             .db
-            .create_billing_run(tenant_id, run_type)
+            .get_coupon(tenant_id, coupon_id)
             .await
             .map_err(|e| {
                 record_error("database", method);
                 record_grpc_request(method, "error");
                 record_grpc_request_duration(method, start.elapsed().as_secs_f64());
                 Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Coupon not found")
             })?;
 
-        // Find subscriptions due for billing
-        let subscriptions = self
+        let promotion_codes = self
             .db
-            .find_subscriptions_due_for_billing(tenant_id)
+            .list_promotion_codes(tenant_id, coupon_id)
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2030,177 +3599,49 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
-        let mut processed = 0;
-        let mut succeeded = 0;
-        let mut failed = 0;
-        let mut results = Vec::new();
-
-        for subscription in subscriptions {
-            processed += 1;
-
-            // Get current billing cycle
-            let cycle = match self
-                .db
-                .get_current_billing_cycle(subscription.subscription_id)
-                .await
-            {
-                Ok(Some(c)) => c,
-                Ok(None) => {
-                    failed += 1;
-                    let result = self
-                        .db
-                        .create_billing_run_result(
-                            billing_run.run_id,
-                            subscription.subscription_id,
-                            "failed",
-                            None,
-                            Some("No pending billing cycle".to_string()),
-                        )
-                        .await
-                        .map_err(|e| Status::internal(e.to_string()))?;
-                    results.push(billing_run_result_to_proto(result));
-                    continue;
-                }
-                Err(e) => {
-                    failed += 1;
-                    let result = self
-                        .db
-                        .create_billing_run_result(
-                            billing_run.run_id,
-                            subscription.subscription_id,
-                            "failed",
-                            None,
-                            Some(e.to_string()),
-                        )
-                        .await
-                        .map_err(|e| Status::internal(e.to_string()))?;
-                    results.push(billing_run_result_to_proto(result));
-                    continue;
-                }
-            };
-
-            // Get plan for recurring charge
-            let plan = match self.db.get_plan(tenant_id, subscription.plan_id).await {
-                Ok(Some(p)) => p,
-                Ok(None) | Err(_) => {
-                    failed += 1;
-                    let result = self
-                        .db
-                        .create_billing_run_result(
-                            billing_run.run_id,
-                            subscription.subscription_id,
-                            "failed",
-                            None,
-                            Some("Plan not found".to_string()),
-                        )
-                        .await
-                        .map_err(|e| Status::internal(e.to_string()))?;
-                    results.push(billing_run_result_to_proto(result));
-                    continue;
-                }
-            };
-
-            // Create recurring charge
-            let recurring_input = CreateCharge {
-                cycle_id: cycle.cycle_id,
-                charge_type: ChargeType::Recurring,
-                description: format!("Monthly subscription - {}", plan.name),
-                quantity: Decimal::ONE,
-                unit_price: plan.base_price,
-                amount: plan.base_price,
-                is_prorated: false,
-                proration_factor: None,
-                component_id: None,
-                metadata: None,
-            };
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
 
-            if let Err(e) = self.db.create_charge(&recurring_input).await {
-                tracing::error!(error = %e, "Failed to create recurring charge");
-            }
+        Ok(Response::new(GetCouponResponse {
+            coupon: Some(coupon_to_proto(coupon)),
+            promotion_codes: promotion_codes
+                .into_iter()
+                .map(promotion_code_to_proto)
+                .collect(),
+        }))
+    }
 
-            // Create usage charges
-            let usage_summaries = self
-                .db
-                .get_usage_summary(
-                    tenant_id,
-                    subscription.subscription_id,
-                    Some(cycle.cycle_id),
-                )
-                .await
-                .unwrap_or_default();
-
-            for summary in usage_summaries {
-                if summary.billable_units > Decimal::ZERO {
-                    let usage_input = CreateCharge {
-                        cycle_id: cycle.cycle_id,
-                        charge_type: ChargeType::Usage,
-                        description: format!(
-                            "{} - {} billable units",
-                            summary.name, summary.billable_units
-                        ),
-                        quantity: summary.billable_units,
-                        unit_price: summary.amount / summary.billable_units,
-                        amount: summary.amount,
-                        is_prorated: false,
-                        proration_factor: None,
-                        component_id: Some(summary.component_id),
-                        metadata: None,
-                    };
-
-                    if let Err(e) = self.db.create_charge(&usage_input).await {
-                        tracing::error!(error = %e, "Failed to create usage charge");
-                    }
-                }
-            }
+    #[tracing::instrument(skip(self, request), fields(method = "ListCoupons"))]
+    async fn list_coupons(
+        &self,
+        request: Request<ListCouponsRequest>,
+    ) -> Result<Response<ListCouponsResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListCoupons";
 
-            // Mark cycle as invoiced (invoice creation would be via invoicing-service)
-            if let Err(e) = self
-                .db
-                .update_billing_cycle_status(cycle.cycle_id, BillingCycleStatus::Invoiced, None)
-                .await
-            {
-                tracing::error!(error = %e, "Failed to update cycle status");
-            }
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_COUPON_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
 
-            // Mark usage as invoiced
-            if let Err(e) = self.db.mark_usage_invoiced(cycle.cycle_id).await {
-                tracing::error!(error = %e, "Failed to mark usage as invoiced");
-            }
+        let req = request.into_inner();
 
-            succeeded += 1;
-            let result = self
-                .db
-                .create_billing_run_result(
-                    billing_run.run_id,
-                    subscription.subscription_id,
-                    "success",
-                    None, // Invoice ID would come from invoicing-service
-                    None,
-                )
-                .await
-                .map_err(|e| Status::internal(e.to_string()))?;
-            results.push(billing_run_result_to_proto(result));
-        }
+        tracing::debug!(tenant_id = %tenant_id, "Listing coupons");
 
-        // Update billing run with final status
-        let status = if failed == 0 {
-            BillingRunStatus::Completed
-        } else if succeeded == 0 {
-            BillingRunStatus::Failed
-        } else {
-            BillingRunStatus::Completed
+        let filter = ListCouponsFilter {
+            include_inactive: req.include_inactive,
+            page_size: if req.page_size > 0 { req.page_size } else { 50 },
+            page_token: if req.page_token.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.page_token)?)
+            },
         };
 
-        let billing_run = self
+        let coupons = self
             .db
-            .update_billing_run(
-                billing_run.run_id,
-                status,
-                processed,
-                succeeded,
-                failed,
-                None,
-            )
+            .list_coupons(tenant_id, &filter)
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2209,85 +3650,832 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
-        let billing_run = billing_run.ok_or_else(|| {
-            record_grpc_request(method, "error");
-            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::internal("Failed to update billing run")
-        })?;
+        let proto_coupons: Vec<_> = coupons.into_iter().map(coupon_to_proto).collect();
+        let next_page_token = proto_coupons
+            .last()
+            .map(|c| c.coupon_id.clone())
+            .unwrap_or_default();
 
-        record_billing_run(&tenant_id.to_string(), run_type.as_str(), status.as_str());
         record_grpc_request(method, "ok");
         record_grpc_request_duration(method, start.elapsed().as_secs_f64());
 
-        Ok(Response::new(RunBillingResponse {
-            billing_run: Some(billing_run_to_proto(billing_run, results)),
+        Ok(Response::new(ListCouponsResponse {
+            coupons: proto_coupons,
+            next_page_token,
         }))
     }
 
-    #[tracing::instrument(skip(self, request), fields(method = "RunBillingForSubscription"))]
-    #[tracing::instrument(skip(self, request), fields(method = "RunBillingForSubscription"))]
-    async fn run_billing_for_subscription(
+    #[tracing::instrument(skip(self, request), fields(method = "ArchiveCoupon"))]
+    async fn archive_coupon(
         &self,
-        request: Request<RunBillingForSubscriptionRequest>,
-    ) -> Result<Response<RunBillingForSubscriptionResponse>, Status> {
+        request: Request<ArchiveCouponRequest>,
+    ) -> Result<Response<ArchiveCouponResponse>, Status> {
         let start = Instant::now();
-        let method = "RunBillingForSubscription";
+        let method = "ArchiveCoupon";
 
         let auth = self
             .capability_checker
-            .require_capability(&request, capabilities::BILLING_RUN_EXECUTE)
+            .require_capability(&request, capabilities::BILLING_COUPON_UPDATE)
             .await?;
         let tenant_id = parse_tenant_id(&auth)?;
 
         let req = request.into_inner();
-        let subscription_id = parse_uuid(&req.subscription_id)?;
+        let coupon_id = parse_uuid(&req.coupon_id)?;
 
-        tracing::info!(
-            tenant_id = %tenant_id,
-            subscription_id = %subscription_id,
-            "Running billing for subscription"
+        tracing::info!(tenant_id = %tenant_id, coupon_id = %coupon_id, "Archiving coupon");
+
+        let coupon = self
+            .db
+            .archive_coupon(tenant_id, coupon_id)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = %e, "Failed to archive coupon");
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Coupon not found or already archived")
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ArchiveCouponResponse {
+            coupon: Some(coupon_to_proto(coupon)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "CreatePromotionCode"))]
+    async fn create_promotion_code(
+        &self,
+        request: Request<CreatePromotionCodeRequest>,
+    ) -> Result<Response<CreatePromotionCodeResponse>, Status> {
+        let start = Instant::now();
+        let method = "CreatePromotionCode";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_COUPON_CREATE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let coupon_id = parse_uuid(&req.coupon_id)?;
+        let code = req.code.trim().to_string();
+        if code.is_empty() {
+            return Err(Status::invalid_argument("code is required"));
+        }
+        if req.max_redemptions < 0 {
+            return Err(Status::invalid_argument(
+                "max_redemptions cannot be negative",
+            ));
+        }
+
+        tracing::info!(tenant_id = %tenant_id, coupon_id = %coupon_id, "Creating promotion code");
+
+        let coupon = self
+            .db
+            .get_coupon(tenant_id, coupon_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Coupon not found")
+            })?;
+        if !coupon.is_active {
+            return Err(Status::failed_precondition("Coupon is archived"));
+        }
+
+        let input = CreatePromotionCode {
+            tenant_id,
+            coupon_id,
+            code,
+            max_redemptions: (req.max_redemptions > 0).then_some(req.max_redemptions),
+            expires_utc: req.expires_at.map(|ts| timestamp_to_datetime(Some(ts))),
+        };
+
+        let promotion_code = self.db.create_promotion_code(&input).await.map_err(|e| {
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            match e {
+                service_core::error::AppError::Conflict(_) => Status::already_exists(e.to_string()),
+                _ => {
+                    tracing::error!(error = %e, "Failed to create promotion code");
+                    record_error("database", method);
+                    Status::internal(e.to_string())
+                }
+            }
+        })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(CreatePromotionCodeResponse {
+            promotion_code: Some(promotion_code_to_proto(promotion_code)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListPromotionCodes"))]
+    async fn list_promotion_codes(
+        &self,
+        request: Request<ListPromotionCodesRequest>,
+    ) -> Result<Response<ListPromotionCodesResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListPromotionCodes";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_COUPON_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let coupon_id = parse_uuid(&req.coupon_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, coupon_id = %coupon_id, "Listing promotion codes");
+
+        let promotion_codes = self
+            .db
+            .list_promotion_codes(tenant_id, coupon_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ListPromotionCodesResponse {
+            promotion_codes: promotion_codes
+                .into_iter()
+                .map(promotion_code_to_proto)
+                .collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ApplyDiscount"))]
+    async fn apply_discount(
+        &self,
+        request: Request<ApplyDiscountRequest>,
+    ) -> Result<Response<ApplyDiscountResponse>, Status> {
+        let start = Instant::now();
+        let method = "ApplyDiscount";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DISCOUNT_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            subscription_id = %subscription_id,
+            "Applying discount"
+        );
+
+        let db_error = |e: service_core::error::AppError| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        };
+        let now = Utc::now();
+
+        let subscription = self
+            .db
+            .get_subscription(tenant_id, subscription_id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| Status::not_found("Subscription not found"))?;
+        let status = SubscriptionStatus::from_string(&subscription.status);
+        if matches!(
+            status,
+            SubscriptionStatus::Cancelled | SubscriptionStatus::Expired
+        ) {
+            return Err(Status::failed_precondition(format!(
+                "Cannot discount a {} subscription",
+                status.as_str()
+            )));
+        }
+
+        // A promotion code names its coupon
+        let promotion_code = if req.promotion_code.is_empty() {
+            None
+        } else {
+            let code = self
+                .db
+                .get_promotion_code_by_code(tenant_id, req.promotion_code.trim())
+                .await
+                .map_err(db_error)?
+                .ok_or_else(|| Status::not_found("Promotion code not found"))?;
+            if !code.is_redeemable(now) {
+                return Err(Status::failed_precondition(
+                    "Promotion code is inactive, expired or fully redeemed",
+                ));
+            }
+            Some(code)
+        };
+        let coupon_id = match (&promotion_code, req.coupon_id.is_empty()) {
+            (Some(code), true) => code.coupon_id,
+            (Some(code), false) if parse_uuid(&req.coupon_id)? == code.coupon_id => code.coupon_id,
+            (Some(_), false) => {
+                return Err(Status::invalid_argument(
+                    "Promotion code belongs to a different coupon",
+                ))
+            }
+            (None, false) => parse_uuid(&req.coupon_id)?,
+            (None, true) => {
+                return Err(Status::invalid_argument(
+                    "coupon_id or promotion_code is required",
+                ))
+            }
+        };
+
+        let coupon = self
+            .db
+            .get_coupon(tenant_id, coupon_id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| Status::not_found("Coupon not found"))?;
+        if !coupon.is_redeemable(now) {
+            return Err(Status::failed_precondition(
+                "Coupon is inactive, expired or fully redeemed",
+            ));
+        }
+
+        if let Some(currency) = &coupon.currency {
+            let plan = self
+                .db
+                .get_plan(tenant_id, subscription.plan_id)
+                .await
+                .map_err(db_error)?
+                .ok_or_else(|| Status::not_found("Plan not found"))?;
+            if !plan.currency.eq_ignore_ascii_case(currency) {
+                return Err(Status::failed_precondition(format!(
+                    "Coupon currency {} does not match plan currency {}",
+                    currency, plan.currency
+                )));
+            }
+        }
+
+        // A charge discount applies when the charge's cycle is billed
+        let charge_id = if req.charge_id.is_empty() {
+            None
+        } else {
+            let charge_id = parse_uuid(&req.charge_id)?;
+            let charge = self
+                .db
+                .get_charge(tenant_id, charge_id)
+                .await
+                .map_err(db_error)?
+                .ok_or_else(|| Status::not_found("Charge not found"))?;
+            if ChargeType::from_string(&charge.charge_type) == ChargeType::Discount {
+                return Err(Status::invalid_argument(
+                    "Cannot discount a discount charge",
+                ));
+            }
+            let pending_cycle = self
+                .db
+                .get_current_billing_cycle(subscription_id)
+                .await
+                .map_err(db_error)?;
+            if pending_cycle.map(|c| c.cycle_id) != Some(charge.cycle_id) {
+                return Err(Status::failed_precondition(
+                    "Charge is not on the subscription's pending billing cycle",
+                ));
+            }
+            Some(charge_id)
+        };
+
+        let input = CreateDiscount {
+            tenant_id,
+            coupon_id,
+            promotion_code_id: promotion_code.map(|c| c.promotion_code_id),
+            subscription_id,
+            charge_id,
+        };
+
+        let discount = self.db.create_discount(&input).await.map_err(|e| {
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            match e {
+                service_core::error::AppError::BadRequest(_) => {
+                    Status::failed_precondition(e.to_string())
+                }
+                _ => {
+                    tracing::error!(error = %e, "Failed to apply discount");
+                    record_error("database", method);
+                    Status::internal(e.to_string())
+                }
+            }
+        })?;
+
+        record_subscription_operation(&tenant_id.to_string(), "discount_applied");
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ApplyDiscountResponse {
+            discount: Some(discount_to_proto(discount)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListDiscounts"))]
+    async fn list_discounts(
+        &self,
+        request: Request<ListDiscountsRequest>,
+    ) -> Result<Response<ListDiscountsResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListDiscounts";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_SUBSCRIPTION_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, subscription_id = %subscription_id, "Listing discounts");
+
+        let discounts = self
+            .db
+            .list_discounts(tenant_id, subscription_id, req.include_ended)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ListDiscountsResponse {
+            discounts: discounts.into_iter().map(discount_to_proto).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "RemoveDiscount"))]
+    async fn remove_discount(
+        &self,
+        request: Request<RemoveDiscountRequest>,
+    ) -> Result<Response<RemoveDiscountResponse>, Status> {
+        let start = Instant::now();
+        let method = "RemoveDiscount";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DISCOUNT_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let discount_id = parse_uuid(&req.discount_id)?;
+
+        tracing::info!(tenant_id = %tenant_id, discount_id = %discount_id, "Removing discount");
+
+        let discount = self
+            .db
+            .end_discount(tenant_id, discount_id)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = %e, "Failed to remove discount");
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Discount not found or already ended")
+            })?;
+
+        record_subscription_operation(&tenant_id.to_string(), "discount_removed");
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(RemoveDiscountResponse {
+            discount: Some(discount_to_proto(discount)),
+        }))
+    }
+
+    // =========================================================================
+    // Dunning
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetDunningPolicy"))]
+    async fn get_dunning_policy(
+        &self,
+        request: Request<GetDunningPolicyRequest>,
+    ) -> Result<Response<GetDunningPolicyResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetDunningPolicy";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        tracing::debug!(tenant_id = %tenant_id, "Getting dunning policy");
+
+        let policy = self.db.get_dunning_policy(tenant_id).await.map_err(|e| {
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        })?;
+
+        let policy = match policy {
+            Some(policy) => dunning_policy_to_proto(policy, false),
+            None => {
+                dunning_policy_to_proto(crate::models::DunningPolicy::default_for(tenant_id), true)
+            }
+        };
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(GetDunningPolicyResponse {
+            policy: Some(policy),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "UpdateDunningPolicy"))]
+    async fn update_dunning_policy(
+        &self,
+        request: Request<UpdateDunningPolicyRequest>,
+    ) -> Result<Response<UpdateDunningPolicyResponse>, Status> {
+        let start = Instant::now();
+        let method = "UpdateDunningPolicy";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let input =
+            dunning_policy_from_proto(tenant_id, request.into_inner()).inspect_err(|_| {
+                record_grpc_request(method, "invalid_argument");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            })?;
+
+        tracing::info!(tenant_id = %tenant_id, "Updating dunning policy");
+
+        let policy = self.db.upsert_dunning_policy(&input).await.map_err(|e| {
+            tracing::error!(error = %e, "Failed to update dunning policy");
+            record_error("database", method);
+            record_grpc_request(method, "error");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::internal(e.to_string())
+        })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(UpdateDunningPolicyResponse {
+            policy: Some(dunning_policy_to_proto(policy, false)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetDunningCase"))]
+    async fn get_dunning_case(
+        &self,
+        request: Request<GetDunningCaseRequest>,
+    ) -> Result<Response<GetDunningCaseResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetDunningCase";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let case_id = parse_uuid(&req.case_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, case_id = %case_id, "Getting dunning case");
+
+        let case = self
+            .db
+            .get_dunning_case(tenant_id, case_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Dunning case not found")
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(GetDunningCaseResponse {
+            dunning_case: Some(dunning_case_to_proto(case)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ListDunningCases"))]
+    async fn list_dunning_cases(
+        &self,
+        request: Request<ListDunningCasesRequest>,
+    ) -> Result<Response<ListDunningCasesResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListDunningCases";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+
+        tracing::debug!(tenant_id = %tenant_id, "Listing dunning cases");
+
+        let filter = ListDunningCasesFilter {
+            subscription_id: if req.subscription_id.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.subscription_id)?)
+            },
+            status: if req.status == 0 {
+                None
+            } else {
+                Some(DunningCaseStatus::from_proto(req.status))
+            },
+            page_size: if req.page_size > 0 { req.page_size } else { 50 },
+            page_token: if req.page_token.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.page_token)?)
+            },
+        };
+
+        let cases = self
+            .db
+            .list_dunning_cases(tenant_id, &filter)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let proto_cases: Vec<_> = cases.into_iter().map(dunning_case_to_proto).collect();
+        let next_page_token = proto_cases
+            .last()
+            .map(|c| c.case_id.clone())
+            .unwrap_or_default();
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ListDunningCasesResponse {
+            dunning_cases: proto_cases,
+            next_page_token,
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "RetryDunningCase"))]
+    async fn retry_dunning_case(
+        &self,
+        request: Request<RetryDunningCaseRequest>,
+    ) -> Result<Response<RetryDunningCaseResponse>, Status> {
+        let start = Instant::now();
+        let method = "RetryDunningCase";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let case_id = parse_uuid(&req.case_id)?;
+
+        tracing::info!(tenant_id = %tenant_id, case_id = %case_id, "Retrying dunning case");
+
+        let case = self
+            .db
+            .get_dunning_case(tenant_id, case_id)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Dunning case not found")
+            })?;
+
+        if case.status != DunningCaseStatus::Open.as_str() {
+            record_grpc_request(method, "failed_precondition");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            return Err(Status::failed_precondition("Dunning case is not open"));
+        }
+
+        let case = self
+            .dunning
+            .retry(&case, Utc::now().date_naive())
+            .await
+            .map_err(|e| {
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                match e {
+                    BillingError::Invoicing(_) => {
+                        record_error("invoicing", method);
+                        record_grpc_request(method, "unavailable");
+                        Status::unavailable(e.to_string())
+                    }
+                    _ => {
+                        record_error("database", method);
+                        record_grpc_request(method, "error");
+                        Status::internal(e.to_string())
+                    }
+                }
+            })?;
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(RetryDunningCaseResponse {
+            dunning_case: Some(dunning_case_to_proto(case)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "ResolveDunningCase"))]
+    async fn resolve_dunning_case(
+        &self,
+        request: Request<ResolveDunningCaseRequest>,
+    ) -> Result<Response<ResolveDunningCaseResponse>, Status> {
+        let start = Instant::now();
+        let method = "ResolveDunningCase";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_DUNNING_MANAGE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let case_id = parse_uuid(&req.case_id)?;
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            case_id = %case_id,
+            mark_paid = req.mark_paid,
+            "Resolving dunning case"
         );
 
-        // Create billing run
-        let billing_run = self
+        let case = self
             .db
-            .create_billing_run(tenant_id, BillingRunType::Single)
+            .get_dunning_case(tenant_id, case_id)
             .await
             .map_err(|e| {
                 record_error("database", method);
                 record_grpc_request(method, "error");
                 record_grpc_request_duration(method, start.elapsed().as_secs_f64());
                 Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "not_found");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::not_found("Dunning case not found")
             })?;
 
-        // Get subscription
-        let subscription = self
+        // A past-due subscription becomes active again; one already paused or
+        // cancelled by the final action stays that way
+        let note = (!req.note.is_empty()).then_some(req.note.as_str());
+        let case = self
             .db
-            .get_subscription(tenant_id, subscription_id)
+            .close_dunning_case(
+                case.case_id,
+                DunningCaseStatus::Resolved,
+                note,
+                req.mark_paid.then_some(BillingCycleStatus::Paid),
+                Some(SubscriptionStatus::Active),
+            )
             .await
             .map_err(|e| {
+                tracing::error!(error = %e, "Failed to resolve dunning case");
                 record_error("database", method);
                 record_grpc_request(method, "error");
                 record_grpc_request_duration(method, start.elapsed().as_secs_f64());
                 Status::internal(e.to_string())
+            })?
+            .ok_or_else(|| {
+                record_grpc_request(method, "failed_precondition");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::failed_precondition("Dunning case is already closed")
             })?;
 
-        let subscription = subscription.ok_or_else(|| {
-            record_grpc_request(method, "not_found");
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(ResolveDunningCaseResponse {
+            dunning_case: Some(dunning_case_to_proto(case)),
+        }))
+    }
+
+    // =========================================================================
+    // Customer Credit
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "GrantCredit"))]
+    async fn grant_credit(
+        &self,
+        request: Request<GrantCreditRequest>,
+    ) -> Result<Response<GrantCreditResponse>, Status> {
+        let start = Instant::now();
+        let method = "GrantCredit";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_CREDIT_GRANT)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let input = credit_grant_from_proto(tenant_id, request.into_inner()).inspect_err(|_| {
+            record_grpc_request(method, "invalid_argument");
             record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::not_found("Subscription not found")
         })?;
 
-        if subscription.status != SubscriptionStatus::Active.as_str() {
-            record_grpc_request(method, "failed_precondition");
+        tracing::info!(
+            tenant_id = %tenant_id,
+            customer_id = %input.customer_id,
+            amount = %input.amount,
+            "Granting credit"
+        );
+
+        let grant = self.db.create_credit_grant(&input).await.map_err(|e| {
+            tracing::error!(error = %e, "Failed to grant credit");
+            record_error("database", method);
+            record_grpc_request(method, "error");
             record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            return Err(Status::failed_precondition("Subscription must be active"));
-        }
+            Status::internal(e.to_string())
+        })?;
 
-        // Get current billing cycle
-        let cycle = self
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(GrantCreditResponse {
+            grant: Some(credit_grant_to_proto(grant)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "GetCreditBalance"))]
+    async fn get_credit_balance(
+        &self,
+        request: Request<GetCreditBalanceRequest>,
+    ) -> Result<Response<GetCreditBalanceResponse>, Status> {
+        let start = Instant::now();
+        let method = "GetCreditBalance";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_CREDIT_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let customer_id = parse_uuid(&req.customer_id)?;
+        let currency = (!req.currency.is_empty()).then(|| req.currency.to_uppercase());
+
+        tracing::debug!(tenant_id = %tenant_id, customer_id = %customer_id, "Getting credit balance");
+
+        let balances = self
             .db
-            .get_current_billing_cycle(subscription_id)
+            .get_credit_balances(tenant_id, customer_id, currency.as_deref())
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2296,16 +4484,9 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
-        let cycle = cycle.ok_or_else(|| {
-            record_grpc_request(method, "failed_precondition");
-            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::failed_precondition("No pending billing cycle")
-        })?;
-
-        // Get plan
-        let plan = self
+        let grants = self
             .db
-            .get_plan(tenant_id, subscription.plan_id)
+            .list_available_credit_grants(tenant_id, customer_id, currency.as_deref())
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2314,70 +4495,110 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
-        let plan = plan.ok_or_else(|| {
-            record_grpc_request(method, "error");
-            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::internal("Plan not found")
-        })?;
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
 
-        // Create recurring charge
-        let recurring_input = CreateCharge {
-            cycle_id: cycle.cycle_id,
-            charge_type: ChargeType::Recurring,
-            description: format!("Monthly subscription - {}", plan.name),
-            quantity: Decimal::ONE,
-            unit_price: plan.base_price,
-            amount: plan.base_price,
-            is_prorated: false,
-            proration_factor: None,
-            component_id: None,
-            metadata: None,
-        };
+        Ok(Response::new(GetCreditBalanceResponse {
+            balances: balances
+                .into_iter()
+                .map(|b| CreditBalance {
+                    currency: b.currency,
+                    available: b.available.to_string(),
+                })
+                .collect(),
+            grants: grants.into_iter().map(credit_grant_to_proto).collect(),
+        }))
+    }
 
-        self.db.create_charge(&recurring_input).await.map_err(|e| {
-            record_error("database", method);
-            record_grpc_request(method, "error");
-            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-            Status::internal(e.to_string())
-        })?;
+    #[tracing::instrument(skip(self, request), fields(method = "ListCreditTransactions"))]
+    async fn list_credit_transactions(
+        &self,
+        request: Request<ListCreditTransactionsRequest>,
+    ) -> Result<Response<ListCreditTransactionsResponse>, Status> {
+        let start = Instant::now();
+        let method = "ListCreditTransactions";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_CREDIT_READ)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let customer_id = parse_uuid(&req.customer_id)?;
+
+        tracing::debug!(tenant_id = %tenant_id, customer_id = %customer_id, "Listing credit transactions");
+
+        let filter = ListCreditTransactionsFilter {
+            grant_id: if req.grant_id.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.grant_id)?)
+            },
+            page_size: if req.page_size > 0 { req.page_size } else { 50 },
+            page_token: if req.page_token.is_empty() {
+                None
+            } else {
+                Some(parse_uuid(&req.page_token)?)
+            },
+        };
 
-        // Create usage charges
-        let usage_summaries = self
+        let transactions = self
             .db
-            .get_usage_summary(tenant_id, subscription_id, Some(cycle.cycle_id))
+            .list_credit_transactions(tenant_id, customer_id, &filter)
             .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        let proto_transactions: Vec<_> = transactions
+            .into_iter()
+            .map(credit_transaction_to_proto)
+            .collect();
+        let next_page_token = proto_transactions
+            .last()
+            .map(|t| t.transaction_id.clone())
             .unwrap_or_default();
 
-        for summary in usage_summaries {
-            if summary.billable_units > Decimal::ZERO {
-                let usage_input = CreateCharge {
-                    cycle_id: cycle.cycle_id,
-                    charge_type: ChargeType::Usage,
-                    description: format!(
-                        "{} - {} billable units",
-                        summary.name, summary.billable_units
-                    ),
-                    quantity: summary.billable_units,
-                    unit_price: summary.amount / summary.billable_units,
-                    amount: summary.amount,
-                    is_prorated: false,
-                    proration_factor: None,
-                    component_id: Some(summary.component_id),
-                    metadata: None,
-                };
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
 
-                self.db.create_charge(&usage_input).await.map_err(|e| {
-                    record_error("database", method);
-                    record_grpc_request(method, "error");
-                    record_grpc_request_duration(method, start.elapsed().as_secs_f64());
-                    Status::internal(e.to_string())
-                })?;
-            }
-        }
+        Ok(Response::new(ListCreditTransactionsResponse {
+            transactions: proto_transactions,
+            next_page_token,
+        }))
+    }
 
-        // Update cycle status
-        self.db
-            .update_billing_cycle_status(cycle.cycle_id, BillingCycleStatus::Invoiced, None)
+    // =========================================================================
+    // Billing Runs
+    // =========================================================================
+
+    #[tracing::instrument(skip(self, request), fields(method = "RunBilling"))]
+    async fn run_billing(
+        &self,
+        request: Request<RunBillingRequest>,
+    ) -> Result<Response<RunBillingResponse>, Status> {
+        let start = Instant::now();
+        let method = "RunBilling";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_RUN_EXECUTE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let run_type = BillingRunType::from_proto(req.run_type);
+
+        tracing::info!(tenant_id = %tenant_id, run_type = ?run_type, "Starting billing run");
+
+        // Find subscriptions due for billing
+        let subscriptions = self
+            .db
+            .find_subscriptions_due_for_billing(Some(tenant_id), Utc::now().date_naive())
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2386,9 +4607,69 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
-        // Mark usage as invoiced
-        self.db
-            .mark_usage_invoiced(cycle.cycle_id)
+        let (billing_run, results) = self
+            .billing
+            .run(tenant_id, run_type, &subscriptions)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+        let results = results
+            .into_iter()
+            .map(billing_run_result_to_proto)
+            .collect();
+
+        record_grpc_request(method, "ok");
+        record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+
+        Ok(Response::new(RunBillingResponse {
+            billing_run: Some(billing_run_to_proto(billing_run, results)),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request), fields(method = "RunBillingForSubscription"))]
+    #[tracing::instrument(skip(self, request), fields(method = "RunBillingForSubscription"))]
+    async fn run_billing_for_subscription(
+        &self,
+        request: Request<RunBillingForSubscriptionRequest>,
+    ) -> Result<Response<RunBillingForSubscriptionResponse>, Status> {
+        let start = Instant::now();
+        let method = "RunBillingForSubscription";
+
+        let auth = self
+            .capability_checker
+            .require_capability(&request, capabilities::BILLING_RUN_EXECUTE)
+            .await?;
+        let tenant_id = parse_tenant_id(&auth)?;
+
+        let req = request.into_inner();
+        let subscription_id = parse_uuid(&req.subscription_id)?;
+
+        tracing::info!(
+            tenant_id = %tenant_id,
+            subscription_id = %subscription_id,
+            "Running billing for subscription"
+        );
+
+        // Create billing run
+        let billing_run = self
+            .db
+            .create_billing_run(tenant_id, BillingRunType::Single)
+            .await
+            .map_err(|e| {
+                record_error("database", method);
+                record_grpc_request(method, "error");
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                Status::internal(e.to_string())
+            })?;
+
+        // Get subscription
+        let subscription = self
+            .db
+            .get_subscription(tenant_id, subscription_id)
             .await
             .map_err(|e| {
                 record_error("database", method);
@@ -2397,10 +4678,56 @@ impl BillingService for BillingServiceImpl {
                 Status::internal(e.to_string())
             })?;
 
+        let subscription = subscription.ok_or_else(|| {
+            record_grpc_request(method, "not_found");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            Status::not_found("Subscription not found")
+        })?;
+
+        if subscription.status != SubscriptionStatus::Active.as_str() {
+            record_grpc_request(method, "failed_precondition");
+            record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+            return Err(Status::failed_precondition("Subscription must be active"));
+        }
+
+        let cycle = self
+            .billing
+            .bill_subscription(&subscription)
+            .await
+            .map_err(|e| {
+                record_grpc_request_duration(method, start.elapsed().as_secs_f64());
+                match e {
+                    BillingError::NoPendingCycle => {
+                        record_grpc_request(method, "failed_precondition");
+                        Status::failed_precondition(e.to_string())
+                    }
+                    BillingError::PlanNotFound => {
+                        record_grpc_request(method, "error");
+                        Status::internal(e.to_string())
+                    }
+                    BillingError::Invoicing(_) => {
+                        record_error("invoicing", method);
+                        record_grpc_request(method, "unavailable");
+                        Status::unavailable(e.to_string())
+                    }
+                    BillingError::Database(_) => {
+                        record_error("database", method);
+                        record_grpc_request(method, "error");
+                        Status::internal(e.to_string())
+                    }
+                }
+            })?;
+
         // Create result
         let result = self
             .db
-            .create_billing_run_result(billing_run.run_id, subscription_id, "success", None, None)
+            .create_billing_run_result(
+                billing_run.run_id,
+                subscription_id,
+                "success",
+                cycle.invoice_id,
+                None,
+            )
             .await
             .map_err(|e| {
                 record_error("database", method);
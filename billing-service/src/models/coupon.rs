@@ -0,0 +1,233 @@
+//! Coupon, promotion code and discount models.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// How a coupon reduces a charge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountType {
+    /// A percentage of the discounted amount.
+    PercentOff,
+    /// A fixed amount, never more than the discounted amount.
+    AmountOff,
+}
+
+impl DiscountType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscountType::PercentOff => "percent_off",
+            DiscountType::AmountOff => "amount_off",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "amount_off" => DiscountType::AmountOff,
+            _ => DiscountType::PercentOff,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            DiscountType::PercentOff => 1,
+            DiscountType::AmountOff => 2,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            2 => DiscountType::AmountOff,
+            _ => DiscountType::PercentOff,
+        }
+    }
+}
+
+/// How many billing cycles a subscription discount applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CouponDuration {
+    /// The first billed cycle only.
+    Once,
+    /// The first `duration_cycles` billed cycles.
+    Repeating,
+    /// Every billed cycle until the discount is removed.
+    Forever,
+}
+
+impl CouponDuration {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CouponDuration::Once => "once",
+            CouponDuration::Repeating => "repeating",
+            CouponDuration::Forever => "forever",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "repeating" => CouponDuration::Repeating,
+            "forever" => CouponDuration::Forever,
+            _ => CouponDuration::Once,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            CouponDuration::Once => 1,
+            CouponDuration::Repeating => 2,
+            CouponDuration::Forever => 3,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            2 => CouponDuration::Repeating,
+            3 => CouponDuration::Forever,
+            _ => CouponDuration::Once,
+        }
+    }
+}
+
+/// Coupon.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Coupon {
+    pub coupon_id: Uuid,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub discount_type: String,
+    pub percent_off: Option<Decimal>,
+    pub amount_off: Option<Decimal>,
+    pub currency: Option<String>,
+    pub duration: String,
+    pub duration_cycles: Option<i32>,
+    pub max_redemptions: Option<i32>,
+    pub times_redeemed: i32,
+    pub expires_utc: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub metadata: Option<serde_json::Value>,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+impl Coupon {
+    /// Whether the coupon can still be redeemed at `now`.
+    pub fn is_redeemable(&self, now: DateTime<Utc>) -> bool {
+        self.is_active
+            && self.expires_utc.is_none_or(|expires| expires > now)
+            && self
+                .max_redemptions
+                .is_none_or(|max| self.times_redeemed < max)
+    }
+
+    /// Whether a discount that has already been applied to `cycles_applied`
+    /// cycles still applies to another.
+    pub fn applies_after(&self, cycles_applied: i32) -> bool {
+        match CouponDuration::from_string(&self.duration) {
+            CouponDuration::Once => cycles_applied < 1,
+            CouponDuration::Repeating => cycles_applied < self.duration_cycles.unwrap_or(1),
+            CouponDuration::Forever => true,
+        }
+    }
+
+    /// The discount on `amount`, never more than `amount` itself.
+    pub fn discount_on(&self, amount: Decimal) -> Decimal {
+        if amount <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let discount = match DiscountType::from_string(&self.discount_type) {
+            DiscountType::PercentOff => {
+                (amount * self.percent_off.unwrap_or_default() / Decimal::ONE_HUNDRED).round_dp(2)
+            }
+            DiscountType::AmountOff => self.amount_off.unwrap_or_default(),
+        };
+        discount.min(amount)
+    }
+}
+
+/// Promotion code for a coupon.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PromotionCode {
+    pub promotion_code_id: Uuid,
+    pub tenant_id: Uuid,
+    pub coupon_id: Uuid,
+    pub code: String,
+    pub max_redemptions: Option<i32>,
+    pub times_redeemed: i32,
+    pub expires_utc: Option<DateTime<Utc>>,
+    pub is_active: bool,
+    pub created_utc: DateTime<Utc>,
+}
+
+impl PromotionCode {
+    /// Whether the code can still be redeemed at `now`.
+    pub fn is_redeemable(&self, now: DateTime<Utc>) -> bool {
+        self.is_active
+            && self.expires_utc.is_none_or(|expires| expires > now)
+            && self
+                .max_redemptions
+                .is_none_or(|max| self.times_redeemed < max)
+    }
+}
+
+/// Coupon redeemed onto a subscription, or onto one of its charges.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Discount {
+    pub discount_id: Uuid,
+    pub tenant_id: Uuid,
+    pub coupon_id: Uuid,
+    pub promotion_code_id: Option<Uuid>,
+    pub subscription_id: Uuid,
+    pub charge_id: Option<Uuid>,
+    pub cycles_applied: i32,
+    pub is_active: bool,
+    pub created_utc: DateTime<Utc>,
+    pub ended_utc: Option<DateTime<Utc>>,
+}
+
+/// Input for creating a coupon.
+#[derive(Debug, Clone)]
+pub struct CreateCoupon {
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub discount_type: DiscountType,
+    pub percent_off: Option<Decimal>,
+    pub amount_off: Option<Decimal>,
+    pub currency: Option<String>,
+    pub duration: CouponDuration,
+    pub duration_cycles: Option<i32>,
+    pub max_redemptions: Option<i32>,
+    pub expires_utc: Option<DateTime<Utc>>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Input for creating a promotion code.
+#[derive(Debug, Clone)]
+pub struct CreatePromotionCode {
+    pub tenant_id: Uuid,
+    pub coupon_id: Uuid,
+    pub code: String,
+    pub max_redemptions: Option<i32>,
+    pub expires_utc: Option<DateTime<Utc>>,
+}
+
+/// Input for redeeming a coupon as a discount.
+#[derive(Debug, Clone)]
+pub struct CreateDiscount {
+    pub tenant_id: Uuid,
+    pub coupon_id: Uuid,
+    pub promotion_code_id: Option<Uuid>,
+    pub subscription_id: Uuid,
+    pub charge_id: Option<Uuid>,
+}
+
+/// Filter parameters for listing coupons.
+#[derive(Debug, Clone, Default)]
+pub struct ListCouponsFilter {
+    pub include_inactive: bool,
+    pub page_size: i32,
+    pub page_token: Option<Uuid>,
+}
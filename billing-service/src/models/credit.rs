@@ -0,0 +1,109 @@
+//! Customer credit grant and transaction models.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Kind of credit movement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CreditTransactionType {
+    /// Credit granted to the customer.
+    Grant,
+    /// Credit applied to a billing cycle.
+    Consume,
+    /// Unused credit lost when its grant expired.
+    Expire,
+}
+
+impl CreditTransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreditTransactionType::Grant => "grant",
+            CreditTransactionType::Consume => "consume",
+            CreditTransactionType::Expire => "expire",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "consume" => CreditTransactionType::Consume,
+            "expire" => CreditTransactionType::Expire,
+            _ => CreditTransactionType::Grant,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            CreditTransactionType::Grant => 1,
+            CreditTransactionType::Consume => 2,
+            CreditTransactionType::Expire => 3,
+        }
+    }
+}
+
+/// Prepaid credit granted to a customer.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CreditGrant {
+    pub grant_id: Uuid,
+    pub tenant_id: Uuid,
+    pub customer_id: Uuid,
+    pub currency: String,
+    pub amount: Decimal,
+    pub remaining: Decimal,
+    /// Lower numbers are consumed first.
+    pub priority: i32,
+    pub description: String,
+    /// Liability account the grant's balance is held in.
+    pub ledger_account_id: Option<Uuid>,
+    pub expires_utc: Option<DateTime<Utc>>,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+/// Movement of credit on a grant. Grants are positive, consumption and
+/// expiry negative.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CreditTransaction {
+    pub transaction_id: Uuid,
+    pub tenant_id: Uuid,
+    pub customer_id: Uuid,
+    pub grant_id: Uuid,
+    pub transaction_type: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub ledger_account_id: Option<Uuid>,
+    pub cycle_id: Option<Uuid>,
+    pub charge_id: Option<Uuid>,
+    pub created_utc: DateTime<Utc>,
+}
+
+/// Available credit in one currency.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CreditBalance {
+    pub currency: String,
+    pub available: Decimal,
+}
+
+/// Input for granting credit.
+#[derive(Debug, Clone)]
+pub struct CreateCreditGrant {
+    pub tenant_id: Uuid,
+    pub customer_id: Uuid,
+    pub currency: String,
+    pub amount: Decimal,
+    pub priority: i32,
+    pub description: String,
+    pub ledger_account_id: Option<Uuid>,
+    pub expires_utc: Option<DateTime<Utc>>,
+}
+
+/// Filter parameters for listing credit transactions.
+#[derive(Debug, Clone, Default)]
+pub struct ListCreditTransactionsFilter {
+    pub grant_id: Option<Uuid>,
+    pub page_size: i32,
+    pub page_token: Option<Uuid>,
+}
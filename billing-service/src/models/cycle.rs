@@ -76,6 +76,8 @@ pub enum ChargeType {
     Usage,
     OneTime,
     Proration,
+    Discount,
+    Credit,
 }
 
 impl ChargeType {
@@ -85,6 +87,8 @@ impl ChargeType {
             ChargeType::Usage => "usage",
             ChargeType::OneTime => "one_time",
             ChargeType::Proration => "proration",
+            ChargeType::Discount => "discount",
+            ChargeType::Credit => "credit",
         }
     }
 
@@ -93,6 +97,8 @@ impl ChargeType {
             "usage" => ChargeType::Usage,
             "one_time" => ChargeType::OneTime,
             "proration" => ChargeType::Proration,
+            "discount" => ChargeType::Discount,
+            "credit" => ChargeType::Credit,
             _ => ChargeType::Recurring,
         }
     }
@@ -103,6 +109,8 @@ impl ChargeType {
             ChargeType::Usage => 2,
             ChargeType::OneTime => 3,
             ChargeType::Proration => 4,
+            ChargeType::Discount => 5,
+            ChargeType::Credit => 6,
         }
     }
 
@@ -112,6 +120,8 @@ impl ChargeType {
             2 => ChargeType::Usage,
             3 => ChargeType::OneTime,
             4 => ChargeType::Proration,
+            5 => ChargeType::Discount,
+            6 => ChargeType::Credit,
             _ => ChargeType::Recurring,
         }
     }
@@ -131,6 +141,8 @@ pub struct Charge {
     pub proration_factor: Option<Decimal>,
     pub component_id: Option<Uuid>,
     pub metadata: Option<serde_json::Value>,
+    pub invoice_id: Option<Uuid>,
+    pub discount_id: Option<Uuid>,
     pub created_utc: DateTime<Utc>,
 }
 
@@ -149,6 +161,14 @@ pub struct CreateCharge {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// A charge on a cycle preview: one already on the cycle, with its ID, or
+/// one billing the cycle would create.
+#[derive(Debug, Clone)]
+pub struct PreviewCharge {
+    pub charge_id: Option<Uuid>,
+    pub charge: CreateCharge,
+}
+
 /// Filter parameters for listing billing cycles.
 #[derive(Debug, Clone, Default)]
 pub struct ListBillingCyclesFilter {
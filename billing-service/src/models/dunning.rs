@@ -0,0 +1,226 @@
+//! Dunning policy and case models.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// What happens to a subscription when its grace period runs out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DunningFinalAction {
+    /// Leave the subscription past due until the case is resolved.
+    None,
+    Pause,
+    Cancel,
+}
+
+impl DunningFinalAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DunningFinalAction::None => "none",
+            DunningFinalAction::Pause => "pause",
+            DunningFinalAction::Cancel => "cancel",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "none" => DunningFinalAction::None,
+            "cancel" => DunningFinalAction::Cancel,
+            _ => DunningFinalAction::Pause,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            DunningFinalAction::None => 1,
+            DunningFinalAction::Pause => 2,
+            DunningFinalAction::Cancel => 3,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            1 => DunningFinalAction::None,
+            3 => DunningFinalAction::Cancel,
+            _ => DunningFinalAction::Pause,
+        }
+    }
+}
+
+/// Why a dunning case was opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DunningReason {
+    /// The billing run could not bill the cycle.
+    BillingFailed,
+    /// The cycle's invoice is past its due date.
+    InvoiceUnpaid,
+}
+
+impl DunningReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DunningReason::BillingFailed => "billing_failed",
+            DunningReason::InvoiceUnpaid => "invoice_unpaid",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "invoice_unpaid" => DunningReason::InvoiceUnpaid,
+            _ => DunningReason::BillingFailed,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            DunningReason::BillingFailed => 1,
+            DunningReason::InvoiceUnpaid => 2,
+        }
+    }
+}
+
+/// Dunning case status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DunningCaseStatus {
+    Open,
+    /// Billing succeeded on retry or the invoice was paid.
+    Recovered,
+    /// Closed by hand.
+    Resolved,
+    /// The grace period ran out and the final action was taken.
+    Exhausted,
+}
+
+impl DunningCaseStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DunningCaseStatus::Open => "open",
+            DunningCaseStatus::Recovered => "recovered",
+            DunningCaseStatus::Resolved => "resolved",
+            DunningCaseStatus::Exhausted => "exhausted",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "recovered" => DunningCaseStatus::Recovered,
+            "resolved" => DunningCaseStatus::Resolved,
+            "exhausted" => DunningCaseStatus::Exhausted,
+            _ => DunningCaseStatus::Open,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            DunningCaseStatus::Open => 1,
+            DunningCaseStatus::Recovered => 2,
+            DunningCaseStatus::Resolved => 3,
+            DunningCaseStatus::Exhausted => 4,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            2 => DunningCaseStatus::Recovered,
+            3 => DunningCaseStatus::Resolved,
+            4 => DunningCaseStatus::Exhausted,
+            _ => DunningCaseStatus::Open,
+        }
+    }
+}
+
+/// Tenant dunning policy. Day offsets count from the day the case opened.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DunningPolicy {
+    pub tenant_id: Uuid,
+    pub retry_days: Vec<i32>,
+    pub reminder_days: Vec<i32>,
+    pub grace_period_days: i32,
+    pub final_action: String,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+impl DunningPolicy {
+    /// The policy used by tenants that have not set one.
+    pub fn default_for(tenant_id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            tenant_id,
+            retry_days: vec![1, 3, 5],
+            reminder_days: vec![0, 3, 6],
+            grace_period_days: 7,
+            final_action: DunningFinalAction::Pause.as_str().to_string(),
+            created_utc: now,
+            updated_utc: now,
+        }
+    }
+
+    /// Whether the retry after `retry_count` earlier retries is due on day `age_days`.
+    pub fn retry_due(&self, retry_count: i32, age_days: i64) -> bool {
+        self.retry_days
+            .get(retry_count.max(0) as usize)
+            .is_some_and(|&day| age_days >= i64::from(day))
+    }
+
+    /// How many reminders should have gone out by day `age_days`.
+    pub fn reminders_due(&self, age_days: i64) -> i32 {
+        self.reminder_days
+            .iter()
+            .filter(|&&day| age_days >= i64::from(day))
+            .count() as i32
+    }
+
+    /// Whether the grace period is over on day `age_days`.
+    pub fn grace_expired(&self, age_days: i64) -> bool {
+        age_days >= i64::from(self.grace_period_days)
+    }
+}
+
+/// Dunning case for one billing cycle.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DunningCase {
+    pub case_id: Uuid,
+    pub tenant_id: Uuid,
+    pub subscription_id: Uuid,
+    pub cycle_id: Uuid,
+    pub reason: String,
+    pub status: String,
+    pub retry_count: i32,
+    pub reminders_sent: i32,
+    pub last_error: Option<String>,
+    pub resolution_note: Option<String>,
+    pub opened_utc: DateTime<Utc>,
+    pub closed_utc: Option<DateTime<Utc>>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+impl DunningCase {
+    /// Whole days since the case opened.
+    pub fn age_days(&self, today: NaiveDate) -> i64 {
+        (today - self.opened_utc.date_naive()).num_days()
+    }
+}
+
+/// Input for setting a tenant's dunning policy.
+#[derive(Debug, Clone)]
+pub struct UpdateDunningPolicy {
+    pub tenant_id: Uuid,
+    pub retry_days: Vec<i32>,
+    pub reminder_days: Vec<i32>,
+    pub grace_period_days: i32,
+    pub final_action: DunningFinalAction,
+}
+
+/// Filter parameters for listing dunning cases.
+#[derive(Debug, Clone, Default)]
+pub struct ListDunningCasesFilter {
+    pub subscription_id: Option<Uuid>,
+    pub status: Option<DunningCaseStatus>,
+    pub page_size: i32,
+    pub page_token: Option<Uuid>,
+}
@@ -1,7 +1,10 @@
 //! Domain models for billing-service.
 
 mod billing_run;
+mod coupon;
+mod credit;
 mod cycle;
+mod dunning;
 mod plan;
 mod subscription;
 mod usage;
@@ -9,15 +12,32 @@ mod usage;
 pub use billing_run::{
     BillingRun, BillingRunResult, BillingRunStatus, BillingRunType, ListBillingRunsFilter,
 };
+pub use coupon::{
+    Coupon, CouponDuration, CreateCoupon, CreateDiscount, CreatePromotionCode, Discount,
+    DiscountType, ListCouponsFilter, PromotionCode,
+};
+pub use credit::{
+    CreateCreditGrant, CreditBalance, CreditGrant, CreditTransaction, CreditTransactionType,
+    ListCreditTransactionsFilter,
+};
 pub use cycle::{
     BillingCycle, BillingCycleStatus, Charge, ChargeType, CreateCharge, ListBillingCyclesFilter,
-    ListChargesFilter,
+    ListChargesFilter, PreviewCharge,
+};
+pub use dunning::{
+    DunningCase, DunningCaseStatus, DunningFinalAction, DunningPolicy, DunningReason,
+    ListDunningCasesFilter, UpdateDunningPolicy,
 };
 pub use plan::{
-    BillingInterval, BillingPlan, CreatePlan, CreateUsageComponent, ListPlansFilter, UpdatePlan,
-    UsageComponent,
+    BillingInterval, BillingPlan, CreatePlan, CreatePlanMigration, CreateUsageComponent,
+    ListPlansFilter, PlanMigration, PlanMigrationStatus, PlanMigrationSubscription, PlanVersion,
+    PricingModel, PricingTier, UpdatePlan, UsageComponent,
 };
 pub use subscription::{
     CreateSubscription, ListSubscriptionsFilter, ProrationMode, Subscription, SubscriptionStatus,
 };
-pub use usage::{ListUsageFilter, RecordUsage, UsageComponentSummary, UsageRecord};
+pub use usage::{
+    CreateUsageThreshold, LateUsage, ListUsageAlertsFilter, ListUsageFilter, PriceBreakdownLine,
+    RecordUsage, RecordUsageOutcome, UsageAlert, UsageComponentSummary, UsageRecord,
+    UsageThreshold, UsageThresholdType,
+};
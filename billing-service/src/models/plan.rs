@@ -1,8 +1,9 @@
 //! Billing plan model.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
 use sqlx::FromRow;
 use uuid::Uuid;
 
@@ -60,6 +61,68 @@ impl BillingInterval {
     }
 }
 
+/// How a usage component prices its billable units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PricingModel {
+    /// Every unit at the unit price.
+    PerUnit,
+    /// Each tier prices the units that fall within it.
+    Graduated,
+    /// All units at the price of the tier the total reaches.
+    Volume,
+    /// The unit price per started package of `package_size` units.
+    Package,
+}
+
+impl PricingModel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PricingModel::PerUnit => "per_unit",
+            PricingModel::Graduated => "graduated",
+            PricingModel::Volume => "volume",
+            PricingModel::Package => "package",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "graduated" => PricingModel::Graduated,
+            "volume" => PricingModel::Volume,
+            "package" => PricingModel::Package,
+            _ => PricingModel::PerUnit,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            2 => PricingModel::Graduated,
+            3 => PricingModel::Volume,
+            4 => PricingModel::Package,
+            _ => PricingModel::PerUnit,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            PricingModel::PerUnit => 1,
+            PricingModel::Graduated => 2,
+            PricingModel::Volume => 3,
+            PricingModel::Package => 4,
+        }
+    }
+}
+
+/// Pricing tier for graduated and volume pricing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PricingTier {
+    /// Inclusive upper bound in billable units; `None` for the last tier.
+    pub up_to: Option<Decimal>,
+    pub unit_price: Decimal,
+    /// Charged once when any units fall in (graduated) or reach (volume) the tier.
+    pub flat_fee: Decimal,
+}
+
 /// Billing plan.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct BillingPlan {
@@ -77,6 +140,93 @@ pub struct BillingPlan {
     pub metadata: Option<serde_json::Value>,
     pub created_utc: DateTime<Utc>,
     pub updated_utc: DateTime<Utc>,
+    /// Current version; `base_price` and `tax_rate_id` are that version's.
+    pub version: i32,
+}
+
+/// Pricing of one version of a plan. Versions are never changed once created.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlanVersion {
+    pub plan_id: Uuid,
+    pub version: i32,
+    pub base_price: Decimal,
+    pub tax_rate_id: Option<Uuid>,
+    pub created_utc: DateTime<Utc>,
+}
+
+/// Status of a subscription within a plan migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanMigrationStatus {
+    /// Moves to the new version at its first renewal on or after its effective date.
+    Scheduled,
+    Applied,
+    /// Left the plan or version, or was rescheduled by a later migration.
+    Skipped,
+}
+
+impl PlanMigrationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlanMigrationStatus::Scheduled => "scheduled",
+            PlanMigrationStatus::Applied => "applied",
+            PlanMigrationStatus::Skipped => "skipped",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "applied" => PlanMigrationStatus::Applied,
+            "skipped" => PlanMigrationStatus::Skipped,
+            _ => PlanMigrationStatus::Scheduled,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            PlanMigrationStatus::Scheduled => 1,
+            PlanMigrationStatus::Applied => 2,
+            PlanMigrationStatus::Skipped => 3,
+        }
+    }
+}
+
+/// A scheduled move of a plan's subscribers from one version to another.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlanMigration {
+    pub migration_id: Uuid,
+    pub tenant_id: Uuid,
+    pub plan_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub effective_date: NaiveDate,
+    pub notice_days: i32,
+    pub subscription_count: i32,
+    pub created_utc: DateTime<Utc>,
+}
+
+/// A subscription moved by a plan migration.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PlanMigrationSubscription {
+    pub subscription_id: Uuid,
+    pub customer_id: Uuid,
+    pub current_price: Decimal,
+    pub new_price: Decimal,
+    /// Start of the first period billed at the new version.
+    pub effective_date: NaiveDate,
+    pub status: String,
+    pub applied_utc: Option<DateTime<Utc>>,
+}
+
+/// Input for scheduling a plan migration.
+#[derive(Debug, Clone)]
+pub struct CreatePlanMigration {
+    pub tenant_id: Uuid,
+    pub plan_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub effective_date: NaiveDate,
+    pub notice_days: i32,
 }
 
 /// Usage component within a plan.
@@ -90,6 +240,11 @@ pub struct UsageComponent {
     pub included_units: i32,
     pub is_active: bool,
     pub created_utc: DateTime<Utc>,
+    pub pricing_model: String,
+    pub tiers: Json<Vec<PricingTier>>,
+    pub package_size: Option<Decimal>,
+    pub minimum_amount: Option<Decimal>,
+    pub maximum_amount: Option<Decimal>,
 }
 
 /// Input for creating a plan.
@@ -114,6 +269,11 @@ pub struct CreateUsageComponent {
     pub unit_name: String,
     pub unit_price: Decimal,
     pub included_units: i32,
+    pub pricing_model: PricingModel,
+    pub tiers: Vec<PricingTier>,
+    pub package_size: Option<Decimal>,
+    pub minimum_amount: Option<Decimal>,
+    pub maximum_amount: Option<Decimal>,
 }
 
 /// Input for updating a plan.
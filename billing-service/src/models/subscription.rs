@@ -11,6 +11,8 @@ use uuid::Uuid;
 pub enum SubscriptionStatus {
     Trial,
     Active,
+    /// Billing failed or an invoice went unpaid; see the open dunning case.
+    PastDue,
     Paused,
     Cancelled,
     Expired,
@@ -21,6 +23,7 @@ impl SubscriptionStatus {
         match self {
             SubscriptionStatus::Trial => "trial",
             SubscriptionStatus::Active => "active",
+            SubscriptionStatus::PastDue => "past_due",
             SubscriptionStatus::Paused => "paused",
             SubscriptionStatus::Cancelled => "cancelled",
             SubscriptionStatus::Expired => "expired",
@@ -30,6 +33,7 @@ impl SubscriptionStatus {
     pub fn from_string(s: &str) -> Self {
         match s {
             "trial" => SubscriptionStatus::Trial,
+            "past_due" => SubscriptionStatus::PastDue,
             "paused" => SubscriptionStatus::Paused,
             "cancelled" => SubscriptionStatus::Cancelled,
             "expired" => SubscriptionStatus::Expired,
@@ -44,6 +48,7 @@ impl SubscriptionStatus {
             SubscriptionStatus::Paused => 3,
             SubscriptionStatus::Cancelled => 4,
             SubscriptionStatus::Expired => 5,
+            SubscriptionStatus::PastDue => 6,
         }
     }
 
@@ -54,6 +59,7 @@ impl SubscriptionStatus {
             3 => SubscriptionStatus::Paused,
             4 => SubscriptionStatus::Cancelled,
             5 => SubscriptionStatus::Expired,
+            6 => SubscriptionStatus::PastDue,
             _ => SubscriptionStatus::Active,
         }
     }
@@ -119,11 +125,23 @@ pub struct Subscription {
     pub current_period_end: NaiveDate,
     pub proration_mode: String,
     pub pending_plan_id: Option<Uuid>,
+    /// Version of the plan the subscription is billed at.
+    pub plan_version: i32,
     pub metadata: Option<serde_json::Value>,
     pub created_utc: DateTime<Utc>,
     pub updated_utc: DateTime<Utc>,
 }
 
+impl Subscription {
+    /// Where billing notices go, from the `billing_email` metadata key.
+    pub fn billing_email(&self) -> Option<&str> {
+        self.metadata
+            .as_ref()
+            .and_then(|m| m.get("billing_email"))
+            .and_then(|e| e.as_str())
+    }
+}
+
 /// Input for creating a subscription.
 #[derive(Debug, Clone)]
 pub struct CreateSubscription {
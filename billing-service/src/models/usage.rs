@@ -1,6 +1,6 @@
 //! Usage record model.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
@@ -42,6 +42,32 @@ pub struct ListUsageFilter {
     pub page_token: Option<Uuid>,
 }
 
+/// Outcome of recording one usage record.
+#[derive(Debug, Clone)]
+pub enum RecordUsageOutcome {
+    /// Stored, with the thresholds it took the cycle's usage to.
+    Recorded(UsageRecord, Vec<UsageAlert>),
+    /// The idempotency key was already recorded; holds the original record.
+    Duplicate(UsageRecord),
+    /// Would take the cycle's usage past a limit threshold.
+    LimitExceeded(String),
+    /// Not recorded, with the reason.
+    Rejected(String),
+}
+
+/// Unbilled usage recorded against a cycle that was already billed.
+#[derive(Debug, Clone, FromRow)]
+pub struct LateUsage {
+    pub cycle_id: Uuid,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub component_id: Uuid,
+    /// Late quantity not billed yet.
+    pub quantity: Decimal,
+    /// The cycle's usage total, including the late quantity.
+    pub cycle_total: Decimal,
+}
+
 /// Usage summary for a component.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UsageComponentSummary {
@@ -51,4 +77,115 @@ pub struct UsageComponentSummary {
     pub included_units: i32,
     pub billable_units: Decimal,
     pub amount: Decimal,
+    pub pricing_model: String,
+    pub breakdown: Vec<PriceBreakdownLine>,
+}
+
+/// One step of a usage price calculation. A summary's lines sum to its amount.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriceBreakdownLine {
+    pub description: String,
+    pub quantity: Decimal,
+    pub unit_price: Decimal,
+    pub amount: Decimal,
+}
+
+/// Usage threshold type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageThresholdType {
+    /// Records an alert when the cycle's usage reaches the threshold.
+    Alert,
+    /// Also rejects usage that would take the cycle's total past the threshold.
+    Limit,
+}
+
+impl UsageThresholdType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageThresholdType::Alert => "alert",
+            UsageThresholdType::Limit => "limit",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "limit" => UsageThresholdType::Limit,
+            _ => UsageThresholdType::Alert,
+        }
+    }
+
+    pub fn to_proto(&self) -> i32 {
+        match self {
+            UsageThresholdType::Alert => 1,
+            UsageThresholdType::Limit => 2,
+        }
+    }
+
+    pub fn from_proto(value: i32) -> Self {
+        match value {
+            2 => UsageThresholdType::Limit,
+            _ => UsageThresholdType::Alert,
+        }
+    }
+}
+
+/// Threshold on a subscription's usage of one component per billing cycle.
+/// Set either as a quantity or as a percentage of the component's included units.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UsageThreshold {
+    pub threshold_id: Uuid,
+    pub tenant_id: Uuid,
+    pub subscription_id: Uuid,
+    pub component_id: Uuid,
+    pub threshold_type: String,
+    pub quantity: Option<Decimal>,
+    pub percent_of_included: Option<Decimal>,
+    pub is_active: bool,
+    pub created_utc: DateTime<Utc>,
+}
+
+impl UsageThreshold {
+    /// The usage quantity the threshold sits at.
+    pub fn resolve(&self, included_units: i32) -> Decimal {
+        match (self.quantity, self.percent_of_included) {
+            (Some(quantity), _) => quantity,
+            (None, Some(percent)) => Decimal::from(included_units) * percent / Decimal::ONE_HUNDRED,
+            (None, None) => Decimal::ZERO,
+        }
+    }
+}
+
+/// A threshold reached within a billing cycle.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UsageAlert {
+    pub alert_id: Uuid,
+    pub tenant_id: Uuid,
+    pub threshold_id: Uuid,
+    pub subscription_id: Uuid,
+    pub component_id: Uuid,
+    pub cycle_id: Uuid,
+    pub threshold_type: String,
+    pub threshold_quantity: Decimal,
+    pub usage_quantity: Decimal,
+    pub triggered_utc: DateTime<Utc>,
+}
+
+/// Input for creating a usage threshold.
+#[derive(Debug, Clone)]
+pub struct CreateUsageThreshold {
+    pub tenant_id: Uuid,
+    pub subscription_id: Uuid,
+    pub component_id: Uuid,
+    pub threshold_type: UsageThresholdType,
+    pub quantity: Option<Decimal>,
+    pub percent_of_included: Option<Decimal>,
+}
+
+/// Filter parameters for listing usage alerts.
+#[derive(Debug, Clone, Default)]
+pub struct ListUsageAlertsFilter {
+    pub cycle_id: Option<Uuid>,
+    pub page_size: i32,
+    pub page_token: Option<Uuid>,
 }
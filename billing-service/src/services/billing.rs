@@ -0,0 +1,838 @@
+//! Billing engine shared by the billing run RPCs and the scheduler.
+
+use crate::models::{
+    BillingCycle, BillingCycleStatus, BillingPlan, BillingRun, BillingRunResult, BillingRunStatus,
+    BillingRunType, Charge, ChargeType, CreateCharge, DunningReason, PreviewCharge, ProrationMode,
+    Subscription,
+};
+use crate::services::pricing::price_usage;
+use crate::services::{record_billing_run, Database};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use service_core::error::AppError;
+use service_core::grpc::{
+    InvoiceProto, InvoiceStatusProto, InvoicingClient, LineItemInput, LineItemTypeProto,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Why a subscription's cycle could not be billed.
+#[derive(Debug, thiserror::Error)]
+pub enum BillingError {
+    #[error("No pending billing cycle")]
+    NoPendingCycle,
+    #[error("Plan not found")]
+    PlanNotFound,
+    #[error("Invoicing service error: {}", .0.message())]
+    Invoicing(#[from] tonic::Status),
+    #[error(transparent)]
+    Database(#[from] AppError),
+}
+
+/// What billing a subscription's pending cycle would charge.
+#[derive(Debug, Clone)]
+pub struct UpcomingCharges {
+    pub cycle: BillingCycle,
+    /// The plan the cycle would be billed on.
+    pub plan: BillingPlan,
+    pub charges: Vec<PreviewCharge>,
+}
+
+/// A discount charge with the discount it redeems.
+struct DiscountCharge {
+    charge: CreateCharge,
+    discount_id: Uuid,
+    /// Whether this is the discount's last application.
+    ends: bool,
+}
+
+/// Charges subscriptions for their pending billing cycle.
+#[derive(Clone)]
+pub struct BillingEngine {
+    db: Arc<Database>,
+    invoicing: Option<Arc<InvoicingClient>>,
+    issue_invoices: bool,
+}
+
+impl BillingEngine {
+    /// Create a BillingEngine that marks cycles invoiced without creating invoices.
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            db,
+            invoicing: None,
+            issue_invoices: false,
+        }
+    }
+
+    /// Create a BillingEngine that raises an invoicing-service invoice per
+    /// cycle, issuing it when `issue_invoices` is set.
+    pub fn with_invoicing(
+        db: Arc<Database>,
+        invoicing: Arc<InvoicingClient>,
+        issue_invoices: bool,
+    ) -> Self {
+        Self {
+            db,
+            invoicing: Some(invoicing),
+            issue_invoices,
+        }
+    }
+
+    /// Create the recurring, usage and discount charges for a subscription's
+    /// pending cycle, draw the customer's credit against them, invoice them,
+    /// then mark the cycle and its usage as invoiced.
+    ///
+    /// Safe to retry after a failure: charges already on the cycle are not
+    /// created again and the cycle's invoice is reused.
+    pub async fn bill_subscription(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<BillingCycle, BillingError> {
+        let cycle = self
+            .db
+            .get_current_billing_cycle(subscription.subscription_id)
+            .await?
+            .ok_or(BillingError::NoPendingCycle)?;
+
+        let plan = self
+            .db
+            .get_subscription_plan(subscription)
+            .await?
+            .ok_or(BillingError::PlanNotFound)?;
+
+        let existing = self.db.get_cycle_charges(cycle.cycle_id).await?;
+        let (charges, late_cycles) = self
+            .cycle_charges(subscription, &plan, &cycle, &existing)
+            .await?;
+        for charge in &charges {
+            self.db.create_charge(charge).await?;
+        }
+
+        let charges = self.db.get_cycle_charges(cycle.cycle_id).await?;
+        for discount in self
+            .discount_charges(subscription, cycle.cycle_id, &charges, &[])
+            .await?
+        {
+            self.db
+                .create_discount_charge(&discount.charge, discount.discount_id, discount.ends)
+                .await?;
+        }
+
+        self.apply_credit(subscription, &plan, &cycle).await?;
+
+        let invoice_id = match &self.invoicing {
+            Some(client) => Some(
+                self.invoice_cycle(client, subscription, &plan, &cycle)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let cycle = self
+            .db
+            .update_billing_cycle_status(cycle.cycle_id, BillingCycleStatus::Invoiced, invoice_id)
+            .await?
+            .ok_or(BillingError::NoPendingCycle)?;
+
+        self.db.mark_usage_invoiced(cycle.cycle_id).await?;
+        for late_cycle in late_cycles {
+            self.db.mark_usage_invoiced(late_cycle).await?;
+        }
+
+        Ok(cycle)
+    }
+
+    /// Work out what billing a subscription's pending cycle would charge,
+    /// without writing anything: the charges already on the cycle followed by
+    /// the ones a billing run would add. Credit is left out, as it is only
+    /// drawn when the cycle is billed.
+    ///
+    /// With a `new_plan` the cycle is priced as though the subscription
+    /// changed to it on `as_of` under `mode`: immediately with proration
+    /// charges, immediately without them, or from the next cycle on.
+    pub async fn preview_upcoming_charges(
+        &self,
+        subscription: &Subscription,
+        new_plan: Option<&BillingPlan>,
+        mode: ProrationMode,
+        as_of: NaiveDate,
+    ) -> Result<UpcomingCharges, BillingError> {
+        let cycle = self
+            .db
+            .get_current_billing_cycle(subscription.subscription_id)
+            .await?
+            .ok_or(BillingError::NoPendingCycle)?;
+
+        let current_plan = self
+            .db
+            .get_subscription_plan(subscription)
+            .await?
+            .ok_or(BillingError::PlanNotFound)?;
+
+        let (plan, mut pending) = match new_plan {
+            Some(new_plan) if mode == ProrationMode::Immediate => (
+                new_plan.clone(),
+                proration_charges(&cycle, &current_plan, new_plan, as_of),
+            ),
+            Some(new_plan) if mode == ProrationMode::None => (new_plan.clone(), Vec::new()),
+            _ => (current_plan, Vec::new()),
+        };
+
+        let existing = self.db.get_cycle_charges(cycle.cycle_id).await?;
+        let (charges, _) = self
+            .cycle_charges(subscription, &plan, &cycle, &existing)
+            .await?;
+        pending.extend(charges);
+
+        let discounts = self
+            .discount_charges(subscription, cycle.cycle_id, &existing, &pending)
+            .await?;
+
+        let charges = existing
+            .into_iter()
+            .map(|c| PreviewCharge {
+                charge_id: Some(c.charge_id),
+                charge: CreateCharge {
+                    cycle_id: c.cycle_id,
+                    charge_type: ChargeType::from_string(&c.charge_type),
+                    description: c.description,
+                    quantity: c.quantity,
+                    unit_price: c.unit_price,
+                    amount: c.amount,
+                    is_prorated: c.is_prorated,
+                    proration_factor: c.proration_factor,
+                    component_id: c.component_id,
+                    metadata: c.metadata,
+                },
+            })
+            .chain(pending.into_iter().map(|charge| PreviewCharge {
+                charge_id: None,
+                charge,
+            }))
+            .chain(discounts.into_iter().map(|discount| PreviewCharge {
+                charge_id: None,
+                charge: discount.charge,
+            }))
+            .collect();
+
+        Ok(UpcomingCharges {
+            cycle,
+            plan,
+            charges,
+        })
+    }
+
+    /// The recurring, usage and late usage charges billing the cycle on
+    /// `plan` would create, skipping those already among `existing`. Returns
+    /// them with the late cycles whose usage they bill.
+    async fn cycle_charges(
+        &self,
+        subscription: &Subscription,
+        plan: &BillingPlan,
+        cycle: &BillingCycle,
+        existing: &[Charge],
+    ) -> Result<(Vec<CreateCharge>, Vec<Uuid>), BillingError> {
+        let mut charges = Vec::new();
+
+        // Recurring charge
+        if !existing
+            .iter()
+            .any(|c| c.charge_type == ChargeType::Recurring.as_str())
+        {
+            charges.push(CreateCharge {
+                cycle_id: cycle.cycle_id,
+                charge_type: ChargeType::Recurring,
+                description: format!("Monthly subscription - {}", plan.name),
+                quantity: Decimal::ONE,
+                unit_price: plan.base_price,
+                amount: plan.base_price,
+                is_prorated: false,
+                proration_factor: None,
+                component_id: None,
+                metadata: None,
+            });
+        }
+
+        // Usage charges
+        let usage_summaries = self
+            .db
+            .summarize_usage(plan.plan_id, subscription.subscription_id, cycle.cycle_id)
+            .await
+            .unwrap_or_default();
+
+        for summary in usage_summaries {
+            let charged = existing.iter().any(|c| {
+                c.component_id == Some(summary.component_id) && late_cycle_id_of(c).is_none()
+            });
+            // A component minimum can apply with no billable units
+            if charged || (summary.billable_units.is_zero() && summary.amount.is_zero()) {
+                continue;
+            }
+
+            let (quantity, unit_price) = if summary.billable_units > Decimal::ZERO {
+                (
+                    summary.billable_units,
+                    summary.amount / summary.billable_units,
+                )
+            } else {
+                (Decimal::ONE, summary.amount)
+            };
+
+            charges.push(CreateCharge {
+                cycle_id: cycle.cycle_id,
+                charge_type: ChargeType::Usage,
+                description: format!(
+                    "{} - {} billable units",
+                    summary.name, summary.billable_units
+                ),
+                quantity,
+                unit_price,
+                amount: summary.amount,
+                is_prorated: false,
+                proration_factor: None,
+                component_id: Some(summary.component_id),
+                metadata: Some(serde_json::json!({
+                    "pricing_model": summary.pricing_model,
+                    "breakdown": summary.breakdown,
+                })),
+            });
+        }
+
+        let (late_charges, late_cycles) = self
+            .late_usage_charges(subscription, cycle, existing)
+            .await?;
+        charges.extend(late_charges);
+
+        Ok((charges, late_cycles))
+    }
+
+    /// Charge usage that arrived after its own cycle was billed onto the
+    /// cycle being billed, one usage charge per late cycle and component.
+    /// Each charge prices only the late units, on top of what the late cycle
+    /// was already billed for. Returns the charges with the late cycles whose
+    /// usage they bill.
+    async fn late_usage_charges(
+        &self,
+        subscription: &Subscription,
+        cycle: &BillingCycle,
+        existing: &[Charge],
+    ) -> Result<(Vec<CreateCharge>, Vec<Uuid>), BillingError> {
+        let late_usage = self
+            .db
+            .list_late_usage(subscription.subscription_id)
+            .await?;
+
+        let mut charges = Vec::new();
+        let mut late_cycles = Vec::new();
+        for late in late_usage {
+            if !late_cycles.contains(&late.cycle_id) {
+                late_cycles.push(late.cycle_id);
+            }
+
+            let late_cycle_id = late.cycle_id.to_string();
+            let charged = existing.iter().any(|c| {
+                c.component_id == Some(late.component_id)
+                    && late_cycle_id_of(c) == Some(late_cycle_id.as_str())
+            });
+            if charged {
+                continue;
+            }
+            let Some(component) = self.db.get_usage_component(late.component_id).await? else {
+                continue;
+            };
+
+            let included = Decimal::from(component.included_units);
+            let billed_units = (late.cycle_total - late.quantity - included).max(Decimal::ZERO);
+            let billable_units = (late.cycle_total - included).max(Decimal::ZERO);
+            let (billed, _) = price_usage((&component).into(), billed_units);
+            let (total, _) = price_usage((&component).into(), billable_units);
+            let amount = total - billed;
+            if amount.is_zero() {
+                continue;
+            }
+
+            let late_units = billable_units - billed_units;
+            let (quantity, unit_price) = if late_units > Decimal::ZERO {
+                (late_units, amount / late_units)
+            } else {
+                (Decimal::ONE, amount)
+            };
+
+            charges.push(CreateCharge {
+                cycle_id: cycle.cycle_id,
+                charge_type: ChargeType::Usage,
+                description: format!(
+                    "{} - {} late billable units for {} to {}",
+                    component.name, late_units, late.period_start, late.period_end
+                ),
+                quantity,
+                unit_price,
+                amount,
+                is_prorated: false,
+                proration_factor: None,
+                component_id: Some(late.component_id),
+                metadata: Some(serde_json::json!({
+                    "pricing_model": component.pricing_model,
+                    "late_cycle_id": late_cycle_id,
+                })),
+            });
+        }
+
+        Ok((charges, late_cycles))
+    }
+
+    /// Turn the subscription's active discounts into discount charges for a
+    /// cycle holding the `existing` charges plus the `pending` ones about to
+    /// be created.
+    ///
+    /// Subscription discounts reduce the cycle's total and charge discounts
+    /// reduce their charge, on the cycle that charge is on. Discounts apply in
+    /// redemption order and never take the total below zero. A discount
+    /// already charged on the cycle is skipped, so retries are safe.
+    async fn discount_charges(
+        &self,
+        subscription: &Subscription,
+        cycle_id: Uuid,
+        existing: &[Charge],
+        pending: &[CreateCharge],
+    ) -> Result<Vec<DiscountCharge>, BillingError> {
+        let discounts = self
+            .db
+            .list_discounts(subscription.tenant_id, subscription.subscription_id, false)
+            .await?;
+
+        let mut remaining: Decimal = existing
+            .iter()
+            .map(|c| c.amount)
+            .chain(pending.iter().map(|c| c.amount))
+            .sum();
+        let mut discount_charges = Vec::new();
+
+        for discount in discounts {
+            if existing
+                .iter()
+                .any(|c| c.discount_id == Some(discount.discount_id))
+            {
+                continue;
+            }
+
+            let base = match discount.charge_id {
+                Some(charge_id) => match existing.iter().find(|c| c.charge_id == charge_id) {
+                    Some(charge) => charge.amount.min(remaining),
+                    None => continue,
+                },
+                None => remaining,
+            };
+
+            let Some(coupon) = self
+                .db
+                .get_coupon(subscription.tenant_id, discount.coupon_id)
+                .await?
+            else {
+                continue;
+            };
+
+            let amount = coupon.discount_on(base);
+            if amount.is_zero() {
+                continue;
+            }
+            let ends =
+                discount.charge_id.is_some() || !coupon.applies_after(discount.cycles_applied + 1);
+
+            discount_charges.push(DiscountCharge {
+                charge: CreateCharge {
+                    cycle_id,
+                    charge_type: ChargeType::Discount,
+                    description: format!("Discount - {}", coupon.name),
+                    quantity: Decimal::ONE,
+                    unit_price: -amount,
+                    amount: -amount,
+                    is_prorated: false,
+                    proration_factor: None,
+                    component_id: None,
+                    metadata: Some(serde_json::json!({
+                        "coupon_id": coupon.coupon_id.to_string(),
+                        "discount_type": coupon.discount_type,
+                        "discounted_charge_id": discount.charge_id.map(|id| id.to_string()),
+                    })),
+                },
+                discount_id: discount.discount_id,
+                ends,
+            });
+            remaining -= amount;
+        }
+
+        Ok(discount_charges)
+    }
+
+    /// Spend the customer's credit in the plan's currency on what the cycle
+    /// still owes after discounts. Credit is drawn once per cycle; a retry or
+    /// concurrent run finds the credit charges already there and leaves them.
+    async fn apply_credit(
+        &self,
+        subscription: &Subscription,
+        plan: &BillingPlan,
+        cycle: &BillingCycle,
+    ) -> Result<(), BillingError> {
+        let charges = self.db.get_cycle_charges(cycle.cycle_id).await?;
+        if charges
+            .iter()
+            .any(|c| c.charge_type == ChargeType::Credit.as_str())
+        {
+            return Ok(());
+        }
+
+        let amount_due: Decimal = charges.iter().map(|c| c.amount).sum();
+        if amount_due <= Decimal::ZERO {
+            return Ok(());
+        }
+
+        self.db
+            .consume_credit(subscription, cycle.cycle_id, &plan.currency, amount_due)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Put every charge on the cycle onto one draft invoice and return its ID.
+    ///
+    /// The invoice ID is stored on the cycle as soon as the invoice exists and
+    /// on each charge once its line item is added. Line items take the charge's
+    /// position as their sort order, so a retry skips the ones already added.
+    async fn invoice_cycle(
+        &self,
+        client: &InvoicingClient,
+        subscription: &Subscription,
+        plan: &BillingPlan,
+        cycle: &BillingCycle,
+    ) -> Result<Uuid, BillingError> {
+        let tenant_id = subscription.tenant_id.to_string();
+
+        let invoice = match cycle.invoice_id {
+            Some(invoice_id) => {
+                client
+                    .get_invoice(&tenant_id, &invoice_id.to_string())
+                    .await?
+                    .invoice
+            }
+            None => self.find_cycle_invoice(client, subscription, cycle).await?,
+        };
+
+        let invoice = match invoice {
+            Some(invoice) => invoice,
+            None => {
+                let metadata = serde_json::json!({
+                    "source": "billing-service",
+                    "billing_cycle_id": cycle.cycle_id.to_string(),
+                    "subscription_id": subscription.subscription_id.to_string(),
+                })
+                .to_string();
+                let notes = format!(
+                    "Billing period {} to {}",
+                    cycle.period_start, cycle.period_end
+                );
+
+                client
+                    .create_invoice(
+                        &tenant_id,
+                        &subscription.customer_id.to_string(),
+                        &plan.currency,
+                        None,
+                        Some(&notes),
+                        Some(&metadata),
+                    )
+                    .await?
+                    .invoice
+                    .ok_or_else(|| tonic::Status::internal("Invoice missing from response"))?
+            }
+        };
+
+        let invoice_id = Uuid::parse_str(&invoice.invoice_id)
+            .map_err(|_| tonic::Status::internal("Invalid invoice_id in response"))?;
+        if cycle.invoice_id.is_none() {
+            self.db
+                .update_billing_cycle_status(
+                    cycle.cycle_id,
+                    BillingCycleStatus::Pending,
+                    Some(invoice_id),
+                )
+                .await?;
+        }
+
+        // An invoice issued by an earlier attempt only needs its charges marked
+        let is_draft = invoice.status == InvoiceStatusProto::Draft as i32;
+        let on_invoice: HashSet<i32> = invoice.line_items.iter().map(|l| l.sort_order).collect();
+        let tax_rate_id = plan.tax_rate_id.map(|id| id.to_string());
+
+        let charges = self.db.get_cycle_charges(cycle.cycle_id).await?;
+        for (index, charge) in charges.iter().enumerate() {
+            if charge.invoice_id.is_some() {
+                continue;
+            }
+
+            let sort_order = index as i32 + 1;
+            if is_draft && !on_invoice.contains(&sort_order) {
+                // Rounded unit prices (usage) are billed as a single unit
+                let (quantity, unit_price) = if charge.quantity * charge.unit_price == charge.amount
+                {
+                    (charge.quantity, charge.unit_price)
+                } else {
+                    (Decimal::ONE, charge.amount)
+                };
+
+                client
+                    .add_line_item(
+                        &tenant_id,
+                        &invoice.invoice_id,
+                        LineItemInput {
+                            description: charge.description.clone(),
+                            quantity: quantity.to_string(),
+                            unit_price: unit_price.to_string(),
+                            tax_rate_id: tax_rate_id.clone(),
+                            tax_group_id: None,
+                            ledger_account_id: None,
+                            sort_order,
+                            line_type: Some(line_item_type_of(charge)),
+                        },
+                    )
+                    .await?;
+            }
+
+            self.db
+                .set_charge_invoice(charge.charge_id, invoice_id)
+                .await?;
+        }
+
+        if is_draft && self.issue_invoices {
+            client
+                .issue_invoice(&tenant_id, &invoice.invoice_id, None)
+                .await?;
+        }
+
+        Ok(invoice_id)
+    }
+
+    /// Find a draft invoice created for the cycle by an attempt that failed
+    /// before the invoice ID was stored.
+    async fn find_cycle_invoice(
+        &self,
+        client: &InvoicingClient,
+        subscription: &Subscription,
+        cycle: &BillingCycle,
+    ) -> Result<Option<InvoiceProto>, BillingError> {
+        let tenant_id = subscription.tenant_id.to_string();
+        let customer_id = subscription.customer_id.to_string();
+        let cycle_id = cycle.cycle_id.to_string();
+        let mut page_token = String::new();
+
+        loop {
+            let response = client
+                .list_invoices(
+                    &tenant_id,
+                    &customer_id,
+                    Some(InvoiceStatusProto::Draft),
+                    100,
+                    Some(&page_token),
+                )
+                .await?;
+
+            let found = response.invoices.into_iter().find(|invoice| {
+                serde_json::from_str::<serde_json::Value>(&invoice.metadata)
+                    .ok()
+                    .and_then(|m| m.get("billing_cycle_id")?.as_str().map(|id| id == cycle_id))
+                    .unwrap_or(false)
+            });
+            if let Some(invoice) = found {
+                // Listed invoices carry no line items
+                return Ok(client
+                    .get_invoice(&tenant_id, &invoice.invoice_id)
+                    .await?
+                    .invoice);
+            }
+
+            if response.next_page_token.is_empty() {
+                return Ok(None);
+            }
+            page_token = response.next_page_token;
+        }
+    }
+
+    /// The invoicing client cycles are invoiced through, if any.
+    pub fn invoicing(&self) -> Option<&Arc<InvoicingClient>> {
+        self.invoicing.as_ref()
+    }
+
+    /// Open a dunning case for a cycle that failed to bill. A subscription
+    /// without a pending cycle had nothing to bill, so it is left alone.
+    async fn open_dunning_case(
+        &self,
+        subscription: &Subscription,
+        error: &BillingError,
+    ) -> Result<(), AppError> {
+        if matches!(error, BillingError::NoPendingCycle) {
+            return Ok(());
+        }
+        if let Some(cycle) = self
+            .db
+            .get_current_billing_cycle(subscription.subscription_id)
+            .await?
+        {
+            self.db
+                .open_dunning_case(
+                    subscription,
+                    cycle.cycle_id,
+                    DunningReason::BillingFailed,
+                    Some(&error.to_string()),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Bill each subscription under a new billing run, recording a result per
+    /// subscription. A failed subscription does not stop the run; its cycle
+    /// goes into dunning.
+    pub async fn run(
+        &self,
+        tenant_id: Uuid,
+        run_type: BillingRunType,
+        subscriptions: &[Subscription],
+    ) -> Result<(BillingRun, Vec<BillingRunResult>), AppError> {
+        let billing_run = self.db.create_billing_run(tenant_id, run_type).await?;
+
+        let mut processed = 0;
+        let mut succeeded = 0;
+        let mut failed = 0;
+        let mut results = Vec::new();
+
+        for subscription in subscriptions {
+            processed += 1;
+
+            let result = match self.bill_subscription(subscription).await {
+                Ok(cycle) => {
+                    succeeded += 1;
+                    self.db
+                        .create_billing_run_result(
+                            billing_run.run_id,
+                            subscription.subscription_id,
+                            "success",
+                            cycle.invoice_id,
+                            None,
+                        )
+                        .await?
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        subscription_id = %subscription.subscription_id,
+                        error = %e,
+                        "Failed to bill subscription"
+                    );
+                    failed += 1;
+                    self.open_dunning_case(subscription, &e).await?;
+                    self.db
+                        .create_billing_run_result(
+                            billing_run.run_id,
+                            subscription.subscription_id,
+                            "failed",
+                            None,
+                            Some(e.to_string()),
+                        )
+                        .await?
+                }
+            };
+            results.push(result);
+        }
+
+        let status = if failed > 0 && succeeded == 0 {
+            BillingRunStatus::Failed
+        } else {
+            BillingRunStatus::Completed
+        };
+
+        let billing_run = self
+            .db
+            .update_billing_run(
+                billing_run.run_id,
+                status,
+                processed,
+                succeeded,
+                failed,
+                None,
+            )
+            .await?
+            .ok_or_else(|| {
+                AppError::InternalError(anyhow::anyhow!("Failed to update billing run"))
+            })?;
+
+        record_billing_run(&tenant_id.to_string(), run_type.as_str(), status.as_str());
+
+        Ok((billing_run, results))
+    }
+}
+
+/// The proration charges for changing from `old_plan` to `new_plan` on
+/// `as_of`: a credit for the unused part of the old plan's price and a charge
+/// for the rest of the cycle on the new plan.
+pub fn proration_charges(
+    cycle: &BillingCycle,
+    old_plan: &BillingPlan,
+    new_plan: &BillingPlan,
+    as_of: NaiveDate,
+) -> Vec<CreateCharge> {
+    let total_days = (cycle.period_end - cycle.period_start).num_days() as f64;
+    let days_remaining = (cycle.period_end - as_of).num_days().max(0) as f64;
+    let proration_factor =
+        Decimal::from_f64_retain(days_remaining / total_days).unwrap_or(Decimal::ZERO);
+
+    // Credit for unused old plan
+    let old_credit = -(old_plan.base_price * proration_factor);
+    // Charge for new plan remaining days
+    let new_charge = new_plan.base_price * proration_factor;
+
+    let mut charges = Vec::new();
+    if old_credit != Decimal::ZERO {
+        charges.push(CreateCharge {
+            cycle_id: cycle.cycle_id,
+            charge_type: ChargeType::Proration,
+            description: format!("Credit for unused {} plan", old_plan.name),
+            quantity: Decimal::ONE,
+            unit_price: old_credit,
+            amount: old_credit,
+            is_prorated: true,
+            proration_factor: Some(proration_factor),
+            component_id: None,
+            metadata: None,
+        });
+    }
+    if new_charge != Decimal::ZERO {
+        charges.push(CreateCharge {
+            cycle_id: cycle.cycle_id,
+            charge_type: ChargeType::Proration,
+            description: format!("Charge for {} plan (prorated)", new_plan.name),
+            quantity: Decimal::ONE,
+            unit_price: new_charge,
+            amount: new_charge,
+            is_prorated: true,
+            proration_factor: Some(proration_factor),
+            component_id: None,
+            metadata: None,
+        });
+    }
+    charges
+}
+
+/// The cycle a late usage charge bills usage for.
+fn late_cycle_id_of(charge: &Charge) -> Option<&str> {
+    charge.metadata.as_ref()?.get("late_cycle_id")?.as_str()
+}
+
+/// The invoice line type of a charge. Discount and credit lines are posted
+/// against contra-revenue and the customer's credit balance instead of revenue.
+fn line_item_type_of(charge: &Charge) -> LineItemTypeProto {
+    match ChargeType::from_string(&charge.charge_type) {
+        ChargeType::Discount => LineItemTypeProto::Discount,
+        ChargeType::Credit => LineItemTypeProto::Credit,
+        _ => LineItemTypeProto::Charge,
+    }
+}
@@ -2,17 +2,27 @@
 
 use crate::models::{
     BillingCycle, BillingCycleStatus, BillingInterval, BillingPlan, BillingRun, BillingRunResult,
-    BillingRunStatus, BillingRunType, Charge, CreateCharge, CreatePlan, CreateSubscription,
-    CreateUsageComponent, ListBillingCyclesFilter, ListBillingRunsFilter, ListChargesFilter,
-    ListPlansFilter, ListSubscriptionsFilter, ListUsageFilter, ProrationMode, RecordUsage,
-    Subscription, SubscriptionStatus, UpdatePlan, UsageComponent, UsageComponentSummary,
-    UsageRecord,
+    BillingRunStatus, BillingRunType, Charge, ChargeType, Coupon, CreateCharge, CreateCoupon,
+    CreateCreditGrant, CreateDiscount, CreatePlan, CreatePlanMigration, CreatePromotionCode,
+    CreateSubscription, CreateUsageComponent, CreateUsageThreshold, CreditBalance, CreditGrant,
+    CreditTransaction, CreditTransactionType, Discount, DunningCase, DunningCaseStatus,
+    DunningPolicy, DunningReason, LateUsage, ListBillingCyclesFilter, ListBillingRunsFilter,
+    ListChargesFilter, ListCouponsFilter, ListCreditTransactionsFilter, ListDunningCasesFilter,
+    ListPlansFilter, ListSubscriptionsFilter, ListUsageAlertsFilter, ListUsageFilter,
+    PlanMigration, PlanMigrationSubscription, PlanVersion, PromotionCode, ProrationMode,
+    RecordUsage, RecordUsageOutcome, Subscription, SubscriptionStatus, UpdateDunningPolicy,
+    UpdatePlan, UsageAlert, UsageComponent, UsageComponentSummary, UsageRecord, UsageThreshold,
+    UsageThresholdType,
 };
 use crate::services::metrics::DB_QUERY_DURATION;
-use chrono::{NaiveDate, Utc};
+use crate::services::pricing::price_usage;
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use service_core::error::AppError;
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::postgres::{PgPool, PgPoolOptions, Postgres};
+use sqlx::types::Json;
+use sqlx::{Acquire, FromRow, Row, Transaction};
+use std::collections::{HashMap, HashSet};
 use std::time::Duration;
 use tracing::{info, instrument};
 use uuid::Uuid;
@@ -98,9 +108,15 @@ impl Database {
         let plan_id = Uuid::new_v4();
         let plan = sqlx::query_as::<_, BillingPlan>(
             r#"
-            INSERT INTO billing_plans (plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
-            RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+            WITH plan AS (
+                INSERT INTO billing_plans (plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, metadata)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
+            ), first_version AS (
+                INSERT INTO billing_plan_versions (plan_id, version, base_price, tax_rate_id)
+                SELECT plan_id, version, base_price, tax_rate_id FROM plan
+            )
+            SELECT * FROM plan
             "#,
         )
         .bind(plan_id)
@@ -136,9 +152,9 @@ impl Database {
         let component_id = Uuid::new_v4();
         let component = sqlx::query_as::<_, UsageComponent>(
             r#"
-            INSERT INTO usage_components (component_id, plan_id, name, unit_name, unit_price, included_units)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING component_id, plan_id, name, unit_name, unit_price, included_units, is_active, created_utc
+            INSERT INTO usage_components (component_id, plan_id, name, unit_name, unit_price, included_units, pricing_model, tiers, package_size, minimum_amount, maximum_amount)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING component_id, plan_id, name, unit_name, unit_price, included_units, is_active, created_utc, pricing_model, tiers, package_size, minimum_amount, maximum_amount
             "#,
         )
         .bind(component_id)
@@ -147,6 +163,11 @@ impl Database {
         .bind(&input.unit_name)
         .bind(input.unit_price)
         .bind(input.included_units)
+        .bind(input.pricing_model.as_str())
+        .bind(Json(&input.tiers))
+        .bind(input.package_size)
+        .bind(input.minimum_amount)
+        .bind(input.maximum_amount)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create usage component: {}", e)))?;
@@ -169,7 +190,7 @@ impl Database {
 
         let plan = sqlx::query_as::<_, BillingPlan>(
             r#"
-            SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+            SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
             FROM billing_plans
             WHERE tenant_id = $1 AND plan_id = $2
             "#,
@@ -197,7 +218,7 @@ impl Database {
 
         let components = sqlx::query_as::<_, UsageComponent>(
             r#"
-            SELECT component_id, plan_id, name, unit_name, unit_price, included_units, is_active, created_utc
+            SELECT component_id, plan_id, name, unit_name, unit_price, included_units, is_active, created_utc, pricing_model, tiers, package_size, minimum_amount, maximum_amount
             FROM usage_components
             WHERE plan_id = $1 AND is_active = TRUE
             ORDER BY name
@@ -213,6 +234,33 @@ impl Database {
         Ok(components)
     }
 
+    /// Get a usage component by ID, including inactive ones.
+    #[instrument(skip(self), fields(component_id = %component_id))]
+    pub async fn get_usage_component(
+        &self,
+        component_id: Uuid,
+    ) -> Result<Option<UsageComponent>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_usage_component"])
+            .start_timer();
+
+        let component = sqlx::query_as::<_, UsageComponent>(
+            r#"
+            SELECT component_id, plan_id, name, unit_name, unit_price, included_units, is_active, created_utc, pricing_model, tiers, package_size, minimum_amount, maximum_amount
+            FROM usage_components
+            WHERE component_id = $1
+            "#,
+        )
+        .bind(component_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get usage component: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(component)
+    }
+
     /// List plans for a tenant.
     #[instrument(skip(self, filter), fields(tenant_id = %tenant_id))]
     pub async fn list_plans(
@@ -229,7 +277,7 @@ impl Database {
         let plans = if let Some(cursor) = filter.page_token {
             sqlx::query_as::<_, BillingPlan>(
                 r#"
-                SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+                SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
                 FROM billing_plans
                 WHERE tenant_id = $1
                   AND ($2::bool = TRUE OR is_archived = FALSE)
@@ -247,7 +295,7 @@ impl Database {
         } else {
             sqlx::query_as::<_, BillingPlan>(
                 r#"
-                SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+                SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
                 FROM billing_plans
                 WHERE tenant_id = $1
                   AND ($2::bool = TRUE OR is_archived = FALSE)
@@ -268,7 +316,8 @@ impl Database {
         Ok(plans)
     }
 
-    /// Update a plan.
+    /// Update a plan. A new base price or tax rate adds a plan version, which
+    /// new subscriptions start on; existing subscriptions keep theirs.
     #[instrument(skip(self, input), fields(tenant_id = %tenant_id, plan_id = %plan_id))]
     pub async fn update_plan(
         &self,
@@ -280,32 +329,84 @@ impl Database {
             .with_label_values(&["update_plan"])
             .start_timer();
 
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let current = sqlx::query_as::<_, BillingPlan>(
+            r#"
+            SELECT plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
+            FROM billing_plans
+            WHERE tenant_id = $1 AND plan_id = $2 AND is_archived = FALSE
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(plan_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get plan: {}", e)))?;
+
+        let Some(current) = current else {
+            return Ok(None);
+        };
+
+        // A new price or tax rate is a new version; subscribers stay on theirs
+        let base_price = input.base_price.unwrap_or(current.base_price);
+        let tax_rate_id = input.tax_rate_id.or(current.tax_rate_id);
+        let version = if base_price != current.base_price || tax_rate_id != current.tax_rate_id {
+            sqlx::query(
+                r#"
+                INSERT INTO billing_plan_versions (plan_id, version, base_price, tax_rate_id)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(plan_id)
+            .bind(current.version + 1)
+            .bind(base_price)
+            .bind(tax_rate_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to create plan version: {}", e))
+            })?;
+            current.version + 1
+        } else {
+            current.version
+        };
+
         let plan = sqlx::query_as::<_, BillingPlan>(
             r#"
             UPDATE billing_plans
             SET name = COALESCE($3, name),
                 description = COALESCE($4, description),
-                base_price = COALESCE($5, base_price),
-                tax_rate_id = COALESCE($6, tax_rate_id),
-                metadata = COALESCE($7, metadata)
-            WHERE tenant_id = $1 AND plan_id = $2 AND is_archived = FALSE
-            RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+                base_price = $5,
+                tax_rate_id = $6,
+                metadata = COALESCE($7, metadata),
+                version = $8
+            WHERE tenant_id = $1 AND plan_id = $2
+            RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
             "#,
         )
         .bind(tenant_id)
         .bind(plan_id)
         .bind(&input.name)
         .bind(&input.description)
-        .bind(input.base_price)
-        .bind(input.tax_rate_id)
+        .bind(base_price)
+        .bind(tax_rate_id)
         .bind(&input.metadata)
-        .fetch_optional(&self.pool)
+        .bind(version)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to update plan: {}", e)))?;
 
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
-        Ok(plan)
+        Ok(Some(plan))
     }
 
     /// Archive a plan.
@@ -324,7 +425,7 @@ impl Database {
             UPDATE billing_plans
             SET is_archived = TRUE, is_active = FALSE
             WHERE tenant_id = $1 AND plan_id = $2 AND is_archived = FALSE
-            RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc
+            RETURNING plan_id, tenant_id, name, description, billing_interval, interval_count, base_price, currency, tax_rate_id, is_active, is_archived, metadata, created_utc, updated_utc, version
             "#,
         )
         .bind(tenant_id)
@@ -342,6 +443,246 @@ impl Database {
         Ok(plan)
     }
 
+    // =========================================================================
+    // Plan Version Operations
+    // =========================================================================
+
+    /// Get the plan a subscription is billed on, priced at the subscription's
+    /// plan version rather than the plan's current one.
+    #[instrument(skip(self, subscription), fields(subscription_id = %subscription.subscription_id))]
+    pub async fn get_subscription_plan(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<Option<BillingPlan>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_subscription_plan"])
+            .start_timer();
+
+        let plan = sqlx::query_as::<_, BillingPlan>(
+            r#"
+            SELECT p.plan_id, p.tenant_id, p.name, p.description, p.billing_interval, p.interval_count, v.base_price, p.currency, v.tax_rate_id, p.is_active, p.is_archived, p.metadata, p.created_utc, p.updated_utc, v.version
+            FROM billing_plans p
+            JOIN billing_plan_versions v ON v.plan_id = p.plan_id AND v.version = $3
+            WHERE p.tenant_id = $1 AND p.plan_id = $2
+            "#,
+        )
+        .bind(subscription.tenant_id)
+        .bind(subscription.plan_id)
+        .bind(subscription.plan_version)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get subscription plan: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(plan)
+    }
+
+    /// List a plan's versions, oldest first.
+    #[instrument(skip(self), fields(plan_id = %plan_id))]
+    pub async fn list_plan_versions(&self, plan_id: Uuid) -> Result<Vec<PlanVersion>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_plan_versions"])
+            .start_timer();
+
+        let versions = sqlx::query_as::<_, PlanVersion>(
+            r#"
+            SELECT plan_id, version, base_price, tax_rate_id, created_utc
+            FROM billing_plan_versions
+            WHERE plan_id = $1
+            ORDER BY version
+            "#,
+        )
+        .bind(plan_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list plan versions: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(versions)
+    }
+
+    /// List the subscriptions on a plan version that a migration can move:
+    /// those not cancelled or expired and not already changing plan.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, plan_id = %plan_id))]
+    pub async fn list_version_subscribers(
+        &self,
+        tenant_id: Uuid,
+        plan_id: Uuid,
+        version: i32,
+    ) -> Result<Vec<Subscription>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_version_subscribers"])
+            .start_timer();
+
+        let subscriptions = sqlx::query_as::<_, Subscription>(
+            r#"
+            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
+            FROM subscriptions
+            WHERE tenant_id = $1 AND plan_id = $2 AND plan_version = $3
+              AND status NOT IN ('cancelled', 'expired')
+              AND pending_plan_id IS NULL
+            ORDER BY created_utc, subscription_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(plan_id)
+        .bind(version)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list version subscribers: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(subscriptions)
+    }
+
+    /// Schedule a plan migration for the given subscriptions. Migrations
+    /// already scheduled for them are skipped in favour of this one.
+    #[instrument(skip(self, input, subscriptions), fields(tenant_id = %input.tenant_id, plan_id = %input.plan_id))]
+    pub async fn create_plan_migration(
+        &self,
+        input: &CreatePlanMigration,
+        subscriptions: &[PlanMigrationSubscription],
+    ) -> Result<PlanMigration, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_plan_migration"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let migration = sqlx::query_as::<_, PlanMigration>(
+            r#"
+            INSERT INTO plan_migrations (migration_id, tenant_id, plan_id, from_version, to_version, effective_date, notice_days, subscription_count)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING migration_id, tenant_id, plan_id, from_version, to_version, effective_date, notice_days, subscription_count, created_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.plan_id)
+        .bind(input.from_version)
+        .bind(input.to_version)
+        .bind(input.effective_date)
+        .bind(input.notice_days)
+        .bind(subscriptions.len() as i32)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create plan migration: {}", e)))?;
+
+        let subscription_ids: Vec<Uuid> = subscriptions.iter().map(|s| s.subscription_id).collect();
+        sqlx::query(
+            r#"
+            UPDATE plan_migration_subscriptions
+            SET status = 'skipped'
+            WHERE subscription_id = ANY($1) AND status = 'scheduled'
+            "#,
+        )
+        .bind(&subscription_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to skip plan migrations: {}", e))
+        })?;
+
+        for subscription in subscriptions {
+            sqlx::query(
+                r#"
+                INSERT INTO plan_migration_subscriptions (migration_id, subscription_id, customer_id, current_price, new_price, effective_date)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .bind(migration.migration_id)
+            .bind(subscription.subscription_id)
+            .bind(subscription.customer_id)
+            .bind(subscription.current_price)
+            .bind(subscription.new_price)
+            .bind(subscription.effective_date)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to schedule plan migration: {}", e))
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        info!(
+            migration_id = %migration.migration_id,
+            subscription_count = migration.subscription_count,
+            "Plan migration scheduled"
+        );
+
+        Ok(migration)
+    }
+
+    /// Get a plan migration by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, migration_id = %migration_id))]
+    pub async fn get_plan_migration(
+        &self,
+        tenant_id: Uuid,
+        migration_id: Uuid,
+    ) -> Result<Option<PlanMigration>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_plan_migration"])
+            .start_timer();
+
+        let migration = sqlx::query_as::<_, PlanMigration>(
+            r#"
+            SELECT migration_id, tenant_id, plan_id, from_version, to_version, effective_date, notice_days, subscription_count, created_utc
+            FROM plan_migrations
+            WHERE tenant_id = $1 AND migration_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(migration_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get plan migration: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(migration)
+    }
+
+    /// List the subscriptions a plan migration moves.
+    #[instrument(skip(self), fields(migration_id = %migration_id))]
+    pub async fn list_plan_migration_subscriptions(
+        &self,
+        migration_id: Uuid,
+    ) -> Result<Vec<PlanMigrationSubscription>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_plan_migration_subscriptions"])
+            .start_timer();
+
+        let subscriptions = sqlx::query_as::<_, PlanMigrationSubscription>(
+            r#"
+            SELECT subscription_id, customer_id, current_price, new_price, effective_date, status, applied_utc
+            FROM plan_migration_subscriptions
+            WHERE migration_id = $1
+            ORDER BY effective_date, subscription_id
+            "#,
+        )
+        .bind(migration_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list plan migration subscriptions: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(subscriptions)
+    }
+
     // =========================================================================
     // Subscription Operations
     // =========================================================================
@@ -374,9 +715,9 @@ impl Database {
 
         let subscription = sqlx::query_as::<_, Subscription>(
             r#"
-            INSERT INTO subscriptions (subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, trial_end_date, current_period_start, current_period_end, proration_mode, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+            INSERT INTO subscriptions (subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, trial_end_date, current_period_start, current_period_end, proration_mode, metadata, plan_version)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
             "#,
         )
         .bind(subscription_id)
@@ -391,6 +732,7 @@ impl Database {
         .bind(period_end)
         .bind(input.proration_mode.as_str())
         .bind(&input.metadata)
+        .bind(plan.version)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create subscription: {}", e)))?;
@@ -414,7 +756,7 @@ impl Database {
 
         let subscription = sqlx::query_as::<_, Subscription>(
             r#"
-            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
             FROM subscriptions
             WHERE tenant_id = $1 AND subscription_id = $2
             "#,
@@ -447,7 +789,7 @@ impl Database {
         let subscriptions = if let Some(cursor) = filter.page_token {
             sqlx::query_as::<_, Subscription>(
                 r#"
-                SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+                SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
                 FROM subscriptions
                 WHERE tenant_id = $1
                   AND ($2::uuid IS NULL OR customer_id = $2)
@@ -469,7 +811,7 @@ impl Database {
         } else {
             sqlx::query_as::<_, Subscription>(
                 r#"
-                SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+                SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
                 FROM subscriptions
                 WHERE tenant_id = $1
                   AND ($2::uuid IS NULL OR customer_id = $2)
@@ -512,7 +854,7 @@ impl Database {
             UPDATE subscriptions
             SET status = $3, end_date = COALESCE($4, end_date), trial_end_date = CASE WHEN $3 = 'active' THEN NULL ELSE trial_end_date END
             WHERE tenant_id = $1 AND subscription_id = $2
-            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
             "#,
         )
         .bind(tenant_id)
@@ -547,9 +889,10 @@ impl Database {
                 sqlx::query_as::<_, Subscription>(
                     r#"
                     UPDATE subscriptions
-                    SET plan_id = $3, pending_plan_id = NULL
+                    SET plan_id = $3, pending_plan_id = NULL,
+                        plan_version = (SELECT version FROM billing_plans WHERE plan_id = $3)
                     WHERE tenant_id = $1 AND subscription_id = $2 AND status = 'active'
-                    RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+                    RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
                     "#,
                 )
                 .bind(tenant_id)
@@ -565,7 +908,7 @@ impl Database {
                     UPDATE subscriptions
                     SET pending_plan_id = $3
                     WHERE tenant_id = $1 AND subscription_id = $2 AND status = 'active'
-                    RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+                    RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
                     "#,
                 )
                 .bind(tenant_id)
@@ -582,72 +925,217 @@ impl Database {
         Ok(subscription)
     }
 
-    // =========================================================================
-    // Billing Cycle Operations
-    // =========================================================================
-
-    /// Create a billing cycle for a subscription.
-    #[instrument(skip(self), fields(subscription_id = %subscription_id))]
-    pub async fn create_billing_cycle(
+    /// Find trials whose trial end date has passed, across all tenants.
+    /// Trials set to be cancelled by then are left for cancellation instead.
+    #[instrument(skip(self))]
+    pub async fn find_trials_ending(
         &self,
-        subscription_id: Uuid,
-        period_start: NaiveDate,
-        period_end: NaiveDate,
-    ) -> Result<BillingCycle, AppError> {
+        today: NaiveDate,
+    ) -> Result<Vec<Subscription>, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["create_billing_cycle"])
+            .with_label_values(&["find_trials_ending"])
             .start_timer();
 
-        let cycle_id = Uuid::new_v4();
-        let cycle = sqlx::query_as::<_, BillingCycle>(
+        let subscriptions = sqlx::query_as::<_, Subscription>(
             r#"
-            INSERT INTO billing_cycles (cycle_id, subscription_id, period_start, period_end)
-            VALUES ($1, $2, $3, $4)
-            RETURNING cycle_id, subscription_id, period_start, period_end, status, invoice_id, created_utc, updated_utc
+            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
+            FROM subscriptions
+            WHERE status = 'trial'
+              AND trial_end_date <= $1
+              AND (end_date IS NULL OR end_date > trial_end_date)
+            ORDER BY trial_end_date
             "#,
         )
-        .bind(cycle_id)
-        .bind(subscription_id)
-        .bind(period_start)
-        .bind(period_end)
-        .fetch_one(&self.pool)
+        .bind(today)
+        .fetch_all(&self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create billing cycle: {}", e)))?;
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to find ending trials: {}", e)))?;
 
         timer.observe_duration();
 
-        Ok(cycle)
+        Ok(subscriptions)
     }
 
-    /// Get a billing cycle by ID with tenant isolation via subscription.
-    #[instrument(skip(self), fields(tenant_id = %tenant_id, cycle_id = %cycle_id))]
-    pub async fn get_billing_cycle(
-        &self,
-        tenant_id: Uuid,
-        cycle_id: Uuid,
-    ) -> Result<Option<BillingCycle>, AppError> {
+    /// Convert a trial to an active subscription. The paid period starts on the
+    /// trial end date, and the pending cycle is moved to cover it.
+    #[instrument(skip(self, subscription), fields(subscription_id = %subscription.subscription_id))]
+    pub async fn end_trial(&self, subscription: &Subscription) -> Result<Subscription, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_billing_cycle"])
+            .with_label_values(&["end_trial"])
             .start_timer();
 
-        let cycle = sqlx::query_as::<_, BillingCycle>(
+        let plan = self
+            .get_plan(subscription.tenant_id, subscription.plan_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("Plan not found")))?;
+
+        let period_start = subscription
+            .trial_end_date
+            .unwrap_or(subscription.current_period_start);
+        let interval = BillingInterval::from_string(&plan.billing_interval);
+        let period_end = calculate_period_end(period_start, interval, plan.interval_count);
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let activated = sqlx::query_as::<_, Subscription>(
             r#"
-            SELECT bc.cycle_id, bc.subscription_id, bc.period_start, bc.period_end, bc.status, bc.invoice_id, bc.created_utc, bc.updated_utc
-            FROM billing_cycles bc
-            JOIN subscriptions s ON bc.subscription_id = s.subscription_id
-            WHERE s.tenant_id = $1 AND bc.cycle_id = $2
+            UPDATE subscriptions
+            SET status = 'active', trial_end_date = NULL,
+                current_period_start = $3, current_period_end = $4
+            WHERE tenant_id = $1 AND subscription_id = $2 AND status = 'trial'
+            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
             "#,
         )
-        .bind(tenant_id)
-        .bind(cycle_id)
-        .fetch_optional(&self.pool)
+        .bind(subscription.tenant_id)
+        .bind(subscription.subscription_id)
+        .bind(period_start)
+        .bind(period_end)
+        .fetch_optional(&mut *tx)
         .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get billing cycle: {}", e)))?;
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to end trial: {}", e)))?
+        .ok_or_else(|| AppError::BadRequest(anyhow::anyhow!("Subscription is not in trial")))?;
 
-        timer.observe_duration();
+        let moved = sqlx::query(
+            r#"
+            UPDATE billing_cycles
+            SET period_start = $2, period_end = $3
+            WHERE subscription_id = $1 AND status = 'pending'
+            "#,
+        )
+        .bind(subscription.subscription_id)
+        .bind(period_start)
+        .bind(period_end)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to move billing cycle: {}", e))
+        })?;
 
-        Ok(cycle)
-    }
+        if moved.rows_affected() == 0 {
+            sqlx::query(
+                r#"
+                INSERT INTO billing_cycles (cycle_id, subscription_id, period_start, period_end)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(subscription.subscription_id)
+            .bind(period_start)
+            .bind(period_end)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to create billing cycle: {}", e))
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        info!(subscription_id = %activated.subscription_id, "Trial ended");
+
+        Ok(activated)
+    }
+
+    /// Cancel every subscription whose end date has passed, across all tenants.
+    #[instrument(skip(self))]
+    pub async fn cancel_ended_subscriptions(
+        &self,
+        today: NaiveDate,
+    ) -> Result<Vec<Subscription>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["cancel_ended_subscriptions"])
+            .start_timer();
+
+        let subscriptions = sqlx::query_as::<_, Subscription>(
+            r#"
+            UPDATE subscriptions
+            SET status = 'cancelled', pending_plan_id = NULL
+            WHERE status IN ('trial', 'active', 'past_due', 'paused')
+              AND end_date <= $1
+            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
+            "#,
+        )
+        .bind(today)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to cancel ended subscriptions: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(subscriptions)
+    }
+
+    // =========================================================================
+    // Billing Cycle Operations
+    // =========================================================================
+
+    /// Create a billing cycle for a subscription.
+    #[instrument(skip(self), fields(subscription_id = %subscription_id))]
+    pub async fn create_billing_cycle(
+        &self,
+        subscription_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<BillingCycle, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_billing_cycle"])
+            .start_timer();
+
+        let cycle_id = Uuid::new_v4();
+        let cycle = sqlx::query_as::<_, BillingCycle>(
+            r#"
+            INSERT INTO billing_cycles (cycle_id, subscription_id, period_start, period_end)
+            VALUES ($1, $2, $3, $4)
+            RETURNING cycle_id, subscription_id, period_start, period_end, status, invoice_id, created_utc, updated_utc
+            "#,
+        )
+        .bind(cycle_id)
+        .bind(subscription_id)
+        .bind(period_start)
+        .bind(period_end)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create billing cycle: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(cycle)
+    }
+
+    /// Get a billing cycle by ID with tenant isolation via subscription.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, cycle_id = %cycle_id))]
+    pub async fn get_billing_cycle(
+        &self,
+        tenant_id: Uuid,
+        cycle_id: Uuid,
+    ) -> Result<Option<BillingCycle>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_billing_cycle"])
+            .start_timer();
+
+        let cycle = sqlx::query_as::<_, BillingCycle>(
+            r#"
+            SELECT bc.cycle_id, bc.subscription_id, bc.period_start, bc.period_end, bc.status, bc.invoice_id, bc.created_utc, bc.updated_utc
+            FROM billing_cycles bc
+            JOIN subscriptions s ON bc.subscription_id = s.subscription_id
+            WHERE s.tenant_id = $1 AND bc.cycle_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(cycle_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get billing cycle: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(cycle)
+    }
 
     /// Get the current (pending) billing cycle for a subscription.
     #[instrument(skip(self), fields(subscription_id = %subscription_id))]
@@ -789,246 +1277,2054 @@ impl Database {
             )));
         }
 
-        let plan = self
-            .get_plan(tenant_id, subscription.plan_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("Plan not found")))?;
-
         // Get current cycle
         let current_cycle = self
             .get_current_billing_cycle(subscription_id)
             .await?
             .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("No current billing cycle")))?;
 
-        // Calculate new period
+        let (_, new_cycle) = self.renew_subscription(&subscription).await?;
+
+        Ok((current_cycle, new_cycle))
+    }
+
+    /// Roll a subscription into its next period and open that period's cycle.
+    /// A pending plan change takes effect and sets the new period's length,
+    /// starting on the new plan's current version. Otherwise a plan migration
+    /// due by the new period's start moves the subscription to its version.
+    #[instrument(skip(self, subscription), fields(subscription_id = %subscription.subscription_id))]
+    pub async fn renew_subscription(
+        &self,
+        subscription: &Subscription,
+    ) -> Result<(Subscription, BillingCycle), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["renew_subscription"])
+            .start_timer();
+
+        let plan_id = subscription.pending_plan_id.unwrap_or(subscription.plan_id);
+        let plan = self
+            .get_plan(subscription.tenant_id, plan_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("Plan not found")))?;
+
         let interval = BillingInterval::from_string(&plan.billing_interval);
         let new_period_start = subscription.current_period_end;
         let new_period_end = calculate_period_end(new_period_start, interval, plan.interval_count);
 
-        // Create new cycle
-        let new_cycle = self
-            .create_billing_cycle(subscription_id, new_period_start, new_period_end)
-            .await?;
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let new_cycle = sqlx::query_as::<_, BillingCycle>(
+            r#"
+            INSERT INTO billing_cycles (cycle_id, subscription_id, period_start, period_end)
+            VALUES ($1, $2, $3, $4)
+            RETURNING cycle_id, subscription_id, period_start, period_end, status, invoice_id, created_utc, updated_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(subscription.subscription_id)
+        .bind(new_period_start)
+        .bind(new_period_end)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create billing cycle: {}", e)))?;
+
+        let plan_version = if subscription.pending_plan_id.is_some() {
+            plan.version
+        } else {
+            let migrated: Option<i32> = sqlx::query_scalar(
+                r#"
+                UPDATE plan_migration_subscriptions ms
+                SET status = 'applied', applied_utc = NOW()
+                FROM plan_migrations m
+                WHERE ms.migration_id = m.migration_id
+                  AND ms.subscription_id = $1 AND ms.status = 'scheduled' AND ms.effective_date <= $2
+                  AND m.plan_id = $3 AND m.from_version = $4
+                RETURNING m.to_version
+                "#,
+            )
+            .bind(subscription.subscription_id)
+            .bind(new_period_start)
+            .bind(subscription.plan_id)
+            .bind(subscription.plan_version)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to apply plan migration: {}", e))
+            })?;
+            migrated.unwrap_or(subscription.plan_version)
+        };
+
+        // Migrations that are due but no longer fit the subscription never apply
+        sqlx::query(
+            r#"
+            UPDATE plan_migration_subscriptions ms
+            SET status = 'skipped'
+            FROM plan_migrations m
+            WHERE ms.migration_id = m.migration_id
+              AND ms.subscription_id = $1 AND ms.status = 'scheduled'
+              AND (ms.effective_date <= $2 OR m.plan_id <> $3 OR m.from_version <> $4)
+            "#,
+        )
+        .bind(subscription.subscription_id)
+        .bind(new_period_start)
+        .bind(plan_id)
+        .bind(plan_version)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to skip plan migrations: {}", e))
+        })?;
+
+        let renewed = sqlx::query_as::<_, Subscription>(
+            r#"
+            UPDATE subscriptions
+            SET current_period_start = $3, current_period_end = $4,
+                plan_id = $5,
+                plan_version = $6,
+                pending_plan_id = NULL
+            WHERE tenant_id = $1 AND subscription_id = $2
+            RETURNING subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
+            "#,
+        )
+        .bind(subscription.tenant_id)
+        .bind(subscription.subscription_id)
+        .bind(new_period_start)
+        .bind(new_period_end)
+        .bind(plan_id)
+        .bind(plan_version)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to advance subscription: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok((renewed, new_cycle))
+    }
+
+    // =========================================================================
+    // Charge Operations
+    // =========================================================================
+
+    /// Create a charge.
+    #[instrument(skip(self, input), fields(cycle_id = %input.cycle_id))]
+    pub async fn create_charge(&self, input: &CreateCharge) -> Result<Charge, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_charge"])
+            .start_timer();
+
+        let charge_id = Uuid::new_v4();
+        let charge = sqlx::query_as::<_, Charge>(
+            r#"
+            INSERT INTO charges (charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, invoice_id, discount_id, created_utc
+            "#,
+        )
+        .bind(charge_id)
+        .bind(input.cycle_id)
+        .bind(input.charge_type.as_str())
+        .bind(&input.description)
+        .bind(input.quantity)
+        .bind(input.unit_price)
+        .bind(input.amount)
+        .bind(input.is_prorated)
+        .bind(input.proration_factor)
+        .bind(input.component_id)
+        .bind(&input.metadata)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create charge: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(charge)
+    }
+
+    /// Get a charge by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, charge_id = %charge_id))]
+    pub async fn get_charge(
+        &self,
+        tenant_id: Uuid,
+        charge_id: Uuid,
+    ) -> Result<Option<Charge>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_charge"])
+            .start_timer();
+
+        let charge = sqlx::query_as::<_, Charge>(
+            r#"
+            SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.invoice_id, c.discount_id, c.created_utc
+            FROM charges c
+            JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
+            JOIN subscriptions s ON bc.subscription_id = s.subscription_id
+            WHERE s.tenant_id = $1 AND c.charge_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(charge_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get charge: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(charge)
+    }
+
+    /// List charges for a billing cycle.
+    #[instrument(skip(self, filter), fields(cycle_id = %cycle_id))]
+    pub async fn list_charges(
+        &self,
+        tenant_id: Uuid,
+        cycle_id: Uuid,
+        filter: &ListChargesFilter,
+    ) -> Result<Vec<Charge>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_charges"])
+            .start_timer();
+
+        let limit = filter.page_size.clamp(1, 100) as i64;
+        let charge_type_str = filter.charge_type.map(|c| c.as_str().to_string());
+
+        let charges = if let Some(cursor) = filter.page_token {
+            sqlx::query_as::<_, Charge>(
+                r#"
+                SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.invoice_id, c.discount_id, c.created_utc
+                FROM charges c
+                JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
+                JOIN subscriptions s ON bc.subscription_id = s.subscription_id
+                WHERE s.tenant_id = $1 AND c.cycle_id = $2
+                  AND ($3::varchar IS NULL OR c.charge_type = $3)
+                  AND c.charge_id > $4
+                ORDER BY c.charge_id
+                LIMIT $5
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(cycle_id)
+            .bind(&charge_type_str)
+            .bind(cursor)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+        } else {
+            sqlx::query_as::<_, Charge>(
+                r#"
+                SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.invoice_id, c.discount_id, c.created_utc
+                FROM charges c
+                JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
+                JOIN subscriptions s ON bc.subscription_id = s.subscription_id
+                WHERE s.tenant_id = $1 AND c.cycle_id = $2
+                  AND ($3::varchar IS NULL OR c.charge_type = $3)
+                ORDER BY c.charge_id
+                LIMIT $4
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(cycle_id)
+            .bind(&charge_type_str)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+        }
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list charges: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(charges)
+    }
+
+    /// Get every charge on a billing cycle in creation order.
+    #[instrument(skip(self), fields(cycle_id = %cycle_id))]
+    pub async fn get_cycle_charges(&self, cycle_id: Uuid) -> Result<Vec<Charge>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_cycle_charges"])
+            .start_timer();
+
+        let charges = sqlx::query_as::<_, Charge>(
+            r#"
+            SELECT charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, invoice_id, discount_id, created_utc
+            FROM charges
+            WHERE cycle_id = $1
+            ORDER BY created_utc, charge_id
+            "#,
+        )
+        .bind(cycle_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get cycle charges: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(charges)
+    }
+
+    /// Record the invoice a charge was billed on.
+    #[instrument(skip(self), fields(charge_id = %charge_id, invoice_id = %invoice_id))]
+    pub async fn set_charge_invoice(
+        &self,
+        charge_id: Uuid,
+        invoice_id: Uuid,
+    ) -> Result<(), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_charge_invoice"])
+            .start_timer();
+
+        sqlx::query("UPDATE charges SET invoice_id = $2 WHERE charge_id = $1")
+            .bind(charge_id)
+            .bind(invoice_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to set charge invoice: {}", e))
+            })?;
+
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // Coupon Operations
+    // =========================================================================
+
+    /// Create a coupon.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn create_coupon(&self, input: &CreateCoupon) -> Result<Coupon, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_coupon"])
+            .start_timer();
+
+        let coupon_id = Uuid::new_v4();
+        let coupon = sqlx::query_as::<_, Coupon>(
+            r#"
+            INSERT INTO coupons (coupon_id, tenant_id, name, discount_type, percent_off, amount_off, currency, duration, duration_cycles, max_redemptions, expires_utc, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING coupon_id, tenant_id, name, discount_type, percent_off, amount_off, currency, duration, duration_cycles, max_redemptions, times_redeemed, expires_utc, is_active, metadata, created_utc, updated_utc
+            "#,
+        )
+        .bind(coupon_id)
+        .bind(input.tenant_id)
+        .bind(&input.name)
+        .bind(input.discount_type.as_str())
+        .bind(input.percent_off)
+        .bind(input.amount_off)
+        .bind(&input.currency)
+        .bind(input.duration.as_str())
+        .bind(input.duration_cycles)
+        .bind(input.max_redemptions)
+        .bind(input.expires_utc)
+        .bind(&input.metadata)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create coupon: {}", e)))?;
+
+        timer.observe_duration();
+        info!(coupon_id = %coupon.coupon_id, "Coupon created");
+
+        Ok(coupon)
+    }
+
+    /// Get a coupon by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, coupon_id = %coupon_id))]
+    pub async fn get_coupon(
+        &self,
+        tenant_id: Uuid,
+        coupon_id: Uuid,
+    ) -> Result<Option<Coupon>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_coupon"])
+            .start_timer();
+
+        let coupon = sqlx::query_as::<_, Coupon>(
+            r#"
+            SELECT coupon_id, tenant_id, name, discount_type, percent_off, amount_off, currency, duration, duration_cycles, max_redemptions, times_redeemed, expires_utc, is_active, metadata, created_utc, updated_utc
+            FROM coupons
+            WHERE tenant_id = $1 AND coupon_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(coupon_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get coupon: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(coupon)
+    }
+
+    /// List coupons for a tenant.
+    #[instrument(skip(self, filter), fields(tenant_id = %tenant_id))]
+    pub async fn list_coupons(
+        &self,
+        tenant_id: Uuid,
+        filter: &ListCouponsFilter,
+    ) -> Result<Vec<Coupon>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_coupons"])
+            .start_timer();
+
+        let limit = filter.page_size.clamp(1, 100) as i64;
+
+        let coupons = sqlx::query_as::<_, Coupon>(
+            r#"
+            SELECT coupon_id, tenant_id, name, discount_type, percent_off, amount_off, currency, duration, duration_cycles, max_redemptions, times_redeemed, expires_utc, is_active, metadata, created_utc, updated_utc
+            FROM coupons
+            WHERE tenant_id = $1
+              AND ($2::bool = TRUE OR is_active = TRUE)
+              AND ($3::uuid IS NULL OR coupon_id > $3)
+            ORDER BY coupon_id
+            LIMIT $4
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(filter.include_inactive)
+        .bind(filter.page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list coupons: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(coupons)
+    }
+
+    /// Deactivate a coupon so it can no longer be redeemed. Existing
+    /// discounts keep applying.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, coupon_id = %coupon_id))]
+    pub async fn archive_coupon(
+        &self,
+        tenant_id: Uuid,
+        coupon_id: Uuid,
+    ) -> Result<Option<Coupon>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["archive_coupon"])
+            .start_timer();
+
+        let coupon = sqlx::query_as::<_, Coupon>(
+            r#"
+            UPDATE coupons
+            SET is_active = FALSE
+            WHERE tenant_id = $1 AND coupon_id = $2 AND is_active = TRUE
+            RETURNING coupon_id, tenant_id, name, discount_type, percent_off, amount_off, currency, duration, duration_cycles, max_redemptions, times_redeemed, expires_utc, is_active, metadata, created_utc, updated_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(coupon_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to archive coupon: {}", e)))?;
+
+        timer.observe_duration();
+
+        if let Some(ref c) = coupon {
+            info!(coupon_id = %c.coupon_id, "Coupon archived");
+        }
+
+        Ok(coupon)
+    }
+
+    /// Create a promotion code for a coupon.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, coupon_id = %input.coupon_id))]
+    pub async fn create_promotion_code(
+        &self,
+        input: &CreatePromotionCode,
+    ) -> Result<PromotionCode, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_promotion_code"])
+            .start_timer();
+
+        let promotion_code_id = Uuid::new_v4();
+        let promotion_code = sqlx::query_as::<_, PromotionCode>(
+            r#"
+            INSERT INTO promotion_codes (promotion_code_id, tenant_id, coupon_id, code, max_redemptions, expires_utc)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING promotion_code_id, tenant_id, coupon_id, code, max_redemptions, times_redeemed, expires_utc, is_active, created_utc
+            "#,
+        )
+        .bind(promotion_code_id)
+        .bind(input.tenant_id)
+        .bind(input.coupon_id)
+        .bind(&input.code)
+        .bind(input.max_redemptions)
+        .bind(input.expires_utc)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(anyhow::anyhow!("Promotion code already exists"))
+            }
+            _ => AppError::DatabaseError(anyhow::anyhow!("Failed to create promotion code: {}", e)),
+        })?;
+
+        timer.observe_duration();
+
+        Ok(promotion_code)
+    }
+
+    /// Get a promotion code by its code.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_promotion_code_by_code(
+        &self,
+        tenant_id: Uuid,
+        code: &str,
+    ) -> Result<Option<PromotionCode>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_promotion_code_by_code"])
+            .start_timer();
+
+        let promotion_code = sqlx::query_as::<_, PromotionCode>(
+            r#"
+            SELECT promotion_code_id, tenant_id, coupon_id, code, max_redemptions, times_redeemed, expires_utc, is_active, created_utc
+            FROM promotion_codes
+            WHERE tenant_id = $1 AND code = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get promotion code: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(promotion_code)
+    }
+
+    /// List the promotion codes of a coupon.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, coupon_id = %coupon_id))]
+    pub async fn list_promotion_codes(
+        &self,
+        tenant_id: Uuid,
+        coupon_id: Uuid,
+    ) -> Result<Vec<PromotionCode>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_promotion_codes"])
+            .start_timer();
+
+        let promotion_codes = sqlx::query_as::<_, PromotionCode>(
+            r#"
+            SELECT promotion_code_id, tenant_id, coupon_id, code, max_redemptions, times_redeemed, expires_utc, is_active, created_utc
+            FROM promotion_codes
+            WHERE tenant_id = $1 AND coupon_id = $2
+            ORDER BY created_utc, promotion_code_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(coupon_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list promotion codes: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(promotion_codes)
+    }
+
+    /// Redeem a coupon, and optionally one of its promotion codes, as a
+    /// discount.
+    ///
+    /// Redemption counts are taken under the same transaction, so a coupon or
+    /// code at its redemption limit, expired or inactive is rejected with
+    /// `BadRequest` even under concurrent redemptions.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, subscription_id = %input.subscription_id))]
+    pub async fn create_discount(&self, input: &CreateDiscount) -> Result<Discount, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_discount"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let redeemed = sqlx::query(
+            r#"
+            UPDATE coupons
+            SET times_redeemed = times_redeemed + 1
+            WHERE tenant_id = $1 AND coupon_id = $2 AND is_active = TRUE
+              AND (expires_utc IS NULL OR expires_utc > NOW())
+              AND (max_redemptions IS NULL OR times_redeemed < max_redemptions)
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(input.coupon_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to redeem coupon: {}", e)))?;
+
+        if redeemed.rows_affected() == 0 {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Coupon is no longer redeemable"
+            )));
+        }
+
+        if let Some(promotion_code_id) = input.promotion_code_id {
+            let redeemed = sqlx::query(
+                r#"
+                UPDATE promotion_codes
+                SET times_redeemed = times_redeemed + 1
+                WHERE tenant_id = $1 AND promotion_code_id = $2 AND is_active = TRUE
+                  AND (expires_utc IS NULL OR expires_utc > NOW())
+                  AND (max_redemptions IS NULL OR times_redeemed < max_redemptions)
+                "#,
+            )
+            .bind(input.tenant_id)
+            .bind(promotion_code_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to redeem promotion code: {}", e))
+            })?;
+
+            if redeemed.rows_affected() == 0 {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Promotion code is no longer redeemable"
+                )));
+            }
+        }
+
+        let discount = sqlx::query_as::<_, Discount>(
+            r#"
+            INSERT INTO discounts (discount_id, tenant_id, coupon_id, promotion_code_id, subscription_id, charge_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING discount_id, tenant_id, coupon_id, promotion_code_id, subscription_id, charge_id, cycles_applied, is_active, created_utc, ended_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.coupon_id)
+        .bind(input.promotion_code_id)
+        .bind(input.subscription_id)
+        .bind(input.charge_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create discount: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        info!(discount_id = %discount.discount_id, coupon_id = %discount.coupon_id, "Coupon redeemed");
+
+        Ok(discount)
+    }
+
+    /// List a subscription's discounts, oldest first.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, subscription_id = %subscription_id))]
+    pub async fn list_discounts(
+        &self,
+        tenant_id: Uuid,
+        subscription_id: Uuid,
+        include_ended: bool,
+    ) -> Result<Vec<Discount>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_discounts"])
+            .start_timer();
+
+        let discounts = sqlx::query_as::<_, Discount>(
+            r#"
+            SELECT discount_id, tenant_id, coupon_id, promotion_code_id, subscription_id, charge_id, cycles_applied, is_active, created_utc, ended_utc
+            FROM discounts
+            WHERE tenant_id = $1 AND subscription_id = $2
+              AND ($3::bool = TRUE OR is_active = TRUE)
+            ORDER BY created_utc, discount_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(subscription_id)
+        .bind(include_ended)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list discounts: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(discounts)
+    }
+
+    /// End a discount so later billing runs no longer apply it.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, discount_id = %discount_id))]
+    pub async fn end_discount(
+        &self,
+        tenant_id: Uuid,
+        discount_id: Uuid,
+    ) -> Result<Option<Discount>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["end_discount"])
+            .start_timer();
+
+        let discount = sqlx::query_as::<_, Discount>(
+            r#"
+            UPDATE discounts
+            SET is_active = FALSE, ended_utc = NOW()
+            WHERE tenant_id = $1 AND discount_id = $2 AND is_active = TRUE
+            RETURNING discount_id, tenant_id, coupon_id, promotion_code_id, subscription_id, charge_id, cycles_applied, is_active, created_utc, ended_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(discount_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to end discount: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(discount)
+    }
+
+    /// Create the discount charge for one cycle and count the cycle against
+    /// the discount, ending it when `ends` is set.
+    #[instrument(skip(self, input), fields(cycle_id = %input.cycle_id, discount_id = %discount_id))]
+    pub async fn create_discount_charge(
+        &self,
+        input: &CreateCharge,
+        discount_id: Uuid,
+        ends: bool,
+    ) -> Result<Charge, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_discount_charge"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let charge = sqlx::query_as::<_, Charge>(
+            r#"
+            INSERT INTO charges (charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, discount_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, invoice_id, discount_id, created_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.cycle_id)
+        .bind(input.charge_type.as_str())
+        .bind(&input.description)
+        .bind(input.quantity)
+        .bind(input.unit_price)
+        .bind(input.amount)
+        .bind(input.is_prorated)
+        .bind(input.proration_factor)
+        .bind(input.component_id)
+        .bind(&input.metadata)
+        .bind(discount_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to create discount charge: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            UPDATE discounts
+            SET cycles_applied = cycles_applied + 1,
+                is_active = is_active AND NOT $2,
+                ended_utc = CASE WHEN $2 THEN NOW() ELSE ended_utc END
+            WHERE discount_id = $1
+            "#,
+        )
+        .bind(discount_id)
+        .bind(ends)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to update discount: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(charge)
+    }
+
+    // =========================================================================
+    // Credit Operations
+    // =========================================================================
+
+    /// Grant credit to a customer and record the grant transaction.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, customer_id = %input.customer_id))]
+    pub async fn create_credit_grant(
+        &self,
+        input: &CreateCreditGrant,
+    ) -> Result<CreditGrant, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_credit_grant"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let grant = sqlx::query_as::<_, CreditGrant>(
+            r#"
+            INSERT INTO credit_grants (grant_id, tenant_id, customer_id, currency, amount, remaining, priority, description, ledger_account_id, expires_utc)
+            VALUES ($1, $2, $3, $4, $5, $5, $6, $7, $8, $9)
+            RETURNING grant_id, tenant_id, customer_id, currency, amount, remaining, priority, description, ledger_account_id, expires_utc, created_utc, updated_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.customer_id)
+        .bind(&input.currency)
+        .bind(input.amount)
+        .bind(input.priority)
+        .bind(&input.description)
+        .bind(input.ledger_account_id)
+        .bind(input.expires_utc)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to grant credit: {}", e)))?;
+
+        Self::insert_credit_transaction(
+            &mut tx,
+            &grant,
+            CreditTransactionType::Grant,
+            grant.amount,
+            None,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        info!(grant_id = %grant.grant_id, amount = %grant.amount, "Credit granted");
+
+        Ok(grant)
+    }
+
+    /// List a customer's grants with credit left that have not expired, in
+    /// the order billing consumes them.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn list_available_credit_grants(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        currency: Option<&str>,
+    ) -> Result<Vec<CreditGrant>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_available_credit_grants"])
+            .start_timer();
+
+        let grants = sqlx::query_as::<_, CreditGrant>(
+            r#"
+            SELECT grant_id, tenant_id, customer_id, currency, amount, remaining, priority, description, ledger_account_id, expires_utc, created_utc, updated_utc
+            FROM credit_grants
+            WHERE tenant_id = $1 AND customer_id = $2
+              AND ($3::varchar IS NULL OR currency = $3)
+              AND remaining > 0
+              AND (expires_utc IS NULL OR expires_utc > NOW())
+            ORDER BY priority, expires_utc NULLS LAST, created_utc, grant_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(currency)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list credit grants: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(grants)
+    }
+
+    /// Get a customer's available credit per currency.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn get_credit_balances(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        currency: Option<&str>,
+    ) -> Result<Vec<CreditBalance>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_credit_balances"])
+            .start_timer();
+
+        let balances = sqlx::query_as::<_, CreditBalance>(
+            r#"
+            SELECT currency, SUM(remaining) AS available
+            FROM credit_grants
+            WHERE tenant_id = $1 AND customer_id = $2
+              AND ($3::varchar IS NULL OR currency = $3)
+              AND remaining > 0
+              AND (expires_utc IS NULL OR expires_utc > NOW())
+            GROUP BY currency
+            ORDER BY currency
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(currency)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get credit balance: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(balances)
+    }
+
+    /// List a customer's credit transactions, oldest first.
+    #[instrument(skip(self, filter), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn list_credit_transactions(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        filter: &ListCreditTransactionsFilter,
+    ) -> Result<Vec<CreditTransaction>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_credit_transactions"])
+            .start_timer();
+
+        let limit = filter.page_size.clamp(1, 100) as i64;
+
+        let transactions = sqlx::query_as::<_, CreditTransaction>(
+            r#"
+            SELECT transaction_id, tenant_id, customer_id, grant_id, transaction_type, amount, currency, ledger_account_id, cycle_id, charge_id, created_utc
+            FROM credit_transactions
+            WHERE tenant_id = $1 AND customer_id = $2
+              AND ($3::uuid IS NULL OR grant_id = $3)
+              AND ($4::uuid IS NULL OR (created_utc, transaction_id) > (
+                  SELECT created_utc, transaction_id FROM credit_transactions WHERE transaction_id = $4
+              ))
+            ORDER BY created_utc, transaction_id
+            LIMIT $5
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(filter.grant_id)
+        .bind(filter.page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list credit transactions: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(transactions)
+    }
+
+    /// Consume up to `amount_due` of a customer's credit onto a cycle, one
+    /// credit charge per grant drawn from.
+    ///
+    /// The cycle row and grants are locked for the transaction, and a cycle
+    /// that already has credit charges gets them back unchanged, so concurrent
+    /// runs never spend credit twice.
+    #[instrument(skip(self, subscription), fields(subscription_id = %subscription.subscription_id, cycle_id = %cycle_id))]
+    pub async fn consume_credit(
+        &self,
+        subscription: &Subscription,
+        cycle_id: Uuid,
+        currency: &str,
+        amount_due: Decimal,
+    ) -> Result<Vec<Charge>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["consume_credit"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        sqlx::query("SELECT cycle_id FROM billing_cycles WHERE cycle_id = $1 FOR UPDATE")
+            .bind(cycle_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to lock billing cycle: {}", e))
+            })?;
+
+        let applied = sqlx::query_as::<_, Charge>(
+            r#"
+            SELECT charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, invoice_id, discount_id, created_utc
+            FROM charges
+            WHERE cycle_id = $1 AND charge_type = $2
+            ORDER BY created_utc, charge_id
+            "#,
+        )
+        .bind(cycle_id)
+        .bind(ChargeType::Credit.as_str())
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get credit charges: {}", e))
+        })?;
+        if !applied.is_empty() {
+            timer.observe_duration();
+            return Ok(applied);
+        }
+
+        let grants = sqlx::query_as::<_, CreditGrant>(
+            r#"
+            SELECT grant_id, tenant_id, customer_id, currency, amount, remaining, priority, description, ledger_account_id, expires_utc, created_utc, updated_utc
+            FROM credit_grants
+            WHERE tenant_id = $1 AND customer_id = $2 AND currency = $3
+              AND remaining > 0
+              AND (expires_utc IS NULL OR expires_utc > NOW())
+            ORDER BY priority, expires_utc NULLS LAST, created_utc, grant_id
+            FOR UPDATE
+            "#,
+        )
+        .bind(subscription.tenant_id)
+        .bind(subscription.customer_id)
+        .bind(currency)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to lock credit grants: {}", e))
+        })?;
+
+        let mut remaining_due = amount_due;
+        let mut charges = Vec::new();
+
+        for grant in grants {
+            if remaining_due <= Decimal::ZERO {
+                break;
+            }
+            let amount = grant.remaining.min(remaining_due);
+
+            let charge = sqlx::query_as::<_, Charge>(
+                r#"
+                INSERT INTO charges (charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, metadata)
+                VALUES ($1, $2, $3, $4, 1, $5, $5, FALSE, $6)
+                RETURNING charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, invoice_id, discount_id, created_utc
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(cycle_id)
+            .bind(ChargeType::Credit.as_str())
+            .bind(format!("Credit applied - {}", grant.description))
+            .bind(-amount)
+            .bind(serde_json::json!({ "credit_grant_id": grant.grant_id.to_string() }))
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to create credit charge: {}", e))
+            })?;
+
+            sqlx::query("UPDATE credit_grants SET remaining = remaining - $2 WHERE grant_id = $1")
+                .bind(grant.grant_id)
+                .bind(amount)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!("Failed to update credit grant: {}", e))
+                })?;
+
+            Self::insert_credit_transaction(
+                &mut tx,
+                &grant,
+                CreditTransactionType::Consume,
+                -amount,
+                Some((cycle_id, charge.charge_id)),
+            )
+            .await?;
+
+            remaining_due -= amount;
+            charges.push(charge);
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        if !charges.is_empty() {
+            info!(applied = %(amount_due - remaining_due), "Credit applied to cycle");
+        }
+
+        Ok(charges)
+    }
+
+    /// Zero out grants whose expiry has passed, recording the credit lost as
+    /// an expire transaction per grant.
+    #[instrument(skip(self))]
+    pub async fn expire_credit_grants(&self) -> Result<Vec<CreditTransaction>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["expire_credit_grants"])
+            .start_timer();
+
+        let transactions = sqlx::query_as::<_, CreditTransaction>(
+            r#"
+            WITH expiring AS (
+                SELECT grant_id, remaining
+                FROM credit_grants
+                WHERE remaining > 0 AND expires_utc <= NOW()
+                FOR UPDATE
+            ),
+            expired AS (
+                UPDATE credit_grants g
+                SET remaining = 0
+                FROM expiring e
+                WHERE g.grant_id = e.grant_id
+                RETURNING g.grant_id, g.tenant_id, g.customer_id, g.currency, g.ledger_account_id, e.remaining
+            )
+            INSERT INTO credit_transactions (transaction_id, tenant_id, customer_id, grant_id, transaction_type, amount, currency, ledger_account_id)
+            SELECT gen_random_uuid(), tenant_id, customer_id, grant_id, 'expire', -remaining, currency, ledger_account_id
+            FROM expired
+            RETURNING transaction_id, tenant_id, customer_id, grant_id, transaction_type, amount, currency, ledger_account_id, cycle_id, charge_id, created_utc
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to expire credit grants: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(transactions)
+    }
+
+    /// Record a movement on a grant, optionally against the cycle and charge
+    /// it was consumed by. Transactions take the wall-clock time so those
+    /// written together keep their order.
+    async fn insert_credit_transaction(
+        tx: &mut Transaction<'_, Postgres>,
+        grant: &CreditGrant,
+        transaction_type: CreditTransactionType,
+        amount: Decimal,
+        consumed_by: Option<(Uuid, Uuid)>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO credit_transactions (transaction_id, tenant_id, customer_id, grant_id, transaction_type, amount, currency, ledger_account_id, cycle_id, charge_id, created_utc)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, clock_timestamp())
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(grant.tenant_id)
+        .bind(grant.customer_id)
+        .bind(grant.grant_id)
+        .bind(transaction_type.as_str())
+        .bind(amount)
+        .bind(&grant.currency)
+        .bind(grant.ledger_account_id)
+        .bind(consumed_by.map(|(cycle_id, _)| cycle_id))
+        .bind(consumed_by.map(|(_, charge_id)| charge_id))
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to record credit transaction: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // Dunning Operations
+    // =========================================================================
+
+    /// Get a tenant's dunning policy, if it has set one.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_dunning_policy(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Option<DunningPolicy>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_dunning_policy"])
+            .start_timer();
+
+        let policy = sqlx::query_as::<_, DunningPolicy>(
+            r#"
+            SELECT tenant_id, retry_days, reminder_days, grace_period_days, final_action, created_utc, updated_utc
+            FROM dunning_policies
+            WHERE tenant_id = $1
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get dunning policy: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(policy)
+    }
+
+    /// Create or replace a tenant's dunning policy.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn upsert_dunning_policy(
+        &self,
+        input: &UpdateDunningPolicy,
+    ) -> Result<DunningPolicy, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["upsert_dunning_policy"])
+            .start_timer();
+
+        let policy = sqlx::query_as::<_, DunningPolicy>(
+            r#"
+            INSERT INTO dunning_policies (tenant_id, retry_days, reminder_days, grace_period_days, final_action)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (tenant_id) DO UPDATE
+            SET retry_days = EXCLUDED.retry_days,
+                reminder_days = EXCLUDED.reminder_days,
+                grace_period_days = EXCLUDED.grace_period_days,
+                final_action = EXCLUDED.final_action
+            RETURNING tenant_id, retry_days, reminder_days, grace_period_days, final_action, created_utc, updated_utc
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(&input.retry_days)
+        .bind(&input.reminder_days)
+        .bind(input.grace_period_days)
+        .bind(input.final_action.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to save dunning policy: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(policy)
+    }
+
+    /// Open a dunning case for a cycle and move the subscription to past due.
+    /// Returns `None` when the cycle already has an open case.
+    #[instrument(skip(self, subscription, last_error), fields(subscription_id = %subscription.subscription_id, cycle_id = %cycle_id))]
+    pub async fn open_dunning_case(
+        &self,
+        subscription: &Subscription,
+        cycle_id: Uuid,
+        reason: DunningReason,
+        last_error: Option<&str>,
+    ) -> Result<Option<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["open_dunning_case"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let case = sqlx::query_as::<_, DunningCase>(
+            r#"
+            INSERT INTO dunning_cases (case_id, tenant_id, subscription_id, cycle_id, reason, last_error)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (cycle_id) WHERE status = 'open' DO NOTHING
+            RETURNING case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(subscription.tenant_id)
+        .bind(subscription.subscription_id)
+        .bind(cycle_id)
+        .bind(reason.as_str())
+        .bind(last_error)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to open dunning case: {}", e)))?;
+
+        if case.is_some() {
+            sqlx::query(
+                r#"
+                UPDATE subscriptions
+                SET status = 'past_due'
+                WHERE subscription_id = $1 AND status = 'active'
+                "#,
+            )
+            .bind(subscription.subscription_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!(
+                    "Failed to mark subscription past due: {}",
+                    e
+                ))
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        if let Some(case) = &case {
+            info!(case_id = %case.case_id, reason = reason.as_str(), "Dunning case opened");
+        }
+
+        Ok(case)
+    }
+
+    /// Get a dunning case by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, case_id = %case_id))]
+    pub async fn get_dunning_case(
+        &self,
+        tenant_id: Uuid,
+        case_id: Uuid,
+    ) -> Result<Option<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_dunning_case"])
+            .start_timer();
+
+        let case = sqlx::query_as::<_, DunningCase>(
+            r#"
+            SELECT case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            FROM dunning_cases
+            WHERE tenant_id = $1 AND case_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(case_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get dunning case: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(case)
+    }
+
+    /// Get the open dunning case for a cycle, if any.
+    #[instrument(skip(self), fields(cycle_id = %cycle_id))]
+    pub async fn get_open_dunning_case_for_cycle(
+        &self,
+        cycle_id: Uuid,
+    ) -> Result<Option<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_open_dunning_case_for_cycle"])
+            .start_timer();
+
+        let case = sqlx::query_as::<_, DunningCase>(
+            r#"
+            SELECT case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            FROM dunning_cases
+            WHERE cycle_id = $1 AND status = 'open'
+            "#,
+        )
+        .bind(cycle_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get dunning case: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(case)
+    }
+
+    /// List a tenant's dunning cases.
+    #[instrument(skip(self, filter), fields(tenant_id = %tenant_id))]
+    pub async fn list_dunning_cases(
+        &self,
+        tenant_id: Uuid,
+        filter: &ListDunningCasesFilter,
+    ) -> Result<Vec<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_dunning_cases"])
+            .start_timer();
+
+        let limit = filter.page_size.clamp(1, 100) as i64;
+        let status_str = filter.status.map(|s| s.as_str().to_string());
+
+        let cases = sqlx::query_as::<_, DunningCase>(
+            r#"
+            SELECT case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            FROM dunning_cases
+            WHERE tenant_id = $1
+              AND ($2::uuid IS NULL OR subscription_id = $2)
+              AND ($3::varchar IS NULL OR status = $3)
+              AND ($4::uuid IS NULL OR case_id > $4)
+            ORDER BY case_id
+            LIMIT $5
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(filter.subscription_id)
+        .bind(&status_str)
+        .bind(filter.page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list dunning cases: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(cases)
+    }
+
+    /// List open dunning cases across all tenants, oldest first.
+    #[instrument(skip(self))]
+    pub async fn list_open_dunning_cases(&self) -> Result<Vec<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_open_dunning_cases"])
+            .start_timer();
+
+        let cases = sqlx::query_as::<_, DunningCase>(
+            r#"
+            SELECT case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            FROM dunning_cases
+            WHERE status = 'open'
+            ORDER BY opened_utc, case_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list open dunning cases: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(cases)
+    }
+
+    /// Record retries and reminders on an open dunning case.
+    #[instrument(skip(self, last_error), fields(case_id = %case_id))]
+    pub async fn update_dunning_case_progress(
+        &self,
+        case_id: Uuid,
+        retry_count: i32,
+        reminders_sent: i32,
+        last_error: Option<&str>,
+    ) -> Result<Option<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["update_dunning_case_progress"])
+            .start_timer();
+
+        let case = sqlx::query_as::<_, DunningCase>(
+            r#"
+            UPDATE dunning_cases
+            SET retry_count = $2, reminders_sent = $3, last_error = COALESCE($4, last_error)
+            WHERE case_id = $1 AND status = 'open'
+            RETURNING case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            "#,
+        )
+        .bind(case_id)
+        .bind(retry_count)
+        .bind(reminders_sent)
+        .bind(last_error)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to update dunning case: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(case)
+    }
+
+    /// Close an open or exhausted dunning case, optionally moving its cycle
+    /// and its subscription to a new status.
+    ///
+    /// A past-due subscription only returns to active once it has no other
+    /// open case; pausing or cancelling applies regardless.
+    #[instrument(skip(self, note), fields(case_id = %case_id))]
+    pub async fn close_dunning_case(
+        &self,
+        case_id: Uuid,
+        status: DunningCaseStatus,
+        note: Option<&str>,
+        cycle_status: Option<BillingCycleStatus>,
+        subscription_status: Option<SubscriptionStatus>,
+    ) -> Result<Option<DunningCase>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["close_dunning_case"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let Some(case) = sqlx::query_as::<_, DunningCase>(
+            r#"
+            UPDATE dunning_cases
+            SET status = $2, resolution_note = COALESCE($3, resolution_note), closed_utc = NOW()
+            WHERE case_id = $1 AND status IN ('open', 'exhausted')
+            RETURNING case_id, tenant_id, subscription_id, cycle_id, reason, status, retry_count, reminders_sent, last_error, resolution_note, opened_utc, closed_utc, updated_utc
+            "#,
+        )
+        .bind(case_id)
+        .bind(status.as_str())
+        .bind(note)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to close dunning case: {}", e)))?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(cycle_status) = cycle_status {
+            sqlx::query("UPDATE billing_cycles SET status = $2 WHERE cycle_id = $1")
+                .bind(case.cycle_id)
+                .bind(cycle_status.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!(
+                        "Failed to update billing cycle status: {}",
+                        e
+                    ))
+                })?;
+        }
+
+        if let Some(subscription_status) = subscription_status {
+            sqlx::query(
+                r#"
+                UPDATE subscriptions
+                SET status = $2,
+                    end_date = CASE WHEN $2 = 'cancelled' THEN LEAST(COALESCE(end_date, CURRENT_DATE), CURRENT_DATE) ELSE end_date END,
+                    pending_plan_id = CASE WHEN $2 = 'cancelled' THEN NULL ELSE pending_plan_id END
+                WHERE subscription_id = $1 AND status = 'past_due'
+                  AND ($2 <> 'active' OR NOT EXISTS (
+                      SELECT 1 FROM dunning_cases WHERE subscription_id = $1 AND status = 'open'
+                  ))
+                "#,
+            )
+            .bind(case.subscription_id)
+            .bind(subscription_status.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to update subscription status: {}", e))
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+        info!(case_id = %case.case_id, status = %case.status, "Dunning case closed");
+
+        Ok(Some(case))
+    }
+
+    /// Find invoiced cycles whose invoice payment is still being tracked,
+    /// across all tenants, with each cycle's tenant.
+    ///
+    /// Cycles whose unpaid-invoice case has already been closed are left alone.
+    #[instrument(skip(self))]
+    pub async fn find_invoiced_cycles(&self) -> Result<Vec<(Uuid, BillingCycle)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["find_invoiced_cycles"])
+            .start_timer();
+
+        let rows = sqlx::query(
+            r#"
+            SELECT s.tenant_id, bc.cycle_id, bc.subscription_id, bc.period_start, bc.period_end, bc.status, bc.invoice_id, bc.created_utc, bc.updated_utc
+            FROM billing_cycles bc
+            JOIN subscriptions s ON bc.subscription_id = s.subscription_id
+            WHERE bc.status = 'invoiced' AND bc.invoice_id IS NOT NULL
+              AND s.status IN ('active', 'past_due')
+              AND NOT EXISTS (
+                  SELECT 1 FROM dunning_cases dc
+                  WHERE dc.cycle_id = bc.cycle_id AND dc.reason = 'invoice_unpaid' AND dc.status <> 'open'
+              )
+            ORDER BY bc.period_end, bc.cycle_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to find invoiced cycles: {}", e)))?;
+
+        let cycles = rows
+            .iter()
+            .map(|row| Ok((row.try_get("tenant_id")?, BillingCycle::from_row(row)?)))
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to read billing cycle: {}", e))
+            })?;
+
+        timer.observe_duration();
+
+        Ok(cycles)
+    }
+
+    // =========================================================================
+    // Usage Operations
+    // =========================================================================
+
+    /// Record usage with idempotency, returning the alerts it triggered.
+    ///
+    /// Usage that would take its cycle past a limit threshold is rejected
+    /// with `TooManyRequests` and nothing is stored.
+    #[instrument(skip(self, input), fields(subscription_id = %input.subscription_id))]
+    pub async fn record_usage(
+        &self,
+        input: &RecordUsage,
+    ) -> Result<(UsageRecord, Vec<UsageAlert>), AppError> {
+        let outcome = self
+            .record_usage_batch(std::slice::from_ref(input))
+            .await?
+            .pop()
+            .ok_or_else(|| AppError::InternalError(anyhow::anyhow!("No usage outcome")))?;
+
+        match outcome {
+            RecordUsageOutcome::Recorded(record, alerts) => Ok((record, alerts)),
+            RecordUsageOutcome::Duplicate(record) => Ok((record, Vec::new())),
+            RecordUsageOutcome::LimitExceeded(message) => {
+                Err(AppError::TooManyRequests(message, None))
+            }
+            RecordUsageOutcome::Rejected(message) => {
+                Err(AppError::BadRequest(anyhow::anyhow!(message)))
+            }
+        }
+    }
+
+    /// Record a batch of usage in one transaction, returning one outcome per
+    /// input, in order.
+    ///
+    /// Records are deduplicated by idempotency key, against earlier batches
+    /// and within the batch, and attributed to the subscription's billing
+    /// cycle covering their timestamp, or to its pending cycle when none does.
+    /// Usage for a cycle that was already billed still counts towards that
+    /// cycle and is billed as late usage with the next one; thresholds are
+    /// only checked for pending cycles.
+    ///
+    /// Each cycle's usage counter row lock serialises concurrent records for
+    /// a component, so limits hold under load. Records are stored in
+    /// (cycle, component) order so concurrent batches take those locks in the
+    /// same order. A record rejected by a limit is rolled back on its own
+    /// without failing the batch.
+    #[instrument(skip(self, inputs), fields(records = inputs.len()))]
+    pub async fn record_usage_batch(
+        &self,
+        inputs: &[RecordUsage],
+    ) -> Result<Vec<RecordUsageOutcome>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["record_usage_batch"])
+            .start_timer();
+
+        // Records already stored under the batch's idempotency keys
+        let keys: Vec<&str> = inputs
+            .iter()
+            .map(|input| input.idempotency_key.as_str())
+            .collect();
+        let mut recorded: HashMap<String, UsageRecord> = sqlx::query_as::<_, UsageRecord>(
+            r#"
+            SELECT record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, is_invoiced, metadata, created_utc
+            FROM usage_records
+            WHERE idempotency_key = ANY($1)
+            "#,
+        )
+        .bind(&keys)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to check idempotency: {}", e)))?
+        .into_iter()
+        .map(|record| (record.idempotency_key.clone(), record))
+        .collect();
+
+        let subscription_ids: Vec<Uuid> = inputs
+            .iter()
+            .map(|input| input.subscription_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let cycles = sqlx::query_as::<_, BillingCycle>(
+            r#"
+            SELECT cycle_id, subscription_id, period_start, period_end, status, invoice_id, created_utc, updated_utc
+            FROM billing_cycles
+            WHERE subscription_id = ANY($1)
+            ORDER BY period_start DESC
+            "#,
+        )
+        .bind(&subscription_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get billing cycles: {}", e))
+        })?;
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Take the usage counter locks in (cycle, component) order, so concurrent
+        // batches touching the same counters queue up instead of deadlocking.
+        // The sort is stable, so records for one counter keep their batch order.
+        let mut order: Vec<(usize, Option<&BillingCycle>)> = inputs
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                (
+                    i,
+                    usage_cycle(&cycles, input.subscription_id, input.timestamp),
+                )
+            })
+            .collect();
+        order.sort_by_key(|(i, cycle)| (cycle.map(|c| c.cycle_id), inputs[*i].component_id));
+
+        let mut outcomes: Vec<Option<RecordUsageOutcome>> = vec![None; inputs.len()];
+        for (i, cycle) in order {
+            let input = &inputs[i];
+            if let Some(existing) = recorded.get(&input.idempotency_key) {
+                outcomes[i] = Some(duplicate_usage(existing, input));
+                continue;
+            }
+
+            let mut savepoint = (&mut tx).begin().await.map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to begin savepoint: {}", e))
+            })?;
+
+            match Self::insert_usage(&mut savepoint, cycle, input).await {
+                Ok((record, alerts)) => {
+                    savepoint.commit().await.map_err(|e| {
+                        AppError::DatabaseError(anyhow::anyhow!(
+                            "Failed to release savepoint: {}",
+                            e
+                        ))
+                    })?;
+                    recorded.insert(record.idempotency_key.clone(), record.clone());
+                    outcomes[i] = Some(RecordUsageOutcome::Recorded(record, alerts));
+                }
+                Err(AppError::TooManyRequests(message, _)) => {
+                    savepoint.rollback().await.map_err(|e| {
+                        AppError::DatabaseError(anyhow::anyhow!(
+                            "Failed to roll back savepoint: {}",
+                            e
+                        ))
+                    })?;
+                    outcomes[i] = Some(RecordUsageOutcome::LimitExceeded(message));
+                }
+                Err(AppError::Conflict(_)) => {
+                    // Race condition: another request recorded the key first
+                    savepoint.rollback().await.map_err(|e| {
+                        AppError::DatabaseError(anyhow::anyhow!(
+                            "Failed to roll back savepoint: {}",
+                            e
+                        ))
+                    })?;
+                    let existing = sqlx::query_as::<_, UsageRecord>(
+                        r#"
+                        SELECT record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, is_invoiced, metadata, created_utc
+                        FROM usage_records
+                        WHERE idempotency_key = $1
+                        "#,
+                    )
+                    .bind(&input.idempotency_key)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| {
+                        AppError::DatabaseError(anyhow::anyhow!(
+                            "Failed to check idempotency: {}",
+                            e
+                        ))
+                    })?;
+                    outcomes[i] = Some(duplicate_usage(&existing, input));
+                    recorded.insert(existing.idempotency_key.clone(), existing);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        let outcomes: Vec<RecordUsageOutcome> = outcomes.into_iter().flatten().collect();
+
+        timer.observe_duration();
+        for outcome in &outcomes {
+            if let RecordUsageOutcome::Recorded(_, alerts) = outcome {
+                for alert in alerts {
+                    info!(
+                        threshold_id = %alert.threshold_id,
+                        threshold_type = %alert.threshold_type,
+                        usage_quantity = %alert.usage_quantity,
+                        "Usage threshold reached"
+                    );
+                }
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    /// Store one usage record and count it towards its cycle.
+    async fn insert_usage(
+        tx: &mut Transaction<'_, Postgres>,
+        cycle: Option<&BillingCycle>,
+        input: &RecordUsage,
+    ) -> Result<(UsageRecord, Vec<UsageAlert>), AppError> {
+        let record = sqlx::query_as::<_, UsageRecord>(
+            r#"
+            INSERT INTO usage_records (record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, is_invoiced, metadata, created_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.subscription_id)
+        .bind(input.component_id)
+        .bind(&input.idempotency_key)
+        .bind(input.quantity)
+        .bind(input.timestamp)
+        .bind(cycle.map(|c| c.cycle_id))
+        .bind(&input.metadata)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(anyhow::anyhow!("Duplicate idempotency key"))
+            }
+            _ => AppError::DatabaseError(anyhow::anyhow!("Failed to record usage: {}", e)),
+        })?;
+
+        let alerts = match cycle {
+            Some(cycle) => Self::count_usage(tx, cycle, input).await?,
+            None => Vec::new(),
+        };
+
+        Ok((record, alerts))
+    }
+
+    /// Add a record to its cycle's usage counter and, for a pending cycle,
+    /// check the component's thresholds against the new total.
+    async fn count_usage(
+        tx: &mut Transaction<'_, Postgres>,
+        cycle: &BillingCycle,
+        input: &RecordUsage,
+    ) -> Result<Vec<UsageAlert>, AppError> {
+        let cycle_id = cycle.cycle_id;
+        let total: Decimal = sqlx::query_scalar(
+            r#"
+            INSERT INTO usage_counters (cycle_id, component_id, subscription_id, quantity)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (cycle_id, component_id) DO UPDATE
+            SET quantity = usage_counters.quantity + EXCLUDED.quantity, updated_utc = NOW()
+            RETURNING quantity
+            "#,
+        )
+        .bind(cycle_id)
+        .bind(input.component_id)
+        .bind(input.subscription_id)
+        .bind(input.quantity)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to update usage counter: {}", e))
+        })?;
 
-        // Update subscription periods
-        sqlx::query(
+        if cycle.status != BillingCycleStatus::Pending.as_str() {
+            return Ok(Vec::new());
+        }
+
+        let thresholds = sqlx::query(
             r#"
-            UPDATE subscriptions
-            SET current_period_start = $3, current_period_end = $4,
-                plan_id = COALESCE(pending_plan_id, plan_id),
-                pending_plan_id = NULL
-            WHERE tenant_id = $1 AND subscription_id = $2
+            SELECT t.threshold_id, t.tenant_id, t.subscription_id, t.component_id, t.threshold_type, t.quantity, t.percent_of_included, t.is_active, t.created_utc, uc.included_units
+            FROM usage_thresholds t
+            JOIN usage_components uc ON uc.component_id = t.component_id
+            WHERE t.subscription_id = $1 AND t.component_id = $2 AND t.is_active = TRUE
             "#,
         )
-        .bind(tenant_id)
-        .bind(subscription_id)
-        .bind(new_period_start)
-        .bind(new_period_end)
-        .execute(&self.pool)
+        .bind(input.subscription_id)
+        .bind(input.component_id)
+        .fetch_all(&mut **tx)
         .await
         .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to advance subscription: {}", e))
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get usage thresholds: {}", e))
         })?;
 
-        Ok((current_cycle, new_cycle))
-    }
+        let mut alerts = Vec::new();
+        for row in thresholds {
+            let threshold = UsageThreshold::from_row(&row).map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to read usage threshold: {}", e))
+            })?;
+            let threshold_quantity = threshold.resolve(row.get("included_units"));
+            let threshold_type = UsageThresholdType::from_string(&threshold.threshold_type);
+
+            if threshold_type == UsageThresholdType::Limit && total > threshold_quantity {
+                return Err(AppError::TooManyRequests(
+                    format!(
+                        "Usage limit of {} reached for this billing cycle",
+                        threshold_quantity.normalize()
+                    ),
+                    None,
+                ));
+            }
+            if total < threshold_quantity {
+                continue;
+            }
 
-    // =========================================================================
-    // Charge Operations
-    // =========================================================================
+            let alert = sqlx::query_as::<_, UsageAlert>(
+                r#"
+                INSERT INTO usage_alerts (alert_id, tenant_id, threshold_id, subscription_id, component_id, cycle_id, threshold_type, threshold_quantity, usage_quantity)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                ON CONFLICT (threshold_id, cycle_id) DO NOTHING
+                RETURNING alert_id, tenant_id, threshold_id, subscription_id, component_id, cycle_id, threshold_type, threshold_quantity, usage_quantity, triggered_utc
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(threshold.tenant_id)
+            .bind(threshold.threshold_id)
+            .bind(threshold.subscription_id)
+            .bind(threshold.component_id)
+            .bind(cycle_id)
+            .bind(threshold_type.as_str())
+            .bind(threshold_quantity)
+            .bind(total)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to record usage alert: {}", e))
+            })?;
+            alerts.extend(alert);
+        }
 
-    /// Create a charge.
-    #[instrument(skip(self, input), fields(cycle_id = %input.cycle_id))]
-    pub async fn create_charge(&self, input: &CreateCharge) -> Result<Charge, AppError> {
+        Ok(alerts)
+    }
+
+    /// Create a usage threshold.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, subscription_id = %input.subscription_id))]
+    pub async fn create_usage_threshold(
+        &self,
+        input: &CreateUsageThreshold,
+    ) -> Result<UsageThreshold, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["create_charge"])
+            .with_label_values(&["create_usage_threshold"])
             .start_timer();
 
-        let charge_id = Uuid::new_v4();
-        let charge = sqlx::query_as::<_, Charge>(
+        let threshold = sqlx::query_as::<_, UsageThreshold>(
             r#"
-            INSERT INTO charges (charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            RETURNING charge_id, cycle_id, charge_type, description, quantity, unit_price, amount, is_prorated, proration_factor, component_id, metadata, created_utc
+            INSERT INTO usage_thresholds (threshold_id, tenant_id, subscription_id, component_id, threshold_type, quantity, percent_of_included)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING threshold_id, tenant_id, subscription_id, component_id, threshold_type, quantity, percent_of_included, is_active, created_utc
             "#,
         )
-        .bind(charge_id)
-        .bind(input.cycle_id)
-        .bind(input.charge_type.as_str())
-        .bind(&input.description)
-        .bind(input.quantity)
-        .bind(input.unit_price)
-        .bind(input.amount)
-        .bind(input.is_prorated)
-        .bind(input.proration_factor)
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.subscription_id)
         .bind(input.component_id)
-        .bind(&input.metadata)
+        .bind(input.threshold_type.as_str())
+        .bind(input.quantity)
+        .bind(input.percent_of_included)
         .fetch_one(&self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to create charge: {}", e)))?;
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to create usage threshold: {}", e))
+        })?;
 
         timer.observe_duration();
+        info!(threshold_id = %threshold.threshold_id, "Usage threshold created");
 
-        Ok(charge)
+        Ok(threshold)
     }
 
-    /// Get a charge by ID.
-    #[instrument(skip(self), fields(tenant_id = %tenant_id, charge_id = %charge_id))]
-    pub async fn get_charge(
+    /// List a subscription's active usage thresholds.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, subscription_id = %subscription_id))]
+    pub async fn list_usage_thresholds(
         &self,
         tenant_id: Uuid,
-        charge_id: Uuid,
-    ) -> Result<Option<Charge>, AppError> {
+        subscription_id: Uuid,
+    ) -> Result<Vec<UsageThreshold>, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_charge"])
+            .with_label_values(&["list_usage_thresholds"])
             .start_timer();
 
-        let charge = sqlx::query_as::<_, Charge>(
+        let thresholds = sqlx::query_as::<_, UsageThreshold>(
             r#"
-            SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.created_utc
-            FROM charges c
-            JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
-            JOIN subscriptions s ON bc.subscription_id = s.subscription_id
-            WHERE s.tenant_id = $1 AND c.charge_id = $2
+            SELECT threshold_id, tenant_id, subscription_id, component_id, threshold_type, quantity, percent_of_included, is_active, created_utc
+            FROM usage_thresholds
+            WHERE tenant_id = $1 AND subscription_id = $2 AND is_active = TRUE
+            ORDER BY created_utc, threshold_id
             "#,
         )
         .bind(tenant_id)
-        .bind(charge_id)
-        .fetch_optional(&self.pool)
+        .bind(subscription_id)
+        .fetch_all(&self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get charge: {}", e)))?;
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list usage thresholds: {}", e))
+        })?;
 
         timer.observe_duration();
 
-        Ok(charge)
+        Ok(thresholds)
     }
 
-    /// List charges for a billing cycle.
-    #[instrument(skip(self, filter), fields(cycle_id = %cycle_id))]
-    pub async fn list_charges(
+    /// Deactivate a usage threshold. Alerts it already recorded are kept.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, threshold_id = %threshold_id))]
+    pub async fn delete_usage_threshold(
         &self,
         tenant_id: Uuid,
-        cycle_id: Uuid,
-        filter: &ListChargesFilter,
-    ) -> Result<Vec<Charge>, AppError> {
+        threshold_id: Uuid,
+    ) -> Result<Option<UsageThreshold>, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["list_charges"])
+            .with_label_values(&["delete_usage_threshold"])
             .start_timer();
 
-        let limit = filter.page_size.clamp(1, 100) as i64;
-        let charge_type_str = filter.charge_type.map(|c| c.as_str().to_string());
-
-        let charges = if let Some(cursor) = filter.page_token {
-            sqlx::query_as::<_, Charge>(
-                r#"
-                SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.created_utc
-                FROM charges c
-                JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
-                JOIN subscriptions s ON bc.subscription_id = s.subscription_id
-                WHERE s.tenant_id = $1 AND c.cycle_id = $2
-                  AND ($3::varchar IS NULL OR c.charge_type = $3)
-                  AND c.charge_id > $4
-                ORDER BY c.charge_id
-                LIMIT $5
-                "#,
-            )
-            .bind(tenant_id)
-            .bind(cycle_id)
-            .bind(&charge_type_str)
-            .bind(cursor)
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await
-        } else {
-            sqlx::query_as::<_, Charge>(
-                r#"
-                SELECT c.charge_id, c.cycle_id, c.charge_type, c.description, c.quantity, c.unit_price, c.amount, c.is_prorated, c.proration_factor, c.component_id, c.metadata, c.created_utc
-                FROM charges c
-                JOIN billing_cycles bc ON c.cycle_id = bc.cycle_id
-                JOIN subscriptions s ON bc.subscription_id = s.subscription_id
-                WHERE s.tenant_id = $1 AND c.cycle_id = $2
-                  AND ($3::varchar IS NULL OR c.charge_type = $3)
-                ORDER BY c.charge_id
-                LIMIT $4
-                "#,
-            )
-            .bind(tenant_id)
-            .bind(cycle_id)
-            .bind(&charge_type_str)
-            .bind(limit)
-            .fetch_all(&self.pool)
-            .await
-        }
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list charges: {}", e)))?;
+        let threshold = sqlx::query_as::<_, UsageThreshold>(
+            r#"
+            UPDATE usage_thresholds
+            SET is_active = FALSE
+            WHERE tenant_id = $1 AND threshold_id = $2 AND is_active = TRUE
+            RETURNING threshold_id, tenant_id, subscription_id, component_id, threshold_type, quantity, percent_of_included, is_active, created_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(threshold_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to delete usage threshold: {}", e))
+        })?;
 
         timer.observe_duration();
 
-        Ok(charges)
+        Ok(threshold)
     }
 
-    // =========================================================================
-    // Usage Operations
-    // =========================================================================
-
-    /// Record usage with idempotency.
-    #[instrument(skip(self, input), fields(subscription_id = %input.subscription_id))]
-    pub async fn record_usage(&self, input: &RecordUsage) -> Result<UsageRecord, AppError> {
+    /// List the alerts a subscription's thresholds recorded, newest first.
+    #[instrument(skip(self, filter), fields(tenant_id = %tenant_id, subscription_id = %subscription_id))]
+    pub async fn list_usage_alerts(
+        &self,
+        tenant_id: Uuid,
+        subscription_id: Uuid,
+        filter: &ListUsageAlertsFilter,
+    ) -> Result<Vec<UsageAlert>, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["record_usage"])
+            .with_label_values(&["list_usage_alerts"])
             .start_timer();
 
-        // Check for existing record with same idempotency key
-        let existing = sqlx::query_as::<_, UsageRecord>(
-            r#"
-            SELECT record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, is_invoiced, metadata, created_utc
-            FROM usage_records
-            WHERE idempotency_key = $1
-            "#,
-        )
-        .bind(&input.idempotency_key)
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to check idempotency: {}", e)))?;
-
-        if let Some(record) = existing {
-            timer.observe_duration();
-            return Ok(record);
-        }
-
-        // Get current billing cycle
-        let cycle = self
-            .get_current_billing_cycle(input.subscription_id)
-            .await?;
+        let limit = filter.page_size.clamp(1, 100) as i64;
 
-        let record_id = Uuid::new_v4();
-        let record = sqlx::query_as::<_, UsageRecord>(
+        let alerts = sqlx::query_as::<_, UsageAlert>(
             r#"
-            INSERT INTO usage_records (record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING record_id, subscription_id, component_id, idempotency_key, quantity, timestamp, cycle_id, is_invoiced, metadata, created_utc
+            SELECT alert_id, tenant_id, threshold_id, subscription_id, component_id, cycle_id, threshold_type, threshold_quantity, usage_quantity, triggered_utc
+            FROM usage_alerts
+            WHERE tenant_id = $1 AND subscription_id = $2
+              AND ($3::uuid IS NULL OR cycle_id = $3)
+              AND ($4::uuid IS NULL OR (triggered_utc, alert_id) < (
+                  SELECT triggered_utc, alert_id FROM usage_alerts WHERE alert_id = $4
+              ))
+            ORDER BY triggered_utc DESC, alert_id DESC
+            LIMIT $5
             "#,
         )
-        .bind(record_id)
-        .bind(input.subscription_id)
-        .bind(input.component_id)
-        .bind(&input.idempotency_key)
-        .bind(input.quantity)
-        .bind(input.timestamp)
-        .bind(cycle.map(|c| c.cycle_id))
-        .bind(&input.metadata)
-        .fetch_one(&self.pool)
+        .bind(tenant_id)
+        .bind(subscription_id)
+        .bind(filter.cycle_id)
+        .bind(filter.page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
         .await
-        .map_err(|e| match e {
-            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
-                // Race condition: another request created the record
-                AppError::Conflict(anyhow::anyhow!("Duplicate idempotency key"))
-            }
-            _ => AppError::DatabaseError(anyhow::anyhow!("Failed to record usage: {}", e)),
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list usage alerts: {}", e))
         })?;
 
         timer.observe_duration();
 
-        Ok(record)
+        Ok(alerts)
     }
 
     /// Get a usage record by ID.
@@ -1157,32 +3453,54 @@ impl Database {
             .await?
             .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("Subscription not found")))?;
 
-        let components = self.get_usage_components(subscription.plan_id).await?;
+        let summaries = self
+            .summarize_usage(subscription.plan_id, subscription_id, actual_cycle_id)
+            .await?;
+
+        timer.observe_duration();
+
+        Ok(summaries)
+    }
+
+    /// Summarize a subscription's usage in a cycle against the usage
+    /// components of a plan, which need not be the subscription's own.
+    #[instrument(skip(self), fields(plan_id = %plan_id, subscription_id = %subscription_id))]
+    pub async fn summarize_usage(
+        &self,
+        plan_id: Uuid,
+        subscription_id: Uuid,
+        cycle_id: Uuid,
+    ) -> Result<Vec<UsageComponentSummary>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["summarize_usage"])
+            .start_timer();
+
+        let components = self.get_usage_components(plan_id).await?;
 
         let mut summaries = Vec::new();
 
         for component in components {
-            // Aggregate usage for this component in the cycle
+            // Running total for this component in the cycle
             let total: Option<Decimal> = sqlx::query_scalar(
                 r#"
-                SELECT COALESCE(SUM(quantity), 0)
-                FROM usage_records
+                SELECT quantity
+                FROM usage_counters
                 WHERE subscription_id = $1 AND component_id = $2 AND cycle_id = $3
                 "#,
             )
             .bind(subscription_id)
             .bind(component.component_id)
-            .bind(actual_cycle_id)
-            .fetch_one(&self.pool)
+            .bind(cycle_id)
+            .fetch_optional(&self.pool)
             .await
             .map_err(|e| {
-                AppError::DatabaseError(anyhow::anyhow!("Failed to aggregate usage: {}", e))
+                AppError::DatabaseError(anyhow::anyhow!("Failed to get usage counter: {}", e))
             })?;
 
             let total_quantity = total.unwrap_or(Decimal::ZERO);
             let included = Decimal::from(component.included_units);
             let billable_units = (total_quantity - included).max(Decimal::ZERO);
-            let amount = billable_units * component.unit_price;
+            let (amount, breakdown) = price_usage((&component).into(), billable_units);
 
             summaries.push(UsageComponentSummary {
                 component_id: component.component_id,
@@ -1191,6 +3509,8 @@ impl Database {
                 included_units: component.included_units,
                 billable_units,
                 amount,
+                pricing_model: component.pricing_model,
+                breakdown,
             });
         }
 
@@ -1225,6 +3545,36 @@ impl Database {
         Ok(result.rows_affected())
     }
 
+    /// List unbilled usage that arrived after its cycle was billed, per cycle
+    /// and component, oldest cycle first.
+    #[instrument(skip(self), fields(subscription_id = %subscription_id))]
+    pub async fn list_late_usage(&self, subscription_id: Uuid) -> Result<Vec<LateUsage>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_late_usage"])
+            .start_timer();
+
+        let usage = sqlx::query_as::<_, LateUsage>(
+            r#"
+            SELECT ur.cycle_id, bc.period_start, bc.period_end, ur.component_id,
+                   SUM(ur.quantity) AS quantity, uc.quantity AS cycle_total
+            FROM usage_records ur
+            JOIN billing_cycles bc ON bc.cycle_id = ur.cycle_id
+            JOIN usage_counters uc ON uc.cycle_id = ur.cycle_id AND uc.component_id = ur.component_id
+            WHERE ur.subscription_id = $1 AND ur.is_invoiced = FALSE AND bc.status <> 'pending'
+            GROUP BY ur.cycle_id, bc.period_start, bc.period_end, ur.component_id, uc.quantity
+            ORDER BY bc.period_start, ur.component_id
+            "#,
+        )
+        .bind(subscription_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list late usage: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(usage)
+    }
+
     // =========================================================================
     // Billing Run Operations
     // =========================================================================
@@ -1460,25 +3810,26 @@ impl Database {
         Ok(results)
     }
 
-    /// Find subscriptions due for billing.
-    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    /// Find active subscriptions whose current period has ended by `today`,
+    /// for one tenant or across all tenants.
+    #[instrument(skip(self))]
     pub async fn find_subscriptions_due_for_billing(
         &self,
-        tenant_id: Uuid,
+        tenant_id: Option<Uuid>,
+        today: NaiveDate,
     ) -> Result<Vec<Subscription>, AppError> {
         let timer = DB_QUERY_DURATION
             .with_label_values(&["find_subscriptions_due_for_billing"])
             .start_timer();
 
-        let today = Utc::now().date_naive();
-
         let subscriptions = sqlx::query_as::<_, Subscription>(
             r#"
-            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, metadata, created_utc, updated_utc
+            SELECT subscription_id, tenant_id, customer_id, plan_id, status, billing_anchor_day, start_date, end_date, trial_end_date, current_period_start, current_period_end, proration_mode, pending_plan_id, plan_version, metadata, created_utc, updated_utc
             FROM subscriptions
-            WHERE tenant_id = $1
+            WHERE ($1::uuid IS NULL OR tenant_id = $1)
               AND status = 'active'
               AND current_period_end <= $2
+            ORDER BY tenant_id, current_period_end
             "#,
         )
         .bind(tenant_id)
@@ -1491,10 +3842,78 @@ impl Database {
 
         Ok(subscriptions)
     }
+
+    /// Try to become the replica that runs the billing scheduler.
+    /// Returns `None` while another replica holds the lock.
+    #[instrument(skip(self))]
+    pub async fn try_acquire_scheduler_lock(&self) -> Result<Option<SchedulerLock>, AppError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let acquired: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+            .bind(SCHEDULER_LOCK_KEY)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to acquire scheduler lock: {}", e))
+            })?;
+
+        Ok(acquired.then_some(SchedulerLock { tx }))
+    }
+}
+
+/// Advisory lock key held by the replica running the billing scheduler.
+const SCHEDULER_LOCK_KEY: i64 = 0x6269_6c6c_7363_6864;
+
+/// Leader lock for the billing scheduler, held until released or dropped.
+pub struct SchedulerLock {
+    tx: Transaction<'static, Postgres>,
+}
+
+impl SchedulerLock {
+    /// Release the lock so the next tick can be taken by any replica.
+    pub async fn release(self) -> Result<(), AppError> {
+        self.tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to release scheduler lock: {}", e))
+        })
+    }
+}
+
+/// The billing cycle usage at `timestamp` belongs to: the subscription's
+/// cycle covering it, else its latest pending cycle. `cycles` are ordered
+/// newest first.
+fn usage_cycle(
+    cycles: &[BillingCycle],
+    subscription_id: Uuid,
+    timestamp: DateTime<Utc>,
+) -> Option<&BillingCycle> {
+    let day = timestamp.date_naive();
+    let mut subscription_cycles = cycles
+        .iter()
+        .filter(|c| c.subscription_id == subscription_id);
+
+    subscription_cycles
+        .clone()
+        .find(|c| c.period_start <= day && day < c.period_end)
+        .or_else(|| subscription_cycles.find(|c| c.status == BillingCycleStatus::Pending.as_str()))
+}
+
+/// Outcome for usage whose idempotency key was already recorded. Keys are
+/// global, so a key recorded for another subscription is rejected rather
+/// than returning that subscription's record.
+fn duplicate_usage(existing: &UsageRecord, input: &RecordUsage) -> RecordUsageOutcome {
+    if existing.subscription_id == input.subscription_id {
+        RecordUsageOutcome::Duplicate(existing.clone())
+    } else {
+        RecordUsageOutcome::Rejected(
+            "Idempotency key already used for another subscription".to_string(),
+        )
+    }
 }
 
 /// Calculate the end date for a billing period.
-fn calculate_period_end(start: NaiveDate, interval: BillingInterval, count: i32) -> NaiveDate {
+pub fn calculate_period_end(start: NaiveDate, interval: BillingInterval, count: i32) -> NaiveDate {
     use chrono::Months;
 
     match interval {
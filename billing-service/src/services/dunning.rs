@@ -0,0 +1,441 @@
+//! Dunning for billing cycles that failed to bill or whose invoice went unpaid.
+//!
+//! Each scheduler pass first checks invoiced cycles against the invoicing
+//! service, recovering cases whose invoice has been paid and opening cases for
+//! invoices past their due date. Every open case then follows its tenant's
+//! policy: reminders and billing retries on the policy's days, and once the
+//! grace period is over the final action is applied to the subscription.
+
+use crate::models::{
+    BillingCycle, BillingCycleStatus, DunningCase, DunningCaseStatus, DunningFinalAction,
+    DunningPolicy, DunningReason, Subscription, SubscriptionStatus,
+};
+use crate::services::{record_subscription_operation, BillingEngine, BillingError, Database};
+use chrono::NaiveDate;
+use service_core::error::AppError;
+use service_core::grpc::{InvoiceStatusProto, InvoicingClient, NotificationClient};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// What one dunning pass did.
+#[derive(Debug, Default)]
+pub struct DunningPass {
+    pub invoices_paid: usize,
+    pub cases_opened: usize,
+    pub reminders_sent: usize,
+    pub retries: usize,
+    pub recovered: usize,
+    pub exhausted: usize,
+}
+
+/// Payment state of a cycle's invoice.
+enum InvoiceState {
+    Paid,
+    Overdue,
+    Outstanding,
+}
+
+/// Works open dunning cases according to each tenant's policy.
+#[derive(Clone)]
+pub struct DunningManager {
+    db: Arc<Database>,
+    billing: BillingEngine,
+    notifications: Option<NotificationClient>,
+}
+
+impl DunningManager {
+    /// Create a DunningManager that records reminders without sending them.
+    pub fn new(db: Arc<Database>, billing: BillingEngine) -> Self {
+        Self {
+            db,
+            billing,
+            notifications: None,
+        }
+    }
+
+    /// Create a DunningManager that emails reminders through the notification
+    /// service to the subscription's `billing_email` metadata.
+    pub fn with_notifications(
+        db: Arc<Database>,
+        billing: BillingEngine,
+        notifications: NotificationClient,
+    ) -> Self {
+        Self {
+            db,
+            billing,
+            notifications: Some(notifications),
+        }
+    }
+
+    /// The engine failed cycles are retried through.
+    pub fn billing(&self) -> &BillingEngine {
+        &self.billing
+    }
+
+    /// Run one dunning pass as of `today`.
+    pub async fn run(&self, today: NaiveDate) -> Result<DunningPass, AppError> {
+        let mut pass = DunningPass::default();
+
+        if let Some(client) = self.billing.invoicing() {
+            for (tenant_id, cycle) in self.db.find_invoiced_cycles().await? {
+                if let Err(e) = self
+                    .sync_invoice(client, tenant_id, &cycle, today, &mut pass)
+                    .await
+                {
+                    tracing::warn!(
+                        cycle_id = %cycle.cycle_id,
+                        error = %e,
+                        "Failed to check cycle invoice"
+                    );
+                }
+            }
+        }
+
+        for case in self.db.list_open_dunning_cases().await? {
+            if let Err(e) = self.advance(&case, today, &mut pass).await {
+                tracing::warn!(case_id = %case.case_id, error = %e, "Failed to advance dunning case");
+            }
+        }
+
+        Ok(pass)
+    }
+
+    /// Retry a case now, outside its schedule: bill the cycle again, or check
+    /// whether its invoice has been paid. Returns the case as it stands after.
+    pub async fn retry(
+        &self,
+        case: &DunningCase,
+        today: NaiveDate,
+    ) -> Result<DunningCase, BillingError> {
+        match DunningReason::from_string(&case.reason) {
+            DunningReason::BillingFailed => {
+                let subscription = self.subscription(case).await?;
+                self.retry_billing(case, &subscription).await
+            }
+            DunningReason::InvoiceUnpaid => {
+                let cycle = self
+                    .db
+                    .get_billing_cycle(case.tenant_id, case.cycle_id)
+                    .await?;
+                let client = self.billing.invoicing();
+                if let (Some(client), Some(cycle)) = (client, cycle) {
+                    if let InvoiceState::Paid =
+                        invoice_state(client, case.tenant_id, &cycle, today).await?
+                    {
+                        return Ok(self.recover(case, Some(BillingCycleStatus::Paid)).await?);
+                    }
+                }
+                Ok(case.clone())
+            }
+        }
+    }
+
+    /// Mark a paid cycle as such, recovering its case, or open a case when
+    /// its invoice is overdue.
+    async fn sync_invoice(
+        &self,
+        client: &InvoicingClient,
+        tenant_id: Uuid,
+        cycle: &BillingCycle,
+        today: NaiveDate,
+        pass: &mut DunningPass,
+    ) -> Result<(), BillingError> {
+        let open_case = self
+            .db
+            .get_open_dunning_case_for_cycle(cycle.cycle_id)
+            .await?;
+
+        match invoice_state(client, tenant_id, cycle, today).await? {
+            InvoiceState::Paid => {
+                pass.invoices_paid += 1;
+                match open_case {
+                    Some(case) => {
+                        self.recover(&case, Some(BillingCycleStatus::Paid)).await?;
+                        pass.recovered += 1;
+                    }
+                    None => {
+                        self.db
+                            .update_billing_cycle_status(
+                                cycle.cycle_id,
+                                BillingCycleStatus::Paid,
+                                None,
+                            )
+                            .await?;
+                    }
+                }
+            }
+            InvoiceState::Overdue if open_case.is_none() => {
+                let Some(subscription) = self
+                    .db
+                    .get_subscription(tenant_id, cycle.subscription_id)
+                    .await?
+                else {
+                    return Ok(());
+                };
+                if self
+                    .db
+                    .open_dunning_case(
+                        &subscription,
+                        cycle.cycle_id,
+                        DunningReason::InvoiceUnpaid,
+                        None,
+                    )
+                    .await?
+                    .is_some()
+                {
+                    pass.cases_opened += 1;
+                }
+            }
+            InvoiceState::Overdue | InvoiceState::Outstanding => {}
+        }
+
+        Ok(())
+    }
+
+    /// Send due reminders, run a due retry and apply the final action once
+    /// the grace period is over.
+    async fn advance(
+        &self,
+        case: &DunningCase,
+        today: NaiveDate,
+        pass: &mut DunningPass,
+    ) -> Result<(), BillingError> {
+        let subscription = self.subscription(case).await?;
+
+        // Paused, cancelled or reactivated by hand while the case was open
+        if subscription.status != SubscriptionStatus::PastDue.as_str() {
+            let note = format!("Subscription is {}", subscription.status);
+            self.db
+                .close_dunning_case(
+                    case.case_id,
+                    DunningCaseStatus::Resolved,
+                    Some(&note),
+                    None,
+                    None,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let policy = self
+            .db
+            .get_dunning_policy(case.tenant_id)
+            .await?
+            .unwrap_or_else(|| DunningPolicy::default_for(case.tenant_id));
+        let age_days = case.age_days(today);
+
+        let mut case = case.clone();
+        let reminders_due = policy.reminders_due(age_days);
+        if reminders_due > case.reminders_sent {
+            self.send_reminder(&subscription, &case, &policy, age_days)
+                .await;
+            pass.reminders_sent += 1;
+            case = self
+                .db
+                .update_dunning_case_progress(case.case_id, case.retry_count, reminders_due, None)
+                .await?
+                .unwrap_or(case);
+        }
+
+        if case.reason == DunningReason::BillingFailed.as_str()
+            && policy.retry_due(case.retry_count, age_days)
+        {
+            pass.retries += 1;
+            case = self.retry_billing(&case, &subscription).await?;
+            if case.status == DunningCaseStatus::Recovered.as_str() {
+                pass.recovered += 1;
+                return Ok(());
+            }
+        }
+
+        if policy.grace_expired(age_days) {
+            self.exhaust(&case, &policy).await?;
+            pass.exhausted += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Bill the case's cycle again, recovering the case on success.
+    async fn retry_billing(
+        &self,
+        case: &DunningCase,
+        subscription: &Subscription,
+    ) -> Result<DunningCase, BillingError> {
+        match self.billing.bill_subscription(subscription).await {
+            // No pending cycle left means it was billed since the case opened
+            Ok(_) | Err(BillingError::NoPendingCycle) => Ok(self.recover(case, None).await?),
+            Err(e) => {
+                tracing::info!(case_id = %case.case_id, error = %e, "Dunning retry failed");
+                Ok(self
+                    .db
+                    .update_dunning_case_progress(
+                        case.case_id,
+                        case.retry_count + 1,
+                        case.reminders_sent,
+                        Some(&e.to_string()),
+                    )
+                    .await?
+                    .unwrap_or_else(|| case.clone()))
+            }
+        }
+    }
+
+    /// Close a case as recovered and make its subscription active again.
+    async fn recover(
+        &self,
+        case: &DunningCase,
+        cycle_status: Option<BillingCycleStatus>,
+    ) -> Result<DunningCase, AppError> {
+        let closed = self
+            .db
+            .close_dunning_case(
+                case.case_id,
+                DunningCaseStatus::Recovered,
+                None,
+                cycle_status,
+                Some(SubscriptionStatus::Active),
+            )
+            .await?;
+        Ok(closed.unwrap_or_else(|| case.clone()))
+    }
+
+    /// Apply the policy's final action. A cycle that never billed is marked
+    /// failed; an unpaid invoice stays with the invoicing service.
+    async fn exhaust(&self, case: &DunningCase, policy: &DunningPolicy) -> Result<(), AppError> {
+        let final_action = DunningFinalAction::from_string(&policy.final_action);
+        let subscription_status = match final_action {
+            DunningFinalAction::None => None,
+            DunningFinalAction::Pause => Some(SubscriptionStatus::Paused),
+            DunningFinalAction::Cancel => Some(SubscriptionStatus::Cancelled),
+        };
+        let cycle_status = (case.reason == DunningReason::BillingFailed.as_str())
+            .then_some(BillingCycleStatus::Failed);
+        let note = format!(
+            "Grace period ended, final action: {}",
+            final_action.as_str()
+        );
+
+        self.db
+            .close_dunning_case(
+                case.case_id,
+                DunningCaseStatus::Exhausted,
+                Some(&note),
+                cycle_status,
+                subscription_status,
+            )
+            .await?;
+
+        if let Some(status) = subscription_status {
+            record_subscription_operation(&case.tenant_id.to_string(), status.as_str());
+        }
+        tracing::info!(
+            case_id = %case.case_id,
+            final_action = final_action.as_str(),
+            "Dunning grace period ended"
+        );
+
+        Ok(())
+    }
+
+    /// Email a payment reminder. Without a notification client or a billing
+    /// email on the subscription the reminder is only logged.
+    async fn send_reminder(
+        &self,
+        subscription: &Subscription,
+        case: &DunningCase,
+        policy: &DunningPolicy,
+        age_days: i64,
+    ) {
+        let (Some(client), Some(email)) = (&self.notifications, subscription.billing_email())
+        else {
+            tracing::info!(
+                case_id = %case.case_id,
+                subscription_id = %subscription.subscription_id,
+                "Dunning reminder due, no notification channel"
+            );
+            return;
+        };
+
+        let days_left = i64::from(policy.grace_period_days) - age_days;
+        let body = match DunningReason::from_string(&case.reason) {
+            DunningReason::BillingFailed => {
+                "We were unable to bill your subscription for the current period."
+            }
+            DunningReason::InvoiceUnpaid => "Your latest invoice is past its due date.",
+        };
+        let body = format!(
+            "{} Please settle the outstanding amount within {} day(s) to keep your subscription active.",
+            body,
+            days_left.max(0)
+        );
+        let metadata = HashMap::from([
+            ("dunning_case_id".to_string(), case.case_id.to_string()),
+            (
+                "subscription_id".to_string(),
+                subscription.subscription_id.to_string(),
+            ),
+        ]);
+
+        if let Err(e) = client
+            .clone()
+            .send_email(
+                email.to_string(),
+                "Payment past due".to_string(),
+                Some(body),
+                None,
+                None,
+                None,
+                metadata,
+            )
+            .await
+        {
+            tracing::warn!(case_id = %case.case_id, error = %e, "Failed to send dunning reminder");
+        }
+    }
+
+    async fn subscription(&self, case: &DunningCase) -> Result<Subscription, BillingError> {
+        self.db
+            .get_subscription(case.tenant_id, case.subscription_id)
+            .await?
+            .ok_or_else(|| {
+                BillingError::Database(AppError::NotFound(anyhow::anyhow!(
+                    "Subscription not found"
+                )))
+            })
+    }
+}
+
+/// Look up a cycle's invoice. An issued invoice is overdue the day after its
+/// due date.
+async fn invoice_state(
+    client: &InvoicingClient,
+    tenant_id: Uuid,
+    cycle: &BillingCycle,
+    today: NaiveDate,
+) -> Result<InvoiceState, BillingError> {
+    let Some(invoice_id) = cycle.invoice_id else {
+        return Ok(InvoiceState::Outstanding);
+    };
+    let Some(invoice) = client
+        .get_invoice(&tenant_id.to_string(), &invoice_id.to_string())
+        .await?
+        .invoice
+    else {
+        return Ok(InvoiceState::Outstanding);
+    };
+
+    let past_due_date =
+        NaiveDate::parse_from_str(&invoice.due_date, "%Y-%m-%d").is_ok_and(|due| due < today);
+    let state = if invoice.status == InvoiceStatusProto::Paid as i32 {
+        InvoiceState::Paid
+    } else if invoice.status == InvoiceStatusProto::Overdue as i32
+        || (invoice.status == InvoiceStatusProto::Issued as i32 && past_due_date)
+    {
+        InvoiceState::Overdue
+    } else {
+        InvoiceState::Outstanding
+    };
+    Ok(state)
+}
@@ -29,6 +29,9 @@ pub static SUBSCRIPTION_OPERATIONS_TOTAL: OnceLock<IntCounterVec> = OnceLock::ne
 /// Usage records counter (per-tenant metering)
 pub static USAGE_RECORDS_TOTAL: OnceLock<IntCounterVec> = OnceLock::new();
 
+/// Usage thresholds reached counter (per-tenant metering)
+pub static USAGE_ALERTS_TOTAL: OnceLock<IntCounterVec> = OnceLock::new();
+
 /// Billing runs counter (per-tenant metering)
 pub static BILLING_RUNS_TOTAL: OnceLock<IntCounterVec> = OnceLock::new();
 
@@ -85,6 +88,18 @@ pub fn init_metrics() {
         .expect("Failed to register USAGE_RECORDS_TOTAL")
     });
 
+    // Usage thresholds reached
+    USAGE_ALERTS_TOTAL.get_or_init(|| {
+        register_int_counter_vec!(
+            opts!(
+                "billing_usage_alerts_total",
+                "Total usage thresholds reached by tenant and threshold type"
+            ),
+            &["tenant_id", "threshold_type"]
+        )
+        .expect("Failed to register USAGE_ALERTS_TOTAL")
+    });
+
     // Billing runs
     BILLING_RUNS_TOTAL.get_or_init(|| {
         register_int_counter_vec!(
@@ -188,6 +203,15 @@ pub fn record_usage_operation(tenant_id: &str, component_id: &str) {
     }
 }
 
+/// Record a usage threshold being reached.
+pub fn record_usage_alert(tenant_id: &str, threshold_type: &str) {
+    if let Some(counter) = USAGE_ALERTS_TOTAL.get() {
+        counter
+            .with_label_values(&[tenant_id, threshold_type])
+            .inc();
+    }
+}
+
 /// Record a billing run.
 pub fn record_billing_run(tenant_id: &str, run_type: &str, status: &str) {
     if let Some(counter) = BILLING_RUNS_TOTAL.get() {
@@ -1,11 +1,18 @@
 //! Services module for billing-service.
 
+pub mod billing;
 pub mod database;
+pub mod dunning;
 pub mod metrics;
+pub mod pricing;
+pub mod scheduler;
 
+pub use billing::{proration_charges, BillingEngine, BillingError, UpcomingCharges};
 pub use database::Database;
+pub use dunning::{DunningManager, DunningPass};
 pub use metrics::{
     get_metrics, init_metrics, record_billing_run, record_charge_amount, record_charge_created,
     record_error, record_grpc_request, record_grpc_request_duration, record_plan_operation,
-    record_subscription_operation, record_usage_operation,
+    record_subscription_operation, record_usage_alert, record_usage_operation,
 };
+pub use scheduler::{BillingScheduler, SchedulerTick};
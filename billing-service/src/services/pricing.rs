@@ -0,0 +1,338 @@
+//! Usage pricing for per-unit, graduated, volume and package components.
+//!
+//! Pricing applies to billable units, i.e. usage beyond the component's
+//! included units. Every step is returned as a breakdown line so a charge can
+//! be explained; the lines always sum to the priced amount.
+
+use crate::models::{CreateUsageComponent, PriceBreakdownLine, PricingModel, PricingTier};
+use rust_decimal::Decimal;
+
+/// Pricing terms of a usage component.
+#[derive(Debug, Clone, Copy)]
+pub struct PricingTerms<'a> {
+    pub pricing_model: PricingModel,
+    pub unit_name: &'a str,
+    pub unit_price: Decimal,
+    pub tiers: &'a [PricingTier],
+    pub package_size: Option<Decimal>,
+    pub minimum_amount: Option<Decimal>,
+    pub maximum_amount: Option<Decimal>,
+}
+
+impl<'a> From<&'a crate::models::UsageComponent> for PricingTerms<'a> {
+    fn from(c: &'a crate::models::UsageComponent) -> Self {
+        Self {
+            pricing_model: PricingModel::from_string(&c.pricing_model),
+            unit_name: &c.unit_name,
+            unit_price: c.unit_price,
+            tiers: &c.tiers.0,
+            package_size: c.package_size,
+            minimum_amount: c.minimum_amount,
+            maximum_amount: c.maximum_amount,
+        }
+    }
+}
+
+impl<'a> From<&'a CreateUsageComponent> for PricingTerms<'a> {
+    fn from(c: &'a CreateUsageComponent) -> Self {
+        Self {
+            pricing_model: c.pricing_model,
+            unit_name: &c.unit_name,
+            unit_price: c.unit_price,
+            tiers: &c.tiers,
+            package_size: c.package_size,
+            minimum_amount: c.minimum_amount,
+            maximum_amount: c.maximum_amount,
+        }
+    }
+}
+
+/// Price billable units, returning the amount and the lines that make it up.
+pub fn price_usage(
+    terms: PricingTerms<'_>,
+    billable_units: Decimal,
+) -> (Decimal, Vec<PriceBreakdownLine>) {
+    let mut lines = Vec::new();
+
+    let mut amount = match terms.pricing_model {
+        PricingModel::PerUnit => {
+            let amount = billable_units * terms.unit_price;
+            if billable_units > Decimal::ZERO {
+                lines.push(line(
+                    format!("{} {}", billable_units, terms.unit_name),
+                    billable_units,
+                    terms.unit_price,
+                ));
+            }
+            amount
+        }
+        PricingModel::Graduated => {
+            let mut lower = Decimal::ZERO;
+            for (index, tier) in terms.tiers.iter().enumerate() {
+                if billable_units <= lower {
+                    break;
+                }
+                let upper = tier
+                    .up_to
+                    .map_or(billable_units, |up| up.min(billable_units));
+                let label = tier_label(index, lower, tier.up_to);
+                lines.push(line(label.clone(), upper - lower, tier.unit_price));
+                if tier.flat_fee > Decimal::ZERO {
+                    lines.push(line(
+                        format!("{} flat fee", label),
+                        Decimal::ONE,
+                        tier.flat_fee,
+                    ));
+                }
+                lower = tier.up_to.unwrap_or(billable_units);
+            }
+            lines.iter().map(|l| l.amount).sum()
+        }
+        PricingModel::Volume => {
+            let mut lower = Decimal::ZERO;
+            for (index, tier) in terms.tiers.iter().enumerate() {
+                if billable_units <= Decimal::ZERO {
+                    break;
+                }
+                if tier.up_to.is_none_or(|up| billable_units <= up) {
+                    let label = format!("{} (all units)", tier_label(index, lower, tier.up_to));
+                    lines.push(line(label.clone(), billable_units, tier.unit_price));
+                    if tier.flat_fee > Decimal::ZERO {
+                        lines.push(line(
+                            format!("{} flat fee", label),
+                            Decimal::ONE,
+                            tier.flat_fee,
+                        ));
+                    }
+                    break;
+                }
+                lower = tier.up_to.unwrap_or(lower);
+            }
+            lines.iter().map(|l| l.amount).sum()
+        }
+        PricingModel::Package => {
+            let size = terms.package_size.unwrap_or(Decimal::ONE);
+            let packages = (billable_units / size).ceil();
+            if packages > Decimal::ZERO {
+                lines.push(line(
+                    format!("{} x {} {}", packages, size, terms.unit_name),
+                    packages,
+                    terms.unit_price,
+                ));
+            }
+            packages * terms.unit_price
+        }
+    };
+
+    if let Some(minimum) = terms.minimum_amount {
+        if amount < minimum {
+            lines.push(line(
+                "Minimum charge".to_string(),
+                Decimal::ONE,
+                minimum - amount,
+            ));
+            amount = minimum;
+        }
+    }
+    if let Some(maximum) = terms.maximum_amount {
+        if amount > maximum {
+            lines.push(line(
+                "Maximum charge cap".to_string(),
+                Decimal::ONE,
+                maximum - amount,
+            ));
+            amount = maximum;
+        }
+    }
+
+    (amount, lines)
+}
+
+/// Check a component's pricing terms before they are stored.
+pub fn validate_pricing(terms: PricingTerms<'_>) -> Result<(), String> {
+    if terms.unit_price < Decimal::ZERO {
+        return Err("unit_price cannot be negative".to_string());
+    }
+
+    match terms.pricing_model {
+        PricingModel::Graduated | PricingModel::Volume => {
+            let Some((last, bounded)) = terms.tiers.split_last() else {
+                return Err(format!(
+                    "{} pricing requires at least one tier",
+                    terms.pricing_model.as_str()
+                ));
+            };
+            if last.up_to.is_some() {
+                return Err("The last tier must have no up_to".to_string());
+            }
+            let mut previous = Decimal::ZERO;
+            for tier in bounded {
+                match tier.up_to {
+                    Some(up_to) if up_to > previous => previous = up_to,
+                    Some(_) => return Err("Tier up_to values must increase".to_string()),
+                    None => return Err("Only the last tier may have no up_to".to_string()),
+                }
+            }
+            if terms
+                .tiers
+                .iter()
+                .any(|t| t.unit_price < Decimal::ZERO || t.flat_fee < Decimal::ZERO)
+            {
+                return Err("Tier prices cannot be negative".to_string());
+            }
+        }
+        PricingModel::PerUnit | PricingModel::Package => {
+            if !terms.tiers.is_empty() {
+                return Err(format!(
+                    "{} pricing does not use tiers",
+                    terms.pricing_model.as_str()
+                ));
+            }
+        }
+    }
+
+    match (terms.pricing_model, terms.package_size) {
+        (PricingModel::Package, Some(size)) if size > Decimal::ZERO => {}
+        (PricingModel::Package, _) => {
+            return Err("package pricing requires a positive package_size".to_string())
+        }
+        (_, Some(_)) => return Err("package_size is only used by package pricing".to_string()),
+        (_, None) => {}
+    }
+
+    if terms.minimum_amount.is_some_and(|m| m < Decimal::ZERO)
+        || terms.maximum_amount.is_some_and(|m| m < Decimal::ZERO)
+    {
+        return Err("minimum_amount and maximum_amount cannot be negative".to_string());
+    }
+    if let (Some(minimum), Some(maximum)) = (terms.minimum_amount, terms.maximum_amount) {
+        if minimum > maximum {
+            return Err("minimum_amount cannot exceed maximum_amount".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+fn line(description: String, quantity: Decimal, unit_price: Decimal) -> PriceBreakdownLine {
+    PriceBreakdownLine {
+        description,
+        quantity,
+        unit_price,
+        amount: quantity * unit_price,
+    }
+}
+
+fn tier_label(index: usize, lower: Decimal, up_to: Option<Decimal>) -> String {
+    match up_to {
+        Some(up_to) => format!("Tier {}: {} - {}", index + 1, lower, up_to),
+        None => format!("Tier {}: over {}", index + 1, lower),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn tier(up_to: Option<&str>, unit_price: &str) -> PricingTier {
+        PricingTier {
+            up_to: up_to.map(dec),
+            unit_price: dec(unit_price),
+            flat_fee: Decimal::ZERO,
+        }
+    }
+
+    fn terms(pricing_model: PricingModel, tiers: &[PricingTier]) -> PricingTerms<'_> {
+        PricingTerms {
+            pricing_model,
+            unit_name: "calls",
+            unit_price: Decimal::ZERO,
+            tiers,
+            package_size: None,
+            minimum_amount: None,
+            maximum_amount: None,
+        }
+    }
+
+    fn api_tiers() -> Vec<PricingTier> {
+        vec![
+            tier(Some("1000"), "0"),
+            tier(Some("10000"), "0.01"),
+            tier(None, "0.005"),
+        ]
+    }
+
+    #[test]
+    fn graduated_prices_each_tier_separately() {
+        let tiers = api_tiers();
+        let (amount, lines) = price_usage(terms(PricingModel::Graduated, &tiers), dec("12000"));
+
+        // 1000 free + 9000 at 0.01 + 2000 at 0.005
+        assert_eq!(amount, dec("100"));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].description, "Tier 2: 1000 - 10000");
+        assert_eq!(lines[1].quantity, dec("9000"));
+        assert_eq!(lines[2].description, "Tier 3: over 10000");
+        assert_eq!(lines[2].amount, dec("10"));
+    }
+
+    #[test]
+    fn volume_prices_all_units_at_reached_tier() {
+        let tiers = api_tiers();
+        let (amount, lines) = price_usage(terms(PricingModel::Volume, &tiers), dec("12000"));
+
+        assert_eq!(amount, dec("60"));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].description, "Tier 3: over 10000 (all units)");
+    }
+
+    #[test]
+    fn package_charges_started_blocks() {
+        let mut package = terms(PricingModel::Package, &[]);
+        package.unit_price = dec("2");
+        package.package_size = Some(dec("100"));
+        let (amount, lines) = price_usage(package, dec("250"));
+
+        assert_eq!(amount, dec("6"));
+        assert_eq!(lines[0].quantity, dec("3"));
+    }
+
+    #[test]
+    fn minimum_and_cap_add_adjustment_lines() {
+        let mut per_unit = terms(PricingModel::PerUnit, &[]);
+        per_unit.unit_price = dec("1");
+        per_unit.minimum_amount = Some(dec("5"));
+        per_unit.maximum_amount = Some(dec("20"));
+
+        let (amount, lines) = price_usage(per_unit, dec("2"));
+        assert_eq!(amount, dec("5"));
+        assert_eq!(lines[1].amount, dec("3"));
+
+        let (amount, lines) = price_usage(per_unit, dec("30"));
+        assert_eq!(amount, dec("20"));
+        assert_eq!(lines[1].amount, dec("-10"));
+        assert_eq!(lines.iter().map(|l| l.amount).sum::<Decimal>(), amount);
+    }
+
+    #[test]
+    fn validate_rejects_bad_tiers() {
+        let unbounded_first = vec![tier(None, "1"), tier(None, "2")];
+        assert!(validate_pricing(terms(PricingModel::Graduated, &unbounded_first)).is_err());
+
+        let decreasing = vec![
+            tier(Some("100"), "1"),
+            tier(Some("50"), "1"),
+            tier(None, "1"),
+        ];
+        assert!(validate_pricing(terms(PricingModel::Volume, &decreasing)).is_err());
+
+        assert!(validate_pricing(terms(PricingModel::Graduated, &[])).is_err());
+        assert!(validate_pricing(terms(PricingModel::Package, &[])).is_err());
+        assert!(validate_pricing(terms(PricingModel::Volume, &api_tiers())).is_ok());
+    }
+}
@@ -0,0 +1,175 @@
+//! Background scheduler for credit and trial expiry, billing, dunning and
+//! period-end cancellations.
+
+use crate::models::{BillingRun, BillingRunType, Subscription};
+use crate::services::{
+    record_subscription_operation, BillingEngine, Database, DunningManager, DunningPass,
+};
+use chrono::NaiveDate;
+use service_core::error::AppError;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// What one scheduler pass did.
+#[derive(Debug, Default)]
+pub struct SchedulerTick {
+    pub credits_expired: usize,
+    pub trials_ended: usize,
+    pub billing_runs: Vec<BillingRun>,
+    pub renewed: usize,
+    pub dunning: DunningPass,
+    pub cancelled: usize,
+}
+
+/// Runs due subscription work for every tenant.
+pub struct BillingScheduler {
+    db: Arc<Database>,
+    billing: BillingEngine,
+    dunning: DunningManager,
+}
+
+impl BillingScheduler {
+    /// Create a new BillingScheduler.
+    pub fn new(db: Arc<Database>) -> Self {
+        Self::with_billing_engine(db.clone(), BillingEngine::new(db))
+    }
+
+    /// Create a new BillingScheduler billing through the given engine.
+    pub fn with_billing_engine(db: Arc<Database>, billing: BillingEngine) -> Self {
+        Self::with_dunning(db.clone(), DunningManager::new(db, billing))
+    }
+
+    /// Create a new BillingScheduler working dunning cases through the given
+    /// manager and billing through its engine.
+    pub fn with_dunning(db: Arc<Database>, dunning: DunningManager) -> Self {
+        Self {
+            db,
+            billing: dunning.billing().clone(),
+            dunning,
+        }
+    }
+
+    /// Run one pass as of `today`. Returns `None` without doing anything when
+    /// another replica holds the scheduler lock.
+    pub async fn run_once(&self, today: NaiveDate) -> Result<Option<SchedulerTick>, AppError> {
+        let Some(lock) = self.db.try_acquire_scheduler_lock().await? else {
+            tracing::debug!("Billing scheduler lock held by another replica");
+            return Ok(None);
+        };
+
+        let tick = self.tick(today).await;
+        lock.release().await?;
+        tick.map(Some)
+    }
+
+    /// Expired credit is written off before anything is billed against it.
+    /// Trials end next so their first paid period can be billed, then due
+    /// subscriptions are billed and renewed. Dunning then works the cycles
+    /// that failed to bill or went unpaid, and ended subscriptions cancel last.
+    async fn tick(&self, today: NaiveDate) -> Result<SchedulerTick, AppError> {
+        let mut tick = SchedulerTick {
+            credits_expired: self.db.expire_credit_grants().await?.len(),
+            ..Default::default()
+        };
+
+        for subscription in self.db.find_trials_ending(today).await? {
+            match self.db.end_trial(&subscription).await {
+                Ok(_) => {
+                    tick.trials_ended += 1;
+                    record_subscription_operation(
+                        &subscription.tenant_id.to_string(),
+                        "trial_ended",
+                    );
+                }
+                Err(e) => tracing::warn!(
+                    subscription_id = %subscription.subscription_id,
+                    error = %e,
+                    "Failed to end trial"
+                ),
+            }
+        }
+
+        // A period already billed by a manual run only needs renewing
+        let mut due_by_tenant: BTreeMap<Uuid, Vec<Subscription>> = BTreeMap::new();
+        for subscription in self
+            .db
+            .find_subscriptions_due_for_billing(None, today)
+            .await?
+        {
+            let pending = self
+                .db
+                .get_current_billing_cycle(subscription.subscription_id)
+                .await?;
+            if pending.is_some() {
+                due_by_tenant
+                    .entry(subscription.tenant_id)
+                    .or_default()
+                    .push(subscription);
+            } else {
+                self.renew(&subscription, &mut tick).await;
+            }
+        }
+
+        for (tenant_id, subscriptions) in due_by_tenant {
+            let (billing_run, results) = match self
+                .billing
+                .run(tenant_id, BillingRunType::Scheduled, &subscriptions)
+                .await
+            {
+                Ok(run) => run,
+                Err(e) => {
+                    tracing::warn!(tenant_id = %tenant_id, error = %e, "Scheduled billing run failed");
+                    continue;
+                }
+            };
+
+            for (subscription, result) in subscriptions.iter().zip(&results) {
+                if result.status == "success" {
+                    self.renew(subscription, &mut tick).await;
+                }
+            }
+
+            tick.billing_runs.push(billing_run);
+        }
+
+        tick.dunning = self.dunning.run(today).await?;
+
+        for subscription in self.db.cancel_ended_subscriptions(today).await? {
+            tick.cancelled += 1;
+            record_subscription_operation(&subscription.tenant_id.to_string(), "cancelled");
+        }
+
+        tracing::info!(
+            credits_expired = tick.credits_expired,
+            trials_ended = tick.trials_ended,
+            billing_runs = tick.billing_runs.len(),
+            renewed = tick.renewed,
+            dunning_recovered = tick.dunning.recovered,
+            dunning_exhausted = tick.dunning.exhausted,
+            cancelled = tick.cancelled,
+            "Billing scheduler pass complete"
+        );
+
+        Ok(tick)
+    }
+
+    /// Move a billed subscription to its next period, unless it ends with
+    /// this one; those are cancelled at the end of the pass.
+    async fn renew(&self, subscription: &Subscription, tick: &mut SchedulerTick) {
+        let ends_now = subscription
+            .end_date
+            .is_some_and(|end| end <= subscription.current_period_end);
+        if ends_now {
+            return;
+        }
+        match self.db.renew_subscription(subscription).await {
+            Ok(_) => tick.renewed += 1,
+            Err(e) => tracing::warn!(
+                subscription_id = %subscription.subscription_id,
+                error = %e,
+                "Failed to renew subscription"
+            ),
+        }
+    }
+}
@@ -5,13 +5,16 @@ use crate::grpc::{
     proto::{billing_service_server::BillingServiceServer, FILE_DESCRIPTOR_SET},
     trace_context_interceptor, BillingServiceImpl, CapabilityChecker,
 };
-use crate::services::{get_metrics, init_metrics, Database};
+use crate::services::{
+    get_metrics, init_metrics, BillingEngine, BillingScheduler, Database, DunningManager,
+};
 use axum::{
     extract::State, http::StatusCode, middleware, response::IntoResponse, routing::get, Json,
     Router,
 };
 use serde_json::json;
 use service_core::error::AppError;
+use service_core::grpc::{InvoicingClient, NotificationClient};
 use service_core::middleware::metrics::metrics_middleware;
 use service_core::middleware::tracing::request_id_middleware;
 use std::net::SocketAddr;
@@ -27,6 +30,8 @@ pub struct AppState {
     pub config: BillingConfig,
     pub db: Arc<Database>,
     pub capability_checker: Arc<CapabilityChecker>,
+    pub invoicing_client: Option<Arc<InvoicingClient>>,
+    pub notification_client: Option<NotificationClient>,
 }
 
 /// State for health check endpoints.
@@ -149,10 +154,51 @@ impl Application {
                 ))
             })?);
 
+        // Try to connect to invoicing service (optional - graceful degradation if unavailable)
+        let invoicing_client = match InvoicingClient::connect(&config.invoicing_service.url).await {
+            Ok(client) => {
+                tracing::info!(
+                    invoicing_service_url = %config.invoicing_service.url,
+                    "Connected to invoicing service"
+                );
+                Some(Arc::new(client))
+            }
+            Err(e) => {
+                tracing::warn!(
+                    invoicing_service_url = %config.invoicing_service.url,
+                    error = %e,
+                    "Failed to connect to invoicing service - invoice creation disabled"
+                );
+                None
+            }
+        };
+
+        // Try to connect to notification service (optional - dunning reminders are only logged without it)
+        let notification_client =
+            match NotificationClient::connect(&config.notification_service.url).await {
+                Ok(client) => {
+                    tracing::info!(
+                        notification_service_url = %config.notification_service.url,
+                        "Connected to notification service"
+                    );
+                    Some(client)
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        notification_service_url = %config.notification_service.url,
+                        error = %e,
+                        "Failed to connect to notification service - dunning reminders disabled"
+                    );
+                    None
+                }
+            };
+
         let state = AppState {
             config: config.clone(),
             db,
             capability_checker,
+            invoicing_client,
+            notification_client,
         };
 
         // Bind HTTP listener
@@ -217,9 +263,52 @@ impl Application {
             .layer(middleware::from_fn(request_id_middleware))
             .with_state(health_state);
 
+        // Billing engine shared by the billing RPCs and the scheduler
+        let billing = match &self.state.invoicing_client {
+            Some(client) => BillingEngine::with_invoicing(
+                self.state.db.clone(),
+                client.clone(),
+                self.state.config.invoicing_service.issue_invoices,
+            ),
+            None => BillingEngine::new(self.state.db.clone()),
+        };
+
         // Build gRPC server
-        let billing_service =
-            BillingServiceImpl::new(self.state.db.clone(), self.state.capability_checker.clone());
+        let mut billing_service = BillingServiceImpl::with_billing_engine(
+            self.state.db.clone(),
+            self.state.capability_checker.clone(),
+            billing.clone(),
+        );
+        if let Some(client) = &self.state.notification_client {
+            billing_service = billing_service.with_notifications(client.clone());
+        }
+
+        // Background scheduler ending trials, billing due subscriptions,
+        // working dunning cases and applying period-end cancellations.
+        // Replicas race for a leader lock on every tick, so only one of them
+        // does the work.
+        let scheduler_interval = self.state.config.scheduler.interval_secs;
+        if scheduler_interval > 0 {
+            let dunning = match &self.state.notification_client {
+                Some(client) => DunningManager::with_notifications(
+                    self.state.db.clone(),
+                    billing,
+                    client.clone(),
+                ),
+                None => DunningManager::new(self.state.db.clone(), billing),
+            };
+            let scheduler = BillingScheduler::with_dunning(self.state.db.clone(), dunning);
+            tokio::spawn(async move {
+                let mut interval =
+                    tokio::time::interval(std::time::Duration::from_secs(scheduler_interval));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = scheduler.run_once(chrono::Utc::now().date_naive()).await {
+                        tracing::warn!(error = %e, "Billing scheduler pass failed");
+                    }
+                }
+            });
+        }
 
         // gRPC health service
         let (mut health_reporter, grpc_health_service) = tonic_health::server::health_reporter();
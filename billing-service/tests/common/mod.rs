@@ -4,7 +4,10 @@
 
 #![allow(dead_code)]
 
-use billing_service::config::{AuthConfig, BillingConfig, DatabaseConfig, InvoicingServiceConfig};
+use billing_service::config::{
+    AuthConfig, BillingConfig, DatabaseConfig, InvoicingServiceConfig, NotificationServiceConfig,
+    SchedulerConfig,
+};
 use billing_service::services::{init_metrics, Database};
 use billing_service::startup::Application;
 use service_core::config::Config as CoreConfig;
@@ -85,16 +88,23 @@ impl TestApp {
             log_level: "warn".to_string(),
             otlp_endpoint: None,
             database: DatabaseConfig {
-                url: db_url_with_schema,
+                url: db_url_with_schema.clone(),
                 max_connections: 5,
                 min_connections: 1,
             },
             invoicing_service: InvoicingServiceConfig {
                 url: "http://localhost:50053".to_string(), // May not be available in tests
+                issue_invoices: false,
+            },
+            notification_service: NotificationServiceConfig {
+                url: "http://localhost:50054".to_string(), // May not be available in tests
             },
             auth: AuthConfig {
                 auth_service_endpoint: "".to_string(), // Empty = disabled mode for tests
             },
+            scheduler: SchedulerConfig {
+                interval_secs: 0, // Tests drive the scheduler directly
+            },
         };
 
         let app = Application::build(config)
@@ -103,7 +113,7 @@ impl TestApp {
 
         let http_port = app.http_port();
         let grpc_port = app.grpc_port();
-        let db = Database::new(&get_test_database_url(), 5, 1)
+        let db = Database::new(&db_url_with_schema, 5, 1)
             .await
             .expect("Failed to create test database");
 
@@ -0,0 +1,439 @@
+//! Coupon, promotion code and discount integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+async fn subscribe(client: &mut Client) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Discounted Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "40.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id,
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+fn coupon_request(name: &str) -> CreateCouponRequest {
+    CreateCouponRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        name: name.to_string(),
+        discount_type: 1, // Percent off
+        percent_off: "25".to_string(),
+        amount_off: "".to_string(),
+        currency: "".to_string(),
+        duration: 1, // Once
+        duration_cycles: 0,
+        max_redemptions: 0,
+        expires_at: None,
+        metadata: "".to_string(),
+    }
+}
+
+async fn create_coupon(client: &mut Client, request: CreateCouponRequest) -> Coupon {
+    client
+        .create_coupon(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner()
+        .coupon
+        .unwrap()
+}
+
+fn apply_request(subscription_id: &str) -> ApplyDiscountRequest {
+    ApplyDiscountRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        subscription_id: subscription_id.to_string(),
+        coupon_id: "".to_string(),
+        promotion_code: "".to_string(),
+        charge_id: "".to_string(),
+    }
+}
+
+/// Bill the pending cycle and return its charges.
+async fn bill(app: &TestApp, client: &mut Client, subscription_id: &str) -> Vec<Charge> {
+    let cycle = app
+        .db
+        .get_current_billing_cycle(Uuid::parse_str(subscription_id).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListChargesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: cycle.cycle_id.to_string(),
+            charge_type: 0,
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_charges(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .charges
+}
+
+/// Open the subscription's next billing cycle.
+async fn renew(app: &TestApp, subscription_id: &str) {
+    let subscription = app
+        .db
+        .get_subscription(app.tenant_id(), Uuid::parse_str(subscription_id).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    app.db.renew_subscription(&subscription).await.unwrap();
+}
+
+fn discount_charges(charges: &[Charge]) -> Vec<&Charge> {
+    charges
+        .iter()
+        .filter(|c| c.charge_type == ChargeType::Discount as i32)
+        .collect()
+}
+
+#[tokio::test]
+async fn create_coupon_validates_terms() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let mut request = coupon_request("Too generous");
+    request.percent_off = "150".to_string();
+    let status = client
+        .create_coupon(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let mut request = coupon_request("No currency");
+    request.discount_type = 2; // Amount off
+    request.amount_off = "5.00".to_string();
+    let status = client
+        .create_coupon(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let mut request = coupon_request("No cycle count");
+    request.duration = 2; // Repeating
+    let status = client
+        .create_coupon(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn redemption_limits_expiry_and_currency_are_enforced() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let first = subscribe(&mut client).await;
+    let second = subscribe(&mut client).await;
+
+    let mut request = coupon_request("Single use");
+    request.max_redemptions = 1;
+    let single_use = create_coupon(&mut client, request).await;
+
+    let mut apply = apply_request(&first.subscription_id);
+    apply.coupon_id = single_use.coupon_id.clone();
+    client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap();
+
+    let mut apply = apply_request(&second.subscription_id);
+    apply.coupon_id = single_use.coupon_id.clone();
+    let status = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Expired promotion code
+    let coupon = create_coupon(&mut client, coupon_request("Spring sale")).await;
+    let request = CreatePromotionCodeRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        coupon_id: coupon.coupon_id.clone(),
+        code: "SPRING".to_string(),
+        max_redemptions: 0,
+        expires_at: Some(prost_types::Timestamp {
+            seconds: 1_600_000_000,
+            nanos: 0,
+        }),
+    };
+    client
+        .create_promotion_code(with_tenant(TEST_TENANT_ID, request.clone()))
+        .await
+        .unwrap();
+    let status = client
+        .create_promotion_code(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+
+    let mut apply = apply_request(&second.subscription_id);
+    apply.promotion_code = "SPRING".to_string();
+    let status = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Amount-off coupons must match the plan currency
+    let mut request = coupon_request("Euro credit");
+    request.discount_type = 2;
+    request.amount_off = "5.00".to_string();
+    request.currency = "EUR".to_string();
+    let euro = create_coupon(&mut client, request).await;
+    let mut apply = apply_request(&second.subscription_id);
+    apply.coupon_id = euro.coupon_id;
+    let status = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Archived coupons can no longer be redeemed
+    client
+        .archive_coupon(with_tenant(
+            TEST_TENANT_ID,
+            ArchiveCouponRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                coupon_id: coupon.coupon_id.clone(),
+            },
+        ))
+        .await
+        .unwrap();
+    let mut apply = apply_request(&second.subscription_id);
+    apply.coupon_id = coupon.coupon_id;
+    let status = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn repeating_discount_applies_for_its_cycles() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let subscription = subscribe(&mut client).await;
+
+    let mut request = coupon_request("Quarter off, two cycles");
+    request.duration = 2; // Repeating
+    request.duration_cycles = 2;
+    let coupon = create_coupon(&mut client, request).await;
+    client
+        .create_promotion_code(with_tenant(
+            TEST_TENANT_ID,
+            CreatePromotionCodeRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                coupon_id: coupon.coupon_id.clone(),
+                code: "SAVE25".to_string(),
+                max_redemptions: 5,
+                expires_at: None,
+            },
+        ))
+        .await
+        .unwrap();
+
+    let mut apply = apply_request(&subscription.subscription_id);
+    apply.promotion_code = "SAVE25".to_string();
+    let discount = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap()
+        .into_inner()
+        .discount
+        .unwrap();
+    assert_eq!(discount.coupon_id, coupon.coupon_id);
+    assert!(!discount.promotion_code_id.is_empty());
+
+    for _ in 0..2 {
+        let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+        let discounts = discount_charges(&charges);
+        assert_eq!(discounts.len(), 1);
+        assert_eq!(discounts[0].amount, "-10.0000");
+        assert_eq!(discounts[0].discount_id, discount.discount_id);
+        renew(&app, &subscription.subscription_id).await;
+    }
+
+    // The third cycle is billed in full
+    let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+    assert!(discount_charges(&charges).is_empty());
+
+    let discounts = client
+        .list_discounts(with_tenant(
+            TEST_TENANT_ID,
+            ListDiscountsRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                subscription_id: subscription.subscription_id.clone(),
+                include_ended: true,
+            },
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .discounts;
+    assert_eq!(discounts.len(), 1);
+    assert_eq!(discounts[0].cycles_applied, 2);
+    assert!(!discounts[0].is_active);
+
+    let coupon = client
+        .get_coupon(with_tenant(
+            TEST_TENANT_ID,
+            GetCouponRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                coupon_id: coupon.coupon_id,
+            },
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(coupon.coupon.unwrap().times_redeemed, 1);
+    assert_eq!(coupon.promotion_codes[0].times_redeemed, 1);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn charge_discount_is_capped_and_removed_discount_stops() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let subscription = subscribe(&mut client).await;
+
+    let setup_fee = client
+        .create_one_time_charge(with_tenant(
+            TEST_TENANT_ID,
+            CreateOneTimeChargeRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                subscription_id: subscription.subscription_id.clone(),
+                description: "Setup fee".to_string(),
+                amount: "15.00".to_string(),
+                metadata: "".to_string(),
+            },
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .charge
+        .unwrap();
+
+    // Twenty off the setup fee only, capped at the fee
+    let mut request = coupon_request("Free setup");
+    request.discount_type = 2;
+    request.amount_off = "20.00".to_string();
+    request.currency = "USD".to_string();
+    let free_setup = create_coupon(&mut client, request).await;
+    let mut apply = apply_request(&subscription.subscription_id);
+    apply.coupon_id = free_setup.coupon_id;
+    apply.charge_id = setup_fee.charge_id.clone();
+    client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap();
+
+    // Ten percent off every cycle, applied to what remains
+    let mut request = coupon_request("Loyalty");
+    request.percent_off = "10".to_string();
+    request.duration = 3; // Forever
+    let loyalty = create_coupon(&mut client, request).await;
+    let mut apply = apply_request(&subscription.subscription_id);
+    apply.coupon_id = loyalty.coupon_id;
+    let loyalty_discount = client
+        .apply_discount(with_tenant(TEST_TENANT_ID, apply))
+        .await
+        .unwrap()
+        .into_inner()
+        .discount
+        .unwrap();
+
+    let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+    let discounts = discount_charges(&charges);
+    assert_eq!(discounts.len(), 2);
+    let (fee_discount, cycle_discount): (Vec<_>, Vec<_>) = discounts
+        .into_iter()
+        .partition(|c| c.metadata.contains(&setup_fee.charge_id));
+    assert_eq!(fee_discount[0].amount, "-15.0000");
+    assert_eq!(cycle_discount[0].amount, "-4.0000");
+    let total: f64 = charges
+        .iter()
+        .map(|c| c.amount.parse::<f64>().unwrap())
+        .sum();
+    assert_eq!(total, 36.0);
+
+    client
+        .remove_discount(with_tenant(
+            TEST_TENANT_ID,
+            RemoveDiscountRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                discount_id: loyalty_discount.discount_id,
+            },
+        ))
+        .await
+        .unwrap();
+
+    renew(&app, &subscription.subscription_id).await;
+    let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+    assert!(discount_charges(&charges).is_empty());
+
+    app.cleanup().await;
+}
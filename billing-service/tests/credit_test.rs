@@ -0,0 +1,325 @@
+//! Customer credit integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+async fn subscribe(client: &mut Client) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Prepaid Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "40.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id,
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+fn grant_request(amount: &str, priority: i32, description: &str) -> GrantCreditRequest {
+    GrantCreditRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        customer_id: TEST_CUSTOMER_ID.to_string(),
+        amount: amount.to_string(),
+        currency: "USD".to_string(),
+        priority,
+        description: description.to_string(),
+        ledger_account_id: "".to_string(),
+        expires_at: None,
+    }
+}
+
+async fn grant(client: &mut Client, request: GrantCreditRequest) -> CreditGrant {
+    client
+        .grant_credit(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner()
+        .grant
+        .unwrap()
+}
+
+async fn balance(client: &mut Client) -> GetCreditBalanceResponse {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetCreditBalanceRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            currency: "".to_string(),
+        },
+    );
+    client
+        .get_credit_balance(request)
+        .await
+        .unwrap()
+        .into_inner()
+}
+
+async fn transactions(client: &mut Client) -> Vec<CreditTransaction> {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListCreditTransactionsRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            grant_id: "".to_string(),
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_credit_transactions(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .transactions
+}
+
+/// Bill the pending cycle and return its charges.
+async fn bill(app: &TestApp, client: &mut Client, subscription_id: &str) -> Vec<Charge> {
+    let cycle = app
+        .db
+        .get_current_billing_cycle(Uuid::parse_str(subscription_id).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListChargesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: cycle.cycle_id.to_string(),
+            charge_type: 0,
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_charges(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .charges
+}
+
+#[tokio::test]
+async fn grant_credit_validates_input() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let mut request = grant_request("0", 0, "Nothing");
+    let status = client
+        .grant_credit(with_tenant(TEST_TENANT_ID, request.clone()))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    request.amount = "10.00".to_string();
+    request.priority = 101;
+    let status = client
+        .grant_credit(with_tenant(TEST_TENANT_ID, request.clone()))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    request.priority = 0;
+    request.expires_at = Some(prost_types::Timestamp {
+        seconds: chrono::Utc::now().timestamp() - 60,
+        nanos: 0,
+    });
+    let status = client
+        .grant_credit(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn billing_consumes_credit_by_priority() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let subscription = subscribe(&mut client).await;
+    let promotional = grant(
+        &mut client,
+        grant_request("30.00", 10, "Promotional credit"),
+    )
+    .await;
+    let prepaid = grant(&mut client, grant_request("25.00", 0, "Prepaid top-up")).await;
+    assert_eq!(promotional.remaining, "30.0000");
+
+    let response = balance(&mut client).await;
+    assert_eq!(response.balances.len(), 1);
+    assert_eq!(response.balances[0].available, "55.0000");
+    assert_eq!(response.grants[0].grant_id, prepaid.grant_id);
+
+    // The prepaid grant goes first, then the promotional grant covers the rest
+    let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+    assert_eq!(charges.iter().filter(|c| c.charge_type == 6).count(), 2);
+    let prepaid_charge = charges
+        .iter()
+        .find(|c| c.description == "Credit applied - Prepaid top-up")
+        .unwrap();
+    let promotional_charge = charges
+        .iter()
+        .find(|c| c.description == "Credit applied - Promotional credit")
+        .unwrap();
+    assert_eq!(prepaid_charge.amount, "-25.0000");
+    assert_eq!(promotional_charge.amount, "-15.0000");
+
+    let response = balance(&mut client).await;
+    assert_eq!(response.balances[0].available, "15.0000");
+    assert_eq!(response.grants.len(), 1);
+    assert_eq!(response.grants[0].grant_id, promotional.grant_id);
+
+    let history = transactions(&mut client).await;
+    let consumed: Vec<_> = history
+        .iter()
+        .filter(|t| t.transaction_type == 2) // CONSUME
+        .collect();
+    assert_eq!(history.len(), 4);
+    assert_eq!(consumed.len(), 2);
+    assert_eq!(consumed[0].grant_id, prepaid.grant_id);
+    assert_eq!(consumed[0].amount, "-25.0000");
+    assert_eq!(consumed[0].cycle_id, prepaid_charge.cycle_id);
+    assert_eq!(consumed[0].charge_id, prepaid_charge.charge_id);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn expired_credit_is_not_consumed() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let subscription = subscribe(&mut client).await;
+    let mut request = grant_request("20.00", 0, "Trial credit");
+    request.ledger_account_id = "dddddddd-dddd-dddd-dddd-dddddddddddd".to_string();
+    request.expires_at = Some(prost_types::Timestamp {
+        seconds: chrono::Utc::now().timestamp() + 3600,
+        nanos: 0,
+    });
+    let expiring = grant(&mut client, request).await;
+
+    // Credit in another currency is never drawn on a USD cycle
+    let mut request = grant_request("50.00", 0, "Euro credit");
+    request.currency = "EUR".to_string();
+    grant(&mut client, request).await;
+
+    sqlx::query(
+        "UPDATE credit_grants SET expires_utc = NOW() - INTERVAL '1 minute' WHERE grant_id = $1",
+    )
+    .bind(Uuid::parse_str(&expiring.grant_id).unwrap())
+    .execute(app.db.pool())
+    .await
+    .unwrap();
+
+    let expired = app.db.expire_credit_grants().await.unwrap();
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].amount.to_string(), "-20.0000");
+    assert_eq!(
+        expired[0].ledger_account_id.unwrap().to_string(),
+        "dddddddd-dddd-dddd-dddd-dddddddddddd"
+    );
+
+    let charges = bill(&app, &mut client, &subscription.subscription_id).await;
+    assert!(charges.iter().all(|c| c.charge_type != 6));
+
+    let response = balance(&mut client).await;
+    assert_eq!(response.balances.len(), 1);
+    assert_eq!(response.balances[0].currency, "EUR");
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn concurrent_billing_spends_credit_once() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let subscription = subscribe(&mut client).await;
+    grant(&mut client, grant_request("100.00", 0, "Prepaid top-up")).await;
+
+    let subscription = app
+        .db
+        .get_subscription(
+            Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+            Uuid::parse_str(&subscription.subscription_id).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    let cycle = app
+        .db
+        .get_current_billing_cycle(subscription.subscription_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Both runs saw no credit charges; only one may draw on the grant
+    let amount_due = rust_decimal::Decimal::new(40, 0);
+    let (first, second) = tokio::join!(
+        app.db
+            .consume_credit(&subscription, cycle.cycle_id, "USD", amount_due),
+        app.db
+            .consume_credit(&subscription, cycle.cycle_id, "USD", amount_due),
+    );
+    let (first, second) = (first.unwrap(), second.unwrap());
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].charge_id, second[0].charge_id);
+
+    let response = balance(&mut client).await;
+    assert_eq!(response.balances[0].available, "60.0000");
+
+    app.cleanup().await;
+}
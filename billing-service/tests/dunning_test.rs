@@ -0,0 +1,366 @@
+//! Dunning integration tests for billing-service.
+//!
+//! Dunning days count from when a case opens, so these tests bill daily
+//! subscriptions as of today and step the scheduler forward from there. The
+//! scheduler lock is a database-wide advisory lock, so they run serially.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use billing_service::services::BillingScheduler;
+use chrono::{Duration, NaiveDate, Utc};
+use common::{with_tenant, TestApp, TEST_TENANT_ID};
+use serial_test::serial;
+use std::sync::Arc;
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+/// Create a daily plan and a subscription whose first period ends today.
+async fn subscribe(client: &mut Client) -> (BillingPlan, Subscription) {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Daily Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 1, // Daily
+            interval_count: 1,
+            base_price: "5.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: "eeeeeeee-eeee-eeee-eeee-eeeeeeeeeeee".to_string(),
+            plan_id: plan.plan_id.clone(),
+            billing_anchor_day: 0,
+            start_date: (today() - Duration::days(1)).to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+
+    (plan, subscription)
+}
+
+/// Move the plan to another tenant so billing cannot find it, or back.
+async fn hide_plan(app: &TestApp, plan_id: &str, hidden: bool) {
+    let tenant_id = if hidden {
+        Uuid::new_v4()
+    } else {
+        app.tenant_id()
+    };
+    sqlx::query("UPDATE billing_plans SET tenant_id = $1 WHERE plan_id = $2")
+        .bind(tenant_id)
+        .bind(Uuid::parse_str(plan_id).unwrap())
+        .execute(app.db.pool())
+        .await
+        .unwrap();
+}
+
+async fn get_subscription(client: &mut Client, subscription_id: &str) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client
+        .get_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+async fn list_cases(client: &mut Client, subscription_id: &str) -> Vec<DunningCase> {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListDunningCasesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            status: 0,
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_dunning_cases(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .dunning_cases
+}
+
+async fn get_cycle_status(client: &mut Client, cycle_id: &str) -> i32 {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetBillingCycleRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: cycle_id.to_string(),
+        },
+    );
+    client
+        .get_billing_cycle(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .billing_cycle
+        .unwrap()
+        .status
+}
+
+#[tokio::test]
+#[serial]
+async fn failed_billing_opens_case_and_retry_recovers() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    let (plan, subscription) = subscribe(&mut client).await;
+    hide_plan(&app, &plan.plan_id, true).await;
+
+    // The failed cycle opens a case and the day-0 reminder goes out
+    let tick = scheduler.run_once(today()).await.unwrap().unwrap();
+    assert_eq!(tick.billing_runs[0].subscriptions_failed, 1);
+    assert_eq!(tick.renewed, 0);
+    assert_eq!(tick.dunning.reminders_sent, 1);
+
+    let past_due = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(past_due.status, 6); // PAST_DUE
+
+    let cases = list_cases(&mut client, &subscription.subscription_id).await;
+    assert_eq!(cases.len(), 1);
+    assert_eq!(cases[0].reason, 1); // BILLING_FAILED
+    assert_eq!(cases[0].status, 1); // OPEN
+    assert_eq!(cases[0].reminders_sent, 1);
+    assert_eq!(cases[0].last_error, "Plan not found");
+
+    // Past-due subscriptions are left to dunning, and the first retry is
+    // only due on day 1
+    let tick = scheduler.run_once(today()).await.unwrap().unwrap();
+    assert!(tick.billing_runs.is_empty());
+    assert_eq!(tick.dunning.retries, 0);
+
+    hide_plan(&app, &plan.plan_id, false).await;
+    let tick = scheduler
+        .run_once(today() + Duration::days(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.dunning.retries, 1);
+    assert_eq!(tick.dunning.recovered, 1);
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetDunningCaseRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            case_id: cases[0].case_id.clone(),
+        },
+    );
+    let case = client
+        .get_dunning_case(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .dunning_case
+        .unwrap();
+    assert_eq!(case.status, 2); // RECOVERED
+    assert_eq!(case.retry_count, 0);
+    assert!(case.closed_at.is_some());
+    assert_eq!(get_cycle_status(&mut client, &case.cycle_id).await, 2); // INVOICED
+
+    let recovered = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(recovered.status, 2); // ACTIVE
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn grace_period_end_applies_final_action() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    // Retry days must increase
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        UpdateDunningPolicyRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            retry_days: vec![3, 1],
+            reminder_days: vec![],
+            grace_period_days: 2,
+            final_action: 3,
+        },
+    );
+    let status = client.update_dunning_policy(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        UpdateDunningPolicyRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            retry_days: vec![1],
+            reminder_days: vec![0, 1],
+            grace_period_days: 2,
+            final_action: 3, // Cancel
+        },
+    );
+    let policy = client
+        .update_dunning_policy(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .policy
+        .unwrap();
+    assert!(!policy.is_default);
+
+    let (plan, subscription) = subscribe(&mut client).await;
+    hide_plan(&app, &plan.plan_id, true).await;
+
+    scheduler.run_once(today()).await.unwrap().unwrap();
+
+    // The retry fails and the second reminder goes out
+    let tick = scheduler
+        .run_once(today() + Duration::days(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.dunning.retries, 1);
+    assert_eq!(tick.dunning.reminders_sent, 1);
+    assert_eq!(tick.dunning.exhausted, 0);
+
+    let cases = list_cases(&mut client, &subscription.subscription_id).await;
+    assert_eq!(cases[0].retry_count, 1);
+    assert_eq!(cases[0].reminders_sent, 2);
+
+    let tick = scheduler
+        .run_once(today() + Duration::days(2))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.dunning.retries, 0);
+    assert_eq!(tick.dunning.exhausted, 1);
+
+    let cases = list_cases(&mut client, &subscription.subscription_id).await;
+    assert_eq!(cases[0].status, 4); // EXHAUSTED
+    assert_eq!(get_cycle_status(&mut client, &cases[0].cycle_id).await, 4); // FAILED
+
+    let cancelled = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(cancelled.status, 4); // CANCELLED
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn resolving_case_restores_subscription() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    // Tenants without a policy get the default one
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetDunningPolicyRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+        },
+    );
+    let policy = client
+        .get_dunning_policy(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .policy
+        .unwrap();
+    assert!(policy.is_default);
+    assert_eq!(policy.retry_days, vec![1, 3, 5]);
+    assert_eq!(policy.final_action, 2); // Pause
+
+    let (plan, subscription) = subscribe(&mut client).await;
+    hide_plan(&app, &plan.plan_id, true).await;
+    scheduler.run_once(today()).await.unwrap().unwrap();
+    let case = list_cases(&mut client, &subscription.subscription_id)
+        .await
+        .remove(0);
+
+    // Settled outside billing
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ResolveDunningCaseRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            case_id: case.case_id.clone(),
+            mark_paid: true,
+            note: "Paid by bank transfer".to_string(),
+        },
+    );
+    let resolved = client
+        .resolve_dunning_case(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .dunning_case
+        .unwrap();
+    assert_eq!(resolved.status, 3); // RESOLVED
+    assert_eq!(resolved.resolution_note, "Paid by bank transfer");
+    assert_eq!(get_cycle_status(&mut client, &case.cycle_id).await, 3); // PAID
+
+    let active = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(active.status, 2); // ACTIVE
+
+    // Closed cases cannot be retried or resolved again
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RetryDunningCaseRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            case_id: case.case_id.clone(),
+        },
+    );
+    let status = client.retry_dunning_case(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ResolveDunningCaseRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            case_id: case.case_id.clone(),
+            mark_paid: false,
+            note: "".to_string(),
+        },
+    );
+    let status = client.resolve_dunning_case(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,636 @@
+//! Invoice creation integration tests for billing-service.
+//!
+//! Each test runs a real invoicing-service next to billing-service. Both bind
+//! their gRPC listener on `port + 1`, so invoicing gets its own port pair.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use billing_service::services::BillingEngine;
+use common::{get_test_database_url, with_tenant, TestApp, TEST_TENANT_ID};
+use invoicing_service::config::{
+    DatabaseConfig, DocumentServiceConfig, InvoicingConfig, LedgerServiceConfig,
+};
+use invoicing_service::grpc::proto::invoicing_service_client::InvoicingServiceClient;
+use invoicing_service::grpc::proto::CreateTaxRateRequest;
+use serial_test::serial;
+use service_core::config::Config as CoreConfig;
+use service_core::grpc::proto::ledger::{
+    Direction as LedgerDirection, PostTransactionRequest, PostTransactionResponse, Transaction,
+};
+use service_core::grpc::{InvoiceStatusProto, InvoicingClient, LineItemInput};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tonic::codegen::{http, Body, BoxFuture, Service, StdError};
+use tonic::server::{NamedService, UnaryService};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+const CUSTOMER_ID: &str = "dddddddd-dddd-dddd-dddd-dddddddddddd";
+
+/// A running invoicing-service in its own schema.
+struct InvoicingApp {
+    grpc_address: String,
+    schema_name: String,
+}
+
+impl InvoicingApp {
+    async fn spawn() -> Self {
+        Self::spawn_with_ledger("http://localhost:50052").await // May not be available in tests
+    }
+
+    async fn spawn_with_ledger(ledger_url: &str) -> Self {
+        let base_url = get_test_database_url();
+        let schema_name = format!(
+            "test_billing_inv_{}_{}",
+            std::process::id(),
+            Uuid::new_v4().simple()
+        );
+
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&base_url)
+            .await
+            .expect("Failed to connect to test database");
+        sqlx::query(&format!("CREATE SCHEMA {}", schema_name))
+            .execute(&pool)
+            .await
+            .expect("Failed to create test schema");
+        pool.close().await;
+
+        let separator = if base_url.contains('?') { "&" } else { "?" };
+        let config = InvoicingConfig {
+            common: CoreConfig {
+                port: free_port_pair(),
+            },
+            service_name: "invoicing-service-test".to_string(),
+            service_version: "0.1.0".to_string(),
+            log_level: "warn".to_string(),
+            otlp_endpoint: None,
+            database: DatabaseConfig {
+                url: format!(
+                    "{}{}options=-c search_path%3D{}",
+                    base_url, separator, schema_name
+                ),
+                max_connections: 5,
+                min_connections: 1,
+            },
+            ledger_service: LedgerServiceConfig {
+                url: ledger_url.to_string(),
+            },
+            document_service: DocumentServiceConfig {
+                url: "http://localhost:50053".to_string(), // Not available in tests
+                signed_url_ttl_seconds: 3600,
+            },
+        };
+
+        let app = invoicing_service::startup::Application::build(config)
+            .await
+            .expect("Failed to build invoicing application");
+        let grpc_address = format!("http://127.0.0.1:{}", app.grpc_port());
+        tokio::spawn(async move {
+            app.run_until_stopped().await.ok();
+        });
+        tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+
+        Self {
+            grpc_address,
+            schema_name,
+        }
+    }
+
+    async fn client(&self) -> Arc<InvoicingClient> {
+        Arc::new(
+            InvoicingClient::connect(&self.grpc_address)
+                .await
+                .expect("Failed to connect to invoicing service"),
+        )
+    }
+
+    async fn create_tax_rate(&self) -> String {
+        let mut client = InvoicingServiceClient::connect(self.grpc_address.clone())
+            .await
+            .unwrap();
+        client
+            .create_tax_rate(CreateTaxRateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                name: "GST 10%".to_string(),
+                rate: "0.10".to_string(),
+                calculation: 1, // Exclusive
+                effective_from: "2020-01-01".to_string(),
+                effective_to: "".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .tax_rate
+            .unwrap()
+            .tax_rate_id
+    }
+
+    async fn cleanup(&self) {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect(&get_test_database_url())
+            .await
+            .expect("Failed to connect to test database");
+        let _ = sqlx::query(&format!(
+            "DROP SCHEMA IF EXISTS {} CASCADE",
+            self.schema_name
+        ))
+        .execute(&pool)
+        .await;
+        pool.close().await;
+    }
+}
+
+/// A ledger-service stand-in that records the journals posted to it.
+#[derive(Clone, Default)]
+struct RecordingLedger {
+    posted: Arc<Mutex<Vec<PostTransactionRequest>>>,
+}
+
+impl RecordingLedger {
+    /// Serve on a random port and return the ledger with its URL.
+    async fn spawn() -> (Self, String) {
+        let ledger = Self::default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tonic::transport::Server::builder()
+            .add_service(ledger.clone())
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener));
+        tokio::spawn(server);
+        (ledger, url)
+    }
+
+    /// The journal posted under an idempotency key.
+    fn journal(&self, idempotency_key: &str) -> Option<PostTransactionRequest> {
+        self.posted
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| r.idempotency_key == idempotency_key)
+            .cloned()
+    }
+}
+
+impl NamedService for RecordingLedger {
+    const NAME: &'static str = "micros.ledger.v1.LedgerService";
+}
+
+impl UnaryService<PostTransactionRequest> for RecordingLedger {
+    type Response = PostTransactionResponse;
+    type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+
+    fn call(&mut self, request: tonic::Request<PostTransactionRequest>) -> Self::Future {
+        let request = request.into_inner();
+        self.posted.lock().unwrap().push(request.clone());
+        Box::pin(async move {
+            Ok(tonic::Response::new(PostTransactionResponse {
+                transaction: Some(Transaction {
+                    journal_id: Uuid::new_v4().to_string(),
+                    tenant_id: request.tenant_id,
+                    ..Default::default()
+                }),
+            }))
+        })
+    }
+}
+
+impl<B> Service<http::Request<B>> for RecordingLedger
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<tonic::body::BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let ledger = self.clone();
+        Box::pin(async move {
+            if request.uri().path() != "/micros.ledger.v1.LedgerService/PostTransaction" {
+                return Ok(tonic::Status::unimplemented("Not recorded").into_http());
+            }
+            let mut grpc = tonic::server::Grpc::new(tonic::codec::ProstCodec::default());
+            Ok(grpc.unary(ledger, request).await)
+        })
+    }
+}
+
+/// Sum a journal's entries on one side, asserting every amount is positive.
+fn journal_side(
+    journal: &PostTransactionRequest,
+    direction: LedgerDirection,
+) -> Vec<(String, String)> {
+    assert!(journal
+        .entries
+        .iter()
+        .all(|e| !e.amount.starts_with('-') && e.amount != "0"));
+    journal
+        .entries
+        .iter()
+        .filter(|e| e.direction == direction as i32)
+        .map(|e| (e.account_id.clone(), e.amount.clone()))
+        .collect()
+}
+
+/// Find a free port whose successor is also free.
+fn free_port_pair() -> u16 {
+    loop {
+        let listener = std::net::TcpListener::bind("0.0.0.0:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        if port < u16::MAX && std::net::TcpListener::bind(("0.0.0.0", port + 1)).is_ok() {
+            return port;
+        }
+    }
+}
+
+/// Create a plan, an active subscription and a one-time charge on its cycle.
+async fn subscribe(client: &mut Client, tax_rate_id: &str) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Invoiced Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "40.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: tax_rate_id.to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id,
+            billing_anchor_day: 1,
+            start_date: "2025-01-01".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateOneTimeChargeRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription.subscription_id.clone(),
+            description: "Setup fee".to_string(),
+            amount: "15.00".to_string(),
+            metadata: "".to_string(),
+        },
+    );
+    client.create_one_time_charge(request).await.unwrap();
+
+    subscription
+}
+
+async fn load_subscription(
+    app: &TestApp,
+    subscription_id: &str,
+) -> billing_service::models::Subscription {
+    app.db
+        .get_subscription(
+            Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+            Uuid::parse_str(subscription_id).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn billing_creates_draft_invoice_for_cycle_charges() {
+    let app = TestApp::spawn().await;
+    let invoicing = InvoicingApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let invoicing_client = invoicing.client().await;
+    let tax_rate_id = invoicing.create_tax_rate().await;
+
+    let subscription = subscribe(&mut client, &tax_rate_id).await;
+    let subscription = load_subscription(&app, &subscription.subscription_id).await;
+
+    let engine =
+        BillingEngine::with_invoicing(Arc::new(app.db.clone()), invoicing_client.clone(), false);
+    let cycle = engine.bill_subscription(&subscription).await.unwrap();
+    let invoice_id = cycle.invoice_id.expect("Cycle should record its invoice");
+
+    let invoice = invoicing_client
+        .get_invoice(TEST_TENANT_ID, &invoice_id.to_string())
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    assert_eq!(invoice.status, InvoiceStatusProto::Draft as i32);
+    assert_eq!(invoice.customer_id, CUSTOMER_ID);
+    assert_eq!(invoice.currency, "USD");
+    assert_eq!(invoice.line_items.len(), 2);
+    assert!(invoice
+        .line_items
+        .iter()
+        .all(|item| item.tax_rate_id == tax_rate_id));
+    assert!(invoice.metadata.contains(&cycle.cycle_id.to_string()));
+
+    // Every charge points at the invoice
+    let charges = app.db.get_cycle_charges(cycle.cycle_id).await.unwrap();
+    assert_eq!(charges.len(), 2);
+    assert!(charges.iter().all(|c| c.invoice_id == Some(invoice_id)));
+
+    invoicing.cleanup().await;
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn billing_issues_invoice_when_enabled() {
+    let app = TestApp::spawn().await;
+    let invoicing = InvoicingApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let invoicing_client = invoicing.client().await;
+
+    let subscription = subscribe(&mut client, "").await;
+    let subscription = load_subscription(&app, &subscription.subscription_id).await;
+
+    let engine =
+        BillingEngine::with_invoicing(Arc::new(app.db.clone()), invoicing_client.clone(), true);
+    let cycle = engine.bill_subscription(&subscription).await.unwrap();
+
+    let invoice = invoicing_client
+        .get_invoice(TEST_TENANT_ID, &cycle.invoice_id.unwrap().to_string())
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    assert_eq!(invoice.status, InvoiceStatusProto::Issued as i32);
+    assert!(!invoice.invoice_number.is_empty());
+    assert_eq!(invoice.total, "55");
+
+    invoicing.cleanup().await;
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn billing_retry_reuses_invoice_from_failed_attempt() {
+    let app = TestApp::spawn().await;
+    let invoicing = InvoicingApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let invoicing_client = invoicing.client().await;
+
+    let subscription = subscribe(&mut client, "").await;
+    let subscription = load_subscription(&app, &subscription.subscription_id).await;
+    let cycle = app
+        .db
+        .get_current_billing_cycle(subscription.subscription_id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // An earlier attempt created the draft and its first line item, then
+    // failed before recording the invoice on the cycle
+    let metadata = serde_json::json!({ "billing_cycle_id": cycle.cycle_id.to_string() });
+    let orphan = invoicing_client
+        .create_invoice(
+            TEST_TENANT_ID,
+            CUSTOMER_ID,
+            "USD",
+            None,
+            None,
+            Some(&metadata.to_string()),
+        )
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    invoicing_client
+        .add_line_item(
+            TEST_TENANT_ID,
+            &orphan.invoice_id,
+            LineItemInput {
+                description: "Setup fee".to_string(),
+                quantity: "1".to_string(),
+                unit_price: "15.00".to_string(),
+                sort_order: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let engine =
+        BillingEngine::with_invoicing(Arc::new(app.db.clone()), invoicing_client.clone(), false);
+    let billed = engine.bill_subscription(&subscription).await.unwrap();
+    assert_eq!(billed.invoice_id.unwrap().to_string(), orphan.invoice_id);
+
+    let drafts = invoicing_client
+        .list_invoices(
+            TEST_TENANT_ID,
+            CUSTOMER_ID,
+            Some(InvoiceStatusProto::Draft),
+            10,
+            None,
+        )
+        .await
+        .unwrap()
+        .invoices;
+    assert_eq!(drafts.len(), 1);
+
+    let invoice = invoicing_client
+        .get_invoice(TEST_TENANT_ID, &orphan.invoice_id)
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    let mut sort_orders: Vec<i32> = invoice.line_items.iter().map(|l| l.sort_order).collect();
+    sort_orders.sort();
+    assert_eq!(sort_orders, vec![1, 2]);
+
+    invoicing.cleanup().await;
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn issued_invoice_posts_credit_against_customer_credit() {
+    let app = TestApp::spawn().await;
+    let (ledger, ledger_url) = RecordingLedger::spawn().await;
+    let invoicing = InvoicingApp::spawn_with_ledger(&ledger_url).await;
+    let mut client = app.grpc_client().await;
+    let invoicing_client = invoicing.client().await;
+
+    let subscription = subscribe(&mut client, "").await;
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GrantCreditRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: CUSTOMER_ID.to_string(),
+            amount: "20.00".to_string(),
+            currency: "USD".to_string(),
+            priority: 0,
+            description: "Prepaid top-up".to_string(),
+            ledger_account_id: "".to_string(),
+            expires_at: None,
+        },
+    );
+    client.grant_credit(request).await.unwrap();
+    let subscription = load_subscription(&app, &subscription.subscription_id).await;
+
+    let engine =
+        BillingEngine::with_invoicing(Arc::new(app.db.clone()), invoicing_client.clone(), true);
+    let cycle = engine.bill_subscription(&subscription).await.unwrap();
+    let invoice_id = cycle.invoice_id.unwrap();
+
+    let invoice = invoicing_client
+        .get_invoice(TEST_TENANT_ID, &invoice_id.to_string())
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    assert_eq!(invoice.total, "35");
+    assert!(!invoice.journal_id.is_empty());
+
+    // Credit is drawn from Customer Credit and netted out of A/R
+    let journal = ledger
+        .journal(&format!("invoice-issue-{}", invoice_id))
+        .expect("Invoice issue should be posted");
+    let mut debits = journal_side(&journal, LedgerDirection::Debit);
+    debits.sort();
+    assert_eq!(
+        debits,
+        vec![
+            ("AR-USD".to_string(), "35".to_string()),
+            ("CUSTOMER-CREDIT-USD".to_string(), "20".to_string()),
+        ]
+    );
+    let mut credits = journal_side(&journal, LedgerDirection::Credit);
+    credits.sort();
+    assert_eq!(
+        credits,
+        vec![
+            ("REVENUE-USD".to_string(), "15".to_string()),
+            ("REVENUE-USD".to_string(), "40".to_string()),
+        ]
+    );
+
+    invoicing.cleanup().await;
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn issued_invoice_posts_discount_to_contra_revenue() {
+    let app = TestApp::spawn().await;
+    let (ledger, ledger_url) = RecordingLedger::spawn().await;
+    let invoicing = InvoicingApp::spawn_with_ledger(&ledger_url).await;
+    let mut client = app.grpc_client().await;
+    let invoicing_client = invoicing.client().await;
+
+    let subscription = subscribe(&mut client, "").await;
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateCouponRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Launch offer".to_string(),
+            discount_type: 1, // Percent off
+            percent_off: "25".to_string(),
+            amount_off: "".to_string(),
+            currency: "".to_string(),
+            duration: 1, // Once
+            duration_cycles: 0,
+            max_redemptions: 0,
+            expires_at: None,
+            metadata: "".to_string(),
+        },
+    );
+    let coupon = client
+        .create_coupon(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .coupon
+        .unwrap();
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ApplyDiscountRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription.subscription_id.clone(),
+            coupon_id: coupon.coupon_id,
+            promotion_code: "".to_string(),
+            charge_id: "".to_string(),
+        },
+    );
+    client.apply_discount(request).await.unwrap();
+    let subscription = load_subscription(&app, &subscription.subscription_id).await;
+
+    let engine =
+        BillingEngine::with_invoicing(Arc::new(app.db.clone()), invoicing_client.clone(), true);
+    let cycle = engine.bill_subscription(&subscription).await.unwrap();
+    let invoice_id = cycle.invoice_id.unwrap();
+
+    let invoice = invoicing_client
+        .get_invoice(TEST_TENANT_ID, &invoice_id.to_string())
+        .await
+        .unwrap()
+        .invoice
+        .unwrap();
+    assert_eq!(invoice.status, InvoiceStatusProto::Issued as i32);
+    assert_eq!(invoice.total, "41.25");
+    assert!(!invoice.journal_id.is_empty());
+
+    // 25% of 55 is debited to contra-revenue, A/R carries the discounted total
+    let journal = ledger
+        .journal(&format!("invoice-issue-{}", invoice_id))
+        .expect("Invoice issue should be posted");
+    let mut debits = journal_side(&journal, LedgerDirection::Debit);
+    debits.sort();
+    assert_eq!(
+        debits,
+        vec![
+            ("AR-USD".to_string(), "41.25".to_string()),
+            ("DISCOUNTS-USD".to_string(), "13.75".to_string()),
+        ]
+    );
+    let mut credits = journal_side(&journal, LedgerDirection::Credit);
+    credits.sort();
+    assert_eq!(
+        credits,
+        vec![
+            ("REVENUE-USD".to_string(), "15".to_string()),
+            ("REVENUE-USD".to_string(), "40".to_string()),
+        ]
+    );
+
+    invoicing.cleanup().await;
+    app.cleanup().await;
+}
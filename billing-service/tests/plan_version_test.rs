@@ -0,0 +1,345 @@
+//! Plan version and subscriber migration integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+async fn create_plan(client: &mut Client) -> BillingPlan {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Team Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "50.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap()
+}
+
+async fn subscribe(client: &mut Client, plan_id: &str) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan_id.to_string(),
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+async fn update_plan(
+    client: &mut Client,
+    plan_id: &str,
+    name: &str,
+    base_price: &str,
+) -> BillingPlan {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        UpdatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            plan_id: plan_id.to_string(),
+            name: name.to_string(),
+            description: "".to_string(),
+            base_price: base_price.to_string(),
+            tax_rate_id: "".to_string(),
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .update_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap()
+}
+
+fn migrate_request(
+    plan_id: &str,
+    effective_date: &str,
+    notice_days: i32,
+    dry_run: bool,
+) -> MigrateSubscribersRequest {
+    MigrateSubscribersRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        plan_id: plan_id.to_string(),
+        from_version: 1,
+        to_version: 2,
+        effective_date: effective_date.to_string(),
+        notice_days,
+        dry_run,
+    }
+}
+
+async fn bill(client: &mut Client, subscription_id: &str) -> String {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    let result = client
+        .run_billing_for_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .result
+        .unwrap();
+    assert_eq!(result.status, "success");
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListBillingCyclesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            status: 0,
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    let cycles = client
+        .list_billing_cycles(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .billing_cycles;
+    let latest = cycles
+        .iter()
+        .max_by_key(|c| c.period_start.clone())
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListChargesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: latest.cycle_id.clone(),
+            charge_type: 1,
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_charges(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .charges[0]
+        .amount
+        .clone()
+}
+
+async fn renew(app: &TestApp, subscription_id: &str) -> billing_service::models::Subscription {
+    let model = app
+        .db
+        .get_subscription(
+            Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+            Uuid::parse_str(subscription_id).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    app.db.renew_subscription(&model).await.unwrap().0
+}
+
+#[tokio::test]
+async fn price_change_adds_version_and_keeps_subscribers_on_theirs() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let plan = create_plan(&mut client).await;
+    assert_eq!(plan.version, 1);
+    let existing = subscribe(&mut client, &plan.plan_id).await;
+    assert_eq!(existing.plan_version, 1);
+
+    let updated = update_plan(&mut client, &plan.plan_id, "", "60.00").await;
+    assert_eq!(updated.version, 2);
+    assert_eq!(updated.base_price, "60.0000");
+
+    // Renaming the plan does not change its price
+    let renamed = update_plan(&mut client, &plan.plan_id, "Team Plan 2024", "").await;
+    assert_eq!(renamed.version, 2);
+    assert_eq!(renamed.name, "Team Plan 2024");
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListPlanVersionsRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            plan_id: plan.plan_id.clone(),
+        },
+    );
+    let versions = client
+        .list_plan_versions(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .versions;
+    let prices: Vec<(i32, &str)> = versions
+        .iter()
+        .map(|v| (v.version, v.base_price.as_str()))
+        .collect();
+    assert_eq!(prices, vec![(1, "50.0000"), (2, "60.0000")]);
+
+    // New subscribers start on the new version; existing ones keep their price
+    let new = subscribe(&mut client, &plan.plan_id).await;
+    assert_eq!(new.plan_version, 2);
+    assert_eq!(
+        bill(&mut client, &existing.subscription_id).await,
+        "50.0000"
+    );
+    assert_eq!(bill(&mut client, &new.subscription_id).await, "60.0000");
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn migrate_subscribers_reports_then_moves_them_at_renewal() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let plan = create_plan(&mut client).await;
+    let first = subscribe(&mut client, &plan.plan_id).await;
+    let second = subscribe(&mut client, &plan.plan_id).await;
+    update_plan(&mut client, &plan.plan_id, "", "60.00").await;
+    let today = chrono::Utc::now().date_naive().to_string();
+
+    let request = migrate_request(&plan.plan_id, &today, 0, true);
+    let report = client
+        .migrate_subscribers(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(report.migration.is_none());
+    assert_eq!(report.subscriptions.len(), 2);
+    for subscription in &report.subscriptions {
+        assert_eq!(subscription.current_price, "50.0000");
+        assert_eq!(subscription.new_price, "60.0000");
+        assert_eq!(subscription.effective_date, first.current_period_end);
+        assert_eq!(subscription.status, 1);
+    }
+
+    let request = migrate_request(&plan.plan_id, &today, 0, false);
+    let response = client
+        .migrate_subscribers(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner();
+    let migration = response.migration.unwrap();
+    assert_eq!(migration.subscription_count, 2);
+
+    // The current cycle is billed at the old price, the next at the new one
+    assert_eq!(bill(&mut client, &first.subscription_id).await, "50.0000");
+    let renewed = renew(&app, &first.subscription_id).await;
+    assert_eq!(renewed.plan_version, 2);
+    assert_eq!(bill(&mut client, &first.subscription_id).await, "60.0000");
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetPlanMigrationRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            migration_id: migration.migration_id,
+        },
+    );
+    let audit = client
+        .get_plan_migration(request)
+        .await
+        .unwrap()
+        .into_inner();
+    let status_of = |subscription_id: &str| {
+        audit
+            .subscriptions
+            .iter()
+            .find(|s| s.subscription_id == subscription_id)
+            .unwrap()
+            .status
+    };
+    assert_eq!(status_of(&first.subscription_id), 2);
+    assert_eq!(status_of(&second.subscription_id), 1);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn notice_period_defers_migration_to_a_later_renewal() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let plan = create_plan(&mut client).await;
+    let subscription = subscribe(&mut client, &plan.plan_id).await;
+    update_plan(&mut client, &plan.plan_id, "", "60.00").await;
+    let today = chrono::Utc::now().date_naive();
+
+    let request = migrate_request(&plan.plan_id, &today.to_string(), 45, false);
+    let response = client
+        .migrate_subscribers(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner();
+    let effective_date = &response.subscriptions[0].effective_date;
+    assert!(effective_date.as_str() > subscription.current_period_end.as_str());
+    let effective_date = chrono::NaiveDate::parse_from_str(effective_date, "%Y-%m-%d").unwrap();
+    assert!(effective_date >= today + chrono::Days::new(45));
+
+    // The first renewal falls inside the notice period
+    let renewed = renew(&app, &subscription.subscription_id).await;
+    assert_eq!(renewed.plan_version, 1);
+    let mut renewed = renew(&app, &subscription.subscription_id).await;
+    while renewed.current_period_start < effective_date {
+        assert_eq!(renewed.plan_version, 1);
+        renewed = renew(&app, &subscription.subscription_id).await;
+    }
+    assert_eq!(renewed.plan_version, 2);
+
+    // Invalid versions are rejected
+    for (from_version, to_version) in [(1, 1), (1, 7)] {
+        let mut request = migrate_request(&plan.plan_id, &today.to_string(), 0, true);
+        request.from_version = from_version;
+        request.to_version = to_version;
+        let status = client
+            .migrate_subscribers(with_tenant(TEST_TENANT_ID, request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    // A notice period past the last representable date is rejected
+    let request = migrate_request(&plan.plan_id, &today.to_string(), i32::MAX, true);
+    let status = client
+        .migrate_subscribers(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,311 @@
+//! Upcoming charge preview integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+async fn create_plan(
+    client: &mut Client,
+    name: &str,
+    base_price: &str,
+    currency: &str,
+) -> BillingPlan {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: name.to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: base_price.to_string(),
+            currency: currency.to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![CreateUsageComponentInput {
+                name: "API Calls".to_string(),
+                unit_name: "calls".to_string(),
+                unit_price: "0.01".to_string(),
+                included_units: 1000,
+                ..Default::default()
+            }],
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap()
+}
+
+/// Subscribe to a $50 "Basic Plan" with 1500 API calls recorded, 500 of them
+/// billable at 0.01.
+async fn subscribe(client: &mut Client) -> (Subscription, BillingPlan) {
+    let plan = create_plan(client, "Basic Plan", "50.00", "USD").await;
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id.clone(),
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RecordUsageRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription.subscription_id.clone(),
+            component_id: plan.usage_components[0].component_id.clone(),
+            quantity: "1500".to_string(),
+            timestamp: None,
+            idempotency_key: "preview-usage".to_string(),
+            metadata: "".to_string(),
+        },
+    );
+    client.record_usage(request).await.unwrap();
+
+    (subscription, plan)
+}
+
+fn preview_request(
+    subscription_id: &str,
+    new_plan_id: &str,
+    proration_mode: i32,
+    as_of: &str,
+) -> PreviewUpcomingChargesRequest {
+    PreviewUpcomingChargesRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        subscription_id: subscription_id.to_string(),
+        new_plan_id: new_plan_id.to_string(),
+        proration_mode,
+        as_of: as_of.to_string(),
+    }
+}
+
+async fn preview(
+    client: &mut Client,
+    request: PreviewUpcomingChargesRequest,
+) -> Result<PreviewUpcomingChargesResponse, tonic::Status> {
+    client
+        .preview_upcoming_charges(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .map(|response| response.into_inner())
+}
+
+async fn list_charges(client: &mut Client, cycle_id: &str) -> Vec<Charge> {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListChargesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: cycle_id.to_string(),
+            charge_type: 0,
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    client
+        .list_charges(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .charges
+}
+
+#[tokio::test]
+async fn preview_matches_the_billing_run_without_persisting() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, _) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateCouponRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Quarter Off".to_string(),
+            discount_type: 1,
+            percent_off: "25".to_string(),
+            amount_off: "".to_string(),
+            currency: "".to_string(),
+            duration: 1,
+            duration_cycles: 0,
+            max_redemptions: 0,
+            expires_at: None,
+            metadata: "".to_string(),
+        },
+    );
+    let coupon = client
+        .create_coupon(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .coupon
+        .unwrap();
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ApplyDiscountRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            coupon_id: coupon.coupon_id,
+            promotion_code: "".to_string(),
+            charge_id: "".to_string(),
+        },
+    );
+    client.apply_discount(request).await.unwrap();
+
+    let response = preview(&mut client, preview_request(subscription_id, "", 0, ""))
+        .await
+        .unwrap();
+
+    // 50.00 recurring, 5.00 usage, then 25% off both
+    assert_eq!(response.charges.len(), 3);
+    assert!(response.charges.iter().all(|c| c.charge_id.is_empty()));
+    let types: Vec<i32> = response.charges.iter().map(|c| c.charge_type).collect();
+    assert_eq!(types, vec![1, 2, 5]);
+    assert_eq!(response.currency, "USD");
+    assert_eq!(response.subtotal.parse::<f64>().unwrap(), 55.0);
+    assert_eq!(response.discount_total.parse::<f64>().unwrap(), -13.75);
+    assert_eq!(response.total.parse::<f64>().unwrap(), 41.25);
+
+    // Nothing was written
+    let cycle_id = response.cycle.as_ref().unwrap().cycle_id.clone();
+    assert!(list_charges(&mut client, &cycle_id).await.is_empty());
+
+    // Billing the cycle creates the same charges
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+
+    let charges = list_charges(&mut client, &cycle_id).await;
+    assert_eq!(charges.len(), 3);
+    for line in &response.charges {
+        let charge = charges
+            .iter()
+            .find(|c| c.description == line.description)
+            .unwrap();
+        assert_eq!(charge.charge_type, line.charge_type);
+        assert_eq!(
+            charge.amount.parse::<f64>().unwrap(),
+            line.amount.parse::<f64>().unwrap()
+        );
+    }
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn preview_plan_change_prorates_without_changing_plan() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, basic_plan) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+    let premium_plan = create_plan(&mut client, "Premium Plan", "100.00", "USD").await;
+
+    let current = preview(&mut client, preview_request(subscription_id, "", 0, ""))
+        .await
+        .unwrap();
+    let period_start = current.cycle.unwrap().period_start;
+
+    // Changing on the first day of the cycle credits all of the old plan
+    let response = preview(
+        &mut client,
+        preview_request(subscription_id, &premium_plan.plan_id, 1, &period_start),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.plan_id, premium_plan.plan_id);
+
+    let amounts: Vec<(i32, f64)> = response
+        .charges
+        .iter()
+        .map(|c| (c.charge_type, c.amount.parse::<f64>().unwrap()))
+        .collect();
+    // Usage recorded against the basic plan's component is not on the
+    // premium plan
+    assert_eq!(amounts, vec![(4, -50.0), (4, 100.0), (1, 100.0)]);
+    assert!(response.charges[..2].iter().all(|c| c.is_prorated));
+    assert_eq!(
+        response.charges[2].description,
+        "Monthly subscription - Premium Plan"
+    );
+
+    // A change at the next cycle leaves this cycle on the current plan
+    let response = preview(
+        &mut client,
+        preview_request(subscription_id, &premium_plan.plan_id, 2, ""),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.plan_id, basic_plan.plan_id);
+    let types: Vec<i32> = response.charges.iter().map(|c| c.charge_type).collect();
+    assert_eq!(types, vec![1, 2]);
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    let subscription = client
+        .get_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+    assert_eq!(subscription.plan_id, basic_plan.plan_id);
+
+    // The new plan is validated as it would be for a change
+    let euro_plan = create_plan(&mut client, "Euro Plan", "40.00", "EUR").await;
+    let status = preview(
+        &mut client,
+        preview_request(subscription_id, &euro_plan.plan_id, 1, ""),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = preview(
+        &mut client,
+        preview_request(
+            subscription_id,
+            "99999999-9999-9999-9999-999999999999",
+            1,
+            "",
+        ),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,247 @@
+//! Usage pricing model integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_TENANT_ID};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+fn tier(up_to: &str, unit_price: &str) -> PricingTier {
+    PricingTier {
+        up_to: up_to.to_string(),
+        unit_price: unit_price.to_string(),
+        flat_fee: "".to_string(),
+    }
+}
+
+fn plan_request(usage_components: Vec<CreateUsageComponentInput>) -> CreatePlanRequest {
+    CreatePlanRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        name: "Tiered Plan".to_string(),
+        description: "".to_string(),
+        billing_interval: 3,
+        interval_count: 1,
+        base_price: "0.00".to_string(),
+        currency: "USD".to_string(),
+        tax_rate_id: "".to_string(),
+        usage_components,
+        metadata: "".to_string(),
+    }
+}
+
+async fn record_usage(client: &mut Client, subscription_id: &str, component_id: &str, qty: &str) {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RecordUsageRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            component_id: component_id.to_string(),
+            quantity: qty.to_string(),
+            timestamp: None,
+            idempotency_key: format!("pricing-{}", component_id),
+            metadata: "".to_string(),
+        },
+    );
+    client.record_usage(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn create_plan_rejects_invalid_pricing() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    // The last tier must be unbounded
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        plan_request(vec![CreateUsageComponentInput {
+            name: "API Calls".to_string(),
+            unit_name: "calls".to_string(),
+            pricing_model: 2, // Graduated
+            tiers: vec![tier("1000", "0"), tier("10000", "0.01")],
+            ..Default::default()
+        }]),
+    );
+    let status = client.create_plan(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    // Package pricing needs a package size
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        plan_request(vec![CreateUsageComponentInput {
+            name: "Messages".to_string(),
+            unit_name: "messages".to_string(),
+            unit_price: "2.00".to_string(),
+            pricing_model: 4, // Package
+            ..Default::default()
+        }]),
+    );
+    let status = client.create_plan(request).await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn usage_summary_and_charges_explain_pricing() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        plan_request(vec![
+            CreateUsageComponentInput {
+                name: "API Calls".to_string(),
+                unit_name: "calls".to_string(),
+                pricing_model: 2, // Graduated
+                tiers: vec![tier("1000", "0"), tier("10000", "0.01"), tier("", "0.005")],
+                ..Default::default()
+            },
+            CreateUsageComponentInput {
+                name: "Bandwidth".to_string(),
+                unit_name: "GB".to_string(),
+                pricing_model: 3, // Volume
+                tiers: vec![tier("100", "0.10"), tier("", "0.05")],
+                ..Default::default()
+            },
+            CreateUsageComponentInput {
+                name: "SMS".to_string(),
+                unit_name: "messages".to_string(),
+                unit_price: "2.00".to_string(),
+                pricing_model: 4, // Package
+                package_size: "100".to_string(),
+                minimum_amount: "10.00".to_string(),
+                ..Default::default()
+            },
+        ]),
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+    let component = |name: &str| {
+        plan.usage_components
+            .iter()
+            .find(|c| c.name == name)
+            .unwrap()
+            .clone()
+    };
+    assert_eq!(component("API Calls").pricing_model, 2);
+    assert_eq!(component("API Calls").tiers.len(), 3);
+    assert_eq!(component("SMS").package_size, "100.0000");
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: "77777777-7777-7777-7777-777777777777".to_string(),
+            plan_id: plan.plan_id.clone(),
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+    let subscription_id = subscription.subscription_id.clone();
+
+    record_usage(
+        &mut client,
+        &subscription_id,
+        &component("API Calls").component_id,
+        "12000",
+    )
+    .await;
+    record_usage(
+        &mut client,
+        &subscription_id,
+        &component("Bandwidth").component_id,
+        "150",
+    )
+    .await;
+    record_usage(
+        &mut client,
+        &subscription_id,
+        &component("SMS").component_id,
+        "250",
+    )
+    .await;
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetUsageSummaryRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.clone(),
+            cycle_id: "".to_string(),
+        },
+    );
+    let summaries = client
+        .get_usage_summary(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .component_summaries;
+    let summary = |name: &str| summaries.iter().find(|s| s.name == name).unwrap();
+
+    // 1000 free, 9000 at 0.01, 2000 at 0.005
+    let api = summary("API Calls");
+    assert_eq!(api.pricing_model, 2);
+    assert_eq!(api.amount.parse::<f64>().unwrap(), 100.0);
+    assert_eq!(api.breakdown.len(), 3);
+    assert_eq!(api.breakdown[1].description, "Tier 2: 1000 - 10000");
+    assert_eq!(api.breakdown[1].amount.parse::<f64>().unwrap(), 90.0);
+
+    // All 150 GB at the second tier
+    let bandwidth = summary("Bandwidth");
+    assert_eq!(bandwidth.amount.parse::<f64>().unwrap(), 7.5);
+    assert_eq!(bandwidth.breakdown.len(), 1);
+
+    // 3 packages of 100 at 2.00, raised to the 10.00 minimum
+    let sms = summary("SMS");
+    assert_eq!(sms.amount.parse::<f64>().unwrap(), 10.0);
+    assert_eq!(sms.breakdown.len(), 2);
+    assert_eq!(sms.breakdown[1].description, "Minimum charge");
+
+    // Usage charges carry the same amounts and breakdown
+    let cycle = app
+        .db
+        .get_current_billing_cycle(Uuid::parse_str(&subscription_id).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.clone(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+
+    let charges = app.db.get_cycle_charges(cycle.cycle_id).await.unwrap();
+    let api_charge = charges
+        .iter()
+        .find(|c| {
+            c.component_id.map(|id| id.to_string()) == Some(component("API Calls").component_id)
+        })
+        .unwrap();
+    assert_eq!(api_charge.amount.to_string(), "100.0000");
+    let metadata = api_charge.metadata.as_ref().unwrap();
+    assert_eq!(metadata["pricing_model"], "graduated");
+    assert_eq!(metadata["breakdown"].as_array().unwrap().len(), 3);
+
+    app.cleanup().await;
+}
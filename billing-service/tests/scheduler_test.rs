@@ -0,0 +1,295 @@
+//! Billing scheduler integration tests for billing-service.
+//!
+//! The scheduler lock is a database-wide advisory lock, so these tests run serially.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use billing_service::services::BillingScheduler;
+use chrono::NaiveDate;
+use common::{with_tenant, TestApp, TEST_TENANT_ID};
+use serial_test::serial;
+use std::sync::Arc;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+fn date(s: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+}
+
+/// Helper to create a plan.
+async fn create_plan(
+    client: &mut Client,
+    name: &str,
+    billing_interval: i32,
+    price: &str,
+) -> BillingPlan {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: name.to_string(),
+            description: "".to_string(),
+            billing_interval,
+            interval_count: 1,
+            base_price: price.to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![],
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap()
+}
+
+/// Helper to create a subscription starting on 2025-01-01.
+async fn create_subscription(
+    client: &mut Client,
+    plan_id: &str,
+    trial_end_date: &str,
+) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: "dddddddd-dddd-dddd-dddd-dddddddddddd".to_string(),
+            plan_id: plan_id.to_string(),
+            billing_anchor_day: 1,
+            start_date: "2025-01-01".to_string(),
+            trial_end_date: trial_end_date.to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+async fn get_subscription(client: &mut Client, subscription_id: &str) -> Subscription {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client
+        .get_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap()
+}
+
+async fn list_cycles(client: &mut Client, subscription_id: &str) -> Vec<BillingCycle> {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListBillingCyclesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            status: 0,
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    let mut cycles = client
+        .list_billing_cycles(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .billing_cycles;
+    cycles.sort_by(|a, b| a.period_start.cmp(&b.period_start));
+    cycles
+}
+
+#[tokio::test]
+#[serial]
+async fn scheduler_bills_due_subscriptions_and_applies_pending_plan() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    let basic = create_plan(&mut client, "Basic Monthly", 3, "50.00").await;
+    let premium = create_plan(&mut client, "Premium Quarterly", 4, "120.00").await;
+    let subscription = create_subscription(&mut client, &basic.plan_id, "").await;
+
+    // Schedule an upgrade for the next period
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ChangePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription.subscription_id.clone(),
+            new_plan_id: premium.plan_id.clone(),
+            proration_mode: 2, // Next cycle
+        },
+    );
+    client.change_plan(request).await.unwrap();
+
+    let tick = scheduler
+        .run_once(date("2025-02-05"))
+        .await
+        .unwrap()
+        .expect("Scheduler lock should be free");
+    assert_eq!(tick.billing_runs.len(), 1);
+    assert_eq!(tick.renewed, 1);
+    assert_eq!(tick.billing_runs[0].run_type, "scheduled");
+    assert_eq!(tick.billing_runs[0].subscriptions_succeeded, 1);
+
+    // The run is visible to the tenant as a scheduled run
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListBillingRunsRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            status: 0,
+            run_type: 1, // Scheduled
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    let runs = client
+        .list_billing_runs(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .billing_runs;
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].subscriptions_processed, 1);
+
+    // The pending plan applies from the new period, which takes its interval
+    let renewed = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(renewed.plan_id, premium.plan_id);
+    assert!(renewed.pending_plan_id.is_empty());
+    assert_eq!(renewed.current_period_start, "2025-02-01");
+    assert_eq!(renewed.current_period_end, "2025-05-01");
+
+    let cycles = list_cycles(&mut client, &subscription.subscription_id).await;
+    assert_eq!(cycles.len(), 2);
+    assert_eq!(cycles[0].status, 2); // INVOICED
+    assert_eq!(cycles[1].status, 1); // PENDING
+    assert_eq!(cycles[1].period_end, "2025-05-01");
+
+    // Nothing is due until the new period ends
+    let tick = scheduler
+        .run_once(date("2025-02-06"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(tick.billing_runs.is_empty());
+    assert_eq!(tick.renewed, 0);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn scheduler_ends_trials_and_cancels_at_period_end() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    let plan = create_plan(&mut client, "Scheduler Plan", 3, "30.00").await;
+    let trial = create_subscription(&mut client, &plan.plan_id, "2025-01-15").await;
+    let cancelling = create_subscription(&mut client, &plan.plan_id, "").await;
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CancelSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: cancelling.subscription_id.clone(),
+            cancel_at_period_end: true,
+            reason: "".to_string(),
+        },
+    );
+    client.cancel_subscription(request).await.unwrap();
+
+    // The trial converts and its paid period starts on the trial end date
+    let tick = scheduler
+        .run_once(date("2025-01-20"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.trials_ended, 1);
+    assert!(tick.billing_runs.is_empty());
+    assert_eq!(tick.cancelled, 0);
+
+    let converted = get_subscription(&mut client, &trial.subscription_id).await;
+    assert_eq!(converted.status, 2); // ACTIVE
+    assert_eq!(converted.current_period_start, "2025-01-15");
+    assert_eq!(converted.current_period_end, "2025-02-15");
+    let cycles = list_cycles(&mut client, &trial.subscription_id).await;
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].period_start, "2025-01-15");
+
+    // The cancelling subscription is billed for its last period, then cancelled
+    let tick = scheduler
+        .run_once(date("2025-02-05"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.billing_runs.len(), 1);
+    assert_eq!(tick.renewed, 0);
+    assert_eq!(tick.cancelled, 1);
+
+    let cancelled = get_subscription(&mut client, &cancelling.subscription_id).await;
+    assert_eq!(cancelled.status, 4); // CANCELLED
+    let cycles = list_cycles(&mut client, &cancelling.subscription_id).await;
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(cycles[0].status, 2); // INVOICED
+
+    let converted = get_subscription(&mut client, &trial.subscription_id).await;
+    assert_eq!(converted.status, 2);
+    assert_eq!(converted.current_period_end, "2025-02-15");
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn scheduler_skips_pass_while_lock_is_held() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+    let scheduler = BillingScheduler::new(Arc::new(app.db.clone()));
+
+    let plan = create_plan(&mut client, "Locked Plan", 3, "10.00").await;
+    let subscription = create_subscription(&mut client, &plan.plan_id, "").await;
+
+    // Another replica holds the lock
+    let lock = app
+        .db
+        .try_acquire_scheduler_lock()
+        .await
+        .unwrap()
+        .expect("Lock should be free");
+    assert!(scheduler
+        .run_once(date("2025-02-05"))
+        .await
+        .unwrap()
+        .is_none());
+
+    let unchanged = get_subscription(&mut client, &subscription.subscription_id).await;
+    assert_eq!(unchanged.current_period_end, "2025-02-01");
+
+    lock.release().await.unwrap();
+    let tick = scheduler
+        .run_once(date("2025-02-05"))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tick.renewed, 1);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,419 @@
+//! Batch and streaming usage ingestion integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use uuid::Uuid;
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+/// Subscribe to a plan with a single "API Calls" component including 1000
+/// calls at 0.01 per call beyond them.
+async fn subscribe(client: &mut Client) -> (Subscription, String) {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Metered Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "10.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![CreateUsageComponentInput {
+                name: "API Calls".to_string(),
+                unit_name: "calls".to_string(),
+                unit_price: "0.01".to_string(),
+                included_units: 1000,
+                ..Default::default()
+            }],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id,
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+
+    (subscription, plan.usage_components[0].component_id.clone())
+}
+
+fn usage(subscription_id: &str, component_id: &str, quantity: &str, key: &str) -> UsageRecordInput {
+    UsageRecordInput {
+        subscription_id: subscription_id.to_string(),
+        component_id: component_id.to_string(),
+        quantity: quantity.to_string(),
+        timestamp: None,
+        idempotency_key: key.to_string(),
+        metadata: "".to_string(),
+    }
+}
+
+async fn record_batch(
+    client: &mut Client,
+    records: Vec<UsageRecordInput>,
+) -> RecordUsageBatchResponse {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RecordUsageBatchRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            records,
+        },
+    );
+    client
+        .record_usage_batch(request)
+        .await
+        .unwrap()
+        .into_inner()
+}
+
+async fn total_usage(client: &mut Client, subscription_id: &str, cycle_id: &str) -> String {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetUsageSummaryRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            cycle_id: cycle_id.to_string(),
+        },
+    );
+    client
+        .get_usage_summary(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .component_summaries[0]
+        .total_quantity
+        .clone()
+}
+
+#[tokio::test]
+async fn record_usage_batch_dedupes_and_reports_each_record() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let response = record_batch(
+        &mut client,
+        vec![
+            usage(subscription_id, &component_id, "10", "batch-1"),
+            usage(subscription_id, &component_id, "20", "batch-2"),
+            // Same key as the first record
+            usage(subscription_id, &component_id, "10", "batch-1"),
+            usage(subscription_id, &component_id, "5", ""),
+            usage(
+                "99999999-9999-9999-9999-999999999999",
+                &component_id,
+                "5",
+                "batch-3",
+            ),
+            usage(
+                subscription_id,
+                "99999999-9999-9999-9999-999999999999",
+                "5",
+                "batch-4",
+            ),
+        ],
+    )
+    .await;
+
+    assert_eq!(response.recorded_count, 2);
+    assert_eq!(response.duplicate_count, 1);
+    assert_eq!(response.rejected_count, 3);
+    let statuses: Vec<i32> = response.results.iter().map(|r| r.status).collect();
+    assert_eq!(statuses, vec![1, 1, 2, 3, 3, 3]);
+    let indexes: Vec<i32> = response.results.iter().map(|r| r.index).collect();
+    assert_eq!(indexes, vec![0, 1, 2, 3, 4, 5]);
+    let first = response.results[0].usage_record.as_ref().unwrap();
+    assert_eq!(
+        response.results[2].usage_record.as_ref().unwrap().record_id,
+        first.record_id
+    );
+    assert_eq!(response.results[4].error, "Subscription not found");
+
+    // Keys recorded by an earlier batch are duplicates too
+    let response = record_batch(
+        &mut client,
+        vec![
+            usage(subscription_id, &component_id, "20", "batch-2"),
+            usage(subscription_id, &component_id, "30", "batch-5"),
+        ],
+    )
+    .await;
+    assert_eq!(response.recorded_count, 1);
+    assert_eq!(response.duplicate_count, 1);
+
+    assert_eq!(
+        total_usage(&mut client, subscription_id, "").await,
+        "60.0000"
+    );
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn concurrent_batches_touching_the_same_counters_both_succeed() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (first, first_component_id) = subscribe(&mut client).await;
+    let (second, second_component_id) = subscribe(&mut client).await;
+
+    // Both batches alternate between the two subscriptions' counters, in
+    // opposite orders
+    let batch = |prefix: &str, reversed: bool| -> Vec<UsageRecordInput> {
+        (0..50)
+            .flat_map(|i| {
+                let mut pair = vec![
+                    usage(
+                        &first.subscription_id,
+                        &first_component_id,
+                        "1",
+                        &format!("{}-first-{}", prefix, i),
+                    ),
+                    usage(
+                        &second.subscription_id,
+                        &second_component_id,
+                        "1",
+                        &format!("{}-second-{}", prefix, i),
+                    ),
+                ];
+                if reversed {
+                    pair.reverse();
+                }
+                pair
+            })
+            .collect()
+    };
+
+    let mut forward_client = client.clone();
+    let mut reverse_client = client.clone();
+    let (forward, reverse) = tokio::join!(
+        record_batch(&mut forward_client, batch("forward", false)),
+        record_batch(&mut reverse_client, batch("reverse", true)),
+    );
+
+    assert_eq!(forward.recorded_count, 100);
+    assert_eq!(reverse.recorded_count, 100);
+    let indexes: Vec<i32> = reverse.results.iter().map(|r| r.index).collect();
+    assert_eq!(indexes, (0..100).collect::<Vec<i32>>());
+    assert_eq!(
+        reverse.results[0]
+            .usage_record
+            .as_ref()
+            .unwrap()
+            .subscription_id,
+        second.subscription_id
+    );
+
+    assert_eq!(
+        total_usage(&mut client, &first.subscription_id, "").await,
+        "100.0000"
+    );
+    assert_eq!(
+        total_usage(&mut client, &second.subscription_id, "").await,
+        "100.0000"
+    );
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn stream_usage_records_across_messages() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.clone();
+
+    // Three messages of 400 records span several write chunks; the last
+    // message repeats the first record's key
+    let mut messages = Vec::new();
+    for message in 0..3 {
+        let mut records: Vec<UsageRecordInput> = (0..400)
+            .map(|i| {
+                let key = format!("stream-{}-{}", message, i);
+                usage(&subscription_id, &component_id, "1", &key)
+            })
+            .collect();
+        if message == 2 {
+            records[399] = usage(&subscription_id, &component_id, "1", "stream-0-0");
+        }
+        messages.push(StreamUsageRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            records,
+        });
+    }
+
+    let request = with_tenant(TEST_TENANT_ID, tokio_stream::iter(messages));
+    let response = client.stream_usage(request).await.unwrap().into_inner();
+
+    assert_eq!(response.results.len(), 1200);
+    assert_eq!(response.recorded_count, 1199);
+    assert_eq!(response.duplicate_count, 1);
+    assert_eq!(response.results[1199].index, 1199);
+    assert_eq!(response.results[1199].status, 2);
+
+    assert_eq!(
+        total_usage(&mut client, &subscription_id, "").await,
+        "1199.0000"
+    );
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn late_usage_is_attributed_to_its_cycle_and_billed_next() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let response = record_batch(
+        &mut client,
+        vec![usage(subscription_id, &component_id, "1200", "late-1")],
+    )
+    .await;
+    let first_cycle_id = response.results[0]
+        .usage_record
+        .as_ref()
+        .unwrap()
+        .cycle_id
+        .clone();
+
+    // Bill the first cycle and move on to the next
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+    let model = app
+        .db
+        .get_subscription(
+            Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+            Uuid::parse_str(subscription_id).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    let (_, second_cycle) = app.db.renew_subscription(&model).await.unwrap();
+
+    // Usage stamped in the first period still belongs to it; usage stamped
+    // in the second period goes to the new cycle
+    let mut in_second_period = usage(subscription_id, &component_id, "50", "late-3");
+    let second_start = second_cycle.period_start.and_hms_opt(12, 0, 0).unwrap();
+    in_second_period.timestamp = Some(prost_types::Timestamp {
+        seconds: second_start.and_utc().timestamp(),
+        nanos: 0,
+    });
+    let response = record_batch(
+        &mut client,
+        vec![
+            usage(subscription_id, &component_id, "300", "late-2"),
+            in_second_period,
+        ],
+    )
+    .await;
+    assert_eq!(response.recorded_count, 2);
+    assert_eq!(
+        response.results[0].usage_record.as_ref().unwrap().cycle_id,
+        first_cycle_id
+    );
+    assert_eq!(
+        response.results[1].usage_record.as_ref().unwrap().cycle_id,
+        second_cycle.cycle_id.to_string()
+    );
+    assert_eq!(
+        total_usage(&mut client, subscription_id, &first_cycle_id).await,
+        "1500.0000"
+    );
+
+    // The late units are billed with the second cycle, priced on top of the
+    // 200 billable units the first cycle was already billed for
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RunBillingForSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    client.run_billing_for_subscription(request).await.unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListChargesRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            cycle_id: second_cycle.cycle_id.to_string(),
+            charge_type: 2,
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    let charges = client
+        .list_charges(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .charges;
+    assert_eq!(charges.len(), 1);
+    assert_eq!(charges[0].amount, "3.0000");
+    assert!(charges[0]
+        .description
+        .contains("300.0000 late billable units"));
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListUsageRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            component_id: "".to_string(),
+            cycle_id: first_cycle_id,
+            is_invoiced: false,
+            page_size: 50,
+            page_token: "".to_string(),
+        },
+    );
+    let records = client
+        .list_usage(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .usage_records;
+    assert_eq!(records.len(), 2);
+    assert!(records.iter().all(|r| r.is_invoiced));
+
+    app.cleanup().await;
+}
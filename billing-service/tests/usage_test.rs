@@ -29,12 +29,14 @@ async fn create_subscription_with_usage(
                     unit_name: "calls".to_string(),
                     unit_price: "0.001".to_string(),
                     included_units: 1000,
+                    ..Default::default()
                 },
                 CreateUsageComponentInput {
                     name: "Storage".to_string(),
                     unit_name: "GB".to_string(),
                     unit_price: "0.10".to_string(),
                     included_units: 10,
+                    ..Default::default()
                 },
             ],
             metadata: "".to_string(),
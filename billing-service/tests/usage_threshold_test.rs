@@ -0,0 +1,330 @@
+//! Usage threshold integration tests for billing-service.
+
+mod common;
+
+use billing_service::grpc::proto::*;
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+
+type Client = billing_service::grpc::proto::billing_service_client::BillingServiceClient<
+    tonic::transport::Channel,
+>;
+
+/// Subscribe to a plan with a single "API Calls" component including 1000 calls.
+async fn subscribe(client: &mut Client) -> (Subscription, String) {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreatePlanRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            name: "Metered Plan".to_string(),
+            description: "".to_string(),
+            billing_interval: 3,
+            interval_count: 1,
+            base_price: "10.00".to_string(),
+            currency: "USD".to_string(),
+            tax_rate_id: "".to_string(),
+            usage_components: vec![CreateUsageComponentInput {
+                name: "API Calls".to_string(),
+                unit_name: "calls".to_string(),
+                unit_price: "0.01".to_string(),
+                included_units: 1000,
+                ..Default::default()
+            }],
+            metadata: "".to_string(),
+        },
+    );
+    let plan = client
+        .create_plan(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .plan
+        .unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        CreateSubscriptionRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            customer_id: TEST_CUSTOMER_ID.to_string(),
+            plan_id: plan.plan_id,
+            billing_anchor_day: 1,
+            start_date: "".to_string(),
+            trial_end_date: "".to_string(),
+            proration_mode: 1,
+            metadata: "".to_string(),
+        },
+    );
+    let subscription = client
+        .create_subscription(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .subscription
+        .unwrap();
+
+    (subscription, plan.usage_components[0].component_id.clone())
+}
+
+fn threshold_request(
+    subscription_id: &str,
+    component_id: &str,
+    threshold_type: i32,
+    quantity: &str,
+    percent_of_included: &str,
+) -> CreateUsageThresholdRequest {
+    CreateUsageThresholdRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        subscription_id: subscription_id.to_string(),
+        component_id: component_id.to_string(),
+        threshold_type,
+        quantity: quantity.to_string(),
+        percent_of_included: percent_of_included.to_string(),
+    }
+}
+
+async fn record(
+    client: &mut Client,
+    subscription_id: &str,
+    component_id: &str,
+    quantity: &str,
+    key: &str,
+) -> Result<RecordUsageResponse, tonic::Status> {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        RecordUsageRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            component_id: component_id.to_string(),
+            quantity: quantity.to_string(),
+            timestamp: None,
+            idempotency_key: key.to_string(),
+            metadata: "".to_string(),
+        },
+    );
+    client
+        .record_usage(request)
+        .await
+        .map(|response| response.into_inner())
+}
+
+async fn total_usage(client: &mut Client, subscription_id: &str) -> String {
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        GetUsageSummaryRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            cycle_id: "".to_string(),
+        },
+    );
+    client
+        .get_usage_summary(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .component_summaries[0]
+        .total_quantity
+        .clone()
+}
+
+#[tokio::test]
+async fn create_usage_threshold_validates_input() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let invalid = [
+        threshold_request(subscription_id, &component_id, 0, "100", ""),
+        threshold_request(subscription_id, &component_id, 1, "100", "80"),
+        threshold_request(subscription_id, &component_id, 1, "", ""),
+        threshold_request(subscription_id, &component_id, 2, "-5", ""),
+        threshold_request(
+            subscription_id,
+            "99999999-9999-9999-9999-999999999999",
+            1,
+            "100",
+            "",
+        ),
+    ];
+    for request in invalid {
+        let status = client
+            .create_usage_threshold(with_tenant(TEST_TENANT_ID, request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn alert_threshold_fires_once_per_cycle() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let request = threshold_request(subscription_id, &component_id, 1, "", "80");
+    let threshold = client
+        .create_usage_threshold(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner()
+        .threshold
+        .unwrap();
+
+    let response = record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "700",
+        "alert-1",
+    )
+    .await
+    .unwrap();
+    assert!(response.alerts.is_empty());
+
+    // Crossing 80% of the 1000 included calls records the alert
+    let response = record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "150",
+        "alert-2",
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.alerts.len(), 1);
+    let alert = &response.alerts[0];
+    assert_eq!(alert.threshold_id, threshold.threshold_id);
+    assert_eq!(alert.threshold_type, 1);
+    assert_eq!(alert.threshold_quantity, "800.0000");
+    assert_eq!(alert.usage_quantity, "850.0000");
+
+    // Further usage in the same cycle does not repeat it
+    let response = record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "100",
+        "alert-3",
+    )
+    .await
+    .unwrap();
+    assert!(response.alerts.is_empty());
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListUsageAlertsRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+            cycle_id: alert.cycle_id.clone(),
+            page_size: 10,
+            page_token: "".to_string(),
+        },
+    );
+    let alerts = client
+        .list_usage_alerts(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .alerts;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].alert_id, alert.alert_id);
+
+    assert_eq!(total_usage(&mut client, subscription_id).await, "950.0000");
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn limit_threshold_rejects_usage_past_it() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let (subscription, component_id) = subscribe(&mut client).await;
+    let subscription_id = subscription.subscription_id.as_str();
+
+    let request = threshold_request(subscription_id, &component_id, 2, "1200", "");
+    let threshold = client
+        .create_usage_threshold(with_tenant(TEST_TENANT_ID, request))
+        .await
+        .unwrap()
+        .into_inner()
+        .threshold
+        .unwrap();
+
+    record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "1100",
+        "limit-1",
+    )
+    .await
+    .unwrap();
+
+    let status = record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "101",
+        "limit-2",
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+    assert_eq!(total_usage(&mut client, subscription_id).await, "1100.0000");
+
+    // Usage up to the limit is accepted and reaching it is recorded
+    let response = record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "100",
+        "limit-3",
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.alerts.len(), 1);
+    assert_eq!(response.alerts[0].threshold_type, 2);
+
+    // Once the limit is deleted usage is accepted again
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        DeleteUsageThresholdRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            threshold_id: threshold.threshold_id,
+        },
+    );
+    client.delete_usage_threshold(request).await.unwrap();
+
+    let request = with_tenant(
+        TEST_TENANT_ID,
+        ListUsageThresholdsRequest {
+            tenant_id: TEST_TENANT_ID.to_string(),
+            subscription_id: subscription_id.to_string(),
+        },
+    );
+    let thresholds = client
+        .list_usage_thresholds(request)
+        .await
+        .unwrap()
+        .into_inner()
+        .thresholds;
+    assert!(thresholds.is_empty());
+
+    record(
+        &mut client,
+        subscription_id,
+        &component_id,
+        "101",
+        "limit-4",
+    )
+    .await
+    .unwrap();
+    assert_eq!(total_usage(&mut client, subscription_id).await, "1301.0000");
+
+    app.cleanup().await;
+}
@@ -0,0 +1,20 @@
+-- Tenant Profiles
+-- Issuer details printed on generated invoice, receipt and statement PDFs.
+-- The logo is stored in document-service and referenced by its document ID.
+
+CREATE TABLE tenant_profiles (
+    tenant_id UUID PRIMARY KEY,
+    legal_name VARCHAR(255) NOT NULL,
+    tax_id VARCHAR(50),
+    address_line1 VARCHAR(255),
+    address_line2 VARCHAR(255),
+    address_city VARCHAR(100),
+    address_state VARCHAR(100),
+    address_postal_code VARCHAR(20),
+    address_country VARCHAR(100),
+    email VARCHAR(255),
+    phone VARCHAR(50),
+    logo_document_id UUID,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,33 @@
+-- Invoice Templates
+-- Per-tenant branding for generated PDFs: colours, logo, label overrides for
+-- the document language, and footer/terms text with {{variable}} placeholders.
+-- A template is used for a customer it is assigned to, otherwise the tenant's
+-- default template is used.
+
+CREATE TABLE invoice_templates (
+    template_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    primary_color VARCHAR(7),
+    accent_color VARCHAR(7),
+    logo_document_id UUID,
+    footer_template TEXT,
+    terms_template TEXT,
+    labels JSONB NOT NULL DEFAULT '{}',
+    is_default BOOLEAN NOT NULL DEFAULT FALSE,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(tenant_id, name)
+);
+
+-- At most one default template per tenant
+CREATE UNIQUE INDEX idx_invoice_templates_default
+    ON invoice_templates(tenant_id) WHERE is_default = TRUE;
+
+CREATE TABLE customer_invoice_templates (
+    tenant_id UUID NOT NULL,
+    customer_id UUID NOT NULL,
+    template_id UUID NOT NULL REFERENCES invoice_templates(template_id),
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, customer_id)
+);
@@ -0,0 +1,40 @@
+-- Credit Notes
+-- A credit note is an invoice of type 'credit_note' that references the
+-- invoice it corrects. Each of its lines references the original line it
+-- credits, so credited quantities can be capped per line. The credit is
+-- applied to the original's balance (amount_credited); any part that exceeds
+-- the balance is left as the credit note's amount_due, a refundable credit
+-- owed to the customer.
+
+ALTER TABLE invoices ADD COLUMN amount_credited DECIMAL(19, 4) NOT NULL DEFAULT 0;
+
+ALTER TABLE line_items ADD COLUMN reference_line_item_id UUID REFERENCES line_items(line_item_id);
+
+CREATE INDEX idx_invoices_reference ON invoices(reference_invoice_id)
+    WHERE reference_invoice_id IS NOT NULL;
+CREATE INDEX idx_line_items_reference ON line_items(reference_line_item_id)
+    WHERE reference_line_item_id IS NOT NULL;
+
+-- Credits reduce the amount due alongside payments
+CREATE OR REPLACE FUNCTION recalculate_invoice_totals()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_invoice_id UUID;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        v_invoice_id := OLD.invoice_id;
+    ELSE
+        v_invoice_id := NEW.invoice_id;
+    END IF;
+
+    UPDATE invoices
+    SET subtotal = COALESCE((SELECT SUM(subtotal) FROM line_items WHERE invoice_id = v_invoice_id), 0),
+        tax_total = COALESCE((SELECT SUM(tax_amount) FROM line_items WHERE invoice_id = v_invoice_id), 0),
+        total = COALESCE((SELECT SUM(total) FROM line_items WHERE invoice_id = v_invoice_id), 0),
+        amount_due = COALESCE((SELECT SUM(total) FROM line_items WHERE invoice_id = v_invoice_id), 0)
+            - amount_paid - amount_credited
+    WHERE invoice_id = v_invoice_id;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
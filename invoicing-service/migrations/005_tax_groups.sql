@@ -0,0 +1,60 @@
+-- Tax Groups
+-- A tax group applies several component rates to a line item, such as GST
+-- split into CGST and SGST, or VAT with withholding. Components can be
+-- limited to intra-state or inter-state supplies, decided by comparing the
+-- invoice billing address with the tenant's registered address.
+--
+-- The tax charged on each line is stored per rate or component so invoices,
+-- statements and PDFs can show a breakdown.
+
+CREATE TABLE tax_groups (
+    tax_group_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(tenant_id, name)
+);
+
+CREATE INDEX idx_tax_groups_tenant ON tax_groups(tenant_id);
+
+CREATE TABLE tax_group_components (
+    tax_group_id UUID NOT NULL REFERENCES tax_groups(tax_group_id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    rate DECIMAL(10, 6) NOT NULL CHECK (rate >= 0 AND rate <= 1),
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('standard', 'compound', 'withholding')),
+    applies_to VARCHAR(20) NOT NULL DEFAULT 'any'
+        CHECK (applies_to IN ('any', 'intra_state', 'inter_state')),
+    sort_order INT NOT NULL,
+    PRIMARY KEY (tax_group_id, sort_order),
+    UNIQUE(tax_group_id, name)
+);
+
+ALTER TABLE line_items ADD COLUMN tax_group_id UUID REFERENCES tax_groups(tax_group_id);
+ALTER TABLE line_items ADD CONSTRAINT line_items_single_tax
+    CHECK (tax_rate_id IS NULL OR tax_group_id IS NULL);
+
+CREATE TABLE line_item_taxes (
+    line_item_id UUID NOT NULL REFERENCES line_items(line_item_id) ON DELETE CASCADE,
+    invoice_id UUID NOT NULL REFERENCES invoices(invoice_id) ON DELETE CASCADE,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('standard', 'compound', 'withholding')),
+    rate DECIMAL(10, 6) NOT NULL,
+    taxable_amount DECIMAL(19, 4) NOT NULL,
+    tax_amount DECIMAL(19, 4) NOT NULL,
+    sort_order INT NOT NULL,
+    PRIMARY KEY (line_item_id, sort_order)
+);
+
+CREATE INDEX idx_line_item_taxes_invoice ON line_item_taxes(invoice_id);
+
+-- Existing single-rate lines get one tax row each
+INSERT INTO line_item_taxes (
+    line_item_id, invoice_id, tenant_id, name, kind, rate, taxable_amount, tax_amount, sort_order
+)
+SELECT li.line_item_id, li.invoice_id, li.tenant_id, tr.name, 'standard', tr.rate,
+    CASE WHEN tr.calculation = 'inclusive' THEN li.subtotal - li.tax_amount ELSE li.subtotal END,
+    li.tax_amount, 0
+FROM line_items li
+JOIN tax_rates tr ON tr.tax_rate_id = li.tax_rate_id;
@@ -0,0 +1,79 @@
+-- Customer Payments
+-- A customer payment is money received from a customer that isn't tied to a
+-- single invoice. It is allocated across the customer's invoices; each
+-- allocation is a receipt against one invoice that references the payment.
+-- Whatever hasn't been allocated stays on the customer account as unapplied
+-- cash and can be allocated later. Unallocating removes the receipt and
+-- restores the invoice balance.
+
+CREATE TABLE customer_payments (
+    payment_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    payment_number VARCHAR(50) NOT NULL,
+    customer_id UUID NOT NULL,
+    amount DECIMAL(19, 4) NOT NULL CHECK (amount > 0),
+    amount_allocated DECIMAL(19, 4) NOT NULL DEFAULT 0,
+    currency VARCHAR(3) NOT NULL,
+    payment_method VARCHAR(50) NOT NULL,
+    payment_reference VARCHAR(255),
+    payment_date DATE NOT NULL,
+    journal_id UUID,
+    notes TEXT,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(tenant_id, payment_number),
+    CHECK (amount_allocated >= 0 AND amount_allocated <= amount)
+);
+
+CREATE INDEX idx_customer_payments_tenant ON customer_payments(tenant_id);
+CREATE INDEX idx_customer_payments_customer ON customer_payments(tenant_id, customer_id);
+CREATE INDEX idx_customer_payments_date ON customer_payments(tenant_id, payment_date);
+
+ALTER TABLE receipts ADD COLUMN payment_id UUID REFERENCES customer_payments(payment_id);
+
+CREATE INDEX idx_receipts_payment ON receipts(payment_id) WHERE payment_id IS NOT NULL;
+
+-- Keep invoice and payment balances in step with allocation receipts
+CREATE OR REPLACE FUNCTION update_invoice_on_receipt()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        UPDATE invoices
+        SET amount_paid = amount_paid + NEW.amount,
+            amount_due = amount_due - NEW.amount,
+            status = CASE
+                WHEN amount_due - NEW.amount <= 0 AND status = 'issued' THEN 'paid'
+                ELSE status
+            END
+        WHERE invoice_id = NEW.invoice_id;
+
+        IF NEW.payment_id IS NOT NULL THEN
+            UPDATE customer_payments
+            SET amount_allocated = amount_allocated + NEW.amount
+            WHERE payment_id = NEW.payment_id;
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        UPDATE invoices
+        SET amount_paid = amount_paid - OLD.amount,
+            amount_due = amount_due + OLD.amount,
+            status = CASE
+                WHEN amount_due + OLD.amount > 0 AND status = 'paid' THEN 'issued'
+                ELSE status
+            END
+        WHERE invoice_id = OLD.invoice_id;
+
+        IF OLD.payment_id IS NOT NULL THEN
+            UPDATE customer_payments
+            SET amount_allocated = amount_allocated - OLD.amount
+            WHERE payment_id = OLD.payment_id;
+        END IF;
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER trg_update_invoice_on_receipt ON receipts;
+CREATE TRIGGER trg_update_invoice_on_receipt
+    AFTER INSERT OR DELETE ON receipts
+    FOR EACH ROW
+    EXECUTE FUNCTION update_invoice_on_receipt();
@@ -0,0 +1,9 @@
+-- Line Item Types
+-- Discount and credit lines carry a negative amount. The type decides where a
+-- line is posted when the invoice is issued: charges credit revenue, discounts
+-- reduce it and credit lines draw down the customer's credit balance, so no
+-- ledger entry is negative.
+
+ALTER TABLE line_items
+    ADD COLUMN line_type VARCHAR(20) NOT NULL DEFAULT 'charge'
+        CHECK (line_type IN ('charge', 'discount', 'credit'));
@@ -13,6 +13,7 @@ pub struct InvoicingConfig {
     pub otlp_endpoint: Option<String>,
     pub database: DatabaseConfig,
     pub ledger_service: LedgerServiceConfig,
+    pub document_service: DocumentServiceConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +28,13 @@ pub struct LedgerServiceConfig {
     pub url: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct DocumentServiceConfig {
+    pub url: String,
+    /// How long signed download URLs for generated PDFs stay valid.
+    pub signed_url_ttl_seconds: i64,
+}
+
 impl InvoicingConfig {
     pub fn from_env() -> Result<Self, AppError> {
         let common = core_config::Config::load()?;
@@ -56,6 +64,14 @@ impl InvoicingConfig {
                 url: env::var("LEDGER_SERVICE_URL")
                     .unwrap_or_else(|_| "http://ledger-service:3001".to_string()),
             },
+            document_service: DocumentServiceConfig {
+                url: env::var("DOCUMENT_SERVICE_URL")
+                    .unwrap_or_else(|_| "http://document-service:3001".to_string()),
+                signed_url_ttl_seconds: env::var("DOCUMENT_URL_TTL_SECONDS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(3600),
+            },
         })
     }
 }
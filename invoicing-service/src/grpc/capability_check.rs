@@ -27,12 +27,18 @@ pub mod capabilities {
     /// Void invoices.
     pub const INVOICE_VOID: &str = "invoicing.invoice:void";
 
+    /// Issue credit notes against invoices.
+    pub const CREDIT_NOTE_CREATE: &str = "invoicing.credit_note:create";
+
     /// Record payments.
     pub const PAYMENT_RECORD: &str = "invoicing.payment:record";
 
     /// Read payments.
     pub const PAYMENT_READ: &str = "invoicing.payment:read";
 
+    /// Allocate customer payments to invoices and remove allocations.
+    pub const PAYMENT_ALLOCATE: &str = "invoicing.payment:allocate";
+
     /// Create tax rates.
     pub const TAX_RATE_CREATE: &str = "invoicing.tax_rate:create";
 
@@ -42,6 +48,27 @@ pub mod capabilities {
     /// Update tax rates.
     pub const TAX_RATE_UPDATE: &str = "invoicing.tax_rate:update";
 
+    /// Create tax groups.
+    pub const TAX_GROUP_CREATE: &str = "invoicing.tax_group:create";
+
+    /// Read tax groups.
+    pub const TAX_GROUP_READ: &str = "invoicing.tax_group:read";
+
     /// Read customer statements.
     pub const STATEMENT_READ: &str = "invoicing.statement:read";
+
+    /// Read the tenant's invoicing profile.
+    pub const TENANT_PROFILE_READ: &str = "invoicing.tenant_profile:read";
+
+    /// Update the tenant's invoicing profile.
+    pub const TENANT_PROFILE_UPDATE: &str = "invoicing.tenant_profile:update";
+
+    /// Create invoice templates.
+    pub const TEMPLATE_CREATE: &str = "invoicing.template:create";
+
+    /// Read and preview invoice templates.
+    pub const TEMPLATE_READ: &str = "invoicing.template:read";
+
+    /// Set the default and customer invoice templates.
+    pub const TEMPLATE_UPDATE: &str = "invoicing.template:update";
 }
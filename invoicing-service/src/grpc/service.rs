@@ -2,35 +2,61 @@
 
 use crate::grpc::proto::{
     invoicing_service_server::InvoicingService, AddLineItemRequest, AddLineItemResponse, Address,
-    CreateInvoiceRequest, CreateInvoiceResponse, CreateTaxRateRequest, CreateTaxRateResponse,
+    AllocatePaymentRequest, AllocatePaymentResponse, CreateCreditNoteRequest,
+    CreateCreditNoteResponse, CreateCustomerPaymentRequest, CreateCustomerPaymentResponse,
+    CreateInvoiceRequest, CreateInvoiceResponse, CreateInvoiceTemplateRequest,
+    CreateInvoiceTemplateResponse, CreateTaxGroupRequest, CreateTaxGroupResponse,
+    CreateTaxRateRequest, CreateTaxRateResponse, CustomerPayment as ProtoCustomerPayment,
     DeleteInvoiceRequest, DeleteInvoiceResponse, GenerateInvoicePdfRequest,
     GenerateInvoicePdfResponse, GenerateReceiptPdfRequest, GenerateReceiptPdfResponse,
     GenerateStatementPdfRequest, GenerateStatementPdfResponse, GenerateStatementRequest,
-    GenerateStatementResponse, GetInvoiceRequest, GetInvoiceResponse, GetReceiptRequest,
-    GetReceiptResponse, GetTaxRateRequest, GetTaxRateResponse, Invoice as ProtoInvoice,
-    InvoiceStatus as ProtoInvoiceStatus, InvoiceType as ProtoInvoiceType, IssueInvoiceRequest,
-    IssueInvoiceResponse, LineItem as ProtoLineItem, ListInvoicesRequest, ListInvoicesResponse,
-    ListReceiptsRequest, ListReceiptsResponse, ListTaxRatesRequest, ListTaxRatesResponse,
-    Receipt as ProtoReceipt, RecordPaymentRequest, RecordPaymentResponse, RemoveLineItemRequest,
-    RemoveLineItemResponse, Statement as ProtoStatement, StatementLine as ProtoStatementLine,
-    TaxCalculation, TaxRate as ProtoTaxRate, UpdateInvoiceRequest, UpdateInvoiceResponse,
-    UpdateLineItemRequest, UpdateLineItemResponse, UpdateTaxRateRequest, UpdateTaxRateResponse,
-    VoidInvoiceRequest, VoidInvoiceResponse,
+    GenerateStatementResponse, GetCustomerPaymentRequest, GetCustomerPaymentResponse,
+    GetInvoiceRequest, GetInvoiceResponse, GetInvoiceTemplateRequest, GetInvoiceTemplateResponse,
+    GetReceiptRequest, GetReceiptResponse, GetTaxGroupRequest, GetTaxGroupResponse,
+    GetTaxRateRequest, GetTaxRateResponse, GetTenantProfileRequest, GetTenantProfileResponse,
+    Invoice as ProtoInvoice, InvoiceStatus as ProtoInvoiceStatus,
+    InvoiceTemplate as ProtoInvoiceTemplate, InvoiceType as ProtoInvoiceType, IssueInvoiceRequest,
+    IssueInvoiceResponse, LineItem as ProtoLineItem, LineItemType as ProtoLineItemType,
+    ListCustomerPaymentsRequest, ListCustomerPaymentsResponse, ListInvoiceTemplatesRequest,
+    ListInvoiceTemplatesResponse, ListInvoicesRequest, ListInvoicesResponse, ListReceiptsRequest,
+    ListReceiptsResponse, ListTaxGroupsRequest, ListTaxGroupsResponse, ListTaxRatesRequest,
+    ListTaxRatesResponse, PlaceOfSupply as ProtoPlaceOfSupply, PreviewInvoiceTemplateRequest,
+    PreviewInvoiceTemplateResponse, Receipt as ProtoReceipt, RecordPaymentRequest,
+    RecordPaymentResponse, RemoveLineItemRequest, RemoveLineItemResponse,
+    SetCustomerTemplateRequest, SetCustomerTemplateResponse, SetDefaultTemplateRequest,
+    SetDefaultTemplateResponse, SetTenantProfileRequest, SetTenantProfileResponse,
+    Statement as ProtoStatement, StatementLine as ProtoStatementLine, TaxAmount, TaxCalculation,
+    TaxComponent as ProtoTaxComponent, TaxComponentKind as ProtoTaxComponentKind,
+    TaxGroup as ProtoTaxGroup, TaxRate as ProtoTaxRate, TemplateDocumentType,
+    TenantProfile as ProtoTenantProfile, UnallocatePaymentRequest, UnallocatePaymentResponse,
+    UpdateInvoiceRequest, UpdateInvoiceResponse, UpdateLineItemRequest, UpdateLineItemResponse,
+    UpdateTaxRateRequest, UpdateTaxRateResponse, VoidInvoiceRequest, VoidInvoiceResponse,
 };
 use crate::models::{
-    CreateInvoice, CreateLineItem, CreateReceipt, CreateTaxRate, Invoice, InvoiceStatus, LineItem,
-    ListInvoicesFilter, ListReceiptsFilter, Receipt, TaxRate, UpdateInvoice, UpdateLineItem,
+    CreateCreditNote, CreateCustomerPayment, CreateInvoice, CreateInvoiceTemplate, CreateLineItem,
+    CreateReceipt, CreateTaxComponent, CreateTaxGroup, CreateTaxRate, CreditNoteLine,
+    CustomerPayment, Invoice, InvoiceStatus, InvoiceTemplate, LineItem, LineItemTax, LineItemType,
+    ListCustomerPaymentsFilter, ListInvoicesFilter, ListReceiptsFilter, PaymentAllocation,
+    PlaceOfSupply, Receipt, SetTenantProfile, Statement, StatementLine, TaxBreakdown,
+    TaxComponentKind, TaxGroup, TaxRate, TenantProfile, UpdateInvoice, UpdateLineItem,
     UpdateTaxRate,
 };
+use crate::rendering::pdf::Image;
+use crate::rendering::{
+    invoice_filename, receipt_filename, render_invoice, render_receipt, render_sample_invoice,
+    render_sample_receipt, render_sample_statement, render_statement, statement_filename, Branding,
+    Letterhead,
+};
 use crate::services::metrics::{
     ERRORS_TOTAL, GRPC_REQUESTS_TOTAL, GRPC_REQUEST_DURATION, INVOICES_TOTAL, INVOICE_AMOUNT_TOTAL,
-    PAYMENT_AMOUNT_TOTAL, RECEIPTS_TOTAL,
+    PAYMENT_AMOUNT_TOTAL, PDFS_GENERATED_TOTAL, RECEIPTS_TOTAL,
 };
-use crate::services::Database;
+use crate::services::{Database, DocumentStore, StoredDocument};
 use chrono::NaiveDate;
 use prost_types::Timestamp;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use service_core::error::AppError;
 use service_core::grpc::{LedgerClient, TransactionEntry};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -52,6 +78,7 @@ fn format_decimal(d: &Decimal) -> String {
 pub struct InvoicingServiceImpl {
     db: Arc<Database>,
     ledger_client: Option<Arc<LedgerClient>>,
+    document_store: Option<DocumentStore>,
 }
 
 impl InvoicingServiceImpl {
@@ -60,6 +87,7 @@ impl InvoicingServiceImpl {
         Self {
             db,
             ledger_client: None,
+            document_store: None,
         }
     }
 
@@ -68,9 +96,16 @@ impl InvoicingServiceImpl {
         Self {
             db,
             ledger_client: Some(ledger_client),
+            document_store: None,
         }
     }
 
+    /// Store generated PDFs in document-service.
+    pub fn with_document_store(mut self, document_store: DocumentStore) -> Self {
+        self.document_store = Some(document_store);
+        self
+    }
+
     /// Convert domain TaxRate to proto TaxRate.
     fn tax_rate_to_proto(rate: &TaxRate) -> ProtoTaxRate {
         ProtoTaxRate {
@@ -92,6 +127,88 @@ impl InvoicingServiceImpl {
         }
     }
 
+    /// Convert a stored component kind to the proto enum.
+    fn tax_component_kind_to_proto(kind: &str) -> i32 {
+        match TaxComponentKind::from_string(kind) {
+            TaxComponentKind::Standard => ProtoTaxComponentKind::Standard as i32,
+            TaxComponentKind::Compound => ProtoTaxComponentKind::Compound as i32,
+            TaxComponentKind::Withholding => ProtoTaxComponentKind::Withholding as i32,
+        }
+    }
+
+    /// Convert domain TaxGroup to proto TaxGroup.
+    fn tax_group_to_proto(group: &TaxGroup) -> ProtoTaxGroup {
+        ProtoTaxGroup {
+            tax_group_id: group.tax_group_id.to_string(),
+            tenant_id: group.tenant_id.to_string(),
+            name: group.name.clone(),
+            components: group
+                .components
+                .iter()
+                .map(|component| ProtoTaxComponent {
+                    name: component.name.clone(),
+                    rate: format_decimal(&component.rate),
+                    kind: Self::tax_component_kind_to_proto(&component.kind),
+                    applies_to: match component.applies_to.as_str() {
+                        "intra_state" => ProtoPlaceOfSupply::IntraState as i32,
+                        "inter_state" => ProtoPlaceOfSupply::InterState as i32,
+                        _ => ProtoPlaceOfSupply::Unspecified as i32,
+                    },
+                })
+                .collect(),
+            active: group.active,
+            created_at: Some(Timestamp {
+                seconds: group.created_utc.timestamp(),
+                nanos: group.created_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+
+    /// Convert a line item tax to proto TaxAmount.
+    fn line_item_tax_to_proto(tax: &LineItemTax) -> TaxAmount {
+        TaxAmount {
+            name: tax.name.clone(),
+            kind: Self::tax_component_kind_to_proto(&tax.kind),
+            rate: format_decimal(&tax.rate),
+            taxable_amount: format_decimal(&tax.taxable_amount),
+            tax_amount: format_decimal(&tax.tax_amount),
+        }
+    }
+
+    /// Convert a tax breakdown row to proto TaxAmount.
+    fn tax_breakdown_to_proto(row: &TaxBreakdown) -> TaxAmount {
+        TaxAmount {
+            name: row.name.clone(),
+            kind: Self::tax_component_kind_to_proto(&row.kind),
+            rate: format_decimal(&row.rate),
+            taxable_amount: format_decimal(&row.taxable_amount),
+            tax_amount: format_decimal(&row.tax_amount),
+        }
+    }
+
+    /// Convert a line item type string to the proto enum value.
+    fn line_item_type_to_proto(line_type: &str) -> i32 {
+        match LineItemType::from_string(line_type) {
+            LineItemType::Charge => ProtoLineItemType::Charge as i32,
+            LineItemType::Discount => ProtoLineItemType::Discount as i32,
+            LineItemType::Credit => ProtoLineItemType::Credit as i32,
+        }
+    }
+
+    /// Ledger account a line item is posted to on issue: customer credit for
+    /// credit lines, else the line's account or the currency's revenue account
+    /// (contra-revenue for discounts).
+    fn line_item_ledger_account(item: &LineItem, currency: &str) -> String {
+        let default_account = match LineItemType::from_string(&item.line_type) {
+            LineItemType::Credit => return format!("CUSTOMER-CREDIT-{}", currency),
+            LineItemType::Discount => format!("DISCOUNTS-{}", currency),
+            LineItemType::Charge => format!("REVENUE-{}", currency),
+        };
+        item.ledger_account_id
+            .map(|id| id.to_string())
+            .unwrap_or(default_account)
+    }
+
     /// Convert domain LineItem to proto LineItem.
     fn line_item_to_proto(item: &LineItem) -> ProtoLineItem {
         ProtoLineItem {
@@ -112,6 +229,20 @@ impl InvoicingServiceImpl {
                 .map(|id| id.to_string())
                 .unwrap_or_default(),
             sort_order: item.sort_order,
+            reference_line_item_id: item
+                .reference_line_item_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            tax_group_id: item
+                .tax_group_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            taxes: item
+                .taxes
+                .iter()
+                .map(Self::line_item_tax_to_proto)
+                .collect(),
+            line_type: Self::line_item_type_to_proto(&item.line_type),
         }
     }
 
@@ -170,6 +301,11 @@ impl InvoicingServiceImpl {
             total: format_decimal(&invoice.total),
             amount_paid: format_decimal(&invoice.amount_paid),
             amount_due: format_decimal(&invoice.amount_due),
+            amount_credited: format_decimal(&invoice.amount_credited),
+            tax_breakdown: TaxBreakdown::from_line_items(line_items)
+                .iter()
+                .map(Self::tax_breakdown_to_proto)
+                .collect(),
             notes: invoice.notes.clone().unwrap_or_default(),
             reference_invoice_id: invoice
                 .reference_invoice_id
@@ -221,6 +357,37 @@ impl InvoicingServiceImpl {
                 seconds: receipt.created_utc.timestamp(),
                 nanos: receipt.created_utc.timestamp_subsec_nanos() as i32,
             }),
+            payment_id: receipt
+                .payment_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Convert domain CustomerPayment and its allocation receipts to proto.
+    fn customer_payment_to_proto(
+        payment: &CustomerPayment,
+        allocations: &[Receipt],
+    ) -> ProtoCustomerPayment {
+        ProtoCustomerPayment {
+            payment_id: payment.payment_id.to_string(),
+            tenant_id: payment.tenant_id.to_string(),
+            payment_number: payment.payment_number.clone(),
+            customer_id: payment.customer_id.to_string(),
+            amount: format_decimal(&payment.amount),
+            amount_allocated: format_decimal(&payment.amount_allocated),
+            amount_unapplied: format_decimal(&payment.amount_unapplied()),
+            currency: payment.currency.clone(),
+            payment_method: payment.payment_method.clone(),
+            payment_reference: payment.payment_reference.clone().unwrap_or_default(),
+            payment_date: payment.payment_date.to_string(),
+            journal_id: payment
+                .journal_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            notes: payment.notes.clone().unwrap_or_default(),
+            created_at: Some(Self::datetime_to_timestamp(payment.created_utc)),
+            allocations: allocations.iter().map(Self::receipt_to_proto).collect(),
         }
     }
 
@@ -231,6 +398,446 @@ impl InvoicingServiceImpl {
             nanos: dt.timestamp_subsec_nanos() as i32,
         }
     }
+
+    /// Convert domain Statement to proto Statement.
+    fn statement_to_proto(statement: &Statement) -> ProtoStatement {
+        ProtoStatement {
+            tenant_id: statement.tenant_id.to_string(),
+            customer_id: statement.customer_id.to_string(),
+            customer_name: statement.customer_name.clone(),
+            billing_address: Some(Address {
+                line1: statement.billing_line1.clone().unwrap_or_default(),
+                line2: statement.billing_line2.clone().unwrap_or_default(),
+                city: statement.billing_city.clone().unwrap_or_default(),
+                state: statement.billing_state.clone().unwrap_or_default(),
+                postal_code: statement.billing_postal_code.clone().unwrap_or_default(),
+                country: statement.billing_country.clone().unwrap_or_default(),
+            }),
+            currency: statement.currency.clone(),
+            period_start: statement.period_start.format("%Y-%m-%d").to_string(),
+            period_end: statement.period_end.format("%Y-%m-%d").to_string(),
+            opening_balance: format_decimal(&statement.opening_balance),
+            closing_balance: format_decimal(&statement.closing_balance),
+            total_debits: format_decimal(&statement.total_debits),
+            total_credits: format_decimal(&statement.total_credits),
+            unapplied_cash: format_decimal(&statement.unapplied_cash),
+            lines: statement
+                .lines
+                .iter()
+                .map(|line| ProtoStatementLine {
+                    date: line.date.format("%Y-%m-%d").to_string(),
+                    document_type: line.document_type.clone(),
+                    document_number: line.document_number.clone(),
+                    description: line.description.clone(),
+                    debit: format_decimal(&line.debit),
+                    credit: format_decimal(&line.credit),
+                    balance: format_decimal(&line.balance),
+                })
+                .collect(),
+            generated_at: Some(Self::datetime_to_timestamp(statement.generated_utc)),
+            tax_breakdown: statement
+                .tax_breakdown
+                .iter()
+                .map(Self::tax_breakdown_to_proto)
+                .collect(),
+        }
+    }
+
+    /// Convert domain TenantProfile to proto TenantProfile.
+    fn tenant_profile_to_proto(profile: &TenantProfile) -> ProtoTenantProfile {
+        ProtoTenantProfile {
+            tenant_id: profile.tenant_id.to_string(),
+            legal_name: profile.legal_name.clone(),
+            tax_id: profile.tax_id.clone().unwrap_or_default(),
+            address: Some(Address {
+                line1: profile.address_line1.clone().unwrap_or_default(),
+                line2: profile.address_line2.clone().unwrap_or_default(),
+                city: profile.address_city.clone().unwrap_or_default(),
+                state: profile.address_state.clone().unwrap_or_default(),
+                postal_code: profile.address_postal_code.clone().unwrap_or_default(),
+                country: profile.address_country.clone().unwrap_or_default(),
+            }),
+            email: profile.email.clone().unwrap_or_default(),
+            phone: profile.phone.clone().unwrap_or_default(),
+            logo_document_id: profile
+                .logo_document_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            created_at: Some(Self::datetime_to_timestamp(profile.created_utc)),
+            updated_at: Some(Self::datetime_to_timestamp(profile.updated_utc)),
+        }
+    }
+
+    /// Convert domain InvoiceTemplate to proto InvoiceTemplate.
+    fn invoice_template_to_proto(template: &InvoiceTemplate) -> ProtoInvoiceTemplate {
+        let labels = template
+            .labels
+            .as_object()
+            .map(|labels| {
+                labels
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ProtoInvoiceTemplate {
+            template_id: template.template_id.to_string(),
+            tenant_id: template.tenant_id.to_string(),
+            name: template.name.clone(),
+            primary_color: template.primary_color.clone().unwrap_or_default(),
+            accent_color: template.accent_color.clone().unwrap_or_default(),
+            logo_document_id: template
+                .logo_document_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            footer_template: template.footer_template.clone().unwrap_or_default(),
+            terms_template: template.terms_template.clone().unwrap_or_default(),
+            labels,
+            is_default: template.is_default,
+            created_at: Some(Self::datetime_to_timestamp(template.created_utc)),
+            updated_at: Some(Self::datetime_to_timestamp(template.updated_utc)),
+        }
+    }
+
+    /// Build a customer's statement for a period from their invoices, credit
+    /// notes and payments, with a running balance after each line.
+    async fn build_statement(
+        &self,
+        method: &str,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        period_start: NaiveDate,
+        period_end: NaiveDate,
+    ) -> Result<Statement, Status> {
+        // Get customer info from most recent invoice
+        let customer_info = self.db.get_customer_info(tenant_id, customer_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get customer info");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get customer info")
+        })?;
+
+        let Some(customer) = customer_info else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("No invoices found for customer"));
+        };
+
+        // Calculate opening balance
+        let opening_balance = self.db.calculate_opening_balance(tenant_id, customer_id, period_start).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to calculate opening balance");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to calculate opening balance")
+        })?;
+
+        // Get invoices in period
+        let invoices = self.db.get_invoices_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get invoices for statement");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get invoices")
+        })?;
+
+        // Get receipts in period
+        let receipts = self.db.get_receipts_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get receipts for statement");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get receipts")
+        })?;
+
+        // Get customer payments in period, with the invoices they're applied to
+        let payments = self.db.get_customer_payments_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get customer payments for statement");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get customer payments")
+        })?;
+        let payment_ids: Vec<Uuid> = payments.iter().map(|p| p.payment_id).collect();
+        let allocated_invoices = self.db.get_allocated_invoice_numbers(tenant_id, &payment_ids).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get payment allocations for statement");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get payment allocations")
+        })?;
+        let unapplied_cash = self.db.calculate_unapplied_cash(tenant_id, customer_id, period_end).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to calculate unapplied cash");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to calculate unapplied cash")
+        })?;
+
+        // Tax on invoices in period, net of credit notes
+        let tax_breakdown = self.db.get_tax_breakdown_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get tax breakdown for statement");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get tax breakdown")
+        })?;
+
+        // Build statement lines and calculate totals
+        let mut lines: Vec<StatementLine> = Vec::new();
+        let mut total_debits = Decimal::ZERO;
+        let mut total_credits = Decimal::ZERO;
+
+        // Add invoice lines (debits)
+        for inv in &invoices {
+            let issue_date = inv.issue_date.unwrap_or(inv.created_utc.date_naive());
+            let doc_type = if inv.invoice_type == "credit_note" {
+                "credit_note"
+            } else {
+                "invoice"
+            };
+            let (debit, credit) = if inv.invoice_type == "credit_note" {
+                total_credits += inv.total;
+                (Decimal::ZERO, inv.total)
+            } else {
+                total_debits += inv.total;
+                (inv.total, Decimal::ZERO)
+            };
+
+            lines.push(StatementLine {
+                date: issue_date,
+                document_type: doc_type.to_string(),
+                document_number: inv.invoice_number.clone().unwrap_or_default(),
+                description: format!(
+                    "{} {}",
+                    if inv.invoice_type == "credit_note" {
+                        "Credit note"
+                    } else {
+                        "Invoice"
+                    },
+                    inv.invoice_number
+                        .as_deref()
+                        .unwrap_or(&inv.invoice_id.to_string())
+                ),
+                debit,
+                credit,
+                balance: Decimal::ZERO, // Will be calculated below
+            });
+        }
+
+        // Add receipt lines (credits)
+        for receipt in &receipts {
+            total_credits += receipt.amount;
+            lines.push(StatementLine {
+                date: receipt.payment_date,
+                document_type: "payment".to_string(),
+                document_number: receipt.receipt_number.clone(),
+                description: format!("Payment - {}", receipt.payment_method),
+                debit: Decimal::ZERO,
+                credit: receipt.amount,
+                balance: Decimal::ZERO, // Will be calculated below
+            });
+        }
+
+        // Add customer payment lines (credits), naming the invoices each is
+        // applied to and any cash left unapplied
+        for payment in &payments {
+            total_credits += payment.amount;
+            let invoice_numbers: Vec<&str> = allocated_invoices
+                .iter()
+                .filter(|(payment_id, _)| *payment_id == payment.payment_id)
+                .map(|(_, number)| number.as_str())
+                .collect();
+            let mut applied = Vec::new();
+            if !invoice_numbers.is_empty() {
+                applied.push(format!("applied to {}", invoice_numbers.join(", ")));
+            }
+            if payment.amount_unapplied() > Decimal::ZERO {
+                applied.push(format!(
+                    "{} unapplied",
+                    format_decimal(&payment.amount_unapplied())
+                ));
+            }
+            let mut description = format!("Payment - {}", payment.payment_method);
+            if !applied.is_empty() {
+                description = format!("{} ({})", description, applied.join(", "));
+            }
+            lines.push(StatementLine {
+                date: payment.payment_date,
+                document_type: "payment".to_string(),
+                document_number: payment.payment_number.clone(),
+                description,
+                debit: Decimal::ZERO,
+                credit: payment.amount,
+                balance: Decimal::ZERO, // Will be calculated below
+            });
+        }
+
+        // Sort by date
+        lines.sort_by_key(|l| l.date);
+
+        // Calculate running balance
+        let mut running_balance = opening_balance;
+        for line in &mut lines {
+            running_balance = running_balance + line.debit - line.credit;
+            line.balance = running_balance;
+        }
+
+        Ok(Statement {
+            tenant_id,
+            customer_id,
+            customer_name: customer.customer_name,
+            billing_line1: customer.billing_line1,
+            billing_line2: customer.billing_line2,
+            billing_city: customer.billing_city,
+            billing_state: customer.billing_state,
+            billing_postal_code: customer.billing_postal_code,
+            billing_country: customer.billing_country,
+            currency: customer.currency,
+            period_start,
+            period_end,
+            opening_balance,
+            closing_balance: running_balance,
+            total_debits,
+            total_credits,
+            unapplied_cash,
+            lines,
+            tax_breakdown,
+            generated_utc: chrono::Utc::now(),
+        })
+    }
+
+    /// Load a customer payment with its allocation receipts.
+    async fn customer_payment(
+        &self,
+        method: &str,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+    ) -> Result<(CustomerPayment, Vec<Receipt>), Status> {
+        let payment = self
+            .db
+            .get_customer_payment(tenant_id, payment_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, payment_id = %payment_id, error = %e, "Failed to get customer payment");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get customer payment")
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "not_found"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Status::not_found("Customer payment not found")
+            })?;
+
+        let allocations = self
+            .db
+            .get_payment_allocations(tenant_id, payment_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, payment_id = %payment_id, error = %e, "Failed to get payment allocations");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get payment allocations")
+            })?;
+
+        Ok((payment, allocations))
+    }
+
+    /// The template for a customer's documents, if the customer has one
+    /// assigned or the tenant has a default.
+    async fn customer_template(
+        &self,
+        method: &str,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+    ) -> Result<Option<InvoiceTemplate>, Status> {
+        self.db
+            .get_template_for_customer(tenant_id, customer_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get invoice template");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice template")
+            })
+    }
+
+    /// Load the tenant's profile, logo and template branding for generated
+    /// PDFs. The template's logo replaces the profile's. A logo that can't be
+    /// fetched or decoded is left off rather than failing the document.
+    async fn letterhead(
+        &self,
+        method: &str,
+        tenant_id: Uuid,
+        template: Option<&InvoiceTemplate>,
+    ) -> Result<Letterhead, Status> {
+        let profile = self.db.get_tenant_profile(tenant_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, error = %e, "Failed to get tenant profile");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "error"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get tenant profile")
+        })?;
+
+        let logo_document_id = template
+            .and_then(|t| t.logo_document_id)
+            .or_else(|| profile.as_ref().and_then(|p| p.logo_document_id));
+        let logo = match (&self.document_store, logo_document_id) {
+            (Some(store), Some(document_id)) => match store.fetch(tenant_id, document_id).await {
+                Ok(data) => Image::decode(&data)
+                    .map_err(|e| {
+                        warn!(tenant_id = %tenant_id, document_id = %document_id, error = %e, "Failed to decode tenant logo");
+                    })
+                    .ok(),
+                Err(e) => {
+                    warn!(tenant_id = %tenant_id, document_id = %document_id, error = %e, "Failed to fetch tenant logo");
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        // Templates are validated when created, so this only falls back to the
+        // default branding if a stored template no longer parses
+        let branding = match template.map(Branding::from_template).transpose() {
+            Ok(branding) => branding.unwrap_or_default(),
+            Err(e) => {
+                warn!(tenant_id = %tenant_id, error = %e, "Invalid invoice template, using default branding");
+                Branding::default()
+            }
+        };
+
+        Ok(Letterhead {
+            profile,
+            logo,
+            branding,
+        })
+    }
+
+    /// Store a rendered PDF in document-service.
+    async fn store_pdf(
+        &self,
+        method: &str,
+        tenant_id: Uuid,
+        filename: &str,
+        pdf: Vec<u8>,
+    ) -> Result<StoredDocument, Status> {
+        let Some(store) = &self.document_store else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "failed_precondition"])
+                .inc();
+            return Err(Status::failed_precondition(
+                "Document storage is not configured",
+            ));
+        };
+
+        store.store_pdf(tenant_id, filename, pdf).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, filename = %filename, error = %e, "Failed to store PDF");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "error"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["document_error"]).inc();
+            Status::unavailable("Failed to store PDF")
+        })
+    }
 }
 
 #[tonic::async_trait]
@@ -596,280 +1203,240 @@ impl InvoicingService for InvoicingServiceImpl {
     }
 
     // -------------------------------------------------------------------------
-    // Invoice Methods
+    // Tax Group Methods
     // -------------------------------------------------------------------------
 
     #[instrument(
         skip(self, request),
         fields(
             service = "invoicing-service",
-            method = "CreateInvoice",
+            method = "CreateTaxGroup",
             tenant_id,
-            customer_id,
-            invoice_id
+            tax_group_id
         )
     )]
-    async fn create_invoice(
+    async fn create_tax_group(
         &self,
-        request: Request<CreateInvoiceRequest>,
-    ) -> Result<Response<CreateInvoiceResponse>, Status> {
+        request: Request<CreateTaxGroupRequest>,
+    ) -> Result<Response<CreateTaxGroupResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["CreateInvoice"])
+            .with_label_values(&["CreateTaxGroup"])
             .start_timer();
         let req = request.into_inner();
 
-        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+        let invalid = |message: String| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["CreateInvoice", "invalid_argument"])
+                .with_label_values(&["CreateTaxGroup", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid tenant_id format")
-        })?;
+            Status::invalid_argument(message)
+        };
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id)
+            .map_err(|_| invalid("Invalid tenant_id format".to_string()))?;
         Span::current().record("tenant_id", tenant_id.to_string());
 
-        let customer_id = Uuid::parse_str(&req.customer_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["CreateInvoice", "invalid_argument"])
-                .inc();
-            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid customer_id format")
-        })?;
-        Span::current().record("customer_id", customer_id.to_string());
+        let name = req.name.trim().to_string();
+        if name.is_empty() {
+            return Err(invalid("name is required".to_string()));
+        }
+        if req.components.is_empty() {
+            return Err(invalid("At least one component is required".to_string()));
+        }
 
-        let invoice_type = match req.invoice_type {
-            x if x == ProtoInvoiceType::CreditNote as i32 => "credit_note",
-            x if x == ProtoInvoiceType::Proforma as i32 => "proforma",
-            _ => "standard",
+        let mut components = Vec::with_capacity(req.components.len());
+        for (index, component) in req.components.into_iter().enumerate() {
+            let component_name = component.name.trim().to_string();
+            if component_name.is_empty() {
+                return Err(invalid(format!("components[{}].name is required", index)));
+            }
+            let rate = Decimal::from_str(&component.rate)
+                .ok()
+                .filter(|r| *r >= Decimal::ZERO && *r <= Decimal::ONE)
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "components[{}].rate must be a decimal between 0 and 1",
+                        index
+                    ))
+                })?;
+            let kind = match ProtoTaxComponentKind::try_from(component.kind) {
+                Ok(ProtoTaxComponentKind::Unspecified) | Ok(ProtoTaxComponentKind::Standard) => {
+                    TaxComponentKind::Standard
+                }
+                Ok(ProtoTaxComponentKind::Compound) => TaxComponentKind::Compound,
+                Ok(ProtoTaxComponentKind::Withholding) => TaxComponentKind::Withholding,
+                Err(_) => return Err(invalid(format!("components[{}].kind is invalid", index))),
+            };
+            // A compound component needs a tax before it to compound on
+            if kind == TaxComponentKind::Compound && index == 0 {
+                return Err(invalid(
+                    "A compound component cannot be the first component".to_string(),
+                ));
+            }
+            let applies_to = match ProtoPlaceOfSupply::try_from(component.applies_to) {
+                Ok(ProtoPlaceOfSupply::Unspecified) => None,
+                Ok(ProtoPlaceOfSupply::IntraState) => Some(PlaceOfSupply::IntraState),
+                Ok(ProtoPlaceOfSupply::InterState) => Some(PlaceOfSupply::InterState),
+                Err(_) => {
+                    return Err(invalid(format!(
+                        "components[{}].applies_to is invalid",
+                        index
+                    )))
+                }
+            };
+            components.push(CreateTaxComponent {
+                name: component_name,
+                rate,
+                kind,
+                applies_to,
+            });
+        }
+
+        let input = CreateTaxGroup {
+            tenant_id,
+            name,
+            components,
         };
 
-        let due_date = if req.due_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.due_date, "%Y-%m-%d").map_err(|_| {
+        let tax_group = self
+            .db
+            .create_tax_group(&input)
+            .await
+            .map_err(|e| match e {
+                AppError::Conflict(e) => {
                     GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["CreateInvoice", "invalid_argument"])
+                        .with_label_values(&["CreateTaxGroup", "already_exists"])
                         .inc();
-                    ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-                    Status::invalid_argument("Invalid due_date format")
-                })?,
-            )
-        };
-
-        let reference_invoice_id = if req.reference_invoice_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.reference_invoice_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["CreateInvoice", "invalid_argument"])
-                    .inc();
-                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-                Status::invalid_argument("Invalid reference_invoice_id format")
-            })?)
-        };
-
-        let metadata = if req.metadata.is_empty() {
-            None
-        } else {
-            Some(serde_json::from_str(&req.metadata).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["CreateInvoice", "invalid_argument"])
-                    .inc();
-                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-                Status::invalid_argument("Invalid metadata JSON")
-            })?)
-        };
-
-        let address = req.billing_address.as_ref();
-
-        let input = CreateInvoice {
-            tenant_id,
-            invoice_type: invoice_type.to_string(),
-            customer_id,
-            customer_name: req.customer_name,
-            billing_line1: address.map(|a| a.line1.clone()).filter(|s| !s.is_empty()),
-            billing_line2: address.map(|a| a.line2.clone()).filter(|s| !s.is_empty()),
-            billing_city: address.map(|a| a.city.clone()).filter(|s| !s.is_empty()),
-            billing_state: address.map(|a| a.state.clone()).filter(|s| !s.is_empty()),
-            billing_postal_code: address
-                .map(|a| a.postal_code.clone())
-                .filter(|s| !s.is_empty()),
-            billing_country: address.map(|a| a.country.clone()).filter(|s| !s.is_empty()),
-            currency: req.currency,
-            due_date,
-            notes: if req.notes.is_empty() {
-                None
-            } else {
-                Some(req.notes)
-            },
-            reference_invoice_id,
-            metadata,
-        };
-
-        let invoice = self.db.create_invoice(&input).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to create invoice");
-            GRPC_REQUESTS_TOTAL.with_label_values(&["CreateInvoice", "error"]).inc();
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to create invoice")
-        })?;
+                    Status::already_exists(e.to_string())
+                }
+                e => {
+                    warn!(tenant_id = %tenant_id, error = %e, "Failed to create tax group");
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["CreateTaxGroup", "error"])
+                        .inc();
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to create tax group")
+                }
+            })?;
 
-        Span::current().record("invoice_id", invoice.invoice_id.to_string());
+        Span::current().record("tax_group_id", tax_group.tax_group_id.to_string());
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["CreateInvoice", "ok"])
+            .with_label_values(&["CreateTaxGroup", "ok"])
             .inc();
-        INVOICES_TOTAL.with_label_values(&["draft"]).inc();
         timer.observe_duration();
 
-        info!(tenant_id = %tenant_id, customer_id = %customer_id, invoice_id = %invoice.invoice_id, "Draft invoice created");
+        info!(
+            tenant_id = %tenant_id,
+            tax_group_id = %tax_group.tax_group_id,
+            components = tax_group.components.len(),
+            "Tax group created"
+        );
 
-        Ok(Response::new(CreateInvoiceResponse {
-            invoice: Some(Self::invoice_to_proto(&invoice, &[])),
+        Ok(Response::new(CreateTaxGroupResponse {
+            tax_group: Some(Self::tax_group_to_proto(&tax_group)),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "GetInvoice")
+        fields(
+            service = "invoicing-service",
+            method = "GetTaxGroup",
+            tenant_id,
+            tax_group_id
+        )
     )]
-    async fn get_invoice(
+    async fn get_tax_group(
         &self,
-        request: Request<GetInvoiceRequest>,
-    ) -> Result<Response<GetInvoiceResponse>, Status> {
+        request: Request<GetTaxGroupRequest>,
+    ) -> Result<Response<GetTaxGroupResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetInvoice"])
+            .with_label_values(&["GetTaxGroup"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetInvoice", "invalid_argument"])
+                .with_label_values(&["GetTaxGroup", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+        let tax_group_id = Uuid::parse_str(&req.tax_group_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetInvoice", "invalid_argument"])
+                .with_label_values(&["GetTaxGroup", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid invoice_id format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tax_group_id format")
         })?;
+        Span::current().record("tax_group_id", tax_group_id.to_string());
 
-        let invoice = self
+        let tax_group = self
             .db
-            .get_invoice(tenant_id, invoice_id)
+            .get_tax_group(tenant_id, tax_group_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice");
+                warn!(tenant_id = %tenant_id, tax_group_id = %tax_group_id, error = %e, "Failed to get tax group");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetInvoice", "error"])
+                    .with_label_values(&["GetTaxGroup", "error"])
                     .inc();
-                Status::internal("Failed to get invoice")
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get tax group")
             })?;
 
         timer.observe_duration();
 
-        match invoice {
-            Some(inv) => {
-                let line_items = self
-                    .db
-                    .get_line_items(tenant_id, invoice_id)
-                    .await
-                    .map_err(|e| {
-                        warn!(error = %e, "Failed to get line items");
-                        Status::internal("Failed to get line items")
-                    })?;
+        match tax_group {
+            Some(group) => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetInvoice", "ok"])
+                    .with_label_values(&["GetTaxGroup", "ok"])
                     .inc();
-                Ok(Response::new(GetInvoiceResponse {
-                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
+                Ok(Response::new(GetTaxGroupResponse {
+                    tax_group: Some(Self::tax_group_to_proto(&group)),
                 }))
             }
             None => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetInvoice", "not_found"])
+                    .with_label_values(&["GetTaxGroup", "not_found"])
                     .inc();
-                Err(Status::not_found("Invoice not found"))
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Err(Status::not_found("Tax group not found"))
             }
         }
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "ListInvoices")
+        fields(service = "invoicing-service", method = "ListTaxGroups", tenant_id)
     )]
-    async fn list_invoices(
+    async fn list_tax_groups(
         &self,
-        request: Request<ListInvoicesRequest>,
-    ) -> Result<Response<ListInvoicesResponse>, Status> {
+        request: Request<ListTaxGroupsRequest>,
+    ) -> Result<Response<ListTaxGroupsResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["ListInvoices"])
+            .with_label_values(&["ListTaxGroups"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["ListInvoices", "invalid_argument"])
+                .with_label_values(&["ListTaxGroups", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
-
-        let status = if req.status == ProtoInvoiceStatus::Unspecified as i32 {
-            None
-        } else {
-            Some(match req.status {
-                x if x == ProtoInvoiceStatus::Issued as i32 => InvoiceStatus::Issued,
-                x if x == ProtoInvoiceStatus::Paid as i32 => InvoiceStatus::Paid,
-                x if x == ProtoInvoiceStatus::Void as i32 => InvoiceStatus::Void,
-                x if x == ProtoInvoiceStatus::Overdue as i32 => InvoiceStatus::Overdue,
-                _ => InvoiceStatus::Draft,
-            })
-        };
-
-        let customer_id = if req.customer_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.customer_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListInvoices", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid customer_id format")
-            })?)
-        };
-
-        let start_date = if req.start_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListInvoices", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid start_date format")
-                })?,
-            )
-        };
-
-        let end_date = if req.end_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListInvoices", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid end_date format")
-                })?,
-            )
-        };
+        Span::current().record("tenant_id", tenant_id.to_string());
 
         let page_token = if req.page_token.is_empty() {
             None
         } else {
             Some(Uuid::parse_str(&req.page_token).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListInvoices", "invalid_argument"])
+                    .with_label_values(&["ListTaxGroups", "invalid_argument"])
                     .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
                 Status::invalid_argument("Invalid page_token format")
             })?)
         };
@@ -880,670 +1447,875 @@ impl InvoicingService for InvoicingServiceImpl {
             req.page_size
         };
 
-        let filter = ListInvoicesFilter {
-            status,
-            customer_id,
-            start_date,
-            end_date,
-            page_size,
-            page_token,
-        };
-
-        let invoices = self
+        let tax_groups = self
             .db
-            .list_invoices(tenant_id, &filter)
+            .list_tax_groups(tenant_id, req.active_only, page_size, page_token)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to list invoices");
+                warn!(tenant_id = %tenant_id, error = %e, "Failed to list tax groups");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListInvoices", "error"])
+                    .with_label_values(&["ListTaxGroups", "error"])
                     .inc();
-                Status::internal("Failed to list invoices")
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to list tax groups")
             })?;
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["ListInvoices", "ok"])
+            .with_label_values(&["ListTaxGroups", "ok"])
             .inc();
         timer.observe_duration();
 
-        let next_page_token = if invoices.len() == filter.page_size as usize {
-            invoices.last().map(|i| i.invoice_id.to_string())
+        let next_page_token = if tax_groups.len() == page_size as usize {
+            tax_groups.last().map(|g| g.tax_group_id.to_string())
         } else {
             None
         };
 
-        Ok(Response::new(ListInvoicesResponse {
-            invoices: invoices
-                .iter()
-                .map(|i| Self::invoice_to_proto(i, &[]))
-                .collect(),
+        Ok(Response::new(ListTaxGroupsResponse {
+            tax_groups: tax_groups.iter().map(Self::tax_group_to_proto).collect(),
             next_page_token: next_page_token.unwrap_or_default(),
         }))
     }
 
+    // -------------------------------------------------------------------------
+    // Tenant Profile Methods
+    // -------------------------------------------------------------------------
+
     #[instrument(
         skip(self, request),
-        fields(
-            service = "invoicing-service",
-            method = "IssueInvoice",
-            tenant_id,
-            invoice_id
-        )
+        fields(service = "invoicing-service", method = "SetTenantProfile", tenant_id)
     )]
-    async fn issue_invoice(
+    async fn set_tenant_profile(
         &self,
-        request: Request<IssueInvoiceRequest>,
-    ) -> Result<Response<IssueInvoiceResponse>, Status> {
+        request: Request<SetTenantProfileRequest>,
+    ) -> Result<Response<SetTenantProfileResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["IssueInvoice"])
+            .with_label_values(&["SetTenantProfile"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["IssueInvoice", "invalid_argument"])
+                .with_label_values(&["SetTenantProfile", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
         Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+        if req.legal_name.trim().is_empty() {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["IssueInvoice", "invalid_argument"])
+                .with_label_values(&["SetTenantProfile", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid invoice_id format")
-        })?;
-        Span::current().record("invoice_id", invoice_id.to_string());
+            return Err(Status::invalid_argument("legal_name is required"));
+        }
 
-        let issue_date = if req.issue_date.is_empty() {
-            chrono::Utc::now().date_naive()
+        let logo_document_id = if req.logo_document_id.is_empty() {
+            None
         } else {
-            NaiveDate::parse_from_str(&req.issue_date, "%Y-%m-%d").map_err(|_| {
+            Some(Uuid::parse_str(&req.logo_document_id).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["IssueInvoice", "invalid_argument"])
+                    .with_label_values(&["SetTenantProfile", "invalid_argument"])
                     .inc();
                 ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-                Status::invalid_argument("Invalid issue_date format")
-            })?
+                Status::invalid_argument("Invalid logo_document_id format")
+            })?)
         };
 
-        // Get invoice and line items for ledger entry
-        let existing_invoice = self
-            .db
-            .get_invoice(tenant_id, invoice_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice for ledger entry");
-                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                Status::internal("Failed to get invoice")
-            })?;
-        let existing_invoice = existing_invoice.ok_or_else(|| {
+        let non_empty = |s: String| Some(s).filter(|s| !s.is_empty());
+        let address = req.address.unwrap_or_default();
+        let input = SetTenantProfile {
+            tenant_id,
+            legal_name: req.legal_name.trim().to_string(),
+            tax_id: non_empty(req.tax_id),
+            address_line1: non_empty(address.line1),
+            address_line2: non_empty(address.line2),
+            address_city: non_empty(address.city),
+            address_state: non_empty(address.state),
+            address_postal_code: non_empty(address.postal_code),
+            address_country: non_empty(address.country),
+            email: non_empty(req.email),
+            phone: non_empty(req.phone),
+            logo_document_id,
+        };
+
+        let profile = self.db.set_tenant_profile(&input).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, error = %e, "Failed to set tenant profile");
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["IssueInvoice", "not_found"])
+                .with_label_values(&["SetTenantProfile", "error"])
                 .inc();
-            Status::not_found("Invoice not found")
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to set tenant profile")
         })?;
 
-        let line_items = self
-            .db
-            .get_line_items(tenant_id, invoice_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get line items for ledger entry");
-                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                Status::internal("Failed to get line items")
-            })?;
-
-        // Create ledger entry if ledger client is available
-        let journal_id = if let Some(ref ledger_client) = self.ledger_client {
-            // Build ledger entries: Debit A/R, Credit Revenue accounts
-            // Convention: A/R account = "AR-{currency}", Revenue from line item ledger_account_id
-            let ar_account_id = format!("AR-{}", existing_invoice.currency);
-            let total_str = format_decimal(&existing_invoice.total);
-            let idempotency_key = format!("invoice-issue-{}", invoice_id);
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["SetTenantProfile", "ok"])
+            .inc();
+        timer.observe_duration();
 
-            let mut entries = vec![TransactionEntry::debit(&ar_account_id, &total_str)];
+        info!(tenant_id = %tenant_id, "Tenant profile set");
 
-            // Credit revenue accounts based on line items
-            for item in &line_items {
-                let revenue_account = item
-                    .ledger_account_id
-                    .map(|id| id.to_string())
-                    .unwrap_or_else(|| format!("REVENUE-{}", existing_invoice.currency));
-                entries.push(TransactionEntry::credit(
-                    &revenue_account,
-                    &format_decimal(&item.total),
-                ));
-            }
+        Ok(Response::new(SetTenantProfileResponse {
+            profile: Some(Self::tenant_profile_to_proto(&profile)),
+        }))
+    }
 
-            let metadata = serde_json::json!({
-                "source": "invoicing-service",
-                "invoice_id": invoice_id.to_string(),
-                "customer_id": existing_invoice.customer_id.to_string(),
-            })
-            .to_string();
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "GetTenantProfile", tenant_id)
+    )]
+    async fn get_tenant_profile(
+        &self,
+        request: Request<GetTenantProfileRequest>,
+    ) -> Result<Response<GetTenantProfileResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetTenantProfile"])
+            .start_timer();
+        let req = request.into_inner();
 
-            match ledger_client
-                .post_transaction(
-                    &tenant_id.to_string(),
-                    entries,
-                    Some(&issue_date.to_string()),
-                    &idempotency_key,
-                    Some(&metadata),
-                )
-                .await
-            {
-                Ok(response) => {
-                    if let Some(ref txn) = response.transaction {
-                        info!(journal_id = %txn.journal_id, "Ledger entry created for invoice issue");
-                        Uuid::parse_str(&txn.journal_id).ok()
-                    } else {
-                        warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, "Ledger response missing transaction");
-                        None
-                    }
-                }
-                Err(e) => {
-                    // Log but don't fail - ledger integration is optional enhancement
-                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create ledger entry for invoice issue");
-                    None
-                }
-            }
-        } else {
-            None
-        };
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTenantProfile", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice = self.db.issue_invoice(tenant_id, invoice_id, issue_date, journal_id).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to issue invoice");
-            GRPC_REQUESTS_TOTAL.with_label_values(&["IssueInvoice", "error"]).inc();
+        let profile = self.db.get_tenant_profile(tenant_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, error = %e, "Failed to get tenant profile");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTenantProfile", "error"])
+                .inc();
             ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            match e {
-                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
-                _ => Status::internal("Failed to issue invoice"),
-            }
+            Status::internal("Failed to get tenant profile")
         })?;
 
         timer.observe_duration();
 
-        match invoice {
-            Some(inv) => {
-                let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
-                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
-                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                    Status::internal("Failed to get line items")
-                })?;
+        match profile {
+            Some(profile) => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["IssueInvoice", "ok"])
+                    .with_label_values(&["GetTenantProfile", "ok"])
                     .inc();
-                INVOICES_TOTAL.with_label_values(&["issued"]).inc();
-                // Track invoice amount by currency for business metrics
-                if let Some(amount) = inv.total.to_f64() {
-                    INVOICE_AMOUNT_TOTAL
-                        .with_label_values(&[&inv.currency])
-                        .inc_by(amount);
-                }
-                info!(
-                    tenant_id = %tenant_id,
-                    invoice_id = %inv.invoice_id,
-                    invoice_number = %inv.invoice_number.as_deref().unwrap_or(""),
-                    total = %inv.total,
-                    currency = %inv.currency,
-                    "Invoice issued"
-                );
-                Ok(Response::new(IssueInvoiceResponse {
-                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
+                Ok(Response::new(GetTenantProfileResponse {
+                    profile: Some(Self::tenant_profile_to_proto(&profile)),
                 }))
             }
             None => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["IssueInvoice", "not_found"])
+                    .with_label_values(&["GetTenantProfile", "not_found"])
                     .inc();
                 ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
-                Err(Status::not_found("Invoice not found"))
+                Err(Status::not_found("Tenant profile not found"))
             }
         }
     }
 
+    // -------------------------------------------------------------------------
+    // Invoice Template Methods
+    // -------------------------------------------------------------------------
+
     #[instrument(
         skip(self, request),
         fields(
             service = "invoicing-service",
-            method = "VoidInvoice",
+            method = "CreateInvoiceTemplate",
             tenant_id,
-            invoice_id
+            template_id
         )
     )]
-    async fn void_invoice(
+    async fn create_invoice_template(
         &self,
-        request: Request<VoidInvoiceRequest>,
-    ) -> Result<Response<VoidInvoiceResponse>, Status> {
+        request: Request<CreateInvoiceTemplateRequest>,
+    ) -> Result<Response<CreateInvoiceTemplateResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["VoidInvoice"])
+            .with_label_values(&["CreateInvoiceTemplate"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let invalid = |message: String| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateInvoiceTemplate", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument(message)
+        };
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id)
+            .map_err(|_| invalid("Invalid tenant_id format".to_string()))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let name = req.name.trim().to_string();
+        if name.is_empty() {
+            return Err(invalid("name is required".to_string()));
+        }
+
+        let logo_document_id = if req.logo_document_id.is_empty() {
+            None
+        } else {
+            Some(
+                Uuid::parse_str(&req.logo_document_id)
+                    .map_err(|_| invalid("Invalid logo_document_id format".to_string()))?,
+            )
+        };
+
+        let non_empty = |s: String| Some(s).filter(|s| !s.trim().is_empty());
+        let primary_color = non_empty(req.primary_color);
+        let accent_color = non_empty(req.accent_color);
+        let footer_template = non_empty(req.footer_template);
+        let terms_template = non_empty(req.terms_template);
+        let labels = serde_json::Value::Object(
+            req.labels
+                .into_iter()
+                .map(|(key, value)| (key, serde_json::Value::String(value)))
+                .collect(),
+        );
+
+        // Reject bad colours, unknown labels and template variables up front
+        // so rendering never has to
+        Branding::new(
+            primary_color.as_deref(),
+            accent_color.as_deref(),
+            &labels,
+            footer_template.as_deref(),
+            terms_template.as_deref(),
+        )
+        .map_err(|e| invalid(e.to_string()))?;
+
+        let input = CreateInvoiceTemplate {
+            tenant_id,
+            name,
+            primary_color,
+            accent_color,
+            logo_document_id,
+            footer_template,
+            terms_template,
+            labels,
+            is_default: req.set_default,
+        };
+
+        let template = self
+            .db
+            .create_invoice_template(&input)
+            .await
+            .map_err(|e| match e {
+                AppError::Conflict(e) => {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["CreateInvoiceTemplate", "already_exists"])
+                        .inc();
+                    Status::already_exists(e.to_string())
+                }
+                e => {
+                    warn!(tenant_id = %tenant_id, error = %e, "Failed to create invoice template");
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["CreateInvoiceTemplate", "error"])
+                        .inc();
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to create invoice template")
+                }
+            })?;
+
+        Span::current().record("template_id", template.template_id.to_string());
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["CreateInvoiceTemplate", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        info!(
+            tenant_id = %tenant_id,
+            template_id = %template.template_id,
+            is_default = template.is_default,
+            "Invoice template created"
+        );
+
+        Ok(Response::new(CreateInvoiceTemplateResponse {
+            template: Some(Self::invoice_template_to_proto(&template)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "GetInvoiceTemplate",
+            tenant_id,
+            template_id
+        )
+    )]
+    async fn get_invoice_template(
+        &self,
+        request: Request<GetInvoiceTemplateRequest>,
+    ) -> Result<Response<GetInvoiceTemplateResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetInvoiceTemplate"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["VoidInvoice", "invalid_argument"])
+                .with_label_values(&["GetInvoiceTemplate", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
         Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+        let template_id = Uuid::parse_str(&req.template_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["VoidInvoice", "invalid_argument"])
+                .with_label_values(&["GetInvoiceTemplate", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid invoice_id format")
+            Status::invalid_argument("Invalid template_id format")
         })?;
-        Span::current().record("invoice_id", invoice_id.to_string());
+        Span::current().record("template_id", template_id.to_string());
 
-        // Get invoice for reversing ledger entry
-        let existing_invoice = self
+        let template = self
             .db
-            .get_invoice(tenant_id, invoice_id)
+            .get_invoice_template(tenant_id, template_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice for reversing entry");
+                warn!(tenant_id = %tenant_id, template_id = %template_id, error = %e, "Failed to get invoice template");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetInvoiceTemplate", "error"])
+                    .inc();
                 ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                Status::internal("Failed to get invoice")
+                Status::internal("Failed to get invoice template")
             })?;
 
-        if let Some(ref inv) = existing_invoice {
-            // Create reversing ledger entry if ledger client is available
-            if let Some(ref ledger_client) = self.ledger_client {
-                let line_items = self
-                    .db
-                    .get_line_items(tenant_id, invoice_id)
-                    .await
-                    .map_err(|e| {
-                        warn!(error = %e, "Failed to get line items for reversing entry");
-                        ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                        Status::internal("Failed to get line items")
-                    })?;
-
-                // Reverse the original entry: Credit A/R, Debit Revenue
-                let ar_account_id = format!("AR-{}", inv.currency);
-                let total_str = format_decimal(&inv.total);
-                let idempotency_key = format!("invoice-void-{}", invoice_id);
-
-                let mut entries = vec![TransactionEntry::credit(&ar_account_id, &total_str)];
-
-                // Debit revenue accounts (reversal)
-                for item in &line_items {
-                    let revenue_account = item
-                        .ledger_account_id
-                        .map(|id| id.to_string())
-                        .unwrap_or_else(|| format!("REVENUE-{}", inv.currency));
-                    entries.push(TransactionEntry::debit(
-                        &revenue_account,
-                        &format_decimal(&item.total),
-                    ));
-                }
-
-                let metadata = serde_json::json!({
-                    "source": "invoicing-service",
-                    "invoice_id": invoice_id.to_string(),
-                    "action": "void",
-                    "original_journal_id": inv.journal_id.map(|j| j.to_string()),
-                })
-                .to_string();
-
-                if let Err(e) = ledger_client
-                    .post_transaction(
-                        &tenant_id.to_string(),
-                        entries,
-                        Some(&chrono::Utc::now().date_naive().to_string()),
-                        &idempotency_key,
-                        Some(&metadata),
-                    )
-                    .await
-                {
-                    // Log but don't fail - ledger integration is optional enhancement
-                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create reversing ledger entry");
-                } else {
-                    info!(invoice_id = %invoice_id, "Reversing ledger entry created for voided invoice");
-                }
-            }
-        }
-
-        let invoice = self.db.void_invoice(tenant_id, invoice_id).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to void invoice");
-            GRPC_REQUESTS_TOTAL.with_label_values(&["VoidInvoice", "error"]).inc();
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            match e {
-                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
-                _ => Status::internal("Failed to void invoice"),
-            }
-        })?;
-
         timer.observe_duration();
 
-        match invoice {
-            Some(inv) => {
-                let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
-                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
-                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                    Status::internal("Failed to get line items")
-                })?;
+        match template {
+            Some(template) => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["VoidInvoice", "ok"])
+                    .with_label_values(&["GetInvoiceTemplate", "ok"])
                     .inc();
-                INVOICES_TOTAL.with_label_values(&["void"]).inc();
-                info!(tenant_id = %tenant_id, invoice_id = %inv.invoice_id, "Invoice voided");
-                Ok(Response::new(VoidInvoiceResponse {
-                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
+                Ok(Response::new(GetInvoiceTemplateResponse {
+                    template: Some(Self::invoice_template_to_proto(&template)),
                 }))
             }
             None => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["VoidInvoice", "not_found"])
+                    .with_label_values(&["GetInvoiceTemplate", "not_found"])
                     .inc();
                 ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
-                Err(Status::not_found("Invoice not found"))
+                Err(Status::not_found("Invoice template not found"))
             }
         }
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "DeleteInvoice")
+        fields(
+            service = "invoicing-service",
+            method = "ListInvoiceTemplates",
+            tenant_id
+        )
     )]
-    async fn delete_invoice(
+    async fn list_invoice_templates(
         &self,
-        request: Request<DeleteInvoiceRequest>,
-    ) -> Result<Response<DeleteInvoiceResponse>, Status> {
+        request: Request<ListInvoiceTemplatesRequest>,
+    ) -> Result<Response<ListInvoiceTemplatesResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["DeleteInvoice"])
+            .with_label_values(&["ListInvoiceTemplates"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["DeleteInvoice", "invalid_argument"])
+                .with_label_values(&["ListInvoiceTemplates", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["DeleteInvoice", "invalid_argument"])
-                .inc();
-            Status::invalid_argument("Invalid invoice_id format")
-        })?;
-
-        let deleted = self
-            .db
-            .delete_invoice(tenant_id, invoice_id)
-            .await
+        let page_token = if req.page_token.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.page_token).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListInvoiceTemplates", "invalid_argument"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid page_token format")
+            })?)
+        };
+
+        let page_size = if req.page_size <= 0 {
+            20
+        } else {
+            req.page_size
+        };
+
+        let templates = self
+            .db
+            .list_invoice_templates(tenant_id, page_size, page_token)
+            .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to delete invoice");
+                warn!(tenant_id = %tenant_id, error = %e, "Failed to list invoice templates");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["DeleteInvoice", "error"])
+                    .with_label_values(&["ListInvoiceTemplates", "error"])
                     .inc();
-                Status::internal("Failed to delete invoice")
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to list invoice templates")
             })?;
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["DeleteInvoice", "ok"])
+            .with_label_values(&["ListInvoiceTemplates", "ok"])
             .inc();
         timer.observe_duration();
 
-        Ok(Response::new(DeleteInvoiceResponse { success: deleted }))
-    }
+        let next_page_token = if templates.len() == page_size as usize {
+            templates.last().map(|t| t.template_id.to_string())
+        } else {
+            None
+        };
 
-    // -------------------------------------------------------------------------
-    // Line Item Methods
-    // -------------------------------------------------------------------------
+        Ok(Response::new(ListInvoiceTemplatesResponse {
+            templates: templates
+                .iter()
+                .map(Self::invoice_template_to_proto)
+                .collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
+        }))
+    }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "AddLineItem")
+        fields(
+            service = "invoicing-service",
+            method = "SetDefaultTemplate",
+            tenant_id,
+            template_id
+        )
     )]
-    async fn add_line_item(
+    async fn set_default_template(
         &self,
-        request: Request<AddLineItemRequest>,
-    ) -> Result<Response<AddLineItemResponse>, Status> {
+        request: Request<SetDefaultTemplateRequest>,
+    ) -> Result<Response<SetDefaultTemplateResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["AddLineItem"])
+            .with_label_values(&["SetDefaultTemplate"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .with_label_values(&["SetDefaultTemplate", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+        let template_id = Uuid::parse_str(&req.template_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .with_label_values(&["SetDefaultTemplate", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid invoice_id format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid template_id format")
         })?;
+        Span::current().record("template_id", template_id.to_string());
 
-        let quantity = Decimal::from_str(&req.quantity).map_err(|_| {
+        let template = self
+            .db
+            .set_default_template(tenant_id, template_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, template_id = %template_id, error = %e, "Failed to set default template");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["SetDefaultTemplate", "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to set default template")
+            })?;
+
+        timer.observe_duration();
+
+        match template {
+            Some(template) => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["SetDefaultTemplate", "ok"])
+                    .inc();
+                info!(tenant_id = %tenant_id, template_id = %template_id, "Default invoice template set");
+                Ok(Response::new(SetDefaultTemplateResponse {
+                    template: Some(Self::invoice_template_to_proto(&template)),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["SetDefaultTemplate", "not_found"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Err(Status::not_found("Invoice template not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "SetCustomerTemplate",
+            tenant_id,
+            customer_id
+        )
+    )]
+    async fn set_customer_template(
+        &self,
+        request: Request<SetCustomerTemplateRequest>,
+    ) -> Result<Response<SetCustomerTemplateResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["SetCustomerTemplate"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .with_label_values(&["SetCustomerTemplate", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid quantity format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let unit_price = Decimal::from_str(&req.unit_price).map_err(|_| {
+        let customer_id = Uuid::parse_str(&req.customer_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .with_label_values(&["SetCustomerTemplate", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid unit_price format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid customer_id format")
         })?;
+        Span::current().record("customer_id", customer_id.to_string());
 
-        let tax_rate_id = if req.tax_rate_id.is_empty() {
+        let template_id = if req.template_id.is_empty() {
             None
         } else {
-            Some(Uuid::parse_str(&req.tax_rate_id).map_err(|_| {
+            Some(Uuid::parse_str(&req.template_id).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .with_label_values(&["SetCustomerTemplate", "invalid_argument"])
                     .inc();
-                Status::invalid_argument("Invalid tax_rate_id format")
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid template_id format")
             })?)
         };
 
-        let ledger_account_id = if req.ledger_account_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.ledger_account_id).map_err(|_| {
+        if let Some(template_id) = template_id {
+            let template = self
+                .db
+                .get_invoice_template(tenant_id, template_id)
+                .await
+                .map_err(|e| {
+                    warn!(tenant_id = %tenant_id, template_id = %template_id, error = %e, "Failed to get invoice template");
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["SetCustomerTemplate", "error"])
+                        .inc();
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to get invoice template")
+                })?;
+            if template.is_none() {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .with_label_values(&["SetCustomerTemplate", "not_found"])
                     .inc();
-                Status::invalid_argument("Invalid ledger_account_id format")
-            })?)
-        };
-
-        let input = CreateLineItem {
-            tenant_id,
-            invoice_id,
-            description: req.description,
-            quantity,
-            unit_price,
-            tax_rate_id,
-            ledger_account_id,
-            sort_order: req.sort_order,
-        };
-
-        let line_item = self.db.add_line_item(&input).await.map_err(|e| {
-            warn!(error = %e, "Failed to add line item");
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["AddLineItem", "error"])
-                .inc();
-            match e {
-                service_core::error::AppError::BadRequest(err) => {
-                    Status::failed_precondition(err.to_string())
-                }
-                service_core::error::AppError::NotFound(err) => Status::not_found(err.to_string()),
-                _ => Status::internal("Failed to add line item"),
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                return Err(Status::not_found("Invoice template not found"));
             }
-        })?;
-
-        // Get updated invoice
-        let invoice = self
-            .db
-            .get_invoice(tenant_id, invoice_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice");
-                Status::internal("Failed to get invoice")
-            })?
-            .ok_or_else(|| Status::not_found("Invoice not found"))?;
+        }
 
-        let line_items = self
-            .db
-            .get_line_items(tenant_id, invoice_id)
+        self.db
+            .set_customer_template(tenant_id, customer_id, template_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get line items");
-                Status::internal("Failed to get line items")
+                warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to set customer template");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["SetCustomerTemplate", "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to set customer template")
             })?;
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["AddLineItem", "ok"])
+            .with_label_values(&["SetCustomerTemplate", "ok"])
             .inc();
         timer.observe_duration();
 
-        Ok(Response::new(AddLineItemResponse {
-            line_item: Some(Self::line_item_to_proto(&line_item)),
-            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        info!(
+            tenant_id = %tenant_id,
+            customer_id = %customer_id,
+            template_id = ?template_id,
+            "Customer invoice template set"
+        );
+
+        Ok(Response::new(SetCustomerTemplateResponse {
+            customer_id: customer_id.to_string(),
+            template_id: template_id.map(|id| id.to_string()).unwrap_or_default(),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "UpdateLineItem")
+        fields(
+            service = "invoicing-service",
+            method = "PreviewInvoiceTemplate",
+            tenant_id,
+            template_id
+        )
     )]
-    async fn update_line_item(
+    async fn preview_invoice_template(
         &self,
-        request: Request<UpdateLineItemRequest>,
-    ) -> Result<Response<UpdateLineItemResponse>, Status> {
+        request: Request<PreviewInvoiceTemplateRequest>,
+    ) -> Result<Response<PreviewInvoiceTemplateResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["UpdateLineItem"])
+            .with_label_values(&["PreviewInvoiceTemplate"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .with_label_values(&["PreviewInvoiceTemplate", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["UpdateLineItem", "invalid_argument"])
-                .inc();
-            Status::invalid_argument("Invalid invoice_id format")
-        })?;
-
-        let line_item_id = Uuid::parse_str(&req.line_item_id).map_err(|_| {
+        let template_id = Uuid::parse_str(&req.template_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .with_label_values(&["PreviewInvoiceTemplate", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid line_item_id format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid template_id format")
         })?;
+        Span::current().record("template_id", template_id.to_string());
 
-        let quantity = if req.quantity.is_empty() {
-            None
-        } else {
-            Some(Decimal::from_str(&req.quantity).map_err(|_| {
+        let template = self
+            .db
+            .get_invoice_template(tenant_id, template_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, template_id = %template_id, error = %e, "Failed to get invoice template");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .with_label_values(&["PreviewInvoiceTemplate", "error"])
                     .inc();
-                Status::invalid_argument("Invalid quantity format")
-            })?)
-        };
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice template")
+            })?;
 
-        let unit_price = if req.unit_price.is_empty() {
-            None
-        } else {
-            Some(Decimal::from_str(&req.unit_price).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid unit_price format")
-            })?)
+        let Some(template) = template else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["PreviewInvoiceTemplate", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Invoice template not found"));
         };
 
-        let tax_rate_id = if req.tax_rate_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.tax_rate_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid tax_rate_id format")
-            })?)
+        let letterhead = self
+            .letterhead("PreviewInvoiceTemplate", tenant_id, Some(&template))
+            .await?;
+        let (document_type, pdf_bytes) = match req.document_type {
+            x if x == TemplateDocumentType::Receipt as i32 => {
+                ("receipt", render_sample_receipt(&letterhead, tenant_id))
+            }
+            x if x == TemplateDocumentType::Statement as i32 => {
+                ("statement", render_sample_statement(&letterhead, tenant_id))
+            }
+            _ => ("invoice", render_sample_invoice(&letterhead, tenant_id)),
         };
 
-        let ledger_account_id = if req.ledger_account_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.ledger_account_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid ledger_account_id format")
-            })?)
-        };
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["PreviewInvoiceTemplate", "ok"])
+            .inc();
+        timer.observe_duration();
 
-        let input = UpdateLineItem {
-            description: if req.description.is_empty() {
-                None
-            } else {
-                Some(req.description)
-            },
-            quantity,
-            unit_price,
-            tax_rate_id,
-            ledger_account_id,
-            sort_order: if req.sort_order == 0 {
+        info!(
+            tenant_id = %tenant_id,
+            template_id = %template_id,
+            document_type = document_type,
+            "Invoice template previewed"
+        );
+
+        Ok(Response::new(PreviewInvoiceTemplateResponse {
+            pdf_bytes,
+            filename: format!("PREVIEW-{}.pdf", document_type.to_uppercase()),
+        }))
+    }
+
+    // -------------------------------------------------------------------------
+    // Invoice Methods
+    // -------------------------------------------------------------------------
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "CreateInvoice",
+            tenant_id,
+            customer_id,
+            invoice_id
+        )
+    )]
+    async fn create_invoice(
+        &self,
+        request: Request<CreateInvoiceRequest>,
+    ) -> Result<Response<CreateInvoiceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["CreateInvoice"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let customer_id = Uuid::parse_str(&req.customer_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid customer_id format")
+        })?;
+        Span::current().record("customer_id", customer_id.to_string());
+
+        let invoice_type = match req.invoice_type {
+            x if x == ProtoInvoiceType::CreditNote as i32 => "credit_note",
+            x if x == ProtoInvoiceType::Proforma as i32 => "proforma",
+            _ => "standard",
+        };
+
+        let due_date = if req.due_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.due_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["CreateInvoice", "invalid_argument"])
+                        .inc();
+                    ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                    Status::invalid_argument("Invalid due_date format")
+                })?,
+            )
+        };
+
+        let reference_invoice_id = if req.reference_invoice_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.reference_invoice_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CreateInvoice", "invalid_argument"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid reference_invoice_id format")
+            })?)
+        };
+
+        let metadata = if req.metadata.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_str(&req.metadata).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CreateInvoice", "invalid_argument"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid metadata JSON")
+            })?)
+        };
+
+        let address = req.billing_address.as_ref();
+
+        let input = CreateInvoice {
+            tenant_id,
+            invoice_type: invoice_type.to_string(),
+            customer_id,
+            customer_name: req.customer_name,
+            billing_line1: address.map(|a| a.line1.clone()).filter(|s| !s.is_empty()),
+            billing_line2: address.map(|a| a.line2.clone()).filter(|s| !s.is_empty()),
+            billing_city: address.map(|a| a.city.clone()).filter(|s| !s.is_empty()),
+            billing_state: address.map(|a| a.state.clone()).filter(|s| !s.is_empty()),
+            billing_postal_code: address
+                .map(|a| a.postal_code.clone())
+                .filter(|s| !s.is_empty()),
+            billing_country: address.map(|a| a.country.clone()).filter(|s| !s.is_empty()),
+            currency: req.currency,
+            due_date,
+            notes: if req.notes.is_empty() {
                 None
             } else {
-                Some(req.sort_order)
+                Some(req.notes)
             },
+            reference_invoice_id,
+            metadata,
         };
 
-        let line_item = self
+        let invoice = self.db.create_invoice(&input).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to create invoice");
+            GRPC_REQUESTS_TOTAL.with_label_values(&["CreateInvoice", "error"]).inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to create invoice")
+        })?;
+
+        Span::current().record("invoice_id", invoice.invoice_id.to_string());
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["CreateInvoice", "ok"])
+            .inc();
+        INVOICES_TOTAL.with_label_values(&["draft"]).inc();
+        timer.observe_duration();
+
+        info!(tenant_id = %tenant_id, customer_id = %customer_id, invoice_id = %invoice.invoice_id, "Draft invoice created");
+
+        Ok(Response::new(CreateInvoiceResponse {
+            invoice: Some(Self::invoice_to_proto(&invoice, &[])),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "GetInvoice")
+    )]
+    async fn get_invoice(
+        &self,
+        request: Request<GetInvoiceRequest>,
+    ) -> Result<Response<GetInvoiceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetInvoice"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetInvoice", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetInvoice", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+
+        let invoice = self
             .db
-            .update_line_item(tenant_id, invoice_id, line_item_id, &input)
+            .get_invoice(tenant_id, invoice_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to update line item");
+                warn!(error = %e, "Failed to get invoice");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "error"])
+                    .with_label_values(&["GetInvoice", "error"])
                     .inc();
-                match e {
-                    service_core::error::AppError::BadRequest(err) => {
-                        Status::failed_precondition(err.to_string())
-                    }
-                    _ => Status::internal("Failed to update line item"),
-                }
+                Status::internal("Failed to get invoice")
             })?;
 
         timer.observe_duration();
 
-        match line_item {
-            Some(item) => {
-                let invoice = self
-                    .db
-                    .get_invoice(tenant_id, invoice_id)
-                    .await
-                    .map_err(|e| {
-                        warn!(error = %e, "Failed to get invoice");
-                        Status::internal("Failed to get invoice")
-                    })?
-                    .ok_or_else(|| Status::not_found("Invoice not found"))?;
-
+        match invoice {
+            Some(inv) => {
                 let line_items = self
                     .db
                     .get_line_items(tenant_id, invoice_id)
@@ -1552,247 +2324,1596 @@ impl InvoicingService for InvoicingServiceImpl {
                         warn!(error = %e, "Failed to get line items");
                         Status::internal("Failed to get line items")
                     })?;
-
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "ok"])
+                    .with_label_values(&["GetInvoice", "ok"])
                     .inc();
-                Ok(Response::new(UpdateLineItemResponse {
-                    line_item: Some(Self::line_item_to_proto(&item)),
-                    invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+                Ok(Response::new(GetInvoiceResponse {
+                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
                 }))
             }
             None => {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["UpdateLineItem", "not_found"])
+                    .with_label_values(&["GetInvoice", "not_found"])
                     .inc();
-                Err(Status::not_found("Line item not found"))
+                Err(Status::not_found("Invoice not found"))
             }
         }
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "RemoveLineItem")
+        fields(service = "invoicing-service", method = "ListInvoices")
     )]
-    async fn remove_line_item(
+    async fn list_invoices(
         &self,
-        request: Request<RemoveLineItemRequest>,
-    ) -> Result<Response<RemoveLineItemResponse>, Status> {
+        request: Request<ListInvoicesRequest>,
+    ) -> Result<Response<ListInvoicesResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["RemoveLineItem"])
+            .with_label_values(&["ListInvoices"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RemoveLineItem", "invalid_argument"])
+                .with_label_values(&["ListInvoices", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RemoveLineItem", "invalid_argument"])
-                .inc();
-            Status::invalid_argument("Invalid invoice_id format")
-        })?;
+        let status = if req.status == ProtoInvoiceStatus::Unspecified as i32 {
+            None
+        } else {
+            Some(match req.status {
+                x if x == ProtoInvoiceStatus::Issued as i32 => InvoiceStatus::Issued,
+                x if x == ProtoInvoiceStatus::Paid as i32 => InvoiceStatus::Paid,
+                x if x == ProtoInvoiceStatus::Void as i32 => InvoiceStatus::Void,
+                x if x == ProtoInvoiceStatus::Overdue as i32 => InvoiceStatus::Overdue,
+                _ => InvoiceStatus::Draft,
+            })
+        };
+
+        let customer_id = if req.customer_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.customer_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListInvoices", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid customer_id format")
+            })?)
+        };
+
+        let start_date = if req.start_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListInvoices", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid start_date format")
+                })?,
+            )
+        };
+
+        let end_date = if req.end_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListInvoices", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid end_date format")
+                })?,
+            )
+        };
+
+        let page_token = if req.page_token.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.page_token).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListInvoices", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid page_token format")
+            })?)
+        };
+
+        let page_size = if req.page_size <= 0 {
+            20
+        } else {
+            req.page_size
+        };
+
+        let filter = ListInvoicesFilter {
+            status,
+            customer_id,
+            start_date,
+            end_date,
+            page_size,
+            page_token,
+        };
+
+        let invoices = self
+            .db
+            .list_invoices(tenant_id, &filter)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to list invoices");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListInvoices", "error"])
+                    .inc();
+                Status::internal("Failed to list invoices")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ListInvoices", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        let next_page_token = if invoices.len() == filter.page_size as usize {
+            invoices.last().map(|i| i.invoice_id.to_string())
+        } else {
+            None
+        };
+
+        Ok(Response::new(ListInvoicesResponse {
+            invoices: invoices
+                .iter()
+                .map(|i| Self::invoice_to_proto(i, &[]))
+                .collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "IssueInvoice",
+            tenant_id,
+            invoice_id
+        )
+    )]
+    async fn issue_invoice(
+        &self,
+        request: Request<IssueInvoiceRequest>,
+    ) -> Result<Response<IssueInvoiceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["IssueInvoice"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["IssueInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["IssueInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+        Span::current().record("invoice_id", invoice_id.to_string());
+
+        let issue_date = if req.issue_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.issue_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["IssueInvoice", "invalid_argument"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid issue_date format")
+            })?
+        };
+
+        // Get invoice and line items for ledger entry
+        let existing_invoice = self
+            .db
+            .get_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get invoice for ledger entry");
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice")
+            })?;
+        let existing_invoice = existing_invoice.ok_or_else(|| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["IssueInvoice", "not_found"])
+                .inc();
+            Status::not_found("Invoice not found")
+        })?;
+
+        let line_items = self
+            .db
+            .get_line_items(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get line items for ledger entry");
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get line items")
+            })?;
+
+        // Create ledger entry if ledger client is available
+        let journal_id = if let Some(ref ledger_client) = self.ledger_client {
+            // Build ledger entries: Debit A/R, Credit Revenue accounts
+            // Convention: A/R account = "AR-{currency}", Revenue from line item ledger_account_id
+            let ar_account_id = format!("AR-{}", existing_invoice.currency);
+            let idempotency_key = format!("invoice-issue-{}", invoice_id);
+
+            // The invoice total already nets negative lines out of A/R
+            let mut entries = Vec::new();
+            if existing_invoice.total > Decimal::ZERO {
+                entries.push(TransactionEntry::debit(
+                    &ar_account_id,
+                    &format_decimal(&existing_invoice.total),
+                ));
+            }
+
+            // Credit revenue per line; negative lines are debited instead, so
+            // discounts land in contra-revenue and credit applied draws down
+            // Customer Credit
+            for item in &line_items {
+                let account = Self::line_item_ledger_account(item, &existing_invoice.currency);
+                let amount = format_decimal(&item.total.abs());
+                if item.total > Decimal::ZERO {
+                    entries.push(TransactionEntry::credit(&account, &amount));
+                } else if item.total < Decimal::ZERO {
+                    entries.push(TransactionEntry::debit(&account, &amount));
+                }
+            }
+
+            let metadata = serde_json::json!({
+                "source": "invoicing-service",
+                "invoice_id": invoice_id.to_string(),
+                "customer_id": existing_invoice.customer_id.to_string(),
+            })
+            .to_string();
+
+            match ledger_client
+                .post_transaction(
+                    &tenant_id.to_string(),
+                    entries,
+                    Some(&issue_date.to_string()),
+                    &idempotency_key,
+                    Some(&metadata),
+                )
+                .await
+            {
+                Ok(response) => {
+                    if let Some(ref txn) = response.transaction {
+                        info!(journal_id = %txn.journal_id, "Ledger entry created for invoice issue");
+                        Uuid::parse_str(&txn.journal_id).ok()
+                    } else {
+                        warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, "Ledger response missing transaction");
+                        None
+                    }
+                }
+                Err(e) => {
+                    // Log but don't fail - ledger integration is optional enhancement
+                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create ledger entry for invoice issue");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let invoice = self.db.issue_invoice(tenant_id, invoice_id, issue_date, journal_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to issue invoice");
+            GRPC_REQUESTS_TOTAL.with_label_values(&["IssueInvoice", "error"]).inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
+                _ => Status::internal("Failed to issue invoice"),
+            }
+        })?;
+
+        timer.observe_duration();
+
+        match invoice {
+            Some(inv) => {
+                let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
+                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to get line items")
+                })?;
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["IssueInvoice", "ok"])
+                    .inc();
+                INVOICES_TOTAL.with_label_values(&["issued"]).inc();
+                // Track invoice amount by currency for business metrics
+                if let Some(amount) = inv.total.to_f64() {
+                    INVOICE_AMOUNT_TOTAL
+                        .with_label_values(&[&inv.currency])
+                        .inc_by(amount);
+                }
+                info!(
+                    tenant_id = %tenant_id,
+                    invoice_id = %inv.invoice_id,
+                    invoice_number = %inv.invoice_number.as_deref().unwrap_or(""),
+                    total = %inv.total,
+                    currency = %inv.currency,
+                    "Invoice issued"
+                );
+                Ok(Response::new(IssueInvoiceResponse {
+                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["IssueInvoice", "not_found"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Err(Status::not_found("Invoice not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "VoidInvoice",
+            tenant_id,
+            invoice_id
+        )
+    )]
+    async fn void_invoice(
+        &self,
+        request: Request<VoidInvoiceRequest>,
+    ) -> Result<Response<VoidInvoiceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["VoidInvoice"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["VoidInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["VoidInvoice", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+        Span::current().record("invoice_id", invoice_id.to_string());
+
+        // Get invoice for reversing ledger entry
+        let existing_invoice = self
+            .db
+            .get_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get invoice for reversing entry");
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice")
+            })?;
+
+        if let Some(ref inv) = existing_invoice {
+            // Reverse the issue journal if ledger client is available
+            if let (Some(ledger_client), Some(journal_id)) = (&self.ledger_client, inv.journal_id) {
+                let metadata = serde_json::json!({
+                    "source": "invoicing-service",
+                    "invoice_id": invoice_id.to_string(),
+                    "action": "void",
+                })
+                .to_string();
+
+                if let Err(e) = ledger_client
+                    .reverse_transaction(
+                        &tenant_id.to_string(),
+                        &journal_id.to_string(),
+                        Some(&chrono::Utc::now().date_naive().to_string()),
+                        &format!("Invoice {} voided", invoice_id),
+                        Some(&metadata),
+                    )
+                    .await
+                {
+                    // Log but don't fail - ledger integration is optional enhancement
+                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to reverse ledger entry");
+                } else {
+                    info!(invoice_id = %invoice_id, journal_id = %journal_id, "Ledger entry reversed for voided invoice");
+                }
+            }
+        }
+
+        let invoice = self.db.void_invoice(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to void invoice");
+            GRPC_REQUESTS_TOTAL.with_label_values(&["VoidInvoice", "error"]).inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
+                _ => Status::internal("Failed to void invoice"),
+            }
+        })?;
+
+        timer.observe_duration();
+
+        match invoice {
+            Some(inv) => {
+                let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
+                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to get line items")
+                })?;
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["VoidInvoice", "ok"])
+                    .inc();
+                INVOICES_TOTAL.with_label_values(&["void"]).inc();
+                info!(tenant_id = %tenant_id, invoice_id = %inv.invoice_id, "Invoice voided");
+                Ok(Response::new(VoidInvoiceResponse {
+                    invoice: Some(Self::invoice_to_proto(&inv, &line_items)),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["VoidInvoice", "not_found"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Err(Status::not_found("Invoice not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "DeleteInvoice")
+    )]
+    async fn delete_invoice(
+        &self,
+        request: Request<DeleteInvoiceRequest>,
+    ) -> Result<Response<DeleteInvoiceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["DeleteInvoice"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["DeleteInvoice", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["DeleteInvoice", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+
+        let deleted = self
+            .db
+            .delete_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to delete invoice");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["DeleteInvoice", "error"])
+                    .inc();
+                Status::internal("Failed to delete invoice")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["DeleteInvoice", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        Ok(Response::new(DeleteInvoiceResponse { success: deleted }))
+    }
+
+    // -------------------------------------------------------------------------
+    // Credit Note Methods
+    // -------------------------------------------------------------------------
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "CreateCreditNote",
+            tenant_id,
+            invoice_id,
+            credit_note_id
+        )
+    )]
+    async fn create_credit_note(
+        &self,
+        request: Request<CreateCreditNoteRequest>,
+    ) -> Result<Response<CreateCreditNoteResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["CreateCreditNote"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let invalid = |message: String| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateCreditNote", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument(message)
+        };
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id)
+            .map_err(|_| invalid("Invalid tenant_id format".to_string()))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id)
+            .map_err(|_| invalid("Invalid invoice_id format".to_string()))?;
+        Span::current().record("invoice_id", invoice_id.to_string());
+
+        if req.full != req.lines.is_empty() {
+            return Err(invalid(
+                "Specify either lines to credit or full, not both".to_string(),
+            ));
+        }
+
+        let mut lines = Vec::with_capacity(req.lines.len());
+        for line in &req.lines {
+            let line_item_id = Uuid::parse_str(&line.line_item_id)
+                .map_err(|_| invalid("Invalid line_item_id format".to_string()))?;
+            let quantity = Decimal::from_str(&line.quantity)
+                .map_err(|_| invalid("Invalid quantity format".to_string()))?;
+            if quantity <= Decimal::ZERO {
+                return Err(invalid("quantity must be positive".to_string()));
+            }
+            lines.push(CreditNoteLine {
+                line_item_id,
+                quantity,
+            });
+        }
+
+        let issue_date = if req.issue_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.issue_date, "%Y-%m-%d")
+                .map_err(|_| invalid("Invalid issue_date format".to_string()))?
+        };
+
+        let input = CreateCreditNote {
+            tenant_id,
+            invoice_id,
+            lines,
+            refundable: req.refundable,
+            issue_date,
+            notes: if req.notes.is_empty() {
+                None
+            } else {
+                Some(req.notes)
+            },
+        };
+
+        let created = self.db.create_credit_note(&input).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create credit note");
+            GRPC_REQUESTS_TOTAL.with_label_values(&["CreateCreditNote", "error"]).inc();
+            match e {
+                AppError::BadRequest(err) => {
+                    ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                    Status::failed_precondition(err.to_string())
+                }
+                _ => {
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to create credit note")
+                }
+            }
+        })?;
+
+        let Some((mut credit_note, invoice)) = created else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateCreditNote", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Invoice not found"));
+        };
+        Span::current().record("credit_note_id", credit_note.invoice_id.to_string());
+
+        let credit_lines = self
+            .db
+            .get_line_items(tenant_id, credit_note.invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, credit_note_id = %credit_note.invoice_id, error = %e, "Failed to get line items");
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get line items")
+            })?;
+
+        // Post the reversal: Debit Revenue per credited line, Credit A/R for
+        // the part applied to the invoice and Customer Credit for the
+        // refundable remainder
+        if let Some(ref ledger_client) = self.ledger_client {
+            let applied = credit_note.total - credit_note.amount_due;
+            let mut entries: Vec<TransactionEntry> = credit_lines
+                .iter()
+                .filter(|item| !item.total.is_zero())
+                .map(|item| {
+                    let account = Self::line_item_ledger_account(item, &credit_note.currency);
+                    let amount = format_decimal(&item.total.abs());
+                    if item.total > Decimal::ZERO {
+                        TransactionEntry::debit(&account, &amount)
+                    } else {
+                        TransactionEntry::credit(&account, &amount)
+                    }
+                })
+                .collect();
+            if applied > Decimal::ZERO {
+                entries.push(TransactionEntry::credit(
+                    &format!("AR-{}", credit_note.currency),
+                    &format_decimal(&applied),
+                ));
+            }
+            if credit_note.amount_due > Decimal::ZERO {
+                entries.push(TransactionEntry::credit(
+                    &format!("CUSTOMER-CREDIT-{}", credit_note.currency),
+                    &format_decimal(&credit_note.amount_due),
+                ));
+            }
+
+            let metadata = serde_json::json!({
+                "source": "invoicing-service",
+                "credit_note_id": credit_note.invoice_id.to_string(),
+                "invoice_id": invoice_id.to_string(),
+                "customer_id": credit_note.customer_id.to_string(),
+            })
+            .to_string();
+
+            match ledger_client
+                .post_transaction(
+                    &tenant_id.to_string(),
+                    entries,
+                    Some(&issue_date.to_string()),
+                    &format!("credit-note-{}", credit_note.invoice_id),
+                    Some(&metadata),
+                )
+                .await
+            {
+                Ok(response) => {
+                    if let Some(journal_id) = response
+                        .transaction
+                        .and_then(|txn| Uuid::parse_str(&txn.journal_id).ok())
+                    {
+                        info!(journal_id = %journal_id, "Ledger entry created for credit note");
+                        if let Err(e) = self
+                            .db
+                            .set_invoice_journal(tenant_id, credit_note.invoice_id, journal_id)
+                            .await
+                        {
+                            warn!(tenant_id = %tenant_id, credit_note_id = %credit_note.invoice_id, error = %e, "Failed to record credit note journal");
+                        } else {
+                            credit_note.journal_id = Some(journal_id);
+                        }
+                    } else {
+                        warn!(tenant_id = %tenant_id, credit_note_id = %credit_note.invoice_id, "Ledger response missing transaction");
+                    }
+                }
+                Err(e) => {
+                    // Log but don't fail - ledger integration is optional enhancement
+                    warn!(tenant_id = %tenant_id, credit_note_id = %credit_note.invoice_id, error = %e, "Failed to create ledger entry for credit note");
+                }
+            }
+        }
+
+        let invoice_lines = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get line items")
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["CreateCreditNote", "ok"])
+            .inc();
+        INVOICES_TOTAL.with_label_values(&["credit_note"]).inc();
+        timer.observe_duration();
+
+        info!(
+            tenant_id = %tenant_id,
+            invoice_id = %invoice_id,
+            credit_note_id = %credit_note.invoice_id,
+            invoice_number = %credit_note.invoice_number.as_deref().unwrap_or(""),
+            total = %credit_note.total,
+            refundable = %credit_note.amount_due,
+            "Credit note issued"
+        );
+
+        Ok(Response::new(CreateCreditNoteResponse {
+            credit_note: Some(Self::invoice_to_proto(&credit_note, &credit_lines)),
+            invoice: Some(Self::invoice_to_proto(&invoice, &invoice_lines)),
+        }))
+    }
+
+    // -------------------------------------------------------------------------
+    // Line Item Methods
+    // -------------------------------------------------------------------------
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "AddLineItem")
+    )]
+    async fn add_line_item(
+        &self,
+        request: Request<AddLineItemRequest>,
+    ) -> Result<Response<AddLineItemResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["AddLineItem"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+
+        let quantity = Decimal::from_str(&req.quantity).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid quantity format")
+        })?;
+
+        let unit_price = Decimal::from_str(&req.unit_price).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid unit_price format")
+        })?;
+
+        let tax_rate_id = if req.tax_rate_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.tax_rate_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid tax_rate_id format")
+            })?)
+        };
+
+        let tax_group_id = if req.tax_group_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.tax_group_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid tax_group_id format")
+            })?)
+        };
+
+        if tax_rate_id.is_some() && tax_group_id.is_some() {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "Set either tax_rate_id or tax_group_id, not both",
+            ));
+        }
+
+        let ledger_account_id = if req.ledger_account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.ledger_account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid ledger_account_id format")
+            })?)
+        };
+
+        let line_type = match ProtoLineItemType::try_from(req.line_type) {
+            Ok(ProtoLineItemType::Unspecified) | Ok(ProtoLineItemType::Charge) => {
+                LineItemType::Charge
+            }
+            Ok(ProtoLineItemType::Discount) => LineItemType::Discount,
+            Ok(ProtoLineItemType::Credit) => LineItemType::Credit,
+            Err(_) => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["AddLineItem", "invalid_argument"])
+                    .inc();
+                return Err(Status::invalid_argument("Invalid line_type"));
+            }
+        };
+        if line_type != LineItemType::Charge && quantity * unit_price >= Decimal::ZERO {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "Discount and credit lines must have a negative amount",
+            ));
+        }
+
+        let input = CreateLineItem {
+            tenant_id,
+            invoice_id,
+            description: req.description,
+            quantity,
+            unit_price,
+            tax_rate_id,
+            tax_group_id,
+            ledger_account_id,
+            sort_order: req.sort_order,
+            line_type,
+        };
+
+        let line_item = self.db.add_line_item(&input).await.map_err(|e| {
+            warn!(error = %e, "Failed to add line item");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AddLineItem", "error"])
+                .inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => {
+                    Status::failed_precondition(err.to_string())
+                }
+                service_core::error::AppError::NotFound(err) => Status::not_found(err.to_string()),
+                _ => Status::internal("Failed to add line item"),
+            }
+        })?;
+
+        // Get updated invoice
+        let invoice = self
+            .db
+            .get_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get invoice");
+                Status::internal("Failed to get invoice")
+            })?
+            .ok_or_else(|| Status::not_found("Invoice not found"))?;
+
+        let line_items = self
+            .db
+            .get_line_items(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get line items");
+                Status::internal("Failed to get line items")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["AddLineItem", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        Ok(Response::new(AddLineItemResponse {
+            line_item: Some(Self::line_item_to_proto(&line_item)),
+            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "UpdateLineItem")
+    )]
+    async fn update_line_item(
+        &self,
+        request: Request<UpdateLineItemRequest>,
+    ) -> Result<Response<UpdateLineItemResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["UpdateLineItem"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+
+        let line_item_id = Uuid::parse_str(&req.line_item_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid line_item_id format")
+        })?;
+
+        let quantity = if req.quantity.is_empty() {
+            None
+        } else {
+            Some(Decimal::from_str(&req.quantity).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid quantity format")
+            })?)
+        };
+
+        let unit_price = if req.unit_price.is_empty() {
+            None
+        } else {
+            Some(Decimal::from_str(&req.unit_price).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid unit_price format")
+            })?)
+        };
+
+        let tax_rate_id = if req.tax_rate_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.tax_rate_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid tax_rate_id format")
+            })?)
+        };
+
+        let tax_group_id = if req.tax_group_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.tax_group_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid tax_group_id format")
+            })?)
+        };
+
+        if tax_rate_id.is_some() && tax_group_id.is_some() {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "Set either tax_rate_id or tax_group_id, not both",
+            ));
+        }
+
+        let ledger_account_id = if req.ledger_account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.ledger_account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid ledger_account_id format")
+            })?)
+        };
+
+        let input = UpdateLineItem {
+            description: if req.description.is_empty() {
+                None
+            } else {
+                Some(req.description)
+            },
+            quantity,
+            unit_price,
+            tax_rate_id,
+            tax_group_id,
+            ledger_account_id,
+            sort_order: if req.sort_order == 0 {
+                None
+            } else {
+                Some(req.sort_order)
+            },
+        };
+
+        let line_item = self
+            .db
+            .update_line_item(tenant_id, invoice_id, line_item_id, &input)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to update line item");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to update line item"),
+                }
+            })?;
+
+        timer.observe_duration();
+
+        match line_item {
+            Some(item) => {
+                let invoice = self
+                    .db
+                    .get_invoice(tenant_id, invoice_id)
+                    .await
+                    .map_err(|e| {
+                        warn!(error = %e, "Failed to get invoice");
+                        Status::internal("Failed to get invoice")
+                    })?
+                    .ok_or_else(|| Status::not_found("Invoice not found"))?;
+
+                let line_items = self
+                    .db
+                    .get_line_items(tenant_id, invoice_id)
+                    .await
+                    .map_err(|e| {
+                        warn!(error = %e, "Failed to get line items");
+                        Status::internal("Failed to get line items")
+                    })?;
+
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "ok"])
+                    .inc();
+                Ok(Response::new(UpdateLineItemResponse {
+                    line_item: Some(Self::line_item_to_proto(&item)),
+                    invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpdateLineItem", "not_found"])
+                    .inc();
+                Err(Status::not_found("Line item not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "RemoveLineItem")
+    )]
+    async fn remove_line_item(
+        &self,
+        request: Request<RemoveLineItemRequest>,
+    ) -> Result<Response<RemoveLineItemResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["RemoveLineItem"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RemoveLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RemoveLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+
+        let line_item_id = Uuid::parse_str(&req.line_item_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RemoveLineItem", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid line_item_id format")
+        })?;
+
+        let removed = self
+            .db
+            .remove_line_item(tenant_id, invoice_id, line_item_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to remove line item");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["RemoveLineItem", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to remove line item"),
+                }
+            })?;
+
+        if !removed {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RemoveLineItem", "not_found"])
+                .inc();
+            return Err(Status::not_found("Line item not found"));
+        }
+
+        // Get updated invoice
+        let invoice = self
+            .db
+            .get_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get invoice");
+                Status::internal("Failed to get invoice")
+            })?
+            .ok_or_else(|| Status::not_found("Invoice not found"))?;
+
+        let line_items = self
+            .db
+            .get_line_items(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get line items");
+                Status::internal("Failed to get line items")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["RemoveLineItem", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        Ok(Response::new(RemoveLineItemResponse {
+            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        }))
+    }
+
+    // -------------------------------------------------------------------------
+    // Receipt Methods
+    // -------------------------------------------------------------------------
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "RecordPayment",
+            tenant_id,
+            invoice_id,
+            receipt_id
+        )
+    )]
+    async fn record_payment(
+        &self,
+        request: Request<RecordPaymentRequest>,
+    ) -> Result<Response<RecordPaymentResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["RecordPayment"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RecordPayment", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RecordPayment", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid invoice_id format")
+        })?;
+        Span::current().record("invoice_id", invoice_id.to_string());
+
+        let amount = Decimal::from_str(&req.amount).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RecordPayment", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid amount format")
+        })?;
+
+        let payment_date =
+            NaiveDate::parse_from_str(&req.payment_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["RecordPayment", "invalid_argument"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+                Status::invalid_argument("Invalid payment_date format")
+            })?;
+
+        // Get invoice for ledger entry
+        let existing_invoice = self
+            .db
+            .get_invoice(tenant_id, invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get invoice for payment ledger entry");
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice")
+            })?;
+        let existing_invoice = existing_invoice.ok_or_else(|| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RecordPayment", "not_found"])
+                .inc();
+            Status::not_found("Invoice not found")
+        })?;
+
+        // Create ledger entry for payment: Debit Cash, Credit A/R
+        let journal_id = if let Some(ref ledger_client) = self.ledger_client {
+            // Convention: Cash account based on payment method, A/R from invoice
+            let cash_account = format!(
+                "CASH-{}-{}",
+                req.payment_method.to_uppercase(),
+                existing_invoice.currency
+            );
+            let ar_account_id = format!("AR-{}", existing_invoice.currency);
+            let amount_str = format_decimal(&amount);
+            // Use invoice_id + payment_date + amount for idempotency
+            let idempotency_key = format!("payment-{}-{}-{}", invoice_id, payment_date, amount_str);
+
+            let entries = vec![
+                TransactionEntry::debit(&cash_account, &amount_str),
+                TransactionEntry::credit(&ar_account_id, &amount_str),
+            ];
+
+            let metadata = serde_json::json!({
+                "source": "invoicing-service",
+                "invoice_id": invoice_id.to_string(),
+                "customer_id": existing_invoice.customer_id.to_string(),
+                "payment_method": &req.payment_method,
+                "amount": &amount_str,
+            })
+            .to_string();
+
+            match ledger_client
+                .post_transaction(
+                    &tenant_id.to_string(),
+                    entries,
+                    Some(&payment_date.to_string()),
+                    &idempotency_key,
+                    Some(&metadata),
+                )
+                .await
+            {
+                Ok(response) => {
+                    if let Some(ref txn) = response.transaction {
+                        info!(journal_id = %txn.journal_id, "Ledger entry created for payment");
+                        Uuid::parse_str(&txn.journal_id).ok()
+                    } else {
+                        warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, "Ledger response missing transaction");
+                        None
+                    }
+                }
+                Err(e) => {
+                    // Log but don't fail - ledger integration is optional enhancement
+                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create ledger entry for payment");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let input = CreateReceipt {
+            tenant_id,
+            invoice_id,
+            amount,
+            payment_method: req.payment_method.clone(),
+            payment_reference: if req.payment_reference.is_empty() {
+                None
+            } else {
+                Some(req.payment_reference)
+            },
+            payment_date,
+            journal_id,
+            notes: if req.notes.is_empty() {
+                None
+            } else {
+                Some(req.notes)
+            },
+        };
+
+        let receipt = self.db.record_payment(&input).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to record payment");
+            GRPC_REQUESTS_TOTAL.with_label_values(&["RecordPayment", "error"]).inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
+                service_core::error::AppError::NotFound(err) => {
+                    ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                    Status::not_found(err.to_string())
+                }
+                _ => Status::internal("Failed to record payment"),
+            }
+        })?;
+
+        Span::current().record("receipt_id", receipt.receipt_id.to_string());
+
+        // Get updated invoice
+        let invoice = self.db.get_invoice(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get invoice");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get invoice")
+        })?.ok_or_else(|| Status::not_found("Invoice not found"))?;
+
+        let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get line items")
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["RecordPayment", "ok"])
+            .inc();
+        RECEIPTS_TOTAL
+            .with_label_values(&[&req.payment_method])
+            .inc();
+        // Track payment amount by currency for business metrics
+        if let Some(payment_amount) = receipt.amount.to_f64() {
+            PAYMENT_AMOUNT_TOTAL
+                .with_label_values(&[&receipt.currency])
+                .inc_by(payment_amount);
+        }
+        timer.observe_duration();
+
+        info!(
+            tenant_id = %tenant_id,
+            invoice_id = %invoice_id,
+            receipt_id = %receipt.receipt_id,
+            receipt_number = %receipt.receipt_number,
+            amount = %receipt.amount,
+            currency = %receipt.currency,
+            payment_method = %receipt.payment_method,
+            "Payment recorded"
+        );
+
+        Ok(Response::new(RecordPaymentResponse {
+            receipt: Some(Self::receipt_to_proto(&receipt)),
+            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "GetReceipt")
+    )]
+    async fn get_receipt(
+        &self,
+        request: Request<GetReceiptRequest>,
+    ) -> Result<Response<GetReceiptResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetReceipt"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetReceipt", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let receipt_id = Uuid::parse_str(&req.receipt_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetReceipt", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid receipt_id format")
+        })?;
+
+        let receipt = self
+            .db
+            .get_receipt(tenant_id, receipt_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get receipt");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetReceipt", "error"])
+                    .inc();
+                Status::internal("Failed to get receipt")
+            })?;
+
+        timer.observe_duration();
+
+        match receipt {
+            Some(r) => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetReceipt", "ok"])
+                    .inc();
+                Ok(Response::new(GetReceiptResponse {
+                    receipt: Some(Self::receipt_to_proto(&r)),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetReceipt", "not_found"])
+                    .inc();
+                Err(Status::not_found("Receipt not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "invoicing-service", method = "ListReceipts")
+    )]
+    async fn list_receipts(
+        &self,
+        request: Request<ListReceiptsRequest>,
+    ) -> Result<Response<ListReceiptsResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ListReceipts"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ListReceipts", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let invoice_id = if req.invoice_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.invoice_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListReceipts", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid invoice_id format")
+            })?)
+        };
+
+        let customer_id = if req.customer_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.customer_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListReceipts", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid customer_id format")
+            })?)
+        };
+
+        let start_date = if req.start_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListReceipts", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid start_date format")
+                })?,
+            )
+        };
 
-        let line_item_id = Uuid::parse_str(&req.line_item_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RemoveLineItem", "invalid_argument"])
-                .inc();
-            Status::invalid_argument("Invalid line_item_id format")
-        })?;
+        let end_date = if req.end_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListReceipts", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid end_date format")
+                })?,
+            )
+        };
 
-        let removed = self
-            .db
-            .remove_line_item(tenant_id, invoice_id, line_item_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to remove line item");
+        let page_token = if req.page_token.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.page_token).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["RemoveLineItem", "error"])
+                    .with_label_values(&["ListReceipts", "invalid_argument"])
                     .inc();
-                match e {
-                    service_core::error::AppError::BadRequest(err) => {
-                        Status::failed_precondition(err.to_string())
-                    }
-                    _ => Status::internal("Failed to remove line item"),
-                }
-            })?;
+                Status::invalid_argument("Invalid page_token format")
+            })?)
+        };
 
-        if !removed {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RemoveLineItem", "not_found"])
-                .inc();
-            return Err(Status::not_found("Line item not found"));
-        }
+        let page_size = if req.page_size <= 0 {
+            20
+        } else {
+            req.page_size
+        };
 
-        // Get updated invoice
-        let invoice = self
-            .db
-            .get_invoice(tenant_id, invoice_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice");
-                Status::internal("Failed to get invoice")
-            })?
-            .ok_or_else(|| Status::not_found("Invoice not found"))?;
+        let filter = ListReceiptsFilter {
+            invoice_id,
+            customer_id,
+            start_date,
+            end_date,
+            page_size,
+            page_token,
+        };
 
-        let line_items = self
+        let receipts = self
             .db
-            .get_line_items(tenant_id, invoice_id)
+            .list_receipts(tenant_id, &filter)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get line items");
-                Status::internal("Failed to get line items")
+                warn!(error = %e, "Failed to list receipts");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListReceipts", "error"])
+                    .inc();
+                Status::internal("Failed to list receipts")
             })?;
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["RemoveLineItem", "ok"])
+            .with_label_values(&["ListReceipts", "ok"])
             .inc();
         timer.observe_duration();
 
-        Ok(Response::new(RemoveLineItemResponse {
-            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        let next_page_token = if receipts.len() == filter.page_size as usize {
+            receipts.last().map(|r| r.receipt_id.to_string())
+        } else {
+            None
+        };
+
+        Ok(Response::new(ListReceiptsResponse {
+            receipts: receipts.iter().map(Self::receipt_to_proto).collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
         }))
     }
 
     // -------------------------------------------------------------------------
-    // Receipt Methods
+    // Customer Payment Methods
     // -------------------------------------------------------------------------
 
     #[instrument(
         skip(self, request),
         fields(
             service = "invoicing-service",
-            method = "RecordPayment",
+            method = "CreateCustomerPayment",
             tenant_id,
-            invoice_id,
-            receipt_id
+            customer_id,
+            payment_id
         )
     )]
-    async fn record_payment(
+    async fn create_customer_payment(
         &self,
-        request: Request<RecordPaymentRequest>,
-    ) -> Result<Response<RecordPaymentResponse>, Status> {
+        request: Request<CreateCustomerPaymentRequest>,
+    ) -> Result<Response<CreateCustomerPaymentResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["RecordPayment"])
+            .with_label_values(&["CreateCustomerPayment"])
             .start_timer();
         let req = request.into_inner();
 
-        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RecordPayment", "invalid_argument"])
-                .inc();
-            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid tenant_id format")
-        })?;
-        Span::current().record("tenant_id", tenant_id.to_string());
-
-        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RecordPayment", "invalid_argument"])
-                .inc();
-            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid invoice_id format")
-        })?;
-        Span::current().record("invoice_id", invoice_id.to_string());
-
-        let amount = Decimal::from_str(&req.amount).map_err(|_| {
+        let invalid = |message: &str| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RecordPayment", "invalid_argument"])
+                .with_label_values(&["CreateCustomerPayment", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-            Status::invalid_argument("Invalid amount format")
-        })?;
-
-        let payment_date =
-            NaiveDate::parse_from_str(&req.payment_date, "%Y-%m-%d").map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["RecordPayment", "invalid_argument"])
-                    .inc();
-                ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
-                Status::invalid_argument("Invalid payment_date format")
-            })?;
+            Status::invalid_argument(message)
+        };
 
-        // Get invoice for ledger entry
-        let existing_invoice = self
-            .db
-            .get_invoice(tenant_id, invoice_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get invoice for payment ledger entry");
-                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-                Status::internal("Failed to get invoice")
-            })?;
-        let existing_invoice = existing_invoice.ok_or_else(|| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["RecordPayment", "not_found"])
-                .inc();
-            Status::not_found("Invoice not found")
-        })?;
+        let tenant_id =
+            Uuid::parse_str(&req.tenant_id).map_err(|_| invalid("Invalid tenant_id format"))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        // Create ledger entry for payment: Debit Cash, Credit A/R
-        let journal_id = if let Some(ref ledger_client) = self.ledger_client {
-            // Convention: Cash account based on payment method, A/R from invoice
-            let cash_account = format!(
-                "CASH-{}-{}",
-                req.payment_method.to_uppercase(),
-                existing_invoice.currency
-            );
-            let ar_account_id = format!("AR-{}", existing_invoice.currency);
-            let amount_str = format_decimal(&amount);
-            // Use invoice_id + payment_date + amount for idempotency
-            let idempotency_key = format!("payment-{}-{}-{}", invoice_id, payment_date, amount_str);
+        let customer_id =
+            Uuid::parse_str(&req.customer_id).map_err(|_| invalid("Invalid customer_id format"))?;
+        Span::current().record("customer_id", customer_id.to_string());
 
-            let entries = vec![
-                TransactionEntry::debit(&cash_account, &amount_str),
-                TransactionEntry::credit(&ar_account_id, &amount_str),
-            ];
+        let amount = Decimal::from_str(&req.amount)
+            .ok()
+            .filter(|a| *a > Decimal::ZERO)
+            .ok_or_else(|| invalid("amount must be a positive decimal"))?;
 
-            let metadata = serde_json::json!({
-                "source": "invoicing-service",
-                "invoice_id": invoice_id.to_string(),
-                "customer_id": existing_invoice.customer_id.to_string(),
-                "payment_method": &req.payment_method,
-                "amount": &amount_str,
-            })
-            .to_string();
+        if req.currency.len() != 3 {
+            return Err(invalid("currency must be a 3-letter code"));
+        }
+        if req.payment_method.is_empty() {
+            return Err(invalid("payment_method is required"));
+        }
 
-            match ledger_client
-                .post_transaction(
-                    &tenant_id.to_string(),
-                    entries,
-                    Some(&payment_date.to_string()),
-                    &idempotency_key,
-                    Some(&metadata),
-                )
-                .await
-            {
-                Ok(response) => {
-                    if let Some(ref txn) = response.transaction {
-                        info!(journal_id = %txn.journal_id, "Ledger entry created for payment");
-                        Uuid::parse_str(&txn.journal_id).ok()
-                    } else {
-                        warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, "Ledger response missing transaction");
-                        None
-                    }
-                }
-                Err(e) => {
-                    // Log but don't fail - ledger integration is optional enhancement
-                    warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to create ledger entry for payment");
-                    None
-                }
-            }
-        } else {
-            None
-        };
+        let payment_date = NaiveDate::parse_from_str(&req.payment_date, "%Y-%m-%d")
+            .map_err(|_| invalid("Invalid payment_date format"))?;
 
-        let input = CreateReceipt {
+        let input = CreateCustomerPayment {
             tenant_id,
-            invoice_id,
+            customer_id,
             amount,
+            currency: req.currency.to_uppercase(),
             payment_method: req.payment_method.clone(),
             payment_reference: if req.payment_reference.is_empty() {
                 None
@@ -1800,7 +3921,6 @@ impl InvoicingService for InvoicingServiceImpl {
                 Some(req.payment_reference)
             },
             payment_date,
-            journal_id,
             notes: if req.notes.is_empty() {
                 None
             } else {
@@ -1808,245 +3928,537 @@ impl InvoicingService for InvoicingServiceImpl {
             },
         };
 
-        let receipt = self.db.record_payment(&input).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to record payment");
-            GRPC_REQUESTS_TOTAL.with_label_values(&["RecordPayment", "error"]).inc();
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            match e {
-                service_core::error::AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
-                service_core::error::AppError::NotFound(err) => {
-                    ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
-                    Status::not_found(err.to_string())
-                }
-                _ => Status::internal("Failed to record payment"),
-            }
-        })?;
-
-        Span::current().record("receipt_id", receipt.receipt_id.to_string());
-
-        // Get updated invoice
-        let invoice = self.db.get_invoice(tenant_id, invoice_id).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get invoice");
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to get invoice")
-        })?.ok_or_else(|| Status::not_found("Invoice not found"))?;
-
-        let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+        let mut payment = self.db.create_customer_payment(&input).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to create customer payment");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateCustomerPayment", "error"])
+                .inc();
             ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to get line items")
+            Status::internal("Failed to create customer payment")
         })?;
+        Span::current().record("payment_id", payment.payment_id.to_string());
+
+        // Create ledger entry: Debit Cash, Credit Customer Credit until the
+        // payment is allocated to invoices
+        if let Some(ref ledger_client) = self.ledger_client {
+            let cash_account = format!(
+                "CASH-{}-{}",
+                payment.payment_method.to_uppercase(),
+                payment.currency
+            );
+            let amount_str = format_decimal(&payment.amount);
+            let entries = vec![
+                TransactionEntry::debit(&cash_account, &amount_str),
+                TransactionEntry::credit(
+                    &format!("CUSTOMER-CREDIT-{}", payment.currency),
+                    &amount_str,
+                ),
+            ];
+
+            let metadata = serde_json::json!({
+                "source": "invoicing-service",
+                "payment_id": payment.payment_id.to_string(),
+                "customer_id": customer_id.to_string(),
+                "payment_method": &payment.payment_method,
+                "amount": &amount_str,
+            })
+            .to_string();
+
+            match ledger_client
+                .post_transaction(
+                    &tenant_id.to_string(),
+                    entries,
+                    Some(&payment_date.to_string()),
+                    &format!("customer-payment-{}", payment.payment_id),
+                    Some(&metadata),
+                )
+                .await
+            {
+                Ok(response) => {
+                    if let Some(journal_id) = response
+                        .transaction
+                        .and_then(|txn| Uuid::parse_str(&txn.journal_id).ok())
+                    {
+                        info!(journal_id = %journal_id, "Ledger entry created for customer payment");
+                        if let Err(e) = self
+                            .db
+                            .set_customer_payment_journal(tenant_id, payment.payment_id, journal_id)
+                            .await
+                        {
+                            warn!(tenant_id = %tenant_id, payment_id = %payment.payment_id, error = %e, "Failed to record customer payment journal");
+                        } else {
+                            payment.journal_id = Some(journal_id);
+                        }
+                    } else {
+                        warn!(tenant_id = %tenant_id, payment_id = %payment.payment_id, "Ledger response missing transaction");
+                    }
+                }
+                Err(e) => {
+                    // Log but don't fail - ledger integration is optional enhancement
+                    warn!(tenant_id = %tenant_id, payment_id = %payment.payment_id, error = %e, "Failed to create ledger entry for customer payment");
+                }
+            }
+        }
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["RecordPayment", "ok"])
-            .inc();
-        RECEIPTS_TOTAL
-            .with_label_values(&[&req.payment_method])
+            .with_label_values(&["CreateCustomerPayment", "ok"])
             .inc();
-        // Track payment amount by currency for business metrics
-        if let Some(payment_amount) = receipt.amount.to_f64() {
+        if let Some(payment_amount) = payment.amount.to_f64() {
             PAYMENT_AMOUNT_TOTAL
-                .with_label_values(&[&receipt.currency])
+                .with_label_values(&[&payment.currency])
                 .inc_by(payment_amount);
         }
         timer.observe_duration();
 
         info!(
             tenant_id = %tenant_id,
-            invoice_id = %invoice_id,
-            receipt_id = %receipt.receipt_id,
-            receipt_number = %receipt.receipt_number,
-            amount = %receipt.amount,
-            currency = %receipt.currency,
-            payment_method = %receipt.payment_method,
-            "Payment recorded"
+            customer_id = %customer_id,
+            payment_id = %payment.payment_id,
+            payment_number = %payment.payment_number,
+            amount = %payment.amount,
+            currency = %payment.currency,
+            "Customer payment created"
         );
 
-        Ok(Response::new(RecordPaymentResponse {
-            receipt: Some(Self::receipt_to_proto(&receipt)),
-            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
+        Ok(Response::new(CreateCustomerPaymentResponse {
+            payment: Some(Self::customer_payment_to_proto(&payment, &[])),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "GetReceipt")
+        fields(
+            service = "invoicing-service",
+            method = "GetCustomerPayment",
+            tenant_id,
+            payment_id
+        )
     )]
-    async fn get_receipt(
+    async fn get_customer_payment(
         &self,
-        request: Request<GetReceiptRequest>,
-    ) -> Result<Response<GetReceiptResponse>, Status> {
+        request: Request<GetCustomerPaymentRequest>,
+    ) -> Result<Response<GetCustomerPaymentResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetReceipt"])
+            .with_label_values(&["GetCustomerPayment"])
             .start_timer();
         let req = request.into_inner();
 
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetReceipt", "invalid_argument"])
+                .with_label_values(&["GetCustomerPayment", "invalid_argument"])
                 .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let receipt_id = Uuid::parse_str(&req.receipt_id).map_err(|_| {
+        let payment_id = Uuid::parse_str(&req.payment_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetReceipt", "invalid_argument"])
+                .with_label_values(&["GetCustomerPayment", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid receipt_id format")
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument("Invalid payment_id format")
         })?;
+        Span::current().record("payment_id", payment_id.to_string());
 
-        let receipt = self
-            .db
-            .get_receipt(tenant_id, receipt_id)
-            .await
-            .map_err(|e| {
-                warn!(error = %e, "Failed to get receipt");
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetReceipt", "error"])
-                    .inc();
-                Status::internal("Failed to get receipt")
-            })?;
+        let (payment, allocations) = self
+            .customer_payment("GetCustomerPayment", tenant_id, payment_id)
+            .await?;
 
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetCustomerPayment", "ok"])
+            .inc();
         timer.observe_duration();
 
-        match receipt {
-            Some(r) => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetReceipt", "ok"])
-                    .inc();
-                Ok(Response::new(GetReceiptResponse {
-                    receipt: Some(Self::receipt_to_proto(&r)),
-                }))
-            }
-            None => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetReceipt", "not_found"])
-                    .inc();
-                Err(Status::not_found("Receipt not found"))
-            }
-        }
+        Ok(Response::new(GetCustomerPaymentResponse {
+            payment: Some(Self::customer_payment_to_proto(&payment, &allocations)),
+        }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "invoicing-service", method = "ListReceipts")
+        fields(
+            service = "invoicing-service",
+            method = "ListCustomerPayments",
+            tenant_id
+        )
     )]
-    async fn list_receipts(
+    async fn list_customer_payments(
         &self,
-        request: Request<ListReceiptsRequest>,
-    ) -> Result<Response<ListReceiptsResponse>, Status> {
+        request: Request<ListCustomerPaymentsRequest>,
+    ) -> Result<Response<ListCustomerPaymentsResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["ListReceipts"])
+            .with_label_values(&["ListCustomerPayments"])
             .start_timer();
         let req = request.into_inner();
 
-        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+        let invalid = |message: &str| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["ListReceipts", "invalid_argument"])
+                .with_label_values(&["ListCustomerPayments", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid tenant_id format")
-        })?;
-
-        let invoice_id = if req.invoice_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.invoice_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListReceipts", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid invoice_id format")
-            })?)
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument(message)
         };
 
+        let tenant_id =
+            Uuid::parse_str(&req.tenant_id).map_err(|_| invalid("Invalid tenant_id format"))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
         let customer_id = if req.customer_id.is_empty() {
             None
         } else {
-            Some(Uuid::parse_str(&req.customer_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListReceipts", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid customer_id format")
-            })?)
+            Some(
+                Uuid::parse_str(&req.customer_id)
+                    .map_err(|_| invalid("Invalid customer_id format"))?,
+            )
         };
 
-        let start_date = if req.start_date.is_empty() {
+        let page_token = if req.page_token.is_empty() {
             None
         } else {
             Some(
-                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListReceipts", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid start_date format")
-                })?,
+                Uuid::parse_str(&req.page_token)
+                    .map_err(|_| invalid("Invalid page_token format"))?,
             )
         };
 
-        let end_date = if req.end_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListReceipts", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid end_date format")
-                })?,
-            )
+        let page_size = if req.page_size <= 0 {
+            20
+        } else {
+            req.page_size
+        };
+
+        let filter = ListCustomerPaymentsFilter {
+            customer_id,
+            unapplied_only: req.unapplied_only,
+            page_size,
+            page_token,
+        };
+
+        let payments = self
+            .db
+            .list_customer_payments(tenant_id, &filter)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, error = %e, "Failed to list customer payments");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListCustomerPayments", "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to list customer payments")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ListCustomerPayments", "ok"])
+            .inc();
+        timer.observe_duration();
+
+        let next_page_token = if payments.len() == filter.page_size as usize {
+            payments.last().map(|p| p.payment_id.to_string())
+        } else {
+            None
+        };
+
+        Ok(Response::new(ListCustomerPaymentsResponse {
+            payments: payments
+                .iter()
+                .map(|payment| Self::customer_payment_to_proto(payment, &[]))
+                .collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "AllocatePayment",
+            tenant_id,
+            payment_id
+        )
+    )]
+    async fn allocate_payment(
+        &self,
+        request: Request<AllocatePaymentRequest>,
+    ) -> Result<Response<AllocatePaymentResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["AllocatePayment"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let invalid = |message: String| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AllocatePayment", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument(message)
+        };
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id)
+            .map_err(|_| invalid("Invalid tenant_id format".to_string()))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
+
+        let payment_id = Uuid::parse_str(&req.payment_id)
+            .map_err(|_| invalid("Invalid payment_id format".to_string()))?;
+        Span::current().record("payment_id", payment_id.to_string());
+
+        if req.allocations.is_empty() {
+            return Err(invalid("At least one allocation is required".to_string()));
+        }
+
+        let mut allocations = Vec::with_capacity(req.allocations.len());
+        for (index, allocation) in req.allocations.iter().enumerate() {
+            let invoice_id = Uuid::parse_str(&allocation.invoice_id)
+                .map_err(|_| invalid(format!("allocations[{}].invoice_id is invalid", index)))?;
+            let amount = Decimal::from_str(&allocation.amount)
+                .ok()
+                .filter(|a| *a > Decimal::ZERO)
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "allocations[{}].amount must be a positive decimal",
+                        index
+                    ))
+                })?;
+            allocations.push(PaymentAllocation { invoice_id, amount });
+        }
+
+        let receipts = self
+            .db
+            .allocate_payment(tenant_id, payment_id, &allocations)
+            .await
+            .map_err(|e| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["AllocatePayment", "error"])
+                    .inc();
+                match e {
+                    AppError::BadRequest(err) => Status::failed_precondition(err.to_string()),
+                    AppError::NotFound(err) => {
+                        ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                        Status::not_found(err.to_string())
+                    }
+                    e => {
+                        warn!(tenant_id = %tenant_id, payment_id = %payment_id, error = %e, "Failed to allocate payment");
+                        ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                        Status::internal("Failed to allocate payment")
+                    }
+                }
+            })?;
+
+        let Some(mut receipts) = receipts else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["AllocatePayment", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Customer payment not found"));
+        };
+
+        // Move each allocation from Customer Credit to A/R
+        if let Some(ref ledger_client) = self.ledger_client {
+            for receipt in &mut receipts {
+                let amount_str = format_decimal(&receipt.amount);
+                let entries = vec![
+                    TransactionEntry::debit(
+                        &format!("CUSTOMER-CREDIT-{}", receipt.currency),
+                        &amount_str,
+                    ),
+                    TransactionEntry::credit(&format!("AR-{}", receipt.currency), &amount_str),
+                ];
+
+                let metadata = serde_json::json!({
+                    "source": "invoicing-service",
+                    "payment_id": payment_id.to_string(),
+                    "receipt_id": receipt.receipt_id.to_string(),
+                    "invoice_id": receipt.invoice_id.to_string(),
+                    "customer_id": receipt.customer_id.to_string(),
+                    "amount": &amount_str,
+                })
+                .to_string();
+
+                match ledger_client
+                    .post_transaction(
+                        &tenant_id.to_string(),
+                        entries,
+                        Some(&chrono::Utc::now().date_naive().to_string()),
+                        &format!("payment-allocation-{}", receipt.receipt_id),
+                        Some(&metadata),
+                    )
+                    .await
+                {
+                    Ok(response) => {
+                        if let Some(journal_id) = response
+                            .transaction
+                            .and_then(|txn| Uuid::parse_str(&txn.journal_id).ok())
+                        {
+                            info!(journal_id = %journal_id, "Ledger entry created for payment allocation");
+                            if let Err(e) = self
+                                .db
+                                .set_receipt_journal(tenant_id, receipt.receipt_id, journal_id)
+                                .await
+                            {
+                                warn!(tenant_id = %tenant_id, receipt_id = %receipt.receipt_id, error = %e, "Failed to record allocation journal");
+                            } else {
+                                receipt.journal_id = Some(journal_id);
+                            }
+                        } else {
+                            warn!(tenant_id = %tenant_id, receipt_id = %receipt.receipt_id, "Ledger response missing transaction");
+                        }
+                    }
+                    Err(e) => {
+                        // Log but don't fail - ledger integration is optional enhancement
+                        warn!(tenant_id = %tenant_id, receipt_id = %receipt.receipt_id, error = %e, "Failed to create ledger entry for payment allocation");
+                    }
+                }
+            }
+        }
+
+        let (payment, allocations) = self
+            .customer_payment("AllocatePayment", tenant_id, payment_id)
+            .await?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["AllocatePayment", "ok"])
+            .inc();
+        for receipt in &receipts {
+            RECEIPTS_TOTAL
+                .with_label_values(&[&receipt.payment_method])
+                .inc();
+        }
+        timer.observe_duration();
+
+        info!(
+            tenant_id = %tenant_id,
+            payment_id = %payment_id,
+            allocations = receipts.len(),
+            amount_unapplied = %payment.amount_unapplied(),
+            "Customer payment allocated"
+        );
+
+        Ok(Response::new(AllocatePaymentResponse {
+            payment: Some(Self::customer_payment_to_proto(&payment, &allocations)),
+            receipts: receipts.iter().map(Self::receipt_to_proto).collect(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(
+            service = "invoicing-service",
+            method = "UnallocatePayment",
+            tenant_id,
+            payment_id,
+            receipt_id
+        )
+    )]
+    async fn unallocate_payment(
+        &self,
+        request: Request<UnallocatePaymentRequest>,
+    ) -> Result<Response<UnallocatePaymentResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["UnallocatePayment"])
+            .start_timer();
+        let req = request.into_inner();
+
+        let invalid = |message: &str| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UnallocatePayment", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            Status::invalid_argument(message)
         };
 
-        let page_token = if req.page_token.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.page_token).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListReceipts", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid page_token format")
-            })?)
-        };
+        let tenant_id =
+            Uuid::parse_str(&req.tenant_id).map_err(|_| invalid("Invalid tenant_id format"))?;
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let page_size = if req.page_size <= 0 {
-            20
-        } else {
-            req.page_size
-        };
+        let payment_id =
+            Uuid::parse_str(&req.payment_id).map_err(|_| invalid("Invalid payment_id format"))?;
+        Span::current().record("payment_id", payment_id.to_string());
 
-        let filter = ListReceiptsFilter {
-            invoice_id,
-            customer_id,
-            start_date,
-            end_date,
-            page_size,
-            page_token,
-        };
+        let receipt_id =
+            Uuid::parse_str(&req.receipt_id).map_err(|_| invalid("Invalid receipt_id format"))?;
+        Span::current().record("receipt_id", receipt_id.to_string());
 
-        let receipts = self
+        let receipt = self
             .db
-            .list_receipts(tenant_id, &filter)
+            .unallocate_payment(tenant_id, payment_id, receipt_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to list receipts");
+                warn!(tenant_id = %tenant_id, payment_id = %payment_id, error = %e, "Failed to unallocate payment");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListReceipts", "error"])
+                    .with_label_values(&["UnallocatePayment", "error"])
                     .inc();
-                Status::internal("Failed to list receipts")
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to unallocate payment")
             })?;
 
+        let Some(receipt) = receipt else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UnallocatePayment", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Payment allocation not found"));
+        };
+
+        // Reverse the allocation journal if ledger client is available
+        if let (Some(ledger_client), Some(journal_id)) = (&self.ledger_client, receipt.journal_id) {
+            let metadata = serde_json::json!({
+                "source": "invoicing-service",
+                "payment_id": payment_id.to_string(),
+                "receipt_id": receipt_id.to_string(),
+                "action": "unallocate",
+            })
+            .to_string();
+
+            if let Err(e) = ledger_client
+                .reverse_transaction(
+                    &tenant_id.to_string(),
+                    &journal_id.to_string(),
+                    Some(&chrono::Utc::now().date_naive().to_string()),
+                    &format!("Receipt {} unallocated", receipt.receipt_number),
+                    Some(&metadata),
+                )
+                .await
+            {
+                // Log but don't fail - ledger integration is optional enhancement
+                warn!(tenant_id = %tenant_id, receipt_id = %receipt_id, error = %e, "Failed to reverse allocation ledger entry");
+            } else {
+                info!(receipt_id = %receipt_id, journal_id = %journal_id, "Ledger entry reversed for payment allocation");
+            }
+        }
+
+        let (payment, allocations) = self
+            .customer_payment("UnallocatePayment", tenant_id, payment_id)
+            .await?;
+
+        let invoice = self.db.get_invoice(tenant_id, receipt.invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %receipt.invoice_id, error = %e, "Failed to get invoice");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get invoice")
+        })?.ok_or_else(|| Status::not_found("Invoice not found"))?;
+
+        let line_items = self.db.get_line_items(tenant_id, receipt.invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %receipt.invoice_id, error = %e, "Failed to get line items");
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get line items")
+        })?;
+
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["ListReceipts", "ok"])
+            .with_label_values(&["UnallocatePayment", "ok"])
             .inc();
         timer.observe_duration();
 
-        let next_page_token = if receipts.len() == filter.page_size as usize {
-            receipts.last().map(|r| r.receipt_id.to_string())
-        } else {
-            None
-        };
+        info!(
+            tenant_id = %tenant_id,
+            payment_id = %payment_id,
+            receipt_id = %receipt_id,
+            invoice_id = %receipt.invoice_id,
+            amount = %receipt.amount,
+            "Customer payment unallocated"
+        );
 
-        Ok(Response::new(ListReceiptsResponse {
-            receipts: receipts.iter().map(Self::receipt_to_proto).collect(),
-            next_page_token: next_page_token.unwrap_or_default(),
+        Ok(Response::new(UnallocatePaymentResponse {
+            payment: Some(Self::customer_payment_to_proto(&payment, &allocations)),
+            invoice: Some(Self::invoice_to_proto(&invoice, &line_items)),
         }))
     }
 
@@ -2117,130 +4529,15 @@ impl InvoicingService for InvoicingServiceImpl {
             ));
         }
 
-        // Get customer info from most recent invoice
-        let customer_info = self.db.get_customer_info(tenant_id, customer_id).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get customer info");
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to get customer info")
-        })?;
-
-        let (customer_name, billing_address, currency) = match customer_info {
-            Some(inv) => (
-                inv.customer_name,
-                Some(Address {
-                    line1: inv.billing_line1.unwrap_or_default(),
-                    line2: inv.billing_line2.unwrap_or_default(),
-                    city: inv.billing_city.unwrap_or_default(),
-                    state: inv.billing_state.unwrap_or_default(),
-                    postal_code: inv.billing_postal_code.unwrap_or_default(),
-                    country: inv.billing_country.unwrap_or_default(),
-                }),
-                inv.currency,
-            ),
-            None => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GenerateStatement", "not_found"])
-                    .inc();
-                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
-                return Err(Status::not_found("No invoices found for customer"));
-            }
-        };
-
-        // Calculate opening balance
-        let opening_balance = self.db.calculate_opening_balance(tenant_id, customer_id, period_start).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to calculate opening balance");
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to calculate opening balance")
-        })?;
-
-        // Get invoices in period
-        let invoices = self.db.get_invoices_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get invoices for statement");
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to get invoices")
-        })?;
-
-        // Get receipts in period
-        let receipts = self.db.get_receipts_for_statement(tenant_id, customer_id, period_start, period_end).await.map_err(|e| {
-            warn!(tenant_id = %tenant_id, customer_id = %customer_id, error = %e, "Failed to get receipts for statement");
-            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
-            Status::internal("Failed to get receipts")
-        })?;
-
-        // Build statement lines and calculate totals
-        let mut lines: Vec<(NaiveDate, ProtoStatementLine)> = Vec::new();
-        let mut total_debits = Decimal::ZERO;
-        let mut total_credits = Decimal::ZERO;
-
-        // Add invoice lines (debits)
-        for inv in &invoices {
-            let issue_date = inv.issue_date.unwrap_or(inv.created_utc.date_naive());
-            let doc_type = if inv.invoice_type == "credit_note" {
-                "credit_note"
-            } else {
-                "invoice"
-            };
-            let (debit, credit) = if inv.invoice_type == "credit_note" {
-                total_credits += inv.total;
-                (Decimal::ZERO, inv.total)
-            } else {
-                total_debits += inv.total;
-                (inv.total, Decimal::ZERO)
-            };
-
-            lines.push((
-                issue_date,
-                ProtoStatementLine {
-                    date: issue_date.format("%Y-%m-%d").to_string(),
-                    document_type: doc_type.to_string(),
-                    document_number: inv.invoice_number.clone().unwrap_or_default(),
-                    description: format!(
-                        "Invoice {}",
-                        inv.invoice_number
-                            .as_deref()
-                            .unwrap_or(&inv.invoice_id.to_string())
-                    ),
-                    debit: format_decimal(&debit),
-                    credit: format_decimal(&credit),
-                    balance: String::new(), // Will be calculated below
-                },
-            ));
-        }
-
-        // Add receipt lines (credits)
-        for receipt in &receipts {
-            total_credits += receipt.amount;
-            lines.push((
-                receipt.payment_date,
-                ProtoStatementLine {
-                    date: receipt.payment_date.format("%Y-%m-%d").to_string(),
-                    document_type: "payment".to_string(),
-                    document_number: receipt.receipt_number.clone(),
-                    description: format!("Payment - {}", receipt.payment_method),
-                    debit: format_decimal(&Decimal::ZERO),
-                    credit: format_decimal(&receipt.amount),
-                    balance: String::new(), // Will be calculated below
-                },
-            ));
-        }
-
-        // Sort by date
-        lines.sort_by(|a, b| a.0.cmp(&b.0));
-
-        // Calculate running balance
-        let mut running_balance = opening_balance;
-        let statement_lines: Vec<ProtoStatementLine> = lines
-            .into_iter()
-            .map(|(_, mut line)| {
-                let debit: Decimal = line.debit.parse().unwrap_or(Decimal::ZERO);
-                let credit: Decimal = line.credit.parse().unwrap_or(Decimal::ZERO);
-                running_balance = running_balance + debit - credit;
-                line.balance = format_decimal(&running_balance);
-                line
-            })
-            .collect();
-
-        let closing_balance = running_balance;
+        let statement = self
+            .build_statement(
+                "GenerateStatement",
+                tenant_id,
+                customer_id,
+                period_start,
+                period_end,
+            )
+            .await?;
 
         GRPC_REQUESTS_TOTAL
             .with_label_values(&["GenerateStatement", "ok"])
@@ -2252,28 +4549,14 @@ impl InvoicingService for InvoicingServiceImpl {
             customer_id = %customer_id,
             period_start = %period_start,
             period_end = %period_end,
-            opening_balance = %opening_balance,
-            closing_balance = %closing_balance,
-            lines = statement_lines.len(),
+            opening_balance = %statement.opening_balance,
+            closing_balance = %statement.closing_balance,
+            lines = statement.lines.len(),
             "Statement generated"
         );
 
         Ok(Response::new(GenerateStatementResponse {
-            statement: Some(ProtoStatement {
-                tenant_id: tenant_id.to_string(),
-                customer_id: customer_id.to_string(),
-                customer_name,
-                billing_address,
-                currency,
-                period_start: period_start.format("%Y-%m-%d").to_string(),
-                period_end: period_end.format("%Y-%m-%d").to_string(),
-                opening_balance: format_decimal(&opening_balance),
-                closing_balance: format_decimal(&closing_balance),
-                total_debits: format_decimal(&total_debits),
-                total_credits: format_decimal(&total_credits),
-                lines: statement_lines,
-                generated_at: Some(Self::datetime_to_timestamp(chrono::Utc::now())),
-            }),
+            statement: Some(Self::statement_to_proto(&statement)),
         }))
     }
 
@@ -2461,37 +4744,82 @@ impl InvoicingService for InvoicingServiceImpl {
             .start_timer();
         let req = request.into_inner();
 
-        let _tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateInvoicePdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
-        Span::current().record("tenant_id", _tenant_id.to_string());
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let _invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
+        let invoice_id = Uuid::parse_str(&req.invoice_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateInvoicePdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid invoice_id format")
         })?;
-        Span::current().record("invoice_id", _invoice_id.to_string());
+        Span::current().record("invoice_id", invoice_id.to_string());
+
+        let invoice = self.db.get_invoice(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get invoice");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateInvoicePdf", "error"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get invoice")
+        })?;
+
+        let Some(invoice) = invoice else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateInvoicePdf", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Invoice not found"));
+        };
 
-        // TODO: Implement PDF generation
-        // 1. Fetch invoice with line items
-        // 2. Generate PDF using printpdf or typst
-        // 3. Return PDF bytes and filename
+        let line_items = self.db.get_line_items(tenant_id, invoice_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, invoice_id = %invoice_id, error = %e, "Failed to get line items");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateInvoicePdf", "error"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get line items")
+        })?;
 
+        let template = self
+            .customer_template("GenerateInvoicePdf", tenant_id, invoice.customer_id)
+            .await?;
+        let letterhead = self
+            .letterhead("GenerateInvoicePdf", tenant_id, template.as_ref())
+            .await?;
+        let pdf = render_invoice(&letterhead, &invoice, &line_items);
+        let filename = invoice_filename(&invoice);
+        let stored = self
+            .store_pdf("GenerateInvoicePdf", tenant_id, &filename, pdf)
+            .await?;
+
+        PDFS_GENERATED_TOTAL.with_label_values(&["invoice"]).inc();
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["GenerateInvoicePdf", "unimplemented"])
+            .with_label_values(&["GenerateInvoicePdf", "ok"])
             .inc();
         timer.observe_duration();
 
-        Err(Status::unimplemented(
-            "GenerateInvoicePdf not yet implemented",
-        ))
+        info!(
+            tenant_id = %tenant_id,
+            invoice_id = %invoice_id,
+            document_id = %stored.document_id,
+            filename = %filename,
+            "Invoice PDF generated"
+        );
+
+        Ok(Response::new(GenerateInvoicePdfResponse {
+            filename,
+            document_id: stored.document_id,
+            url: stored.url,
+            url_expires_at: stored.url_expires_at,
+        }))
     }
 
     #[instrument(
@@ -2512,37 +4840,109 @@ impl InvoicingService for InvoicingServiceImpl {
             .start_timer();
         let req = request.into_inner();
 
-        let _tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateReceiptPdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
-        Span::current().record("tenant_id", _tenant_id.to_string());
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let _receipt_id = Uuid::parse_str(&req.receipt_id).map_err(|_| {
+        let receipt_id = Uuid::parse_str(&req.receipt_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateReceiptPdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid receipt_id format")
         })?;
-        Span::current().record("receipt_id", _receipt_id.to_string());
+        Span::current().record("receipt_id", receipt_id.to_string());
+
+        let receipt = self.db.get_receipt(tenant_id, receipt_id).await.map_err(|e| {
+            warn!(tenant_id = %tenant_id, receipt_id = %receipt_id, error = %e, "Failed to get receipt");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateReceiptPdf", "error"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+            Status::internal("Failed to get receipt")
+        })?;
+
+        let Some(receipt) = receipt else {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateReceiptPdf", "not_found"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+            return Err(Status::not_found("Receipt not found"));
+        };
 
-        // TODO: Implement PDF generation
-        // 1. Fetch receipt
-        // 2. Generate PDF using printpdf or typst
-        // 3. Return PDF bytes and filename
+        let invoice = self
+            .db
+            .get_invoice(tenant_id, receipt.invoice_id)
+            .await
+            .map_err(|e| {
+                warn!(tenant_id = %tenant_id, invoice_id = %receipt.invoice_id, error = %e, "Failed to get invoice");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GenerateReceiptPdf", "error"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                Status::internal("Failed to get invoice")
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GenerateReceiptPdf", "not_found"])
+                    .inc();
+                ERRORS_TOTAL.with_label_values(&["not_found"]).inc();
+                Status::not_found("Invoice not found")
+            })?;
+
+        let payment = match receipt.payment_id {
+            Some(payment_id) => self
+                .db
+                .get_customer_payment(tenant_id, payment_id)
+                .await
+                .map_err(|e| {
+                    warn!(tenant_id = %tenant_id, payment_id = %payment_id, error = %e, "Failed to get customer payment");
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["GenerateReceiptPdf", "error"])
+                        .inc();
+                    ERRORS_TOTAL.with_label_values(&["db_error"]).inc();
+                    Status::internal("Failed to get customer payment")
+                })?,
+            None => None,
+        };
 
+        let template = self
+            .customer_template("GenerateReceiptPdf", tenant_id, receipt.customer_id)
+            .await?;
+        let letterhead = self
+            .letterhead("GenerateReceiptPdf", tenant_id, template.as_ref())
+            .await?;
+        let pdf = render_receipt(&letterhead, &receipt, &invoice, payment.as_ref());
+        let filename = receipt_filename(&receipt);
+        let stored = self
+            .store_pdf("GenerateReceiptPdf", tenant_id, &filename, pdf)
+            .await?;
+
+        PDFS_GENERATED_TOTAL.with_label_values(&["receipt"]).inc();
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["GenerateReceiptPdf", "unimplemented"])
+            .with_label_values(&["GenerateReceiptPdf", "ok"])
             .inc();
         timer.observe_duration();
 
-        Err(Status::unimplemented(
-            "GenerateReceiptPdf not yet implemented",
-        ))
+        info!(
+            tenant_id = %tenant_id,
+            receipt_id = %receipt_id,
+            document_id = %stored.document_id,
+            filename = %filename,
+            "Receipt PDF generated"
+        );
+
+        Ok(Response::new(GenerateReceiptPdfResponse {
+            filename,
+            document_id: stored.document_id,
+            url: stored.url,
+            url_expires_at: stored.url_expires_at,
+        }))
     }
 
     #[instrument(
@@ -2563,25 +4963,25 @@ impl InvoicingService for InvoicingServiceImpl {
             .start_timer();
         let req = request.into_inner();
 
-        let _tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateStatementPdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
-        Span::current().record("tenant_id", _tenant_id.to_string());
+        Span::current().record("tenant_id", tenant_id.to_string());
 
-        let _customer_id = Uuid::parse_str(&req.customer_id).map_err(|_| {
+        let customer_id = Uuid::parse_str(&req.customer_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateStatementPdf", "invalid_argument"])
                 .inc();
             ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
             Status::invalid_argument("Invalid customer_id format")
         })?;
-        Span::current().record("customer_id", _customer_id.to_string());
+        Span::current().record("customer_id", customer_id.to_string());
 
-        let _period_start =
+        let period_start =
             NaiveDate::parse_from_str(&req.period_start, "%Y-%m-%d").map_err(|_| {
                 GRPC_REQUESTS_TOTAL
                     .with_label_values(&["GenerateStatementPdf", "invalid_argument"])
@@ -2590,7 +4990,7 @@ impl InvoicingService for InvoicingServiceImpl {
                 Status::invalid_argument("Invalid period_start format")
             })?;
 
-        let _period_end = NaiveDate::parse_from_str(&req.period_end, "%Y-%m-%d").map_err(|_| {
+        let period_end = NaiveDate::parse_from_str(&req.period_end, "%Y-%m-%d").map_err(|_| {
             GRPC_REQUESTS_TOTAL
                 .with_label_values(&["GenerateStatementPdf", "invalid_argument"])
                 .inc();
@@ -2598,18 +4998,58 @@ impl InvoicingService for InvoicingServiceImpl {
             Status::invalid_argument("Invalid period_end format")
         })?;
 
-        // TODO: Implement statement PDF generation
-        // 1. Generate statement (same logic as GenerateStatement)
-        // 2. Generate PDF using printpdf or typst
-        // 3. Return PDF bytes, filename, and statement data
+        if period_start > period_end {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GenerateStatementPdf", "invalid_argument"])
+                .inc();
+            ERRORS_TOTAL.with_label_values(&["validation_error"]).inc();
+            return Err(Status::invalid_argument(
+                "period_start must be before period_end",
+            ));
+        }
 
+        let statement = self
+            .build_statement(
+                "GenerateStatementPdf",
+                tenant_id,
+                customer_id,
+                period_start,
+                period_end,
+            )
+            .await?;
+
+        let template = self
+            .customer_template("GenerateStatementPdf", tenant_id, customer_id)
+            .await?;
+        let letterhead = self
+            .letterhead("GenerateStatementPdf", tenant_id, template.as_ref())
+            .await?;
+        let pdf = render_statement(&letterhead, &statement);
+        let filename = statement_filename(&statement);
+        let stored = self
+            .store_pdf("GenerateStatementPdf", tenant_id, &filename, pdf)
+            .await?;
+
+        PDFS_GENERATED_TOTAL.with_label_values(&["statement"]).inc();
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["GenerateStatementPdf", "unimplemented"])
+            .with_label_values(&["GenerateStatementPdf", "ok"])
             .inc();
         timer.observe_duration();
 
-        Err(Status::unimplemented(
-            "GenerateStatementPdf not yet implemented",
-        ))
+        info!(
+            tenant_id = %tenant_id,
+            customer_id = %customer_id,
+            document_id = %stored.document_id,
+            filename = %filename,
+            "Statement PDF generated"
+        );
+
+        Ok(Response::new(GenerateStatementPdfResponse {
+            filename,
+            statement: Some(Self::statement_to_proto(&statement)),
+            document_id: stored.document_id,
+            url: stored.url,
+            url_expires_at: stored.url_expires_at,
+        }))
     }
 }
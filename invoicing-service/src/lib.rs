@@ -3,5 +3,6 @@
 pub mod config;
 pub mod grpc;
 pub mod models;
+pub mod rendering;
 pub mod services;
 pub mod startup;
@@ -0,0 +1,26 @@
+//! Credit note model for invoicing-service.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Quantity to credit on one line of the original invoice.
+#[derive(Debug, Clone)]
+pub struct CreditNoteLine {
+    pub line_item_id: Uuid,
+    pub quantity: Decimal,
+}
+
+/// Input for creating a credit note against an issued invoice.
+#[derive(Debug, Clone)]
+pub struct CreateCreditNote {
+    pub tenant_id: Uuid,
+    pub invoice_id: Uuid,
+    /// Lines to credit; empty credits everything not yet credited.
+    pub lines: Vec<CreditNoteLine>,
+    /// Allow the credit to exceed the invoice balance, leaving the excess as
+    /// a refundable credit on the credit note.
+    pub refundable: bool,
+    pub issue_date: NaiveDate,
+    pub notes: Option<String>,
+}
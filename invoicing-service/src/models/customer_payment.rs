@@ -0,0 +1,62 @@
+//! Customer payment model for invoicing-service.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Money received from a customer, allocated across their invoices.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CustomerPayment {
+    pub payment_id: Uuid,
+    pub tenant_id: Uuid,
+    pub payment_number: String,
+    pub customer_id: Uuid,
+    pub amount: Decimal,
+    pub amount_allocated: Decimal,
+    pub currency: String,
+    pub payment_method: String,
+    pub payment_reference: Option<String>,
+    pub payment_date: NaiveDate,
+    pub journal_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub created_utc: DateTime<Utc>,
+}
+
+impl CustomerPayment {
+    /// Cash not yet allocated to an invoice.
+    pub fn amount_unapplied(&self) -> Decimal {
+        self.amount - self.amount_allocated
+    }
+}
+
+/// Input for recording a customer payment.
+#[derive(Debug, Clone)]
+pub struct CreateCustomerPayment {
+    pub tenant_id: Uuid,
+    pub customer_id: Uuid,
+    pub amount: Decimal,
+    pub currency: String,
+    pub payment_method: String,
+    pub payment_reference: Option<String>,
+    pub payment_date: NaiveDate,
+    pub notes: Option<String>,
+}
+
+/// Amount of a customer payment to apply to one invoice.
+#[derive(Debug, Clone)]
+pub struct PaymentAllocation {
+    pub invoice_id: Uuid,
+    pub amount: Decimal,
+}
+
+/// Filter parameters for listing customer payments.
+#[derive(Debug, Clone, Default)]
+pub struct ListCustomerPaymentsFilter {
+    pub customer_id: Option<Uuid>,
+    /// Only payments with unapplied cash.
+    pub unapplied_only: bool,
+    pub page_size: i32,
+    pub page_token: Option<Uuid>,
+}
@@ -90,6 +90,7 @@ pub struct Invoice {
     pub total: Decimal,
     pub amount_paid: Decimal,
     pub amount_due: Decimal,
+    pub amount_credited: Decimal,
     pub notes: Option<String>,
     pub reference_invoice_id: Option<Uuid>,
     pub journal_id: Option<Uuid>,
@@ -0,0 +1,43 @@
+//! Invoice template model for invoicing-service.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A tenant's branding for generated invoice, receipt and statement PDFs.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InvoiceTemplate {
+    pub template_id: Uuid,
+    pub tenant_id: Uuid,
+    pub name: String,
+    /// Hex colour (#RRGGBB) for titles, table headings and totals.
+    pub primary_color: Option<String>,
+    /// Hex colour (#RRGGBB) for the table heading band.
+    pub accent_color: Option<String>,
+    /// Replaces the tenant profile's logo.
+    pub logo_document_id: Option<Uuid>,
+    /// Footer text with `{{variable}}` placeholders.
+    pub footer_template: Option<String>,
+    /// Terms printed after the totals, with `{{variable}}` placeholders.
+    pub terms_template: Option<String>,
+    /// Label key to replacement text, e.g. `{"invoice_title": "FACTURE"}`.
+    pub labels: serde_json::Value,
+    pub is_default: bool,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+/// Input for creating an invoice template.
+#[derive(Debug, Clone)]
+pub struct CreateInvoiceTemplate {
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub primary_color: Option<String>,
+    pub accent_color: Option<String>,
+    pub logo_document_id: Option<Uuid>,
+    pub footer_template: Option<String>,
+    pub terms_template: Option<String>,
+    pub labels: serde_json::Value,
+    pub is_default: bool,
+}
@@ -6,6 +6,38 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::LineItemTax;
+
+/// What a line item bills, which decides where it is posted in the ledger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineItemType {
+    /// Goods or services, credited to revenue.
+    Charge,
+    /// Negative line reducing revenue.
+    Discount,
+    /// Negative line paid from the customer's credit balance.
+    Credit,
+}
+
+impl LineItemType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineItemType::Charge => "charge",
+            LineItemType::Discount => "discount",
+            LineItemType::Credit => "credit",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "discount" => LineItemType::Discount,
+            "credit" => LineItemType::Credit,
+            _ => LineItemType::Charge,
+        }
+    }
+}
+
 /// Line item on an invoice.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct LineItem {
@@ -16,12 +48,19 @@ pub struct LineItem {
     pub quantity: Decimal,
     pub unit_price: Decimal,
     pub tax_rate_id: Option<Uuid>,
+    pub tax_group_id: Option<Uuid>,
     pub tax_amount: Decimal,
     pub subtotal: Decimal,
     pub total: Decimal,
     pub ledger_account_id: Option<Uuid>,
+    pub reference_line_item_id: Option<Uuid>,
     pub sort_order: i32,
+    pub line_type: String,
     pub created_utc: DateTime<Utc>,
+    /// Tax per rate or group component; `tax_amount` is their sum.
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub taxes: Vec<LineItemTax>,
 }
 
 /// Input for creating a line item.
@@ -33,8 +72,10 @@ pub struct CreateLineItem {
     pub quantity: Decimal,
     pub unit_price: Decimal,
     pub tax_rate_id: Option<Uuid>,
+    pub tax_group_id: Option<Uuid>,
     pub ledger_account_id: Option<Uuid>,
     pub sort_order: i32,
+    pub line_type: LineItemType,
 }
 
 /// Input for updating a line item.
@@ -44,6 +85,7 @@ pub struct UpdateLineItem {
     pub quantity: Option<Decimal>,
     pub unit_price: Option<Decimal>,
     pub tax_rate_id: Option<Uuid>,
+    pub tax_group_id: Option<Uuid>,
     pub ledger_account_id: Option<Uuid>,
     pub sort_order: Option<i32>,
 }
@@ -1,13 +1,30 @@
 //! Domain models for invoicing-service.
 
+mod credit_note;
+mod customer_payment;
 mod invoice;
+mod invoice_template;
 mod line_item;
 mod receipt;
+mod statement;
+mod tax_group;
 mod tax_rate;
+mod tenant_profile;
 
+pub use credit_note::{CreateCreditNote, CreditNoteLine};
+pub use customer_payment::{
+    CreateCustomerPayment, CustomerPayment, ListCustomerPaymentsFilter, PaymentAllocation,
+};
 pub use invoice::{
     CreateInvoice, Invoice, InvoiceStatus, InvoiceType, ListInvoicesFilter, UpdateInvoice,
 };
-pub use line_item::{CreateLineItem, LineItem, UpdateLineItem};
+pub use invoice_template::{CreateInvoiceTemplate, InvoiceTemplate};
+pub use line_item::{CreateLineItem, LineItem, LineItemType, UpdateLineItem};
 pub use receipt::{CreateReceipt, ListReceiptsFilter, Receipt};
+pub use statement::{Statement, StatementLine};
+pub use tax_group::{
+    CreateTaxComponent, CreateTaxGroup, LineItemTax, PlaceOfSupply, TaxBreakdown, TaxComponent,
+    TaxComponentKind, TaxGroup,
+};
 pub use tax_rate::{CreateTaxRate, TaxRate, UpdateTaxRate};
+pub use tenant_profile::{SetTenantProfile, TenantProfile};
@@ -21,6 +21,8 @@ pub struct Receipt {
     pub payment_date: NaiveDate,
     pub journal_id: Option<Uuid>,
     pub notes: Option<String>,
+    /// The customer payment this receipt allocates, if any.
+    pub payment_id: Option<Uuid>,
     pub created_utc: DateTime<Utc>,
 }
 
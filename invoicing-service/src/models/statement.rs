@@ -0,0 +1,46 @@
+//! Statement model for invoicing-service.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::TaxBreakdown;
+
+/// Customer account activity over a period.
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub tenant_id: Uuid,
+    pub customer_id: Uuid,
+    pub customer_name: String,
+    pub billing_line1: Option<String>,
+    pub billing_line2: Option<String>,
+    pub billing_city: Option<String>,
+    pub billing_state: Option<String>,
+    pub billing_postal_code: Option<String>,
+    pub billing_country: Option<String>,
+    pub currency: String,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub opening_balance: Decimal,
+    pub closing_balance: Decimal,
+    pub total_debits: Decimal,
+    pub total_credits: Decimal,
+    /// Customer payments received by the period end not yet allocated.
+    pub unapplied_cash: Decimal,
+    pub lines: Vec<StatementLine>,
+    /// Tax on invoices issued in the period, net of credit notes.
+    pub tax_breakdown: Vec<TaxBreakdown>,
+    pub generated_utc: DateTime<Utc>,
+}
+
+/// One invoice, credit note or payment on a statement.
+#[derive(Debug, Clone)]
+pub struct StatementLine {
+    pub date: NaiveDate,
+    pub document_type: String,
+    pub document_number: String,
+    pub description: String,
+    pub debit: Decimal,
+    pub credit: Decimal,
+    pub balance: Decimal,
+}
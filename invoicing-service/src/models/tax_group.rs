@@ -0,0 +1,151 @@
+//! Tax group model for invoicing-service.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::LineItem;
+
+/// How a tax group component is calculated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaxComponentKind {
+    /// Percentage of the line subtotal.
+    Standard,
+    /// Percentage of the line subtotal plus the taxes before it.
+    Compound,
+    /// Percentage of the line subtotal deducted from the total.
+    Withholding,
+}
+
+impl TaxComponentKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxComponentKind::Standard => "standard",
+            TaxComponentKind::Compound => "compound",
+            TaxComponentKind::Withholding => "withholding",
+        }
+    }
+
+    pub fn from_string(s: &str) -> Self {
+        match s {
+            "compound" => TaxComponentKind::Compound,
+            "withholding" => TaxComponentKind::Withholding,
+            _ => TaxComponentKind::Standard,
+        }
+    }
+}
+
+/// Whether a supply is within the tenant's registered state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceOfSupply {
+    IntraState,
+    InterState,
+}
+
+impl PlaceOfSupply {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlaceOfSupply::IntraState => "intra_state",
+            PlaceOfSupply::InterState => "inter_state",
+        }
+    }
+}
+
+/// A named set of tax components applied together to a line item.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaxGroup {
+    pub tax_group_id: Uuid,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub active: bool,
+    pub created_utc: DateTime<Utc>,
+    #[sqlx(skip)]
+    #[serde(default)]
+    pub components: Vec<TaxComponent>,
+}
+
+/// One rate within a tax group.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaxComponent {
+    pub tax_group_id: Uuid,
+    pub name: String,
+    pub rate: Decimal,
+    pub kind: String,
+    /// `any`, `intra_state` or `inter_state`.
+    pub applies_to: String,
+    pub sort_order: i32,
+}
+
+impl TaxComponent {
+    /// Whether the component is charged for the given place of supply.
+    pub fn applies(&self, place_of_supply: PlaceOfSupply) -> bool {
+        self.applies_to == "any" || self.applies_to == place_of_supply.as_str()
+    }
+}
+
+/// Input for one component of a new tax group.
+#[derive(Debug, Clone)]
+pub struct CreateTaxComponent {
+    pub name: String,
+    pub rate: Decimal,
+    pub kind: TaxComponentKind,
+    pub applies_to: Option<PlaceOfSupply>,
+}
+
+/// Input for creating a tax group.
+#[derive(Debug, Clone)]
+pub struct CreateTaxGroup {
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub components: Vec<CreateTaxComponent>,
+}
+
+/// Tax charged on a line item for one rate or group component.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct LineItemTax {
+    pub name: String,
+    pub kind: String,
+    pub rate: Decimal,
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+/// Tax totals for one rate or component across a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct TaxBreakdown {
+    pub name: String,
+    pub kind: String,
+    pub rate: Decimal,
+    pub taxable_amount: Decimal,
+    pub tax_amount: Decimal,
+}
+
+impl TaxBreakdown {
+    /// Sum line item taxes by name, kind and rate, in order of first use.
+    pub fn from_line_items(line_items: &[LineItem]) -> Vec<Self> {
+        let mut breakdown: Vec<Self> = Vec::new();
+        for tax in line_items.iter().flat_map(|item| &item.taxes) {
+            match breakdown
+                .iter_mut()
+                .find(|b| b.name == tax.name && b.kind == tax.kind && b.rate == tax.rate)
+            {
+                Some(row) => {
+                    row.taxable_amount += tax.taxable_amount;
+                    row.tax_amount += tax.tax_amount;
+                }
+                None => breakdown.push(Self {
+                    name: tax.name.clone(),
+                    kind: tax.kind.clone(),
+                    rate: tax.rate,
+                    taxable_amount: tax.taxable_amount,
+                    tax_amount: tax.tax_amount,
+                }),
+            }
+        }
+        breakdown
+    }
+}
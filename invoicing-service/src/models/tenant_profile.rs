@@ -0,0 +1,42 @@
+//! Tenant profile model for invoicing-service.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Issuer details printed on a tenant's invoices, receipts and statements.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TenantProfile {
+    pub tenant_id: Uuid,
+    pub legal_name: String,
+    pub tax_id: Option<String>,
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub address_city: Option<String>,
+    pub address_state: Option<String>,
+    pub address_postal_code: Option<String>,
+    pub address_country: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub logo_document_id: Option<Uuid>,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+/// Input for setting a tenant profile.
+#[derive(Debug, Clone)]
+pub struct SetTenantProfile {
+    pub tenant_id: Uuid,
+    pub legal_name: String,
+    pub tax_id: Option<String>,
+    pub address_line1: Option<String>,
+    pub address_line2: Option<String>,
+    pub address_city: Option<String>,
+    pub address_state: Option<String>,
+    pub address_postal_code: Option<String>,
+    pub address_country: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub logo_document_id: Option<Uuid>,
+}
@@ -0,0 +1,255 @@
+//! Tenant branding applied to rendered documents.
+//!
+//! Footer and terms text are templates with Handlebars-style `{{variable}}`
+//! placeholders. Labels replace the fixed text printed on documents, which is
+//! how a template sets the document language.
+
+use crate::models::InvoiceTemplate;
+use crate::rendering::pdf::Color;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Variables available to footer and terms templates.
+pub const TEMPLATE_VARIABLES: &[&str] = &[
+    "tenant.legal_name",
+    "tenant.tax_id",
+    "tenant.email",
+    "tenant.phone",
+    "customer.name",
+    "document.number",
+    "document.date",
+    "document.due_date",
+    "document.total",
+    "document.amount_due",
+    "document.currency",
+];
+
+/// Label keys and their default text.
+pub const DEFAULT_LABELS: &[(&str, &str)] = &[
+    ("invoice_title", "INVOICE"),
+    ("credit_note_title", "CREDIT NOTE"),
+    ("proforma_title", "PROFORMA INVOICE"),
+    ("receipt_title", "RECEIPT"),
+    ("statement_title", "STATEMENT"),
+    ("invoice_number", "Invoice no."),
+    ("credit_note_number", "Credit note no."),
+    ("proforma_number", "Proforma no."),
+    ("receipt_number", "Receipt no."),
+    ("draft", "Draft"),
+    ("issue_date", "Issue date"),
+    ("due_date", "Due date"),
+    ("status", "Status"),
+    ("status_draft", "Draft"),
+    ("status_issued", "Issued"),
+    ("status_paid", "Paid"),
+    ("status_void", "Void"),
+    ("status_overdue", "Overdue"),
+    ("payment_date", "Payment date"),
+    ("period", "Period"),
+    ("period_to", "to"),
+    ("generated", "Generated"),
+    ("currency", "Currency"),
+    ("bill_to", "BILL TO"),
+    ("received_from", "RECEIVED FROM"),
+    ("statement_for", "STATEMENT FOR"),
+    ("description", "Description"),
+    ("quantity", "Qty"),
+    ("unit_price", "Unit price"),
+    ("tax", "Tax"),
+    ("amount", "Amount"),
+    ("subtotal", "Subtotal"),
+    ("tax_on", "on"),
+    ("tax_summary", "Tax summary"),
+    ("taxable_amount", "Taxable amount"),
+    ("total", "Total"),
+    ("amount_paid", "Amount paid"),
+    ("amount_due", "Amount due"),
+    ("amount_credited", "Amount credited"),
+    ("refundable_credit", "Refundable credit"),
+    ("notes", "NOTES"),
+    ("terms", "TERMS"),
+    ("payment_details", "Payment details"),
+    ("payment_method", "Payment method"),
+    ("payment_reference", "Payment reference"),
+    ("invoice_total", "Invoice total"),
+    ("amount_received", "Amount received"),
+    ("invoice_balance_due", "Invoice balance due"),
+    ("customer_payment", "Customer payment"),
+    ("payment_total", "Payment total"),
+    ("unapplied_cash", "Unapplied cash"),
+    ("opening_balance", "Opening balance"),
+    ("invoiced", "Invoiced"),
+    ("payments_and_credits", "Payments and credits"),
+    ("closing_balance", "Closing balance"),
+    ("date", "Date"),
+    ("number", "Number"),
+    ("debit", "Debit"),
+    ("credit", "Credit"),
+    ("balance", "Balance"),
+    ("tax_id", "Tax ID"),
+    ("page", "Page"),
+    ("page_of", "of"),
+];
+
+/// Why a template's branding is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrandingError(String);
+
+impl fmt::Display for BrandingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for BrandingError {}
+
+#[derive(Debug, Clone)]
+enum Part {
+    Text(String),
+    Variable(String),
+}
+
+/// Text with `{{variable}}` placeholders.
+#[derive(Debug, Clone)]
+pub struct TextTemplate {
+    parts: Vec<Part>,
+}
+
+impl TextTemplate {
+    /// Parse a template, rejecting unclosed placeholders and unknown variables.
+    pub fn parse(source: &str) -> Result<Self, BrandingError> {
+        let mut parts = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_string()));
+            }
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| BrandingError("Unclosed {{ in template".to_string()))?;
+            let name = after[..end].trim();
+            if !TEMPLATE_VARIABLES.contains(&name) {
+                return Err(BrandingError(format!(
+                    "Unknown template variable '{}'",
+                    name
+                )));
+            }
+            parts.push(Part::Variable(name.to_string()));
+            rest = &after[end + 2..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        Ok(Self { parts })
+    }
+
+    /// Substitute variables; those without a value render as empty text.
+    pub fn render(&self, values: &HashMap<&str, String>) -> String {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.as_str(),
+                Part::Variable(name) => values.get(name.as_str()).map_or("", String::as_str),
+            })
+            .collect()
+    }
+}
+
+/// Colours, labels and template text applied to a document.
+#[derive(Debug, Clone)]
+pub struct Branding {
+    pub primary: Color,
+    pub accent: Color,
+    labels: HashMap<String, String>,
+    footer: Option<TextTemplate>,
+    terms: Option<TextTemplate>,
+}
+
+impl Default for Branding {
+    fn default() -> Self {
+        Self {
+            primary: Color::BLACK,
+            accent: Color::LIGHT_GREY,
+            labels: HashMap::new(),
+            footer: None,
+            terms: None,
+        }
+    }
+}
+
+impl Branding {
+    /// Validate and build branding from its stored parts.
+    pub fn new(
+        primary_color: Option<&str>,
+        accent_color: Option<&str>,
+        labels: &serde_json::Value,
+        footer_template: Option<&str>,
+        terms_template: Option<&str>,
+    ) -> Result<Self, BrandingError> {
+        let color = |field: &str, value: Option<&str>, default: Color| match value {
+            Some(hex) => Color::from_hex(hex)
+                .ok_or_else(|| BrandingError(format!("{} must be a #RRGGBB colour", field))),
+            None => Ok(default),
+        };
+
+        let labels = match labels {
+            serde_json::Value::Null => HashMap::new(),
+            serde_json::Value::Object(map) => {
+                let mut labels = HashMap::with_capacity(map.len());
+                for (key, value) in map {
+                    if !DEFAULT_LABELS.iter().any(|(k, _)| k == key) {
+                        return Err(BrandingError(format!("Unknown label '{}'", key)));
+                    }
+                    let text = value.as_str().ok_or_else(|| {
+                        BrandingError(format!("Label '{}' must be a string", key))
+                    })?;
+                    labels.insert(key.clone(), text.to_string());
+                }
+                labels
+            }
+            _ => return Err(BrandingError("labels must be an object".to_string())),
+        };
+
+        Ok(Self {
+            primary: color("primary_color", primary_color, Color::BLACK)?,
+            accent: color("accent_color", accent_color, Color::LIGHT_GREY)?,
+            labels,
+            footer: footer_template.map(TextTemplate::parse).transpose()?,
+            terms: terms_template.map(TextTemplate::parse).transpose()?,
+        })
+    }
+
+    /// Branding from a stored template.
+    pub fn from_template(template: &InvoiceTemplate) -> Result<Self, BrandingError> {
+        Self::new(
+            template.primary_color.as_deref(),
+            template.accent_color.as_deref(),
+            &template.labels,
+            template.footer_template.as_deref(),
+            template.terms_template.as_deref(),
+        )
+    }
+
+    /// Text for a label, falling back to its default.
+    pub fn label<'a>(&'a self, key: &'a str) -> &'a str {
+        self.labels
+            .get(key)
+            .map(String::as_str)
+            .or_else(|| {
+                DEFAULT_LABELS
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, text)| *text)
+            })
+            .unwrap_or(key)
+    }
+
+    pub(crate) fn footer(&self, values: &HashMap<&str, String>) -> Option<String> {
+        self.footer.as_ref().map(|footer| footer.render(values))
+    }
+
+    pub(crate) fn terms(&self, values: &HashMap<&str, String>) -> Option<String> {
+        self.terms.as_ref().map(|terms| terms.render(values))
+    }
+}
@@ -0,0 +1,934 @@
+//! Page layouts for invoices, receipts and statements.
+
+use crate::models::{
+    CustomerPayment, Invoice, InvoiceStatus, InvoiceType, LineItem, Receipt, Statement,
+    TaxBreakdown, TenantProfile,
+};
+use crate::rendering::branding::Branding;
+use crate::rendering::pdf::{Color, Font, Image, ImageId, PdfDocument, PAGE_HEIGHT, PAGE_WIDTH};
+use chrono::NaiveDate;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const MARGIN: f32 = 50.0;
+const RIGHT: f32 = PAGE_WIDTH - MARGIN;
+const TOP: f32 = PAGE_HEIGHT - MARGIN;
+/// Lowest baseline for body content; the footer sits below it.
+const BOTTOM: f32 = 80.0;
+
+const LOGO_MAX_WIDTH: f32 = 150.0;
+const LOGO_MAX_HEIGHT: f32 = 50.0;
+
+/// Issuer details printed at the top of every document, and the branding
+/// applied to it.
+#[derive(Default)]
+pub struct Letterhead {
+    pub profile: Option<TenantProfile>,
+    pub logo: Option<Image>,
+    pub branding: Branding,
+}
+
+/// Suggested filename for an invoice PDF.
+pub fn invoice_filename(invoice: &Invoice) -> String {
+    match &invoice.invoice_number {
+        Some(number) => format!("{}.pdf", number),
+        None => format!("DRAFT-{}.pdf", short_id(invoice.invoice_id)),
+    }
+}
+
+/// Suggested filename for a receipt PDF.
+pub fn receipt_filename(receipt: &Receipt) -> String {
+    format!("{}.pdf", receipt.receipt_number)
+}
+
+/// Suggested filename for a statement PDF.
+pub fn statement_filename(statement: &Statement) -> String {
+    format!(
+        "STMT-{}-{}-{}.pdf",
+        short_id(statement.customer_id),
+        statement.period_start.format("%Y%m%d"),
+        statement.period_end.format("%Y%m%d")
+    )
+}
+
+/// Render an invoice, credit note or proforma invoice with its line items,
+/// a breakdown of tax by rate or component and its totals.
+pub fn render_invoice(
+    letterhead: &Letterhead,
+    invoice: &Invoice,
+    line_items: &[LineItem],
+) -> Vec<u8> {
+    let branding = &letterhead.branding;
+    let invoice_type = InvoiceType::from_string(&invoice.invoice_type);
+    let status = InvoiceStatus::from_string(&invoice.status);
+    let (title, number_label) = match invoice_type {
+        InvoiceType::CreditNote => ("credit_note_title", "credit_note_number"),
+        InvoiceType::Proforma => ("proforma_title", "proforma_number"),
+        InvoiceType::Standard => ("invoice_title", "invoice_number"),
+    };
+    let title = branding.label(title);
+    let number = invoice
+        .invoice_number
+        .clone()
+        .unwrap_or_else(|| branding.label("draft").to_string());
+
+    let mut meta = vec![(branding.label(number_label), number.clone())];
+    if let Some(issue_date) = invoice.issue_date {
+        meta.push((branding.label("issue_date"), issue_date.to_string()));
+    }
+    if let Some(due_date) = invoice.due_date {
+        meta.push((branding.label("due_date"), due_date.to_string()));
+    }
+    let status_label = format!("status_{}", status.as_str());
+    meta.push((
+        branding.label("status"),
+        branding.label(&status_label).to_string(),
+    ));
+
+    let values = template_values(
+        letterhead,
+        &invoice.customer_name,
+        &number,
+        invoice.issue_date,
+        invoice.due_date,
+        &invoice.total,
+        &invoice.amount_due,
+        &invoice.currency,
+    );
+
+    let mut canvas = Canvas::new(letterhead, &format!("{} {}", title, number));
+    canvas.header(letterhead, title, &meta);
+    canvas.address_block(
+        branding.label("bill_to"),
+        &invoice.customer_name,
+        &address_lines(
+            &invoice.billing_line1,
+            &invoice.billing_line2,
+            &invoice.billing_city,
+            &invoice.billing_state,
+            &invoice.billing_postal_code,
+            &invoice.billing_country,
+        ),
+    );
+
+    let columns = [
+        Column::left(branding.label("description"), MARGIN, 250.0),
+        Column::right(branding.label("quantity"), 360.0),
+        Column::right(branding.label("unit_price"), 430.0),
+        Column::right(branding.label("tax"), 485.0),
+        Column::right(branding.label("amount"), RIGHT),
+    ];
+    canvas.table_header(&columns);
+    for item in line_items {
+        canvas.table_row(
+            &columns,
+            &[
+                item.description.clone(),
+                format_quantity(&item.quantity),
+                format_amount(&item.unit_price),
+                format_amount(&item.tax_amount),
+                format_amount(&item.subtotal),
+            ],
+        );
+    }
+
+    let currency = invoice.currency.as_str();
+    let mut totals = vec![TotalRow::new(
+        branding.label("subtotal"),
+        format_money(&invoice.subtotal, currency),
+    )];
+    for row in TaxBreakdown::from_line_items(line_items) {
+        totals.push(TotalRow::new(
+            &format!(
+                "{} {} {}",
+                tax_label(&row),
+                branding.label("tax_on"),
+                format_amount(&row.taxable_amount)
+            ),
+            format_money(&row.tax_amount, currency),
+        ));
+    }
+    totals.push(TotalRow::bold(
+        branding.label("total"),
+        format_money(&invoice.total, currency),
+    ));
+    if invoice.amount_paid > Decimal::ZERO {
+        totals.push(TotalRow::new(
+            branding.label("amount_paid"),
+            format_money(&invoice.amount_paid, currency),
+        ));
+    }
+    if invoice.amount_credited > Decimal::ZERO {
+        totals.push(TotalRow::new(
+            branding.label("amount_credited"),
+            format_money(&invoice.amount_credited, currency),
+        ));
+    }
+    if invoice_type == InvoiceType::CreditNote && invoice.amount_due > Decimal::ZERO {
+        totals.push(TotalRow::bold(
+            branding.label("refundable_credit"),
+            format_money(&invoice.amount_due, currency),
+        ));
+    }
+    if invoice_type != InvoiceType::CreditNote && status != InvoiceStatus::Void {
+        totals.push(TotalRow::bold(
+            branding.label("amount_due"),
+            format_money(&invoice.amount_due, currency),
+        ));
+    }
+    canvas.totals(&totals);
+
+    if let Some(notes) = invoice.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+        canvas.notes(branding.label("notes"), notes);
+    }
+    canvas.terms(letterhead, &values);
+
+    canvas.finish(letterhead, &number, &values)
+}
+
+/// Render the receipt for a payment against an invoice. A receipt allocating
+/// a customer payment also shows the payment it was allocated from.
+pub fn render_receipt(
+    letterhead: &Letterhead,
+    receipt: &Receipt,
+    invoice: &Invoice,
+    payment: Option<&CustomerPayment>,
+) -> Vec<u8> {
+    let branding = &letterhead.branding;
+    let invoice_number = invoice
+        .invoice_number
+        .clone()
+        .unwrap_or_else(|| short_id(invoice.invoice_id));
+    let meta = [
+        (
+            branding.label("receipt_number"),
+            receipt.receipt_number.clone(),
+        ),
+        (
+            branding.label("payment_date"),
+            receipt.payment_date.to_string(),
+        ),
+        (branding.label("invoice_number"), invoice_number),
+    ];
+
+    let values = template_values(
+        letterhead,
+        &invoice.customer_name,
+        &receipt.receipt_number,
+        Some(receipt.payment_date),
+        invoice.due_date,
+        &receipt.amount,
+        &invoice.amount_due,
+        &receipt.currency,
+    );
+
+    let title = branding.label("receipt_title");
+    let mut canvas = Canvas::new(letterhead, &format!("{} {}", title, receipt.receipt_number));
+    canvas.header(letterhead, title, &meta);
+    canvas.address_block(
+        branding.label("received_from"),
+        &invoice.customer_name,
+        &address_lines(
+            &invoice.billing_line1,
+            &invoice.billing_line2,
+            &invoice.billing_city,
+            &invoice.billing_state,
+            &invoice.billing_postal_code,
+            &invoice.billing_country,
+        ),
+    );
+
+    let columns = [
+        Column::left(branding.label("payment_details"), MARGIN, 300.0),
+        Column::right("", RIGHT),
+    ];
+    canvas.table_header(&columns);
+    canvas.table_row(
+        &columns,
+        &[
+            branding.label("payment_method").to_string(),
+            humanize(&receipt.payment_method),
+        ],
+    );
+    if let Some(reference) = receipt.payment_reference.as_deref() {
+        canvas.table_row(
+            &columns,
+            &[
+                branding.label("payment_reference").to_string(),
+                reference.to_string(),
+            ],
+        );
+    }
+    if let Some(payment) = payment {
+        canvas.table_row(
+            &columns,
+            &[
+                branding.label("customer_payment").to_string(),
+                payment.payment_number.clone(),
+            ],
+        );
+        canvas.table_row(
+            &columns,
+            &[
+                branding.label("payment_total").to_string(),
+                format_money(&payment.amount, &payment.currency),
+            ],
+        );
+    }
+    canvas.table_row(
+        &columns,
+        &[
+            branding.label("invoice_total").to_string(),
+            format_money(&invoice.total, &invoice.currency),
+        ],
+    );
+
+    let mut totals = vec![
+        TotalRow::bold(
+            branding.label("amount_received"),
+            format_money(&receipt.amount, &receipt.currency),
+        ),
+        TotalRow::new(
+            branding.label("invoice_balance_due"),
+            format_money(&invoice.amount_due, &invoice.currency),
+        ),
+    ];
+    if let Some(payment) = payment.filter(|p| p.amount_unapplied() > Decimal::ZERO) {
+        totals.push(TotalRow::new(
+            branding.label("unapplied_cash"),
+            format_money(&payment.amount_unapplied(), &payment.currency),
+        ));
+    }
+    canvas.totals(&totals);
+
+    if let Some(notes) = receipt.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+        canvas.notes(branding.label("notes"), notes);
+    }
+    canvas.terms(letterhead, &values);
+
+    canvas.finish(letterhead, &receipt.receipt_number, &values)
+}
+
+/// Render a customer statement with its running balance.
+pub fn render_statement(letterhead: &Letterhead, statement: &Statement) -> Vec<u8> {
+    let branding = &letterhead.branding;
+    let period = format!(
+        "{} {} {}",
+        statement.period_start,
+        branding.label("period_to"),
+        statement.period_end
+    );
+    let meta = [
+        (branding.label("period"), period.clone()),
+        (
+            branding.label("generated"),
+            statement.generated_utc.date_naive().to_string(),
+        ),
+        (branding.label("currency"), statement.currency.clone()),
+    ];
+
+    let values = template_values(
+        letterhead,
+        &statement.customer_name,
+        "",
+        Some(statement.period_end),
+        None,
+        &statement.closing_balance,
+        &statement.closing_balance,
+        &statement.currency,
+    );
+
+    let title = branding.label("statement_title");
+    let reference = format!("{} {}", title, period);
+    let mut canvas = Canvas::new(letterhead, &reference);
+    canvas.header(letterhead, title, &meta);
+    canvas.address_block(
+        branding.label("statement_for"),
+        &statement.customer_name,
+        &address_lines(
+            &statement.billing_line1,
+            &statement.billing_line2,
+            &statement.billing_city,
+            &statement.billing_state,
+            &statement.billing_postal_code,
+            &statement.billing_country,
+        ),
+    );
+
+    let currency = statement.currency.as_str();
+    let mut totals = vec![
+        TotalRow::new(
+            branding.label("opening_balance"),
+            format_money(&statement.opening_balance, currency),
+        ),
+        TotalRow::new(
+            branding.label("invoiced"),
+            format_money(&statement.total_debits, currency),
+        ),
+        TotalRow::new(
+            branding.label("payments_and_credits"),
+            format_money(&statement.total_credits, currency),
+        ),
+        TotalRow::bold(
+            branding.label("closing_balance"),
+            format_money(&statement.closing_balance, currency),
+        ),
+    ];
+    if statement.unapplied_cash > Decimal::ZERO {
+        totals.push(TotalRow::new(
+            branding.label("unapplied_cash"),
+            format_money(&statement.unapplied_cash, currency),
+        ));
+    }
+    canvas.totals(&totals);
+
+    let columns = [
+        Column::left(branding.label("date"), MARGIN, 55.0),
+        Column::left(branding.label("number"), 110.0, 90.0),
+        Column::left(branding.label("description"), 205.0, 140.0),
+        Column::right(branding.label("debit"), 410.0),
+        Column::right(branding.label("credit"), 478.0),
+        Column::right(branding.label("balance"), RIGHT),
+    ];
+    canvas.table_header(&columns);
+    canvas.table_row(
+        &columns,
+        &[
+            statement.period_start.to_string(),
+            String::new(),
+            branding.label("opening_balance").to_string(),
+            String::new(),
+            String::new(),
+            format_amount(&statement.opening_balance),
+        ],
+    );
+    for line in &statement.lines {
+        let amount = |value: &Decimal| {
+            if value.is_zero() {
+                String::new()
+            } else {
+                format_amount(value)
+            }
+        };
+        canvas.table_row(
+            &columns,
+            &[
+                line.date.to_string(),
+                line.document_number.clone(),
+                line.description.clone(),
+                amount(&line.debit),
+                amount(&line.credit),
+                format_amount(&line.balance),
+            ],
+        );
+    }
+
+    if !statement.tax_breakdown.is_empty() {
+        let columns = [
+            Column::left(branding.label("tax_summary"), MARGIN, 250.0),
+            Column::right(branding.label("taxable_amount"), 430.0),
+            Column::right(branding.label("tax"), RIGHT),
+        ];
+        canvas.table_header(&columns);
+        for row in &statement.tax_breakdown {
+            canvas.table_row(
+                &columns,
+                &[
+                    tax_label(row),
+                    format_amount(&row.taxable_amount),
+                    format_amount(&row.tax_amount),
+                ],
+            );
+        }
+    }
+
+    canvas.terms(letterhead, &values);
+
+    canvas.finish(letterhead, &reference, &values)
+}
+
+/// Values for the `{{variable}}` placeholders in footer and terms templates.
+#[allow(clippy::too_many_arguments)]
+fn template_values<'a>(
+    letterhead: &Letterhead,
+    customer_name: &str,
+    number: &str,
+    date: Option<NaiveDate>,
+    due_date: Option<NaiveDate>,
+    total: &Decimal,
+    amount_due: &Decimal,
+    currency: &str,
+) -> HashMap<&'a str, String> {
+    let mut values = HashMap::new();
+    if let Some(profile) = &letterhead.profile {
+        values.insert("tenant.legal_name", profile.legal_name.clone());
+        values.insert("tenant.tax_id", profile.tax_id.clone().unwrap_or_default());
+        values.insert("tenant.email", profile.email.clone().unwrap_or_default());
+        values.insert("tenant.phone", profile.phone.clone().unwrap_or_default());
+    }
+    values.insert("customer.name", customer_name.to_string());
+    values.insert("document.number", number.to_string());
+    values.insert(
+        "document.date",
+        date.map(|d| d.to_string()).unwrap_or_default(),
+    );
+    values.insert(
+        "document.due_date",
+        due_date.map(|d| d.to_string()).unwrap_or_default(),
+    );
+    values.insert("document.total", format_amount(total));
+    values.insert("document.amount_due", format_amount(amount_due));
+    values.insert("document.currency", currency.to_string());
+    values
+}
+
+/// A tax rate or component with its percentage, e.g. "CGST (9%)".
+fn tax_label(row: &TaxBreakdown) -> String {
+    let percent = (row.rate * Decimal::ONE_HUNDRED).normalize();
+    format!("{} ({}%)", row.name, percent)
+}
+
+fn address_lines(
+    line1: &Option<String>,
+    line2: &Option<String>,
+    city: &Option<String>,
+    state: &Option<String>,
+    postal_code: &Option<String>,
+    country: &Option<String>,
+) -> Vec<String> {
+    let present = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let locality: Vec<String> = [city, state, postal_code]
+        .into_iter()
+        .filter_map(present)
+        .collect();
+
+    let mut lines: Vec<String> = [line1, line2].into_iter().filter_map(present).collect();
+    if !locality.is_empty() {
+        lines.push(locality.join(", "));
+    }
+    lines.extend(present(country));
+    lines
+}
+
+/// Format an amount to two decimal places with thousands separators.
+fn format_amount(amount: &Decimal) -> String {
+    let rounded = amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+    let text = format!("{:.2}", rounded.abs());
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, "00"));
+
+    let mut grouped = String::new();
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if rounded.is_sign_negative() && !rounded.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    format!("{}{}.{}", sign, grouped, fraction)
+}
+
+fn format_money(amount: &Decimal, currency: &str) -> String {
+    format!("{} {}", currency, format_amount(amount))
+}
+
+fn format_quantity(quantity: &Decimal) -> String {
+    quantity.normalize().to_string()
+}
+
+/// "bank_transfer" -> "Bank transfer".
+fn humanize(value: &str) -> String {
+    let text = value.replace('_', " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => text,
+    }
+}
+
+fn short_id(id: Uuid) -> String {
+    id.simple().to_string()[..8].to_uppercase()
+}
+
+/// Break text into lines no wider than `width`, splitting words that are
+/// wider than a line on their own.
+fn wrap(text: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if font.text_width(&candidate, size) <= width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                line.push(c);
+                if font.text_width(&line, size) > width && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::take(&mut line));
+                    line.push(c);
+                }
+            }
+        }
+        lines.push(line);
+    }
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+enum Align {
+    Left,
+    Right,
+}
+
+/// Table column. Left-aligned columns start at `edge` and wrap at `width`;
+/// right-aligned columns end at `edge`.
+struct Column {
+    title: String,
+    edge: f32,
+    align: Align,
+    width: f32,
+}
+
+impl Column {
+    fn left(title: &str, edge: f32, width: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            edge,
+            align: Align::Left,
+            width,
+        }
+    }
+
+    fn right(title: &str, edge: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            edge,
+            align: Align::Right,
+            width: f32::MAX,
+        }
+    }
+}
+
+struct TotalRow {
+    label: String,
+    value: String,
+    font: Font,
+}
+
+impl TotalRow {
+    fn new(label: &str, value: String) -> Self {
+        Self {
+            label: label.to_string(),
+            value,
+            font: Font::Regular,
+        }
+    }
+
+    fn bold(label: &str, value: String) -> Self {
+        Self {
+            font: Font::Bold,
+            ..Self::new(label, value)
+        }
+    }
+}
+
+/// Lays content out top to bottom, starting new pages as they fill.
+struct Canvas {
+    document: PdfDocument,
+    logo: Option<ImageId>,
+    primary: Color,
+    accent: Color,
+    page: usize,
+    y: f32,
+    table: Option<Vec<(String, f32, bool)>>,
+}
+
+impl Canvas {
+    fn new(letterhead: &Letterhead, title: &str) -> Self {
+        let mut document = PdfDocument::new(title);
+        let logo = letterhead
+            .logo
+            .as_ref()
+            .map(|logo| document.add_image(logo.clone()));
+        let page = document.add_page();
+        Self {
+            document,
+            logo,
+            primary: letterhead.branding.primary,
+            accent: letterhead.branding.accent,
+            page,
+            y: TOP,
+            table: None,
+        }
+    }
+
+    fn header(&mut self, letterhead: &Letterhead, title: &str, meta: &[(&str, String)]) {
+        let mut left = TOP;
+        if let (Some(logo), Some(image)) = (self.logo, letterhead.logo.as_ref()) {
+            let ratio = image.aspect_ratio();
+            let (mut width, mut height) = (LOGO_MAX_HEIGHT * ratio, LOGO_MAX_HEIGHT);
+            if width > LOGO_MAX_WIDTH {
+                width = LOGO_MAX_WIDTH;
+                height = width / ratio;
+            }
+            self.document
+                .page(self.page)
+                .image(logo, MARGIN, TOP - height, width, height);
+            left -= height + 8.0;
+        }
+
+        if let Some(profile) = &letterhead.profile {
+            let page = self.document.page(self.page);
+            left -= 12.0;
+            page.text(
+                MARGIN,
+                left,
+                Font::Bold,
+                12.0,
+                self.primary,
+                &profile.legal_name,
+            );
+            let mut lines = address_lines(
+                &profile.address_line1,
+                &profile.address_line2,
+                &profile.address_city,
+                &profile.address_state,
+                &profile.address_postal_code,
+                &profile.address_country,
+            );
+            lines.extend(profile.email.clone());
+            lines.extend(profile.phone.clone());
+            lines.extend(
+                profile
+                    .tax_id
+                    .as_ref()
+                    .map(|id| format!("{}: {}", letterhead.branding.label("tax_id"), id)),
+            );
+            for line in lines {
+                left -= 12.0;
+                page.text(MARGIN, left, Font::Regular, 9.0, Color::GREY, &line);
+            }
+        }
+
+        let page = self.document.page(self.page);
+        let mut right = TOP - 20.0;
+        page.text_right(RIGHT, right, Font::Bold, 22.0, self.primary, title);
+        right -= 10.0;
+        for (label, value) in meta {
+            right -= 14.0;
+            page.text_right(RIGHT - 120.0, right, Font::Regular, 9.0, Color::GREY, label);
+            page.text_right(RIGHT, right, Font::Bold, 9.0, Color::BLACK, value);
+        }
+
+        self.y = left.min(right) - 30.0;
+    }
+
+    fn address_block(&mut self, label: &str, name: &str, lines: &[String]) {
+        self.ensure(40.0 + 12.0 * lines.len() as f32);
+        let page = self.document.page(self.page);
+        page.text(MARGIN, self.y, Font::Bold, 8.0, Color::GREY, label);
+        self.y -= 15.0;
+        page.text(MARGIN, self.y, Font::Bold, 11.0, Color::BLACK, name);
+        for line in lines {
+            self.y -= 13.0;
+            page.text(MARGIN, self.y, Font::Regular, 10.0, Color::BLACK, line);
+        }
+        self.y -= 30.0;
+    }
+
+    fn table_header(&mut self, columns: &[Column]) {
+        self.table = Some(
+            columns
+                .iter()
+                .map(|c| (c.title.clone(), c.edge, matches!(c.align, Align::Right)))
+                .collect(),
+        );
+        self.ensure(40.0);
+        self.draw_table_header();
+    }
+
+    fn draw_table_header(&mut self) {
+        let Some(columns) = &self.table else {
+            return;
+        };
+        let page = self.document.page(self.page);
+        page.rect(
+            MARGIN - 5.0,
+            self.y - 6.0,
+            RIGHT - MARGIN + 10.0,
+            20.0,
+            self.accent,
+        );
+        for (title, edge, right) in columns {
+            if *right {
+                page.text_right(*edge, self.y, Font::Bold, 9.0, self.primary, title);
+            } else {
+                page.text(*edge, self.y, Font::Bold, 9.0, self.primary, title);
+            }
+        }
+        self.y -= 22.0;
+    }
+
+    fn table_row(&mut self, columns: &[Column], cells: &[String]) {
+        let wrapped: Vec<Vec<String>> = columns
+            .iter()
+            .zip(cells)
+            .map(|(column, cell)| match column.align {
+                Align::Left => wrap(cell, Font::Regular, 9.5, column.width),
+                Align::Right => vec![cell.clone()],
+            })
+            .collect();
+        let height = wrapped.iter().map(Vec::len).max().unwrap_or(1) as f32 * 12.0;
+
+        if self.ensure(height + 8.0) {
+            self.draw_table_header();
+        }
+
+        let page = self.document.page(self.page);
+        for (column, lines) in columns.iter().zip(&wrapped) {
+            let mut y = self.y;
+            for line in lines {
+                match column.align {
+                    Align::Left => {
+                        page.text(column.edge, y, Font::Regular, 9.5, Color::BLACK, line)
+                    }
+                    Align::Right => {
+                        page.text_right(column.edge, y, Font::Regular, 9.5, Color::BLACK, line)
+                    }
+                }
+                y -= 12.0;
+            }
+        }
+        self.y -= height - 4.0;
+        page.line(
+            (MARGIN - 5.0, self.y),
+            (RIGHT + 5.0, self.y),
+            0.5,
+            Color::LIGHT_GREY,
+        );
+        self.y -= 14.0;
+    }
+
+    fn totals(&mut self, rows: &[TotalRow]) {
+        self.table = None;
+        self.y -= 6.0;
+        self.ensure(16.0 * rows.len() as f32);
+        let page = self.document.page(self.page);
+        for row in rows {
+            let color = match row.font {
+                Font::Bold => self.primary,
+                Font::Regular => Color::BLACK,
+            };
+            page.text_right(RIGHT - 110.0, self.y, row.font, 10.0, color, &row.label);
+            page.text_right(RIGHT, self.y, row.font, 10.0, color, &row.value);
+            self.y -= 16.0;
+        }
+        self.y -= 20.0;
+    }
+
+    fn notes(&mut self, heading: &str, notes: &str) {
+        self.table = None;
+        self.ensure(30.0);
+        self.document
+            .page(self.page)
+            .text(MARGIN, self.y, Font::Bold, 9.0, Color::GREY, heading);
+        self.y -= 14.0;
+        for line in wrap(notes, Font::Regular, 9.5, RIGHT - MARGIN) {
+            self.ensure(12.0);
+            self.document.page(self.page).text(
+                MARGIN,
+                self.y,
+                Font::Regular,
+                9.5,
+                Color::BLACK,
+                &line,
+            );
+            self.y -= 12.0;
+        }
+        self.y -= 14.0;
+    }
+
+    /// The branding's terms, if it has any.
+    fn terms(&mut self, letterhead: &Letterhead, values: &HashMap<&str, String>) {
+        let branding = &letterhead.branding;
+        if let Some(terms) = branding.terms(values).filter(|t| !t.trim().is_empty()) {
+            self.notes(branding.label("terms"), &terms);
+        }
+    }
+
+    /// Start a new page if fewer than `height` points are left on this one.
+    /// Returns whether it did.
+    fn ensure(&mut self, height: f32) -> bool {
+        if self.y - height >= BOTTOM {
+            return false;
+        }
+        self.page = self.document.add_page();
+        self.y = TOP;
+        true
+    }
+
+    /// Add page footers and serialize the document. The branding's footer
+    /// replaces the default issuer and reference line.
+    fn finish(
+        mut self,
+        letterhead: &Letterhead,
+        reference: &str,
+        values: &HashMap<&str, String>,
+    ) -> Vec<u8> {
+        let branding = &letterhead.branding;
+        let footer = branding.footer(values).unwrap_or_else(|| {
+            letterhead
+                .profile
+                .as_ref()
+                .map(|p| format!("{} - {}", p.legal_name, reference))
+                .unwrap_or_else(|| reference.to_string())
+        });
+        let footer_lines = wrap(&footer, Font::Regular, 8.0, RIGHT - MARGIN - 70.0);
+        let count = self.document.page_count();
+        for index in 0..count {
+            let page = self.document.page(index);
+            page.line((MARGIN, 55.0), (RIGHT, 55.0), 0.5, self.accent);
+            for (line, text) in footer_lines.iter().take(3).enumerate() {
+                let y = 42.0 - 10.0 * line as f32;
+                page.text(MARGIN, y, Font::Regular, 8.0, Color::GREY, text);
+            }
+            page.text_right(
+                RIGHT,
+                42.0,
+                Font::Regular,
+                8.0,
+                Color::GREY,
+                &format!(
+                    "{} {} {} {}",
+                    branding.label("page"),
+                    index + 1,
+                    branding.label("page_of"),
+                    count
+                ),
+            );
+        }
+        self.document.finish()
+    }
+}
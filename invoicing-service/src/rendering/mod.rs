@@ -0,0 +1,13 @@
+//! PDF rendering of invoices, receipts and statements.
+
+mod branding;
+mod layout;
+pub mod pdf;
+mod sample;
+
+pub use branding::{Branding, BrandingError, TextTemplate, DEFAULT_LABELS, TEMPLATE_VARIABLES};
+pub use layout::{
+    invoice_filename, receipt_filename, render_invoice, render_receipt, render_statement,
+    statement_filename, Letterhead,
+};
+pub use sample::{render_sample_invoice, render_sample_receipt, render_sample_statement};
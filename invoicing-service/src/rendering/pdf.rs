@@ -0,0 +1,474 @@
+//! Minimal PDF writer.
+//!
+//! Writes PDF 1.4 files using the standard Helvetica fonts, which every
+//! reader provides, so no font files are embedded. Text is WinAnsi encoded;
+//! characters outside it are written as `?`. Content streams and images are
+//! Flate compressed.
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+/// A4 page width in points.
+pub const PAGE_WIDTH: f32 = 595.0;
+
+/// A4 page height in points.
+pub const PAGE_HEIGHT: f32 = 842.0;
+
+/// Helvetica glyph widths for characters 32..=126, in 1/1000 of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Helvetica-Bold glyph widths for characters 32..=126.
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Standard font used for text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+        }
+    }
+
+    /// Width of `text` in points when set at `size`.
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        let widths = match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        let units: u32 = text
+            .chars()
+            .map(|c| match c as u32 {
+                code @ 32..=126 => widths[(code - 32) as usize] as u32,
+                _ => 556,
+            })
+            .sum();
+        units as f32 * size / 1000.0
+    }
+}
+
+/// RGB colour with components from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color(pub f32, pub f32, pub f32);
+
+impl Color {
+    pub const BLACK: Color = Color(0.0, 0.0, 0.0);
+    pub const GREY: Color = Color(0.45, 0.45, 0.45);
+    pub const LIGHT_GREY: Color = Color(0.93, 0.93, 0.93);
+
+    /// Parse a `#RRGGBB` hex colour.
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let digits = hex.strip_prefix('#')?;
+        if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component =
+            |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).map(|v| v as f32 / 255.0);
+        Some(Color(
+            component(0).ok()?,
+            component(2).ok()?,
+            component(4).ok()?,
+        ))
+    }
+}
+
+/// Largest encoded image accepted for embedding, in bytes.
+pub const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Largest image width or height accepted for embedding, in pixels.
+pub const MAX_IMAGE_DIMENSION: u32 = 2048;
+
+/// Raster image decoded for embedding.
+#[derive(Clone)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    rgb: Vec<u8>,
+    alpha: Option<Vec<u8>>,
+}
+
+impl Image {
+    /// Decode a PNG or JPEG image. Images are tenant uploads, so oversized files
+    /// and dimensions beyond `MAX_IMAGE_DIMENSION` are rejected before any pixels
+    /// are decoded.
+    pub fn decode(data: &[u8]) -> Result<Self, image::ImageError> {
+        if data.len() > MAX_IMAGE_BYTES {
+            return Err(image::ImageError::Limits(
+                image::error::LimitError::from_kind(
+                    image::error::LimitErrorKind::InsufficientMemory,
+                ),
+            ));
+        }
+
+        let mut limits = image::io::Limits::default();
+        limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+        limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+
+        let mut reader = image::io::Reader::new(std::io::Cursor::new(data))
+            .with_guessed_format()
+            .map_err(image::ImageError::IoError)?;
+        reader.limits(limits);
+        let rgba = reader.decode()?.to_rgba8();
+        let (width, height) = rgba.dimensions();
+
+        let mut rgb = Vec::with_capacity((width * height * 3) as usize);
+        let mut alpha = Vec::with_capacity((width * height) as usize);
+        for pixel in rgba.pixels() {
+            rgb.extend_from_slice(&pixel.0[..3]);
+            alpha.push(pixel.0[3]);
+        }
+        let opaque = alpha.iter().all(|a| *a == u8::MAX);
+
+        Ok(Self {
+            width,
+            height,
+            rgb,
+            alpha: if opaque { None } else { Some(alpha) },
+        })
+    }
+
+    /// Width divided by height.
+    pub fn aspect_ratio(&self) -> f32 {
+        self.width as f32 / self.height.max(1) as f32
+    }
+}
+
+/// Handle to an image added to a document.
+#[derive(Debug, Clone, Copy)]
+pub struct ImageId(usize);
+
+/// One page's content stream.
+#[derive(Default)]
+pub struct Page {
+    content: Vec<u8>,
+}
+
+impl Page {
+    /// Draw text with its baseline starting at (x, y).
+    pub fn text(&mut self, x: f32, y: f32, font: Font, size: f32, color: Color, text: &str) {
+        self.set_fill(color);
+        let _ = write!(
+            self.content,
+            "BT /{} {:.1} Tf {:.2} {:.2} Td (",
+            font.resource_name(),
+            size,
+            x,
+            y
+        );
+        self.content.extend(encode_text(text));
+        self.content.extend_from_slice(b") Tj ET\n");
+    }
+
+    /// Draw text with its baseline ending at (right, y).
+    pub fn text_right(
+        &mut self,
+        right: f32,
+        y: f32,
+        font: Font,
+        size: f32,
+        color: Color,
+        text: &str,
+    ) {
+        let x = right - font.text_width(text, size);
+        self.text(x, y, font, size, color, text);
+    }
+
+    /// Draw a straight line.
+    pub fn line(&mut self, from: (f32, f32), to: (f32, f32), width: f32, color: Color) {
+        let _ = writeln!(
+            self.content,
+            "{:.3} {:.3} {:.3} RG {:.2} w {:.2} {:.2} m {:.2} {:.2} l S",
+            color.0, color.1, color.2, width, from.0, from.1, to.0, to.1
+        );
+    }
+
+    /// Fill a rectangle whose bottom-left corner is (x, y).
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        self.set_fill(color);
+        let _ = writeln!(
+            self.content,
+            "{:.2} {:.2} {:.2} {:.2} re f",
+            x, y, width, height
+        );
+    }
+
+    /// Draw an image into the box whose bottom-left corner is (x, y).
+    pub fn image(&mut self, image: ImageId, x: f32, y: f32, width: f32, height: f32) {
+        let _ = writeln!(
+            self.content,
+            "q {:.2} 0 0 {:.2} {:.2} {:.2} cm /Im{} Do Q",
+            width, height, x, y, image.0
+        );
+    }
+
+    fn set_fill(&mut self, color: Color) {
+        let _ = write!(
+            self.content,
+            "{:.3} {:.3} {:.3} rg ",
+            color.0, color.1, color.2
+        );
+    }
+}
+
+/// A PDF document under construction.
+pub struct PdfDocument {
+    title: String,
+    pages: Vec<Page>,
+    images: Vec<Image>,
+}
+
+impl PdfDocument {
+    /// Start an empty document with the given title.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            pages: Vec::new(),
+            images: Vec::new(),
+        }
+    }
+
+    /// Add an image that any page can draw.
+    pub fn add_image(&mut self, image: Image) -> ImageId {
+        self.images.push(image);
+        ImageId(self.images.len() - 1)
+    }
+
+    /// Append a blank page and return its index.
+    pub fn add_page(&mut self) -> usize {
+        self.pages.push(Page::default());
+        self.pages.len() - 1
+    }
+
+    /// Number of pages so far.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Page at `index`.
+    pub fn page(&mut self, index: usize) -> &mut Page {
+        &mut self.pages[index]
+    }
+
+    /// Serialize the document.
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.pages.is_empty() {
+            self.add_page();
+        }
+
+        let mut writer = ObjectWriter::new();
+
+        // Fixed object numbers: 1 catalog, 2 page tree, 3 resources, 4-5 fonts, 6 info
+        let first_image = 7;
+        let mut next = first_image;
+        let mut image_objects = Vec::new();
+        for image in &self.images {
+            let smask = image.alpha.as_ref().map(|_| next + 1);
+            image_objects.push((next, smask));
+            next += if smask.is_some() { 2 } else { 1 };
+        }
+        let first_page = next;
+
+        writer.object(1, "<< /Type /Catalog /Pages 2 0 R >>");
+
+        let kids: Vec<String> = (0..self.pages.len())
+            .map(|i| format!("{} 0 R", first_page + 2 * i))
+            .collect();
+        writer.object(
+            2,
+            &format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                self.pages.len()
+            ),
+        );
+
+        let xobjects: String = image_objects
+            .iter()
+            .enumerate()
+            .map(|(i, (object, _))| format!("/Im{} {} 0 R ", i, object))
+            .collect();
+        writer.object(
+            3,
+            &format!(
+                "<< /Font << /F1 4 0 R /F2 5 0 R >> /XObject << {}>> >>",
+                xobjects
+            ),
+        );
+
+        for (number, font) in [(4, Font::Regular), (5, Font::Bold)] {
+            writer.object(
+                number,
+                &format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font.base_font()
+                ),
+            );
+        }
+
+        let mut title = Vec::new();
+        title.extend(encode_text(&self.title));
+        let mut info = b"<< /Producer (invoicing-service) /Title (".to_vec();
+        info.extend(title);
+        info.extend_from_slice(b") >>");
+        writer.raw_object(6, &info);
+
+        for (image, (object, smask)) in self.images.iter().zip(&image_objects) {
+            let smask_ref = smask
+                .map(|n| format!(" /SMask {} 0 R", n))
+                .unwrap_or_default();
+            writer.stream(
+                *object,
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceRGB /BitsPerComponent 8{}",
+                    image.width, image.height, smask_ref
+                ),
+                &image.rgb,
+            );
+            if let (Some(number), Some(alpha)) = (smask, &image.alpha) {
+                writer.stream(
+                    *number,
+                    &format!(
+                        "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceGray /BitsPerComponent 8",
+                        image.width, image.height
+                    ),
+                    alpha,
+                );
+            }
+        }
+
+        for (i, page) in self.pages.iter().enumerate() {
+            let number = first_page + 2 * i;
+            writer.object(
+                number,
+                &format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources 3 0 R /Contents {} 0 R >>",
+                    PAGE_WIDTH,
+                    PAGE_HEIGHT,
+                    number + 1
+                ),
+            );
+            writer.stream(number + 1, "", &page.content);
+        }
+
+        writer.finish(1, 6)
+    }
+}
+
+/// Serializes numbered objects and the cross-reference table.
+struct ObjectWriter {
+    buffer: Vec<u8>,
+    offsets: Vec<(usize, usize)>,
+}
+
+impl ObjectWriter {
+    fn new() -> Self {
+        Self {
+            // Binary comment marks the file as binary for transfer tools
+            buffer: b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec(),
+            offsets: Vec::new(),
+        }
+    }
+
+    fn object(&mut self, number: usize, body: &str) {
+        self.raw_object(number, body.as_bytes());
+    }
+
+    fn raw_object(&mut self, number: usize, body: &[u8]) {
+        self.offsets.push((number, self.buffer.len()));
+        self.buffer
+            .extend_from_slice(format!("{} 0 obj\n", number).as_bytes());
+        self.buffer.extend_from_slice(body);
+        self.buffer.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, number: usize, dictionary: &str, data: &[u8]) {
+        let compressed = compress(data);
+        let mut body = format!(
+            "<< {} /Filter /FlateDecode /Length {} >>\nstream\n",
+            dictionary,
+            compressed.len()
+        )
+        .into_bytes();
+        body.extend(compressed);
+        body.extend_from_slice(b"\nendstream");
+        self.raw_object(number, &body);
+    }
+
+    fn finish(mut self, root: usize, info: usize) -> Vec<u8> {
+        self.offsets.sort_unstable();
+        let size = self.offsets.len() + 1;
+        let xref = self.buffer.len();
+
+        let mut table = format!("xref\n0 {}\n0000000000 65535 f \n", size);
+        for (_, offset) in &self.offsets {
+            let _ = writeln!(table, "{:010} 00000 n ", offset);
+        }
+        let _ = write!(
+            table,
+            "trailer\n<< /Size {} /Root {} 0 R /Info {} 0 R >>\nstartxref\n{}\n%%EOF\n",
+            size, root, info, xref
+        );
+        self.buffer.extend_from_slice(table.as_bytes());
+        self.buffer
+    }
+}
+
+fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Writing to a Vec cannot fail
+    let _ = encoder.write_all(data);
+    encoder.finish().unwrap_or_default()
+}
+
+/// Encode text as a WinAnsi literal string body, escaping delimiters.
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        let byte = match c {
+            '(' | ')' | '\\' => {
+                bytes.push(b'\\');
+                c as u8
+            }
+            ' '..='~' => c as u8,
+            '\u{a0}'..='\u{ff}' => c as u32 as u8,
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            _ => b'?',
+        };
+        bytes.push(byte);
+    }
+    bytes
+}
@@ -0,0 +1,191 @@
+//! Sample documents for previewing a template.
+
+use crate::models::{
+    Invoice, LineItem, LineItemTax, LineItemType, Receipt, Statement, StatementLine, TaxBreakdown,
+};
+use crate::rendering::layout::{render_invoice, render_receipt, render_statement, Letterhead};
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Render an invoice with sample line items and tax.
+pub fn render_sample_invoice(letterhead: &Letterhead, tenant_id: Uuid) -> Vec<u8> {
+    let invoice = sample_invoice(tenant_id);
+    let tax_rate_id = Uuid::new_v4();
+    let line_items = [
+        (
+            "Professional services",
+            Decimal::new(8, 0),
+            Decimal::new(12500, 2),
+        ),
+        (
+            "Software subscription",
+            Decimal::ONE,
+            Decimal::new(25000, 2),
+        ),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, (description, quantity, unit_price))| {
+        let subtotal = quantity * unit_price;
+        let tax = sample_tax(subtotal);
+        let tax_amount = tax.tax_amount;
+        LineItem {
+            line_item_id: Uuid::new_v4(),
+            invoice_id: invoice.invoice_id,
+            tenant_id,
+            description: description.to_string(),
+            quantity,
+            unit_price,
+            tax_rate_id: Some(tax_rate_id),
+            tax_group_id: None,
+            tax_amount,
+            subtotal,
+            total: subtotal + tax_amount,
+            ledger_account_id: None,
+            reference_line_item_id: None,
+            sort_order: index as i32,
+            line_type: LineItemType::Charge.as_str().to_string(),
+            created_utc: invoice.created_utc,
+            taxes: vec![tax],
+        }
+    })
+    .collect::<Vec<_>>();
+
+    render_invoice(letterhead, &invoice, &line_items)
+}
+
+/// Render a receipt for a sample part payment.
+pub fn render_sample_receipt(letterhead: &Letterhead, tenant_id: Uuid) -> Vec<u8> {
+    let mut invoice = sample_invoice(tenant_id);
+    let amount = Decimal::new(50000, 2);
+    invoice.amount_paid = amount;
+    invoice.amount_due -= amount;
+    let receipt = Receipt {
+        receipt_id: Uuid::new_v4(),
+        tenant_id,
+        receipt_number: "RCP-SAMPLE-0001".to_string(),
+        invoice_id: invoice.invoice_id,
+        customer_id: invoice.customer_id,
+        amount,
+        currency: invoice.currency.clone(),
+        payment_method: "bank_transfer".to_string(),
+        payment_reference: Some("TRX-000123".to_string()),
+        payment_date: Utc::now().date_naive(),
+        journal_id: None,
+        notes: None,
+        payment_id: None,
+        created_utc: Utc::now(),
+    };
+
+    render_receipt(letterhead, &receipt, &invoice, None)
+}
+
+/// Render a statement with a sample invoice and payment.
+pub fn render_sample_statement(letterhead: &Letterhead, tenant_id: Uuid) -> Vec<u8> {
+    let invoice = sample_invoice(tenant_id);
+    let today = Utc::now().date_naive();
+    let opening_balance = Decimal::new(20000, 2);
+    let payment = Decimal::new(50000, 2);
+    let after_invoice = opening_balance + invoice.total;
+    let statement = Statement {
+        tenant_id,
+        customer_id: invoice.customer_id,
+        customer_name: invoice.customer_name.clone(),
+        billing_line1: invoice.billing_line1.clone(),
+        billing_line2: None,
+        billing_city: invoice.billing_city.clone(),
+        billing_state: invoice.billing_state.clone(),
+        billing_postal_code: invoice.billing_postal_code.clone(),
+        billing_country: invoice.billing_country.clone(),
+        currency: invoice.currency.clone(),
+        period_start: today - Duration::days(30),
+        period_end: today,
+        opening_balance,
+        closing_balance: after_invoice - payment,
+        total_debits: invoice.total,
+        total_credits: payment,
+        unapplied_cash: Decimal::ZERO,
+        lines: vec![
+            StatementLine {
+                date: today - Duration::days(20),
+                document_type: "invoice".to_string(),
+                document_number: "INV-SAMPLE-0001".to_string(),
+                description: "Invoice INV-SAMPLE-0001".to_string(),
+                debit: invoice.total,
+                credit: Decimal::ZERO,
+                balance: after_invoice,
+            },
+            StatementLine {
+                date: today - Duration::days(5),
+                document_type: "payment".to_string(),
+                document_number: "RCP-SAMPLE-0001".to_string(),
+                description: "Payment - bank_transfer".to_string(),
+                debit: Decimal::ZERO,
+                credit: payment,
+                balance: after_invoice - payment,
+            },
+        ],
+        tax_breakdown: vec![TaxBreakdown {
+            name: "Sales tax".to_string(),
+            kind: "standard".to_string(),
+            rate: Decimal::new(10, 2),
+            taxable_amount: invoice.subtotal,
+            tax_amount: invoice.tax_total,
+        }],
+        generated_utc: Utc::now(),
+    };
+
+    render_statement(letterhead, &statement)
+}
+
+/// 10% sales tax on a sample subtotal.
+fn sample_tax(subtotal: Decimal) -> LineItemTax {
+    let rate = Decimal::new(10, 2);
+    LineItemTax {
+        name: "Sales tax".to_string(),
+        kind: "standard".to_string(),
+        rate,
+        taxable_amount: subtotal,
+        tax_amount: subtotal * rate,
+    }
+}
+
+/// An issued invoice for 1,250.00 plus 10% tax to a sample customer.
+fn sample_invoice(tenant_id: Uuid) -> Invoice {
+    let now = Utc::now();
+    let today = now.date_naive();
+    let subtotal = Decimal::new(125000, 2);
+    let tax_total = Decimal::new(12500, 2);
+    Invoice {
+        invoice_id: Uuid::new_v4(),
+        tenant_id,
+        invoice_number: Some("INV-SAMPLE-0001".to_string()),
+        invoice_type: "standard".to_string(),
+        status: "issued".to_string(),
+        customer_id: Uuid::nil(),
+        customer_name: "Sample Customer Ltd".to_string(),
+        billing_line1: Some("1 Example Street".to_string()),
+        billing_line2: None,
+        billing_city: Some("Springfield".to_string()),
+        billing_state: Some("State".to_string()),
+        billing_postal_code: Some("00000".to_string()),
+        billing_country: Some("US".to_string()),
+        currency: "USD".to_string(),
+        issue_date: Some(today),
+        due_date: Some(today + Duration::days(30)),
+        subtotal,
+        tax_total,
+        total: subtotal + tax_total,
+        amount_paid: Decimal::ZERO,
+        amount_due: subtotal + tax_total,
+        amount_credited: Decimal::ZERO,
+        notes: None,
+        reference_invoice_id: None,
+        journal_id: None,
+        metadata: None,
+        created_utc: now,
+        issued_utc: Some(now),
+        voided_utc: None,
+    }
+}
@@ -1,11 +1,14 @@
 //! Database service for invoicing-service.
 
 use crate::models::{
-    CreateInvoice, CreateLineItem, CreateReceipt, CreateTaxRate, Invoice, LineItem,
-    ListInvoicesFilter, ListReceiptsFilter, Receipt, TaxRate, UpdateInvoice, UpdateLineItem,
-    UpdateTaxRate,
+    CreateCreditNote, CreateCustomerPayment, CreateInvoice, CreateInvoiceTemplate, CreateLineItem,
+    CreateReceipt, CreateTaxGroup, CreateTaxRate, CustomerPayment, Invoice, InvoiceTemplate,
+    LineItem, LineItemTax, ListCustomerPaymentsFilter, ListInvoicesFilter, ListReceiptsFilter,
+    PaymentAllocation, Receipt, SetTenantProfile, TaxBreakdown, TaxComponent, TaxGroup, TaxRate,
+    TenantProfile, UpdateInvoice, UpdateLineItem, UpdateTaxRate,
 };
 use crate::services::metrics::DB_QUERY_DURATION;
+use crate::services::tax;
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use service_core::error::AppError;
@@ -14,6 +17,14 @@ use std::time::Duration;
 use tracing::{info, instrument};
 use uuid::Uuid;
 
+/// A stored line item tax with the line it belongs to.
+#[derive(sqlx::FromRow)]
+struct LineItemTaxRow {
+    line_item_id: Uuid,
+    #[sqlx(flatten)]
+    tax: LineItemTax,
+}
+
 /// Database connection pool wrapper.
 #[derive(Clone)]
 pub struct Database {
@@ -257,6 +268,188 @@ impl Database {
         Ok(tax_rate)
     }
 
+    // -------------------------------------------------------------------------
+    // Tax Group Operations
+    // -------------------------------------------------------------------------
+
+    /// Create a tax group with its components.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn create_tax_group(&self, input: &CreateTaxGroup) -> Result<TaxGroup, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_tax_group"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let mut tax_group = sqlx::query_as::<_, TaxGroup>(
+            r#"
+            INSERT INTO tax_groups (tax_group_id, tenant_id, name, active)
+            VALUES ($1, $2, $3, TRUE)
+            RETURNING tax_group_id, tenant_id, name, active, created_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(&input.name)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(anyhow::anyhow!("Tax group '{}' already exists", input.name))
+            }
+            _ => AppError::DatabaseError(anyhow::anyhow!("Failed to create tax group: {}", e)),
+        })?;
+
+        for (sort_order, component) in input.components.iter().enumerate() {
+            let component = sqlx::query_as::<_, TaxComponent>(
+                r#"
+                INSERT INTO tax_group_components (tax_group_id, name, rate, kind, applies_to, sort_order)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING tax_group_id, name, rate, kind, applies_to, sort_order
+                "#,
+            )
+            .bind(tax_group.tax_group_id)
+            .bind(&component.name)
+            .bind(component.rate)
+            .bind(component.kind.as_str())
+            .bind(component.applies_to.map_or("any", |p| p.as_str()))
+            .bind(sort_order as i32)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                    AppError::Conflict(anyhow::anyhow!(
+                        "Tax group '{}' has more than one component named '{}'",
+                        input.name,
+                        component.name
+                    ))
+                }
+                _ => AppError::DatabaseError(anyhow::anyhow!(
+                    "Failed to create tax group component: {}",
+                    e
+                )),
+            })?;
+            tax_group.components.push(component);
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(tax_group_id = %tax_group.tax_group_id, name = %tax_group.name, "Tax group created");
+
+        Ok(tax_group)
+    }
+
+    /// Get a tax group and its components by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, tax_group_id = %tax_group_id))]
+    pub async fn get_tax_group(
+        &self,
+        tenant_id: Uuid,
+        tax_group_id: Uuid,
+    ) -> Result<Option<TaxGroup>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_tax_group"])
+            .start_timer();
+
+        let tax_group = sqlx::query_as::<_, TaxGroup>(
+            r#"
+            SELECT tax_group_id, tenant_id, name, active, created_utc
+            FROM tax_groups
+            WHERE tenant_id = $1 AND tax_group_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(tax_group_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get tax group: {}", e)))?;
+
+        timer.observe_duration();
+
+        match tax_group {
+            Some(group) => Ok(self.with_components(vec![group]).await?.pop()),
+            None => Ok(None),
+        }
+    }
+
+    /// List tax groups for a tenant.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn list_tax_groups(
+        &self,
+        tenant_id: Uuid,
+        active_only: bool,
+        page_size: i32,
+        page_token: Option<Uuid>,
+    ) -> Result<Vec<TaxGroup>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_tax_groups"])
+            .start_timer();
+
+        let limit = page_size.clamp(1, 100) as i64;
+
+        let tax_groups = sqlx::query_as::<_, TaxGroup>(
+            r#"
+            SELECT tax_group_id, tenant_id, name, active, created_utc
+            FROM tax_groups
+            WHERE tenant_id = $1
+              AND ($2::bool = FALSE OR active = TRUE)
+              AND ($3::uuid IS NULL OR tax_group_id > $3)
+            ORDER BY tax_group_id
+            LIMIT $4
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(active_only)
+        .bind(page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list tax groups: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        self.with_components(tax_groups).await
+    }
+
+    /// Load the components of the given tax groups.
+    async fn with_components(
+        &self,
+        mut tax_groups: Vec<TaxGroup>,
+    ) -> Result<Vec<TaxGroup>, AppError> {
+        let ids: Vec<Uuid> = tax_groups.iter().map(|g| g.tax_group_id).collect();
+        let components = sqlx::query_as::<_, TaxComponent>(
+            r#"
+            SELECT tax_group_id, name, rate, kind, applies_to, sort_order
+            FROM tax_group_components
+            WHERE tax_group_id = ANY($1)
+            ORDER BY tax_group_id, sort_order
+            "#,
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get tax group components: {}", e))
+        })?;
+
+        for group in &mut tax_groups {
+            group.components = components
+                .iter()
+                .filter(|c| c.tax_group_id == group.tax_group_id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(tax_groups)
+    }
+
     // -------------------------------------------------------------------------
     // Invoice Operations
     // -------------------------------------------------------------------------
@@ -279,7 +472,7 @@ impl Database {
             VALUES ($1, $2, $3, 'draft', $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             "#,
         )
@@ -325,7 +518,7 @@ impl Database {
             r#"
             SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             FROM invoices
             WHERE tenant_id = $1 AND invoice_id = $2
@@ -361,7 +554,7 @@ impl Database {
                 r#"
                 SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                     billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                    currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                    currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                     notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
                 FROM invoices
                 WHERE tenant_id = $1
@@ -388,7 +581,7 @@ impl Database {
                 r#"
                 SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                     billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                    currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                    currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                     notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
                 FROM invoices
                 WHERE tenant_id = $1
@@ -431,8 +624,8 @@ impl Database {
 
         // First check if invoice is in draft status
         let existing = self.get_invoice(tenant_id, invoice_id).await?;
-        match existing {
-            Some(inv) if inv.status == "draft" => {}
+        let existing = match existing {
+            Some(inv) if inv.status == "draft" => inv,
             Some(_) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
                     "Only draft invoices can be issued"
@@ -441,6 +634,15 @@ impl Database {
             None => return Ok(None),
         };
 
+        // The tenant's registered address may have changed since drafting
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+        self.refresh_group_taxes(&mut tx, &existing).await?;
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         // Check if invoice has line items
         let line_items = self.get_line_items(tenant_id, invoice_id).await?;
         if line_items.is_empty() {
@@ -462,7 +664,7 @@ impl Database {
             WHERE tenant_id = $1 AND invoice_id = $2 AND status = 'draft'
             RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             "#,
         )
@@ -501,6 +703,16 @@ impl Database {
         // Check if invoice is in issued status
         let existing = self.get_invoice(tenant_id, invoice_id).await?;
         match existing {
+            Some(inv) if inv.invoice_type == "credit_note" => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Credit notes cannot be voided"
+                )))
+            }
+            Some(inv) if inv.amount_credited > Decimal::ZERO => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Invoices with credit notes cannot be voided"
+                )))
+            }
             Some(inv) if inv.status == "issued" => {}
             Some(_) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
@@ -518,7 +730,7 @@ impl Database {
             WHERE tenant_id = $1 AND invoice_id = $2 AND status = 'issued'
             RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             "#,
         )
@@ -594,6 +806,11 @@ impl Database {
             None => return Ok(None),
         };
 
+        // The address and the taxes that follow from it change together
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
         let invoice = sqlx::query_as::<_, Invoice>(
             r#"
             UPDATE invoices
@@ -610,7 +827,7 @@ impl Database {
             WHERE tenant_id = $1 AND invoice_id = $2 AND status = 'draft'
             RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             "#,
         )
@@ -626,17 +843,30 @@ impl Database {
         .bind(input.due_date)
         .bind(&input.notes)
         .bind(&input.metadata)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to update invoice: {}", e)))?;
 
+        // A new billing address can change the place of supply
+        let address_changed = input.billing_state.is_some() || input.billing_country.is_some();
+        if let Some(inv) = invoice.as_ref().filter(|_| address_changed) {
+            self.refresh_group_taxes(&mut tx, inv).await?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
         if let Some(ref inv) = invoice {
             info!(invoice_id = %inv.invoice_id, "Invoice updated");
         }
 
-        Ok(invoice)
+        match invoice {
+            Some(_) if address_changed => self.get_invoice(tenant_id, invoice_id).await,
+            other => Ok(other),
+        }
     }
 
     // -------------------------------------------------------------------------
@@ -652,8 +882,8 @@ impl Database {
 
         // Verify invoice is in draft status
         let invoice = self.get_invoice(input.tenant_id, input.invoice_id).await?;
-        match invoice {
-            Some(inv) if inv.status == "draft" => {}
+        let invoice = match invoice {
+            Some(inv) if inv.status == "draft" => inv,
             Some(_) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
                     "Can only add line items to draft invoices"
@@ -666,32 +896,28 @@ impl Database {
 
         // Calculate amounts
         let subtotal = input.quantity * input.unit_price;
-        let tax_amount = if let Some(tax_rate_id) = input.tax_rate_id {
-            let tax_rate = self.get_tax_rate(input.tenant_id, tax_rate_id).await?;
-            if let Some(rate) = tax_rate {
-                if rate.calculation == "inclusive" {
-                    subtotal - (subtotal / (Decimal::ONE + rate.rate))
-                } else {
-                    subtotal * rate.rate
-                }
-            } else {
-                Decimal::ZERO
-            }
-        } else {
-            Decimal::ZERO
-        };
+        let taxes = self
+            .calculate_line_taxes(&invoice, input.tax_rate_id, input.tax_group_id, subtotal)
+            .await?;
+        let tax_amount: Decimal = taxes.iter().map(|t| t.tax_amount).sum();
         let total = subtotal + tax_amount;
 
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
         let line_item_id = Uuid::new_v4();
-        let line_item = sqlx::query_as::<_, LineItem>(
+        let mut line_item = sqlx::query_as::<_, LineItem>(
             r#"
             INSERT INTO line_items (
                 line_item_id, invoice_id, tenant_id, description, quantity, unit_price,
-                tax_rate_id, tax_amount, subtotal, total, ledger_account_id, sort_order
+                tax_rate_id, tax_group_id, tax_amount, subtotal, total, ledger_account_id, sort_order,
+                line_type
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             RETURNING line_item_id, invoice_id, tenant_id, description, quantity, unit_price,
-                tax_rate_id, tax_amount, subtotal, total, ledger_account_id, sort_order, created_utc
+                tax_rate_id, tax_group_id, tax_amount, subtotal, total, ledger_account_id, reference_line_item_id,
+                sort_order, line_type, created_utc
             "#,
         )
         .bind(line_item_id)
@@ -701,23 +927,39 @@ impl Database {
         .bind(input.quantity)
         .bind(input.unit_price)
         .bind(input.tax_rate_id)
+        .bind(input.tax_group_id)
         .bind(tax_amount)
         .bind(subtotal)
         .bind(total)
         .bind(input.ledger_account_id)
         .bind(input.sort_order)
-        .fetch_one(&self.pool)
+        .bind(input.line_type.as_str())
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to add line item: {}", e)))?;
 
+        Self::replace_line_taxes(
+            &mut tx,
+            line_item.tenant_id,
+            line_item.invoice_id,
+            line_item.line_item_id,
+            &taxes,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
         info!(line_item_id = %line_item.line_item_id, "Line item added");
 
+        line_item.taxes = taxes;
         Ok(line_item)
     }
 
-    /// Get line items for an invoice.
+    /// Get line items for an invoice, with their taxes.
     #[instrument(skip(self), fields(tenant_id = %tenant_id, invoice_id = %invoice_id))]
     pub async fn get_line_items(
         &self,
@@ -728,10 +970,11 @@ impl Database {
             .with_label_values(&["get_line_items"])
             .start_timer();
 
-        let line_items = sqlx::query_as::<_, LineItem>(
+        let mut line_items = sqlx::query_as::<_, LineItem>(
             r#"
             SELECT line_item_id, invoice_id, tenant_id, description, quantity, unit_price,
-                tax_rate_id, tax_amount, subtotal, total, ledger_account_id, sort_order, created_utc
+                tax_rate_id, tax_group_id, tax_amount, subtotal, total, ledger_account_id, reference_line_item_id,
+                sort_order, line_type, created_utc
             FROM line_items
             WHERE tenant_id = $1 AND invoice_id = $2
             ORDER BY sort_order, created_utc
@@ -743,8 +986,32 @@ impl Database {
         .await
         .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get line items: {}", e)))?;
 
+        let taxes = sqlx::query_as::<_, LineItemTaxRow>(
+            r#"
+            SELECT line_item_id, name, kind, rate, taxable_amount, tax_amount
+            FROM line_item_taxes
+            WHERE tenant_id = $1 AND invoice_id = $2
+            ORDER BY line_item_id, sort_order
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(invoice_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get line item taxes: {}", e))
+        })?;
+
         timer.observe_duration();
 
+        for item in &mut line_items {
+            item.taxes = taxes
+                .iter()
+                .filter(|row| row.line_item_id == item.line_item_id)
+                .map(|row| row.tax.clone())
+                .collect();
+        }
+
         Ok(line_items)
     }
 
@@ -763,8 +1030,8 @@ impl Database {
 
         // Verify invoice is in draft status
         let invoice = self.get_invoice(tenant_id, invoice_id).await?;
-        match invoice {
-            Some(inv) if inv.status == "draft" => {}
+        let invoice = match invoice {
+            Some(inv) if inv.status == "draft" => inv,
             Some(_) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
                     "Can only update line items on draft invoices"
@@ -778,22 +1045,16 @@ impl Database {
         let unit_price = input.unit_price.unwrap_or(Decimal::ZERO);
         let subtotal = quantity * unit_price;
 
-        let tax_amount = if let Some(tax_rate_id) = input.tax_rate_id {
-            let tax_rate = self.get_tax_rate(tenant_id, tax_rate_id).await?;
-            if let Some(rate) = tax_rate {
-                if rate.calculation == "inclusive" {
-                    subtotal - (subtotal / (Decimal::ONE + rate.rate))
-                } else {
-                    subtotal * rate.rate
-                }
-            } else {
-                Decimal::ZERO
-            }
-        } else {
-            Decimal::ZERO
-        };
+        let taxes = self
+            .calculate_line_taxes(&invoice, input.tax_rate_id, input.tax_group_id, subtotal)
+            .await?;
+        let tax_amount: Decimal = taxes.iter().map(|t| t.tax_amount).sum();
         let total = subtotal + tax_amount;
 
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
         let line_item = sqlx::query_as::<_, LineItem>(
             r#"
             UPDATE line_items
@@ -801,14 +1062,16 @@ impl Database {
                 quantity = COALESCE($5, quantity),
                 unit_price = COALESCE($6, unit_price),
                 tax_rate_id = $7,
-                tax_amount = $8,
-                subtotal = $9,
-                total = $10,
-                ledger_account_id = $11,
-                sort_order = COALESCE($12, sort_order)
+                tax_group_id = $8,
+                tax_amount = $9,
+                subtotal = $10,
+                total = $11,
+                ledger_account_id = $12,
+                sort_order = COALESCE($13, sort_order)
             WHERE tenant_id = $1 AND invoice_id = $2 AND line_item_id = $3
             RETURNING line_item_id, invoice_id, tenant_id, description, quantity, unit_price,
-                tax_rate_id, tax_amount, subtotal, total, ledger_account_id, sort_order, created_utc
+                tax_rate_id, tax_group_id, tax_amount, subtotal, total, ledger_account_id, reference_line_item_id,
+                sort_order, line_type, created_utc
             "#,
         )
         .bind(tenant_id)
@@ -818,20 +1081,173 @@ impl Database {
         .bind(input.quantity)
         .bind(input.unit_price)
         .bind(input.tax_rate_id)
+        .bind(input.tax_group_id)
         .bind(tax_amount)
         .bind(subtotal)
         .bind(total)
         .bind(input.ledger_account_id)
         .bind(input.sort_order)
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| {
             AppError::DatabaseError(anyhow::anyhow!("Failed to update line item: {}", e))
         })?;
 
+        let Some(mut line_item) = line_item else {
+            return Ok(None);
+        };
+
+        Self::replace_line_taxes(
+            &mut tx,
+            line_item.tenant_id,
+            line_item.invoice_id,
+            line_item.line_item_id,
+            &taxes,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
-        Ok(line_item)
+        line_item.taxes = taxes;
+        Ok(Some(line_item))
+    }
+
+    /// Taxes on a line subtotal from its tax rate or tax group. Group
+    /// components depend on the invoice's place of supply.
+    async fn calculate_line_taxes(
+        &self,
+        invoice: &Invoice,
+        tax_rate_id: Option<Uuid>,
+        tax_group_id: Option<Uuid>,
+        subtotal: Decimal,
+    ) -> Result<Vec<LineItemTax>, AppError> {
+        match (tax_rate_id, tax_group_id) {
+            (Some(_), Some(_)) => Err(AppError::BadRequest(anyhow::anyhow!(
+                "A line item can have a tax rate or a tax group, not both"
+            ))),
+            (Some(tax_rate_id), None) => Ok(self
+                .get_tax_rate(invoice.tenant_id, tax_rate_id)
+                .await?
+                .map(|rate| tax::rate_taxes(&rate, subtotal))
+                .unwrap_or_default()),
+            (None, Some(tax_group_id)) => {
+                let tax_group = match self.get_tax_group(invoice.tenant_id, tax_group_id).await? {
+                    Some(group) if group.active => group,
+                    Some(_) => {
+                        return Err(AppError::BadRequest(anyhow::anyhow!(
+                            "Tax group is inactive"
+                        )))
+                    }
+                    None => {
+                        return Err(AppError::BadRequest(anyhow::anyhow!("Tax group not found")))
+                    }
+                };
+                let profile = self.get_tenant_profile(invoice.tenant_id).await?;
+                let place_of_supply = tax::place_of_supply(profile.as_ref(), invoice);
+                Ok(tax::group_taxes(&tax_group, subtotal, place_of_supply))
+            }
+            (None, None) => Ok(Vec::new()),
+        }
+    }
+
+    /// Replace the stored taxes of a line item.
+    async fn replace_line_taxes(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        invoice_id: Uuid,
+        line_item_id: Uuid,
+        taxes: &[LineItemTax],
+    ) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM line_item_taxes WHERE line_item_id = $1")
+            .bind(line_item_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to clear line item taxes: {}", e))
+            })?;
+
+        for (sort_order, tax) in taxes.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO line_item_taxes (
+                    line_item_id, invoice_id, tenant_id, name, kind, rate, taxable_amount, tax_amount,
+                    sort_order
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+            )
+            .bind(line_item_id)
+            .bind(invoice_id)
+            .bind(tenant_id)
+            .bind(&tax.name)
+            .bind(&tax.kind)
+            .bind(tax.rate)
+            .bind(tax.taxable_amount)
+            .bind(tax.tax_amount)
+            .bind(sort_order as i32)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to add line item tax: {}", e))
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Recalculate tax group lines on a draft invoice, whose place of supply
+    /// follows the billing address and the tenant's registered address.
+    /// Runs in the caller's transaction, so the lines change together with
+    /// whatever moved the place of supply.
+    async fn refresh_group_taxes(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        invoice: &Invoice,
+    ) -> Result<(), AppError> {
+        let line_items = self
+            .get_line_items(invoice.tenant_id, invoice.invoice_id)
+            .await?;
+
+        for line_item in line_items.iter().filter(|l| l.tax_group_id.is_some()) {
+            let taxes = self
+                .calculate_line_taxes(invoice, None, line_item.tax_group_id, line_item.subtotal)
+                .await?;
+            if taxes == line_item.taxes {
+                continue;
+            }
+            let tax_amount: Decimal = taxes.iter().map(|t| t.tax_amount).sum();
+
+            sqlx::query(
+                r#"
+                UPDATE line_items
+                SET tax_amount = $2,
+                    total = subtotal + $2
+                WHERE line_item_id = $1
+                "#,
+            )
+            .bind(line_item.line_item_id)
+            .bind(tax_amount)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to update line item tax: {}", e))
+            })?;
+
+            Self::replace_line_taxes(
+                tx,
+                line_item.tenant_id,
+                line_item.invoice_id,
+                line_item.line_item_id,
+                &taxes,
+            )
+            .await?;
+        }
+
+        Ok(())
     }
 
     /// Remove a line item.
@@ -892,6 +1308,11 @@ impl Database {
         // Verify invoice is in issued status
         let invoice = self.get_invoice(input.tenant_id, input.invoice_id).await?;
         let invoice = match invoice {
+            Some(inv) if inv.invoice_type == "credit_note" => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Payments cannot be recorded against credit notes"
+                )))
+            }
             Some(inv) if inv.status == "issued" => inv,
             Some(_) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
@@ -919,7 +1340,7 @@ impl Database {
             )
             VALUES ($1, $2, next_receipt_number($2), $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
-                payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+                payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
             "#,
         )
         .bind(receipt_id)
@@ -965,7 +1386,7 @@ impl Database {
         let receipt = sqlx::query_as::<_, Receipt>(
             r#"
             SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
-                payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+                payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
             FROM receipts
             WHERE tenant_id = $1 AND receipt_id = $2
             "#,
@@ -998,7 +1419,7 @@ impl Database {
             sqlx::query_as::<_, Receipt>(
                 r#"
                 SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
-                    payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+                    payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
                 FROM receipts
                 WHERE tenant_id = $1
                   AND ($2::uuid IS NULL OR invoice_id = $2)
@@ -1023,7 +1444,7 @@ impl Database {
             sqlx::query_as::<_, Receipt>(
                 r#"
                 SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
-                    payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+                    payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
                 FROM receipts
                 WHERE tenant_id = $1
                   AND ($2::uuid IS NULL OR invoice_id = $2)
@@ -1050,135 +1471,1090 @@ impl Database {
         Ok(receipts)
     }
 
-    // -------------------------------------------------------------------------
-    // Statement Operations
-    // -------------------------------------------------------------------------
-
-    /// Calculate opening balance for a customer before a given date.
-    /// Opening balance = sum of issued invoice totals - sum of payment amounts before period_start.
-    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
-    pub async fn calculate_opening_balance(
+    /// Record the ledger journal posted for a receipt.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, receipt_id = %receipt_id))]
+    pub async fn set_receipt_journal(
         &self,
         tenant_id: Uuid,
-        customer_id: Uuid,
-        before_date: NaiveDate,
-    ) -> Result<Decimal, AppError> {
+        receipt_id: Uuid,
+        journal_id: Uuid,
+    ) -> Result<(), AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["calculate_opening_balance"])
+            .with_label_values(&["set_receipt_journal"])
             .start_timer();
 
-        // Sum of issued invoice totals before period start
-        let invoice_total: Option<Decimal> = sqlx::query_scalar(
+        sqlx::query(
             r#"
-            SELECT COALESCE(SUM(total), 0)
-            FROM invoices
-            WHERE tenant_id = $1
-              AND customer_id = $2
-              AND status IN ('issued', 'paid', 'overdue')
-              AND issue_date < $3
+            UPDATE receipts
+            SET journal_id = $3
+            WHERE tenant_id = $1 AND receipt_id = $2
             "#,
         )
         .bind(tenant_id)
-        .bind(customer_id)
-        .bind(before_date)
-        .fetch_one(&self.pool)
+        .bind(receipt_id)
+        .bind(journal_id)
+        .execute(&self.pool)
         .await
         .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to calculate invoice total: {}", e))
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set receipt journal: {}", e))
         })?;
 
-        // Sum of payments before period start
-        let payment_total: Option<Decimal> = sqlx::query_scalar(
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Customer Payment Operations
+    // -------------------------------------------------------------------------
+
+    /// Record a customer payment. The whole amount starts as unapplied cash.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, customer_id = %input.customer_id))]
+    pub async fn create_customer_payment(
+        &self,
+        input: &CreateCustomerPayment,
+    ) -> Result<CustomerPayment, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_customer_payment"])
+            .start_timer();
+
+        let payment = sqlx::query_as::<_, CustomerPayment>(
             r#"
-            SELECT COALESCE(SUM(amount), 0)
-            FROM receipts
-            WHERE tenant_id = $1
-              AND customer_id = $2
-              AND payment_date < $3
+            INSERT INTO customer_payments (
+                payment_id, tenant_id, payment_number, customer_id, amount, currency,
+                payment_method, payment_reference, payment_date, notes
+            )
+            VALUES ($1, $2, next_receipt_number($2, 'PAY'), $3, $4, $5, $6, $7, $8, $9)
+            RETURNING payment_id, tenant_id, payment_number, customer_id, amount, amount_allocated,
+                currency, payment_method, payment_reference, payment_date, journal_id, notes, created_utc
             "#,
         )
-        .bind(tenant_id)
-        .bind(customer_id)
-        .bind(before_date)
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.customer_id)
+        .bind(input.amount)
+        .bind(&input.currency)
+        .bind(&input.payment_method)
+        .bind(&input.payment_reference)
+        .bind(input.payment_date)
+        .bind(&input.notes)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to calculate payment total: {}", e))
+            AppError::DatabaseError(anyhow::anyhow!("Failed to create customer payment: {}", e))
         })?;
 
         timer.observe_duration();
 
-        let opening =
-            invoice_total.unwrap_or(Decimal::ZERO) - payment_total.unwrap_or(Decimal::ZERO);
+        info!(
+            payment_id = %payment.payment_id,
+            payment_number = %payment.payment_number,
+            amount = %payment.amount,
+            "Customer payment recorded"
+        );
+
+        Ok(payment)
+    }
+
+    /// Get a customer payment by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, payment_id = %payment_id))]
+    pub async fn get_customer_payment(
+        &self,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+    ) -> Result<Option<CustomerPayment>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_customer_payment"])
+            .start_timer();
+
+        let payment = sqlx::query_as::<_, CustomerPayment>(
+            r#"
+            SELECT payment_id, tenant_id, payment_number, customer_id, amount, amount_allocated,
+                currency, payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+            FROM customer_payments
+            WHERE tenant_id = $1 AND payment_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get customer payment: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(payment)
+    }
+
+    /// List customer payments for a tenant.
+    #[instrument(skip(self, filter), fields(tenant_id = %tenant_id))]
+    pub async fn list_customer_payments(
+        &self,
+        tenant_id: Uuid,
+        filter: &ListCustomerPaymentsFilter,
+    ) -> Result<Vec<CustomerPayment>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_customer_payments"])
+            .start_timer();
+
+        let limit = filter.page_size.clamp(1, 100) as i64;
+
+        let payments = sqlx::query_as::<_, CustomerPayment>(
+            r#"
+            SELECT payment_id, tenant_id, payment_number, customer_id, amount, amount_allocated,
+                currency, payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+            FROM customer_payments
+            WHERE tenant_id = $1
+              AND ($2::uuid IS NULL OR customer_id = $2)
+              AND (NOT $3 OR amount_allocated < amount)
+              AND ($4::uuid IS NULL OR payment_id > $4)
+            ORDER BY payment_id
+            LIMIT $5
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(filter.customer_id)
+        .bind(filter.unapplied_only)
+        .bind(filter.page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list customer payments: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(payments)
+    }
+
+    /// Receipts allocating a customer payment, oldest first.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, payment_id = %payment_id))]
+    pub async fn get_payment_allocations(
+        &self,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+    ) -> Result<Vec<Receipt>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_payment_allocations"])
+            .start_timer();
+
+        let receipts = sqlx::query_as::<_, Receipt>(
+            r#"
+            SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
+                payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
+            FROM receipts
+            WHERE tenant_id = $1 AND payment_id = $2
+            ORDER BY created_utc, receipt_number
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get payment allocations: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(receipts)
+    }
+
+    /// Allocate unapplied cash from a customer payment to the customer's
+    /// issued invoices, creating a receipt against each. Returns the new
+    /// receipts, or None if the payment doesn't exist.
+    #[instrument(skip(self, allocations), fields(tenant_id = %tenant_id, payment_id = %payment_id))]
+    pub async fn allocate_payment(
+        &self,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+        allocations: &[PaymentAllocation],
+    ) -> Result<Option<Vec<Receipt>>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["allocate_payment"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Lock the payment so concurrent allocations can't over-allocate it
+        let payment = sqlx::query_as::<_, CustomerPayment>(
+            r#"
+            SELECT payment_id, tenant_id, payment_number, customer_id, amount, amount_allocated,
+                currency, payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+            FROM customer_payments
+            WHERE tenant_id = $1 AND payment_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get customer payment: {}", e))
+        })?;
+
+        let Some(payment) = payment else {
+            return Ok(None);
+        };
+
+        let requested: Decimal = allocations.iter().map(|a| a.amount).sum();
+        if requested > payment.amount_unapplied() {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Allocations of {} exceed unapplied amount {}",
+                requested,
+                payment.amount_unapplied()
+            )));
+        }
+
+        let mut receipts = Vec::with_capacity(allocations.len());
+        for (index, allocation) in allocations.iter().enumerate() {
+            if allocations[..index]
+                .iter()
+                .any(|a| a.invoice_id == allocation.invoice_id)
+            {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Invoice {} is allocated more than once",
+                    allocation.invoice_id
+                )));
+            }
+
+            let invoice = sqlx::query_as::<_, Invoice>(
+                r#"
+                SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                    billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                    currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
+                    notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
+                FROM invoices
+                WHERE tenant_id = $1 AND invoice_id = $2
+                FOR UPDATE
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(allocation.invoice_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get invoice: {}", e)))?;
+
+            let invoice = match invoice {
+                Some(inv) if inv.customer_id != payment.customer_id => {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Invoice {} belongs to another customer",
+                        allocation.invoice_id
+                    )))
+                }
+                Some(inv) if inv.invoice_type == "credit_note" => {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Payments cannot be allocated to credit notes"
+                    )))
+                }
+                Some(inv) if inv.status == "issued" => inv,
+                Some(_) => {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Can only allocate payments to issued invoices"
+                    )))
+                }
+                None => {
+                    return Err(AppError::NotFound(anyhow::anyhow!(
+                        "Invoice {} not found",
+                        allocation.invoice_id
+                    )))
+                }
+            };
+
+            if invoice.currency != payment.currency {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Invoice {} is in {}, payment is in {}",
+                    allocation.invoice_id,
+                    invoice.currency,
+                    payment.currency
+                )));
+            }
+            if allocation.amount > invoice.amount_due {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Allocation {} exceeds amount due {} on invoice {}",
+                    allocation.amount,
+                    invoice.amount_due,
+                    allocation.invoice_id
+                )));
+            }
+
+            let receipt = sqlx::query_as::<_, Receipt>(
+                r#"
+                INSERT INTO receipts (
+                    receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
+                    payment_method, payment_reference, payment_date, payment_id
+                )
+                VALUES ($1, $2, next_receipt_number($2), $3, $4, $5, $6, $7, $8, $9, $10)
+                RETURNING receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
+                    payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
+                "#,
+            )
+            .bind(Uuid::new_v4())
+            .bind(tenant_id)
+            .bind(allocation.invoice_id)
+            .bind(payment.customer_id)
+            .bind(allocation.amount)
+            .bind(&payment.currency)
+            .bind(&payment.payment_method)
+            .bind(&payment.payment_reference)
+            .bind(payment.payment_date)
+            .bind(payment_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to allocate payment: {}", e))
+            })?;
+            receipts.push(receipt);
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(
+            payment_id = %payment_id,
+            allocations = receipts.len(),
+            amount = %requested,
+            "Customer payment allocated"
+        );
+
+        Ok(Some(receipts))
+    }
+
+    /// Remove an allocation of a customer payment, deleting its receipt and
+    /// returning the amount to the invoice balance and the payment's
+    /// unapplied cash. Returns the removed receipt, or None if the payment
+    /// has no such allocation.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, payment_id = %payment_id, receipt_id = %receipt_id))]
+    pub async fn unallocate_payment(
+        &self,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+        receipt_id: Uuid,
+    ) -> Result<Option<Receipt>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["unallocate_payment"])
+            .start_timer();
+
+        let receipt = sqlx::query_as::<_, Receipt>(
+            r#"
+            DELETE FROM receipts
+            WHERE tenant_id = $1 AND payment_id = $2 AND receipt_id = $3
+            RETURNING receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
+                payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_id)
+        .bind(receipt_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to unallocate payment: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        if let Some(ref receipt) = receipt {
+            info!(
+                payment_id = %payment_id,
+                receipt_id = %receipt.receipt_id,
+                amount = %receipt.amount,
+                "Customer payment unallocated"
+            );
+        }
+
+        Ok(receipt)
+    }
+
+    /// Record the ledger journal posted for a customer payment.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, payment_id = %payment_id))]
+    pub async fn set_customer_payment_journal(
+        &self,
+        tenant_id: Uuid,
+        payment_id: Uuid,
+        journal_id: Uuid,
+    ) -> Result<(), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_customer_payment_journal"])
+            .start_timer();
+
+        sqlx::query(
+            r#"
+            UPDATE customer_payments
+            SET journal_id = $3
+            WHERE tenant_id = $1 AND payment_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_id)
+        .bind(journal_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to set customer payment journal: {}",
+                e
+            ))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Credit Note Operations
+    // -------------------------------------------------------------------------
+
+    /// Issue a credit note against an invoice and apply it to the invoice's
+    /// balance. Credited quantities are capped per original line by what
+    /// earlier credit notes have already credited. Returns the credit note and
+    /// the updated invoice, or None if the invoice doesn't exist.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, invoice_id = %input.invoice_id))]
+    pub async fn create_credit_note(
+        &self,
+        input: &CreateCreditNote,
+    ) -> Result<Option<(Invoice, Invoice)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_credit_note"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Lock the invoice so concurrent credit notes can't over-credit it
+        let invoice = sqlx::query_as::<_, Invoice>(
+            r#"
+            SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
+                notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
+            FROM invoices
+            WHERE tenant_id = $1 AND invoice_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(input.invoice_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get invoice: {}", e)))?;
+
+        let invoice = match invoice {
+            Some(inv) if inv.invoice_type != "standard" => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Only standard invoices can be credited"
+                )))
+            }
+            Some(inv) if inv.status == "issued" || inv.status == "paid" => inv,
+            Some(_) => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Only issued invoices can be credited"
+                )))
+            }
+            None => return Ok(None),
+        };
+
+        let line_items = self
+            .get_line_items(input.tenant_id, input.invoice_id)
+            .await?;
+
+        // Quantities and amounts already credited per original line
+        let credited: Vec<(Uuid, Decimal, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT li.reference_line_item_id, SUM(li.quantity), SUM(li.subtotal), SUM(li.tax_amount)
+            FROM line_items li
+            JOIN invoices cn ON cn.invoice_id = li.invoice_id
+            WHERE cn.tenant_id = $1
+              AND cn.reference_invoice_id = $2
+              AND cn.invoice_type = 'credit_note'
+              AND cn.status <> 'void'
+              AND li.reference_line_item_id IS NOT NULL
+            GROUP BY li.reference_line_item_id
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(input.invoice_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get credited quantities: {}", e))
+        })?;
+
+        let already_credited = |line: &LineItem| {
+            credited
+                .iter()
+                .find(|(id, ..)| *id == line.line_item_id)
+                .map(|(_, quantity, subtotal, tax)| (*quantity, *subtotal, *tax))
+                .unwrap_or((Decimal::ZERO, Decimal::ZERO, Decimal::ZERO))
+        };
+
+        let requested: Vec<(&LineItem, Decimal)> = if input.lines.is_empty() {
+            line_items
+                .iter()
+                .map(|line| (line, line.quantity - already_credited(line).0))
+                .filter(|(_, remaining)| *remaining > Decimal::ZERO)
+                .collect()
+        } else {
+            let mut requested = Vec::with_capacity(input.lines.len());
+            for credit in &input.lines {
+                let line = line_items
+                    .iter()
+                    .find(|line| line.line_item_id == credit.line_item_id)
+                    .ok_or_else(|| {
+                        AppError::BadRequest(anyhow::anyhow!(
+                            "Line item {} is not on the invoice",
+                            credit.line_item_id
+                        ))
+                    })?;
+                if requested
+                    .iter()
+                    .any(|(l, _): &(&LineItem, Decimal)| l.line_item_id == line.line_item_id)
+                {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Line item {} is credited more than once",
+                        credit.line_item_id
+                    )));
+                }
+                let remaining = line.quantity - already_credited(line).0;
+                if credit.quantity > remaining {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Cannot credit {} of line item {}; {} remaining",
+                        credit.quantity,
+                        credit.line_item_id,
+                        remaining
+                    )));
+                }
+                requested.push((line, credit.quantity));
+            }
+            requested
+        };
+
+        if requested.is_empty() {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Invoice has already been fully credited"
+            )));
+        }
+
+        let credit_note_id = Uuid::new_v4();
+        sqlx::query(
+            r#"
+            INSERT INTO invoices (
+                invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                currency, issue_date, notes, reference_invoice_id, issued_utc
+            )
+            VALUES ($1, $2, next_invoice_number($2, 'CN'), 'credit_note', 'issued', $3, $4,
+                $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW())
+            "#,
+        )
+        .bind(credit_note_id)
+        .bind(input.tenant_id)
+        .bind(invoice.customer_id)
+        .bind(&invoice.customer_name)
+        .bind(&invoice.billing_line1)
+        .bind(&invoice.billing_line2)
+        .bind(&invoice.billing_city)
+        .bind(&invoice.billing_state)
+        .bind(&invoice.billing_postal_code)
+        .bind(&invoice.billing_country)
+        .bind(&invoice.currency)
+        .bind(input.issue_date)
+        .bind(&input.notes)
+        .bind(invoice.invoice_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to create credit note: {}", e))
+        })?;
+
+        let mut credit_total = Decimal::ZERO;
+        for (line, quantity) in requested {
+            let (credited_quantity, credited_subtotal, credited_tax) = already_credited(line);
+            // Crediting the rest of a line takes exactly what's left, so
+            // rounding never leaves a residue on the original
+            let (subtotal, tax_amount) = if quantity == line.quantity - credited_quantity {
+                (
+                    line.subtotal - credited_subtotal,
+                    line.tax_amount - credited_tax,
+                )
+            } else {
+                let share = quantity / line.quantity;
+                (
+                    (line.subtotal * share).round_dp(4),
+                    (line.tax_amount * share).round_dp(4),
+                )
+            };
+            credit_total += subtotal + tax_amount;
+
+            let credit_line_id = Uuid::new_v4();
+            sqlx::query(
+                r#"
+                INSERT INTO line_items (
+                    line_item_id, invoice_id, tenant_id, description, quantity, unit_price,
+                    tax_rate_id, tax_group_id, tax_amount, subtotal, total, ledger_account_id,
+                    reference_line_item_id, sort_order, line_type
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                "#,
+            )
+            .bind(credit_line_id)
+            .bind(credit_note_id)
+            .bind(input.tenant_id)
+            .bind(&line.description)
+            .bind(quantity)
+            .bind(line.unit_price)
+            .bind(line.tax_rate_id)
+            .bind(line.tax_group_id)
+            .bind(tax_amount)
+            .bind(subtotal)
+            .bind(subtotal + tax_amount)
+            .bind(line.ledger_account_id)
+            .bind(line.line_item_id)
+            .bind(line.sort_order)
+            .bind(&line.line_type)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to add credit note line: {}", e))
+            })?;
+
+            // Credit each tax component in proportion to the quantity
+            let taxes = tax::scale_taxes(&line.taxes, quantity / line.quantity, tax_amount);
+            Self::replace_line_taxes(
+                &mut tx,
+                input.tenant_id,
+                credit_note_id,
+                credit_line_id,
+                &taxes,
+            )
+            .await?;
+        }
+
+        // Apply what the balance allows; the rest is refundable if requested
+        let applied = credit_total.min(invoice.amount_due.max(Decimal::ZERO));
+        let refundable = credit_total - applied;
+        if refundable > Decimal::ZERO && !input.refundable {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Credit of {} exceeds the invoice balance of {}; request a refundable credit",
+                credit_total,
+                invoice.amount_due
+            )));
+        }
+
+        let credit_note = sqlx::query_as::<_, Invoice>(
+            r#"
+            UPDATE invoices
+            SET amount_due = $3
+            WHERE tenant_id = $1 AND invoice_id = $2
+            RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
+                notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(credit_note_id)
+        .bind(refundable)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to create credit note: {}", e))
+        })?;
+
+        let invoice = sqlx::query_as::<_, Invoice>(
+            r#"
+            UPDATE invoices
+            SET amount_credited = amount_credited + $3,
+                amount_due = amount_due - $3,
+                status = CASE
+                    WHEN amount_due - $3 <= 0 AND status = 'issued' THEN 'paid'
+                    ELSE status
+                END
+            WHERE tenant_id = $1 AND invoice_id = $2
+            RETURNING invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
+                notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(input.invoice_id)
+        .bind(applied)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to apply credit note: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(
+            credit_note_id = %credit_note.invoice_id,
+            invoice_number = %credit_note.invoice_number.as_deref().unwrap_or(""),
+            applied = %applied,
+            refundable = %refundable,
+            "Credit note issued"
+        );
+
+        Ok(Some((credit_note, invoice)))
+    }
+
+    /// Record the ledger journal posted for an invoice or credit note.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, invoice_id = %invoice_id))]
+    pub async fn set_invoice_journal(
+        &self,
+        tenant_id: Uuid,
+        invoice_id: Uuid,
+        journal_id: Uuid,
+    ) -> Result<(), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_invoice_journal"])
+            .start_timer();
+
+        sqlx::query(
+            r#"
+            UPDATE invoices
+            SET journal_id = $3
+            WHERE tenant_id = $1 AND invoice_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(invoice_id)
+        .bind(journal_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set invoice journal: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Statement Operations
+    // -------------------------------------------------------------------------
+
+    /// Calculate opening balance for a customer before a given date.
+    /// Opening balance = sum of issued invoice totals - credit note totals - payment amounts before period_start.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn calculate_opening_balance(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        before_date: NaiveDate,
+    ) -> Result<Decimal, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["calculate_opening_balance"])
+            .start_timer();
+
+        // Sum of issued invoice totals before period start
+        let invoice_total: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(CASE WHEN invoice_type = 'credit_note' THEN -total ELSE total END), 0)
+            FROM invoices
+            WHERE tenant_id = $1
+              AND customer_id = $2
+              AND status IN ('issued', 'paid', 'overdue')
+              AND issue_date < $3
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(before_date)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to calculate invoice total: {}", e))
+        })?;
+
+        // Sum of payments before period start. Receipts allocating a customer
+        // payment are counted through the payment itself.
+        let payment_total: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(amount), 0)
+            FROM (
+                SELECT amount
+                FROM receipts
+                WHERE tenant_id = $1
+                  AND customer_id = $2
+                  AND payment_id IS NULL
+                  AND payment_date < $3
+                UNION ALL
+                SELECT amount
+                FROM customer_payments
+                WHERE tenant_id = $1
+                  AND customer_id = $2
+                  AND payment_date < $3
+            ) payments
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(before_date)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to calculate payment total: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        let opening =
+            invoice_total.unwrap_or(Decimal::ZERO) - payment_total.unwrap_or(Decimal::ZERO);
         Ok(opening)
     }
 
-    /// Get invoices for a customer within a date range (for statement).
+    /// Get invoices for a customer within a date range (for statement).
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn get_invoices_for_statement(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<Invoice>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_invoices_for_statement"])
+            .start_timer();
+
+        let invoices = sqlx::query_as::<_, Invoice>(
+            r#"
+            SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
+                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
+                notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
+            FROM invoices
+            WHERE tenant_id = $1
+              AND customer_id = $2
+              AND status IN ('issued', 'paid', 'overdue')
+              AND issue_date >= $3
+              AND issue_date <= $4
+            ORDER BY issue_date, invoice_number
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get invoices for statement: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(invoices)
+    }
+
+    /// Get receipts for a customer within a date range (for statement),
+    /// leaving out receipts that allocate a customer payment.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn get_receipts_for_statement(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<Receipt>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_receipts_for_statement"])
+            .start_timer();
+
+        let receipts = sqlx::query_as::<_, Receipt>(
+            r#"
+            SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
+                payment_method, payment_reference, payment_date, journal_id, notes, payment_id, created_utc
+            FROM receipts
+            WHERE tenant_id = $1
+              AND customer_id = $2
+              AND payment_id IS NULL
+              AND payment_date >= $3
+              AND payment_date <= $4
+            ORDER BY payment_date, receipt_number
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to get receipts for statement: {}",
+                e
+            ))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(receipts)
+    }
+
+    /// Get customer payments received within a date range (for statement).
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn get_customer_payments_for_statement(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<CustomerPayment>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_customer_payments_for_statement"])
+            .start_timer();
+
+        let payments = sqlx::query_as::<_, CustomerPayment>(
+            r#"
+            SELECT payment_id, tenant_id, payment_number, customer_id, amount, amount_allocated,
+                currency, payment_method, payment_reference, payment_date, journal_id, notes, created_utc
+            FROM customer_payments
+            WHERE tenant_id = $1
+              AND customer_id = $2
+              AND payment_date >= $3
+              AND payment_date <= $4
+            ORDER BY payment_date, payment_number
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to get customer payments for statement: {}",
+                e
+            ))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(payments)
+    }
+
+    /// Invoice numbers each customer payment is allocated to, as
+    /// `(payment_id, invoice_number)` pairs (for statement).
+    #[instrument(skip(self, payment_ids), fields(tenant_id = %tenant_id))]
+    pub async fn get_allocated_invoice_numbers(
+        &self,
+        tenant_id: Uuid,
+        payment_ids: &[Uuid],
+    ) -> Result<Vec<(Uuid, String)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_allocated_invoice_numbers"])
+            .start_timer();
+
+        let numbers = sqlx::query_as::<_, (Uuid, String)>(
+            r#"
+            SELECT r.payment_id, COALESCE(i.invoice_number, i.invoice_id::text)
+            FROM receipts r
+            JOIN invoices i ON i.invoice_id = r.invoice_id
+            WHERE r.tenant_id = $1 AND r.payment_id = ANY($2)
+            ORDER BY r.created_utc, r.receipt_number
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(payment_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to get allocated invoice numbers: {}",
+                e
+            ))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(numbers)
+    }
+
+    /// Cash from customer payments received up to a date that is not yet
+    /// allocated to an invoice.
     #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
-    pub async fn get_invoices_for_statement(
+    pub async fn calculate_unapplied_cash(
         &self,
         tenant_id: Uuid,
         customer_id: Uuid,
-        start_date: NaiveDate,
-        end_date: NaiveDate,
-    ) -> Result<Vec<Invoice>, AppError> {
+        as_of: NaiveDate,
+    ) -> Result<Decimal, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_invoices_for_statement"])
+            .with_label_values(&["calculate_unapplied_cash"])
             .start_timer();
 
-        let invoices = sqlx::query_as::<_, Invoice>(
+        let unapplied: Option<Decimal> = sqlx::query_scalar(
             r#"
-            SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
-                billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
-                notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
-            FROM invoices
+            SELECT COALESCE(SUM(amount - amount_allocated), 0)
+            FROM customer_payments
             WHERE tenant_id = $1
               AND customer_id = $2
-              AND status IN ('issued', 'paid', 'overdue')
-              AND issue_date >= $3
-              AND issue_date <= $4
-            ORDER BY issue_date, invoice_number
+              AND payment_date <= $3
             "#,
         )
         .bind(tenant_id)
         .bind(customer_id)
-        .bind(start_date)
-        .bind(end_date)
-        .fetch_all(&self.pool)
+        .bind(as_of)
+        .fetch_one(&self.pool)
         .await
         .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to get invoices for statement: {}", e))
+            AppError::DatabaseError(anyhow::anyhow!("Failed to calculate unapplied cash: {}", e))
         })?;
 
         timer.observe_duration();
 
-        Ok(invoices)
+        Ok(unapplied.unwrap_or(Decimal::ZERO))
     }
 
-    /// Get receipts for a customer within a date range (for statement).
+    /// Tax on a customer's invoices issued within a date range, per rate or
+    /// component, with credit notes subtracted (for statement).
     #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
-    pub async fn get_receipts_for_statement(
+    pub async fn get_tax_breakdown_for_statement(
         &self,
         tenant_id: Uuid,
         customer_id: Uuid,
         start_date: NaiveDate,
         end_date: NaiveDate,
-    ) -> Result<Vec<Receipt>, AppError> {
+    ) -> Result<Vec<TaxBreakdown>, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_receipts_for_statement"])
+            .with_label_values(&["get_tax_breakdown_for_statement"])
             .start_timer();
 
-        let receipts = sqlx::query_as::<_, Receipt>(
+        let breakdown = sqlx::query_as::<_, TaxBreakdown>(
             r#"
-            SELECT receipt_id, tenant_id, receipt_number, invoice_id, customer_id, amount, currency,
-                payment_method, payment_reference, payment_date, journal_id, notes, created_utc
-            FROM receipts
-            WHERE tenant_id = $1
-              AND customer_id = $2
-              AND payment_date >= $3
-              AND payment_date <= $4
-            ORDER BY payment_date, receipt_number
+            SELECT t.name, t.kind, t.rate,
+                SUM(CASE WHEN i.invoice_type = 'credit_note' THEN -t.taxable_amount ELSE t.taxable_amount END)
+                    AS taxable_amount,
+                SUM(CASE WHEN i.invoice_type = 'credit_note' THEN -t.tax_amount ELSE t.tax_amount END)
+                    AS tax_amount
+            FROM line_item_taxes t
+            JOIN invoices i ON i.invoice_id = t.invoice_id
+            WHERE i.tenant_id = $1
+              AND i.customer_id = $2
+              AND i.status IN ('issued', 'paid', 'overdue')
+              AND i.issue_date >= $3
+              AND i.issue_date <= $4
+            GROUP BY t.name, t.kind, t.rate
+            ORDER BY MIN(t.sort_order), t.name, t.rate
             "#,
         )
         .bind(tenant_id)
@@ -1189,14 +2565,14 @@ impl Database {
         .await
         .map_err(|e| {
             AppError::DatabaseError(anyhow::anyhow!(
-                "Failed to get receipts for statement: {}",
+                "Failed to get tax breakdown for statement: {}",
                 e
             ))
         })?;
 
         timer.observe_duration();
 
-        Ok(receipts)
+        Ok(breakdown)
     }
 
     /// Get customer name and address for statement header.
@@ -1211,7 +2587,7 @@ impl Database {
             r#"
             SELECT invoice_id, tenant_id, invoice_number, invoice_type, status, customer_id, customer_name,
                 billing_line1, billing_line2, billing_city, billing_state, billing_postal_code, billing_country,
-                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due,
+                currency, issue_date, due_date, subtotal, tax_total, total, amount_paid, amount_due, amount_credited,
                 notes, reference_invoice_id, journal_id, metadata, created_utc, issued_utc, voided_utc
             FROM invoices
             WHERE tenant_id = $1 AND customer_id = $2
@@ -1229,4 +2605,394 @@ impl Database {
 
         Ok(invoice)
     }
+
+    // -------------------------------------------------------------------------
+    // Tenant Profile Operations
+    // -------------------------------------------------------------------------
+
+    /// Create or replace a tenant's profile.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn set_tenant_profile(
+        &self,
+        input: &SetTenantProfile,
+    ) -> Result<TenantProfile, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_tenant_profile"])
+            .start_timer();
+
+        let profile = sqlx::query_as::<_, TenantProfile>(
+            r#"
+            INSERT INTO tenant_profiles (tenant_id, legal_name, tax_id, address_line1, address_line2,
+                address_city, address_state, address_postal_code, address_country, email, phone, logo_document_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (tenant_id) DO UPDATE SET
+                legal_name = EXCLUDED.legal_name,
+                tax_id = EXCLUDED.tax_id,
+                address_line1 = EXCLUDED.address_line1,
+                address_line2 = EXCLUDED.address_line2,
+                address_city = EXCLUDED.address_city,
+                address_state = EXCLUDED.address_state,
+                address_postal_code = EXCLUDED.address_postal_code,
+                address_country = EXCLUDED.address_country,
+                email = EXCLUDED.email,
+                phone = EXCLUDED.phone,
+                logo_document_id = EXCLUDED.logo_document_id,
+                updated_utc = NOW()
+            RETURNING tenant_id, legal_name, tax_id, address_line1, address_line2, address_city, address_state,
+                address_postal_code, address_country, email, phone, logo_document_id, created_utc, updated_utc
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(&input.legal_name)
+        .bind(&input.tax_id)
+        .bind(&input.address_line1)
+        .bind(&input.address_line2)
+        .bind(&input.address_city)
+        .bind(&input.address_state)
+        .bind(&input.address_postal_code)
+        .bind(&input.address_country)
+        .bind(&input.email)
+        .bind(&input.phone)
+        .bind(input.logo_document_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set tenant profile: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(tenant_id = %profile.tenant_id, "Tenant profile set");
+
+        Ok(profile)
+    }
+
+    /// Get a tenant's profile.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_tenant_profile(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Option<TenantProfile>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_tenant_profile"])
+            .start_timer();
+
+        let profile = sqlx::query_as::<_, TenantProfile>(
+            r#"
+            SELECT tenant_id, legal_name, tax_id, address_line1, address_line2, address_city, address_state,
+                address_postal_code, address_country, email, phone, logo_document_id, created_utc, updated_utc
+            FROM tenant_profiles
+            WHERE tenant_id = $1
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get tenant profile: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(profile)
+    }
+
+    // -------------------------------------------------------------------------
+    // Invoice Template Operations
+    // -------------------------------------------------------------------------
+
+    /// Create an invoice template, replacing the tenant's default if it is
+    /// created as the default.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn create_invoice_template(
+        &self,
+        input: &CreateInvoiceTemplate,
+    ) -> Result<InvoiceTemplate, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_invoice_template"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        if input.is_default {
+            sqlx::query(
+                r#"
+                UPDATE invoice_templates
+                SET is_default = FALSE, updated_utc = NOW()
+                WHERE tenant_id = $1 AND is_default = TRUE
+                "#,
+            )
+            .bind(input.tenant_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to clear default template: {}", e))
+            })?;
+        }
+
+        let template = sqlx::query_as::<_, InvoiceTemplate>(
+            r#"
+            INSERT INTO invoice_templates (template_id, tenant_id, name, primary_color, accent_color,
+                logo_document_id, footer_template, terms_template, labels, is_default)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING template_id, tenant_id, name, primary_color, accent_color, logo_document_id,
+                footer_template, terms_template, labels, is_default, created_utc, updated_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(&input.name)
+        .bind(&input.primary_color)
+        .bind(&input.accent_color)
+        .bind(input.logo_document_id)
+        .bind(&input.footer_template)
+        .bind(&input.terms_template)
+        .bind(&input.labels)
+        .bind(input.is_default)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(anyhow::anyhow!(
+                    "Invoice template '{}' already exists",
+                    input.name
+                ))
+            }
+            _ => AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to create invoice template: {}",
+                e
+            )),
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(template_id = %template.template_id, name = %template.name, "Invoice template created");
+
+        Ok(template)
+    }
+
+    /// Get an invoice template by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, template_id = %template_id))]
+    pub async fn get_invoice_template(
+        &self,
+        tenant_id: Uuid,
+        template_id: Uuid,
+    ) -> Result<Option<InvoiceTemplate>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_invoice_template"])
+            .start_timer();
+
+        let template = sqlx::query_as::<_, InvoiceTemplate>(
+            r#"
+            SELECT template_id, tenant_id, name, primary_color, accent_color, logo_document_id,
+                footer_template, terms_template, labels, is_default, created_utc, updated_utc
+            FROM invoice_templates
+            WHERE tenant_id = $1 AND template_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(template_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get invoice template: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(template)
+    }
+
+    /// List a tenant's invoice templates.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn list_invoice_templates(
+        &self,
+        tenant_id: Uuid,
+        page_size: i32,
+        page_token: Option<Uuid>,
+    ) -> Result<Vec<InvoiceTemplate>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_invoice_templates"])
+            .start_timer();
+
+        let limit = page_size.clamp(1, 100) as i64;
+
+        let templates = sqlx::query_as::<_, InvoiceTemplate>(
+            r#"
+            SELECT template_id, tenant_id, name, primary_color, accent_color, logo_document_id,
+                footer_template, terms_template, labels, is_default, created_utc, updated_utc
+            FROM invoice_templates
+            WHERE tenant_id = $1
+              AND ($2::uuid IS NULL OR template_id > $2)
+            ORDER BY template_id
+            LIMIT $3
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list invoice templates: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(templates)
+    }
+
+    /// Make a template the tenant's default, replacing the previous default.
+    /// Returns None if the template doesn't exist.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, template_id = %template_id))]
+    pub async fn set_default_template(
+        &self,
+        tenant_id: Uuid,
+        template_id: Uuid,
+    ) -> Result<Option<InvoiceTemplate>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_default_template"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            UPDATE invoice_templates
+            SET is_default = FALSE, updated_utc = NOW()
+            WHERE tenant_id = $1 AND is_default = TRUE AND template_id <> $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(template_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to clear default template: {}", e))
+        })?;
+
+        let template = sqlx::query_as::<_, InvoiceTemplate>(
+            r#"
+            UPDATE invoice_templates
+            SET is_default = TRUE, updated_utc = NOW()
+            WHERE tenant_id = $1 AND template_id = $2
+            RETURNING template_id, tenant_id, name, primary_color, accent_color, logo_document_id,
+                footer_template, terms_template, labels, is_default, created_utc, updated_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(template_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set default template: {}", e))
+        })?;
+
+        // Leave the previous default in place if the template doesn't exist
+        if template.is_none() {
+            return Ok(None);
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(template)
+    }
+
+    /// Assign a template to a customer, or clear the assignment so the
+    /// customer gets the tenant's default.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn set_customer_template(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+        template_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_customer_template"])
+            .start_timer();
+
+        match template_id {
+            Some(template_id) => sqlx::query(
+                r#"
+                INSERT INTO customer_invoice_templates (tenant_id, customer_id, template_id)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (tenant_id, customer_id) DO UPDATE SET template_id = EXCLUDED.template_id
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(customer_id)
+            .bind(template_id)
+            .execute(&self.pool)
+            .await,
+            None => sqlx::query(
+                r#"
+                DELETE FROM customer_invoice_templates
+                WHERE tenant_id = $1 AND customer_id = $2
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(customer_id)
+            .execute(&self.pool)
+            .await,
+        }
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set customer template: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(())
+    }
+
+    /// The template for a customer's documents: the one assigned to the
+    /// customer, otherwise the tenant's default.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, customer_id = %customer_id))]
+    pub async fn get_template_for_customer(
+        &self,
+        tenant_id: Uuid,
+        customer_id: Uuid,
+    ) -> Result<Option<InvoiceTemplate>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_template_for_customer"])
+            .start_timer();
+
+        let template = sqlx::query_as::<_, InvoiceTemplate>(
+            r#"
+            SELECT t.template_id, t.tenant_id, t.name, t.primary_color, t.accent_color, t.logo_document_id,
+                t.footer_template, t.terms_template, t.labels, t.is_default, t.created_utc, t.updated_utc
+            FROM invoice_templates t
+            LEFT JOIN customer_invoice_templates c
+                ON c.template_id = t.template_id AND c.tenant_id = $1 AND c.customer_id = $2
+            WHERE t.tenant_id = $1 AND (c.customer_id IS NOT NULL OR t.is_default = TRUE)
+            ORDER BY (c.customer_id IS NOT NULL) DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(customer_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to get template for customer: {}",
+                e
+            ))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(template)
+    }
 }
@@ -0,0 +1,101 @@
+//! Storage of generated documents in document-service.
+
+use prost_types::Timestamp;
+use service_core::grpc::DocumentClient;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// User recorded as the owner of documents invoicing-service uploads.
+const SERVICE_USER_ID: &str = "invoicing-service";
+
+/// A generated document stored in document-service.
+#[derive(Debug, Clone)]
+pub struct StoredDocument {
+    pub document_id: String,
+    pub url: String,
+    pub url_expires_at: Option<Timestamp>,
+}
+
+/// Uploads generated PDFs and fetches tenant assets through document-service.
+///
+/// Documents are stored under the tenant as the organisation, so each tenant
+/// only sees its own files.
+#[derive(Clone)]
+pub struct DocumentStore {
+    client: DocumentClient,
+    app_id: String,
+    signed_url_ttl_seconds: i64,
+}
+
+impl DocumentStore {
+    /// Create a store uploading as `app_id`, signing URLs valid for the given
+    /// number of seconds.
+    pub fn new(client: DocumentClient, app_id: String, signed_url_ttl_seconds: i64) -> Self {
+        Self {
+            client,
+            app_id,
+            signed_url_ttl_seconds,
+        }
+    }
+
+    /// Upload a PDF for a tenant and sign a URL to download it.
+    #[instrument(skip(self, data), fields(tenant_id = %tenant_id, size = data.len()))]
+    pub async fn store_pdf(
+        &self,
+        tenant_id: Uuid,
+        filename: &str,
+        data: Vec<u8>,
+    ) -> Result<StoredDocument, tonic::Status> {
+        let org_id = tenant_id.to_string();
+        let mut client = self.client.clone();
+
+        let document = client
+            .upload_document(
+                &self.app_id,
+                &org_id,
+                SERVICE_USER_ID,
+                filename.to_string(),
+                "application/pdf".to_string(),
+                data,
+            )
+            .await?
+            .document
+            .ok_or_else(|| tonic::Status::internal("Document service returned no document"))?;
+
+        let signed = client
+            .generate_signed_url(
+                &self.app_id,
+                &org_id,
+                SERVICE_USER_ID,
+                document.id.clone(),
+                self.signed_url_ttl_seconds,
+            )
+            .await?;
+
+        Ok(StoredDocument {
+            document_id: document.id,
+            url: signed.url,
+            url_expires_at: signed.expires_at,
+        })
+    }
+
+    /// Download one of a tenant's documents.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, document_id = %document_id))]
+    pub async fn fetch(
+        &self,
+        tenant_id: Uuid,
+        document_id: Uuid,
+    ) -> Result<Vec<u8>, tonic::Status> {
+        let (_, _, data) = self
+            .client
+            .clone()
+            .download_document(
+                &self.app_id,
+                &tenant_id.to_string(),
+                SERVICE_USER_ID,
+                document_id.to_string(),
+            )
+            .await?;
+        Ok(data)
+    }
+}
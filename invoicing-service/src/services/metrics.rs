@@ -31,7 +31,7 @@ pub static INVOICES_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
     register_counter_vec!(
         "invoicing_invoices_total",
         "Total number of invoices by status",
-        &["status"] // draft, issued, paid, void
+        &["status"] // draft, issued, paid, void, credit_note
     )
     .expect("Failed to register invoices_total")
 });
@@ -87,6 +87,16 @@ pub static PAYMENT_AMOUNT_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
     .expect("Failed to register payment_amount_total")
 });
 
+/// Generated PDF counter by document type.
+pub static PDFS_GENERATED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "invoicing_pdfs_generated_total",
+        "Total number of PDFs generated",
+        &["document_type"]
+    )
+    .expect("Failed to register pdfs_generated_total")
+});
+
 /// Initialize all metrics (forces lazy initialization).
 pub fn init_metrics() {
     Lazy::force(&GRPC_REQUESTS_TOTAL);
@@ -97,6 +107,7 @@ pub fn init_metrics() {
     Lazy::force(&DB_QUERY_DURATION);
     Lazy::force(&INVOICE_AMOUNT_TOTAL);
     Lazy::force(&PAYMENT_AMOUNT_TOTAL);
+    Lazy::force(&PDFS_GENERATED_TOTAL);
 }
 
 /// Get metrics in Prometheus text format.
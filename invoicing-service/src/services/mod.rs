@@ -1,7 +1,10 @@
 //! Services module for invoicing-service.
 
 pub mod database;
+pub mod documents;
 pub mod metrics;
+pub mod tax;
 
 pub use database::Database;
+pub use documents::{DocumentStore, StoredDocument};
 pub use metrics::{get_metrics, init_metrics};
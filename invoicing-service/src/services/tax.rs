@@ -0,0 +1,123 @@
+//! Line item tax calculation.
+//!
+//! A line is taxed by at most one tax rate or one tax group. Every tax charged
+//! is kept per rate or component so invoices, statements and PDFs can show a
+//! breakdown; the line's `tax_amount` is always the sum of its taxes.
+
+use crate::models::{
+    Invoice, LineItemTax, PlaceOfSupply, TaxComponentKind, TaxGroup, TaxRate, TenantProfile,
+};
+use rust_decimal::Decimal;
+
+/// Place of supply for an invoice, comparing its billing address with the
+/// tenant's registered address. The supply is inter-state when the countries
+/// or states differ; a side that is not set is treated as matching.
+pub fn place_of_supply(profile: Option<&TenantProfile>, invoice: &Invoice) -> PlaceOfSupply {
+    fn matches(registered: Option<&str>, billed: Option<&str>) -> bool {
+        let registered = registered.map(str::trim).filter(|s| !s.is_empty());
+        let billed = billed.map(str::trim).filter(|s| !s.is_empty());
+        match (registered, billed) {
+            (Some(registered), Some(billed)) => registered.eq_ignore_ascii_case(billed),
+            _ => true,
+        }
+    }
+
+    let Some(profile) = profile else {
+        return PlaceOfSupply::IntraState;
+    };
+    let same_country = matches(
+        profile.address_country.as_deref(),
+        invoice.billing_country.as_deref(),
+    );
+    let same_state = matches(
+        profile.address_state.as_deref(),
+        invoice.billing_state.as_deref(),
+    );
+    if same_country && same_state {
+        PlaceOfSupply::IntraState
+    } else {
+        PlaceOfSupply::InterState
+    }
+}
+
+/// Tax on a line subtotal under a single tax rate.
+pub fn rate_taxes(tax_rate: &TaxRate, subtotal: Decimal) -> Vec<LineItemTax> {
+    let tax_amount = if tax_rate.calculation == "inclusive" {
+        subtotal - (subtotal / (Decimal::ONE + tax_rate.rate))
+    } else {
+        subtotal * tax_rate.rate
+    };
+    let taxable_amount = if tax_rate.calculation == "inclusive" {
+        subtotal - tax_amount
+    } else {
+        subtotal
+    };
+    vec![LineItemTax {
+        name: tax_rate.name.clone(),
+        kind: TaxComponentKind::Standard.as_str().to_string(),
+        rate: tax_rate.rate,
+        taxable_amount,
+        tax_amount,
+    }]
+}
+
+/// Taxes on a line subtotal under a tax group, for the components that apply
+/// to the place of supply. Compound components are charged on the subtotal
+/// plus the taxes before them; withholding is negative.
+pub fn group_taxes(
+    tax_group: &TaxGroup,
+    subtotal: Decimal,
+    place_of_supply: PlaceOfSupply,
+) -> Vec<LineItemTax> {
+    let mut taxes: Vec<LineItemTax> = Vec::new();
+    for component in tax_group
+        .components
+        .iter()
+        .filter(|c| c.applies(place_of_supply))
+    {
+        let kind = TaxComponentKind::from_string(&component.kind);
+        let taxable_amount = match kind {
+            TaxComponentKind::Compound => {
+                subtotal
+                    + taxes
+                        .iter()
+                        .filter(|t| t.tax_amount > Decimal::ZERO)
+                        .map(|t| t.tax_amount)
+                        .sum::<Decimal>()
+            }
+            _ => subtotal,
+        };
+        let tax_amount = (taxable_amount * component.rate).round_dp(4);
+        taxes.push(LineItemTax {
+            name: component.name.clone(),
+            kind: kind.as_str().to_string(),
+            rate: component.rate,
+            taxable_amount,
+            tax_amount: if kind == TaxComponentKind::Withholding {
+                -tax_amount
+            } else {
+                tax_amount
+            },
+        });
+    }
+    taxes
+}
+
+/// Taxes for a share of a line, as on a credit note. Amounts are scaled and
+/// rounded, and the last tax absorbs the rounding so the taxes still sum to
+/// `tax_amount`.
+pub fn scale_taxes(taxes: &[LineItemTax], share: Decimal, tax_amount: Decimal) -> Vec<LineItemTax> {
+    let mut scaled: Vec<LineItemTax> = taxes
+        .iter()
+        .map(|tax| LineItemTax {
+            taxable_amount: (tax.taxable_amount * share).round_dp(4),
+            tax_amount: (tax.tax_amount * share).round_dp(4),
+            ..tax.clone()
+        })
+        .collect();
+    let assigned: Decimal = scaled.iter().map(|t| t.tax_amount).sum();
+    if let Some(last) = scaled.last_mut() {
+        last.tax_amount += tax_amount - assigned;
+    }
+    scaled
+}
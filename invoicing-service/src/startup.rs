@@ -5,14 +5,14 @@ use crate::grpc::{
     proto::{invoicing_service_server::InvoicingServiceServer, FILE_DESCRIPTOR_SET},
     InvoicingServiceImpl,
 };
-use crate::services::{get_metrics, init_metrics, Database};
+use crate::services::{get_metrics, init_metrics, Database, DocumentStore};
 use axum::{
     extract::State, http::StatusCode, middleware, response::IntoResponse, routing::get, Json,
     Router,
 };
 use serde_json::json;
 use service_core::error::AppError;
-use service_core::grpc::LedgerClient;
+use service_core::grpc::{DocumentClient, LedgerClient};
 use service_core::middleware::metrics::metrics_middleware;
 use service_core::middleware::tracing::request_id_middleware;
 use std::net::SocketAddr;
@@ -28,6 +28,7 @@ pub struct AppState {
     pub config: InvoicingConfig,
     pub db: Arc<Database>,
     pub ledger_client: Option<Arc<LedgerClient>>,
+    pub document_store: Option<DocumentStore>,
 }
 
 /// State for health check endpoints.
@@ -157,10 +158,34 @@ impl Application {
             }
         };
 
+        // Try to connect to document service (optional - PDF generation is unavailable without it)
+        let document_store = match DocumentClient::connect(&config.document_service.url).await {
+            Ok(client) => {
+                tracing::info!(
+                    document_service_url = %config.document_service.url,
+                    "Connected to document service"
+                );
+                Some(DocumentStore::new(
+                    client,
+                    config.service_name.clone(),
+                    config.document_service.signed_url_ttl_seconds,
+                ))
+            }
+            Err(e) => {
+                tracing::warn!(
+                    document_service_url = %config.document_service.url,
+                    error = %e,
+                    "Failed to connect to document service - PDF generation disabled"
+                );
+                None
+            }
+        };
+
         let state = AppState {
             config: config.clone(),
             db,
             ledger_client,
+            document_store,
         };
 
         // Bind HTTP listener
@@ -225,14 +250,17 @@ impl Application {
             .layer(middleware::from_fn(request_id_middleware))
             .with_state(health_state);
 
-        // Build gRPC server with optional ledger client
-        let invoicing_service = match self.state.ledger_client {
+        // Build gRPC server with optional ledger client and document storage
+        let mut invoicing_service = match self.state.ledger_client {
             Some(ref ledger_client) => InvoicingServiceImpl::with_ledger_client(
                 self.state.db.clone(),
                 ledger_client.clone(),
             ),
             None => InvoicingServiceImpl::new(self.state.db.clone()),
         };
+        if let Some(document_store) = &self.state.document_store {
+            invoicing_service = invoicing_service.with_document_store(document_store.clone());
+        }
 
         // gRPC health service
         let (mut health_reporter, grpc_health_service) = tonic_health::server::health_reporter();
@@ -4,7 +4,9 @@
 
 #![allow(dead_code)]
 
-use invoicing_service::config::{DatabaseConfig, InvoicingConfig, LedgerServiceConfig};
+use invoicing_service::config::{
+    DatabaseConfig, DocumentServiceConfig, InvoicingConfig, LedgerServiceConfig,
+};
 use invoicing_service::services::{init_metrics, Database};
 use invoicing_service::startup::Application;
 use service_core::config::Config as CoreConfig;
@@ -92,6 +94,10 @@ impl TestApp {
             ledger_service: LedgerServiceConfig {
                 url: "http://localhost:50052".to_string(), // May not be available in tests
             },
+            document_service: DocumentServiceConfig {
+                url: "http://localhost:50053".to_string(), // Not available in tests
+                signed_url_ttl_seconds: 3600,
+            },
         };
 
         let app = Application::build(config)
@@ -0,0 +1,377 @@
+//! Credit note integration tests for invoicing-service.
+
+mod common;
+
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use invoicing_service::grpc::proto::{
+    invoicing_service_client::InvoicingServiceClient, AddLineItemRequest, CreateCreditNoteRequest,
+    CreateInvoiceRequest, CreateTaxRateRequest, CreditNoteLine, GenerateStatementRequest, Invoice,
+    InvoiceStatus, InvoiceType, IssueInvoiceRequest, LineItemType, RecordPaymentRequest,
+    TaxCalculation, VoidInvoiceRequest,
+};
+use tonic::transport::Channel;
+
+/// Helper to create a draft invoice with `(description, quantity, unit_price)`
+/// lines, optionally taxed at `tax_rate_id`.
+async fn create_draft_invoice(
+    client: &mut InvoicingServiceClient<Channel>,
+    lines: &[(&str, &str, &str)],
+    tax_rate_id: &str,
+) -> String {
+    let invoice_id = client
+        .create_invoice(with_tenant(
+            TEST_TENANT_ID,
+            CreateInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_type: InvoiceType::Standard as i32,
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                customer_name: "Credit Customer".to_string(),
+                currency: "USD".to_string(),
+                due_date: "2026-02-28".to_string(),
+                metadata: "{}".to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to create invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+        .invoice_id;
+
+    for (sort_order, (description, quantity, unit_price)) in lines.iter().enumerate() {
+        client
+            .add_line_item(with_tenant(
+                TEST_TENANT_ID,
+                AddLineItemRequest {
+                    tenant_id: TEST_TENANT_ID.to_string(),
+                    invoice_id: invoice_id.clone(),
+                    description: description.to_string(),
+                    quantity: quantity.to_string(),
+                    unit_price: unit_price.to_string(),
+                    tax_rate_id: tax_rate_id.to_string(),
+                    ledger_account_id: String::new(),
+                    sort_order: sort_order as i32,
+                    tax_group_id: String::new(),
+                    line_type: LineItemType::Charge as i32,
+                },
+            ))
+            .await
+            .expect("Failed to add line item");
+    }
+
+    invoice_id
+}
+
+/// Helper to create and issue an invoice, returning it with its line items.
+async fn create_issued_invoice(
+    client: &mut InvoicingServiceClient<Channel>,
+    lines: &[(&str, &str, &str)],
+    tax_rate_id: &str,
+) -> Invoice {
+    let invoice_id = create_draft_invoice(client, lines, tax_rate_id).await;
+    client
+        .issue_invoice(with_tenant(
+            TEST_TENANT_ID,
+            IssueInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id,
+                issue_date: "2026-01-10".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to issue invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+}
+
+async fn credit(
+    client: &mut InvoicingServiceClient<Channel>,
+    invoice_id: &str,
+    lines: &[(&str, &str)],
+    refundable: bool,
+) -> Result<(Invoice, Invoice), tonic::Status> {
+    let response = client
+        .create_credit_note(with_tenant(
+            TEST_TENANT_ID,
+            CreateCreditNoteRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice_id.to_string(),
+                lines: lines
+                    .iter()
+                    .map(|(line_item_id, quantity)| CreditNoteLine {
+                        line_item_id: line_item_id.to_string(),
+                        quantity: quantity.to_string(),
+                    })
+                    .collect(),
+                full: lines.is_empty(),
+                refundable,
+                issue_date: "2026-01-20".to_string(),
+                notes: String::new(),
+            },
+        ))
+        .await?
+        .into_inner();
+    Ok((
+        response.credit_note.expect("Missing credit note"),
+        response.invoice.expect("Missing invoice"),
+    ))
+}
+
+#[tokio::test]
+async fn partial_credits_are_capped_per_line_and_reduce_amount_due() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let tax_rate_id = client
+        .create_tax_rate(with_tenant(
+            TEST_TENANT_ID,
+            CreateTaxRateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                name: "VAT 10%".to_string(),
+                rate: "0.10".to_string(),
+                calculation: TaxCalculation::Exclusive as i32,
+                effective_from: "2026-01-01".to_string(),
+                effective_to: String::new(),
+            },
+        ))
+        .await
+        .expect("Failed to create tax rate")
+        .into_inner()
+        .tax_rate
+        .expect("Missing tax rate")
+        .tax_rate_id;
+
+    let invoice = create_issued_invoice(
+        &mut client,
+        &[("Widgets", "10", "10.00"), ("Setup", "1", "50.00")],
+        &tax_rate_id,
+    )
+    .await;
+    assert_eq!(invoice.total, "165");
+    let widgets = invoice.line_items[0].line_item_id.clone();
+    let setup = invoice.line_items[1].line_item_id.clone();
+
+    let (credit_note, updated) =
+        credit(&mut client, &invoice.invoice_id, &[(&widgets, "4")], false)
+            .await
+            .expect("Failed to create credit note");
+    assert_eq!(credit_note.invoice_type, InvoiceType::CreditNote as i32);
+    assert_eq!(credit_note.status, InvoiceStatus::Issued as i32);
+    assert!(credit_note.invoice_number.starts_with("CN-"));
+    assert_eq!(credit_note.reference_invoice_id, invoice.invoice_id);
+    assert_eq!(credit_note.issue_date, "2026-01-20");
+    assert_eq!(credit_note.subtotal, "40");
+    assert_eq!(credit_note.tax_total, "4");
+    assert_eq!(credit_note.total, "44");
+    assert_eq!(credit_note.amount_due, "0");
+    assert_eq!(credit_note.line_items.len(), 1);
+    assert_eq!(credit_note.line_items[0].reference_line_item_id, widgets);
+    assert_eq!(credit_note.line_items[0].quantity, "4");
+    assert_eq!(updated.amount_credited, "44");
+    assert_eq!(updated.amount_due, "121");
+
+    let over = credit(&mut client, &invoice.invoice_id, &[(&widgets, "7")], false)
+        .await
+        .expect_err("Only 6 widgets remain to be credited");
+    assert_eq!(over.code(), tonic::Code::FailedPrecondition);
+
+    let unknown = credit(
+        &mut client,
+        &invoice.invoice_id,
+        &[(&credit_note.line_items[0].line_item_id, "1")],
+        false,
+    )
+    .await
+    .expect_err("Line is not on the invoice");
+    assert_eq!(unknown.code(), tonic::Code::FailedPrecondition);
+
+    // Crediting everything left settles the invoice
+    let (rest, updated) = credit(&mut client, &invoice.invoice_id, &[], false)
+        .await
+        .expect("Failed to credit remainder");
+    assert_eq!(rest.total, "121");
+    assert_eq!(rest.line_items.len(), 2);
+    assert_eq!(rest.line_items[0].quantity, "6");
+    assert_eq!(rest.line_items[1].reference_line_item_id, setup);
+    assert_eq!(updated.amount_credited, "165");
+    assert_eq!(updated.amount_due, "0");
+    assert_eq!(updated.status, InvoiceStatus::Paid as i32);
+
+    let again = credit(&mut client, &invoice.invoice_id, &[], true)
+        .await
+        .expect_err("Invoice is fully credited");
+    assert_eq!(again.code(), tonic::Code::FailedPrecondition);
+
+    // The statement shows both credit notes reducing the balance
+    let statement = client
+        .generate_statement(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-01-01".to_string(),
+                period_end: "2026-01-31".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to generate statement")
+        .into_inner()
+        .statement
+        .expect("Missing statement");
+    let credits: Vec<_> = statement
+        .lines
+        .iter()
+        .filter(|line| line.document_type == "credit_note")
+        .collect();
+    assert_eq!(credits.len(), 2);
+    assert!(credits[0].description.starts_with("Credit note CN-"));
+    assert_eq!(statement.closing_balance, "0");
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn credit_beyond_balance_requires_refundable() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let invoice = create_issued_invoice(&mut client, &[("Consulting", "2", "50.00")], "").await;
+    client
+        .record_payment(with_tenant(
+            TEST_TENANT_ID,
+            RecordPaymentRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice.invoice_id.clone(),
+                amount: "60.00".to_string(),
+                payment_method: "card".to_string(),
+                payment_reference: String::new(),
+                payment_date: "2026-01-15".to_string(),
+                notes: String::new(),
+            },
+        ))
+        .await
+        .expect("Failed to record payment");
+
+    let line = invoice.line_items[0].line_item_id.clone();
+    let refused = credit(&mut client, &invoice.invoice_id, &[(&line, "1")], false)
+        .await
+        .expect_err("Credit exceeds the remaining balance of 40");
+    assert_eq!(refused.code(), tonic::Code::FailedPrecondition);
+
+    let (credit_note, updated) = credit(&mut client, &invoice.invoice_id, &[(&line, "1")], true)
+        .await
+        .expect("Failed to create refundable credit note");
+    assert_eq!(credit_note.total, "50");
+    assert_eq!(credit_note.amount_due, "10");
+    assert_eq!(updated.amount_credited, "40");
+    assert_eq!(updated.amount_due, "0");
+    assert_eq!(updated.status, InvoiceStatus::Paid as i32);
+
+    // Neither document can be voided once credited, and credit notes take no payments
+    for invoice_id in [&invoice.invoice_id, &credit_note.invoice_id] {
+        let void = client
+            .void_invoice(with_tenant(
+                TEST_TENANT_ID,
+                VoidInvoiceRequest {
+                    tenant_id: TEST_TENANT_ID.to_string(),
+                    invoice_id: invoice_id.clone(),
+                    reason: String::new(),
+                },
+            ))
+            .await
+            .expect_err("Void should be rejected");
+        assert_eq!(void.code(), tonic::Code::FailedPrecondition);
+    }
+
+    let payment = client
+        .record_payment(with_tenant(
+            TEST_TENANT_ID,
+            RecordPaymentRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: credit_note.invoice_id.clone(),
+                amount: "10.00".to_string(),
+                payment_method: "card".to_string(),
+                payment_reference: String::new(),
+                payment_date: "2026-01-21".to_string(),
+                notes: String::new(),
+            },
+        ))
+        .await
+        .expect_err("Credit notes take no payments");
+    assert_eq!(payment.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn create_credit_note_validation() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let draft = create_draft_invoice(&mut client, &[("Item", "1", "10.00")], "").await;
+    let not_issued = credit(&mut client, &draft, &[], false)
+        .await
+        .expect_err("Drafts can't be credited");
+    assert_eq!(not_issued.code(), tonic::Code::FailedPrecondition);
+
+    let missing = credit(&mut client, &uuid::Uuid::new_v4().to_string(), &[], false)
+        .await
+        .expect_err("Invoice should not exist");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    let invoice = create_issued_invoice(&mut client, &[("Item", "3", "10.00")], "").await;
+    let line = invoice.line_items[0].line_item_id.clone();
+    for (lines, full) in [
+        (vec![], false),
+        (
+            vec![CreditNoteLine {
+                line_item_id: line.clone(),
+                quantity: "1".to_string(),
+            }],
+            true,
+        ),
+        (
+            vec![CreditNoteLine {
+                line_item_id: line.clone(),
+                quantity: "0".to_string(),
+            }],
+            false,
+        ),
+        (
+            vec![CreditNoteLine {
+                line_item_id: "not-a-uuid".to_string(),
+                quantity: "1".to_string(),
+            }],
+            false,
+        ),
+    ] {
+        let status = client
+            .create_credit_note(with_tenant(
+                TEST_TENANT_ID,
+                CreateCreditNoteRequest {
+                    tenant_id: TEST_TENANT_ID.to_string(),
+                    invoice_id: invoice.invoice_id.clone(),
+                    lines,
+                    full,
+                    ..Default::default()
+                },
+            ))
+            .await
+            .expect_err("Request should be rejected");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    let duplicate = credit(
+        &mut client,
+        &invoice.invoice_id,
+        &[(&line, "1"), (&line, "1")],
+        false,
+    )
+    .await
+    .expect_err("A line can be credited once per credit note");
+    assert_eq!(duplicate.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,452 @@
+//! Customer payment allocation integration tests for invoicing-service.
+
+mod common;
+
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use invoicing_service::grpc::proto::{
+    invoicing_service_client::InvoicingServiceClient, AddLineItemRequest, AllocatePaymentRequest,
+    AllocatePaymentResponse, CreateCustomerPaymentRequest, CreateInvoiceRequest, CustomerPayment,
+    GenerateStatementRequest, GetCustomerPaymentRequest, Invoice, InvoiceStatus, InvoiceType,
+    IssueInvoiceRequest, ListCustomerPaymentsRequest, ListReceiptsRequest, PaymentAllocation,
+    UnallocatePaymentRequest,
+};
+use tonic::transport::Channel;
+
+/// Helper to create and issue a single-line invoice for the test customer.
+async fn create_issued_invoice(
+    client: &mut InvoicingServiceClient<Channel>,
+    amount: &str,
+    currency: &str,
+) -> Invoice {
+    let invoice_id = client
+        .create_invoice(with_tenant(
+            TEST_TENANT_ID,
+            CreateInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_type: InvoiceType::Standard as i32,
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                customer_name: "Payment Customer".to_string(),
+                currency: currency.to_string(),
+                due_date: "2099-12-31".to_string(),
+                metadata: "{}".to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to create invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+        .invoice_id;
+
+    client
+        .add_line_item(with_tenant(
+            TEST_TENANT_ID,
+            AddLineItemRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice_id.clone(),
+                description: "Services".to_string(),
+                quantity: "1".to_string(),
+                unit_price: amount.to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to add line item");
+
+    client
+        .issue_invoice(with_tenant(
+            TEST_TENANT_ID,
+            IssueInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id,
+                issue_date: "2026-01-10".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to issue invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+}
+
+/// Helper to record a customer payment.
+async fn create_payment(
+    client: &mut InvoicingServiceClient<Channel>,
+    amount: &str,
+    currency: &str,
+) -> CustomerPayment {
+    client
+        .create_customer_payment(with_tenant(
+            TEST_TENANT_ID,
+            CreateCustomerPaymentRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                amount: amount.to_string(),
+                currency: currency.to_string(),
+                payment_method: "bank_transfer".to_string(),
+                payment_reference: "TRX-4411".to_string(),
+                payment_date: "2026-01-20".to_string(),
+                notes: String::new(),
+            },
+        ))
+        .await
+        .expect("Failed to create customer payment")
+        .into_inner()
+        .payment
+        .expect("Missing payment")
+}
+
+async fn allocate(
+    client: &mut InvoicingServiceClient<Channel>,
+    payment_id: &str,
+    allocations: &[(&str, &str)],
+) -> Result<AllocatePaymentResponse, tonic::Status> {
+    client
+        .allocate_payment(with_tenant(
+            TEST_TENANT_ID,
+            AllocatePaymentRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                payment_id: payment_id.to_string(),
+                allocations: allocations
+                    .iter()
+                    .map(|(invoice_id, amount)| PaymentAllocation {
+                        invoice_id: invoice_id.to_string(),
+                        amount: amount.to_string(),
+                    })
+                    .collect(),
+            },
+        ))
+        .await
+        .map(|response| response.into_inner())
+}
+
+async fn get_invoice(client: &mut InvoicingServiceClient<Channel>, invoice_id: &str) -> Invoice {
+    client
+        .get_invoice(with_tenant(
+            TEST_TENANT_ID,
+            invoicing_service::grpc::proto::GetInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice_id.to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to get invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+}
+
+#[tokio::test]
+async fn allocate_payment_across_invoices_leaves_unapplied_cash() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let first = create_issued_invoice(&mut client, "300.00", "USD").await;
+    let second = create_issued_invoice(&mut client, "200.00", "USD").await;
+    let payment = create_payment(&mut client, "600.00", "USD").await;
+    assert!(payment.payment_number.starts_with("PAY-"));
+    assert_eq!(payment.amount_unapplied, "600");
+    assert!(payment.allocations.is_empty());
+
+    let response = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[
+            (&first.invoice_id, "300.00"),
+            (&second.invoice_id, "150.00"),
+        ],
+    )
+    .await
+    .expect("Failed to allocate payment");
+
+    assert_eq!(response.receipts.len(), 2);
+    assert!(response
+        .receipts
+        .iter()
+        .all(|r| r.payment_id == payment.payment_id && r.payment_method == "bank_transfer"));
+    let payment = response.payment.expect("Missing payment");
+    assert_eq!(payment.amount_allocated, "450");
+    assert_eq!(payment.amount_unapplied, "150");
+    assert_eq!(payment.allocations.len(), 2);
+
+    let first = get_invoice(&mut client, &first.invoice_id).await;
+    assert_eq!(first.status, InvoiceStatus::Paid as i32);
+    assert_eq!(first.amount_due, "0");
+    let second = get_invoice(&mut client, &second.invoice_id).await;
+    assert_eq!(second.status, InvoiceStatus::Issued as i32);
+    assert_eq!(second.amount_paid, "150");
+    assert_eq!(second.amount_due, "50");
+
+    // The allocation is a receipt against the invoice
+    let receipts = client
+        .list_receipts(with_tenant(
+            TEST_TENANT_ID,
+            ListReceiptsRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: second.invoice_id.clone(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to list receipts")
+        .into_inner()
+        .receipts;
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].amount, "150");
+    assert_eq!(receipts[0].payment_id, payment.payment_id);
+
+    // The rest can be allocated later
+    let response = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&second.invoice_id, "50")],
+    )
+    .await
+    .expect("Failed to allocate remaining balance");
+    let payment = response.payment.expect("Missing payment");
+    assert_eq!(payment.amount_unapplied, "100");
+
+    let payments = client
+        .list_customer_payments(with_tenant(
+            TEST_TENANT_ID,
+            ListCustomerPaymentsRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                unapplied_only: true,
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to list customer payments")
+        .into_inner()
+        .payments;
+    assert_eq!(payments.len(), 1);
+    assert_eq!(payments[0].payment_id, payment.payment_id);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn allocate_payment_rejects_invalid_allocations() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let invoice = create_issued_invoice(&mut client, "300.00", "USD").await;
+    let other = create_issued_invoice(&mut client, "100.00", "USD").await;
+    let euro_invoice = create_issued_invoice(&mut client, "100.00", "EUR").await;
+    let payment = create_payment(&mut client, "200.00", "USD").await;
+
+    // More than the payment's unapplied cash
+    let err = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&invoice.invoice_id, "150"), (&other.invoice_id, "100")],
+    )
+    .await
+    .expect_err("allocations exceed the payment");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    // More than the invoice's amount due
+    let err = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&other.invoice_id, "150")],
+    )
+    .await
+    .expect_err("allocation exceeds amount due");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    // The same invoice twice
+    let err = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&invoice.invoice_id, "10"), (&invoice.invoice_id, "10")],
+    )
+    .await
+    .expect_err("invoice allocated twice");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    // A different currency
+    let err = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&euro_invoice.invoice_id, "10")],
+    )
+    .await
+    .expect_err("currency mismatch");
+    assert_eq!(err.code(), tonic::Code::FailedPrecondition);
+
+    let err = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&invoice.invoice_id, "0")],
+    )
+    .await
+    .expect_err("amount must be positive");
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let err = allocate(
+        &mut client,
+        &uuid::Uuid::new_v4().to_string(),
+        &[(&invoice.invoice_id, "10")],
+    )
+    .await
+    .expect_err("payment must exist");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    // Nothing was allocated by the failed requests
+    let payment = client
+        .get_customer_payment(with_tenant(
+            TEST_TENANT_ID,
+            GetCustomerPaymentRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                payment_id: payment.payment_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to get customer payment")
+        .into_inner()
+        .payment
+        .expect("Missing payment");
+    assert_eq!(payment.amount_unapplied, "200");
+    assert!(payment.allocations.is_empty());
+    assert_eq!(
+        get_invoice(&mut client, &invoice.invoice_id)
+            .await
+            .amount_due,
+        "300"
+    );
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn unallocate_payment_restores_invoice_balance() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let invoice = create_issued_invoice(&mut client, "300.00", "USD").await;
+    let payment = create_payment(&mut client, "300.00", "USD").await;
+    let receipt = allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&invoice.invoice_id, "300")],
+    )
+    .await
+    .expect("Failed to allocate payment")
+    .receipts
+    .remove(0);
+    assert_eq!(
+        get_invoice(&mut client, &invoice.invoice_id).await.status,
+        InvoiceStatus::Paid as i32
+    );
+
+    let unallocate = |receipt_id: String| UnallocatePaymentRequest {
+        tenant_id: TEST_TENANT_ID.to_string(),
+        payment_id: payment.payment_id.clone(),
+        receipt_id,
+    };
+
+    let response = client
+        .unallocate_payment(with_tenant(
+            TEST_TENANT_ID,
+            unallocate(receipt.receipt_id.clone()),
+        ))
+        .await
+        .expect("Failed to unallocate payment")
+        .into_inner();
+    let invoice = response.invoice.expect("Missing invoice");
+    assert_eq!(invoice.status, InvoiceStatus::Issued as i32);
+    assert_eq!(invoice.amount_paid, "0");
+    assert_eq!(invoice.amount_due, "300");
+    let payment_after = response.payment.expect("Missing payment");
+    assert_eq!(payment_after.amount_unapplied, "300");
+    assert!(payment_after.allocations.is_empty());
+
+    let err = client
+        .unallocate_payment(with_tenant(
+            TEST_TENANT_ID,
+            unallocate(receipt.receipt_id.clone()),
+        ))
+        .await
+        .expect_err("allocation was already removed");
+    assert_eq!(err.code(), tonic::Code::NotFound);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn statement_counts_payment_once_and_reports_unapplied_cash() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let first = create_issued_invoice(&mut client, "300.00", "USD").await;
+    let second = create_issued_invoice(&mut client, "200.00", "USD").await;
+    let payment = create_payment(&mut client, "600.00", "USD").await;
+    allocate(
+        &mut client,
+        &payment.payment_id,
+        &[(&first.invoice_id, "300"), (&second.invoice_id, "150")],
+    )
+    .await
+    .expect("Failed to allocate payment");
+
+    let statement = client
+        .generate_statement(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-01-01".to_string(),
+                period_end: "2026-01-31".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to generate statement")
+        .into_inner()
+        .statement
+        .expect("Missing statement");
+
+    // Two invoices and one payment line; allocation receipts aren't repeated
+    assert_eq!(statement.lines.len(), 3);
+    assert_eq!(statement.total_debits, "500");
+    assert_eq!(statement.total_credits, "600");
+    assert_eq!(statement.closing_balance, "-100");
+    assert_eq!(statement.unapplied_cash, "150");
+
+    let payment_line = statement
+        .lines
+        .iter()
+        .find(|line| line.document_type == "payment")
+        .expect("Missing payment line");
+    assert_eq!(payment_line.document_number, payment.payment_number);
+    assert!(payment_line
+        .description
+        .contains(first.invoice_number.as_str()));
+    assert!(payment_line
+        .description
+        .contains(second.invoice_number.as_str()));
+    assert!(payment_line.description.contains("150 unapplied"));
+
+    // The payment counts in the next period's opening balance
+    let next = client
+        .generate_statement(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-02-01".to_string(),
+                period_end: "2026-02-28".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to generate statement")
+        .into_inner()
+        .statement
+        .expect("Missing statement");
+    assert_eq!(next.opening_balance, "-100");
+    assert_eq!(next.unapplied_cash, "150");
+
+    app.cleanup().await;
+}
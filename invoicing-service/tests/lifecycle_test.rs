@@ -6,7 +6,7 @@ mod common;
 use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
 use invoicing_service::grpc::proto::{
     AddLineItemRequest, Address, CreateInvoiceRequest, InvoiceStatus, InvoiceType,
-    IssueInvoiceRequest, UpdateInvoiceRequest, VoidInvoiceRequest,
+    IssueInvoiceRequest, LineItemType, UpdateInvoiceRequest, VoidInvoiceRequest,
 };
 
 /// Helper to create a billing address for tests.
@@ -76,6 +76,8 @@ async fn add_line_item(
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
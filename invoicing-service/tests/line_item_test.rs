@@ -5,7 +5,7 @@ mod common;
 use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
 use invoicing_service::grpc::proto::{
     AddLineItemRequest, Address, CreateInvoiceRequest, GetInvoiceRequest, InvoiceType,
-    IssueInvoiceRequest, RemoveLineItemRequest, UpdateLineItemRequest,
+    IssueInvoiceRequest, LineItemType, RemoveLineItemRequest, UpdateLineItemRequest,
 };
 
 /// Helper to create a billing address for tests.
@@ -71,6 +71,8 @@ async fn add_line_item_succeeds() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -112,6 +114,8 @@ async fn add_multiple_line_items() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 1,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -132,6 +136,8 @@ async fn add_multiple_line_items() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 2,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -182,6 +188,8 @@ async fn update_line_item_succeeds() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -208,6 +216,7 @@ async fn update_line_item_succeeds() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 1,
+            tax_group_id: String::new(),
         },
     );
 
@@ -248,6 +257,8 @@ async fn remove_line_item_succeeds() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 1,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -267,6 +278,8 @@ async fn remove_line_item_succeeds() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 2,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -325,6 +338,8 @@ async fn add_line_item_to_issued_invoice_fails() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -360,6 +375,8 @@ async fn add_line_item_to_issued_invoice_fails() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 1,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -389,6 +406,8 @@ async fn update_line_item_on_issued_invoice_fails() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -430,6 +449,7 @@ async fn update_line_item_on_issued_invoice_fails() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
         },
     );
 
@@ -459,6 +479,8 @@ async fn remove_line_item_from_issued_invoice_fails() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -522,6 +544,8 @@ async fn line_item_with_fractional_quantity() {
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -539,3 +563,47 @@ async fn line_item_with_fractional_quantity() {
 
     app.cleanup().await;
 }
+
+#[tokio::test]
+async fn credit_line_must_be_negative() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let invoice_id = create_draft_invoice(&mut client).await;
+
+    let credit_line = |unit_price: &str| {
+        with_tenant(
+            TEST_TENANT_ID,
+            AddLineItemRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice_id.clone(),
+                description: "Credit applied".to_string(),
+                quantity: "1".to_string(),
+                unit_price: unit_price.to_string(),
+                tax_rate_id: String::new(),
+                ledger_account_id: String::new(),
+                sort_order: 0,
+                tax_group_id: String::new(),
+                line_type: LineItemType::Credit as i32,
+            },
+        )
+    };
+
+    let status = client
+        .add_line_item(credit_line("20.00"))
+        .await
+        .expect_err("A positive credit line should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let line_item = client
+        .add_line_item(credit_line("-20.00"))
+        .await
+        .expect("Failed to add credit line")
+        .into_inner()
+        .line_item
+        .expect("Missing line item");
+    assert_eq!(line_item.line_type, LineItemType::Credit as i32);
+    assert_eq!(line_item.total, "-20");
+
+    app.cleanup().await;
+}
@@ -5,7 +5,7 @@ mod common;
 use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
 use invoicing_service::grpc::proto::{
     AddLineItemRequest, Address, CreateInvoiceRequest, GetReceiptRequest, InvoiceStatus,
-    InvoiceType, IssueInvoiceRequest, ListReceiptsRequest, RecordPaymentRequest,
+    InvoiceType, IssueInvoiceRequest, LineItemType, ListReceiptsRequest, RecordPaymentRequest,
 };
 
 /// Helper to create a billing address for tests.
@@ -67,6 +67,8 @@ async fn create_issued_invoice(
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
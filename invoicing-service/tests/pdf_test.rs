@@ -0,0 +1,663 @@
+//! PDF generation and tenant profile tests for invoicing-service.
+
+mod common;
+
+use chrono::{NaiveDate, Utc};
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use flate2::read::ZlibDecoder;
+use invoicing_service::grpc::proto::{
+    Address, CreateInvoiceRequest, GenerateInvoicePdfRequest, GenerateReceiptPdfRequest,
+    GenerateStatementPdfRequest, GetTenantProfileRequest, InvoiceType, SetTenantProfileRequest,
+};
+use invoicing_service::models::{
+    CustomerPayment, Invoice, LineItem, LineItemTax, Receipt, Statement, StatementLine,
+    TaxBreakdown, TaxRate, TenantProfile,
+};
+use invoicing_service::rendering::pdf::{Image, MAX_IMAGE_BYTES, MAX_IMAGE_DIMENSION};
+use invoicing_service::rendering::{
+    invoice_filename, receipt_filename, render_invoice, render_receipt, render_statement,
+    statement_filename, Branding, Letterhead,
+};
+use invoicing_service::services::tax::rate_taxes;
+use rust_decimal::Decimal;
+use std::io::Read;
+use std::str::FromStr;
+use uuid::Uuid;
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+}
+
+/// Extract the text drawn on every page, in order, by inflating each content
+/// stream and unescaping its string literals.
+fn pdf_text(pdf: &[u8]) -> String {
+    let mut text = String::new();
+    let mut rest = pdf;
+    while let Some(start) = find(rest, b"stream\n") {
+        let body = &rest[start + b"stream\n".len()..];
+        let end = find(body, b"\nendstream").expect("Unterminated stream");
+        let mut decoded = Vec::new();
+        if ZlibDecoder::new(&body[..end])
+            .read_to_end(&mut decoded)
+            .is_ok()
+        {
+            let decoded = String::from_utf8_lossy(&decoded);
+            text.push_str(&decoded.replace("\\(", "(").replace("\\)", ")"));
+        }
+        rest = &body[end + b"\nendstream".len()..];
+    }
+    text
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Check the file structure: header, trailer, and that every cross-reference
+/// entry points at the object it names.
+fn assert_well_formed(pdf: &[u8]) {
+    assert!(pdf.starts_with(b"%PDF-1."));
+    assert!(pdf.ends_with(b"%%EOF\n"));
+
+    let text = String::from_utf8_lossy(pdf);
+    let startxref: usize = text
+        .rsplit("startxref\n")
+        .next()
+        .and_then(|s| s.lines().next())
+        .and_then(|s| s.parse().ok())
+        .expect("Missing startxref");
+    let table = &pdf[startxref..];
+    assert!(table.starts_with(b"xref\n"));
+
+    let table = String::from_utf8_lossy(table);
+    let entries: Vec<&str> = table
+        .lines()
+        .skip(3)
+        .take_while(|l| l.ends_with(" n "))
+        .collect();
+    assert!(!entries.is_empty());
+    for (index, entry) in entries.iter().enumerate() {
+        let offset: usize = entry[..10].parse().unwrap();
+        let expected = format!("{} 0 obj", index + 1);
+        assert!(
+            pdf[offset..].starts_with(expected.as_bytes()),
+            "xref entry {} does not point at its object",
+            index + 1
+        );
+    }
+}
+
+fn page_count(pdf: &[u8]) -> usize {
+    String::from_utf8_lossy(pdf).matches("/Type /Page ").count()
+}
+
+fn letterhead() -> Letterhead {
+    Letterhead {
+        profile: Some(TenantProfile {
+            tenant_id: Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+            legal_name: "Acme Supplies Ltd".to_string(),
+            tax_id: Some("GB123456789".to_string()),
+            address_line1: Some("1 Market Street".to_string()),
+            address_line2: None,
+            address_city: Some("London".to_string()),
+            address_state: None,
+            address_postal_code: Some("EC1A 1AA".to_string()),
+            address_country: Some("GB".to_string()),
+            email: Some("billing@acme.test".to_string()),
+            phone: None,
+            logo_document_id: None,
+            created_utc: Utc::now(),
+            updated_utc: Utc::now(),
+        }),
+        logo: None,
+        branding: Branding::default(),
+    }
+}
+
+fn invoice() -> Invoice {
+    Invoice {
+        invoice_id: Uuid::new_v4(),
+        tenant_id: Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+        invoice_number: Some("INV-202603-0007".to_string()),
+        invoice_type: "standard".to_string(),
+        status: "issued".to_string(),
+        customer_id: Uuid::parse_str(TEST_CUSTOMER_ID).unwrap(),
+        customer_name: "Globex Corporation".to_string(),
+        billing_line1: Some("42 Industrial Way".to_string()),
+        billing_line2: None,
+        billing_city: Some("Springfield".to_string()),
+        billing_state: Some("IL".to_string()),
+        billing_postal_code: Some("62701".to_string()),
+        billing_country: Some("US".to_string()),
+        currency: "USD".to_string(),
+        issue_date: Some(date("2026-03-01")),
+        due_date: Some(date("2026-03-31")),
+        subtotal: dec("1250.00"),
+        tax_total: dec("125.00"),
+        total: dec("1375.00"),
+        amount_paid: dec("375.00"),
+        amount_due: dec("1000.00"),
+        amount_credited: Decimal::ZERO,
+        notes: Some("Thank you for your business.".to_string()),
+        reference_invoice_id: None,
+        journal_id: None,
+        metadata: None,
+        created_utc: Utc::now(),
+        issued_utc: Some(Utc::now()),
+        voided_utc: None,
+    }
+}
+
+fn tax_rate(name: &str, rate: &str) -> TaxRate {
+    TaxRate {
+        tax_rate_id: Uuid::new_v4(),
+        tenant_id: Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+        name: name.to_string(),
+        rate: dec(rate),
+        calculation: "exclusive".to_string(),
+        effective_from: date("2026-01-01"),
+        effective_to: None,
+        active: true,
+        created_utc: Utc::now(),
+    }
+}
+
+fn line_item(
+    invoice: &Invoice,
+    description: &str,
+    quantity: &str,
+    unit_price: &str,
+    tax_rate: Option<&TaxRate>,
+) -> LineItem {
+    let subtotal = dec(quantity) * dec(unit_price);
+    let taxes = tax_rate
+        .map(|r| rate_taxes(r, subtotal))
+        .unwrap_or_default();
+    let tax_amount = taxes.iter().map(|t| t.tax_amount).sum();
+    LineItem {
+        line_item_id: Uuid::new_v4(),
+        invoice_id: invoice.invoice_id,
+        tenant_id: invoice.tenant_id,
+        description: description.to_string(),
+        quantity: dec(quantity),
+        unit_price: dec(unit_price),
+        tax_rate_id: tax_rate.map(|r| r.tax_rate_id),
+        tax_group_id: None,
+        tax_amount,
+        subtotal,
+        total: subtotal + tax_amount,
+        ledger_account_id: None,
+        reference_line_item_id: None,
+        sort_order: 0,
+        line_type: "charge".to_string(),
+        created_utc: Utc::now(),
+        taxes,
+    }
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+#[test]
+fn invoice_pdf_shows_issuer_customer_lines_and_tax_breakdown() {
+    let invoice = invoice();
+    let vat = tax_rate("VAT", "0.10");
+    let items = vec![
+        line_item(&invoice, "Consulting", "10", "100.00", Some(&vat)),
+        line_item(&invoice, "Support plan", "1", "250.00", Some(&vat)),
+    ];
+
+    let pdf = render_invoice(&letterhead(), &invoice, &items);
+    assert_well_formed(&pdf);
+    assert_eq!(page_count(&pdf), 1);
+
+    let text = pdf_text(&pdf);
+    assert!(text.contains("INVOICE"));
+    assert!(text.contains("INV-202603-0007"));
+    assert!(text.contains("Acme Supplies Ltd"));
+    assert!(text.contains("GB123456789"));
+    assert!(text.contains("Globex Corporation"));
+    assert!(text.contains("42 Industrial Way"));
+    assert!(text.contains("Consulting"));
+    assert!(text.contains("Support plan"));
+    assert!(text.contains("VAT (10%) on 1,250.00"));
+    assert!(text.contains("USD 125.00"));
+    assert!(text.contains("USD 1,375.00"));
+    assert!(text.contains("Amount paid"));
+    assert!(text.contains("USD 1,000.00"));
+    assert!(text.contains("Thank you for your business."));
+    assert!(text.contains("Page 1 of 1"));
+
+    assert_eq!(invoice_filename(&invoice), "INV-202603-0007.pdf");
+}
+
+#[test]
+fn invoice_pdf_shows_tax_components() {
+    let invoice = invoice();
+    let mut item = line_item(&invoice, "Consulting", "10", "100.00", None);
+    let component = |name: &str, kind: &str, rate: &str, tax: &str| LineItemTax {
+        name: name.to_string(),
+        kind: kind.to_string(),
+        rate: dec(rate),
+        taxable_amount: dec("1000.00"),
+        tax_amount: dec(tax),
+    };
+    item.taxes = vec![
+        component("CGST", "standard", "0.09", "90.00"),
+        component("SGST", "standard", "0.09", "90.00"),
+        component("TDS", "withholding", "0.02", "-20.00"),
+    ];
+    item.tax_amount = dec("160.00");
+
+    let text = pdf_text(&render_invoice(&letterhead(), &invoice, &[item]));
+    assert!(text.contains("CGST (9%) on 1,000.00"));
+    assert!(text.contains("SGST (9%) on 1,000.00"));
+    assert!(text.contains("TDS (2%) on 1,000.00"));
+    assert!(text.contains("USD 90.00"));
+    assert!(text.contains("USD -20.00"));
+}
+
+#[test]
+fn credit_note_and_draft_invoice_pdfs() {
+    let mut credit_note = invoice();
+    credit_note.invoice_type = "credit_note".to_string();
+    credit_note.amount_paid = Decimal::ZERO;
+    let pdf = render_invoice(&letterhead(), &credit_note, &[]);
+    let text = pdf_text(&pdf);
+    assert!(text.contains("CREDIT NOTE"));
+    assert!(!text.contains("Amount due"));
+    assert!(text.contains("(Refundable credit)"));
+
+    let mut credited = invoice();
+    credited.amount_credited = dec("200.00");
+    credited.amount_due = dec("800.00");
+    let text = pdf_text(&render_invoice(&letterhead(), &credited, &[]));
+    assert!(text.contains("(Amount credited)"));
+
+    let mut draft = invoice();
+    draft.invoice_number = None;
+    draft.status = "draft".to_string();
+    let pdf = render_invoice(&Letterhead::default(), &draft, &[]);
+    assert_well_formed(&pdf);
+    assert!(pdf_text(&pdf).contains("Draft"));
+    assert!(invoice_filename(&draft).starts_with("DRAFT-"));
+}
+
+#[test]
+fn long_invoice_paginates() {
+    let invoice = invoice();
+    let items: Vec<LineItem> = (1..=80)
+        .map(|i| line_item(&invoice, &format!("Line item {}", i), "1", "10.00", None))
+        .collect();
+
+    let pdf = render_invoice(&letterhead(), &invoice, &items);
+    assert_well_formed(&pdf);
+
+    let pages = page_count(&pdf);
+    assert!(pages > 1, "80 lines should not fit on one page");
+    let text = pdf_text(&pdf);
+    assert!(text.contains("Line item 1)"));
+    assert!(text.contains("Line item 80)"));
+    assert!(text.contains(&format!("Page {} of {}", pages, pages)));
+}
+
+#[test]
+fn receipt_pdf_references_invoice() {
+    let invoice = invoice();
+    let receipt = Receipt {
+        receipt_id: Uuid::new_v4(),
+        tenant_id: invoice.tenant_id,
+        receipt_number: "RCP-202603-0003".to_string(),
+        invoice_id: invoice.invoice_id,
+        customer_id: invoice.customer_id,
+        amount: dec("375.00"),
+        currency: "USD".to_string(),
+        payment_method: "bank_transfer".to_string(),
+        payment_reference: Some("TXN-9981".to_string()),
+        payment_date: date("2026-03-10"),
+        journal_id: None,
+        notes: None,
+        payment_id: None,
+        created_utc: Utc::now(),
+    };
+
+    let pdf = render_receipt(&letterhead(), &receipt, &invoice, None);
+    assert_well_formed(&pdf);
+
+    let text = pdf_text(&pdf);
+    assert!(text.contains("RCP-202603-0003"));
+    assert!(text.contains("INV-202603-0007"));
+    assert!(text.contains("USD 375.00"));
+    assert!(text.contains("TXN-9981"));
+    assert_eq!(receipt_filename(&receipt), "RCP-202603-0003.pdf");
+}
+
+#[test]
+fn receipt_pdf_shows_customer_payment() {
+    let invoice = invoice();
+    let payment = CustomerPayment {
+        payment_id: Uuid::new_v4(),
+        tenant_id: invoice.tenant_id,
+        payment_number: "PAY-202603-0002".to_string(),
+        customer_id: invoice.customer_id,
+        amount: dec("1000.00"),
+        amount_allocated: dec("375.00"),
+        currency: "USD".to_string(),
+        payment_method: "bank_transfer".to_string(),
+        payment_reference: None,
+        payment_date: date("2026-03-10"),
+        journal_id: None,
+        notes: None,
+        created_utc: Utc::now(),
+    };
+    let receipt = Receipt {
+        receipt_id: Uuid::new_v4(),
+        tenant_id: invoice.tenant_id,
+        receipt_number: "RCP-202603-0004".to_string(),
+        invoice_id: invoice.invoice_id,
+        customer_id: invoice.customer_id,
+        amount: dec("375.00"),
+        currency: "USD".to_string(),
+        payment_method: "bank_transfer".to_string(),
+        payment_reference: None,
+        payment_date: date("2026-03-10"),
+        journal_id: None,
+        notes: None,
+        payment_id: Some(payment.payment_id),
+        created_utc: Utc::now(),
+    };
+
+    let pdf = render_receipt(&letterhead(), &receipt, &invoice, Some(&payment));
+    assert_well_formed(&pdf);
+
+    let text = pdf_text(&pdf);
+    assert!(text.contains("Customer payment"));
+    assert!(text.contains("PAY-202603-0002"));
+    assert!(text.contains("USD 1,000.00"));
+    assert!(text.contains("Unapplied cash"));
+    assert!(text.contains("USD 625.00"));
+}
+
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut data = std::io::Cursor::new(Vec::new());
+    image::RgbaImage::new(width, height)
+        .write_to(&mut data, image::ImageOutputFormat::Png)
+        .unwrap();
+    data.into_inner()
+}
+
+#[test]
+fn logo_decoding_rejects_oversized_images() {
+    let logo = Image::decode(&png(64, 32)).expect("Small logo should decode");
+    assert_eq!(logo.aspect_ratio(), 2.0);
+
+    assert!(Image::decode(&png(MAX_IMAGE_DIMENSION + 1, 1)).is_err());
+    assert!(Image::decode(&png(1, MAX_IMAGE_DIMENSION + 1)).is_err());
+    assert!(Image::decode(&vec![0; MAX_IMAGE_BYTES + 1]).is_err());
+}
+
+#[test]
+fn statement_pdf_shows_balances() {
+    let statement = Statement {
+        tenant_id: Uuid::parse_str(TEST_TENANT_ID).unwrap(),
+        customer_id: Uuid::parse_str(TEST_CUSTOMER_ID).unwrap(),
+        customer_name: "Globex Corporation".to_string(),
+        billing_line1: Some("42 Industrial Way".to_string()),
+        billing_line2: None,
+        billing_city: Some("Springfield".to_string()),
+        billing_state: None,
+        billing_postal_code: None,
+        billing_country: None,
+        currency: "USD".to_string(),
+        period_start: date("2026-03-01"),
+        period_end: date("2026-03-31"),
+        opening_balance: dec("200.00"),
+        closing_balance: dec("1200.00"),
+        total_debits: dec("1375.00"),
+        total_credits: dec("375.00"),
+        unapplied_cash: dec("50.00"),
+        lines: vec![
+            StatementLine {
+                date: date("2026-03-01"),
+                document_type: "invoice".to_string(),
+                document_number: "INV-202603-0007".to_string(),
+                description: "Invoice INV-202603-0007".to_string(),
+                debit: dec("1375.00"),
+                credit: Decimal::ZERO,
+                balance: dec("1575.00"),
+            },
+            StatementLine {
+                date: date("2026-03-10"),
+                document_type: "payment".to_string(),
+                document_number: "RCP-202603-0003".to_string(),
+                description: "Payment - card".to_string(),
+                debit: Decimal::ZERO,
+                credit: dec("375.00"),
+                balance: dec("1200.00"),
+            },
+        ],
+        tax_breakdown: vec![TaxBreakdown {
+            name: "VAT".to_string(),
+            kind: "standard".to_string(),
+            rate: dec("0.10"),
+            taxable_amount: dec("1250.00"),
+            tax_amount: dec("125.00"),
+        }],
+        generated_utc: Utc::now(),
+    };
+
+    let pdf = render_statement(&letterhead(), &statement);
+    assert_well_formed(&pdf);
+
+    let text = pdf_text(&pdf);
+    assert!(text.contains("Opening balance"));
+    assert!(text.contains("INV-202603-0007"));
+    assert!(text.contains("RCP-202603-0003"));
+    assert!(text.contains("1,575.00"));
+    assert!(text.contains("USD 1,200.00"));
+    assert!(text.contains("Tax summary"));
+    assert!(text.contains("VAT (10%)"));
+    assert!(text.contains("Unapplied cash"));
+    assert_eq!(
+        statement_filename(&statement),
+        "STMT-22222222-20260301-20260331.pdf"
+    );
+}
+
+// ============================================================================
+// gRPC
+// ============================================================================
+
+#[tokio::test]
+async fn tenant_profile_roundtrip() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let not_found = client
+        .get_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            GetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+            },
+        ))
+        .await
+        .expect_err("Profile should not exist yet");
+    assert_eq!(not_found.code(), tonic::Code::NotFound);
+
+    let missing_name = client
+        .set_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            SetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("legal_name is required");
+    assert_eq!(missing_name.code(), tonic::Code::InvalidArgument);
+
+    let logo_document_id = Uuid::new_v4().to_string();
+    let set = client
+        .set_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            SetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                legal_name: "Acme Supplies Ltd".to_string(),
+                tax_id: "GB123456789".to_string(),
+                address: Some(Address {
+                    line1: "1 Market Street".to_string(),
+                    city: "London".to_string(),
+                    country: "GB".to_string(),
+                    ..Default::default()
+                }),
+                email: "billing@acme.test".to_string(),
+                phone: String::new(),
+                logo_document_id: logo_document_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to set tenant profile")
+        .into_inner()
+        .profile
+        .expect("Missing profile");
+    assert_eq!(set.legal_name, "Acme Supplies Ltd");
+
+    // Setting again replaces the profile
+    client
+        .set_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            SetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                legal_name: "Acme Supplies Group Ltd".to_string(),
+                tax_id: "GB123456789".to_string(),
+                logo_document_id: logo_document_id.clone(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to replace tenant profile");
+
+    let profile = client
+        .get_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            GetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to get tenant profile")
+        .into_inner()
+        .profile
+        .expect("Missing profile");
+    assert_eq!(profile.legal_name, "Acme Supplies Group Ltd");
+    assert_eq!(profile.tax_id, "GB123456789");
+    assert_eq!(profile.logo_document_id, logo_document_id);
+    assert_eq!(profile.address.unwrap_or_default().line1, "");
+    assert_eq!(profile.created_at, set.created_at);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn pdf_generation_requires_document_storage() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    // Unknown documents are reported before storage is needed
+    let missing_invoice = client
+        .generate_invoice_pdf(with_tenant(
+            TEST_TENANT_ID,
+            GenerateInvoicePdfRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: Uuid::new_v4().to_string(),
+            },
+        ))
+        .await
+        .expect_err("Invoice should not exist");
+    assert_eq!(missing_invoice.code(), tonic::Code::NotFound);
+
+    let missing_receipt = client
+        .generate_receipt_pdf(with_tenant(
+            TEST_TENANT_ID,
+            GenerateReceiptPdfRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                receipt_id: Uuid::new_v4().to_string(),
+            },
+        ))
+        .await
+        .expect_err("Receipt should not exist");
+    assert_eq!(missing_receipt.code(), tonic::Code::NotFound);
+
+    let bad_period = client
+        .generate_statement_pdf(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementPdfRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-03-31".to_string(),
+                period_end: "2026-03-01".to_string(),
+            },
+        ))
+        .await
+        .expect_err("Period is reversed");
+    assert_eq!(bad_period.code(), tonic::Code::InvalidArgument);
+
+    let invoice_id = client
+        .create_invoice(with_tenant(
+            TEST_TENANT_ID,
+            CreateInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_type: InvoiceType::Standard as i32,
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                customer_name: "Globex Corporation".to_string(),
+                billing_address: None,
+                currency: "USD".to_string(),
+                due_date: "2026-03-31".to_string(),
+                notes: String::new(),
+                reference_invoice_id: String::new(),
+                metadata: "{}".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to create invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+        .invoice_id;
+
+    // The test app has no document-service, so rendering can't be stored
+    let unavailable = client
+        .generate_invoice_pdf(with_tenant(
+            TEST_TENANT_ID,
+            GenerateInvoicePdfRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id,
+            },
+        ))
+        .await
+        .expect_err("Document storage is not configured");
+    assert_eq!(unavailable.code(), tonic::Code::FailedPrecondition);
+
+    let unavailable = client
+        .generate_statement_pdf(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementPdfRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-01-01".to_string(),
+                period_end: "2026-12-31".to_string(),
+            },
+        ))
+        .await
+        .expect_err("Document storage is not configured");
+    assert_eq!(unavailable.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
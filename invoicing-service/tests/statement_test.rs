@@ -5,7 +5,7 @@ mod common;
 use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
 use invoicing_service::grpc::proto::{
     AddLineItemRequest, Address, CreateInvoiceRequest, GenerateStatementRequest, InvoiceType,
-    IssueInvoiceRequest, RecordPaymentRequest,
+    IssueInvoiceRequest, LineItemType, RecordPaymentRequest,
 };
 
 /// Helper to create a billing address for tests.
@@ -68,6 +68,8 @@ async fn create_and_issue_invoice(
             tax_rate_id: String::new(),
             ledger_account_id: String::new(),
             sort_order: 0,
+            tax_group_id: String::new(),
+            line_type: LineItemType::Charge as i32,
         },
     );
 
@@ -0,0 +1,456 @@
+//! Tax group integration tests for invoicing-service.
+
+mod common;
+
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use invoicing_service::grpc::proto::{
+    invoicing_service_client::InvoicingServiceClient, AddLineItemRequest, Address,
+    CreateCreditNoteRequest, CreateInvoiceRequest, CreateTaxGroupRequest, CreditNoteLine,
+    GenerateStatementRequest, GetTaxGroupRequest, Invoice, InvoiceType, IssueInvoiceRequest,
+    ListTaxGroupsRequest, PlaceOfSupply, SetTenantProfileRequest, TaxAmount, TaxComponent,
+    TaxComponentKind, UpdateInvoiceRequest,
+};
+use invoicing_service::models::{PlaceOfSupply as Supply, TaxComponent as Component, TaxGroup};
+use invoicing_service::services::tax::group_taxes;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+fn component(
+    name: &str,
+    rate: &str,
+    kind: TaxComponentKind,
+    applies_to: PlaceOfSupply,
+) -> TaxComponent {
+    TaxComponent {
+        name: name.to_string(),
+        rate: rate.to_string(),
+        kind: kind as i32,
+        applies_to: applies_to as i32,
+    }
+}
+
+async fn create_tax_group(
+    client: &mut InvoicingServiceClient<Channel>,
+    name: &str,
+    components: Vec<TaxComponent>,
+) -> Result<String, tonic::Status> {
+    let response = client
+        .create_tax_group(with_tenant(
+            TEST_TENANT_ID,
+            CreateTaxGroupRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                name: name.to_string(),
+                components,
+            },
+        ))
+        .await?
+        .into_inner();
+    Ok(response.tax_group.expect("Missing tax group").tax_group_id)
+}
+
+/// GST 18%: CGST and SGST within the state, IGST across states.
+async fn create_gst_group(client: &mut InvoicingServiceClient<Channel>) -> String {
+    create_tax_group(
+        client,
+        "GST 18%",
+        vec![
+            component(
+                "CGST",
+                "0.09",
+                TaxComponentKind::Standard,
+                PlaceOfSupply::IntraState,
+            ),
+            component(
+                "SGST",
+                "0.09",
+                TaxComponentKind::Standard,
+                PlaceOfSupply::IntraState,
+            ),
+            component(
+                "IGST",
+                "0.18",
+                TaxComponentKind::Standard,
+                PlaceOfSupply::InterState,
+            ),
+        ],
+    )
+    .await
+    .expect("Failed to create tax group")
+}
+
+/// Register the tenant in Karnataka, India.
+async fn set_registered_state(client: &mut InvoicingServiceClient<Channel>) {
+    client
+        .set_tenant_profile(with_tenant(
+            TEST_TENANT_ID,
+            SetTenantProfileRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                legal_name: "Acme India Pvt Ltd".to_string(),
+                address: Some(Address {
+                    state: "Karnataka".to_string(),
+                    country: "IN".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to set tenant profile");
+}
+
+/// Create a draft invoice billed to `state` with one line taxed by the group.
+async fn create_draft_invoice(
+    client: &mut InvoicingServiceClient<Channel>,
+    state: &str,
+    tax_group_id: &str,
+) -> Invoice {
+    let invoice_id = client
+        .create_invoice(with_tenant(
+            TEST_TENANT_ID,
+            CreateInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_type: InvoiceType::Standard as i32,
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                customer_name: "GST Customer".to_string(),
+                billing_address: Some(Address {
+                    state: state.to_string(),
+                    country: "IN".to_string(),
+                    ..Default::default()
+                }),
+                currency: "INR".to_string(),
+                metadata: "{}".to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to create invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+        .invoice_id;
+
+    client
+        .add_line_item(with_tenant(
+            TEST_TENANT_ID,
+            AddLineItemRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id,
+                description: "Consulting".to_string(),
+                quantity: "10".to_string(),
+                unit_price: "100".to_string(),
+                tax_group_id: tax_group_id.to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to add line item")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice")
+}
+
+fn names(taxes: &[TaxAmount]) -> Vec<&str> {
+    taxes.iter().map(|t| t.name.as_str()).collect()
+}
+
+#[tokio::test]
+async fn create_get_and_list_tax_groups() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let tax_group_id = create_gst_group(&mut client).await;
+
+    let group = client
+        .get_tax_group(with_tenant(
+            TEST_TENANT_ID,
+            GetTaxGroupRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                tax_group_id: tax_group_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to get tax group")
+        .into_inner()
+        .tax_group
+        .expect("Missing tax group");
+    assert_eq!(group.name, "GST 18%");
+    assert!(group.active);
+    assert_eq!(
+        group
+            .components
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<_>>(),
+        ["CGST", "SGST", "IGST"]
+    );
+    assert_eq!(group.components[2].rate, "0.18");
+    assert_eq!(
+        group.components[2].applies_to,
+        PlaceOfSupply::InterState as i32
+    );
+
+    let duplicate = create_gst_group_result(&mut client).await;
+    assert_eq!(duplicate.unwrap_err().code(), tonic::Code::AlreadyExists);
+
+    let compound_first = create_tax_group(
+        &mut client,
+        "Compound only",
+        vec![component(
+            "QST",
+            "0.09975",
+            TaxComponentKind::Compound,
+            PlaceOfSupply::Unspecified,
+        )],
+    )
+    .await;
+    assert_eq!(
+        compound_first.unwrap_err().code(),
+        tonic::Code::InvalidArgument
+    );
+
+    let bad_rate = create_tax_group(
+        &mut client,
+        "Too high",
+        vec![component(
+            "VAT",
+            "1.5",
+            TaxComponentKind::Standard,
+            PlaceOfSupply::Unspecified,
+        )],
+    )
+    .await;
+    assert_eq!(bad_rate.unwrap_err().code(), tonic::Code::InvalidArgument);
+
+    let groups = client
+        .list_tax_groups(with_tenant(
+            TEST_TENANT_ID,
+            ListTaxGroupsRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                active_only: true,
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to list tax groups")
+        .into_inner()
+        .tax_groups;
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].components.len(), 3);
+
+    app.cleanup().await;
+}
+
+async fn create_gst_group_result(
+    client: &mut InvoicingServiceClient<Channel>,
+) -> Result<String, tonic::Status> {
+    create_tax_group(
+        client,
+        "GST 18%",
+        vec![component(
+            "IGST",
+            "0.18",
+            TaxComponentKind::Standard,
+            PlaceOfSupply::Unspecified,
+        )],
+    )
+    .await
+}
+
+#[tokio::test]
+async fn place_of_supply_follows_billing_state() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    set_registered_state(&mut client).await;
+    let tax_group_id = create_gst_group(&mut client).await;
+
+    // Within the registered state: CGST + SGST
+    let invoice = create_draft_invoice(&mut client, "karnataka", &tax_group_id).await;
+    let line = &invoice.line_items[0];
+    assert_eq!(line.tax_group_id, tax_group_id);
+    assert!(line.tax_rate_id.is_empty());
+    assert_eq!(names(&line.taxes), ["CGST", "SGST"]);
+    assert_eq!(line.taxes[0].tax_amount, "90");
+    assert_eq!(line.tax_amount, "180");
+    assert_eq!(invoice.tax_total, "180");
+    assert_eq!(invoice.total, "1180");
+    assert_eq!(names(&invoice.tax_breakdown), ["CGST", "SGST"]);
+
+    // Billing another state switches the draft to IGST
+    let invoice = client
+        .update_invoice(with_tenant(
+            TEST_TENANT_ID,
+            UpdateInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice.invoice_id.clone(),
+                billing_address: Some(Address {
+                    state: "Maharashtra".to_string(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to update invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice");
+    let line = &invoice.line_items[0];
+    assert_eq!(names(&line.taxes), ["IGST"]);
+    assert_eq!(line.taxes[0].tax_amount, "180");
+    assert_eq!(line.taxes[0].taxable_amount, "1000");
+    assert_eq!(invoice.tax_total, "180");
+    assert_eq!(invoice.total, "1180");
+    assert_eq!(names(&invoice.tax_breakdown), ["IGST"]);
+
+    // A line can't carry both a tax rate and a tax group
+    let both = client
+        .add_line_item(with_tenant(
+            TEST_TENANT_ID,
+            AddLineItemRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice.invoice_id.clone(),
+                description: "Both".to_string(),
+                quantity: "1".to_string(),
+                unit_price: "10".to_string(),
+                tax_rate_id: Uuid::new_v4().to_string(),
+                tax_group_id: tax_group_id.clone(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("tax rate and tax group are exclusive");
+    assert_eq!(both.code(), tonic::Code::InvalidArgument);
+
+    let unknown = client
+        .add_line_item(with_tenant(
+            TEST_TENANT_ID,
+            AddLineItemRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice.invoice_id.clone(),
+                description: "Unknown group".to_string(),
+                quantity: "1".to_string(),
+                unit_price: "10".to_string(),
+                tax_group_id: Uuid::new_v4().to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect_err("tax group must exist");
+    assert_eq!(unknown.code(), tonic::Code::FailedPrecondition);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn credit_notes_and_statements_carry_component_taxes() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    set_registered_state(&mut client).await;
+    let tax_group_id = create_gst_group(&mut client).await;
+    let draft = create_draft_invoice(&mut client, "Karnataka", &tax_group_id).await;
+
+    let invoice = client
+        .issue_invoice(with_tenant(
+            TEST_TENANT_ID,
+            IssueInvoiceRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: draft.invoice_id.clone(),
+                issue_date: "2026-01-10".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to issue invoice")
+        .into_inner()
+        .invoice
+        .expect("Missing invoice");
+    assert_eq!(invoice.amount_due, "1180");
+
+    // Credit 3 of 10 units
+    let credit_note = client
+        .create_credit_note(with_tenant(
+            TEST_TENANT_ID,
+            CreateCreditNoteRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                invoice_id: invoice.invoice_id.clone(),
+                lines: vec![CreditNoteLine {
+                    line_item_id: invoice.line_items[0].line_item_id.clone(),
+                    quantity: "3".to_string(),
+                }],
+                issue_date: "2026-01-20".to_string(),
+                ..Default::default()
+            },
+        ))
+        .await
+        .expect("Failed to create credit note")
+        .into_inner()
+        .credit_note
+        .expect("Missing credit note");
+    let line = &credit_note.line_items[0];
+    assert_eq!(line.tax_group_id, tax_group_id);
+    assert_eq!(names(&line.taxes), ["CGST", "SGST"]);
+    assert_eq!(line.taxes[0].taxable_amount, "300");
+    assert_eq!(line.taxes[0].tax_amount, "27");
+    assert_eq!(credit_note.tax_total, "54");
+
+    // The statement nets the credit note's tax against the invoice's
+    let statement = client
+        .generate_statement(with_tenant(
+            TEST_TENANT_ID,
+            GenerateStatementRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                period_start: "2026-01-01".to_string(),
+                period_end: "2026-01-31".to_string(),
+            },
+        ))
+        .await
+        .expect("Failed to generate statement")
+        .into_inner()
+        .statement
+        .expect("Missing statement");
+    assert_eq!(names(&statement.tax_breakdown), ["CGST", "SGST"]);
+    assert_eq!(statement.tax_breakdown[0].taxable_amount, "700");
+    assert_eq!(statement.tax_breakdown[0].tax_amount, "63");
+    assert_eq!(statement.tax_breakdown[1].tax_amount, "63");
+
+    app.cleanup().await;
+}
+
+#[test]
+fn compound_and_withholding_components() {
+    let component = |name: &str, rate: &str, kind: &str| Component {
+        tax_group_id: Uuid::nil(),
+        name: name.to_string(),
+        rate: dec(rate),
+        kind: kind.to_string(),
+        applies_to: "any".to_string(),
+        sort_order: 0,
+    };
+    let group = TaxGroup {
+        tax_group_id: Uuid::nil(),
+        tenant_id: Uuid::nil(),
+        name: "VAT with withholding".to_string(),
+        active: true,
+        created_utc: chrono::Utc::now(),
+        components: vec![
+            component("GST", "0.05", "standard"),
+            component("WHT", "0.10", "withholding"),
+            component("QST", "0.09975", "compound"),
+        ],
+    };
+
+    let taxes = group_taxes(&group, dec("100"), Supply::IntraState);
+    assert_eq!(taxes[0].tax_amount, dec("5"));
+    assert_eq!(taxes[1].tax_amount, dec("-10"));
+    // Compounded on the subtotal and GST, not on withholding
+    assert_eq!(taxes[2].taxable_amount, dec("105"));
+    assert_eq!(taxes[2].tax_amount, dec("10.4738"));
+}
@@ -0,0 +1,439 @@
+//! Invoice template and branding tests for invoicing-service.
+
+mod common;
+
+use common::{with_tenant, TestApp, TEST_CUSTOMER_ID, TEST_TENANT_ID};
+use flate2::read::ZlibDecoder;
+use invoicing_service::grpc::proto::{
+    CreateInvoiceTemplateRequest, GetInvoiceTemplateRequest, InvoiceTemplate,
+    ListInvoiceTemplatesRequest, PreviewInvoiceTemplateRequest, SetCustomerTemplateRequest,
+    SetDefaultTemplateRequest, TemplateDocumentType,
+};
+use invoicing_service::rendering::{
+    render_sample_invoice, render_sample_receipt, render_sample_statement, Branding, Letterhead,
+    TextTemplate,
+};
+use std::collections::HashMap;
+use std::io::Read;
+use uuid::Uuid;
+
+/// Extract the text drawn on every page by inflating each content stream.
+fn pdf_text(pdf: &[u8]) -> String {
+    let mut text = String::new();
+    let mut rest = pdf;
+    while let Some(start) = find(rest, b"stream\n") {
+        let body = &rest[start + b"stream\n".len()..];
+        let end = find(body, b"\nendstream").expect("Unterminated stream");
+        let mut decoded = Vec::new();
+        if ZlibDecoder::new(&body[..end])
+            .read_to_end(&mut decoded)
+            .is_ok()
+        {
+            text.push_str(&String::from_utf8_lossy(&decoded));
+        }
+        rest = &body[end + b"\nendstream".len()..];
+    }
+    text
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn labels(pairs: &[(&str, &str)]) -> serde_json::Value {
+    serde_json::Value::Object(
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), serde_json::Value::String(v.to_string())))
+            .collect(),
+    )
+}
+
+fn tenant_id() -> Uuid {
+    Uuid::parse_str(TEST_TENANT_ID).unwrap()
+}
+
+// ============================================================================
+// Branding
+// ============================================================================
+
+#[test]
+fn text_template_substitutes_known_variables() {
+    let template =
+        TextTemplate::parse("Invoice {{ document.number }} for {{customer.name}}{{tenant.phone}}")
+            .expect("Template should parse");
+
+    let values = HashMap::from([
+        ("document.number", "INV-0001".to_string()),
+        ("customer.name", "Globex".to_string()),
+    ]);
+    assert_eq!(template.render(&values), "Invoice INV-0001 for Globex");
+
+    let unknown = TextTemplate::parse("Hello {{customer.email}}").unwrap_err();
+    assert!(unknown.to_string().contains("customer.email"));
+    assert!(TextTemplate::parse("Hello {{customer.name").is_err());
+}
+
+#[test]
+fn branding_rejects_invalid_colours_and_labels() {
+    let empty = serde_json::json!({});
+    assert!(Branding::new(Some("#336699"), Some("#eeeeee"), &empty, None, None).is_ok());
+    assert!(Branding::new(Some("336699"), None, &empty, None, None).is_err());
+    assert!(Branding::new(None, Some("#33669Z"), &empty, None, None).is_err());
+
+    let unknown =
+        Branding::new(None, None, &labels(&[("greeting", "Hi")]), None, None).unwrap_err();
+    assert!(unknown.to_string().contains("greeting"));
+    assert!(Branding::new(None, None, &serde_json::json!({ "total": 1 }), None, None).is_err());
+
+    let branding = Branding::new(None, None, &labels(&[("total", "Gesamt")]), None, None).unwrap();
+    assert_eq!(branding.label("total"), "Gesamt");
+    assert_eq!(branding.label("subtotal"), "Subtotal");
+}
+
+#[test]
+fn branded_documents_use_labels_colours_footer_and_terms() {
+    let branding = Branding::new(
+        Some("#336699"),
+        Some("#ffcc00"),
+        &labels(&[
+            ("invoice_title", "FACTURA"),
+            ("receipt_title", "RECIBO"),
+            ("statement_title", "ESTADO DE CUENTA"),
+            ("total", "Importe total"),
+        ]),
+        Some("Gracias, {{customer.name}}"),
+        Some("Pagar {{document.number}} antes de {{document.due_date}}"),
+    )
+    .unwrap();
+    let letterhead = Letterhead {
+        profile: None,
+        logo: None,
+        branding,
+    };
+
+    let invoice = pdf_text(&render_sample_invoice(&letterhead, tenant_id()));
+    assert!(invoice.contains("(FACTURA)"));
+    assert!(invoice.contains("(Importe total)"));
+    assert!(!invoice.contains("(INVOICE)"));
+    assert!(invoice.contains("Pagar INV-SAMPLE-0001 antes de"));
+    assert!(invoice.contains("Gracias, "));
+    assert!(invoice.contains("0.200 0.400 0.600 rg"));
+    assert!(invoice.contains("1.000 0.800 0.000"));
+
+    let receipt = pdf_text(&render_sample_receipt(&letterhead, tenant_id()));
+    assert!(receipt.contains("(RECIBO)"));
+
+    let statement = pdf_text(&render_sample_statement(&letterhead, tenant_id()));
+    assert!(statement.contains("(ESTADO DE CUENTA)"));
+}
+
+// ============================================================================
+// Template Registry
+// ============================================================================
+
+async fn create_template(
+    client: &mut invoicing_service::grpc::proto::invoicing_service_client::InvoicingServiceClient<
+        tonic::transport::Channel,
+    >,
+    request: CreateInvoiceTemplateRequest,
+) -> Result<InvoiceTemplate, tonic::Status> {
+    Ok(client
+        .create_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            CreateInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                ..request
+            },
+        ))
+        .await?
+        .into_inner()
+        .template
+        .expect("Missing template"))
+}
+
+#[tokio::test]
+async fn create_and_list_invoice_templates() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let logo_document_id = Uuid::new_v4().to_string();
+    let standard = create_template(
+        &mut client,
+        CreateInvoiceTemplateRequest {
+            name: "Standard".to_string(),
+            primary_color: "#336699".to_string(),
+            logo_document_id: logo_document_id.clone(),
+            footer_template: "Thank you, {{customer.name}}".to_string(),
+            labels: HashMap::from([("invoice_title".to_string(), "TAX INVOICE".to_string())]),
+            set_default: true,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Failed to create template");
+    assert!(standard.is_default);
+    assert_eq!(standard.logo_document_id, logo_document_id);
+    assert_eq!(standard.accent_color, "");
+    assert_eq!(standard.labels["invoice_title"], "TAX INVOICE");
+
+    let duplicate = create_template(
+        &mut client,
+        CreateInvoiceTemplateRequest {
+            name: "Standard".to_string(),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("Names are unique per tenant");
+    assert_eq!(duplicate.code(), tonic::Code::AlreadyExists);
+
+    for invalid in [
+        CreateInvoiceTemplateRequest::default(),
+        CreateInvoiceTemplateRequest {
+            name: "Bad colour".to_string(),
+            primary_color: "blue".to_string(),
+            ..Default::default()
+        },
+        CreateInvoiceTemplateRequest {
+            name: "Bad variable".to_string(),
+            terms_template: "Due {{invoice.due}}".to_string(),
+            ..Default::default()
+        },
+        CreateInvoiceTemplateRequest {
+            name: "Bad label".to_string(),
+            labels: HashMap::from([("heading".to_string(), "X".to_string())]),
+            ..Default::default()
+        },
+    ] {
+        let status = create_template(&mut client, invalid)
+            .await
+            .expect_err("Template should be rejected");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    // A new default replaces the old one
+    let german = create_template(
+        &mut client,
+        CreateInvoiceTemplateRequest {
+            name: "German".to_string(),
+            labels: HashMap::from([("invoice_title".to_string(), "RECHNUNG".to_string())]),
+            set_default: true,
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Failed to create template");
+
+    let fetched = client
+        .get_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            GetInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: standard.template_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to get template")
+        .into_inner()
+        .template
+        .expect("Missing template");
+    assert!(!fetched.is_default);
+    assert_eq!(fetched.footer_template, "Thank you, {{customer.name}}");
+
+    let page = client
+        .list_invoice_templates(with_tenant(
+            TEST_TENANT_ID,
+            ListInvoiceTemplatesRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                page_size: 1,
+                page_token: String::new(),
+            },
+        ))
+        .await
+        .expect("Failed to list templates")
+        .into_inner();
+    assert_eq!(page.templates.len(), 1);
+    assert!(!page.next_page_token.is_empty());
+
+    let rest = client
+        .list_invoice_templates(with_tenant(
+            TEST_TENANT_ID,
+            ListInvoiceTemplatesRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                page_size: 10,
+                page_token: page.next_page_token,
+            },
+        ))
+        .await
+        .expect("Failed to list templates")
+        .into_inner();
+    assert_eq!(rest.templates.len(), 1);
+    let mut names = vec![
+        page.templates[0].name.clone(),
+        rest.templates[0].name.clone(),
+    ];
+    names.sort();
+    assert_eq!(names, ["German", "Standard"]);
+
+    // Switching the default back
+    let default = client
+        .set_default_template(with_tenant(
+            TEST_TENANT_ID,
+            SetDefaultTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: standard.template_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to set default template")
+        .into_inner()
+        .template
+        .expect("Missing template");
+    assert!(default.is_default);
+
+    let german = client
+        .get_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            GetInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: german.template_id,
+            },
+        ))
+        .await
+        .expect("Failed to get template")
+        .into_inner()
+        .template
+        .expect("Missing template");
+    assert!(!german.is_default);
+
+    let missing = client
+        .set_default_template(with_tenant(
+            TEST_TENANT_ID,
+            SetDefaultTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: Uuid::new_v4().to_string(),
+            },
+        ))
+        .await
+        .expect_err("Template should not exist");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    app.cleanup().await;
+}
+
+#[tokio::test]
+async fn customer_template_assignment_and_preview() {
+    let app = TestApp::spawn().await;
+    let mut client = app.grpc_client().await;
+
+    let template = create_template(
+        &mut client,
+        CreateInvoiceTemplateRequest {
+            name: "Spanish".to_string(),
+            accent_color: "#ffcc00".to_string(),
+            labels: HashMap::from([
+                ("invoice_title".to_string(), "FACTURA".to_string()),
+                (
+                    "statement_title".to_string(),
+                    "ESTADO DE CUENTA".to_string(),
+                ),
+            ]),
+            terms_template: "Vence el {{document.due_date}}".to_string(),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("Failed to create template");
+
+    let unknown = client
+        .set_customer_template(with_tenant(
+            TEST_TENANT_ID,
+            SetCustomerTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                template_id: Uuid::new_v4().to_string(),
+            },
+        ))
+        .await
+        .expect_err("Template should not exist");
+    assert_eq!(unknown.code(), tonic::Code::NotFound);
+
+    let assigned = client
+        .set_customer_template(with_tenant(
+            TEST_TENANT_ID,
+            SetCustomerTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                template_id: template.template_id.clone(),
+            },
+        ))
+        .await
+        .expect("Failed to assign template")
+        .into_inner();
+    assert_eq!(assigned.template_id, template.template_id);
+
+    let cleared = client
+        .set_customer_template(with_tenant(
+            TEST_TENANT_ID,
+            SetCustomerTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                customer_id: TEST_CUSTOMER_ID.to_string(),
+                template_id: String::new(),
+            },
+        ))
+        .await
+        .expect("Failed to clear template")
+        .into_inner();
+    assert_eq!(cleared.template_id, "");
+
+    let preview = client
+        .preview_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            PreviewInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: template.template_id.clone(),
+                document_type: TemplateDocumentType::Unspecified as i32,
+            },
+        ))
+        .await
+        .expect("Failed to preview template")
+        .into_inner();
+    assert_eq!(preview.filename, "PREVIEW-INVOICE.pdf");
+    assert!(preview.pdf_bytes.starts_with(b"%PDF-1."));
+    let text = pdf_text(&preview.pdf_bytes);
+    assert!(text.contains("(FACTURA)"));
+    assert!(text.contains("Vence el "));
+
+    let statement = client
+        .preview_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            PreviewInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: template.template_id.clone(),
+                document_type: TemplateDocumentType::Statement as i32,
+            },
+        ))
+        .await
+        .expect("Failed to preview template")
+        .into_inner();
+    assert_eq!(statement.filename, "PREVIEW-STATEMENT.pdf");
+    assert!(pdf_text(&statement.pdf_bytes).contains("(ESTADO DE CUENTA)"));
+
+    let missing = client
+        .preview_invoice_template(with_tenant(
+            TEST_TENANT_ID,
+            PreviewInvoiceTemplateRequest {
+                tenant_id: TEST_TENANT_ID.to_string(),
+                template_id: Uuid::new_v4().to_string(),
+                document_type: TemplateDocumentType::Invoice as i32,
+            },
+        ))
+        .await
+        .expect_err("Template should not exist");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    app.cleanup().await;
+}
@@ -0,0 +1,19 @@
+-- Journal Reversals
+-- A posted journal is corrected by posting its mirror image under a new journal.
+-- Reversal entries point back at the original; journal_reversals points forward
+-- and its primary key guarantees a journal is reversed at most once.
+
+ALTER TABLE ledger_entries ADD COLUMN reverses_journal_id UUID;
+
+CREATE INDEX idx_entries_reverses_journal ON ledger_entries(reverses_journal_id)
+    WHERE reverses_journal_id IS NOT NULL;
+
+CREATE TABLE journal_reversals (
+    original_journal_id UUID PRIMARY KEY,
+    reversal_journal_id UUID NOT NULL UNIQUE,
+    tenant_id UUID NOT NULL,
+    reason TEXT,
+    reversed_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_journal_reversals_tenant ON journal_reversals(tenant_id);
@@ -0,0 +1,20 @@
+-- Accounting Periods
+-- Closed periods lock back-dated postings. Closing a period rolls revenue and
+-- expense balances into the period's retained-earnings equity account.
+
+CREATE TABLE accounting_periods (
+    period_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'closed')),
+    retained_earnings_account_id UUID REFERENCES accounts(account_id),
+    closing_journal_id UUID,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    closed_utc TIMESTAMPTZ,
+    CHECK (end_date >= start_date),
+    UNIQUE(tenant_id, start_date)
+);
+
+CREATE INDEX idx_periods_tenant_dates ON accounting_periods(tenant_id, start_date, end_date);
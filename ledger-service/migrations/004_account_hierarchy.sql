@@ -0,0 +1,9 @@
+-- Account Hierarchy
+-- Accounts can be nested under a parent to form a chart-of-accounts tree.
+-- Parents with children are header accounts: they roll up balances but
+-- cannot receive postings themselves.
+
+ALTER TABLE accounts
+    ADD COLUMN parent_account_id UUID REFERENCES accounts(account_id);
+
+CREATE INDEX idx_accounts_parent ON accounts(parent_account_id) WHERE parent_account_id IS NOT NULL;
@@ -0,0 +1,49 @@
+-- Multi-Currency Journals
+-- Exchange rates convert entries into a base currency. Cross-currency journals
+-- record the base-currency value of every entry and must balance in that base.
+-- Revaluation entries adjust only the base value of a foreign-currency account,
+-- so they carry a zero amount and a positive base_amount.
+
+CREATE TABLE exchange_rates (
+    tenant_id UUID NOT NULL,
+    from_currency VARCHAR(3) NOT NULL,
+    to_currency VARCHAR(3) NOT NULL,
+    rate_date DATE NOT NULL,
+    rate DECIMAL(19, 8) NOT NULL CHECK (rate > 0), -- units of to_currency per unit of from_currency
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (tenant_id, from_currency, to_currency, rate_date),
+    CHECK (from_currency <> to_currency)
+);
+
+ALTER TABLE ledger_entries
+    ADD COLUMN base_currency VARCHAR(3),
+    ADD COLUMN base_amount DECIMAL(19, 4),
+    ADD COLUMN exchange_rate DECIMAL(19, 8);
+
+ALTER TABLE ledger_entries DROP CONSTRAINT ledger_entries_amount_check;
+ALTER TABLE ledger_entries ADD CONSTRAINT ledger_entries_amount_check
+    CHECK (amount > 0 OR (amount = 0 AND base_amount > 0));
+
+-- Journals with base amounts balance in the base currency
+CREATE OR REPLACE FUNCTION check_double_entry()
+RETURNS TRIGGER AS $$
+DECLARE
+    debit_sum DECIMAL(19, 4);
+    credit_sum DECIMAL(19, 4);
+BEGIN
+    SELECT
+        COALESCE(SUM(CASE WHEN direction = 'debit' THEN COALESCE(base_amount, amount) ELSE 0 END), 0),
+        COALESCE(SUM(CASE WHEN direction = 'credit' THEN COALESCE(base_amount, amount) ELSE 0 END), 0)
+    INTO debit_sum, credit_sum
+    FROM ledger_entries
+    WHERE journal_id = NEW.journal_id;
+
+    IF debit_sum != credit_sum THEN
+        RAISE EXCEPTION 'Double-entry violation: debits (%) != credits (%) for journal %',
+            debit_sum, credit_sum, NEW.journal_id;
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
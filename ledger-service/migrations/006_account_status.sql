@@ -0,0 +1,25 @@
+-- Account Lifecycle
+-- Accounts are active, frozen (readable, no new postings) or closed (zero
+-- balance, no new postings). Every status change is recorded with its actor
+-- and reason.
+
+ALTER TABLE accounts
+    ADD COLUMN status VARCHAR(10) NOT NULL DEFAULT 'active'
+        CHECK (status IN ('active', 'frozen', 'closed'));
+
+UPDATE accounts SET status = 'closed' WHERE closed_utc IS NOT NULL;
+
+CREATE INDEX idx_accounts_tenant_status ON accounts(tenant_id, status);
+
+CREATE TABLE account_status_changes (
+    change_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    account_id UUID NOT NULL REFERENCES accounts(account_id),
+    from_status VARCHAR(10) NOT NULL,
+    to_status VARCHAR(10) NOT NULL,
+    actor VARCHAR(255) NOT NULL,
+    reason TEXT,
+    changed_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_account_status_changes_account ON account_status_changes(tenant_id, account_id, changed_utc);
@@ -0,0 +1,30 @@
+-- Pending Holds
+-- A hold reserves an amount for a future debit/credit pair without posting it.
+-- Pending, unexpired holds reduce the available balance of the account they
+-- would draw down. Capturing a hold (fully or partially) posts a real journal;
+-- releasing or expiring it frees the reservation.
+
+CREATE TABLE holds (
+    hold_id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL,
+    debit_account_id UUID NOT NULL REFERENCES accounts(account_id),
+    credit_account_id UUID NOT NULL REFERENCES accounts(account_id),
+    amount DECIMAL(19, 4) NOT NULL CHECK (amount > 0),
+    captured_amount DECIMAL(19, 4) CHECK (captured_amount > 0),
+    currency VARCHAR(3) NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'captured', 'released', 'expired')),
+    expires_utc TIMESTAMPTZ NOT NULL,
+    idempotency_key VARCHAR(255),
+    metadata JSONB,
+    journal_id UUID,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_utc TIMESTAMPTZ,
+    CHECK (debit_account_id <> credit_account_id),
+    CHECK (captured_amount IS NULL OR captured_amount <= amount),
+    UNIQUE (tenant_id, idempotency_key)
+);
+
+CREATE INDEX idx_holds_pending_debit ON holds(tenant_id, debit_account_id) WHERE status = 'pending';
+CREATE INDEX idx_holds_pending_credit ON holds(tenant_id, credit_account_id) WHERE status = 'pending';
+CREATE INDEX idx_holds_pending_expiry ON holds(expires_utc) WHERE status = 'pending';
@@ -0,0 +1,84 @@
+-- Account Balance Snapshots
+-- One row per account and day with postings, holding the account's raw
+-- (debit minus credit) closing balance for that day. A trigger keeps the rows
+-- current as entries are inserted, so the balance on any date is the latest
+-- snapshot on or before it instead of a sum over every entry.
+
+CREATE TABLE account_balance_snapshots (
+    tenant_id UUID NOT NULL,
+    account_id UUID NOT NULL REFERENCES accounts(account_id),
+    snapshot_date DATE NOT NULL,
+    balance DECIMAL(19, 4) NOT NULL,
+    updated_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (account_id, snapshot_date)
+);
+
+CREATE INDEX idx_balance_snapshots_tenant ON account_balance_snapshots(tenant_id, account_id);
+
+-- Serializes snapshot maintenance per account. Writers take these locks in
+-- account_id order before inserting entries; the trigger re-enters them.
+CREATE OR REPLACE FUNCTION lock_account_balance(p_account_id UUID)
+RETURNS VOID AS $$
+BEGIN
+    PERFORM pg_advisory_xact_lock(hashtextextended(p_account_id::text, 0));
+END;
+$$ LANGUAGE plpgsql;
+
+-- Fold a new entry into its day's snapshot and every later one
+CREATE OR REPLACE FUNCTION apply_balance_snapshot()
+RETURNS TRIGGER AS $$
+DECLARE
+    impact DECIMAL(19, 4);
+    opening DECIMAL(19, 4);
+BEGIN
+    impact := CASE WHEN NEW.direction = 'debit' THEN NEW.amount ELSE -NEW.amount END;
+
+    -- Base-only revaluation entries do not move the account-currency balance
+    IF impact = 0 THEN
+        RETURN NEW;
+    END IF;
+
+    PERFORM lock_account_balance(NEW.account_id);
+
+    -- Backdated entries move every later closing balance
+    UPDATE account_balance_snapshots
+    SET balance = balance + impact, updated_utc = NOW()
+    WHERE account_id = NEW.account_id AND snapshot_date > NEW.effective_date;
+
+    SELECT balance INTO opening
+    FROM account_balance_snapshots
+    WHERE account_id = NEW.account_id AND snapshot_date < NEW.effective_date
+    ORDER BY snapshot_date DESC
+    LIMIT 1;
+
+    INSERT INTO account_balance_snapshots (tenant_id, account_id, snapshot_date, balance)
+    VALUES (NEW.tenant_id, NEW.account_id, NEW.effective_date, COALESCE(opening, 0) + impact)
+    ON CONFLICT (account_id, snapshot_date)
+    DO UPDATE SET balance = account_balance_snapshots.balance + impact, updated_utc = NOW();
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER maintain_balance_snapshots
+    AFTER INSERT ON ledger_entries
+    FOR EACH ROW
+    EXECUTE FUNCTION apply_balance_snapshot();
+
+-- Backfill snapshots for entries posted before this migration
+INSERT INTO account_balance_snapshots (tenant_id, account_id, snapshot_date, balance)
+SELECT
+    tenant_id,
+    account_id,
+    effective_date,
+    SUM(net) OVER (PARTITION BY account_id ORDER BY effective_date)
+FROM (
+    SELECT
+        tenant_id,
+        account_id,
+        effective_date,
+        SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END) AS net
+    FROM ledger_entries
+    WHERE amount <> 0
+    GROUP BY tenant_id, account_id, effective_date
+) daily;
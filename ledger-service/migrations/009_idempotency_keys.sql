@@ -0,0 +1,30 @@
+-- Idempotency Keys
+-- Idempotency keys are scoped per tenant and remember a SHA-256 fingerprint of
+-- the request that first used them, so a replay with a different payload is
+-- rejected instead of silently returning the original journal. Keys expire
+-- after the configured retention window and may then be reused.
+
+CREATE TABLE idempotency_keys (
+    tenant_id UUID NOT NULL,
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_hash CHAR(64),
+    journal_id UUID NOT NULL,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_utc TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (tenant_id, idempotency_key)
+);
+
+CREATE INDEX idx_idempotency_keys_expires ON idempotency_keys(expires_utc);
+
+-- Carry over existing keys with the default 30-day retention. Their requests
+-- were never fingerprinted, so replays of them are not checked for mismatches.
+INSERT INTO idempotency_keys (tenant_id, idempotency_key, request_hash, journal_id, created_utc, expires_utc)
+SELECT tenant_id, idempotency_key, NULL, journal_id, posted_utc, posted_utc + INTERVAL '30 days'
+FROM ledger_entries
+WHERE idempotency_key IS NOT NULL
+ON CONFLICT (tenant_id, idempotency_key) DO NOTHING;
+
+-- Entries keep the key for traceability, but it is no longer unique: keys are
+-- per tenant, and an expired key can be used again.
+DROP INDEX idx_entries_idempotency;
+CREATE INDEX idx_entries_idempotency ON ledger_entries(tenant_id, idempotency_key) WHERE idempotency_key IS NOT NULL;
@@ -0,0 +1,22 @@
+-- Period Closing Entries
+-- Closing entries point at the period they close, so reports can leave them
+-- out without trusting journal metadata that any caller can set. Only
+-- ClosePeriod writes this column.
+
+ALTER TABLE ledger_entries ADD COLUMN closes_period_id UUID REFERENCES accounting_periods(period_id);
+
+CREATE INDEX idx_entries_closes_period ON ledger_entries(closes_period_id)
+    WHERE closes_period_id IS NOT NULL;
+
+-- Link closing journals posted before this migration. The latest one of each
+-- period is its closing_journal_id; earlier ones (from a reopen and re-close)
+-- are matched by the period they name, on the period's end date.
+UPDATE ledger_entries e
+SET closes_period_id = p.period_id
+FROM accounting_periods p
+WHERE e.tenant_id = p.tenant_id
+  AND e.effective_date = p.end_date
+  AND (
+      e.journal_id = p.closing_journal_id
+      OR (e.metadata->>'source' = 'period_close' AND e.metadata->>'period_id' = p.period_id::text)
+  );
@@ -0,0 +1,13 @@
+-- Tenant Base Currency
+-- A tenant reports in one base currency. Once it is set, every entry carries
+-- its value in that currency (base_amount), so reports, period closes and FX
+-- revaluation aggregate base amounts across all account currencies. Setting it
+-- converts the entries already posted at the rates of their effective dates.
+-- Tenants that posted cross-currency journals before this migration set the
+-- base currency those journals used.
+
+CREATE TABLE tenant_base_currencies (
+    tenant_id UUID PRIMARY KEY,
+    base_currency VARCHAR(3) NOT NULL,
+    created_utc TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
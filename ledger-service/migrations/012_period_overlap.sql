@@ -0,0 +1,11 @@
+-- Period Overlap
+-- OpenPeriod checks for an overlapping period before inserting, but two
+-- concurrent calls with different start dates can both pass that check. The
+-- exclusion constraint rejects the second one, so every date of a tenant falls
+-- in at most one period.
+
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+ALTER TABLE accounting_periods
+    ADD CONSTRAINT accounting_periods_no_overlap
+    EXCLUDE USING gist (tenant_id WITH =, daterange(start_date, end_date, '[]') WITH &&);
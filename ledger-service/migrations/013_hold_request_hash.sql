@@ -0,0 +1,7 @@
+-- Hold Request Fingerprints
+-- Holds remember a SHA-256 fingerprint of the CreateHold request that first
+-- used their idempotency key, so a replay with a different payload is rejected
+-- instead of silently returning the original hold. Holds created before this
+-- migration were never fingerprinted, so replays of them are not checked.
+
+ALTER TABLE holds ADD COLUMN request_hash CHAR(64);
@@ -12,6 +12,8 @@ pub struct LedgerConfig {
     pub log_level: String,
     pub otlp_endpoint: Option<String>,
     pub database: DatabaseConfig,
+    pub holds: HoldConfig,
+    pub idempotency: IdempotencyConfig,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +23,22 @@ pub struct DatabaseConfig {
     pub min_connections: u32,
 }
 
+#[derive(Debug, Clone)]
+pub struct HoldConfig {
+    /// Lifetime of a hold when CreateHold does not set one.
+    pub default_ttl_secs: i64,
+    /// How often lapsed holds are marked expired. 0 disables the sweeper.
+    pub expiry_interval_secs: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct IdempotencyConfig {
+    /// How long a journal's idempotency key is remembered before it can be reused.
+    pub retention_secs: i64,
+    /// How often expired keys are deleted. 0 disables the sweeper.
+    pub purge_interval_secs: u64,
+}
+
 impl LedgerConfig {
     pub fn from_env() -> Result<Self, AppError> {
         let common = core_config::Config::load()?;
@@ -45,6 +63,26 @@ impl LedgerConfig {
                     .and_then(|s| s.parse().ok())
                     .unwrap_or(2),
             },
+            holds: HoldConfig {
+                default_ttl_secs: env::var("HOLD_DEFAULT_TTL_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(7 * 24 * 60 * 60),
+                expiry_interval_secs: env::var("HOLD_EXPIRY_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(60),
+            },
+            idempotency: IdempotencyConfig {
+                retention_secs: env::var("IDEMPOTENCY_RETENTION_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(30 * 24 * 60 * 60),
+                purge_interval_secs: env::var("IDEMPOTENCY_PURGE_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(3600),
+            },
         })
     }
 }
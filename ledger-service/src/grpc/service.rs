@@ -1,51 +1,93 @@
 //! LedgerService gRPC implementation.
 
+use crate::config::HoldConfig;
 use crate::grpc::proto::{
-    ledger_service_server::LedgerService, Account as ProtoAccount, AccountType as ProtoAccountType,
-    CreateAccountRequest, CreateAccountResponse, Direction as ProtoDirection, GetAccountRequest,
-    GetAccountResponse, GetBalanceRequest, GetBalanceResponse, GetBalancesRequest,
-    GetBalancesResponse, GetStatementRequest, GetStatementResponse, GetTransactionRequest,
-    GetTransactionResponse, LedgerEntry as ProtoLedgerEntry, ListAccountsRequest,
-    ListAccountsResponse, ListTransactionsRequest, ListTransactionsResponse,
-    PostTransactionRequest, PostTransactionResponse, Transaction as ProtoTransaction,
+    ledger_service_server::LedgerService, Account as ProtoAccount,
+    AccountRevaluation as ProtoAccountRevaluation, AccountStatus as ProtoAccountStatus,
+    AccountStatusChange as ProtoAccountStatusChange, AccountTreeNode,
+    AccountType as ProtoAccountType, AccountTypeTotal, AccountingPeriod as ProtoAccountingPeriod,
+    BalanceSheet, CaptureHoldRequest, CaptureHoldResponse, CloseAccountRequest,
+    CloseAccountResponse, ClosePeriodRequest, ClosePeriodResponse, CreateAccountRequest,
+    CreateAccountResponse, CreateHoldRequest, CreateHoldResponse, Direction as ProtoDirection,
+    ExchangeRate as ProtoExchangeRate, ExportTransactionsRequest, ExportTransactionsResponse,
+    FreezeAccountRequest, FreezeAccountResponse, GetAccountRequest, GetAccountResponse,
+    GetAccountTreeRequest, GetAccountTreeResponse, GetBalanceRequest, GetBalanceResponse,
+    GetBalanceSheetRequest, GetBalanceSheetResponse, GetBalancesRequest, GetBalancesResponse,
+    GetBaseCurrencyRequest, GetBaseCurrencyResponse, GetExchangeRateRequest,
+    GetExchangeRateResponse, GetHoldRequest, GetHoldResponse, GetIncomeStatementRequest,
+    GetIncomeStatementResponse, GetStatementRequest, GetStatementResponse, GetTransactionRequest,
+    GetTransactionResponse, GetTrialBalanceRequest, GetTrialBalanceResponse, Hold as ProtoHold,
+    HoldStatus as ProtoHoldStatus, ImportRowError, ImportTransactionsRequest,
+    ImportTransactionsResponse, IncomeStatement, LedgerEntry as ProtoLedgerEntry,
+    ListAccountStatusChangesRequest, ListAccountStatusChangesResponse, ListAccountsRequest,
+    ListAccountsResponse, ListHoldsRequest, ListHoldsResponse, ListPeriodsRequest,
+    ListPeriodsResponse, ListTransactionsRequest, ListTransactionsResponse, OpenPeriodRequest,
+    OpenPeriodResponse, PeriodStatus as ProtoPeriodStatus, PostTransactionRequest,
+    PostTransactionResponse, ReleaseHoldRequest, ReleaseHoldResponse, ReopenAccountRequest,
+    ReopenAccountResponse, ReopenPeriodRequest, ReopenPeriodResponse, ReportLine,
+    RevalueAccountsRequest, RevalueAccountsResponse, ReverseTransactionRequest,
+    ReverseTransactionResponse, SetBaseCurrencyRequest, SetBaseCurrencyResponse,
+    SnapshotMismatch as ProtoSnapshotMismatch, Transaction as ProtoTransaction, TrialBalance,
+    UpsertExchangeRateRequest, UpsertExchangeRateResponse, VerifyBalanceSnapshotsRequest,
+    VerifyBalanceSnapshotsResponse,
 };
-use crate::models::{Account, AccountType, CreateAccount, Direction, LedgerEntry, PostEntry};
+use crate::models::{
+    Account, AccountActivity, AccountStatus, AccountStatusChange, AccountType, AccountingPeriod,
+    CreateAccount, CreateHold, Direction, ExchangeRate, ExportedEntry, Hold,
+    HoldIdempotencyConflict, HoldStatus, IdempotencyConflict, JournalReversal, LedgerEntry,
+    OpenPeriod, PeriodStatus, PostEntry, UpsertExchangeRate,
+};
+use crate::services::export::{self, format_decimal, ExportFormat};
 use crate::services::metrics::{
     ACCOUNTS_CREATED, AMOUNT_TOTAL, ENTRIES_TOTAL, GRPC_REQUESTS_TOTAL, GRPC_REQUEST_DURATION,
     TRANSACTIONS_TOTAL,
 };
 use crate::services::Database;
 use chrono::NaiveDate;
+use futures::StreamExt;
 use prost_types::Timestamp;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
-use tonic::{Request, Response, Status};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status, Streaming};
 use tracing::{info, instrument, warn};
 use uuid::Uuid;
 
-/// Format a Decimal as a normalized string (remove trailing zeros).
-fn format_decimal(d: &Decimal) -> String {
-    let s = d.to_string();
-    // Remove trailing zeros after decimal point
-    if s.contains('.') {
-        let trimmed = s.trim_end_matches('0').trim_end_matches('.');
-        trimmed.to_string()
-    } else {
-        s
-    }
-}
+/// Most row errors returned by one ImportTransactions call.
+const MAX_IMPORT_ERRORS: usize = 1000;
+
+/// Entries read per database page by ExportTransactions.
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+type ExportStream =
+    Pin<Box<dyn futures::Stream<Item = Result<ExportTransactionsResponse, Status>> + Send>>;
+
+/// Parsed PostTransaction request.
+/// Contains: (tenant_id, entries, effective_date, idempotency_key, metadata, base_currency)
+type ParsedPost<'a> = (
+    Uuid,
+    Vec<PostEntry>,
+    NaiveDate,
+    Option<&'a str>,
+    Option<serde_json::Value>,
+    Option<&'a str>,
+);
 
 /// LedgerService implementation.
 pub struct LedgerServiceImpl {
     db: Arc<Database>,
+    holds: HoldConfig,
 }
 
 impl LedgerServiceImpl {
     /// Create a new LedgerService instance.
-    pub fn new(db: Arc<Database>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<Database>, holds: HoldConfig) -> Self {
+        Self { db, holds }
     }
 
     /// Convert domain Account to proto Account.
@@ -81,7 +123,118 @@ impl LedgerServiceImpl {
                 nanos: t.timestamp_subsec_nanos() as i32,
             }),
             balance: balance.map(|b| format_decimal(&b)).unwrap_or_default(),
+            parent_account_id: account
+                .parent_account_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            status: account
+                .parsed_status()
+                .map(|s| s.to_proto())
+                .unwrap_or(ProtoAccountStatus::Unspecified as i32),
+        }
+    }
+
+    /// Convert domain AccountStatusChange to proto AccountStatusChange.
+    fn status_change_to_proto(change: &AccountStatusChange) -> ProtoAccountStatusChange {
+        let to_proto = |status: &str| {
+            AccountStatus::from_string(status)
+                .map(|s| s.to_proto())
+                .unwrap_or(ProtoAccountStatus::Unspecified as i32)
+        };
+        ProtoAccountStatusChange {
+            change_id: change.change_id.to_string(),
+            account_id: change.account_id.to_string(),
+            from_status: to_proto(&change.from_status),
+            to_status: to_proto(&change.to_status),
+            actor: change.actor.clone(),
+            reason: change.reason.clone().unwrap_or_default(),
+            changed_at: Some(Timestamp {
+                seconds: change.changed_utc.timestamp(),
+                nanos: change.changed_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+
+    /// Shared implementation of FreezeAccount, CloseAccount and ReopenAccount.
+    async fn change_account_status(
+        &self,
+        method: &str,
+        tenant_id: &str,
+        account_id: &str,
+        actor: &str,
+        reason: &str,
+        to_status: AccountStatus,
+    ) -> Result<ProtoAccount, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&[method])
+            .start_timer();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let account_id = Uuid::parse_str(account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid account_id format")
+        })?;
+
+        // Validate actor
+        if actor.is_empty() || actor.len() > 255 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&[method, "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "actor must be between 1 and 255 characters",
+            ));
         }
+
+        let reason = if reason.is_empty() {
+            None
+        } else {
+            Some(reason)
+        };
+
+        let account = self
+            .db
+            .change_account_status(tenant_id, account_id, to_status, actor, reason)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to change account status");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to change account status"),
+                }
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&[method, "not_found"])
+                    .inc();
+                Status::not_found("Account not found")
+            })?;
+
+        GRPC_REQUESTS_TOTAL.with_label_values(&[method, "ok"]).inc();
+
+        timer.observe_duration();
+
+        info!(
+            account_id = %account_id,
+            status = %to_status,
+            actor = %actor,
+            "Account status changed successfully"
+        );
+
+        Ok(Self::account_to_proto(&account, None))
     }
 
     /// Convert domain LedgerEntry to proto LedgerEntry.
@@ -106,14 +259,203 @@ impl LedgerServiceImpl {
                 .as_ref()
                 .map(|m| m.to_string())
                 .unwrap_or_default(),
+            base_currency: entry.base_currency.clone().unwrap_or_default(),
+            base_amount: entry
+                .base_amount
+                .map(|a| format_decimal(&a))
+                .unwrap_or_default(),
+            exchange_rate: entry
+                .exchange_rate
+                .map(|r| format_decimal(&r))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Convert domain ExchangeRate to proto ExchangeRate.
+    fn exchange_rate_to_proto(rate: &ExchangeRate) -> ProtoExchangeRate {
+        ProtoExchangeRate {
+            tenant_id: rate.tenant_id.to_string(),
+            from_currency: rate.from_currency.clone(),
+            to_currency: rate.to_currency.clone(),
+            rate_date: rate.rate_date.to_string(),
+            rate: format_decimal(&rate.rate),
+            created_at: Some(Timestamp {
+                seconds: rate.created_utc.timestamp(),
+                nanos: rate.created_utc.timestamp_subsec_nanos() as i32,
+            }),
+            updated_at: Some(Timestamp {
+                seconds: rate.updated_utc.timestamp(),
+                nanos: rate.updated_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+
+    /// Parse and validate a PostTransaction request (also used per journal by ImportTransactions).
+    #[allow(clippy::result_large_err)]
+    fn parse_post_request(req: &PostTransactionRequest) -> Result<ParsedPost<'_>, Status> {
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id)
+            .map_err(|_| Status::invalid_argument("Invalid tenant_id format"))?;
+
+        // Validate entries exist
+        if req.entries.is_empty() {
+            return Err(Status::invalid_argument(
+                "At least 2 entries required for a transaction",
+            ));
+        }
+
+        // Parse entries
+        let mut entries = Vec::with_capacity(req.entries.len());
+        for proto_entry in &req.entries {
+            let account_id = Uuid::parse_str(&proto_entry.account_id)
+                .map_err(|_| Status::invalid_argument("Invalid account_id format in entry"))?;
+
+            let amount = Decimal::from_str(&proto_entry.amount)
+                .map_err(|_| Status::invalid_argument("Invalid amount format in entry"))?;
+
+            let direction = Direction::from_proto(proto_entry.direction)
+                .ok_or_else(|| Status::invalid_argument("Invalid direction in entry"))?;
+
+            let exchange_rate = if proto_entry.exchange_rate.is_empty() {
+                None
+            } else {
+                match Decimal::from_str(&proto_entry.exchange_rate) {
+                    Ok(rate) if rate > Decimal::ZERO => Some(rate),
+                    _ => {
+                        return Err(Status::invalid_argument(
+                            "Invalid exchange_rate in entry (expected a positive decimal)",
+                        ));
+                    }
+                }
+            };
+
+            entries.push(PostEntry {
+                account_id,
+                amount,
+                direction,
+                exchange_rate,
+            });
+        }
+
+        let base_currency = if req.base_currency.is_empty() {
+            None
+        } else {
+            Some(req.base_currency.as_str())
+        };
+
+        // Parse effective date (defaults to today)
+        let effective_date = if req.effective_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.effective_date, "%Y-%m-%d").map_err(|_| {
+                Status::invalid_argument("Invalid effective_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        // Parse idempotency key
+        let idempotency_key = if req.idempotency_key.is_empty() {
+            None
+        } else {
+            Some(req.idempotency_key.as_str())
+        };
+
+        // Parse metadata
+        let metadata = if req.metadata.is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::from_str(&req.metadata)
+                    .map_err(|_| Status::invalid_argument("Invalid metadata JSON"))?,
+            )
+        };
+
+        Ok((
+            tenant_id,
+            entries,
+            effective_date,
+            idempotency_key,
+            metadata,
+            base_currency,
+        ))
+    }
+
+    /// Convert domain Hold to proto Hold.
+    fn hold_to_proto(hold: &Hold) -> ProtoHold {
+        ProtoHold {
+            hold_id: hold.hold_id.to_string(),
+            tenant_id: hold.tenant_id.to_string(),
+            debit_account_id: hold.debit_account_id.to_string(),
+            credit_account_id: hold.credit_account_id.to_string(),
+            amount: format_decimal(&hold.amount),
+            captured_amount: hold
+                .captured_amount
+                .map(|a| format_decimal(&a))
+                .unwrap_or_default(),
+            currency: hold.currency.clone(),
+            status: hold
+                .parsed_status()
+                .map(|s| s.to_proto())
+                .unwrap_or(ProtoHoldStatus::Unspecified as i32),
+            expires_at: Some(Timestamp {
+                seconds: hold.expires_utc.timestamp(),
+                nanos: hold.expires_utc.timestamp_subsec_nanos() as i32,
+            }),
+            idempotency_key: hold.idempotency_key.clone().unwrap_or_default(),
+            metadata: hold
+                .metadata
+                .as_ref()
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+            journal_id: hold.journal_id.map(|id| id.to_string()).unwrap_or_default(),
+            created_at: Some(Timestamp {
+                seconds: hold.created_utc.timestamp(),
+                nanos: hold.created_utc.timestamp_subsec_nanos() as i32,
+            }),
+            resolved_at: hold.resolved_utc.map(|t| Timestamp {
+                seconds: t.timestamp(),
+                nanos: t.timestamp_subsec_nanos() as i32,
+            }),
+        }
+    }
+
+    /// Convert domain AccountingPeriod to proto AccountingPeriod.
+    fn period_to_proto(period: &AccountingPeriod) -> ProtoAccountingPeriod {
+        ProtoAccountingPeriod {
+            period_id: period.period_id.to_string(),
+            tenant_id: period.tenant_id.to_string(),
+            name: period.name.clone(),
+            start_date: period.start_date.to_string(),
+            end_date: period.end_date.to_string(),
+            status: period
+                .parsed_status()
+                .map(|s| s.to_proto())
+                .unwrap_or(ProtoPeriodStatus::Unspecified as i32),
+            retained_earnings_account_id: period
+                .retained_earnings_account_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            closing_journal_id: period
+                .closing_journal_id
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            created_at: Some(Timestamp {
+                seconds: period.created_utc.timestamp(),
+                nanos: period.created_utc.timestamp_subsec_nanos() as i32,
+            }),
+            closed_at: period.closed_utc.map(|t| Timestamp {
+                seconds: t.timestamp(),
+                nanos: t.timestamp_subsec_nanos() as i32,
+            }),
         }
     }
 
     /// Convert entries to a Transaction proto.
+    /// `reversals` may contain links for other journals; only those touching `journal_id` are used.
     fn entries_to_transaction(
         tenant_id: Uuid,
         journal_id: Uuid,
         entries: &[LedgerEntry],
+        reversals: &[JournalReversal],
     ) -> ProtoTransaction {
         let effective_date = entries
             .first()
@@ -132,6 +474,23 @@ impl LedgerServiceImpl {
             .and_then(|e| e.metadata.as_ref())
             .map(|m| m.to_string())
             .unwrap_or_default();
+        let reversal = reversals
+            .iter()
+            .find(|r| r.original_journal_id == journal_id || r.reversal_journal_id == journal_id);
+        let reverses_journal_id = entries
+            .first()
+            .and_then(|e| e.reverses_journal_id)
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        let reversed_by_journal_id = reversal
+            .filter(|r| r.original_journal_id == journal_id)
+            .map(|r| r.reversal_journal_id.to_string())
+            .unwrap_or_default();
+        let reversal_reason = reversal.and_then(|r| r.reason.clone()).unwrap_or_default();
+        let base_currency = entries
+            .iter()
+            .find_map(|e| e.base_currency.clone())
+            .unwrap_or_default();
 
         ProtoTransaction {
             journal_id: journal_id.to_string(),
@@ -141,12 +500,172 @@ impl LedgerServiceImpl {
             posted_at,
             idempotency_key,
             metadata,
+            reverses_journal_id,
+            reversed_by_journal_id,
+            reversal_reason,
+            base_currency,
+        }
+    }
+
+    /// Build an account tree node and its descendants.
+    /// Returns the node together with its roll-up balance.
+    fn build_tree_node(
+        account: &Account,
+        balance: Decimal,
+        children: &HashMap<Uuid, Vec<(&Account, Decimal)>>,
+    ) -> (AccountTreeNode, Decimal) {
+        let mut rollup = balance;
+        let mut child_nodes = Vec::new();
+        for (child, child_balance) in children.get(&account.account_id).into_iter().flatten() {
+            let (node, child_rollup) = Self::build_tree_node(child, *child_balance, children);
+            rollup += child_rollup;
+            child_nodes.push(node);
+        }
+
+        let node = AccountTreeNode {
+            account: Some(Self::account_to_proto(account, Some(balance))),
+            rollup_balance: format_decimal(&rollup),
+            children: child_nodes,
+        };
+        (node, rollup)
+    }
+
+    /// Convert aggregated account activity to a report line.
+    /// The net balance goes into the debit or credit column depending on its side.
+    fn activity_to_report_line(activity: &AccountActivity) -> ReportLine {
+        let raw = activity.raw_balance();
+        ReportLine {
+            account_id: activity.account_id.to_string(),
+            account_code: activity.account_code.clone(),
+            account_type: activity.parsed_type().to_proto(),
+            debit_balance: format_decimal(&raw.max(Decimal::ZERO)),
+            credit_balance: format_decimal(&(-raw).max(Decimal::ZERO)),
+            balance: format_decimal(&activity.normal_balance()),
         }
     }
+
+    /// Build a trial balance for the accounts of one currency.
+    fn build_trial_balance(currency: &str, accounts: &[AccountActivity]) -> TrialBalance {
+        let mut total_debits = Decimal::ZERO;
+        let mut total_credits = Decimal::ZERO;
+        let mut type_totals: Vec<(AccountType, Decimal, Decimal, Decimal)> = Vec::new();
+
+        for activity in accounts {
+            let raw = activity.raw_balance();
+            let debit = raw.max(Decimal::ZERO);
+            let credit = (-raw).max(Decimal::ZERO);
+            total_debits += debit;
+            total_credits += credit;
+
+            let account_type = activity.parsed_type();
+            match type_totals.iter_mut().find(|(t, ..)| *t == account_type) {
+                Some((_, d, c, b)) => {
+                    *d += debit;
+                    *c += credit;
+                    *b += activity.normal_balance();
+                }
+                None => type_totals.push((account_type, debit, credit, activity.normal_balance())),
+            }
+        }
+
+        TrialBalance {
+            currency: currency.to_string(),
+            lines: accounts.iter().map(Self::activity_to_report_line).collect(),
+            type_totals: type_totals
+                .iter()
+                .map(|(t, d, c, b)| AccountTypeTotal {
+                    account_type: t.to_proto(),
+                    debit_balance: format_decimal(d),
+                    credit_balance: format_decimal(c),
+                    balance: format_decimal(b),
+                })
+                .collect(),
+            total_debits: format_decimal(&total_debits),
+            total_credits: format_decimal(&total_credits),
+            balanced: total_debits == total_credits,
+        }
+    }
+
+    /// Build a balance sheet for the accounts of one currency.
+    /// Revenue and expense not yet closed into equity are reported as net income.
+    fn build_balance_sheet(currency: &str, accounts: &[AccountActivity]) -> BalanceSheet {
+        let mut sheet = BalanceSheet {
+            currency: currency.to_string(),
+            ..Default::default()
+        };
+        let mut total_assets = Decimal::ZERO;
+        let mut total_liabilities = Decimal::ZERO;
+        let mut total_equity = Decimal::ZERO;
+        let mut net_income = Decimal::ZERO;
+
+        for activity in accounts {
+            let balance = activity.normal_balance();
+            match activity.parsed_type() {
+                AccountType::Asset => {
+                    total_assets += balance;
+                    sheet.assets.push(Self::activity_to_report_line(activity));
+                }
+                AccountType::Liability => {
+                    total_liabilities += balance;
+                    sheet
+                        .liabilities
+                        .push(Self::activity_to_report_line(activity));
+                }
+                AccountType::Equity => {
+                    total_equity += balance;
+                    sheet.equity.push(Self::activity_to_report_line(activity));
+                }
+                AccountType::Revenue => net_income += balance,
+                AccountType::Expense => net_income -= balance,
+            }
+        }
+
+        sheet.total_assets = format_decimal(&total_assets);
+        sheet.total_liabilities = format_decimal(&total_liabilities);
+        sheet.total_equity = format_decimal(&total_equity);
+        sheet.net_income = format_decimal(&net_income);
+        sheet.balanced = total_assets == total_liabilities + total_equity + net_income;
+        sheet
+    }
+
+    /// Build an income statement for the accounts of one currency.
+    fn build_income_statement(currency: &str, accounts: &[AccountActivity]) -> IncomeStatement {
+        let mut statement = IncomeStatement {
+            currency: currency.to_string(),
+            ..Default::default()
+        };
+        let mut total_revenue = Decimal::ZERO;
+        let mut total_expenses = Decimal::ZERO;
+
+        for activity in accounts {
+            match activity.parsed_type() {
+                AccountType::Revenue => {
+                    total_revenue += activity.normal_balance();
+                    statement
+                        .revenue
+                        .push(Self::activity_to_report_line(activity));
+                }
+                AccountType::Expense => {
+                    total_expenses += activity.normal_balance();
+                    statement
+                        .expenses
+                        .push(Self::activity_to_report_line(activity));
+                }
+                _ => {}
+            }
+        }
+
+        statement.total_revenue = format_decimal(&total_revenue);
+        statement.total_expenses = format_decimal(&total_expenses);
+        statement.net_income = format_decimal(&(total_revenue - total_expenses));
+        statement
+    }
 }
 
 #[tonic::async_trait]
 impl LedgerService for LedgerServiceImpl {
+    type ExportTransactionsStream = ExportStream;
+
     #[instrument(
         skip(self, request),
         fields(service = "ledger-service", method = "CreateAccount")
@@ -209,6 +728,18 @@ impl LedgerService for LedgerServiceImpl {
             })?)
         };
 
+        // Parse optional parent account
+        let parent_account_id = if req.parent_account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.parent_account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CreateAccount", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid parent_account_id format")
+            })?)
+        };
+
         let input = CreateAccount {
             tenant_id,
             account_type,
@@ -216,6 +747,7 @@ impl LedgerService for LedgerServiceImpl {
             currency: req.currency.to_uppercase(),
             allow_negative: req.allow_negative,
             metadata,
+            parent_account_id,
         };
 
         let account = self.db.create_account(&input).await.map_err(|e| {
@@ -224,6 +756,9 @@ impl LedgerService for LedgerServiceImpl {
                 .with_label_values(&["CreateAccount", "error"])
                 .inc();
             match e {
+                service_core::error::AppError::BadRequest(err) => {
+                    Status::invalid_argument(err.to_string())
+                }
                 service_core::error::AppError::Conflict(err) => {
                     Status::already_exists(err.to_string())
                 }
@@ -349,6 +884,18 @@ impl LedgerService for LedgerServiceImpl {
             Some(req.currency.as_str())
         };
 
+        // Parse optional status filter
+        let status = if req.status == ProtoAccountStatus::Unspecified as i32 {
+            None
+        } else {
+            Some(AccountStatus::from_proto(req.status).ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListAccounts", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid status filter")
+            })?)
+        };
+
         // Parse page token
         let page_token = if req.page_token.is_empty() {
             None
@@ -369,7 +916,14 @@ impl LedgerService for LedgerServiceImpl {
 
         let accounts = self
             .db
-            .list_accounts(tenant_id, account_type, currency, page_size, page_token)
+            .list_accounts(
+                tenant_id,
+                account_type,
+                currency,
+                status,
+                page_size,
+                page_token,
+            )
             .await
             .map_err(|e| {
                 warn!(error = %e, "Failed to list accounts");
@@ -404,14 +958,14 @@ impl LedgerService for LedgerServiceImpl {
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "PostTransaction")
+        fields(service = "ledger-service", method = "GetAccountTree")
     )]
-    async fn post_transaction(
+    async fn get_account_tree(
         &self,
-        request: Request<PostTransactionRequest>,
-    ) -> Result<Response<PostTransactionResponse>, Status> {
+        request: Request<GetAccountTreeRequest>,
+    ) -> Result<Response<GetAccountTreeResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["PostTransaction"])
+            .with_label_values(&["GetAccountTree"])
             .start_timer();
 
         let req = request.into_inner();
@@ -419,82 +973,234 @@ impl LedgerService for LedgerServiceImpl {
         // Parse tenant_id
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["PostTransaction", "invalid_argument"])
+                .with_label_values(&["GetAccountTree", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        // Validate entries exist
-        if req.entries.is_empty() {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["PostTransaction", "invalid_argument"])
-                .inc();
-            return Err(Status::invalid_argument(
-                "At least 2 entries required for a transaction",
-            ));
-        }
-
-        // Parse entries
-        let mut entries = Vec::with_capacity(req.entries.len());
-        for proto_entry in &req.entries {
-            let account_id = Uuid::parse_str(&proto_entry.account_id).map_err(|_| {
+        // Parse optional root account
+        let root_account_id = if req.root_account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.root_account_id).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["PostTransaction", "invalid_argument"])
+                    .with_label_values(&["GetAccountTree", "invalid_argument"])
                     .inc();
-                Status::invalid_argument("Invalid account_id format in entry")
-            })?;
+                Status::invalid_argument("Invalid root_account_id format")
+            })?)
+        };
+
+        // Parse as_of_date
+        let as_of_date = if req.as_of_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["GetAccountTree", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+                })?,
+            )
+        };
 
-            let amount = Decimal::from_str(&proto_entry.amount).map_err(|_| {
+        let tree = self
+            .db
+            .get_account_tree(tenant_id, root_account_id, as_of_date)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get account tree");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["PostTransaction", "invalid_argument"])
+                    .with_label_values(&["GetAccountTree", "error"])
                     .inc();
-                Status::invalid_argument("Invalid amount format in entry")
-            })?;
-
-            let direction = Direction::from_proto(proto_entry.direction).ok_or_else(|| {
+                Status::internal("Failed to get account tree")
+            })?
+            .ok_or_else(|| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["PostTransaction", "invalid_argument"])
+                    .with_label_values(&["GetAccountTree", "not_found"])
                     .inc();
-                Status::invalid_argument("Invalid direction in entry")
+                Status::not_found("Account not found")
             })?;
 
-            entries.push(PostEntry {
-                account_id,
-                amount,
-                direction,
-            });
+        // Group accounts under their parents; everything else is a root
+        let mut children: HashMap<Uuid, Vec<(&Account, Decimal)>> = HashMap::new();
+        let mut roots = Vec::new();
+        for (account, balance) in &tree {
+            match account.parent_account_id {
+                Some(parent_id) if Some(account.account_id) != root_account_id => children
+                    .entry(parent_id)
+                    .or_default()
+                    .push((account, *balance)),
+                _ => roots.push((account, *balance)),
+            }
         }
 
-        // Parse effective date (defaults to today)
-        let effective_date = if req.effective_date.is_empty() {
-            chrono::Utc::now().date_naive()
-        } else {
-            NaiveDate::parse_from_str(&req.effective_date, "%Y-%m-%d").map_err(|_| {
+        let roots = roots
+            .into_iter()
+            .map(|(account, balance)| Self::build_tree_node(account, balance, &children).0)
+            .collect();
+
+        timer.observe_duration();
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetAccountTree", "ok"])
+            .inc();
+
+        Ok(Response::new(GetAccountTreeResponse {
+            roots,
+            as_of_date: as_of_date
+                .unwrap_or_else(|| chrono::Utc::now().date_naive())
+                .to_string(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "FreezeAccount")
+    )]
+    async fn freeze_account(
+        &self,
+        request: Request<FreezeAccountRequest>,
+    ) -> Result<Response<FreezeAccountResponse>, Status> {
+        let req = request.into_inner();
+        let account = self
+            .change_account_status(
+                "FreezeAccount",
+                &req.tenant_id,
+                &req.account_id,
+                &req.actor,
+                &req.reason,
+                AccountStatus::Frozen,
+            )
+            .await?;
+
+        Ok(Response::new(FreezeAccountResponse {
+            account: Some(account),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "CloseAccount")
+    )]
+    async fn close_account(
+        &self,
+        request: Request<CloseAccountRequest>,
+    ) -> Result<Response<CloseAccountResponse>, Status> {
+        let req = request.into_inner();
+        let account = self
+            .change_account_status(
+                "CloseAccount",
+                &req.tenant_id,
+                &req.account_id,
+                &req.actor,
+                &req.reason,
+                AccountStatus::Closed,
+            )
+            .await?;
+
+        Ok(Response::new(CloseAccountResponse {
+            account: Some(account),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ReopenAccount")
+    )]
+    async fn reopen_account(
+        &self,
+        request: Request<ReopenAccountRequest>,
+    ) -> Result<Response<ReopenAccountResponse>, Status> {
+        let req = request.into_inner();
+        let account = self
+            .change_account_status(
+                "ReopenAccount",
+                &req.tenant_id,
+                &req.account_id,
+                &req.actor,
+                &req.reason,
+                AccountStatus::Active,
+            )
+            .await?;
+
+        Ok(Response::new(ReopenAccountResponse {
+            account: Some(account),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ListAccountStatusChanges")
+    )]
+    async fn list_account_status_changes(
+        &self,
+        request: Request<ListAccountStatusChangesRequest>,
+    ) -> Result<Response<ListAccountStatusChangesResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ListAccountStatusChanges"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ListAccountStatusChanges", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let account_id = Uuid::parse_str(&req.account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ListAccountStatusChanges", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid account_id format")
+        })?;
+
+        let changes = self
+            .db
+            .list_account_status_changes(tenant_id, account_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to list account status changes");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["PostTransaction", "invalid_argument"])
+                    .with_label_values(&["ListAccountStatusChanges", "error"])
                     .inc();
-                Status::invalid_argument("Invalid effective_date format (expected YYYY-MM-DD)")
-            })?
-        };
+                Status::internal("Failed to list account status changes")
+            })?;
 
-        // Parse idempotency key
-        let idempotency_key = if req.idempotency_key.is_empty() {
-            None
-        } else {
-            Some(req.idempotency_key.as_str())
-        };
+        timer.observe_duration();
 
-        // Parse metadata
-        let metadata = if req.metadata.is_empty() {
-            None
-        } else {
-            Some(serde_json::from_str(&req.metadata).map_err(|_| {
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ListAccountStatusChanges", "ok"])
+            .inc();
+
+        Ok(Response::new(ListAccountStatusChangesResponse {
+            changes: changes.iter().map(Self::status_change_to_proto).collect(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "PostTransaction")
+    )]
+    async fn post_transaction(
+        &self,
+        request: Request<PostTransactionRequest>,
+    ) -> Result<Response<PostTransactionResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["PostTransaction"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        let (tenant_id, entries, effective_date, idempotency_key, metadata, base_currency) =
+            Self::parse_post_request(&req).inspect_err(|_| {
                 GRPC_REQUESTS_TOTAL
                     .with_label_values(&["PostTransaction", "invalid_argument"])
                     .inc();
-                Status::invalid_argument("Invalid metadata JSON")
-            })?)
-        };
+            })?;
 
         // Post the transaction
         let (journal_id, inserted_entries, currency) = self
@@ -505,6 +1211,7 @@ impl LedgerService for LedgerServiceImpl {
                 effective_date,
                 idempotency_key,
                 metadata,
+                base_currency,
             )
             .await
             .map_err(|e| {
@@ -516,6 +1223,14 @@ impl LedgerService for LedgerServiceImpl {
                     service_core::error::AppError::BadRequest(err) => {
                         Status::invalid_argument(err.to_string())
                     }
+                    service_core::error::AppError::Conflict(err)
+                        if err.is::<IdempotencyConflict>() =>
+                    {
+                        Status::already_exists(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
                     _ => Status::internal("Failed to post transaction"),
                 }
             })?;
@@ -525,12 +1240,12 @@ impl LedgerService for LedgerServiceImpl {
             .inc();
         TRANSACTIONS_TOTAL.with_label_values(&["ok"]).inc();
 
-        // P3: Record entry and amount metrics
-        for entry in &entries {
+        // P3: Record entry and amount metrics (in the journal currency)
+        for entry in &inserted_entries {
             let direction_str = entry.direction.as_str();
             ENTRIES_TOTAL.with_label_values(&[direction_str]).inc();
             // Convert Decimal to f64 for counter (counters only accept f64)
-            if let Some(amount_f64) = entry.amount.to_f64() {
+            if let Some(amount_f64) = entry.base_amount.unwrap_or(entry.amount).to_f64() {
                 AMOUNT_TOTAL
                     .with_label_values(&[direction_str, &currency])
                     .inc_by(amount_f64);
@@ -550,20 +1265,21 @@ impl LedgerService for LedgerServiceImpl {
                 tenant_id,
                 journal_id,
                 &inserted_entries,
+                &[],
             )),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "GetTransaction")
+        fields(service = "ledger-service", method = "ReverseTransaction")
     )]
-    async fn get_transaction(
+    async fn reverse_transaction(
         &self,
-        request: Request<GetTransactionRequest>,
-    ) -> Result<Response<GetTransactionResponse>, Status> {
+        request: Request<ReverseTransactionRequest>,
+    ) -> Result<Response<ReverseTransactionResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetTransaction"])
+            .with_label_values(&["ReverseTransaction"])
             .start_timer();
 
         let req = request.into_inner();
@@ -571,60 +1287,240 @@ impl LedgerService for LedgerServiceImpl {
         // Parse IDs
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetTransaction", "invalid_argument"])
+                .with_label_values(&["ReverseTransaction", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
         let journal_id = Uuid::parse_str(&req.journal_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetTransaction", "invalid_argument"])
+                .with_label_values(&["ReverseTransaction", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid journal_id format")
         })?;
 
-        let entries = self
+        // Parse effective date (defaults to today)
+        let effective_date = if req.effective_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.effective_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ReverseTransaction", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid effective_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let reason = if req.reason.is_empty() {
+            None
+        } else {
+            Some(req.reason.as_str())
+        };
+
+        // Parse metadata
+        let metadata = if req.metadata.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_str(&req.metadata).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ReverseTransaction", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid metadata JSON")
+            })?)
+        };
+
+        let (reversal_journal_id, inserted_entries, _currency) = self
             .db
-            .get_entries_by_journal(tenant_id, journal_id)
+            .reverse_transaction(tenant_id, journal_id, effective_date, reason, metadata)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get transaction");
+                warn!(error = %e, "Failed to reverse transaction");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetTransaction", "error"])
+                    .with_label_values(&["ReverseTransaction", "error"])
                     .inc();
-                Status::internal("Failed to get transaction")
+                match e {
+                    service_core::error::AppError::NotFound(err) => {
+                        Status::not_found(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::invalid_argument(err.to_string())
+                    }
+                    _ => Status::internal("Failed to reverse transaction"),
+                }
             })?;
 
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ReverseTransaction", "ok"])
+            .inc();
+        TRANSACTIONS_TOTAL.with_label_values(&["reversed"]).inc();
+
         timer.observe_duration();
 
-        if entries.is_empty() {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetTransaction", "not_found"])
-                .inc();
-            return Err(Status::not_found("Transaction not found"));
-        }
+        info!(
+            journal_id = %journal_id,
+            reversal_journal_id = %reversal_journal_id,
+            "Transaction reversed successfully"
+        );
 
-        GRPC_REQUESTS_TOTAL
-            .with_label_values(&["GetTransaction", "ok"])
-            .inc();
+        let reversal = JournalReversal {
+            original_journal_id: journal_id,
+            reversal_journal_id,
+            tenant_id,
+            reason: reason.map(str::to_string),
+            reversed_utc: chrono::Utc::now(),
+        };
 
-        Ok(Response::new(GetTransactionResponse {
+        Ok(Response::new(ReverseTransactionResponse {
             transaction: Some(Self::entries_to_transaction(
-                tenant_id, journal_id, &entries,
+                tenant_id,
+                reversal_journal_id,
+                &inserted_entries,
+                &[reversal],
             )),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "ListTransactions")
+        fields(service = "ledger-service", method = "ImportTransactions")
     )]
-    async fn list_transactions(
+    async fn import_transactions(
         &self,
-        request: Request<ListTransactionsRequest>,
-    ) -> Result<Response<ListTransactionsResponse>, Status> {
+        request: Request<Streaming<ImportTransactionsRequest>>,
+    ) -> Result<Response<ImportTransactionsResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["ListTransactions"])
+            .with_label_values(&["ImportTransactions"])
+            .start_timer();
+
+        let mut stream = request.into_inner();
+        let mut response = ImportTransactionsResponse::default();
+
+        while let Some(batch) = stream.next().await {
+            let batch = batch.map_err(|e| {
+                warn!(error = %e, "Import stream failed");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ImportTransactions", "error"])
+                    .inc();
+                Status::internal(format!("Stream error: {}", e))
+            })?;
+
+            for req in &batch.transactions {
+                response.rows_received += 1;
+
+                let mut fail = |status: Status| {
+                    response.rows_failed += 1;
+                    if response.errors.len() < MAX_IMPORT_ERRORS {
+                        response.errors.push(ImportRowError {
+                            row_number: response.rows_received,
+                            idempotency_key: req.idempotency_key.clone(),
+                            code: match status.code() {
+                                tonic::Code::InvalidArgument => "invalid_argument",
+                                tonic::Code::AlreadyExists => "already_exists",
+                                tonic::Code::FailedPrecondition => "failed_precondition",
+                                _ => "internal",
+                            }
+                            .to_string(),
+                            message: status.message().to_string(),
+                        });
+                    }
+                };
+
+                let (tenant_id, entries, effective_date, idempotency_key, metadata, base_currency) =
+                    match Self::parse_post_request(req) {
+                        Ok(parsed) => parsed,
+                        Err(status) => {
+                            fail(status);
+                            continue;
+                        }
+                    };
+
+                // Rows already posted under their key are replayed, which still
+                // rejects a row whose payload differs from the original
+                let replay = match idempotency_key {
+                    Some(key) => match self
+                        .db
+                        .find_journal_by_idempotency_key(tenant_id, key)
+                        .await
+                    {
+                        Ok(existing) => existing.is_some(),
+                        Err(e) => {
+                            warn!(error = %e, "Failed to check idempotency during import");
+                            fail(Status::internal("Failed to post transaction"));
+                            continue;
+                        }
+                    },
+                    None => false,
+                };
+
+                let result = self
+                    .db
+                    .post_transaction(
+                        tenant_id,
+                        &entries,
+                        effective_date,
+                        idempotency_key,
+                        metadata,
+                        base_currency,
+                    )
+                    .await;
+
+                match result {
+                    Ok(_) if replay => response.rows_replayed += 1,
+                    Ok(_) => {
+                        response.rows_posted += 1;
+                        TRANSACTIONS_TOTAL.with_label_values(&["ok"]).inc();
+                    }
+                    Err(e) => fail(match e {
+                        service_core::error::AppError::BadRequest(err) => {
+                            Status::invalid_argument(err.to_string())
+                        }
+                        service_core::error::AppError::Conflict(err)
+                            if err.is::<IdempotencyConflict>() =>
+                        {
+                            Status::already_exists(err.to_string())
+                        }
+                        service_core::error::AppError::Conflict(err) => {
+                            Status::failed_precondition(err.to_string())
+                        }
+                        e => {
+                            warn!(error = %e, "Failed to post imported transaction");
+                            Status::internal("Failed to post transaction")
+                        }
+                    }),
+                }
+            }
+        }
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ImportTransactions", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        info!(
+            rows_received = response.rows_received,
+            rows_posted = response.rows_posted,
+            rows_replayed = response.rows_replayed,
+            rows_failed = response.rows_failed,
+            "Transactions imported"
+        );
+
+        Ok(Response::new(response))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ExportTransactions")
+    )]
+    async fn export_transactions(
+        &self,
+        request: Request<ExportTransactionsRequest>,
+    ) -> Result<Response<Self::ExportTransactionsStream>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ExportTransactions"])
             .start_timer();
 
         let req = request.into_inner();
@@ -632,48 +1528,352 @@ impl LedgerService for LedgerServiceImpl {
         // Parse tenant_id
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["ListTransactions", "invalid_argument"])
+                .with_label_values(&["ExportTransactions", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        // Parse optional account_id filter
-        let account_id = if req.account_id.is_empty() {
-            None
-        } else {
-            Some(Uuid::parse_str(&req.account_id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListTransactions", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid account_id format")
-            })?)
-        };
+        // Parse date range
+        let start_date = NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ExportTransactions", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+        })?;
 
-        // Parse date filters
-        let start_date = if req.start_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListTransactions", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
-                })?,
-            )
-        };
+        let end_date = NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ExportTransactions", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+        })?;
 
-        let end_date = if req.end_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["ListTransactions", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
-                })?,
-            )
+        if end_date < start_date {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ExportTransactions", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "end_date must not be before start_date",
+            ));
+        }
+
+        let format = ExportFormat::from_proto(req.format).ok_or_else(|| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ExportTransactions", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid format")
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ExportTransactions", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        // Stream pages of entries; a journal split across pages is held back
+        // until its last entry has been read so JSONL lines stay whole
+        let db = self.db.clone();
+        let (tx, rx) = mpsc::channel(4);
+
+        tokio::spawn(async move {
+            let mut after: Option<LedgerEntry> = None;
+            let mut pending: Vec<ExportedEntry> = Vec::new();
+            let mut first_page = true;
+
+            loop {
+                let page = match db
+                    .export_entries(
+                        tenant_id,
+                        start_date,
+                        end_date,
+                        after.as_ref(),
+                        EXPORT_PAGE_SIZE,
+                    )
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(e) => {
+                        warn!(error = %e, "Failed to export transactions");
+                        let _ = tx
+                            .send(Err(Status::internal("Failed to export transactions")))
+                            .await;
+                        return;
+                    }
+                };
+                let last_page = (page.len() as i64) < EXPORT_PAGE_SIZE;
+                after = page.last().map(|row| row.entry.clone());
+
+                let mut data = String::new();
+                if first_page && format == ExportFormat::Csv {
+                    data.push_str(export::CSV_HEADER);
+                }
+                first_page = false;
+
+                for row in page {
+                    match format {
+                        ExportFormat::Csv => data.push_str(&export::csv_row(&row)),
+                        ExportFormat::Jsonl => {
+                            if pending
+                                .first()
+                                .is_some_and(|p| p.entry.journal_id != row.entry.journal_id)
+                            {
+                                data.extend(export::jsonl_line(&pending));
+                                pending.clear();
+                            }
+                            pending.push(row);
+                        }
+                    }
+                }
+                if last_page {
+                    data.extend(export::jsonl_line(&pending));
+                }
+
+                if !data.is_empty()
+                    && tx
+                        .send(Ok(ExportTransactionsResponse { data }))
+                        .await
+                        .is_err()
+                {
+                    return;
+                }
+                if last_page {
+                    return;
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "CreateHold")
+    )]
+    async fn create_hold(
+        &self,
+        request: Request<CreateHoldRequest>,
+    ) -> Result<Response<CreateHoldResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["CreateHold"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let debit_account_id = Uuid::parse_str(&req.debit_account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid debit_account_id format")
+        })?;
+
+        let credit_account_id = Uuid::parse_str(&req.credit_account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid credit_account_id format")
+        })?;
+
+        let amount = Decimal::from_str(&req.amount).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid amount format")
+        })?;
+
+        // Expiry defaults to the configured TTL
+        if req.expires_in_seconds < 0 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "expires_in_seconds must not be negative",
+            ));
+        }
+        let ttl_secs = if req.expires_in_seconds == 0 {
+            self.holds.default_ttl_secs
+        } else {
+            req.expires_in_seconds
+        };
+        let expires_utc = chrono::Duration::try_seconds(ttl_secs)
+            .and_then(|ttl| chrono::Utc::now().checked_add_signed(ttl))
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CreateHold", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("expires_in_seconds is out of range")
+            })?;
+
+        let idempotency_key = if req.idempotency_key.is_empty() {
+            None
+        } else {
+            Some(req.idempotency_key)
+        };
+
+        // Parse metadata
+        let metadata = if req.metadata.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_str(&req.metadata).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CreateHold", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid metadata JSON")
+            })?)
+        };
+
+        let input = CreateHold {
+            tenant_id,
+            debit_account_id,
+            credit_account_id,
+            amount,
+            expires_in_seconds: req.expires_in_seconds,
+            expires_utc,
+            idempotency_key,
+            metadata,
+        };
+
+        let hold = self.db.create_hold(&input).await.map_err(|e| {
+            warn!(error = %e, "Failed to create hold");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CreateHold", "error"])
+                .inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => {
+                    Status::invalid_argument(err.to_string())
+                }
+                service_core::error::AppError::Conflict(err)
+                    if err.is::<HoldIdempotencyConflict>() =>
+                {
+                    Status::already_exists(err.to_string())
+                }
+                service_core::error::AppError::Conflict(err) => {
+                    Status::failed_precondition(err.to_string())
+                }
+                _ => Status::internal("Failed to create hold"),
+            }
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["CreateHold", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(CreateHoldResponse {
+            hold: Some(Self::hold_to_proto(&hold)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetHold")
+    )]
+    async fn get_hold(
+        &self,
+        request: Request<GetHoldRequest>,
+    ) -> Result<Response<GetHoldResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetHold"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let hold_id = Uuid::parse_str(&req.hold_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid hold_id format")
+        })?;
+
+        let hold = self
+            .db
+            .get_hold(tenant_id, hold_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get hold");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetHold", "error"])
+                    .inc();
+                Status::internal("Failed to get hold")
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetHold", "not_found"])
+                    .inc();
+                Status::not_found("Hold not found")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetHold", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(GetHoldResponse {
+            hold: Some(Self::hold_to_proto(&hold)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ListHolds")
+    )]
+    async fn list_holds(
+        &self,
+        request: Request<ListHoldsRequest>,
+    ) -> Result<Response<ListHoldsResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ListHolds"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ListHolds", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse optional account filter
+        let account_id = if req.account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListHolds", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid account_id format")
+            })?)
+        };
+
+        // Parse optional status filter
+        let status = if req.status == ProtoHoldStatus::Unspecified as i32 {
+            None
+        } else {
+            Some(HoldStatus::from_proto(req.status).ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListHolds", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid status filter")
+            })?)
         };
 
         // Parse page token
@@ -682,7 +1882,7 @@ impl LedgerService for LedgerServiceImpl {
         } else {
             Some(Uuid::parse_str(&req.page_token).map_err(|_| {
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListTransactions", "invalid_argument"])
+                    .with_label_values(&["ListHolds", "invalid_argument"])
                     .inc();
                 Status::invalid_argument("Invalid page_token format")
             })?)
@@ -691,55 +1891,1435 @@ impl LedgerService for LedgerServiceImpl {
         let page_size = if req.page_size <= 0 {
             20
         } else {
-            req.page_size
+            req.page_size.min(100)
         };
 
-        let transactions = self
+        let holds = self
             .db
-            .list_transactions(
-                tenant_id, account_id, start_date, end_date, page_size, page_token,
-            )
+            .list_holds(tenant_id, account_id, status, page_size, page_token)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to list transactions");
+                warn!(error = %e, "Failed to list holds");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["ListTransactions", "error"])
+                    .with_label_values(&["ListHolds", "error"])
                     .inc();
-                Status::internal("Failed to list transactions")
+                Status::internal("Failed to list holds")
             })?;
 
         timer.observe_duration();
 
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["ListTransactions", "ok"])
+            .with_label_values(&["ListHolds", "ok"])
+            .inc();
+
+        // Generate next page token if we got a full page
+        let next_page_token = if holds.len() == page_size as usize {
+            holds.last().map(|h| h.hold_id.to_string())
+        } else {
+            None
+        };
+
+        Ok(Response::new(ListHoldsResponse {
+            holds: holds.iter().map(Self::hold_to_proto).collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "CaptureHold")
+    )]
+    async fn capture_hold(
+        &self,
+        request: Request<CaptureHoldRequest>,
+    ) -> Result<Response<CaptureHoldResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["CaptureHold"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CaptureHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let hold_id = Uuid::parse_str(&req.hold_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["CaptureHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid hold_id format")
+        })?;
+
+        // Amount defaults to the full hold
+        let amount = if req.amount.is_empty() {
+            None
+        } else {
+            Some(Decimal::from_str(&req.amount).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CaptureHold", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid amount format")
+            })?)
+        };
+
+        // Parse effective date (defaults to today)
+        let effective_date = if req.effective_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.effective_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CaptureHold", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid effective_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        // Parse metadata
+        let metadata = if req.metadata.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_str(&req.metadata).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CaptureHold", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid metadata JSON")
+            })?)
+        };
+
+        let (hold, journal_id, inserted_entries, currency) = self
+            .db
+            .capture_hold(tenant_id, hold_id, amount, effective_date, metadata)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to capture hold");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CaptureHold", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::invalid_argument(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to capture hold"),
+                }
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["CaptureHold", "not_found"])
+                    .inc();
+                Status::not_found("Hold not found")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["CaptureHold", "ok"])
+            .inc();
+        TRANSACTIONS_TOTAL.with_label_values(&["ok"]).inc();
+
+        for entry in &inserted_entries {
+            let direction_str = entry.direction.as_str();
+            ENTRIES_TOTAL.with_label_values(&[direction_str]).inc();
+            if let Some(amount_f64) = entry.amount.to_f64() {
+                AMOUNT_TOTAL
+                    .with_label_values(&[direction_str, &currency])
+                    .inc_by(amount_f64);
+            }
+        }
+
+        timer.observe_duration();
+
+        info!(
+            hold_id = %hold_id,
+            journal_id = %journal_id,
+            "Hold captured successfully"
+        );
+
+        Ok(Response::new(CaptureHoldResponse {
+            hold: Some(Self::hold_to_proto(&hold)),
+            transaction: Some(Self::entries_to_transaction(
+                tenant_id,
+                journal_id,
+                &inserted_entries,
+                &[],
+            )),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ReleaseHold")
+    )]
+    async fn release_hold(
+        &self,
+        request: Request<ReleaseHoldRequest>,
+    ) -> Result<Response<ReleaseHoldResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ReleaseHold"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ReleaseHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let hold_id = Uuid::parse_str(&req.hold_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ReleaseHold", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid hold_id format")
+        })?;
+
+        let hold = self
+            .db
+            .release_hold(tenant_id, hold_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to release hold");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ReleaseHold", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to release hold"),
+                }
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ReleaseHold", "not_found"])
+                    .inc();
+                Status::not_found("Hold not found")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ReleaseHold", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(ReleaseHoldResponse {
+            hold: Some(Self::hold_to_proto(&hold)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetTransaction")
+    )]
+    async fn get_transaction(
+        &self,
+        request: Request<GetTransactionRequest>,
+    ) -> Result<Response<GetTransactionResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetTransaction"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTransaction", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let journal_id = Uuid::parse_str(&req.journal_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTransaction", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid journal_id format")
+        })?;
+
+        let entries = self
+            .db
+            .get_entries_by_journal(tenant_id, journal_id)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get transaction");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetTransaction", "error"])
+                    .inc();
+                Status::internal("Failed to get transaction")
+            })?;
+
+        timer.observe_duration();
+
+        if entries.is_empty() {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTransaction", "not_found"])
+                .inc();
+            return Err(Status::not_found("Transaction not found"));
+        }
+
+        let reversals = self
+            .db
+            .get_reversals(tenant_id, &[journal_id])
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get reversal status");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetTransaction", "error"])
+                    .inc();
+                Status::internal("Failed to get transaction")
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetTransaction", "ok"])
+            .inc();
+
+        Ok(Response::new(GetTransactionResponse {
+            transaction: Some(Self::entries_to_transaction(
+                tenant_id, journal_id, &entries, &reversals,
+            )),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "ListTransactions")
+    )]
+    async fn list_transactions(
+        &self,
+        request: Request<ListTransactionsRequest>,
+    ) -> Result<Response<ListTransactionsResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["ListTransactions"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ListTransactions", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse optional account_id filter
+        let account_id = if req.account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListTransactions", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid account_id format")
+            })?)
+        };
+
+        // Parse date filters
+        let start_date = if req.start_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListTransactions", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+                })?,
+            )
+        };
+
+        let end_date = if req.end_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["ListTransactions", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+                })?,
+            )
+        };
+
+        // Parse page token
+        let page_token = if req.page_token.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.page_token).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListTransactions", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid page_token format")
+            })?)
+        };
+
+        let page_size = if req.page_size <= 0 {
+            20
+        } else {
+            req.page_size
+        };
+
+        let transactions = self
+            .db
+            .list_transactions(
+                tenant_id, account_id, start_date, end_date, page_size, page_token,
+            )
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to list transactions");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListTransactions", "error"])
+                    .inc();
+                Status::internal("Failed to list transactions")
+            })?;
+
+        let journal_ids: Vec<Uuid> = transactions.iter().map(|(jid, _)| *jid).collect();
+        let reversals = self
+            .db
+            .get_reversals(tenant_id, &journal_ids)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get reversal status");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["ListTransactions", "error"])
+                    .inc();
+                Status::internal("Failed to list transactions")
+            })?;
+
+        timer.observe_duration();
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ListTransactions", "ok"])
+            .inc();
+
+        // Generate next page token
+        let next_page_token = if transactions.len() == page_size as usize {
+            transactions.last().map(|(jid, _)| jid.to_string())
+        } else {
+            None
+        };
+
+        Ok(Response::new(ListTransactionsResponse {
+            transactions: transactions
+                .iter()
+                .map(|(jid, entries)| {
+                    Self::entries_to_transaction(tenant_id, *jid, entries, &reversals)
+                })
+                .collect(),
+            next_page_token: next_page_token.unwrap_or_default(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetBalance")
+    )]
+    async fn get_balance(
+        &self,
+        request: Request<GetBalanceRequest>,
+    ) -> Result<Response<GetBalanceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetBalance"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBalance", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let account_id = Uuid::parse_str(&req.account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBalance", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid account_id format")
+        })?;
+
+        // Parse as_of_date
+        let as_of_date = if req.as_of_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["GetBalance", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+                })?,
+            )
+        };
+
+        let result = if req.include_descendants {
+            self.db
+                .get_rollup_balance(tenant_id, account_id, as_of_date)
+                .await
+        } else {
+            self.db.get_balance(tenant_id, account_id, as_of_date).await
+        }
+        .map_err(|e| {
+            warn!(error = %e, "Failed to get balance");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBalance", "error"])
+                .inc();
+            Status::internal("Failed to get balance")
+        })?;
+
+        // Holds reserve the current balance, so available is only reported for it
+        let available_balance =
+            if result.is_some() && as_of_date.is_none() && !req.include_descendants {
+                self.db
+                    .get_available_balance(tenant_id, account_id)
+                    .await
+                    .map_err(|e| {
+                        warn!(error = %e, "Failed to get available balance");
+                        GRPC_REQUESTS_TOTAL
+                            .with_label_values(&["GetBalance", "error"])
+                            .inc();
+                        Status::internal("Failed to get balance")
+                    })?
+            } else {
+                None
+            };
+
+        timer.observe_duration();
+
+        match result {
+            Some((balance, currency)) => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalance", "ok"])
+                    .inc();
+                Ok(Response::new(GetBalanceResponse {
+                    account_id: account_id.to_string(),
+                    balance: format_decimal(&balance),
+                    currency,
+                    as_of_date: as_of_date
+                        .unwrap_or_else(|| chrono::Utc::now().date_naive())
+                        .to_string(),
+                    available_balance: available_balance
+                        .map(|b| format_decimal(&b))
+                        .unwrap_or_default(),
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalance", "not_found"])
+                    .inc();
+                Err(Status::not_found("Account not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetBalances")
+    )]
+    async fn get_balances(
+        &self,
+        request: Request<GetBalancesRequest>,
+    ) -> Result<Response<GetBalancesResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetBalances"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBalances", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse account_ids
+        let mut account_ids = Vec::with_capacity(req.account_ids.len());
+        for id in &req.account_ids {
+            let account_id = Uuid::parse_str(id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalances", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid account_id format")
+            })?;
+            account_ids.push(account_id);
+        }
+
+        // Parse as_of_date
+        let as_of_date = if req.as_of_date.is_empty() {
+            None
+        } else {
+            Some(
+                NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["GetBalances", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+                })?,
+            )
+        };
+
+        let results = self
+            .db
+            .get_balances(tenant_id, &account_ids, as_of_date, req.include_descendants)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get balances");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalances", "error"])
+                    .inc();
+                Status::internal("Failed to get balances")
+            })?;
+
+        timer.observe_duration();
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetBalances", "ok"])
+            .inc();
+
+        let as_of_str = as_of_date
+            .unwrap_or_else(|| chrono::Utc::now().date_naive())
+            .to_string();
+
+        Ok(Response::new(GetBalancesResponse {
+            balances: results
+                .iter()
+                .map(|(account_id, balance, currency)| GetBalanceResponse {
+                    account_id: account_id.to_string(),
+                    balance: format_decimal(balance),
+                    currency: currency.clone(),
+                    as_of_date: as_of_str.clone(),
+                    // Batch queries report posted balances only
+                    available_balance: String::new(),
+                })
+                .collect(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetStatement")
+    )]
+    async fn get_statement(
+        &self,
+        request: Request<GetStatementRequest>,
+    ) -> Result<Response<GetStatementResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetStatement"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let account_id = Uuid::parse_str(&req.account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid account_id format")
+        })?;
+
+        // Parse dates
+        let start_date = NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+        })?;
+
+        let end_date = NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+        })?;
+
+        if end_date < start_date {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetStatement", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument("end_date must be >= start_date"));
+        }
+
+        let result = self
+            .db
+            .get_statement(tenant_id, account_id, start_date, end_date)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get statement");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetStatement", "error"])
+                    .inc();
+                Status::internal("Failed to get statement")
+            })?;
+
+        timer.observe_duration();
+
+        match result {
+            Some((currency, opening_balance, closing_balance, entries)) => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetStatement", "ok"])
+                    .inc();
+
+                // Build statement lines with running balance
+                let mut running = opening_balance;
+                let lines: Vec<_> = entries
+                    .iter()
+                    .map(|e| {
+                        match e.direction.as_str() {
+                            "debit" => running += e.amount,
+                            "credit" => running -= e.amount,
+                            _ => {}
+                        }
+                        crate::grpc::proto::StatementLine {
+                            entry_id: e.entry_id.to_string(),
+                            journal_id: e.journal_id.to_string(),
+                            effective_date: e.effective_date.to_string(),
+                            direction: match e.direction.as_str() {
+                                "debit" => ProtoDirection::Debit as i32,
+                                "credit" => ProtoDirection::Credit as i32,
+                                _ => ProtoDirection::Unspecified as i32,
+                            },
+                            amount: format_decimal(&e.amount),
+                            running_balance: format_decimal(&running),
+                            metadata: e
+                                .metadata
+                                .as_ref()
+                                .map(|m| m.to_string())
+                                .unwrap_or_default(),
+                        }
+                    })
+                    .collect();
+
+                Ok(Response::new(GetStatementResponse {
+                    account_id: account_id.to_string(),
+                    currency,
+                    opening_balance: format_decimal(&opening_balance),
+                    closing_balance: format_decimal(&closing_balance),
+                    lines,
+                }))
+            }
+            None => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetStatement", "not_found"])
+                    .inc();
+                Err(Status::not_found("Account not found"))
+            }
+        }
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetTrialBalance")
+    )]
+    async fn get_trial_balance(
+        &self,
+        request: Request<GetTrialBalanceRequest>,
+    ) -> Result<Response<GetTrialBalanceResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetTrialBalance"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetTrialBalance", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse as_of_date
+        let as_of_date = if req.as_of_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetTrialBalance", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let snapshot = self
+            .db
+            .get_balance_snapshot(tenant_id, as_of_date)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to read balances for GetTrialBalance");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetTrialBalance", "error"])
+                    .inc();
+                Status::internal("Failed to build report")
+            })?;
+
+        let trial_balances = snapshot
+            .accounts
+            .chunk_by(|a, b| a.currency == b.currency)
+            .map(|accounts| Self::build_trial_balance(&accounts[0].currency, accounts))
+            .collect();
+
+        timer.observe_duration();
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetTrialBalance", "ok"])
+            .inc();
+
+        Ok(Response::new(GetTrialBalanceResponse {
+            as_of_date: as_of_date.to_string(),
+            trial_balances,
+            generated_at: Some(Timestamp {
+                seconds: snapshot.snapshot_utc.timestamp(),
+                nanos: snapshot.snapshot_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetBalanceSheet")
+    )]
+    async fn get_balance_sheet(
+        &self,
+        request: Request<GetBalanceSheetRequest>,
+    ) -> Result<Response<GetBalanceSheetResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetBalanceSheet"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBalanceSheet", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse as_of_date
+        let as_of_date = if req.as_of_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalanceSheet", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let snapshot = self
+            .db
+            .get_balance_snapshot(tenant_id, as_of_date)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to read balances for GetBalanceSheet");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetBalanceSheet", "error"])
+                    .inc();
+                Status::internal("Failed to build report")
+            })?;
+
+        let balance_sheets = snapshot
+            .accounts
+            .chunk_by(|a, b| a.currency == b.currency)
+            .map(|accounts| Self::build_balance_sheet(&accounts[0].currency, accounts))
+            .collect();
+
+        timer.observe_duration();
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetBalanceSheet", "ok"])
+            .inc();
+
+        Ok(Response::new(GetBalanceSheetResponse {
+            as_of_date: as_of_date.to_string(),
+            balance_sheets,
+            generated_at: Some(Timestamp {
+                seconds: snapshot.snapshot_utc.timestamp(),
+                nanos: snapshot.snapshot_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetIncomeStatement")
+    )]
+    async fn get_income_statement(
+        &self,
+        request: Request<GetIncomeStatementRequest>,
+    ) -> Result<Response<GetIncomeStatementResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetIncomeStatement"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetIncomeStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse dates
+        let start_date = NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetIncomeStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+        })?;
+
+        let end_date = NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetIncomeStatement", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+        })?;
+
+        if end_date < start_date {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetIncomeStatement", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument("end_date must be >= start_date"));
+        }
+
+        let snapshot = self
+            .db
+            .get_income_snapshot(tenant_id, start_date, end_date)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to read balances for GetIncomeStatement");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetIncomeStatement", "error"])
+                    .inc();
+                Status::internal("Failed to build report")
+            })?;
+
+        let income_statements = snapshot
+            .accounts
+            .chunk_by(|a, b| a.currency == b.currency)
+            .map(|accounts| Self::build_income_statement(&accounts[0].currency, accounts))
+            .collect();
+
+        timer.observe_duration();
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetIncomeStatement", "ok"])
+            .inc();
+
+        Ok(Response::new(GetIncomeStatementResponse {
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            income_statements,
+            generated_at: Some(Timestamp {
+                seconds: snapshot.snapshot_utc.timestamp(),
+                nanos: snapshot.snapshot_utc.timestamp_subsec_nanos() as i32,
+            }),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "UpsertExchangeRate")
+    )]
+    async fn upsert_exchange_rate(
+        &self,
+        request: Request<UpsertExchangeRateRequest>,
+    ) -> Result<Response<UpsertExchangeRateResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["UpsertExchangeRate"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpsertExchangeRate", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Validate currencies (ISO 4217)
+        if req.from_currency.len() != 3 || req.to_currency.len() != 3 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpsertExchangeRate", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "from_currency and to_currency must be 3-letter ISO 4217 codes",
+            ));
+        }
+        let from_currency = req.from_currency.to_uppercase();
+        let to_currency = req.to_currency.to_uppercase();
+        if from_currency == to_currency {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpsertExchangeRate", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "from_currency and to_currency must differ",
+            ));
+        }
+
+        // Parse rate date (defaults to today)
+        let rate_date = if req.rate_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.rate_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpsertExchangeRate", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid rate_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let rate = match Decimal::from_str(&req.rate) {
+            Ok(rate) if rate > Decimal::ZERO => rate,
+            _ => {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["UpsertExchangeRate", "invalid_argument"])
+                    .inc();
+                return Err(Status::invalid_argument(
+                    "Invalid rate (expected a positive decimal)",
+                ));
+            }
+        };
+
+        let input = UpsertExchangeRate {
+            tenant_id,
+            from_currency,
+            to_currency,
+            rate_date,
+            rate,
+        };
+
+        let exchange_rate = self.db.upsert_exchange_rate(&input).await.map_err(|e| {
+            warn!(error = %e, "Failed to upsert exchange rate");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["UpsertExchangeRate", "error"])
+                .inc();
+            Status::internal("Failed to save exchange rate")
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["UpsertExchangeRate", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(UpsertExchangeRateResponse {
+            exchange_rate: Some(Self::exchange_rate_to_proto(&exchange_rate)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetExchangeRate")
+    )]
+    async fn get_exchange_rate(
+        &self,
+        request: Request<GetExchangeRateRequest>,
+    ) -> Result<Response<GetExchangeRateResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetExchangeRate"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetExchangeRate", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Parse as_of_date (defaults to today)
+        let as_of_date = if req.as_of_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetExchangeRate", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let exchange_rate = self
+            .db
+            .get_exchange_rate(
+                tenant_id,
+                &req.from_currency.to_uppercase(),
+                &req.to_currency.to_uppercase(),
+                as_of_date,
+            )
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to get exchange rate");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetExchangeRate", "error"])
+                    .inc();
+                Status::internal("Failed to get exchange rate")
+            })?
+            .ok_or_else(|| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["GetExchangeRate", "not_found"])
+                    .inc();
+                Status::not_found(format!(
+                    "No exchange rate from {} to {} on or before {}",
+                    req.from_currency, req.to_currency, as_of_date
+                ))
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetExchangeRate", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(GetExchangeRateResponse {
+            exchange_rate: Some(Self::exchange_rate_to_proto(&exchange_rate)),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "RevalueAccounts")
+    )]
+    async fn revalue_accounts(
+        &self,
+        request: Request<RevalueAccountsRequest>,
+    ) -> Result<Response<RevalueAccountsResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["RevalueAccounts"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RevalueAccounts", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let gain_loss_account_id = Uuid::parse_str(&req.gain_loss_account_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RevalueAccounts", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid gain_loss_account_id format")
+        })?;
+
+        let requested_base_currency = req.base_currency.to_uppercase();
+        if !requested_base_currency.is_empty() && requested_base_currency.len() != 3 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["RevalueAccounts", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "base_currency must be a 3-letter ISO 4217 code",
+            ));
+        }
+
+        // Parse as_of_date (defaults to today)
+        let as_of_date = if req.as_of_date.is_empty() {
+            chrono::Utc::now().date_naive()
+        } else {
+            NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["RevalueAccounts", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+            })?
+        };
+
+        let (base_currency, (revaluations, journal)) = self
+            .db
+            .revalue_accounts(
+                tenant_id,
+                as_of_date,
+                Some(requested_base_currency.as_str()).filter(|c| !c.is_empty()),
+                gain_loss_account_id,
+            )
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to revalue accounts");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["RevalueAccounts", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::invalid_argument(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to revalue accounts"),
+                }
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["RevalueAccounts", "ok"])
+            .inc();
+        if journal.is_some() {
+            TRANSACTIONS_TOTAL.with_label_values(&["revalued"]).inc();
+        }
+
+        timer.observe_duration();
+
+        Ok(Response::new(RevalueAccountsResponse {
+            as_of_date: as_of_date.to_string(),
+            base_currency,
+            revaluations: revaluations
+                .iter()
+                .map(|r| ProtoAccountRevaluation {
+                    account_id: r.account_id.to_string(),
+                    currency: r.currency.clone(),
+                    balance: format_decimal(&r.balance),
+                    rate: format_decimal(&r.rate),
+                    carrying_base_amount: format_decimal(&r.carrying_base_amount),
+                    revalued_base_amount: format_decimal(&r.revalued_base_amount),
+                    gain_loss: format_decimal(&r.adjustment()),
+                })
+                .collect(),
+            transaction: journal.map(|(journal_id, entries)| {
+                Self::entries_to_transaction(tenant_id, journal_id, &entries, &[])
+            }),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "SetBaseCurrency")
+    )]
+    async fn set_base_currency(
+        &self,
+        request: Request<SetBaseCurrencyRequest>,
+    ) -> Result<Response<SetBaseCurrencyResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["SetBaseCurrency"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["SetBaseCurrency", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Validate currency (ISO 4217)
+        if req.base_currency.len() != 3 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["SetBaseCurrency", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "base_currency must be a 3-letter ISO 4217 code",
+            ));
+        }
+        let base_currency = req.base_currency.to_uppercase();
+
+        let entries_converted = self
+            .db
+            .set_base_currency(tenant_id, &base_currency)
+            .await
+            .map_err(|e| {
+                warn!(error = %e, "Failed to set base currency");
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["SetBaseCurrency", "error"])
+                    .inc();
+                match e {
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::invalid_argument(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to set base currency"),
+                }
+            })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["SetBaseCurrency", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(SetBaseCurrencyResponse {
+            base_currency,
+            entries_converted: entries_converted as i64,
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "GetBaseCurrency")
+    )]
+    async fn get_base_currency(
+        &self,
+        request: Request<GetBaseCurrencyRequest>,
+    ) -> Result<Response<GetBaseCurrencyResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["GetBaseCurrency"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBaseCurrency", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        let base_currency = self.db.get_base_currency(tenant_id).await.map_err(|e| {
+            warn!(error = %e, "Failed to get base currency");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["GetBaseCurrency", "error"])
+                .inc();
+            Status::internal("Failed to get base currency")
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["GetBaseCurrency", "ok"])
+            .inc();
+
+        timer.observe_duration();
+
+        Ok(Response::new(GetBaseCurrencyResponse {
+            base_currency: base_currency.unwrap_or_default(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "OpenPeriod")
+    )]
+    async fn open_period(
+        &self,
+        request: Request<OpenPeriodRequest>,
+    ) -> Result<Response<OpenPeriodResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["OpenPeriod"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse tenant_id
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid tenant_id format")
+        })?;
+
+        // Validate name
+        if req.name.is_empty() || req.name.len() > 100 {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument(
+                "name must be between 1 and 100 characters",
+            ));
+        }
+
+        // Parse dates
+        let start_date = NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+        })?;
+
+        let end_date = NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+        })?;
+
+        if end_date < start_date {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "invalid_argument"])
+                .inc();
+            return Err(Status::invalid_argument("end_date must be >= start_date"));
+        }
+
+        // Parse optional retained earnings account
+        let retained_earnings_account_id = if req.retained_earnings_account_id.is_empty() {
+            None
+        } else {
+            Some(
+                Uuid::parse_str(&req.retained_earnings_account_id).map_err(|_| {
+                    GRPC_REQUESTS_TOTAL
+                        .with_label_values(&["OpenPeriod", "invalid_argument"])
+                        .inc();
+                    Status::invalid_argument("Invalid retained_earnings_account_id format")
+                })?,
+            )
+        };
+
+        let input = OpenPeriod {
+            tenant_id,
+            name: req.name,
+            start_date,
+            end_date,
+            retained_earnings_account_id,
+        };
+
+        let period = self.db.open_period(&input).await.map_err(|e| {
+            warn!(error = %e, "Failed to open period");
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["OpenPeriod", "error"])
+                .inc();
+            match e {
+                service_core::error::AppError::BadRequest(err) => {
+                    Status::invalid_argument(err.to_string())
+                }
+                service_core::error::AppError::Conflict(err) => {
+                    Status::already_exists(err.to_string())
+                }
+                _ => Status::internal("Failed to open period"),
+            }
+        })?;
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["OpenPeriod", "ok"])
             .inc();
 
-        // Generate next page token
-        let next_page_token = if transactions.len() == page_size as usize {
-            transactions.last().map(|(jid, _)| jid.to_string())
-        } else {
-            None
-        };
+        timer.observe_duration();
 
-        Ok(Response::new(ListTransactionsResponse {
-            transactions: transactions
-                .iter()
-                .map(|(jid, entries)| Self::entries_to_transaction(tenant_id, *jid, entries))
-                .collect(),
-            next_page_token: next_page_token.unwrap_or_default(),
+        Ok(Response::new(OpenPeriodResponse {
+            period: Some(Self::period_to_proto(&period)),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "GetBalance")
+        fields(service = "ledger-service", method = "ClosePeriod")
     )]
-    async fn get_balance(
+    async fn close_period(
         &self,
-        request: Request<GetBalanceRequest>,
-    ) -> Result<Response<GetBalanceResponse>, Status> {
+        request: Request<ClosePeriodRequest>,
+    ) -> Result<Response<ClosePeriodResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetBalance"])
+            .with_label_values(&["ClosePeriod"])
             .start_timer();
 
         let req = request.into_inner();
@@ -747,267 +3327,270 @@ impl LedgerService for LedgerServiceImpl {
         // Parse IDs
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetBalance", "invalid_argument"])
+                .with_label_values(&["ClosePeriod", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        let account_id = Uuid::parse_str(&req.account_id).map_err(|_| {
+        let period_id = Uuid::parse_str(&req.period_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetBalance", "invalid_argument"])
+                .with_label_values(&["ClosePeriod", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid account_id format")
+            Status::invalid_argument("Invalid period_id format")
         })?;
 
-        // Parse as_of_date
-        let as_of_date = if req.as_of_date.is_empty() {
+        let retained_earnings_account_id = if req.retained_earnings_account_id.is_empty() {
             None
         } else {
             Some(
-                NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
+                Uuid::parse_str(&req.retained_earnings_account_id).map_err(|_| {
                     GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["GetBalance", "invalid_argument"])
+                        .with_label_values(&["ClosePeriod", "invalid_argument"])
                         .inc();
-                    Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
+                    Status::invalid_argument("Invalid retained_earnings_account_id format")
                 })?,
             )
         };
 
-        let result = self
+        let (period, closing) = self
             .db
-            .get_balance(tenant_id, account_id, as_of_date)
+            .close_period(tenant_id, period_id, retained_earnings_account_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get balance");
+                warn!(error = %e, "Failed to close period");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetBalance", "error"])
+                    .with_label_values(&["ClosePeriod", "error"])
                     .inc();
-                Status::internal("Failed to get balance")
+                match e {
+                    service_core::error::AppError::NotFound(err) => {
+                        Status::not_found(err.to_string())
+                    }
+                    service_core::error::AppError::BadRequest(err) => {
+                        Status::invalid_argument(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to close period"),
+                }
             })?;
 
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ClosePeriod", "ok"])
+            .inc();
+
         timer.observe_duration();
 
-        match result {
-            Some((balance, currency)) => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetBalance", "ok"])
-                    .inc();
-                Ok(Response::new(GetBalanceResponse {
-                    account_id: account_id.to_string(),
-                    balance: format_decimal(&balance),
-                    currency,
-                    as_of_date: as_of_date
-                        .unwrap_or_else(|| chrono::Utc::now().date_naive())
-                        .to_string(),
-                }))
-            }
-            None => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetBalance", "not_found"])
-                    .inc();
-                Err(Status::not_found("Account not found"))
-            }
-        }
+        info!(
+            period_id = %period_id,
+            closing_journal_id = ?closing.as_ref().map(|(journal_id, _)| *journal_id),
+            "Period closed successfully"
+        );
+
+        Ok(Response::new(ClosePeriodResponse {
+            period: Some(Self::period_to_proto(&period)),
+            closing_transaction: closing.map(|(journal_id, entries)| {
+                Self::entries_to_transaction(tenant_id, journal_id, &entries, &[])
+            }),
+        }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "GetBalances")
+        fields(service = "ledger-service", method = "ReopenPeriod")
     )]
-    async fn get_balances(
+    async fn reopen_period(
         &self,
-        request: Request<GetBalancesRequest>,
-    ) -> Result<Response<GetBalancesResponse>, Status> {
+        request: Request<ReopenPeriodRequest>,
+    ) -> Result<Response<ReopenPeriodResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetBalances"])
+            .with_label_values(&["ReopenPeriod"])
             .start_timer();
 
         let req = request.into_inner();
 
-        // Parse tenant_id
+        // Parse IDs
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetBalances", "invalid_argument"])
+                .with_label_values(&["ReopenPeriod", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        // Parse account_ids
-        let mut account_ids = Vec::with_capacity(req.account_ids.len());
-        for id in &req.account_ids {
-            let account_id = Uuid::parse_str(id).map_err(|_| {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetBalances", "invalid_argument"])
-                    .inc();
-                Status::invalid_argument("Invalid account_id format")
-            })?;
-            account_ids.push(account_id);
-        }
-
-        // Parse as_of_date
-        let as_of_date = if req.as_of_date.is_empty() {
-            None
-        } else {
-            Some(
-                NaiveDate::parse_from_str(&req.as_of_date, "%Y-%m-%d").map_err(|_| {
-                    GRPC_REQUESTS_TOTAL
-                        .with_label_values(&["GetBalances", "invalid_argument"])
-                        .inc();
-                    Status::invalid_argument("Invalid as_of_date format (expected YYYY-MM-DD)")
-                })?,
-            )
-        };
+        let period_id = Uuid::parse_str(&req.period_id).map_err(|_| {
+            GRPC_REQUESTS_TOTAL
+                .with_label_values(&["ReopenPeriod", "invalid_argument"])
+                .inc();
+            Status::invalid_argument("Invalid period_id format")
+        })?;
 
-        let results = self
+        let period = self
             .db
-            .get_balances(tenant_id, &account_ids, as_of_date)
+            .reopen_period(tenant_id, period_id)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get balances");
+                warn!(error = %e, "Failed to reopen period");
                 GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetBalances", "error"])
+                    .with_label_values(&["ReopenPeriod", "error"])
                     .inc();
-                Status::internal("Failed to get balances")
+                match e {
+                    service_core::error::AppError::NotFound(err) => {
+                        Status::not_found(err.to_string())
+                    }
+                    service_core::error::AppError::Conflict(err) => {
+                        Status::failed_precondition(err.to_string())
+                    }
+                    _ => Status::internal("Failed to reopen period"),
+                }
             })?;
 
-        timer.observe_duration();
-
         GRPC_REQUESTS_TOTAL
-            .with_label_values(&["GetBalances", "ok"])
+            .with_label_values(&["ReopenPeriod", "ok"])
             .inc();
 
-        let as_of_str = as_of_date
-            .unwrap_or_else(|| chrono::Utc::now().date_naive())
-            .to_string();
+        timer.observe_duration();
 
-        Ok(Response::new(GetBalancesResponse {
-            balances: results
-                .iter()
-                .map(|(account_id, balance, currency)| GetBalanceResponse {
-                    account_id: account_id.to_string(),
-                    balance: format_decimal(balance),
-                    currency: currency.clone(),
-                    as_of_date: as_of_str.clone(),
-                })
-                .collect(),
+        Ok(Response::new(ReopenPeriodResponse {
+            period: Some(Self::period_to_proto(&period)),
         }))
     }
 
     #[instrument(
         skip(self, request),
-        fields(service = "ledger-service", method = "GetStatement")
+        fields(service = "ledger-service", method = "ListPeriods")
     )]
-    async fn get_statement(
+    async fn list_periods(
         &self,
-        request: Request<GetStatementRequest>,
-    ) -> Result<Response<GetStatementResponse>, Status> {
+        request: Request<ListPeriodsRequest>,
+    ) -> Result<Response<ListPeriodsResponse>, Status> {
         let timer = GRPC_REQUEST_DURATION
-            .with_label_values(&["GetStatement"])
+            .with_label_values(&["ListPeriods"])
             .start_timer();
 
         let req = request.into_inner();
 
-        // Parse IDs
+        // Parse tenant_id
         let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetStatement", "invalid_argument"])
+                .with_label_values(&["ListPeriods", "invalid_argument"])
                 .inc();
             Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        let account_id = Uuid::parse_str(&req.account_id).map_err(|_| {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetStatement", "invalid_argument"])
-                .inc();
-            Status::invalid_argument("Invalid account_id format")
-        })?;
+        // Parse optional status filter
+        let status = if req.status == ProtoPeriodStatus::Unspecified as i32 {
+            None
+        } else {
+            PeriodStatus::from_proto(req.status)
+        };
 
-        // Parse dates
-        let start_date = NaiveDate::parse_from_str(&req.start_date, "%Y-%m-%d").map_err(|_| {
+        let periods = self.db.list_periods(tenant_id, status).await.map_err(|e| {
+            warn!(error = %e, "Failed to list periods");
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetStatement", "invalid_argument"])
+                .with_label_values(&["ListPeriods", "error"])
                 .inc();
-            Status::invalid_argument("Invalid start_date format (expected YYYY-MM-DD)")
+            Status::internal("Failed to list periods")
         })?;
 
-        let end_date = NaiveDate::parse_from_str(&req.end_date, "%Y-%m-%d").map_err(|_| {
+        timer.observe_duration();
+
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["ListPeriods", "ok"])
+            .inc();
+
+        Ok(Response::new(ListPeriodsResponse {
+            periods: periods.iter().map(Self::period_to_proto).collect(),
+        }))
+    }
+
+    #[instrument(
+        skip(self, request),
+        fields(service = "ledger-service", method = "VerifyBalanceSnapshots")
+    )]
+    async fn verify_balance_snapshots(
+        &self,
+        request: Request<VerifyBalanceSnapshotsRequest>,
+    ) -> Result<Response<VerifyBalanceSnapshotsResponse>, Status> {
+        let timer = GRPC_REQUEST_DURATION
+            .with_label_values(&["VerifyBalanceSnapshots"])
+            .start_timer();
+
+        let req = request.into_inner();
+
+        // Parse IDs
+        let tenant_id = Uuid::parse_str(&req.tenant_id).map_err(|_| {
             GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetStatement", "invalid_argument"])
+                .with_label_values(&["VerifyBalanceSnapshots", "invalid_argument"])
                 .inc();
-            Status::invalid_argument("Invalid end_date format (expected YYYY-MM-DD)")
+            Status::invalid_argument("Invalid tenant_id format")
         })?;
 
-        if end_date < start_date {
-            GRPC_REQUESTS_TOTAL
-                .with_label_values(&["GetStatement", "invalid_argument"])
-                .inc();
-            return Err(Status::invalid_argument("end_date must be >= start_date"));
-        }
+        let account_id = if req.account_id.is_empty() {
+            None
+        } else {
+            Some(Uuid::parse_str(&req.account_id).map_err(|_| {
+                GRPC_REQUESTS_TOTAL
+                    .with_label_values(&["VerifyBalanceSnapshots", "invalid_argument"])
+                    .inc();
+                Status::invalid_argument("Invalid account_id format")
+            })?)
+        };
 
-        let result = self
+        let verification = self
             .db
-            .get_statement(tenant_id, account_id, start_date, end_date)
+            .verify_balance_snapshots(tenant_id, account_id, req.rebuild)
             .await
             .map_err(|e| {
-                warn!(error = %e, "Failed to get statement");
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetStatement", "error"])
-                    .inc();
-                Status::internal("Failed to get statement")
+                warn!(error = %e, "Failed to verify balance snapshots");
+                match e {
+                    service_core::error::AppError::NotFound(err) => {
+                        GRPC_REQUESTS_TOTAL
+                            .with_label_values(&["VerifyBalanceSnapshots", "not_found"])
+                            .inc();
+                        Status::not_found(err.to_string())
+                    }
+                    _ => {
+                        GRPC_REQUESTS_TOTAL
+                            .with_label_values(&["VerifyBalanceSnapshots", "error"])
+                            .inc();
+                        Status::internal("Failed to verify balance snapshots")
+                    }
+                }
             })?;
 
-        timer.observe_duration();
+        GRPC_REQUESTS_TOTAL
+            .with_label_values(&["VerifyBalanceSnapshots", "ok"])
+            .inc();
 
-        match result {
-            Some((currency, opening_balance, closing_balance, entries)) => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetStatement", "ok"])
-                    .inc();
+        timer.observe_duration();
 
-                // Build statement lines with running balance
-                let mut running = opening_balance;
-                let lines: Vec<_> = entries
-                    .iter()
-                    .map(|e| {
-                        match e.direction.as_str() {
-                            "debit" => running += e.amount,
-                            "credit" => running -= e.amount,
-                            _ => {}
-                        }
-                        crate::grpc::proto::StatementLine {
-                            entry_id: e.entry_id.to_string(),
-                            journal_id: e.journal_id.to_string(),
-                            effective_date: e.effective_date.to_string(),
-                            direction: match e.direction.as_str() {
-                                "debit" => ProtoDirection::Debit as i32,
-                                "credit" => ProtoDirection::Credit as i32,
-                                _ => ProtoDirection::Unspecified as i32,
-                            },
-                            amount: format_decimal(&e.amount),
-                            running_balance: format_decimal(&running),
-                            metadata: e
-                                .metadata
-                                .as_ref()
-                                .map(|m| m.to_string())
-                                .unwrap_or_default(),
-                        }
-                    })
-                    .collect();
+        info!(
+            accounts_checked = verification.accounts_checked,
+            mismatch_count = verification.mismatches.len(),
+            rebuilt = verification.rebuilt,
+            "Balance snapshots verified"
+        );
 
-                Ok(Response::new(GetStatementResponse {
-                    account_id: account_id.to_string(),
-                    currency,
-                    opening_balance: format_decimal(&opening_balance),
-                    closing_balance: format_decimal(&closing_balance),
-                    lines,
-                }))
-            }
-            None => {
-                GRPC_REQUESTS_TOTAL
-                    .with_label_values(&["GetStatement", "not_found"])
-                    .inc();
-                Err(Status::not_found("Account not found"))
-            }
-        }
+        Ok(Response::new(VerifyBalanceSnapshotsResponse {
+            accounts_checked: verification.accounts_checked,
+            snapshots_checked: verification.snapshots_checked,
+            mismatches: verification
+                .mismatches
+                .iter()
+                .map(|m| ProtoSnapshotMismatch {
+                    account_id: m.account_id.to_string(),
+                    snapshot_date: m.snapshot_date.to_string(),
+                    snapshot_balance: m
+                        .snapshot_balance
+                        .map(|b| format_decimal(&b))
+                        .unwrap_or_default(),
+                    entries_balance: m
+                        .entries_balance
+                        .map(|b| format_decimal(&b))
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            rebuilt: verification.rebuilt,
+        }))
     }
 }
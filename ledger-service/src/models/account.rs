@@ -71,6 +71,62 @@ impl std::fmt::Display for AccountType {
     }
 }
 
+/// Account lifecycle status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AccountStatus {
+    Active,
+    Frozen,
+    Closed,
+}
+
+impl AccountStatus {
+    /// Convert from string representation.
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s {
+            "active" => Some(Self::Active),
+            "frozen" => Some(Self::Frozen),
+            "closed" => Some(Self::Closed),
+            _ => None,
+        }
+    }
+
+    /// Convert from proto enum value.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Active),
+            2 => Some(Self::Frozen),
+            3 => Some(Self::Closed),
+            _ => None,
+        }
+    }
+
+    /// Convert to proto enum value.
+    pub fn to_proto(self) -> i32 {
+        match self {
+            Self::Active => 1,
+            Self::Frozen => 2,
+            Self::Closed => 3,
+        }
+    }
+
+    /// Get string representation for database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Frozen => "frozen",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+impl std::fmt::Display for AccountStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Ledger account.
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct Account {
@@ -83,12 +139,19 @@ pub struct Account {
     pub metadata: Option<serde_json::Value>,
     pub created_utc: DateTime<Utc>,
     pub closed_utc: Option<DateTime<Utc>>,
+    pub parent_account_id: Option<Uuid>,
+    pub status: String,
 }
 
 impl Account {
     /// Check if account is closed.
     pub fn is_closed(&self) -> bool {
-        self.closed_utc.is_some()
+        self.parsed_status() == Some(AccountStatus::Closed)
+    }
+
+    /// Get parsed account status.
+    pub fn parsed_status(&self) -> Option<AccountStatus> {
+        AccountStatus::from_string(&self.status)
     }
 
     /// Get parsed account type.
@@ -113,4 +176,18 @@ pub struct CreateAccount {
     pub currency: String,
     pub allow_negative: bool,
     pub metadata: Option<serde_json::Value>,
+    pub parent_account_id: Option<Uuid>,
+}
+
+/// Recorded change of an account's status.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AccountStatusChange {
+    pub change_id: Uuid,
+    pub tenant_id: Uuid,
+    pub account_id: Uuid,
+    pub from_status: String,
+    pub to_status: String,
+    pub actor: String,
+    pub reason: Option<String>,
+    pub changed_utc: DateTime<Utc>,
 }
@@ -61,6 +61,10 @@ pub struct LedgerEntry {
     pub posted_utc: DateTime<Utc>,
     pub idempotency_key: Option<String>,
     pub metadata: Option<serde_json::Value>,
+    pub reverses_journal_id: Option<Uuid>,
+    pub base_currency: Option<String>,
+    pub base_amount: Option<Decimal>,
+    pub exchange_rate: Option<Decimal>,
 }
 
 impl LedgerEntry {
@@ -73,6 +77,15 @@ impl LedgerEntry {
         }
     }
 
+    /// Get the direction that cancels this entry out.
+    pub fn reversed_direction(&self) -> Option<Direction> {
+        match self.parsed_direction() {
+            Some(Direction::Debit) => Some(Direction::Credit),
+            Some(Direction::Credit) => Some(Direction::Debit),
+            None => None,
+        }
+    }
+
     /// Get signed amount (positive for debit, negative for credit).
     pub fn signed_amount(&self) -> Decimal {
         match self.parsed_direction() {
@@ -89,4 +102,15 @@ pub struct PostEntry {
     pub account_id: Uuid,
     pub amount: Decimal,
     pub direction: Direction,
+    /// Rate into the journal's base currency; looked up by effective date when unset.
+    pub exchange_rate: Option<Decimal>,
+}
+
+/// Ledger entry with the account details written by ExportTransactions.
+#[derive(Debug, Clone, FromRow)]
+pub struct ExportedEntry {
+    #[sqlx(flatten)]
+    pub entry: LedgerEntry,
+    pub account_code: String,
+    pub currency: String,
 }
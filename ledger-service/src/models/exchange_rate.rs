@@ -0,0 +1,102 @@
+//! Exchange rate and FX revaluation models.
+
+use super::Direction;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Exchange rate effective from `rate_date` until the next rate for the pair.
+/// One unit of `from_currency` is worth `rate` units of `to_currency`.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ExchangeRate {
+    pub tenant_id: Uuid,
+    pub from_currency: String,
+    pub to_currency: String,
+    pub rate_date: NaiveDate,
+    pub rate: Decimal,
+    pub created_utc: DateTime<Utc>,
+    pub updated_utc: DateTime<Utc>,
+}
+
+/// Input for creating or replacing the rate of a currency pair on a date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpsertExchangeRate {
+    pub tenant_id: Uuid,
+    pub from_currency: String,
+    pub to_currency: String,
+    pub rate_date: NaiveDate,
+    pub rate: Decimal,
+}
+
+/// Revaluation of one foreign-currency account into the base currency.
+/// Base amounts are raw (debit - credit) values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountRevaluation {
+    pub account_id: Uuid,
+    pub currency: String,
+    /// Raw balance in the account's own currency.
+    pub balance: Decimal,
+    /// Closing rate used to revalue the balance.
+    pub rate: Decimal,
+    /// Base value recorded so far, including earlier revaluations.
+    pub carrying_base_amount: Decimal,
+    /// Base value at the closing rate.
+    pub revalued_base_amount: Decimal,
+}
+
+impl AccountRevaluation {
+    /// Unrealised gain (positive) or loss (negative) in the base currency.
+    /// A debit adjustment increases an asset and reduces a liability, so it is a
+    /// gain for both account types.
+    pub fn adjustment(&self) -> Decimal {
+        self.revalued_base_amount - self.carrying_base_amount
+    }
+}
+
+/// Convert journal entries, given as (direction, amount, rate), into base-currency
+/// amounts rounded to 4 decimal places. Rounding each entry on its own can leave a
+/// journal that balances at full precision a few hundredths of a cent out, so the
+/// residual is put on the largest converted entry.
+/// Returns None if the journal does not balance in the base currency.
+pub fn convert_to_base(entries: &[(Direction, Decimal, Decimal)]) -> Option<Vec<Decimal>> {
+    let signed = |direction: Direction, value: Decimal| match direction {
+        Direction::Debit => value,
+        Direction::Credit => -value,
+    };
+
+    let unrounded: Decimal = entries
+        .iter()
+        .map(|(direction, amount, rate)| signed(*direction, amount * rate))
+        .sum();
+    if !unrounded.round_dp(4).is_zero() {
+        return None;
+    }
+
+    let mut base_amounts: Vec<Decimal> = entries
+        .iter()
+        .map(|(_, amount, rate)| (amount * rate).round_dp(4))
+        .collect();
+    let residual: Decimal = entries
+        .iter()
+        .zip(&base_amounts)
+        .map(|((direction, ..), base_amount)| signed(*direction, *base_amount))
+        .sum();
+    if residual.is_zero() {
+        return Some(base_amounts);
+    }
+
+    // Entries already in the base currency keep their amount
+    let (index, _) = entries
+        .iter()
+        .zip(&base_amounts)
+        .enumerate()
+        .filter(|(_, ((_, _, rate), _))| *rate != Decimal::ONE)
+        .max_by_key(|(_, (_, base_amount))| **base_amount)?;
+    base_amounts[index] -= signed(entries[index].0, residual);
+    if base_amounts[index] <= Decimal::ZERO {
+        return None;
+    }
+    Some(base_amounts)
+}
@@ -0,0 +1,114 @@
+//! Pending hold (authorization) model.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Hold status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum HoldStatus {
+    Pending,
+    Captured,
+    Released,
+    Expired,
+}
+
+impl HoldStatus {
+    /// Convert from string representation.
+    pub fn from_string(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "captured" => Some(Self::Captured),
+            "released" => Some(Self::Released),
+            "expired" => Some(Self::Expired),
+            _ => None,
+        }
+    }
+
+    /// Convert from proto enum value.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Pending),
+            2 => Some(Self::Captured),
+            3 => Some(Self::Released),
+            4 => Some(Self::Expired),
+            _ => None,
+        }
+    }
+
+    /// Convert to proto enum value.
+    pub fn to_proto(self) -> i32 {
+        match self {
+            Self::Pending => 1,
+            Self::Captured => 2,
+            Self::Released => 3,
+            Self::Expired => 4,
+        }
+    }
+
+    /// Get string representation for database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Captured => "captured",
+            Self::Released => "released",
+            Self::Expired => "expired",
+        }
+    }
+}
+
+impl std::fmt::Display for HoldStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Reservation of a future debit/credit pair. While pending and unexpired it reduces
+/// the available balance of the account it would draw down.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct Hold {
+    pub hold_id: Uuid,
+    pub tenant_id: Uuid,
+    pub debit_account_id: Uuid,
+    pub credit_account_id: Uuid,
+    pub amount: Decimal,
+    pub captured_amount: Option<Decimal>,
+    pub currency: String,
+    pub status: String,
+    pub expires_utc: DateTime<Utc>,
+    pub idempotency_key: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub journal_id: Option<Uuid>,
+    pub created_utc: DateTime<Utc>,
+    pub resolved_utc: Option<DateTime<Utc>>,
+}
+
+impl Hold {
+    /// Get parsed hold status.
+    pub fn parsed_status(&self) -> Option<HoldStatus> {
+        HoldStatus::from_string(&self.status)
+    }
+
+    /// Check if the hold still reserves funds at `now`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.parsed_status() == Some(HoldStatus::Pending) && self.expires_utc > now
+    }
+}
+
+/// Input for creating a hold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateHold {
+    pub tenant_id: Uuid,
+    pub debit_account_id: Uuid,
+    pub credit_account_id: Uuid,
+    pub amount: Decimal,
+    /// Lifetime requested by the caller, 0 for the default; `expires_utc` is derived from it.
+    pub expires_in_seconds: i64,
+    pub expires_utc: DateTime<Utc>,
+    pub idempotency_key: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+}
@@ -0,0 +1,197 @@
+//! Idempotency key models.
+
+use crate::models::{CreateHold, Hold, LedgerEntry, PostEntry};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Journal posted under a tenant's idempotency key, kept until `expires_utc`.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    pub tenant_id: Uuid,
+    pub idempotency_key: String,
+    /// SHA-256 of the original request; `None` for keys recorded before fingerprinting.
+    pub request_hash: Option<String>,
+    pub journal_id: Uuid,
+    pub created_utc: DateTime<Utc>,
+    pub expires_utc: DateTime<Utc>,
+}
+
+/// A replayed idempotency key whose request differs from the original.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error(
+    "Idempotency key {idempotency_key} was already used for journal {journal_id} with a different request: {}",
+    .differences.join("; ")
+)]
+pub struct IdempotencyConflict {
+    pub idempotency_key: String,
+    pub journal_id: Uuid,
+    pub differences: Vec<String>,
+}
+
+/// A replayed CreateHold idempotency key whose request differs from the original.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error(
+    "Idempotency key {idempotency_key} was already used for hold {hold_id} with a different request: {}",
+    .differences.join("; ")
+)]
+pub struct HoldIdempotencyConflict {
+    pub idempotency_key: String,
+    pub hold_id: Uuid,
+    pub differences: Vec<String>,
+}
+
+/// Canonical (account, direction, amount) of an entry, used for comparisons.
+fn entry_key(account_id: Uuid, direction: &str, amount: &Decimal) -> (Uuid, String, String) {
+    (
+        account_id,
+        direction.to_string(),
+        amount.normalize().to_string(),
+    )
+}
+
+/// Fingerprint a PostTransaction request.
+/// Entry order and trailing zeros in amounts do not change the hash.
+pub fn request_fingerprint(
+    entries: &[PostEntry],
+    effective_date: NaiveDate,
+    metadata: Option<&serde_json::Value>,
+    base_currency: Option<&str>,
+) -> String {
+    let mut canonical_entries: Vec<_> = entries
+        .iter()
+        .map(|e| {
+            let (account_id, direction, amount) =
+                entry_key(e.account_id, e.direction.as_str(), &e.amount);
+            let rate = e.exchange_rate.map(|r| r.normalize().to_string());
+            (account_id, direction, amount, rate)
+        })
+        .collect();
+    canonical_entries.sort();
+
+    let canonical = serde_json::json!({
+        "effective_date": effective_date.to_string(),
+        "base_currency": base_currency,
+        "metadata": metadata,
+        "entries": canonical_entries,
+    });
+    format!("{:x}", Sha256::digest(canonical.to_string().as_bytes()))
+}
+
+/// Describe how a request differs from the journal originally posted under its key.
+/// Stored exchange rates are only compared when the request sets one, since the
+/// original may have looked its rates up by date.
+pub fn describe_differences(
+    original: &[LedgerEntry],
+    entries: &[PostEntry],
+    effective_date: NaiveDate,
+    metadata: Option<&serde_json::Value>,
+    base_currency: Option<&str>,
+) -> Vec<String> {
+    let mut differences = Vec::new();
+    let Some(first) = original.first() else {
+        return differences;
+    };
+
+    if first.effective_date != effective_date {
+        differences.push(format!(
+            "effective_date {} was originally {}",
+            effective_date, first.effective_date
+        ));
+    }
+    if first.base_currency.as_deref() != base_currency {
+        differences.push(format!(
+            "base_currency {} was originally {}",
+            base_currency.unwrap_or("(none)"),
+            first.base_currency.as_deref().unwrap_or("(none)")
+        ));
+    }
+    if first.metadata.as_ref() != metadata {
+        differences.push("metadata differs".to_string());
+    }
+
+    let mut original_entries: Vec<_> = original
+        .iter()
+        .map(|e| entry_key(e.account_id, &e.direction, &e.amount))
+        .collect();
+    let mut requested_entries: Vec<_> = entries
+        .iter()
+        .map(|e| entry_key(e.account_id, e.direction.as_str(), &e.amount))
+        .collect();
+    original_entries.sort();
+    requested_entries.sort();
+    if original_entries != requested_entries {
+        differences.push(format!(
+            "entries differ ({} requested, {} originally posted)",
+            requested_entries.len(),
+            original_entries.len()
+        ));
+    } else if entries.iter().any(|e| {
+        e.exchange_rate.is_some_and(|rate| {
+            !original.iter().any(|o| {
+                o.account_id == e.account_id
+                    && o.direction == e.direction.as_str()
+                    && o.amount == e.amount
+                    && o.exchange_rate == Some(rate)
+            })
+        })
+    }) {
+        differences.push("exchange rates differ".to_string());
+    }
+
+    if differences.is_empty() {
+        differences.push("request does not match the original".to_string());
+    }
+    differences
+}
+
+/// Fingerprint a CreateHold request.
+/// Trailing zeros in the amount do not change the hash.
+pub fn hold_fingerprint(input: &CreateHold) -> String {
+    let canonical = serde_json::json!({
+        "debit_account_id": input.debit_account_id,
+        "credit_account_id": input.credit_account_id,
+        "amount": input.amount.normalize().to_string(),
+        "expires_in_seconds": input.expires_in_seconds,
+        "metadata": input.metadata,
+    });
+    format!("{:x}", Sha256::digest(canonical.to_string().as_bytes()))
+}
+
+/// Describe how a request differs from the hold originally created under its key.
+/// The requested lifetime is not stored, so it is the difference left when
+/// everything else matches.
+pub fn describe_hold_differences(original: &Hold, input: &CreateHold) -> Vec<String> {
+    let mut differences = Vec::new();
+
+    if original.amount != input.amount {
+        differences.push(format!(
+            "amount {} was originally {}",
+            input.amount.normalize(),
+            original.amount.normalize()
+        ));
+    }
+    if original.debit_account_id != input.debit_account_id {
+        differences.push(format!(
+            "debit_account_id {} was originally {}",
+            input.debit_account_id, original.debit_account_id
+        ));
+    }
+    if original.credit_account_id != input.credit_account_id {
+        differences.push(format!(
+            "credit_account_id {} was originally {}",
+            input.credit_account_id, original.credit_account_id
+        ));
+    }
+    if original.metadata != input.metadata {
+        differences.push("metadata differs".to_string());
+    }
+
+    if differences.is_empty() {
+        differences.push("expires_in_seconds differs".to_string());
+    }
+    differences
+}
@@ -2,6 +2,23 @@
 
 mod account;
 mod entry;
+mod exchange_rate;
+mod hold;
+mod idempotency;
+mod period;
+mod report;
+mod reversal;
+mod snapshot;
 
-pub use account::{Account, AccountType, CreateAccount};
-pub use entry::{Direction, LedgerEntry, PostEntry};
+pub use account::{Account, AccountStatus, AccountStatusChange, AccountType, CreateAccount};
+pub use entry::{Direction, ExportedEntry, LedgerEntry, PostEntry};
+pub use exchange_rate::{convert_to_base, AccountRevaluation, ExchangeRate, UpsertExchangeRate};
+pub use hold::{CreateHold, Hold, HoldStatus};
+pub use idempotency::{
+    describe_differences, describe_hold_differences, hold_fingerprint, request_fingerprint,
+    HoldIdempotencyConflict, IdempotencyConflict, IdempotencyRecord,
+};
+pub use period::{AccountingPeriod, OpenPeriod, PeriodStatus};
+pub use report::{AccountActivity, ReportSnapshot};
+pub use reversal::JournalReversal;
+pub use snapshot::{SnapshotMismatch, SnapshotVerification};
@@ -0,0 +1,89 @@
+//! Accounting period model.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Accounting period status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "varchar", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum PeriodStatus {
+    Open,
+    Closed,
+}
+
+impl PeriodStatus {
+    /// Convert from proto enum value.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Open),
+            2 => Some(Self::Closed),
+            _ => None,
+        }
+    }
+
+    /// Convert to proto enum value.
+    pub fn to_proto(self) -> i32 {
+        match self {
+            Self::Open => 1,
+            Self::Closed => 2,
+        }
+    }
+
+    /// Get string representation for database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Closed => "closed",
+        }
+    }
+}
+
+impl std::fmt::Display for PeriodStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Accounting period. Postings dated inside a closed period are rejected.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AccountingPeriod {
+    pub period_id: Uuid,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub status: String,
+    pub retained_earnings_account_id: Option<Uuid>,
+    pub closing_journal_id: Option<Uuid>,
+    pub created_utc: DateTime<Utc>,
+    pub closed_utc: Option<DateTime<Utc>>,
+}
+
+impl AccountingPeriod {
+    /// Get parsed period status.
+    pub fn parsed_status(&self) -> Option<PeriodStatus> {
+        match self.status.as_str() {
+            "open" => Some(PeriodStatus::Open),
+            "closed" => Some(PeriodStatus::Closed),
+            _ => None,
+        }
+    }
+
+    /// Check if period is closed.
+    pub fn is_closed(&self) -> bool {
+        self.parsed_status() == Some(PeriodStatus::Closed)
+    }
+}
+
+/// Input for opening a new accounting period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenPeriod {
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub retained_earnings_account_id: Option<Uuid>,
+}
@@ -0,0 +1,50 @@
+//! Financial report models.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::AccountType;
+
+/// Aggregated debit/credit activity of one account, as read by the report queries.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct AccountActivity {
+    pub account_id: Uuid,
+    pub account_code: String,
+    pub account_type: String,
+    /// Reporting currency: the tenant base currency if set, else the account currency.
+    pub currency: String,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+}
+
+impl AccountActivity {
+    /// Get parsed account type.
+    pub fn parsed_type(&self) -> AccountType {
+        AccountType::from_string(&self.account_type)
+    }
+
+    /// Raw balance (debits - credits).
+    pub fn raw_balance(&self) -> Decimal {
+        self.debit_total - self.credit_total
+    }
+
+    /// Balance with the sign of the account's normal side (positive = normal).
+    pub fn normal_balance(&self) -> Decimal {
+        match self.parsed_type() {
+            AccountType::Asset | AccountType::Expense => self.raw_balance(),
+            _ => -self.raw_balance(),
+        }
+    }
+}
+
+/// Account activity read from a single database snapshot.
+#[derive(Debug, Clone)]
+pub struct ReportSnapshot {
+    /// Time at which the snapshot was taken.
+    pub snapshot_utc: DateTime<Utc>,
+    /// Activity per account, ordered by currency, account type and account code.
+    pub accounts: Vec<AccountActivity>,
+}
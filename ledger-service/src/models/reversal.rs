@@ -0,0 +1,16 @@
+//! Journal reversal model.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Link between a posted journal and the journal that reversed it.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct JournalReversal {
+    pub original_journal_id: Uuid,
+    pub reversal_journal_id: Uuid,
+    pub tenant_id: Uuid,
+    pub reason: Option<String>,
+    pub reversed_utc: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+//! Account balance snapshot models.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A day on which an account's stored snapshot disagrees with its entries.
+/// Balances are raw (debit - credit) closing balances; `None` means no row on that side.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SnapshotMismatch {
+    pub account_id: Uuid,
+    pub snapshot_date: NaiveDate,
+    pub snapshot_balance: Option<Decimal>,
+    pub entries_balance: Option<Decimal>,
+}
+
+/// Result of verifying (and optionally rebuilding) balance snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotVerification {
+    pub accounts_checked: i64,
+    /// Snapshot days derived from the entries.
+    pub snapshots_checked: i64,
+    pub mismatches: Vec<SnapshotMismatch>,
+    /// Whether the snapshots were replaced with the ones derived from the entries.
+    pub rebuilt: bool,
+}
@@ -1,23 +1,64 @@
 //! Database service for ledger-service.
 
-use crate::models::{Account, AccountType, CreateAccount, Direction, LedgerEntry, PostEntry};
+use crate::models::{
+    convert_to_base, describe_differences, describe_hold_differences, hold_fingerprint,
+    request_fingerprint,
+};
+use crate::models::{
+    Account, AccountActivity, AccountRevaluation, AccountStatus, AccountStatusChange, AccountType,
+    AccountingPeriod, CreateAccount, CreateHold, Direction, ExchangeRate, ExportedEntry, Hold,
+    HoldIdempotencyConflict, HoldStatus, IdempotencyConflict, IdempotencyRecord, JournalReversal,
+    LedgerEntry, OpenPeriod, PeriodStatus, PostEntry, ReportSnapshot, SnapshotMismatch,
+    SnapshotVerification, UpsertExchangeRate,
+};
 use crate::services::metrics::DB_QUERY_DURATION;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use service_core::error::AppError;
 use sqlx::postgres::{PgPool, PgPoolOptions};
 use std::time::Duration;
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 use uuid::Uuid;
 
 /// Statement data returned by get_statement query.
 /// Contains: (currency, opening_balance, closing_balance, entries)
 type StatementData = (String, Decimal, Decimal, Vec<LedgerEntry>);
 
+/// Period close result returned by close_period.
+/// Contains: (closed_period, Option<(closing_journal_id, closing_entries)>)
+type PeriodCloseData = (AccountingPeriod, Option<(Uuid, Vec<LedgerEntry>)>);
+
+/// Revaluation result returned by revalue_accounts.
+/// Contains: (revaluations, Option<(revaluation_journal_id, entries)>)
+type RevaluationData = (Vec<AccountRevaluation>, Option<(Uuid, Vec<LedgerEntry>)>);
+
+/// Account tree returned by get_account_tree.
+/// Contains: (account, own_balance) per account, ordered by account_code
+type AccountTreeData = Vec<(Account, Decimal)>;
+
+/// Hold capture result returned by capture_hold.
+/// Contains: (captured_hold, journal_id, entries, currency)
+type HoldCaptureData = (Hold, Uuid, Vec<LedgerEntry>, String);
+
+/// Accounts compared (and locked) per transaction by verify_balance_snapshots.
+const SNAPSHOT_VERIFY_BATCH_SIZE: usize = 200;
+
+/// Record a posted journal is linked to, written in the same database
+/// transaction as its entries.
+#[derive(Debug, Clone, Copy)]
+enum JournalLink<'a> {
+    /// Reverses the original journal, with an optional reason.
+    Reversal(Uuid, Option<&'a str>),
+    /// Captures a pending hold for the given amount.
+    HoldCapture(Uuid, Decimal),
+}
+
 /// Database connection pool wrapper.
 #[derive(Clone)]
 pub struct Database {
     pool: PgPool,
+    /// How long a journal's idempotency key is remembered.
+    idempotency_retention_secs: i64,
 }
 
 impl Database {
@@ -27,6 +68,7 @@ impl Database {
         database_url: &str,
         max_connections: u32,
         min_connections: u32,
+        idempotency_retention_secs: i64,
     ) -> Result<Self, AppError> {
         info!(
             max_connections = max_connections,
@@ -45,7 +87,10 @@ impl Database {
 
         info!("PostgreSQL connection pool established");
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            idempotency_retention_secs,
+        })
     }
 
     /// Get a reference to the connection pool.
@@ -86,12 +131,20 @@ impl Database {
             .with_label_values(&["create_account"])
             .start_timer();
 
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        if let Some(parent_id) = input.parent_account_id {
+            Self::validate_parent_account(&mut tx, input, parent_id).await?;
+        }
+
         let account_id = Uuid::new_v4();
         let account = sqlx::query_as::<_, Account>(
             r#"
-            INSERT INTO accounts (account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc
+            INSERT INTO accounts (account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, parent_account_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
             "#,
         )
         .bind(account_id)
@@ -101,7 +154,8 @@ impl Database {
         .bind(&input.currency)
         .bind(input.allow_negative)
         .bind(&input.metadata)
-        .fetch_one(&self.pool)
+        .bind(input.parent_account_id)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| match e {
             sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
@@ -113,6 +167,10 @@ impl Database {
             _ => AppError::DatabaseError(anyhow::anyhow!("Failed to create account: {}", e)),
         })?;
 
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
         info!(
@@ -137,7 +195,7 @@ impl Database {
 
         let account = sqlx::query_as::<_, Account>(
             r#"
-            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
             FROM accounts
             WHERE tenant_id = $1 AND account_id = $2
             "#,
@@ -180,6 +238,7 @@ impl Database {
         tenant_id: Uuid,
         account_type: Option<AccountType>,
         currency: Option<&str>,
+        status: Option<AccountStatus>,
         page_size: i32,
         page_token: Option<Uuid>,
     ) -> Result<Vec<Account>, AppError> {
@@ -193,19 +252,21 @@ impl Database {
         let accounts = if let Some(cursor) = page_token {
             sqlx::query_as::<_, Account>(
                 r#"
-                SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc
+                SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
                 FROM accounts
                 WHERE tenant_id = $1
                   AND ($2::varchar IS NULL OR account_type = $2)
                   AND ($3::varchar IS NULL OR currency = $3)
-                  AND account_id > $4
+                  AND ($4::varchar IS NULL OR status = $4)
+                  AND account_id > $5
                 ORDER BY account_id
-                LIMIT $5
+                LIMIT $6
                 "#,
             )
             .bind(tenant_id)
             .bind(account_type.map(|t| t.as_str()))
             .bind(currency)
+            .bind(status.map(|s| s.as_str()))
             .bind(cursor)
             .bind(limit)
             .fetch_all(&self.pool)
@@ -213,18 +274,20 @@ impl Database {
         } else {
             sqlx::query_as::<_, Account>(
                 r#"
-                SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc
+                SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
                 FROM accounts
                 WHERE tenant_id = $1
                   AND ($2::varchar IS NULL OR account_type = $2)
                   AND ($3::varchar IS NULL OR currency = $3)
+                  AND ($4::varchar IS NULL OR status = $4)
                 ORDER BY account_id
-                LIMIT $4
+                LIMIT $5
                 "#,
             )
             .bind(tenant_id)
             .bind(account_type.map(|t| t.as_str()))
             .bind(currency)
+            .bind(status.map(|s| s.as_str()))
             .bind(limit)
             .fetch_all(&self.pool)
             .await
@@ -236,6 +299,388 @@ impl Database {
         Ok(accounts)
     }
 
+    /// Get the chart-of-accounts tree with each account's own balance as of a date.
+    /// With `root_account_id` the tree is limited to that account and its descendants,
+    /// otherwise all top-level accounts of the tenant and their descendants are returned.
+    /// Returns None if the root account does not exist.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_account_tree(
+        &self,
+        tenant_id: Uuid,
+        root_account_id: Option<Uuid>,
+        as_of_date: Option<NaiveDate>,
+    ) -> Result<Option<AccountTreeData>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_account_tree"])
+            .start_timer();
+
+        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        let accounts = sqlx::query_as::<_, Account>(
+            r#"
+            WITH RECURSIVE tree AS (
+                SELECT account_id
+                FROM accounts
+                WHERE tenant_id = $1
+                  AND (($2::uuid IS NULL AND parent_account_id IS NULL) OR account_id = $2)
+                UNION ALL
+                SELECT a.account_id
+                FROM accounts a
+                JOIN tree t ON a.parent_account_id = t.account_id
+                WHERE a.tenant_id = $1
+            )
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
+            FROM accounts
+            WHERE account_id IN (SELECT account_id FROM tree)
+            ORDER BY account_code
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(root_account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get account tree: {}", e))
+        })?;
+
+        if root_account_id.is_some() && accounts.is_empty() {
+            return Ok(None);
+        }
+
+        let account_ids: Vec<Uuid> = accounts.iter().map(|a| a.account_id).collect();
+
+        // Raw (debit - credit) balance of every account in the tree
+        let raw_balances: Vec<(Uuid, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT account_id,
+                   SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END)
+            FROM ledger_entries
+            WHERE tenant_id = $1
+              AND account_id = ANY($2)
+              AND effective_date <= $3
+            GROUP BY account_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(&account_ids)
+        .bind(as_of)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get balances: {}", e)))?;
+
+        let raw_balances: std::collections::HashMap<Uuid, Decimal> =
+            raw_balances.into_iter().collect();
+
+        let tree = accounts
+            .into_iter()
+            .map(|account| {
+                let raw = raw_balances
+                    .get(&account.account_id)
+                    .copied()
+                    .unwrap_or(Decimal::ZERO);
+                let account_type = AccountType::from_string(&account.account_type);
+                let is_debit_normal =
+                    matches!(account_type, AccountType::Asset | AccountType::Expense);
+                let balance = if is_debit_normal { raw } else { -raw };
+                (account, balance)
+            })
+            .collect();
+
+        timer.observe_duration();
+
+        Ok(Some(tree))
+    }
+
+    /// Check that an account can be created under `parent_id`.
+    /// The parent must belong to the tenant, share the child's type and currency so
+    /// balances can be rolled up, and must not hold postings of its own since it
+    /// becomes a header account. The parent row is locked FOR UPDATE, so a journal
+    /// posting to it either commits first or sees the new sub-account.
+    async fn validate_parent_account(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        input: &CreateAccount,
+        parent_id: Uuid,
+    ) -> Result<(), AppError> {
+        let parent = sqlx::query_as::<_, Account>(
+            r#"
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
+            FROM accounts
+            WHERE tenant_id = $1 AND account_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(parent_id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to lock parent account: {}", e))
+        })?
+        .ok_or_else(|| {
+            AppError::BadRequest(anyhow::anyhow!(
+                "Parent account {} does not exist or does not belong to tenant",
+                parent_id
+            ))
+        })?;
+
+        if parent.parsed_type() != Some(input.account_type) {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Parent account {} has type {} but account has type {}",
+                parent_id,
+                parent.account_type,
+                input.account_type
+            )));
+        }
+
+        if parent.currency != input.currency {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Parent account {} has currency {} but account has currency {}",
+                parent_id,
+                parent.currency,
+                input.currency
+            )));
+        }
+
+        let has_entries: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM ledger_entries WHERE tenant_id = $1 AND account_id = $2)",
+        )
+        .bind(input.tenant_id)
+        .bind(parent_id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to check parent entries: {}", e))
+        })?;
+
+        if has_entries {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Parent account {} has postings and cannot become a header account",
+                parent_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Move an account to `to_status`, recording the change with its actor and reason.
+    /// Freezing requires an active account; closing requires a zero balance and no open
+    /// sub-accounts; reopening requires a frozen or closed account under an open parent.
+    /// The account row is locked FOR UPDATE, so postings holding it FOR SHARE either
+    /// commit first (and count towards the balance) or see the new status.
+    /// Returns None if the account does not exist.
+    #[instrument(skip(self, reason), fields(tenant_id = %tenant_id, account_id = %account_id, to_status = %to_status))]
+    pub async fn change_account_status(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+        to_status: AccountStatus,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<Option<Account>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["change_account_status"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let account = sqlx::query_as::<_, Account>(
+            r#"
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
+            FROM accounts
+            WHERE tenant_id = $1 AND account_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to lock account: {}", e)))?;
+
+        let account = match account {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+
+        let from_status = account.parsed_status().unwrap_or(AccountStatus::Active);
+        let allowed = match to_status {
+            AccountStatus::Active => from_status != AccountStatus::Active,
+            AccountStatus::Frozen => from_status == AccountStatus::Active,
+            AccountStatus::Closed => from_status != AccountStatus::Closed,
+        };
+        if !allowed {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Account {} is {} and cannot be changed to {}",
+                account_id,
+                from_status,
+                to_status
+            )));
+        }
+
+        match to_status {
+            AccountStatus::Closed => {
+                let open_children: i64 = sqlx::query_scalar(
+                    r#"
+                    SELECT COUNT(*)
+                    FROM accounts
+                    WHERE tenant_id = $1 AND parent_account_id = $2 AND status <> 'closed'
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(account_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!("Failed to check sub-accounts: {}", e))
+                })?;
+
+                if open_children > 0 {
+                    return Err(AppError::Conflict(anyhow::anyhow!(
+                        "Account {} has {} sub-accounts that are not closed",
+                        account_id,
+                        open_children
+                    )));
+                }
+
+                let balance: Decimal = sqlx::query_scalar(
+                    r#"
+                    SELECT COALESCE(
+                        SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END),
+                        0
+                    )
+                    FROM ledger_entries
+                    WHERE tenant_id = $1 AND account_id = $2
+                    "#,
+                )
+                .bind(tenant_id)
+                .bind(account_id)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!("Failed to get balance: {}", e))
+                })?;
+
+                if !balance.is_zero() {
+                    return Err(AppError::Conflict(anyhow::anyhow!(
+                        "Account {} has a non-zero balance ({}) and cannot be closed",
+                        account_id,
+                        balance.abs()
+                    )));
+                }
+            }
+            AccountStatus::Active => {
+                if let Some(parent_id) = account.parent_account_id {
+                    let parent_status: String = sqlx::query_scalar(
+                        "SELECT status FROM accounts WHERE tenant_id = $1 AND account_id = $2",
+                    )
+                    .bind(tenant_id)
+                    .bind(parent_id)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| {
+                        AppError::DatabaseError(anyhow::anyhow!(
+                            "Failed to get parent account: {}",
+                            e
+                        ))
+                    })?;
+
+                    if parent_status == AccountStatus::Closed.as_str() {
+                        return Err(AppError::Conflict(anyhow::anyhow!(
+                            "Parent account {} is closed; reopen it first",
+                            parent_id
+                        )));
+                    }
+                }
+            }
+            AccountStatus::Frozen => {}
+        }
+
+        let updated = sqlx::query_as::<_, Account>(
+            r#"
+            UPDATE accounts
+            SET status = $3,
+                closed_utc = CASE WHEN $3 = 'closed' THEN NOW() ELSE NULL END
+            WHERE tenant_id = $1 AND account_id = $2
+            RETURNING account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(to_status.as_str())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to update account status: {}", e))
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO account_status_changes (change_id, tenant_id, account_id, from_status, to_status, actor, reason)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(from_status.as_str())
+        .bind(to_status.as_str())
+        .bind(actor)
+        .bind(reason)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to record status change: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(
+            from_status = %from_status,
+            actor = %actor,
+            "Account status changed"
+        );
+
+        Ok(Some(updated))
+    }
+
+    /// List the recorded status changes of an account, oldest first.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
+    pub async fn list_account_status_changes(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Vec<AccountStatusChange>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_account_status_changes"])
+            .start_timer();
+
+        let changes = sqlx::query_as::<_, AccountStatusChange>(
+            r#"
+            SELECT change_id, tenant_id, account_id, from_status, to_status, actor, reason, changed_utc
+            FROM account_status_changes
+            WHERE tenant_id = $1 AND account_id = $2
+            ORDER BY changed_utc, change_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to list status changes: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(changes)
+    }
+
     // -------------------------------------------------------------------------
     // Transaction Operations
     // -------------------------------------------------------------------------
@@ -244,7 +689,10 @@ impl Database {
     /// Validates that debits equal credits, all accounts belong to tenant,
     /// all accounts have same currency, and no account would go negative
     /// (unless allow_negative is set).
-    /// Returns (journal_id, entries, currency).
+    /// Once the tenant has a base currency, every entry is valued in it and accounts
+    /// may differ in currency: the journal must then balance in base amounts. A
+    /// requested `base_currency` must match the tenant's.
+    /// Returns (journal_id, entries, currency), where currency is the base currency if set.
     #[instrument(skip(self, entries, metadata), fields(tenant_id = %tenant_id, entry_count = entries.len()))]
     pub async fn post_transaction(
         &self,
@@ -253,11 +701,127 @@ impl Database {
         effective_date: NaiveDate,
         idempotency_key: Option<&str>,
         metadata: Option<serde_json::Value>,
+        base_currency: Option<&str>,
+    ) -> Result<(Uuid, Vec<LedgerEntry>, String), AppError> {
+        self.post_journal(
+            tenant_id,
+            entries,
+            effective_date,
+            idempotency_key,
+            metadata,
+            base_currency,
+            None,
+        )
+        .await
+    }
+
+    /// Reverse a posted journal by posting its mirror-image entries under a new journal.
+    /// A journal can be reversed at most once, and reversals themselves cannot be reversed.
+    /// Returns (reversal_journal_id, entries, currency).
+    #[instrument(skip(self, metadata), fields(tenant_id = %tenant_id, journal_id = %journal_id))]
+    pub async fn reverse_transaction(
+        &self,
+        tenant_id: Uuid,
+        journal_id: Uuid,
+        effective_date: NaiveDate,
+        reason: Option<&str>,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<(Uuid, Vec<LedgerEntry>, String), AppError> {
+        let original = self.get_entries_by_journal(tenant_id, journal_id).await?;
+        let first = original.first().ok_or_else(|| {
+            AppError::NotFound(anyhow::anyhow!("Transaction {} not found", journal_id))
+        })?;
+
+        if let Some(reversed) = first.reverses_journal_id {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Journal {} is a reversal of {} and cannot be reversed",
+                journal_id,
+                reversed
+            )));
+        }
+
+        if let Some(existing) = self
+            .get_reversals(tenant_id, &[journal_id])
+            .await?
+            .into_iter()
+            .find(|r| r.original_journal_id == journal_id)
+        {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Journal {} has already been reversed by journal {}",
+                journal_id,
+                existing.reversal_journal_id
+            )));
+        }
+
+        if original.iter().any(|e| e.amount.is_zero()) {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Journal {} is an FX revaluation and cannot be reversed; revalue again instead",
+                journal_id
+            )));
+        }
+
+        if effective_date < first.effective_date {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Reversal effective_date {} is before the original effective_date {}",
+                effective_date,
+                first.effective_date
+            )));
+        }
+
+        let entries: Vec<PostEntry> = original
+            .iter()
+            .filter_map(|e| {
+                e.reversed_direction().map(|direction| PostEntry {
+                    account_id: e.account_id,
+                    amount: e.amount,
+                    direction,
+                    exchange_rate: e.exchange_rate,
+                })
+            })
+            .collect();
+
+        // Cross-currency journals are reversed at their original rates
+        self.post_journal(
+            tenant_id,
+            &entries,
+            effective_date,
+            None,
+            metadata,
+            first.base_currency.as_deref(),
+            Some(JournalLink::Reversal(journal_id, reason)),
+        )
+        .await
+    }
+
+    /// Shared posting path for regular transactions, reversals and hold captures.
+    /// When `link` is set, the new journal is linked to the reversed journal or the
+    /// captured hold inside the same database transaction as the entries.
+    #[allow(clippy::too_many_arguments)]
+    async fn post_journal(
+        &self,
+        tenant_id: Uuid,
+        entries: &[PostEntry],
+        effective_date: NaiveDate,
+        idempotency_key: Option<&str>,
+        metadata: Option<serde_json::Value>,
+        base_currency: Option<&str>,
+        link: Option<JournalLink<'_>>,
     ) -> Result<(Uuid, Vec<LedgerEntry>, String), AppError> {
         let timer = DB_QUERY_DURATION
             .with_label_values(&["post_transaction"])
             .start_timer();
 
+        let reversal = match link {
+            Some(JournalLink::Reversal(original_journal_id, reason)) => {
+                Some((original_journal_id, reason))
+            }
+            _ => None,
+        };
+        let capture = match link {
+            Some(JournalLink::HoldCapture(hold_id, amount)) => Some((hold_id, amount)),
+            _ => None,
+        };
+
         // Validate at least 2 entries
         if entries.len() < 2 {
             return Err(AppError::BadRequest(anyhow::anyhow!(
@@ -272,7 +836,7 @@ impl Database {
         // P1: Also fetch accounts to check currency consistency and allow_negative
         let accounts: Vec<Account> = sqlx::query_as::<_, Account>(
             r#"
-            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
             FROM accounts
             WHERE tenant_id = $1 AND account_id = ANY($2)
             "#,
@@ -297,22 +861,46 @@ impl Database {
             }
         }
 
-        // P1: Validate currency consistency - all accounts must have same currency
-        let first_currency = &accounts[0].currency;
-        for account in &accounts {
-            if account.currency != *first_currency {
+        // Once the tenant has a base currency every entry is valued in it;
+        // a requested base currency only has to match
+        let requested_base_currency = base_currency;
+        let tenant_base_currency = self.get_base_currency(tenant_id).await?;
+        match (requested_base_currency, tenant_base_currency.as_deref()) {
+            (Some(requested), None) => {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
-                    "Currency mismatch: account {} has currency {} but expected {}",
-                    account.account_id,
-                    account.currency,
-                    first_currency
+                    "base_currency {} requested but the tenant has no base currency (set one with SetBaseCurrency)",
+                    requested
+                )));
+            }
+            (Some(requested), Some(base)) if requested != base => {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "base_currency {} does not match the tenant base currency {}",
+                    requested,
+                    base
                 )));
             }
+            _ => {}
         }
+        let base_currency = tenant_base_currency.as_deref();
 
-        // Validate double-entry: sum of debits must equal sum of credits
-        let mut debit_sum = Decimal::ZERO;
-        let mut credit_sum = Decimal::ZERO;
+        // P1: Validate currency consistency - all accounts must have same currency,
+        // unless the journal balances in the tenant base currency
+        let first_currency = &accounts[0].currency;
+        if base_currency.is_none() {
+            for account in &accounts {
+                if account.currency != *first_currency {
+                    return Err(AppError::BadRequest(anyhow::anyhow!(
+                        "Currency mismatch: account {} has currency {} but expected {} (set a tenant base currency for cross-currency journals)",
+                        account.account_id,
+                        account.currency,
+                        first_currency
+                    )));
+                }
+            }
+        }
+        let journal_currency = base_currency
+            .map(str::to_string)
+            .unwrap_or_else(|| first_currency.clone());
 
         for entry in entries {
             if entry.amount <= Decimal::ZERO {
@@ -320,9 +908,59 @@ impl Database {
                     "Entry amount must be positive"
                 )));
             }
+        }
+
+        // Value each entry in the base currency: (exchange_rate, base_amount)
+        let mut base_values: Vec<Option<(Decimal, Decimal)>> = vec![None; entries.len()];
+        if let Some(base) = base_currency {
+            let mut rated = Vec::with_capacity(entries.len());
+            for entry in entries {
+                let account = account_map.get(&entry.account_id).unwrap();
+                let rate = if account.currency == base {
+                    Decimal::ONE
+                } else if let Some(rate) = entry.exchange_rate {
+                    rate
+                } else {
+                    self.find_exchange_rate(tenant_id, &account.currency, base, effective_date)
+                        .await?
+                        .ok_or_else(|| {
+                            AppError::BadRequest(anyhow::anyhow!(
+                                "No exchange rate from {} to {} on or before {}",
+                                account.currency,
+                                base,
+                                effective_date
+                            ))
+                        })?
+                };
+                rated.push((entry.direction, entry.amount, rate));
+            }
+            // A journal that does not balance keeps its plainly rounded amounts and
+            // is rejected below
+            let base_amounts = convert_to_base(&rated).unwrap_or_else(|| {
+                rated
+                    .iter()
+                    .map(|(_, amount, rate)| (amount * rate).round_dp(4))
+                    .collect()
+            });
+            base_values = rated
+                .iter()
+                .zip(base_amounts)
+                .map(|((_, _, rate), base_amount)| Some((*rate, base_amount)))
+                .collect();
+        }
+
+        // Validate double-entry: sum of debits must equal sum of credits
+        // (in the base currency once the tenant has one)
+        let mut debit_sum = Decimal::ZERO;
+        let mut credit_sum = Decimal::ZERO;
+
+        for (entry, base_value) in entries.iter().zip(&base_values) {
+            let amount = base_value
+                .map(|(_, base_amount)| base_amount)
+                .unwrap_or(entry.amount);
             match entry.direction {
-                Direction::Debit => debit_sum += entry.amount,
-                Direction::Credit => credit_sum += entry.amount,
+                Direction::Debit => debit_sum += amount,
+                Direction::Credit => credit_sum += amount,
             }
         }
 
@@ -334,12 +972,82 @@ impl Database {
             )));
         }
 
-        // P1: Validate negative balance constraints
-        // Calculate impact on each account and check if resulting balance would go negative
-        for entry in entries {
-            let account = account_map.get(&entry.account_id).unwrap();
-
-            // Skip check if account allows negative balances
+        // Check idempotency - a replay of the same request returns the original journal
+        let request_hash = idempotency_key.map(|_| {
+            request_fingerprint(
+                entries,
+                effective_date,
+                metadata.as_ref(),
+                requested_base_currency,
+            )
+        });
+        if let (Some(key), Some(hash)) = (idempotency_key, request_hash.as_deref()) {
+            if let Some((journal_id, entries)) = self
+                .find_idempotent_replay(
+                    tenant_id,
+                    key,
+                    hash,
+                    entries,
+                    effective_date,
+                    metadata.as_ref(),
+                    base_currency,
+                )
+                .await?
+            {
+                timer.observe_duration();
+                return Ok((journal_id, entries, journal_currency));
+            }
+        }
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Entries were valued against the base currency read above; SetBaseCurrency
+        // may have run since
+        if Self::lock_base_currency(&mut tx, tenant_id).await? != tenant_base_currency {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Tenant base currency changed while posting; retry the request"
+            )));
+        }
+
+        Self::ensure_period_open(&mut tx, tenant_id, effective_date).await?;
+        Self::ensure_accounts_active(&mut tx, tenant_id, &account_ids).await?;
+        Self::lock_account_balances(&mut tx, &account_ids).await?;
+
+        // Header accounts only roll up their children and cannot be posted to.
+        // Checked under the account row locks so a concurrently created sub-account is seen.
+        let header_account: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT parent_account_id
+            FROM accounts
+            WHERE tenant_id = $1 AND parent_account_id = ANY($2)
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(&account_ids)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to check header accounts: {}", e))
+        })?;
+
+        if let Some(account_id) = header_account {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Account {} is a header account with sub-accounts and cannot be posted to",
+                account_id
+            )));
+        }
+
+        // P1: Validate negative balance constraints
+        // Calculate impact on each account and check if resulting balance would go negative.
+        // Checked under the balance locks, which holds take too, so concurrent postings
+        // and holds on an account see each other.
+        for entry in entries {
+            let account = account_map.get(&entry.account_id).unwrap();
+
+            // Skip check if account allows negative balances
             if account.allow_negative {
                 continue;
             }
@@ -357,13 +1065,21 @@ impl Database {
             )
             .bind(tenant_id)
             .bind(entry.account_id)
-            .fetch_one(&self.pool)
+            .fetch_one(&mut *tx)
             .await
             .map_err(|e| {
                 AppError::DatabaseError(anyhow::anyhow!("Failed to get balance: {}", e))
             })?;
 
-            let current = current_balance.unwrap_or(Decimal::ZERO);
+            // Pending holds drawing the account down reduce its available balance;
+            // a hold being captured is replaced by this journal and not counted
+            let (pending_debits, pending_credits) = Self::pending_hold_totals(
+                &mut *tx,
+                tenant_id,
+                entry.account_id,
+                capture.map(|(hold_id, _)| hold_id),
+            )
+            .await?;
 
             // Calculate impact based on account type and direction
             // Asset/Expense: debit increases, credit decreases (normal debit balance)
@@ -375,11 +1091,17 @@ impl Database {
 
             // For normal-debit accounts (asset/expense), balance should stay >= 0
             // For normal-credit accounts (liability/equity/revenue), balance should stay <= 0
-            let new_balance = current + impact;
-
             let account_type = AccountType::from_string(&account.account_type);
             let is_debit_normal = matches!(account_type, AccountType::Asset | AccountType::Expense);
 
+            let current = current_balance.unwrap_or(Decimal::ZERO)
+                + if is_debit_normal {
+                    -pending_credits
+                } else {
+                    pending_debits
+                };
+            let new_balance = current + impact;
+
             if is_debit_normal && new_balance < Decimal::ZERO {
                 return Err(AppError::BadRequest(anyhow::anyhow!(
                     "Insufficient balance in account {}: current {}, would become {}",
@@ -398,44 +1120,118 @@ impl Database {
             }
         }
 
-        // Check idempotency - use transaction to handle race condition
-        let mut tx = self.pool.begin().await.map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
-        })?;
+        let journal_id = Uuid::new_v4();
+        let mut inserted_entries = Vec::with_capacity(entries.len());
 
-        if let Some(key) = idempotency_key {
-            let existing = sqlx::query_scalar::<_, Uuid>(
-                "SELECT journal_id FROM ledger_entries WHERE idempotency_key = $1 LIMIT 1",
+        // Claim the idempotency key; an expired claim is taken over, a live one means
+        // a concurrent request with the same key won the race
+        if let (Some(key), Some(hash)) = (idempotency_key, request_hash.as_deref()) {
+            let claimed = sqlx::query(
+                r#"
+                INSERT INTO idempotency_keys (tenant_id, idempotency_key, request_hash, journal_id, expires_utc)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (tenant_id, idempotency_key) DO UPDATE
+                SET request_hash = EXCLUDED.request_hash, journal_id = EXCLUDED.journal_id,
+                    created_utc = NOW(), expires_utc = EXCLUDED.expires_utc
+                WHERE idempotency_keys.expires_utc <= NOW()
+                "#,
             )
+            .bind(tenant_id)
             .bind(key)
-            .fetch_optional(&mut *tx)
+            .bind(hash)
+            .bind(journal_id)
+            .bind(Utc::now() + chrono::Duration::seconds(self.idempotency_retention_secs))
+            .execute(&mut *tx)
             .await
             .map_err(|e| {
-                AppError::DatabaseError(anyhow::anyhow!("Failed to check idempotency: {}", e))
+                AppError::DatabaseError(anyhow::anyhow!("Failed to claim idempotency key: {}", e))
             })?;
 
-            if let Some(journal_id) = existing {
-                // Return existing transaction
+            if claimed.rows_affected() == 0 {
                 tx.rollback().await.ok();
-                let entries = self.get_entries_by_journal(tenant_id, journal_id).await?;
-                timer.observe_duration();
-                return Ok((journal_id, entries, first_currency.clone()));
+                if let Some((jid, entries)) = self
+                    .find_idempotent_replay(
+                        tenant_id,
+                        key,
+                        hash,
+                        entries,
+                        effective_date,
+                        metadata.as_ref(),
+                        base_currency,
+                    )
+                    .await?
+                {
+                    timer.observe_duration();
+                    return Ok((jid, entries, journal_currency));
+                }
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Duplicate idempotency key"
+                )));
             }
         }
 
-        let journal_id = Uuid::new_v4();
-        let mut inserted_entries = Vec::with_capacity(entries.len());
+        // Link the reversal first so a concurrent second reversal fails on the primary key
+        if let Some((original_journal_id, reason)) = reversal {
+            sqlx::query(
+                r#"
+                INSERT INTO journal_reversals (original_journal_id, reversal_journal_id, tenant_id, reason)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(original_journal_id)
+            .bind(journal_id)
+            .bind(tenant_id)
+            .bind(reason)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                    AppError::Conflict(anyhow::anyhow!(
+                        "Journal {} has already been reversed",
+                        original_journal_id
+                    ))
+                }
+                _ => AppError::DatabaseError(anyhow::anyhow!("Failed to link reversal: {}", e)),
+            })?;
+        }
+
+        // Resolve the hold in the same transaction; its row lock serializes captures
+        if let Some((hold_id, amount)) = capture {
+            let captured = sqlx::query(
+                r#"
+                UPDATE holds
+                SET status = 'captured', captured_amount = $3, journal_id = $4, resolved_utc = NOW()
+                WHERE tenant_id = $1 AND hold_id = $2 AND status = 'pending' AND expires_utc > NOW()
+                "#,
+            )
+            .bind(tenant_id)
+            .bind(hold_id)
+            .bind(amount)
+            .bind(journal_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to capture hold: {}", e))
+            })?;
+
+            if captured.rows_affected() == 0 {
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Hold {} is no longer pending",
+                    hold_id
+                )));
+            }
+        }
 
-        for (i, entry) in entries.iter().enumerate() {
+        for (i, (entry, base_value)) in entries.iter().zip(&base_values).enumerate() {
             let entry_id = Uuid::new_v4();
             // Only first entry gets the idempotency key
             let key = if i == 0 { idempotency_key } else { None };
 
             let result = sqlx::query_as::<_, LedgerEntry>(
                 r#"
-                INSERT INTO ledger_entries (entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, idempotency_key, metadata)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-                RETURNING entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata
+                INSERT INTO ledger_entries (entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                RETURNING entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate
                 "#,
             )
             .bind(entry_id)
@@ -447,36 +1243,15 @@ impl Database {
             .bind(effective_date)
             .bind(key)
             .bind(&metadata)
+            .bind(reversal.map(|(original_journal_id, _)| original_journal_id))
+            .bind(base_currency)
+            .bind(base_value.map(|(_, base_amount)| base_amount))
+            .bind(base_value.map(|(rate, _)| rate))
             .fetch_one(&mut *tx)
             .await;
 
             match result {
                 Ok(inserted) => inserted_entries.push(inserted),
-                Err(sqlx::Error::Database(ref db_err)) if db_err.is_unique_violation() => {
-                    // P2: Idempotency key race condition - another request won
-                    // Roll back and return the existing transaction
-                    tx.rollback().await.ok();
-                    if let Some(key) = idempotency_key {
-                        let existing_journal = sqlx::query_scalar::<_, Uuid>(
-                            "SELECT journal_id FROM ledger_entries WHERE idempotency_key = $1 LIMIT 1",
-                        )
-                        .bind(key)
-                        .fetch_optional(&self.pool)
-                        .await
-                        .map_err(|e| {
-                            AppError::DatabaseError(anyhow::anyhow!("Failed to fetch existing: {}", e))
-                        })?;
-
-                        if let Some(jid) = existing_journal {
-                            let entries = self.get_entries_by_journal(tenant_id, jid).await?;
-                            timer.observe_duration();
-                            return Ok((jid, entries, first_currency.clone()));
-                        }
-                    }
-                    return Err(AppError::Conflict(anyhow::anyhow!(
-                        "Duplicate idempotency key"
-                    )));
-                }
                 Err(e) => {
                     return Err(AppError::DatabaseError(anyhow::anyhow!(
                         "Failed to insert entry: {}",
@@ -496,10 +1271,11 @@ impl Database {
             journal_id = %journal_id,
             entry_count = entries.len(),
             total_amount = %debit_sum,
+            reverses_journal_id = ?reversal.map(|(original_journal_id, _)| original_journal_id),
             "Transaction posted"
         );
 
-        Ok((journal_id, inserted_entries, first_currency.clone()))
+        Ok((journal_id, inserted_entries, journal_currency))
     }
 
     /// Get all entries for a journal.
@@ -515,7 +1291,7 @@ impl Database {
 
         let entries = sqlx::query_as::<_, LedgerEntry>(
             r#"
-            SELECT entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata
+            SELECT entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate
             FROM ledger_entries
             WHERE tenant_id = $1 AND journal_id = $2
             ORDER BY entry_id
@@ -532,6 +1308,85 @@ impl Database {
         Ok(entries)
     }
 
+    /// Get a page of entries effective in a date range for export, with their
+    /// account code and currency. Entries are ordered by
+    /// (effective_date, posted_utc, journal_id, entry_id), so a journal's entries
+    /// are contiguous; pass the last entry of the previous page as `after`.
+    #[instrument(skip(self, after), fields(tenant_id = %tenant_id))]
+    pub async fn export_entries(
+        &self,
+        tenant_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+        after: Option<&LedgerEntry>,
+        limit: i64,
+    ) -> Result<Vec<ExportedEntry>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["export_entries"])
+            .start_timer();
+
+        let entries = sqlx::query_as::<_, ExportedEntry>(
+            r#"
+            SELECT e.entry_id, e.tenant_id, e.journal_id, e.account_id, e.amount, e.direction, e.effective_date, e.posted_utc, e.idempotency_key, e.metadata, e.reverses_journal_id, e.base_currency, e.base_amount, e.exchange_rate,
+                   a.account_code, a.currency
+            FROM ledger_entries e
+            JOIN accounts a ON a.account_id = e.account_id
+            WHERE e.tenant_id = $1
+              AND e.effective_date >= $2
+              AND e.effective_date <= $3
+              AND ($4::date IS NULL
+                   OR (e.effective_date, e.posted_utc, e.journal_id, e.entry_id) > ($4, $5, $6, $7))
+            ORDER BY e.effective_date, e.posted_utc, e.journal_id, e.entry_id
+            LIMIT $8
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(after.map(|e| e.effective_date))
+        .bind(after.map(|e| e.posted_utc))
+        .bind(after.map(|e| e.journal_id))
+        .bind(after.map(|e| e.entry_id))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to export entries: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(entries)
+    }
+
+    /// Get reversal links where any of the given journals is the original or the reversal.
+    #[instrument(skip(self, journal_ids), fields(tenant_id = %tenant_id, journal_count = journal_ids.len()))]
+    pub async fn get_reversals(
+        &self,
+        tenant_id: Uuid,
+        journal_ids: &[Uuid],
+    ) -> Result<Vec<JournalReversal>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_reversals"])
+            .start_timer();
+
+        let reversals = sqlx::query_as::<_, JournalReversal>(
+            r#"
+            SELECT original_journal_id, reversal_journal_id, tenant_id, reason, reversed_utc
+            FROM journal_reversals
+            WHERE tenant_id = $1
+              AND (original_journal_id = ANY($2) OR reversal_journal_id = ANY($2))
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(journal_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get reversals: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(reversals)
+    }
+
     /// List transactions (grouped by journal_id) with optional filters.
     /// P3: Orders by effective_date DESC, posted_utc DESC (most recent first).
     #[instrument(skip(self), fields(tenant_id = %tenant_id))]
@@ -632,186 +1487,2333 @@ impl Database {
     }
 
     // -------------------------------------------------------------------------
-    // Balance Operations
+    // Idempotency Operations
     // -------------------------------------------------------------------------
 
-    /// Get balance for an account as of a specific date.
-    /// P2: Balance calculation considers account type:
-    /// - Asset/Expense (debit-normal): balance = debits - credits (positive = normal)
-    /// - Liability/Equity/Revenue (credit-normal): balance = credits - debits (positive = normal)
-    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
-    pub async fn get_balance(
+    /// Get the unexpired idempotency record for a tenant's key, if any.
+    #[instrument(skip(self, key), fields(tenant_id = %tenant_id))]
+    pub async fn get_idempotency_record(
         &self,
         tenant_id: Uuid,
-        account_id: Uuid,
-        as_of_date: Option<NaiveDate>,
-    ) -> Result<Option<(Decimal, String)>, AppError> {
-        let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_balance"])
-            .start_timer();
-
-        // First get the account to verify it exists and get currency/type
-        let account = self.get_account(tenant_id, account_id).await?;
-        let account = match account {
-            Some(a) => a,
-            None => return Ok(None),
-        };
-
-        // Calculate balance from entries
-        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
-
-        // Calculate raw balance (debits - credits)
-        let raw_balance: Option<Decimal> = sqlx::query_scalar(
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        sqlx::query_as::<_, IdempotencyRecord>(
             r#"
-            SELECT COALESCE(
-                SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END),
-                0
-            )
-            FROM ledger_entries
-            WHERE tenant_id = $1
-              AND account_id = $2
-              AND effective_date <= $3
+            SELECT tenant_id, idempotency_key, request_hash, journal_id, created_utc, expires_utc
+            FROM idempotency_keys
+            WHERE tenant_id = $1 AND idempotency_key = $2 AND expires_utc > NOW()
             "#,
         )
         .bind(tenant_id)
-        .bind(account_id)
-        .bind(as_of)
-        .fetch_one(&self.pool)
+        .bind(key)
+        .fetch_optional(&self.pool)
         .await
-        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get balance: {}", e)))?;
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to check idempotency: {}", e)))
+    }
 
-        let raw = raw_balance.unwrap_or(Decimal::ZERO);
+    /// Get the journal already posted under an idempotency key, if any.
+    pub async fn find_journal_by_idempotency_key(
+        &self,
+        tenant_id: Uuid,
+        key: &str,
+    ) -> Result<Option<Uuid>, AppError> {
+        Ok(self
+            .get_idempotency_record(tenant_id, key)
+            .await?
+            .map(|record| record.journal_id))
+    }
 
-        // P2: Adjust sign based on account type
-        // For credit-normal accounts, negate to show positive balance
-        let account_type = AccountType::from_string(&account.account_type);
-        let is_debit_normal = matches!(account_type, AccountType::Asset | AccountType::Expense);
-        let balance = if is_debit_normal { raw } else { -raw };
+    /// Resolve a replayed idempotency key. Returns the original journal when the
+    /// request matches its fingerprint, `None` when the key is unused or expired, and
+    /// a Conflict wrapping [`IdempotencyConflict`] when the request differs.
+    #[allow(clippy::too_many_arguments)]
+    async fn find_idempotent_replay(
+        &self,
+        tenant_id: Uuid,
+        key: &str,
+        request_hash: &str,
+        entries: &[PostEntry],
+        effective_date: NaiveDate,
+        metadata: Option<&serde_json::Value>,
+        base_currency: Option<&str>,
+    ) -> Result<Option<(Uuid, Vec<LedgerEntry>)>, AppError> {
+        let Some(record) = self.get_idempotency_record(tenant_id, key).await? else {
+            return Ok(None);
+        };
 
-        timer.observe_duration();
+        let original = self
+            .get_entries_by_journal(tenant_id, record.journal_id)
+            .await?;
 
-        Ok(Some((balance, account.currency)))
+        // Keys carried over from before fingerprinting replay unchecked
+        if record.request_hash.is_none() || record.request_hash.as_deref() == Some(request_hash) {
+            return Ok(Some((record.journal_id, original)));
+        }
+
+        warn!(journal_id = %record.journal_id, "Idempotency key reused with a different request");
+        Err(AppError::Conflict(anyhow::Error::new(
+            IdempotencyConflict {
+                idempotency_key: key.to_string(),
+                journal_id: record.journal_id,
+                differences: describe_differences(
+                    &original,
+                    entries,
+                    effective_date,
+                    metadata,
+                    base_currency,
+                ),
+            },
+        )))
     }
 
-    /// Get balances for multiple accounts.
-    #[instrument(skip(self, account_ids), fields(tenant_id = %tenant_id, account_count = account_ids.len()))]
-    pub async fn get_balances(
-        &self,
-        tenant_id: Uuid,
-        account_ids: &[Uuid],
-        as_of_date: Option<NaiveDate>,
-    ) -> Result<Vec<(Uuid, Decimal, String)>, AppError> {
+    /// Delete idempotency keys past their retention window.
+    /// Returns the number of keys deleted.
+    #[instrument(skip(self))]
+    pub async fn purge_idempotency_keys(&self) -> Result<u64, AppError> {
         let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_balances"])
+            .with_label_values(&["purge_idempotency_keys"])
             .start_timer();
 
-        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_utc <= NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to purge idempotency keys: {}", e))
+            })?;
 
-        // Get all accounts first to verify they exist and get currencies
-        let mut results = Vec::with_capacity(account_ids.len());
+        timer.observe_duration();
 
-        for account_id in account_ids {
-            if let Some((balance, currency)) = self
-                .get_balance(tenant_id, *account_id, Some(as_of))
-                .await?
-            {
-                results.push((*account_id, balance, currency));
-            }
+        if result.rows_affected() > 0 {
+            info!(count = result.rows_affected(), "Idempotency keys purged");
         }
 
-        timer.observe_duration();
-
-        Ok(results)
+        Ok(result.rows_affected())
     }
 
     // -------------------------------------------------------------------------
-    // Statement Operations
+    // Hold Operations
     // -------------------------------------------------------------------------
 
-    /// Get account statement with running balance for a date range.
-    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
-    pub async fn get_statement(
-        &self,
+    /// Sum the pending, unexpired holds on an account.
+    /// Returns (amount held on the debit side, amount held on the credit side).
+    async fn pending_hold_totals<'e>(
+        executor: impl sqlx::PgExecutor<'e>,
         tenant_id: Uuid,
         account_id: Uuid,
-        start_date: NaiveDate,
-        end_date: NaiveDate,
-    ) -> Result<Option<StatementData>, AppError> {
-        let timer = DB_QUERY_DURATION
-            .with_label_values(&["get_statement"])
-            .start_timer();
-
-        // Get account
-        let account = self.get_account(tenant_id, account_id).await?;
-        let account = match account {
-            Some(a) => a,
-            None => return Ok(None),
-        };
-
-        // Calculate opening balance (balance as of day before start_date)
-        let opening_date = start_date.pred_opt().unwrap_or(start_date);
-        let opening_balance: Option<Decimal> = sqlx::query_scalar(
+        exclude_hold_id: Option<Uuid>,
+    ) -> Result<(Decimal, Decimal), AppError> {
+        sqlx::query_as(
             r#"
-            SELECT COALESCE(
-                SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END),
-                0
-            )
-            FROM ledger_entries
+            SELECT
+                COALESCE(SUM(amount) FILTER (WHERE debit_account_id = $2), 0),
+                COALESCE(SUM(amount) FILTER (WHERE credit_account_id = $2), 0)
+            FROM holds
             WHERE tenant_id = $1
-              AND account_id = $2
-              AND effective_date <= $3
+              AND (debit_account_id = $2 OR credit_account_id = $2)
+              AND status = 'pending'
+              AND expires_utc > NOW()
+              AND ($3::uuid IS NULL OR hold_id <> $3)
             "#,
         )
         .bind(tenant_id)
         .bind(account_id)
-        .bind(opening_date)
-        .fetch_one(&self.pool)
+        .bind(exclude_hold_id)
+        .fetch_one(executor)
         .await
-        .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to get opening balance: {}", e))
-        })?;
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to sum holds: {}", e)))
+    }
 
-        let opening_balance = opening_balance.unwrap_or(Decimal::ZERO);
+    /// Get the available balance of an account: its current balance less the
+    /// pending holds that would draw it down. Signed like get_balance.
+    /// Returns None if the account does not exist.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
+    pub async fn get_available_balance(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Option<Decimal>, AppError> {
+        let account = match self.get_account(tenant_id, account_id).await? {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+        let balance = self
+            .get_balance(tenant_id, account_id, None)
+            .await?
+            .map(|(b, _)| b)
+            .unwrap_or(Decimal::ZERO);
+        let (pending_debits, pending_credits) =
+            Self::pending_hold_totals(&self.pool, tenant_id, account_id, None).await?;
 
-        // Get entries in date range
-        let entries = sqlx::query_as::<_, LedgerEntry>(
-            r#"
-            SELECT entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata
-            FROM ledger_entries
-            WHERE tenant_id = $1
-              AND account_id = $2
-              AND effective_date >= $3
-              AND effective_date <= $4
-            ORDER BY effective_date, posted_utc
-            "#,
-        )
-        .bind(tenant_id)
-        .bind(account_id)
-        .bind(start_date)
-        .bind(end_date)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| {
-            AppError::DatabaseError(anyhow::anyhow!("Failed to get statement entries: {}", e))
+        let account_type = AccountType::from_string(&account.account_type);
+        let is_debit_normal = matches!(account_type, AccountType::Asset | AccountType::Expense);
+        let held = if is_debit_normal {
+            pending_credits
+        } else {
+            pending_debits
+        };
+
+        Ok(Some(balance - held))
+    }
+
+    /// Create a hold reserving `amount` for a future debit/credit pair.
+    /// The account drawn down by the hold must have enough available balance unless it
+    /// allows negative balances. It is checked under the same balance locks as postings,
+    /// so concurrent holds and journals on an account are checked one after another.
+    /// With an idempotency key, a replay of the same request returns the original hold
+    /// and a replay with a different request is rejected.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id))]
+    pub async fn create_hold(&self, input: &CreateHold) -> Result<Hold, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["create_hold"])
+            .start_timer();
+
+        let request_hash = input
+            .idempotency_key
+            .as_ref()
+            .map(|_| hold_fingerprint(input));
+        if let (Some(key), Some(hash)) = (&input.idempotency_key, request_hash.as_deref()) {
+            if let Some(existing) = self.find_hold_replay(input, key, hash).await? {
+                timer.observe_duration();
+                return Ok(existing);
+            }
+        }
+
+        if input.amount <= Decimal::ZERO {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Hold amount must be positive"
+            )));
+        }
+
+        if input.debit_account_id == input.credit_account_id {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Hold debit and credit accounts must differ"
+            )));
+        }
+
+        let account_ids = [input.debit_account_id, input.credit_account_id];
+        let accounts: Vec<Account> = sqlx::query_as::<_, Account>(
+            r#"
+            SELECT account_id, tenant_id, account_type, account_code, currency, allow_negative, metadata, created_utc, closed_utc, parent_account_id, status
+            FROM accounts
+            WHERE tenant_id = $1 AND account_id = ANY($2)
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(&account_ids[..])
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to fetch accounts: {}", e)))?;
+
+        let find = |account_id: Uuid| {
+            accounts
+                .iter()
+                .find(|a| a.account_id == account_id)
+                .ok_or_else(|| {
+                    AppError::BadRequest(anyhow::anyhow!(
+                        "Account {} does not exist or does not belong to tenant",
+                        account_id
+                    ))
+                })
+        };
+        let debit_account = find(input.debit_account_id)?;
+        let credit_account = find(input.credit_account_id)?;
+
+        if debit_account.currency != credit_account.currency {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Currency mismatch: account {} has currency {} but account {} has currency {}",
+                debit_account.account_id,
+                debit_account.currency,
+                credit_account.account_id,
+                credit_account.currency
+            )));
+        }
+
+        let is_header: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM accounts WHERE tenant_id = $1 AND parent_account_id = ANY($2))",
+        )
+        .bind(input.tenant_id)
+        .bind(&account_ids[..])
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to check header accounts: {}", e))
         })?;
 
-        // Calculate closing balance
-        let mut closing_balance = opening_balance;
-        for entry in &entries {
-            match entry.direction.as_str() {
-                "debit" => closing_balance += entry.amount,
-                "credit" => closing_balance -= entry.amount,
-                _ => {}
+        if is_header {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Holds cannot be placed on header accounts with sub-accounts"
+            )));
+        }
+
+        // The debit draws down credit-normal accounts, the credit draws down debit-normal ones
+        let is_debit_normal = |account: &Account| {
+            matches!(
+                AccountType::from_string(&account.account_type),
+                AccountType::Asset | AccountType::Expense
+            )
+        };
+        let drawn_account = if is_debit_normal(credit_account) {
+            Some(credit_account)
+        } else if !is_debit_normal(debit_account) {
+            Some(debit_account)
+        } else {
+            None
+        };
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        Self::ensure_accounts_active(&mut tx, input.tenant_id, &account_ids).await?;
+        Self::lock_account_balances(&mut tx, &account_ids).await?;
+
+        if let Some(account) = drawn_account.filter(|a| !a.allow_negative) {
+            let raw_balance: Decimal = sqlx::query_scalar(
+                r#"
+                SELECT COALESCE(
+                    SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END),
+                    0
+                )
+                FROM ledger_entries
+                WHERE tenant_id = $1 AND account_id = $2
+                "#,
+            )
+            .bind(input.tenant_id)
+            .bind(account.account_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to get balance: {}", e))
+            })?;
+
+            let (pending_debits, pending_credits) =
+                Self::pending_hold_totals(&mut *tx, input.tenant_id, account.account_id, None)
+                    .await?;
+            let available = if is_debit_normal(account) {
+                raw_balance - pending_credits
+            } else {
+                -raw_balance - pending_debits
+            };
+
+            if available < input.amount {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Insufficient available balance in account {}: available {}, hold {}",
+                    account.account_id,
+                    available,
+                    input.amount
+                )));
             }
         }
 
+        let result = sqlx::query_as::<_, Hold>(
+            r#"
+            INSERT INTO holds (hold_id, tenant_id, debit_account_id, credit_account_id, amount, currency, expires_utc, idempotency_key, metadata, request_hash)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING hold_id, tenant_id, debit_account_id, credit_account_id, amount, captured_amount, currency, status, expires_utc, idempotency_key, metadata, journal_id, created_utc, resolved_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(input.debit_account_id)
+        .bind(input.credit_account_id)
+        .bind(input.amount)
+        .bind(&debit_account.currency)
+        .bind(input.expires_utc)
+        .bind(&input.idempotency_key)
+        .bind(&input.metadata)
+        .bind(&request_hash)
+        .fetch_one(&mut *tx)
+        .await;
+
+        let hold = match result {
+            Ok(hold) => hold,
+            Err(sqlx::Error::Database(ref db_err)) if db_err.is_unique_violation() => {
+                // Idempotency key race - another request created the hold first
+                tx.rollback().await.ok();
+                if let (Some(key), Some(hash)) = (&input.idempotency_key, request_hash.as_deref()) {
+                    if let Some(existing) = self.find_hold_replay(input, key, hash).await? {
+                        timer.observe_duration();
+                        return Ok(existing);
+                    }
+                }
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Duplicate idempotency key"
+                )));
+            }
+            Err(e) => {
+                return Err(AppError::DatabaseError(anyhow::anyhow!(
+                    "Failed to create hold: {}",
+                    e
+                )));
+            }
+        };
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
         timer.observe_duration();
 
-        Ok(Some((
-            account.currency,
-            opening_balance,
-            closing_balance,
-            entries,
+        info!(
+            hold_id = %hold.hold_id,
+            amount = %hold.amount,
+            expires_utc = %hold.expires_utc,
+            "Hold created"
+        );
+
+        Ok(hold)
+    }
+
+    /// Look up the hold created under an idempotency key.
+    /// Returns the hold if the key is unused or was used for the same request, and an
+    /// `HoldIdempotencyConflict` if it was used for a different request.
+    async fn find_hold_replay(
+        &self,
+        input: &CreateHold,
+        key: &str,
+        request_hash: &str,
+    ) -> Result<Option<Hold>, AppError> {
+        let row: Option<(Uuid, Option<String>)> = sqlx::query_as(
+            "SELECT hold_id, request_hash FROM holds WHERE tenant_id = $1 AND idempotency_key = $2",
+        )
+        .bind(input.tenant_id)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get hold: {}", e)))?;
+
+        let Some((hold_id, stored_hash)) = row else {
+            return Ok(None);
+        };
+        let Some(original) = self.get_hold(input.tenant_id, hold_id).await? else {
+            return Ok(None);
+        };
+
+        // Holds created before fingerprinting replay unchecked
+        if stored_hash.is_none() || stored_hash.as_deref() == Some(request_hash) {
+            return Ok(Some(original));
+        }
+
+        warn!(hold_id = %hold_id, "Idempotency key reused with a different hold request");
+        Err(AppError::Conflict(anyhow::Error::new(
+            HoldIdempotencyConflict {
+                idempotency_key: key.to_string(),
+                hold_id,
+                differences: describe_hold_differences(&original, input),
+            },
         )))
     }
+
+    /// Get a hold by ID.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, hold_id = %hold_id))]
+    pub async fn get_hold(&self, tenant_id: Uuid, hold_id: Uuid) -> Result<Option<Hold>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_hold"])
+            .start_timer();
+
+        let hold = sqlx::query_as::<_, Hold>(
+            r#"
+            SELECT hold_id, tenant_id, debit_account_id, credit_account_id, amount, captured_amount, currency, status, expires_utc, idempotency_key, metadata, journal_id, created_utc, resolved_utc
+            FROM holds
+            WHERE tenant_id = $1 AND hold_id = $2
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(hold_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get hold: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(hold)
+    }
+
+    /// List holds for a tenant, optionally limited to one account (either side) and status.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn list_holds(
+        &self,
+        tenant_id: Uuid,
+        account_id: Option<Uuid>,
+        status: Option<HoldStatus>,
+        page_size: i32,
+        page_token: Option<Uuid>,
+    ) -> Result<Vec<Hold>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_holds"])
+            .start_timer();
+
+        let limit = page_size.clamp(1, 100) as i64;
+
+        let holds = sqlx::query_as::<_, Hold>(
+            r#"
+            SELECT hold_id, tenant_id, debit_account_id, credit_account_id, amount, captured_amount, currency, status, expires_utc, idempotency_key, metadata, journal_id, created_utc, resolved_utc
+            FROM holds
+            WHERE tenant_id = $1
+              AND ($2::uuid IS NULL OR debit_account_id = $2 OR credit_account_id = $2)
+              AND ($3::varchar IS NULL OR status = $3)
+              AND ($4::uuid IS NULL OR hold_id > $4)
+            ORDER BY hold_id
+            LIMIT $5
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(status.map(|s| s.as_str()))
+        .bind(page_token)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list holds: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(holds)
+    }
+
+    /// Capture a pending hold, posting `amount` (the full hold when unset) from its debit
+    /// to its credit account. Any remainder of a partial capture is released.
+    /// Returns None if the hold does not exist.
+    #[instrument(skip(self, metadata), fields(tenant_id = %tenant_id, hold_id = %hold_id))]
+    pub async fn capture_hold(
+        &self,
+        tenant_id: Uuid,
+        hold_id: Uuid,
+        amount: Option<Decimal>,
+        effective_date: NaiveDate,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<Option<HoldCaptureData>, AppError> {
+        let hold = match self.get_hold(tenant_id, hold_id).await? {
+            Some(h) => h,
+            None => return Ok(None),
+        };
+
+        if hold.parsed_status() == Some(HoldStatus::Pending) && !hold.is_active(Utc::now()) {
+            self.expire_holds().await?;
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Hold {} expired at {}",
+                hold_id,
+                hold.expires_utc
+            )));
+        }
+        if hold.parsed_status() != Some(HoldStatus::Pending) {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Hold {} is {} and cannot be captured",
+                hold_id,
+                hold.status
+            )));
+        }
+
+        let amount = amount.unwrap_or(hold.amount);
+        if amount <= Decimal::ZERO || amount > hold.amount {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Capture amount must be positive and at most the held amount {}",
+                hold.amount
+            )));
+        }
+
+        let entries = [
+            PostEntry {
+                account_id: hold.debit_account_id,
+                amount,
+                direction: Direction::Debit,
+                exchange_rate: None,
+            },
+            PostEntry {
+                account_id: hold.credit_account_id,
+                amount,
+                direction: Direction::Credit,
+                exchange_rate: None,
+            },
+        ];
+
+        let (journal_id, inserted_entries, currency) = self
+            .post_journal(
+                tenant_id,
+                &entries,
+                effective_date,
+                None,
+                metadata.or_else(|| hold.metadata.clone()),
+                None,
+                Some(JournalLink::HoldCapture(hold_id, amount)),
+            )
+            .await?;
+
+        let hold = self.get_hold(tenant_id, hold_id).await?.ok_or_else(|| {
+            AppError::DatabaseError(anyhow::anyhow!("Hold {} vanished after capture", hold_id))
+        })?;
+
+        Ok(Some((hold, journal_id, inserted_entries, currency)))
+    }
+
+    /// Release a pending hold without posting anything.
+    /// Returns None if the hold does not exist.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, hold_id = %hold_id))]
+    pub async fn release_hold(
+        &self,
+        tenant_id: Uuid,
+        hold_id: Uuid,
+    ) -> Result<Option<Hold>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["release_hold"])
+            .start_timer();
+
+        let released = sqlx::query_as::<_, Hold>(
+            r#"
+            UPDATE holds
+            SET status = 'released', resolved_utc = NOW()
+            WHERE tenant_id = $1 AND hold_id = $2 AND status = 'pending'
+            RETURNING hold_id, tenant_id, debit_account_id, credit_account_id, amount, captured_amount, currency, status, expires_utc, idempotency_key, metadata, journal_id, created_utc, resolved_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(hold_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to release hold: {}", e)))?;
+
+        timer.observe_duration();
+
+        if let Some(hold) = released {
+            info!("Hold released");
+            return Ok(Some(hold));
+        }
+
+        match self.get_hold(tenant_id, hold_id).await? {
+            Some(hold) => Err(AppError::Conflict(anyhow::anyhow!(
+                "Hold {} is {} and cannot be released",
+                hold_id,
+                hold.status
+            ))),
+            None => Ok(None),
+        }
+    }
+
+    /// Mark pending holds past their expiry as expired, across all tenants.
+    /// Expired holds stop reserving funds as soon as they lapse; this only
+    /// records the final status. Returns the number of holds expired.
+    #[instrument(skip(self))]
+    pub async fn expire_holds(&self) -> Result<u64, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["expire_holds"])
+            .start_timer();
+
+        let result = sqlx::query(
+            r#"
+            UPDATE holds
+            SET status = 'expired', resolved_utc = expires_utc
+            WHERE status = 'pending' AND expires_utc <= NOW()
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to expire holds: {}", e)))?;
+
+        timer.observe_duration();
+
+        if result.rows_affected() > 0 {
+            info!(count = result.rows_affected(), "Holds expired");
+        }
+
+        Ok(result.rows_affected())
+    }
+
+    // -------------------------------------------------------------------------
+    // Balance Operations
+    // -------------------------------------------------------------------------
+
+    /// Get balance for an account as of a specific date.
+    /// P2: Balance calculation considers account type:
+    /// - Asset/Expense (debit-normal): balance = debits - credits (positive = normal)
+    /// - Liability/Equity/Revenue (credit-normal): balance = credits - debits (positive = normal)
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
+    pub async fn get_balance(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+        as_of_date: Option<NaiveDate>,
+    ) -> Result<Option<(Decimal, String)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_balance"])
+            .start_timer();
+
+        // First get the account to verify it exists and get currency/type
+        let account = self.get_account(tenant_id, account_id).await?;
+        let account = match account {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+
+        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        // Raw balance (debits - credits) from the nearest snapshot
+        let raw = self.snapshot_balance(tenant_id, account_id, as_of).await?;
+
+        // P2: Adjust sign based on account type
+        // For credit-normal accounts, negate to show positive balance
+        let account_type = AccountType::from_string(&account.account_type);
+        let is_debit_normal = matches!(account_type, AccountType::Asset | AccountType::Expense);
+        let balance = if is_debit_normal { raw } else { -raw };
+
+        timer.observe_duration();
+
+        Ok(Some((balance, account.currency)))
+    }
+
+    /// Get the roll-up balance of an account and all of its descendants as of a date.
+    /// Descendants share the account's type and currency, so the balance is signed
+    /// to the account's normal side just like get_balance.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
+    pub async fn get_rollup_balance(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+        as_of_date: Option<NaiveDate>,
+    ) -> Result<Option<(Decimal, String)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_rollup_balance"])
+            .start_timer();
+
+        let account = self.get_account(tenant_id, account_id).await?;
+        let account = match account {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+
+        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        let raw_balance: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT account_id FROM accounts WHERE tenant_id = $1 AND account_id = $2
+                UNION ALL
+                SELECT a.account_id
+                FROM accounts a
+                JOIN subtree s ON a.parent_account_id = s.account_id
+                WHERE a.tenant_id = $1
+            )
+            SELECT COALESCE(SUM(latest.balance), 0)
+            FROM subtree
+            CROSS JOIN LATERAL (
+                SELECT balance
+                FROM account_balance_snapshots
+                WHERE tenant_id = $1
+                  AND account_id = subtree.account_id
+                  AND snapshot_date <= $3
+                ORDER BY snapshot_date DESC
+                LIMIT 1
+            ) latest
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(as_of)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get roll-up balance: {}", e))
+        })?;
+
+        let raw = raw_balance.unwrap_or(Decimal::ZERO);
+
+        let account_type = AccountType::from_string(&account.account_type);
+        let is_debit_normal = matches!(account_type, AccountType::Asset | AccountType::Expense);
+        let balance = if is_debit_normal { raw } else { -raw };
+
+        timer.observe_duration();
+
+        Ok(Some((balance, account.currency)))
+    }
+
+    /// Get balances for multiple accounts.
+    /// With `include_descendants`, each balance is the roll-up of the account's subtree.
+    #[instrument(skip(self, account_ids), fields(tenant_id = %tenant_id, account_count = account_ids.len()))]
+    pub async fn get_balances(
+        &self,
+        tenant_id: Uuid,
+        account_ids: &[Uuid],
+        as_of_date: Option<NaiveDate>,
+        include_descendants: bool,
+    ) -> Result<Vec<(Uuid, Decimal, String)>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_balances"])
+            .start_timer();
+
+        let as_of = as_of_date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        // Get all accounts first to verify they exist and get currencies
+        let mut results = Vec::with_capacity(account_ids.len());
+
+        for account_id in account_ids {
+            let result = if include_descendants {
+                self.get_rollup_balance(tenant_id, *account_id, Some(as_of))
+                    .await?
+            } else {
+                self.get_balance(tenant_id, *account_id, Some(as_of))
+                    .await?
+            };
+            if let Some((balance, currency)) = result {
+                results.push((*account_id, balance, currency));
+            }
+        }
+
+        timer.observe_duration();
+
+        Ok(results)
+    }
+
+    // -------------------------------------------------------------------------
+    // Balance Snapshot Operations
+    // -------------------------------------------------------------------------
+
+    /// Raw (debit - credit) balance of an account at the end of `as_of`, read from
+    /// the latest balance snapshot on or before that date.
+    async fn snapshot_balance(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+        as_of: NaiveDate,
+    ) -> Result<Decimal, AppError> {
+        let balance: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            SELECT balance
+            FROM account_balance_snapshots
+            WHERE tenant_id = $1
+              AND account_id = $2
+              AND snapshot_date <= $3
+            ORDER BY snapshot_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(as_of)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to read balance snapshot: {}", e))
+        })?;
+
+        Ok(balance.unwrap_or(Decimal::ZERO))
+    }
+
+    /// Take the per-account snapshot locks in account_id order, so journals touching
+    /// the same accounts queue up instead of deadlocking in the snapshot trigger.
+    async fn lock_account_balances(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        account_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        let mut ids = account_ids.to_vec();
+        ids.sort();
+        ids.dedup();
+
+        for account_id in ids {
+            sqlx::query("SELECT lock_account_balance($1)")
+                .bind(account_id)
+                .execute(&mut **tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!(
+                        "Failed to lock account balance: {}",
+                        e
+                    ))
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Compare balance snapshots with balances recomputed from the raw entries,
+    /// for one account or every account of the tenant. With `rebuild`, the
+    /// snapshots are replaced by the recomputed ones. Accounts are checked in
+    /// batches, each in its own transaction holding only that batch's locks.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn verify_balance_snapshots(
+        &self,
+        tenant_id: Uuid,
+        account_id: Option<Uuid>,
+        rebuild: bool,
+    ) -> Result<SnapshotVerification, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["verify_balance_snapshots"])
+            .start_timer();
+
+        let account_ids: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT account_id
+            FROM accounts
+            WHERE tenant_id = $1 AND ($2::uuid IS NULL OR account_id = $2)
+            ORDER BY account_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list accounts: {}", e)))?;
+
+        if account_id.is_some() && account_ids.is_empty() {
+            return Err(AppError::NotFound(anyhow::anyhow!(
+                "Account {} not found",
+                account_id.unwrap_or_default()
+            )));
+        }
+
+        let mut snapshots_checked = 0;
+        let mut mismatches = Vec::new();
+        for batch in account_ids.chunks(SNAPSHOT_VERIFY_BATCH_SIZE) {
+            let (checked, batch_mismatches) = self
+                .verify_snapshot_batch(tenant_id, batch, rebuild)
+                .await?;
+            snapshots_checked += checked;
+            mismatches.extend(batch_mismatches);
+        }
+
+        timer.observe_duration();
+
+        if !mismatches.is_empty() {
+            warn!(
+                mismatch_count = mismatches.len(),
+                rebuilt = rebuild,
+                "Balance snapshots disagree with ledger entries"
+            );
+        }
+
+        Ok(SnapshotVerification {
+            accounts_checked: account_ids.len() as i64,
+            snapshots_checked,
+            mismatches,
+            rebuilt: rebuild,
+        })
+    }
+
+    /// Verify (and optionally rebuild) the snapshots of one batch of accounts.
+    /// Returns the number of snapshots checked and the mismatches found.
+    async fn verify_snapshot_batch(
+        &self,
+        tenant_id: Uuid,
+        account_ids: &[Uuid],
+        rebuild: bool,
+    ) -> Result<(i64, Vec<SnapshotMismatch>), AppError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Hold off postings to these accounts while comparing and rewriting
+        Self::lock_account_balances(&mut tx, account_ids).await?;
+
+        let expected_daily = r#"
+            SELECT
+                tenant_id,
+                account_id,
+                effective_date AS snapshot_date,
+                SUM(net) OVER (PARTITION BY account_id ORDER BY effective_date) AS balance
+            FROM (
+                SELECT
+                    tenant_id,
+                    account_id,
+                    effective_date,
+                    SUM(CASE WHEN direction = 'debit' THEN amount ELSE -amount END) AS net
+                FROM ledger_entries
+                WHERE tenant_id = $1 AND account_id = ANY($2) AND amount <> 0
+                GROUP BY tenant_id, account_id, effective_date
+            ) daily
+        "#;
+
+        let snapshots_checked: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM ({}) expected",
+            expected_daily
+        ))
+        .bind(tenant_id)
+        .bind(account_ids)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to count snapshots: {}", e))
+        })?;
+
+        let mismatches = sqlx::query_as::<_, SnapshotMismatch>(&format!(
+            r#"
+            WITH expected AS ({}),
+            stored AS (
+                SELECT account_id, snapshot_date, balance
+                FROM account_balance_snapshots
+                WHERE tenant_id = $1 AND account_id = ANY($2)
+            )
+            SELECT
+                COALESCE(s.account_id, e.account_id) AS account_id,
+                COALESCE(s.snapshot_date, e.snapshot_date) AS snapshot_date,
+                s.balance AS snapshot_balance,
+                e.balance AS entries_balance
+            FROM stored s
+            FULL OUTER JOIN expected e
+              ON e.account_id = s.account_id AND e.snapshot_date = s.snapshot_date
+            WHERE s.balance IS DISTINCT FROM e.balance
+            ORDER BY 1, 2
+            "#,
+            expected_daily
+        ))
+        .bind(tenant_id)
+        .bind(account_ids)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to verify snapshots: {}", e))
+        })?;
+
+        if rebuild {
+            sqlx::query(
+                "DELETE FROM account_balance_snapshots WHERE tenant_id = $1 AND account_id = ANY($2)",
+            )
+            .bind(tenant_id)
+            .bind(account_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to clear snapshots: {}", e))
+            })?;
+
+            sqlx::query(&format!(
+                r#"
+                INSERT INTO account_balance_snapshots (tenant_id, account_id, snapshot_date, balance)
+                SELECT tenant_id, account_id, snapshot_date, balance FROM ({}) expected
+                "#,
+                expected_daily
+            ))
+            .bind(tenant_id)
+            .bind(account_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to rebuild snapshots: {}", e))
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok((snapshots_checked, mismatches))
+    }
+
+    // -------------------------------------------------------------------------
+    // Statement Operations
+    // -------------------------------------------------------------------------
+
+    /// Get account statement with running balance for a date range.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, account_id = %account_id))]
+    pub async fn get_statement(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Option<StatementData>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_statement"])
+            .start_timer();
+
+        // Get account
+        let account = self.get_account(tenant_id, account_id).await?;
+        let account = match account {
+            Some(a) => a,
+            None => return Ok(None),
+        };
+
+        // Calculate opening balance (balance as of day before start_date)
+        let opening_date = start_date.pred_opt().unwrap_or(start_date);
+        let opening_balance = self
+            .snapshot_balance(tenant_id, account_id, opening_date)
+            .await?;
+
+        // Get entries in date range
+        let entries = sqlx::query_as::<_, LedgerEntry>(
+            r#"
+            SELECT entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate
+            FROM ledger_entries
+            WHERE tenant_id = $1
+              AND account_id = $2
+              AND effective_date >= $3
+              AND effective_date <= $4
+            ORDER BY effective_date, posted_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get statement entries: {}", e))
+        })?;
+
+        // Calculate closing balance
+        let mut closing_balance = opening_balance;
+        for entry in &entries {
+            match entry.direction.as_str() {
+                "debit" => closing_balance += entry.amount,
+                "credit" => closing_balance -= entry.amount,
+                _ => {}
+            }
+        }
+
+        timer.observe_duration();
+
+        Ok(Some((
+            account.currency,
+            opening_balance,
+            closing_balance,
+            entries,
+        )))
+    }
+
+    // -------------------------------------------------------------------------
+    // Report Operations
+    // -------------------------------------------------------------------------
+
+    /// Get debit/credit totals of every account with entries up to `as_of_date`.
+    /// Used for the trial balance and balance sheet.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_balance_snapshot(
+        &self,
+        tenant_id: Uuid,
+        as_of_date: NaiveDate,
+    ) -> Result<ReportSnapshot, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_balance_snapshot"])
+            .start_timer();
+
+        let all_types = [
+            AccountType::Asset,
+            AccountType::Liability,
+            AccountType::Equity,
+            AccountType::Revenue,
+            AccountType::Expense,
+        ];
+        let snapshot = self
+            .get_report_snapshot(tenant_id, None, as_of_date, &all_types, false)
+            .await?;
+
+        timer.observe_duration();
+
+        Ok(snapshot)
+    }
+
+    /// Get revenue and expense activity between `start_date` and `end_date` (inclusive).
+    /// Period-closing journals are excluded so closed periods still report their results.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_income_snapshot(
+        &self,
+        tenant_id: Uuid,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<ReportSnapshot, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_income_snapshot"])
+            .start_timer();
+
+        let snapshot = self
+            .get_report_snapshot(
+                tenant_id,
+                Some(start_date),
+                end_date,
+                &[AccountType::Revenue, AccountType::Expense],
+                true,
+            )
+            .await?;
+
+        timer.observe_duration();
+
+        Ok(snapshot)
+    }
+
+    /// Aggregate account activity inside a read-only repeatable-read transaction, so the
+    /// returned totals and snapshot time describe the same committed state of the ledger.
+    async fn get_report_snapshot(
+        &self,
+        tenant_id: Uuid,
+        start_date: Option<NaiveDate>,
+        end_date: NaiveDate,
+        account_types: &[AccountType],
+        exclude_period_close: bool,
+    ) -> Result<ReportSnapshot, AppError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to set isolation level: {}", e))
+            })?;
+
+        let snapshot_utc: DateTime<Utc> = sqlx::query_scalar("SELECT NOW()")
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to read snapshot time: {}", e))
+            })?;
+
+        // With a base currency every account is reported at its base value in one
+        // section; without one, journals are single-currency and each currency balances
+        let base_currency: Option<String> = sqlx::query_scalar(
+            "SELECT base_currency FROM tenant_base_currencies WHERE tenant_id = $1",
+        )
+        .bind(tenant_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get base currency: {}", e))
+        })?;
+
+        let types: Vec<&str> = account_types.iter().map(|t| t.as_str()).collect();
+
+        let accounts = sqlx::query_as::<_, AccountActivity>(
+            r#"
+            SELECT a.account_id, a.account_code, a.account_type, COALESCE($6, a.currency) AS currency,
+                   COALESCE(SUM(CASE WHEN $6::text IS NULL THEN e.amount ELSE e.base_amount END) FILTER (WHERE e.direction = 'debit'), 0) AS debit_total,
+                   COALESCE(SUM(CASE WHEN $6::text IS NULL THEN e.amount ELSE e.base_amount END) FILTER (WHERE e.direction = 'credit'), 0) AS credit_total
+            FROM accounts a
+            JOIN ledger_entries e ON e.account_id = a.account_id AND e.tenant_id = a.tenant_id
+            WHERE a.tenant_id = $1
+              AND ($2::date IS NULL OR e.effective_date >= $2)
+              AND e.effective_date <= $3
+              AND a.account_type = ANY($4)
+              AND (NOT $5 OR e.closes_period_id IS NULL)
+            GROUP BY a.account_id, a.account_code, a.account_type, a.currency
+            ORDER BY COALESCE($6, a.currency),
+                     CASE a.account_type
+                         WHEN 'asset' THEN 1
+                         WHEN 'liability' THEN 2
+                         WHEN 'equity' THEN 3
+                         WHEN 'revenue' THEN 4
+                         ELSE 5
+                     END,
+                     a.account_code
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(&types)
+        .bind(exclude_period_close)
+        .bind(base_currency)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to aggregate account activity: {}",
+                e
+            ))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        Ok(ReportSnapshot {
+            snapshot_utc,
+            accounts,
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // Base Currency Operations
+    // -------------------------------------------------------------------------
+
+    /// Get the tenant's base currency, if one has been set.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_base_currency(&self, tenant_id: Uuid) -> Result<Option<String>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_base_currency"])
+            .start_timer();
+
+        let base_currency: Option<String> = sqlx::query_scalar(
+            "SELECT base_currency FROM tenant_base_currencies WHERE tenant_id = $1",
+        )
+        .bind(tenant_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get base currency: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(base_currency)
+    }
+
+    /// Set the tenant's base currency and value every entry posted so far in it,
+    /// at the rate of its effective date. The base currency cannot be changed
+    /// once set; setting the same one again converts nothing.
+    /// Returns the number of entries converted.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, base_currency = %base_currency))]
+    pub async fn set_base_currency(
+        &self,
+        tenant_id: Uuid,
+        base_currency: &str,
+    ) -> Result<u64, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["set_base_currency"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Postings hold this lock shared until they commit, so every entry committed
+        // before it is converted below and every later one sees the base currency
+        sqlx::query(
+            "SELECT pg_advisory_xact_lock(hashtextextended('base_currency:' || $1::text, 0))",
+        )
+        .bind(tenant_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to lock base currency: {}", e))
+        })?;
+
+        let existing: Option<String> = sqlx::query_scalar(
+            "SELECT base_currency FROM tenant_base_currencies WHERE tenant_id = $1",
+        )
+        .bind(tenant_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get base currency: {}", e))
+        })?;
+
+        match existing.as_deref() {
+            Some(existing) if existing == base_currency => {
+                timer.observe_duration();
+                return Ok(0);
+            }
+            Some(existing) => {
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Tenant base currency is already {} and cannot be changed",
+                    existing
+                )));
+            }
+            None => {}
+        }
+
+        // Cross-currency journals posted before base currencies were per tenant
+        let other_base: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT base_currency
+            FROM ledger_entries
+            WHERE tenant_id = $1 AND base_currency IS NOT NULL AND base_currency <> $2
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(base_currency)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!(
+                "Failed to check entry base currencies: {}",
+                e
+            ))
+        })?;
+
+        if let Some(other_base) = other_base {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Entries were already posted with base currency {}",
+                other_base
+            )));
+        }
+
+        // (entry_id, journal_id, direction, amount, effective_date, account currency)
+        let unvalued: Vec<(Uuid, Uuid, Direction, Decimal, NaiveDate, String)> = sqlx::query_as(
+            r#"
+            SELECT e.entry_id, e.journal_id, e.direction, e.amount, e.effective_date, a.currency
+            FROM ledger_entries e
+            JOIN accounts a ON a.account_id = e.account_id
+            WHERE e.tenant_id = $1 AND e.base_amount IS NULL
+            ORDER BY e.journal_id, e.entry_id
+            "#,
+        )
+        .bind(tenant_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get unvalued entries: {}", e))
+        })?;
+
+        let mut rates: std::collections::HashMap<(String, NaiveDate), Decimal> =
+            std::collections::HashMap::new();
+        let mut entry_ids = Vec::with_capacity(unvalued.len());
+        let mut base_amounts = Vec::with_capacity(unvalued.len());
+        let mut exchange_rates = Vec::with_capacity(unvalued.len());
+
+        for journal in unvalued.chunk_by(|a, b| a.1 == b.1) {
+            let mut rated = Vec::with_capacity(journal.len());
+            for (_, _, direction, amount, effective_date, currency) in journal {
+                let rate = if currency == base_currency {
+                    Decimal::ONE
+                } else if let Some(rate) = rates.get(&(currency.clone(), *effective_date)) {
+                    *rate
+                } else {
+                    let rate = self
+                        .find_exchange_rate(tenant_id, currency, base_currency, *effective_date)
+                        .await?
+                        .ok_or_else(|| {
+                            AppError::BadRequest(anyhow::anyhow!(
+                                "No exchange rate from {} to {} on or before {} for existing entries",
+                                currency,
+                                base_currency,
+                                effective_date
+                            ))
+                        })?;
+                    rates.insert((currency.clone(), *effective_date), rate);
+                    rate
+                };
+                rated.push((*direction, *amount, rate));
+            }
+
+            let journal_id = journal[0].1;
+            let converted = convert_to_base(&rated).ok_or_else(|| {
+                AppError::BadRequest(anyhow::anyhow!(
+                    "Journal {} does not balance in {}",
+                    journal_id,
+                    base_currency
+                ))
+            })?;
+            for ((entry, (_, _, rate)), base_amount) in journal.iter().zip(&rated).zip(converted) {
+                entry_ids.push(entry.0);
+                base_amounts.push(base_amount);
+                exchange_rates.push(*rate);
+            }
+        }
+
+        let converted = sqlx::query(
+            r#"
+            UPDATE ledger_entries e
+            SET base_currency = $2, base_amount = v.base_amount, exchange_rate = v.exchange_rate
+            FROM UNNEST($3::uuid[], $4::numeric[], $5::numeric[]) AS v(entry_id, base_amount, exchange_rate)
+            WHERE e.tenant_id = $1 AND e.entry_id = v.entry_id
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(base_currency)
+        .bind(&entry_ids)
+        .bind(&base_amounts)
+        .bind(&exchange_rates)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to convert entries: {}", e))
+        })?
+        .rows_affected();
+
+        sqlx::query(
+            "INSERT INTO tenant_base_currencies (tenant_id, base_currency) VALUES ($1, $2)",
+        )
+        .bind(tenant_id)
+        .bind(base_currency)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to set base currency: {}", e))
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(entries_converted = converted, "Tenant base currency set");
+
+        Ok(converted)
+    }
+
+    /// Take the tenant's base-currency lock shared and read the base currency.
+    /// Anything writing entries holds it until commit, so it either commits before
+    /// SetBaseCurrency converts the existing entries or sees the new base currency.
+    async fn lock_base_currency(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+    ) -> Result<Option<String>, AppError> {
+        sqlx::query(
+            "SELECT pg_advisory_xact_lock_shared(hashtextextended('base_currency:' || $1::text, 0))",
+        )
+        .bind(tenant_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to lock base currency: {}", e))
+        })?;
+
+        sqlx::query_scalar("SELECT base_currency FROM tenant_base_currencies WHERE tenant_id = $1")
+            .bind(tenant_id)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| {
+                AppError::DatabaseError(anyhow::anyhow!("Failed to get base currency: {}", e))
+            })
+    }
+
+    // -------------------------------------------------------------------------
+    // Exchange Rate Operations
+    // -------------------------------------------------------------------------
+
+    /// Create or replace the exchange rate of a currency pair on a date.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, from = %input.from_currency, to = %input.to_currency))]
+    pub async fn upsert_exchange_rate(
+        &self,
+        input: &UpsertExchangeRate,
+    ) -> Result<ExchangeRate, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["upsert_exchange_rate"])
+            .start_timer();
+
+        let rate = sqlx::query_as::<_, ExchangeRate>(
+            r#"
+            INSERT INTO exchange_rates (tenant_id, from_currency, to_currency, rate_date, rate)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (tenant_id, from_currency, to_currency, rate_date)
+            DO UPDATE SET rate = EXCLUDED.rate, updated_utc = NOW()
+            RETURNING tenant_id, from_currency, to_currency, rate_date, rate, created_utc, updated_utc
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(&input.from_currency)
+        .bind(&input.to_currency)
+        .bind(input.rate_date)
+        .bind(input.rate)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to upsert exchange rate: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(rate_date = %rate.rate_date, rate = %rate.rate, "Exchange rate saved");
+
+        Ok(rate)
+    }
+
+    /// Get the exchange rate of a currency pair in effect on a date,
+    /// i.e. the latest rate dated on or before `as_of_date`.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn get_exchange_rate(
+        &self,
+        tenant_id: Uuid,
+        from_currency: &str,
+        to_currency: &str,
+        as_of_date: NaiveDate,
+    ) -> Result<Option<ExchangeRate>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["get_exchange_rate"])
+            .start_timer();
+
+        let rate = sqlx::query_as::<_, ExchangeRate>(
+            r#"
+            SELECT tenant_id, from_currency, to_currency, rate_date, rate, created_utc, updated_utc
+            FROM exchange_rates
+            WHERE tenant_id = $1
+              AND from_currency = $2
+              AND to_currency = $3
+              AND rate_date <= $4
+            ORDER BY rate_date DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(from_currency)
+        .bind(to_currency)
+        .bind(as_of_date)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get exchange rate: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        Ok(rate)
+    }
+
+    /// Get just the rate value in effect on a date.
+    async fn find_exchange_rate(
+        &self,
+        tenant_id: Uuid,
+        from_currency: &str,
+        to_currency: &str,
+        as_of_date: NaiveDate,
+    ) -> Result<Option<Decimal>, AppError> {
+        Ok(self
+            .get_exchange_rate(tenant_id, from_currency, to_currency, as_of_date)
+            .await?
+            .map(|r| r.rate))
+    }
+
+    /// Revalue active foreign-currency asset and liability accounts into the tenant
+    /// base currency at the rate in effect on `as_of_date`. A requested base currency
+    /// must match the tenant's.
+    /// The carrying base value of an account is the sum of its recorded base amounts.
+    /// Each difference to the revalued amount is posted as a base-only adjustment
+    /// against `gain_loss_account_id` in a single journal dated `as_of_date`.
+    /// Returns the base currency, the revaluations and the journal, if anything had
+    /// to be adjusted.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn revalue_accounts(
+        &self,
+        tenant_id: Uuid,
+        as_of_date: NaiveDate,
+        requested_base_currency: Option<&str>,
+        gain_loss_account_id: Uuid,
+    ) -> Result<(String, RevaluationData), AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["revalue_accounts"])
+            .start_timer();
+
+        let base_currency = self.get_base_currency(tenant_id).await?.ok_or_else(|| {
+            AppError::Conflict(anyhow::anyhow!(
+                "Tenant has no base currency (set one with SetBaseCurrency)"
+            ))
+        })?;
+        let base_currency = base_currency.as_str();
+        if let Some(requested) = requested_base_currency {
+            if requested != base_currency {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "base_currency {} does not match the tenant base currency {}",
+                    requested,
+                    base_currency
+                )));
+            }
+        }
+
+        let gain_loss_account = self
+            .get_account(tenant_id, gain_loss_account_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest(anyhow::anyhow!(
+                    "Gain/loss account {} does not exist or does not belong to tenant",
+                    gain_loss_account_id
+                ))
+            })?;
+
+        if gain_loss_account.currency != base_currency {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Gain/loss account {} has currency {} but base currency is {}",
+                gain_loss_account_id,
+                gain_loss_account.currency,
+                base_currency
+            )));
+        }
+
+        let is_header: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM accounts WHERE tenant_id = $1 AND parent_account_id = $2)",
+        )
+        .bind(tenant_id)
+        .bind(gain_loss_account_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to check header accounts: {}", e))
+        })?;
+
+        if is_header {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Account {} is a header account with sub-accounts and cannot be posted to",
+                gain_loss_account_id
+            )));
+        }
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        Self::ensure_period_open(&mut tx, tenant_id, as_of_date).await?;
+        Self::ensure_accounts_active(&mut tx, tenant_id, &[gain_loss_account_id]).await?;
+
+        // (account_id, currency, raw balance, raw carrying base value)
+        let positions: Vec<(Uuid, String, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT a.account_id, a.currency,
+                   SUM(CASE WHEN e.direction = 'debit' THEN e.amount ELSE -e.amount END),
+                   SUM(CASE WHEN e.direction = 'debit' THEN e.base_amount ELSE -e.base_amount END)
+            FROM accounts a
+            JOIN ledger_entries e ON e.account_id = a.account_id AND e.tenant_id = a.tenant_id
+            WHERE a.tenant_id = $1
+              AND a.currency <> $3
+              AND a.account_type IN ('asset', 'liability')
+              AND a.status = 'active'
+              AND e.effective_date <= $2
+            GROUP BY a.account_id, a.currency, a.account_code
+            ORDER BY a.account_code
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(as_of_date)
+        .bind(base_currency)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get foreign positions: {}", e))
+        })?;
+
+        let mut revaluations = Vec::with_capacity(positions.len());
+        for (account_id, currency, balance, carrying_base_amount) in positions {
+            let rate = self
+                .find_exchange_rate(tenant_id, &currency, base_currency, as_of_date)
+                .await?
+                .ok_or_else(|| {
+                    AppError::BadRequest(anyhow::anyhow!(
+                        "No exchange rate from {} to {} on or before {}",
+                        currency,
+                        base_currency,
+                        as_of_date
+                    ))
+                })?;
+            revaluations.push(AccountRevaluation {
+                account_id,
+                currency,
+                balance,
+                rate,
+                carrying_base_amount,
+                revalued_base_amount: (balance * rate).round_dp(4),
+            });
+        }
+
+        let metadata = serde_json::json!({
+            "source": "fx_revaluation",
+            "as_of_date": as_of_date.to_string(),
+        });
+        let journal_id = Uuid::new_v4();
+        let mut inserted_entries = Vec::new();
+        let mut net = Decimal::ZERO;
+
+        // Foreign-currency side: base-only adjustment with a zero amount
+        for revaluation in &revaluations {
+            let adjustment = revaluation.adjustment();
+            if adjustment.is_zero() {
+                continue;
+            }
+            net += adjustment;
+            let inserted = Self::insert_base_entry(
+                &mut tx,
+                tenant_id,
+                journal_id,
+                revaluation.account_id,
+                Decimal::ZERO,
+                adjustment,
+                base_currency,
+                revaluation.rate,
+                as_of_date,
+                &metadata,
+            )
+            .await?;
+            inserted_entries.push(inserted);
+        }
+
+        // Net gain is credited, net loss debited to the gain/loss account
+        if !net.is_zero() {
+            let inserted = Self::insert_base_entry(
+                &mut tx,
+                tenant_id,
+                journal_id,
+                gain_loss_account_id,
+                net.abs(),
+                -net,
+                base_currency,
+                Decimal::ONE,
+                as_of_date,
+                &metadata,
+            )
+            .await?;
+            inserted_entries.push(inserted);
+        }
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        let journal = if inserted_entries.is_empty() {
+            None
+        } else {
+            info!(
+                journal_id = %journal_id,
+                account_count = revaluations.len(),
+                net_adjustment = %net,
+                "Foreign-currency accounts revalued"
+            );
+            Some((journal_id, inserted_entries))
+        };
+
+        Ok((base_currency.to_string(), (revaluations, journal)))
+    }
+
+    /// Insert one entry of a base-currency journal. `signed_base_amount` is positive
+    /// for a debit and negative for a credit.
+    #[allow(clippy::too_many_arguments)]
+    async fn insert_base_entry(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        journal_id: Uuid,
+        account_id: Uuid,
+        amount: Decimal,
+        signed_base_amount: Decimal,
+        base_currency: &str,
+        exchange_rate: Decimal,
+        effective_date: NaiveDate,
+        metadata: &serde_json::Value,
+    ) -> Result<LedgerEntry, AppError> {
+        let direction = if signed_base_amount > Decimal::ZERO {
+            Direction::Debit
+        } else {
+            Direction::Credit
+        };
+
+        sqlx::query_as::<_, LedgerEntry>(
+            r#"
+            INSERT INTO ledger_entries (entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, metadata, base_currency, base_amount, exchange_rate)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(tenant_id)
+        .bind(journal_id)
+        .bind(account_id)
+        .bind(amount)
+        .bind(direction.as_str())
+        .bind(effective_date)
+        .bind(metadata)
+        .bind(base_currency)
+        .bind(signed_base_amount.abs())
+        .bind(exchange_rate)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to insert revaluation entry: {}", e))
+        })
+    }
+
+    /// Reject postings to frozen or closed accounts. The account rows are locked
+    /// FOR SHARE so a concurrent status change waits for this journal.
+    async fn ensure_accounts_active(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        account_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        let inactive: Option<(Uuid, String)> = sqlx::query_as(
+            r#"
+            SELECT account_id, status
+            FROM accounts
+            WHERE tenant_id = $1 AND account_id = ANY($2)
+            ORDER BY account_id
+            FOR SHARE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(account_ids)
+        .fetch_all(&mut **tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to lock accounts: {}", e)))?
+        .into_iter()
+        .find(|(_, status)| status != AccountStatus::Active.as_str());
+
+        if let Some((account_id, status)) = inactive {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Account {} is {} and cannot be posted to",
+                account_id,
+                status
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Reject postings dated inside a closed period. The covering period row is locked
+    /// FOR SHARE so a concurrent close waits for this journal (or this journal sees the close).
+    async fn ensure_period_open(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        tenant_id: Uuid,
+        effective_date: NaiveDate,
+    ) -> Result<(), AppError> {
+        let period: Option<(String, String)> = sqlx::query_as(
+            r#"
+            SELECT name, status
+            FROM accounting_periods
+            WHERE tenant_id = $1 AND start_date <= $2 AND end_date >= $2
+            FOR SHARE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(effective_date)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to check period: {}", e)))?;
+
+        if let Some((name, status)) = period {
+            if status == PeriodStatus::Closed.as_str() {
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Accounting period '{}' is closed; cannot post with effective_date {}",
+                    name,
+                    effective_date
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // -------------------------------------------------------------------------
+    // Period Operations
+    // -------------------------------------------------------------------------
+
+    /// Open a new accounting period. Periods of a tenant must not overlap; an
+    /// exclusion constraint backs the check against concurrent opens.
+    #[instrument(skip(self, input), fields(tenant_id = %input.tenant_id, name = %input.name))]
+    pub async fn open_period(&self, input: &OpenPeriod) -> Result<AccountingPeriod, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["open_period"])
+            .start_timer();
+
+        if let Some(account_id) = input.retained_earnings_account_id {
+            self.get_retained_earnings_account(input.tenant_id, account_id)
+                .await?;
+        }
+
+        let overlapping: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT name
+            FROM accounting_periods
+            WHERE tenant_id = $1 AND start_date <= $3 AND end_date >= $2
+            LIMIT 1
+            "#,
+        )
+        .bind(input.tenant_id)
+        .bind(input.start_date)
+        .bind(input.end_date)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to check periods: {}", e)))?;
+
+        if let Some(name) = overlapping {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Period overlaps existing accounting period '{}'",
+                name
+            )));
+        }
+
+        let period = sqlx::query_as::<_, AccountingPeriod>(
+            r#"
+            INSERT INTO accounting_periods (period_id, tenant_id, name, start_date, end_date, retained_earnings_account_id)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING period_id, tenant_id, name, start_date, end_date, status, retained_earnings_account_id, closing_journal_id, created_utc, closed_utc
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(input.tenant_id)
+        .bind(&input.name)
+        .bind(input.start_date)
+        .bind(input.end_date)
+        .bind(input.retained_earnings_account_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                AppError::Conflict(anyhow::anyhow!(
+                    "An accounting period starting {} already exists",
+                    input.start_date
+                ))
+            }
+            // A concurrently opened period overlapping this one won the race
+            sqlx::Error::Database(ref db_err) if db_err.code().as_deref() == Some("23P01") => {
+                AppError::Conflict(anyhow::anyhow!(
+                    "Period overlaps an existing accounting period"
+                ))
+            }
+            _ => AppError::DatabaseError(anyhow::anyhow!("Failed to open period: {}", e)),
+        })?;
+
+        timer.observe_duration();
+
+        info!(period_id = %period.period_id, "Accounting period opened");
+
+        Ok(period)
+    }
+
+    /// List accounting periods for a tenant, oldest first.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id))]
+    pub async fn list_periods(
+        &self,
+        tenant_id: Uuid,
+        status: Option<PeriodStatus>,
+    ) -> Result<Vec<AccountingPeriod>, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["list_periods"])
+            .start_timer();
+
+        let periods = sqlx::query_as::<_, AccountingPeriod>(
+            r#"
+            SELECT period_id, tenant_id, name, start_date, end_date, status, retained_earnings_account_id, closing_journal_id, created_utc, closed_utc
+            FROM accounting_periods
+            WHERE tenant_id = $1
+              AND ($2::varchar IS NULL OR status = $2)
+            ORDER BY start_date
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(status.map(|s| s.as_str()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to list periods: {}", e)))?;
+
+        timer.observe_duration();
+
+        Ok(periods)
+    }
+
+    /// Close an accounting period.
+    /// Revenue and expense balances up to the period end are rolled into the retained
+    /// earnings account by a closing journal dated on the last day of the period.
+    /// Closing again after a reopen only rolls what was posted since the last close.
+    /// Periods close in date order; an open earlier period rejects the close.
+    /// Once the tenant has a base currency, accounts in every currency are closed at
+    /// their base value into a retained earnings account in that currency.
+    /// Returns the closed period and the closing journal, if anything was rolled.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, period_id = %period_id))]
+    pub async fn close_period(
+        &self,
+        tenant_id: Uuid,
+        period_id: Uuid,
+        retained_earnings_account_id: Option<Uuid>,
+    ) -> Result<PeriodCloseData, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["close_period"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        // Lock the period so postings into it wait for the close to finish
+        let period = sqlx::query_as::<_, AccountingPeriod>(
+            r#"
+            SELECT period_id, tenant_id, name, start_date, end_date, status, retained_earnings_account_id, closing_journal_id, created_utc, closed_utc
+            FROM accounting_periods
+            WHERE tenant_id = $1 AND period_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(period_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get period: {}", e)))?
+        .ok_or_else(|| AppError::NotFound(anyhow::anyhow!("Accounting period not found")))?;
+
+        if period.is_closed() {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Accounting period '{}' is already closed",
+                period.name
+            )));
+        }
+
+        // Closing sweeps everything up to the period end, so earlier periods must be
+        // closed first or their activity would be swept again when they close
+        let earlier_open: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT name
+            FROM accounting_periods
+            WHERE tenant_id = $1 AND start_date < $2 AND status = 'open'
+            ORDER BY start_date
+            LIMIT 1
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(period.start_date)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to check earlier periods: {}", e))
+        })?;
+
+        if let Some(name) = earlier_open {
+            return Err(AppError::Conflict(anyhow::anyhow!(
+                "Earlier accounting period '{}' is still open and must be closed first",
+                name
+            )));
+        }
+
+        let re_account_id = retained_earnings_account_id
+            .or(period.retained_earnings_account_id)
+            .ok_or_else(|| {
+                AppError::Conflict(anyhow::anyhow!(
+                    "No retained earnings account configured for period '{}'",
+                    period.name
+                ))
+            })?;
+        let re_account = self
+            .get_retained_earnings_account(tenant_id, re_account_id)
+            .await?;
+
+        // With a base currency, accounts in any currency are closed at their base value
+        let base_currency = Self::lock_base_currency(&mut tx, tenant_id).await?;
+        if let Some(base) = base_currency.as_deref() {
+            if re_account.currency != base {
+                return Err(AppError::BadRequest(anyhow::anyhow!(
+                    "Retained earnings account {} has currency {} but the tenant base currency is {}",
+                    re_account_id,
+                    re_account.currency,
+                    base
+                )));
+            }
+        }
+
+        // Raw (debit - credit) balance of every revenue/expense account at period end,
+        // in the account currency and in the base currency
+        let balances: Vec<(Uuid, String, Decimal, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT a.account_id, a.currency,
+                   SUM(CASE WHEN e.direction = 'debit' THEN e.amount ELSE -e.amount END) AS raw,
+                   SUM(CASE WHEN e.direction = 'debit' THEN 1 ELSE -1 END * COALESCE(e.base_amount, e.amount)) AS base_raw
+            FROM accounts a
+            JOIN ledger_entries e ON e.account_id = a.account_id
+            WHERE a.tenant_id = $1
+              AND a.account_type IN ('revenue', 'expense')
+              AND e.effective_date <= $2
+            GROUP BY a.account_id, a.currency, a.account_code
+            HAVING SUM(CASE WHEN e.direction = 'debit' THEN e.amount ELSE -e.amount END) <> 0
+                OR SUM(CASE WHEN e.direction = 'debit' THEN 1 ELSE -1 END * COALESCE(e.base_amount, e.amount)) <> 0
+            ORDER BY a.account_code
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(period.end_date)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to get closing balances: {}", e))
+        })?;
+
+        // Closing entries as (account_id, amount, base_amount, direction)
+        let closing_direction = |raw: Decimal| {
+            if raw > Decimal::ZERO {
+                Direction::Credit
+            } else {
+                Direction::Debit
+            }
+        };
+        let mut entries: Vec<(Uuid, Decimal, Decimal, Direction)> =
+            Vec::with_capacity(balances.len() + 1);
+        let mut net = Decimal::ZERO;
+        for (account_id, currency, raw, base_raw) in balances {
+            if base_currency.is_none() && currency != re_account.currency {
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Account {} has currency {} but retained earnings account is {} (set a tenant base currency to close foreign-currency accounts)",
+                    account_id,
+                    currency,
+                    re_account.currency
+                )));
+            }
+            net += base_raw;
+            if raw.is_zero()
+                || base_raw.is_zero()
+                || raw.is_sign_positive() == base_raw.is_sign_positive()
+            {
+                let side = if raw.is_zero() { base_raw } else { raw };
+                entries.push((
+                    account_id,
+                    raw.abs(),
+                    base_raw.abs(),
+                    closing_direction(side),
+                ));
+            } else {
+                // Rate moves left the base value on the other side: zero each separately
+                entries.push((account_id, raw.abs(), Decimal::ZERO, closing_direction(raw)));
+                entries.push((
+                    account_id,
+                    Decimal::ZERO,
+                    base_raw.abs(),
+                    closing_direction(base_raw),
+                ));
+            }
+        }
+        if net != Decimal::ZERO {
+            // Net debit (loss) reduces retained earnings, net credit (profit) increases it
+            let direction = if net > Decimal::ZERO {
+                Direction::Debit
+            } else {
+                Direction::Credit
+            };
+            entries.push((re_account_id, net.abs(), net.abs(), direction));
+        }
+
+        let closing = if entries.is_empty() {
+            None
+        } else {
+            let account_ids: Vec<Uuid> =
+                entries.iter().map(|(account_id, ..)| *account_id).collect();
+            Self::lock_account_balances(&mut tx, &account_ids).await?;
+
+            let journal_id = Uuid::new_v4();
+            let metadata = serde_json::json!({
+                "source": "period_close",
+                "period_id": period_id.to_string(),
+            });
+            let mut inserted_entries = Vec::with_capacity(entries.len());
+            for (account_id, amount, base_amount, direction) in &entries {
+                let inserted = sqlx::query_as::<_, LedgerEntry>(
+                    r#"
+                    INSERT INTO ledger_entries (entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, metadata, closes_period_id, base_currency, base_amount)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                    RETURNING entry_id, tenant_id, journal_id, account_id, amount, direction, effective_date, posted_utc, idempotency_key, metadata, reverses_journal_id, base_currency, base_amount, exchange_rate
+                    "#,
+                )
+                .bind(Uuid::new_v4())
+                .bind(tenant_id)
+                .bind(journal_id)
+                .bind(account_id)
+                .bind(amount)
+                .bind(direction.as_str())
+                .bind(period.end_date)
+                .bind(&metadata)
+                .bind(period_id)
+                .bind(base_currency.as_deref())
+                .bind(base_currency.as_ref().map(|_| *base_amount))
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| {
+                    AppError::DatabaseError(anyhow::anyhow!("Failed to insert closing entry: {}", e))
+                })?;
+                inserted_entries.push(inserted);
+            }
+            Some((journal_id, inserted_entries))
+        };
+
+        let period = sqlx::query_as::<_, AccountingPeriod>(
+            r#"
+            UPDATE accounting_periods
+            SET status = 'closed',
+                closed_utc = NOW(),
+                retained_earnings_account_id = $3,
+                closing_journal_id = COALESCE($4, closing_journal_id)
+            WHERE tenant_id = $1 AND period_id = $2
+            RETURNING period_id, tenant_id, name, start_date, end_date, status, retained_earnings_account_id, closing_journal_id, created_utc, closed_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(period_id)
+        .bind(re_account_id)
+        .bind(closing.as_ref().map(|(journal_id, _)| *journal_id))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to close period: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(
+            period_id = %period_id,
+            closing_journal_id = ?closing.as_ref().map(|(journal_id, _)| *journal_id),
+            "Accounting period closed"
+        );
+
+        Ok((period, closing))
+    }
+
+    /// Reopen a closed accounting period so it accepts postings again.
+    /// The closing journal is kept; a later close rolls only the new activity.
+    #[instrument(skip(self), fields(tenant_id = %tenant_id, period_id = %period_id))]
+    pub async fn reopen_period(
+        &self,
+        tenant_id: Uuid,
+        period_id: Uuid,
+    ) -> Result<AccountingPeriod, AppError> {
+        let timer = DB_QUERY_DURATION
+            .with_label_values(&["reopen_period"])
+            .start_timer();
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to begin transaction: {}", e))
+        })?;
+
+        let status: Option<String> = sqlx::query_scalar(
+            "SELECT status FROM accounting_periods WHERE tenant_id = $1 AND period_id = $2 FOR UPDATE",
+        )
+        .bind(tenant_id)
+        .bind(period_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to get period: {}", e)))?;
+
+        match status.as_deref() {
+            None => {
+                return Err(AppError::NotFound(anyhow::anyhow!(
+                    "Accounting period not found"
+                )))
+            }
+            Some(s) if s != PeriodStatus::Closed.as_str() => {
+                return Err(AppError::Conflict(anyhow::anyhow!(
+                    "Accounting period is not closed"
+                )))
+            }
+            _ => {}
+        }
+
+        let period = sqlx::query_as::<_, AccountingPeriod>(
+            r#"
+            UPDATE accounting_periods
+            SET status = 'open', closed_utc = NULL
+            WHERE tenant_id = $1 AND period_id = $2
+            RETURNING period_id, tenant_id, name, start_date, end_date, status, retained_earnings_account_id, closing_journal_id, created_utc, closed_utc
+            "#,
+        )
+        .bind(tenant_id)
+        .bind(period_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError(anyhow::anyhow!("Failed to reopen period: {}", e)))?;
+
+        tx.commit().await.map_err(|e| {
+            AppError::DatabaseError(anyhow::anyhow!("Failed to commit transaction: {}", e))
+        })?;
+
+        timer.observe_duration();
+
+        info!(period_id = %period_id, "Accounting period reopened");
+
+        Ok(period)
+    }
+
+    /// Fetch an account and verify it can receive closing entries.
+    async fn get_retained_earnings_account(
+        &self,
+        tenant_id: Uuid,
+        account_id: Uuid,
+    ) -> Result<Account, AppError> {
+        let account = self
+            .get_account(tenant_id, account_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest(anyhow::anyhow!(
+                    "Retained earnings account {} does not exist or does not belong to tenant",
+                    account_id
+                ))
+            })?;
+
+        if account.parsed_type() != Some(AccountType::Equity) {
+            return Err(AppError::BadRequest(anyhow::anyhow!(
+                "Retained earnings account {} must be an equity account",
+                account_id
+            )));
+        }
+
+        Ok(account)
+    }
 }
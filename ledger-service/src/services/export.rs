@@ -0,0 +1,153 @@
+//! Stable CSV and JSONL layouts for exported journals.
+//!
+//! CSV has one row per entry under a fixed header; JSONL has one journal per
+//! line with its entries nested. Columns and fields are only ever appended.
+
+use crate::models::ExportedEntry;
+use chrono::SecondsFormat;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Export file layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+}
+
+impl ExportFormat {
+    /// Convert from proto enum value. Unspecified defaults to CSV.
+    pub fn from_proto(value: i32) -> Option<Self> {
+        match value {
+            0 | 1 => Some(Self::Csv),
+            2 => Some(Self::Jsonl),
+            _ => None,
+        }
+    }
+}
+
+/// CSV header line, one column per field of `csv_row`.
+pub const CSV_HEADER: &str = "journal_id,effective_date,posted_at,entry_id,account_id,account_code,direction,amount,currency,base_currency,base_amount,exchange_rate,idempotency_key,reverses_journal_id,metadata\n";
+
+/// Format a Decimal as a normalized string (remove trailing zeros).
+/// Normalizing also turns negative zero (e.g. a netted credit-normal balance) into "0".
+pub fn format_decimal(d: &Decimal) -> String {
+    d.normalize().to_string()
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Format one entry as a CSV line (including the trailing newline).
+pub fn csv_row(row: &ExportedEntry) -> String {
+    let entry = &row.entry;
+    let fields = [
+        entry.journal_id.to_string(),
+        entry.effective_date.to_string(),
+        entry
+            .posted_utc
+            .to_rfc3339_opts(SecondsFormat::Micros, true),
+        entry.entry_id.to_string(),
+        entry.account_id.to_string(),
+        row.account_code.clone(),
+        entry.direction.clone(),
+        format_decimal(&entry.amount),
+        row.currency.clone(),
+        entry.base_currency.clone().unwrap_or_default(),
+        entry
+            .base_amount
+            .map(|a| format_decimal(&a))
+            .unwrap_or_default(),
+        entry
+            .exchange_rate
+            .map(|r| format_decimal(&r))
+            .unwrap_or_default(),
+        entry.idempotency_key.clone().unwrap_or_default(),
+        entry
+            .reverses_journal_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        entry
+            .metadata
+            .as_ref()
+            .map(|m| m.to_string())
+            .unwrap_or_default(),
+    ];
+
+    let mut line = fields
+        .iter()
+        .map(|f| csv_field(f))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+#[derive(Serialize)]
+struct JournalLine<'a> {
+    journal_id: Uuid,
+    effective_date: String,
+    posted_at: String,
+    idempotency_key: Option<&'a str>,
+    reverses_journal_id: Option<Uuid>,
+    base_currency: Option<&'a str>,
+    metadata: Option<&'a serde_json::Value>,
+    entries: Vec<EntryLine<'a>>,
+}
+
+#[derive(Serialize)]
+struct EntryLine<'a> {
+    entry_id: Uuid,
+    account_id: Uuid,
+    account_code: &'a str,
+    direction: &'a str,
+    amount: String,
+    currency: &'a str,
+    base_amount: Option<String>,
+    exchange_rate: Option<String>,
+}
+
+/// Format the entries of one journal as a JSON line (including the trailing newline).
+/// Returns None for an empty journal.
+pub fn jsonl_line(journal: &[ExportedEntry]) -> Option<String> {
+    let first = &journal.first()?.entry;
+    let line = JournalLine {
+        journal_id: first.journal_id,
+        effective_date: first.effective_date.to_string(),
+        posted_at: first
+            .posted_utc
+            .to_rfc3339_opts(SecondsFormat::Micros, true),
+        // Only one entry of a journal carries the idempotency key
+        idempotency_key: journal
+            .iter()
+            .find_map(|row| row.entry.idempotency_key.as_deref()),
+        reverses_journal_id: first.reverses_journal_id,
+        base_currency: first.base_currency.as_deref(),
+        metadata: first.metadata.as_ref(),
+        entries: journal
+            .iter()
+            .map(|row| EntryLine {
+                entry_id: row.entry.entry_id,
+                account_id: row.entry.account_id,
+                account_code: &row.account_code,
+                direction: &row.entry.direction,
+                amount: format_decimal(&row.entry.amount),
+                currency: &row.currency,
+                base_amount: row.entry.base_amount.map(|a| format_decimal(&a)),
+                exchange_rate: row.entry.exchange_rate.map(|r| format_decimal(&r)),
+            })
+            .collect(),
+    };
+
+    // Serializing plain data cannot fail
+    let mut json = serde_json::to_string(&line).ok()?;
+    json.push('\n');
+    Some(json)
+}
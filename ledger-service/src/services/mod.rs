@@ -1,6 +1,7 @@
 //! Services module for ledger-service.
 
 pub mod database;
+pub mod export;
 pub mod metrics;
 
 pub use database::Database;
@@ -112,6 +112,7 @@ impl Application {
             &config.database.url,
             config.database.max_connections,
             config.database.min_connections,
+            config.idempotency.retention_secs,
         )
         .await
         .map_err(|e| {
@@ -197,7 +198,40 @@ impl Application {
             .with_state(health_state);
 
         // Build gRPC server
-        let ledger_service = LedgerServiceImpl::new(self.state.db);
+        let ledger_service =
+            LedgerServiceImpl::new(self.state.db.clone(), self.state.config.holds.clone());
+
+        // Background sweeper marking lapsed holds as expired
+        let expiry_interval = self.state.config.holds.expiry_interval_secs;
+        if expiry_interval > 0 {
+            let db = self.state.db.clone();
+            tokio::spawn(async move {
+                let mut interval =
+                    tokio::time::interval(std::time::Duration::from_secs(expiry_interval));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = db.expire_holds().await {
+                        tracing::warn!(error = %e, "Failed to expire holds");
+                    }
+                }
+            });
+        }
+
+        // Background sweeper deleting idempotency keys past their retention
+        let purge_interval = self.state.config.idempotency.purge_interval_secs;
+        if purge_interval > 0 {
+            let db = self.state.db.clone();
+            tokio::spawn(async move {
+                let mut interval =
+                    tokio::time::interval(std::time::Duration::from_secs(purge_interval));
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = db.purge_idempotency_keys().await {
+                        tracing::warn!(error = %e, "Failed to purge idempotency keys");
+                    }
+                }
+            });
+        }
 
         // gRPC health service
         let (mut health_reporter, grpc_health_service) = tonic_health::server::health_reporter();
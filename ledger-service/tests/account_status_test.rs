@@ -0,0 +1,357 @@
+//! Account Lifecycle Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountStatus as ProtoAccountStatus,
+    AccountType as ProtoAccountType, CloseAccountRequest, CreateAccountRequest,
+    Direction as ProtoDirection, FreezeAccountRequest, GetAccountRequest,
+    ListAccountStatusChangesRequest, ListAccountsRequest, PostTransactionEntry,
+    PostTransactionRequest, ReopenAccountRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+async fn create_account(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_type: ProtoAccountType,
+    account_code: &str,
+) -> String {
+    create_test_account(client, tenant_id, account_type, account_code, "USD", false)
+        .await
+        .account
+        .unwrap()
+        .account_id
+}
+
+async fn try_transfer(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    debit_account_id: &str,
+    credit_account_id: &str,
+    amount: &str,
+) -> Result<(), tonic::Status> {
+    client
+        .post_transaction(PostTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            entries: vec![
+                PostTransactionEntry {
+                    account_id: debit_account_id.to_string(),
+                    amount: amount.to_string(),
+                    direction: ProtoDirection::Debit as i32,
+                    exchange_rate: String::new(),
+                },
+                PostTransactionEntry {
+                    account_id: credit_account_id.to_string(),
+                    amount: amount.to_string(),
+                    direction: ProtoDirection::Credit as i32,
+                    exchange_rate: String::new(),
+                },
+            ],
+            effective_date: String::new(),
+            idempotency_key: String::new(),
+            metadata: String::new(),
+            base_currency: String::new(),
+        })
+        .await
+        .map(|_| ())
+}
+
+async fn freeze(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_id: &str,
+    reason: &str,
+) -> Result<ProtoAccountStatus, tonic::Status> {
+    client
+        .freeze_account(FreezeAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: "ops@example.com".to_string(),
+            reason: reason.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().account.unwrap().status())
+}
+
+async fn close(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_id: &str,
+) -> Result<ProtoAccountStatus, tonic::Status> {
+    client
+        .close_account(CloseAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: "ops@example.com".to_string(),
+            reason: "Customer offboarded".to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().account.unwrap().status())
+}
+
+async fn reopen(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_id: &str,
+) -> Result<ProtoAccountStatus, tonic::Status> {
+    client
+        .reopen_account(ReopenAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: "supervisor@example.com".to_string(),
+            reason: String::new(),
+        })
+        .await
+        .map(|r| r.into_inner().account.unwrap().status())
+}
+
+/// Frozen accounts reject postings but stay readable until reopened
+#[tokio::test]
+async fn frozen_account_rejects_postings() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let cash = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1000").await;
+    let revenue = create_account(&mut client, tenant_id, ProtoAccountType::Revenue, "4000").await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &revenue,
+        "100.00",
+        None,
+        None,
+    )
+    .await;
+
+    let status = freeze(&mut client, tenant_id, &cash, "Fraud review")
+        .await
+        .expect("Failed to freeze account");
+    assert_eq!(status, ProtoAccountStatus::Frozen);
+
+    let result = try_transfer(&mut client, tenant_id, &cash, &revenue, "10.00").await;
+    let status = result.expect_err("Posting to a frozen account should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    assert!(status.message().contains("frozen"));
+
+    let account = client
+        .get_account(GetAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: cash.clone(),
+        })
+        .await
+        .expect("Frozen account should stay readable")
+        .into_inner()
+        .account
+        .unwrap();
+    assert_eq!(account.status(), ProtoAccountStatus::Frozen);
+    assert_eq!(account.balance, "100");
+
+    let status = reopen(&mut client, tenant_id, &cash)
+        .await
+        .expect("Failed to reopen account");
+    assert_eq!(status, ProtoAccountStatus::Active);
+    try_transfer(&mut client, tenant_id, &cash, &revenue, "10.00")
+        .await
+        .expect("Posting after reopen should succeed");
+
+    let changes = client
+        .list_account_status_changes(ListAccountStatusChangesRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: cash.clone(),
+        })
+        .await
+        .expect("Failed to list status changes")
+        .into_inner()
+        .changes;
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].from_status(), ProtoAccountStatus::Active);
+    assert_eq!(changes[0].to_status(), ProtoAccountStatus::Frozen);
+    assert_eq!(changes[0].actor, "ops@example.com");
+    assert_eq!(changes[0].reason, "Fraud review");
+    assert_eq!(changes[1].to_status(), ProtoAccountStatus::Active);
+    assert_eq!(changes[1].actor, "supervisor@example.com");
+    assert!(changes[1].reason.is_empty());
+}
+
+/// Accounts can only be closed with a zero balance
+#[tokio::test]
+async fn close_requires_zero_balance() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let cash = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1000").await;
+    let wallet = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1100").await;
+    let revenue = create_account(&mut client, tenant_id, ProtoAccountType::Revenue, "4000").await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &wallet,
+        &revenue,
+        "100.00",
+        None,
+        None,
+    )
+    .await;
+
+    let status = close(&mut client, tenant_id, &wallet)
+        .await
+        .expect_err("Closing with a balance should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Sweep the balance out, then close
+    post_test_transaction(&mut client, tenant_id, &cash, &wallet, "100.00", None, None).await;
+    let status = close(&mut client, tenant_id, &wallet)
+        .await
+        .expect("Failed to close account");
+    assert_eq!(status, ProtoAccountStatus::Closed);
+
+    let account = client
+        .get_account(GetAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: wallet.clone(),
+        })
+        .await
+        .expect("Failed to get account")
+        .into_inner()
+        .account
+        .unwrap();
+    assert!(account.closed_at.is_some());
+
+    let status = try_transfer(&mut client, tenant_id, &wallet, &revenue, "5.00")
+        .await
+        .expect_err("Posting to a closed account should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Closed accounts cannot be frozen, but can be reopened
+    let status = freeze(&mut client, tenant_id, &wallet, "")
+        .await
+        .expect_err("Freezing a closed account should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    reopen(&mut client, tenant_id, &wallet)
+        .await
+        .expect("Failed to reopen closed account");
+    try_transfer(&mut client, tenant_id, &wallet, &revenue, "5.00")
+        .await
+        .expect("Posting after reopen should succeed");
+}
+
+/// Header accounts close only after their sub-accounts
+#[tokio::test]
+async fn close_header_requires_closed_children() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let assets = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1000").await;
+    let bank = client
+        .create_account(CreateAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_type: ProtoAccountType::Asset as i32,
+            account_code: "1100".to_string(),
+            currency: "USD".to_string(),
+            allow_negative: false,
+            metadata: String::new(),
+            parent_account_id: assets.clone(),
+        })
+        .await
+        .expect("Failed to create sub-account")
+        .into_inner()
+        .account
+        .unwrap()
+        .account_id;
+
+    let status = close(&mut client, tenant_id, &assets)
+        .await
+        .expect_err("Header with an open sub-account should not close");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    close(&mut client, tenant_id, &bank)
+        .await
+        .expect("Failed to close sub-account");
+    close(&mut client, tenant_id, &assets)
+        .await
+        .expect("Failed to close header account");
+
+    // A sub-account cannot be reopened under a closed parent
+    let status = reopen(&mut client, tenant_id, &bank)
+        .await
+        .expect_err("Reopen under a closed parent should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// Invalid transitions and requests are rejected
+#[tokio::test]
+async fn reject_invalid_status_changes() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let cash = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1000").await;
+
+    let status = reopen(&mut client, tenant_id, &cash)
+        .await
+        .expect_err("Reopening an active account should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    freeze(&mut client, tenant_id, &cash, "")
+        .await
+        .expect("Failed to freeze account");
+    let status = freeze(&mut client, tenant_id, &cash, "")
+        .await
+        .expect_err("Freezing twice should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    let status = client
+        .close_account(CloseAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: cash.clone(),
+            actor: String::new(),
+            reason: String::new(),
+        })
+        .await
+        .expect_err("Missing actor should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = close(&mut client, tenant_id, &Uuid::new_v4().to_string())
+        .await
+        .expect_err("Unknown account should be not found");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
+/// ListAccounts filters by status
+#[tokio::test]
+async fn list_accounts_filter_by_status() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let cash = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1000").await;
+    let wallet = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1100").await;
+    let savings = create_account(&mut client, tenant_id, ProtoAccountType::Asset, "1200").await;
+    freeze(&mut client, tenant_id, &wallet, "")
+        .await
+        .expect("Failed to freeze account");
+    close(&mut client, tenant_id, &savings)
+        .await
+        .expect("Failed to close account");
+
+    let list = |status: ProtoAccountStatus| {
+        let mut client = client.clone();
+        async move {
+            client
+                .list_accounts(ListAccountsRequest {
+                    tenant_id: tenant_id.to_string(),
+                    account_type: 0,
+                    currency: String::new(),
+                    page_size: 0,
+                    page_token: String::new(),
+                    status: status as i32,
+                })
+                .await
+                .expect("Failed to list accounts")
+                .into_inner()
+                .accounts
+                .into_iter()
+                .map(|a| a.account_id)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    assert_eq!(list(ProtoAccountStatus::Active).await, vec![cash]);
+    assert_eq!(list(ProtoAccountStatus::Frozen).await, vec![wallet]);
+    assert_eq!(list(ProtoAccountStatus::Closed).await, vec![savings]);
+    assert_eq!(list(ProtoAccountStatus::Unspecified).await.len(), 3);
+}
@@ -139,6 +139,7 @@ async fn reject_duplicate_account_code_same_tenant() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: String::new(),
+        parent_account_id: String::new(),
     };
 
     let result = client.create_account(request).await;
@@ -194,6 +195,7 @@ async fn reject_invalid_currency_code() {
         currency: "INVALID".to_string(), // Not 3 characters
         allow_negative: false,
         metadata: String::new(),
+        parent_account_id: String::new(),
     };
 
     let result = client.create_account(request).await;
@@ -288,6 +290,7 @@ async fn list_accounts_with_pagination() {
         page_token: String::new(),
         account_type: 0,
         currency: String::new(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
@@ -304,6 +307,7 @@ async fn list_accounts_with_pagination() {
         page_token: response.next_page_token,
         account_type: 0,
         currency: String::new(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
@@ -317,6 +321,7 @@ async fn list_accounts_with_pagination() {
         page_token: response.next_page_token,
         account_type: 0,
         currency: String::new(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
@@ -365,6 +370,7 @@ async fn list_accounts_filter_by_type() {
         page_token: String::new(),
         account_type: ProtoAccountType::Asset as i32,
         currency: String::new(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
@@ -415,6 +421,7 @@ async fn list_accounts_filter_by_currency() {
         page_token: String::new(),
         account_type: 0,
         currency: "USD".to_string(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
@@ -472,6 +479,7 @@ async fn list_accounts_tenant_isolation() {
         page_token: String::new(),
         account_type: 0,
         currency: String::new(),
+        status: 0,
     };
 
     let response = client.list_accounts(request).await.unwrap().into_inner();
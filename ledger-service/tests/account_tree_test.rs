@@ -0,0 +1,369 @@
+//! Chart-of-Accounts Hierarchy Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    CreateAccountRequest, Direction as ProtoDirection, GetAccountRequest, GetAccountTreeRequest,
+    GetBalanceRequest, GetBalancesRequest, PostTransactionEntry, PostTransactionRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+async fn create_child_account(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_type: ProtoAccountType,
+    account_code: &str,
+    currency: &str,
+    parent_account_id: &str,
+) -> Result<String, tonic::Status> {
+    client
+        .create_account(CreateAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_type: account_type as i32,
+            account_code: account_code.to_string(),
+            currency: currency.to_string(),
+            allow_negative: false,
+            metadata: String::new(),
+            parent_account_id: parent_account_id.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().account.unwrap().account_id)
+}
+
+/// Chart of accounts used by the tree tests.
+struct Chart {
+    assets: String,
+    cash: String,
+    bank_hdfc: String,
+    petty_cash: String,
+    capital: String,
+}
+
+/// Build 1000 Assets › 1100 Cash › {1110 Bank-HDFC, 1120 Petty-Cash} and fund
+/// the leaves from a capital account: HDFC 700, petty cash 50.
+async fn setup_chart(client: &mut LedgerServiceClient<Channel>, tenant_id: Uuid) -> Chart {
+    let assets = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let cash = create_child_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1100",
+        "USD",
+        &assets,
+    )
+    .await
+    .expect("Failed to create cash");
+    let bank_hdfc = create_child_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1110",
+        "USD",
+        &cash,
+    )
+    .await
+    .expect("Failed to create bank");
+    let petty_cash = create_child_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1120",
+        "USD",
+        &cash,
+    )
+    .await
+    .expect("Failed to create petty cash");
+    let capital = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+
+    post_test_transaction(
+        client,
+        tenant_id,
+        &bank_hdfc,
+        &capital,
+        "700.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        client,
+        tenant_id,
+        &petty_cash,
+        &capital,
+        "50.00",
+        Some("2026-01-20"),
+        None,
+    )
+    .await;
+
+    Chart {
+        assets,
+        cash,
+        bank_hdfc,
+        petty_cash,
+        capital,
+    }
+}
+
+/// Child accounts report their parent
+#[tokio::test]
+async fn create_child_account_sets_parent() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let chart = setup_chart(&mut client, tenant_id).await;
+
+    let bank = client
+        .get_account(GetAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: chart.bank_hdfc.clone(),
+        })
+        .await
+        .expect("Failed to get account")
+        .into_inner()
+        .account
+        .unwrap();
+    assert_eq!(bank.parent_account_id, chart.cash);
+
+    let assets = client
+        .get_account(GetAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: chart.assets.clone(),
+        })
+        .await
+        .expect("Failed to get account")
+        .into_inner()
+        .account
+        .unwrap();
+    assert!(assets.parent_account_id.is_empty());
+}
+
+/// GetBalance and GetBalances roll up descendants on request
+#[tokio::test]
+async fn rollup_balances_include_descendants() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let chart = setup_chart(&mut client, tenant_id).await;
+
+    // Header account has no postings of its own
+    let own = get_balance(&mut client, tenant_id, &chart.assets, None).await;
+    assert_eq!(own.balance, "0");
+
+    let rollup = client
+        .get_balance(GetBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: chart.assets.clone(),
+            as_of_date: String::new(),
+            include_descendants: true,
+        })
+        .await
+        .expect("Failed to get roll-up balance")
+        .into_inner();
+    assert_eq!(rollup.balance, "750");
+    assert_eq!(rollup.currency, "USD");
+
+    // Point-in-time roll-up only includes the first posting
+    let early = client
+        .get_balance(GetBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: chart.cash.clone(),
+            as_of_date: "2026-01-15".to_string(),
+            include_descendants: true,
+        })
+        .await
+        .expect("Failed to get roll-up balance")
+        .into_inner();
+    assert_eq!(early.balance, "700");
+
+    let balances = client
+        .get_balances(GetBalancesRequest {
+            tenant_id: tenant_id.to_string(),
+            account_ids: vec![chart.cash.clone(), chart.petty_cash.clone()],
+            as_of_date: String::new(),
+            include_descendants: true,
+        })
+        .await
+        .expect("Failed to get balances")
+        .into_inner()
+        .balances;
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances[0].balance, "750");
+    assert_eq!(balances[1].balance, "50");
+}
+
+/// Header accounts cannot be posted to
+#[tokio::test]
+async fn reject_posting_to_header_account() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let chart = setup_chart(&mut client, tenant_id).await;
+
+    let result = client
+        .post_transaction(PostTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            entries: vec![
+                PostTransactionEntry {
+                    account_id: chart.cash.clone(),
+                    amount: "10.00".to_string(),
+                    direction: ProtoDirection::Debit as i32,
+                    exchange_rate: String::new(),
+                },
+                PostTransactionEntry {
+                    account_id: chart.capital.clone(),
+                    amount: "10.00".to_string(),
+                    direction: ProtoDirection::Credit as i32,
+                    exchange_rate: String::new(),
+                },
+            ],
+            effective_date: String::new(),
+            idempotency_key: String::new(),
+            metadata: String::new(),
+            base_currency: String::new(),
+        })
+        .await;
+
+    let status = result.expect_err("Posting to a header account should fail");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("header account"));
+}
+
+/// Parents must match type and currency and must not hold postings
+#[tokio::test]
+async fn reject_invalid_parent_account() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let chart = setup_chart(&mut client, tenant_id).await;
+
+    let status = create_child_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Liability,
+        "2100",
+        "USD",
+        &chart.assets,
+    )
+    .await
+    .expect_err("Type mismatch should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = create_child_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+        &chart.assets,
+    )
+    .await
+    .expect_err("Currency mismatch should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    // The bank account already has postings and cannot become a header
+    let status = create_child_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1111",
+        "USD",
+        &chart.bank_hdfc,
+    )
+    .await
+    .expect_err("Parent with postings should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = create_child_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1300",
+        "USD",
+        &Uuid::new_v4().to_string(),
+    )
+    .await
+    .expect_err("Unknown parent should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// GetAccountTree nests accounts and rolls up balances
+#[tokio::test]
+async fn get_account_tree_rolls_up_balances() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let chart = setup_chart(&mut client, tenant_id).await;
+
+    let response = client
+        .get_account_tree(GetAccountTreeRequest {
+            tenant_id: tenant_id.to_string(),
+            root_account_id: String::new(),
+            as_of_date: String::new(),
+        })
+        .await
+        .expect("Failed to get account tree")
+        .into_inner();
+
+    // Roots ordered by account code: 1000 Assets, 3000 Capital
+    assert_eq!(response.roots.len(), 2);
+    let assets = &response.roots[0];
+    assert_eq!(assets.account.as_ref().unwrap().account_id, chart.assets);
+    assert_eq!(assets.account.as_ref().unwrap().balance, "0");
+    assert_eq!(assets.rollup_balance, "750");
+    assert_eq!(response.roots[1].rollup_balance, "750");
+
+    let cash = &assets.children[0];
+    assert_eq!(cash.rollup_balance, "750");
+    let leaves: Vec<_> = cash
+        .children
+        .iter()
+        .map(|n| {
+            let account = n.account.as_ref().unwrap();
+            (account.account_code.as_str(), n.rollup_balance.as_str())
+        })
+        .collect();
+    assert_eq!(leaves, vec![("1110", "700"), ("1120", "50")]);
+    assert!(cash.children.iter().all(|n| n.children.is_empty()));
+
+    // Subtree rooted at a nested account
+    let subtree = client
+        .get_account_tree(GetAccountTreeRequest {
+            tenant_id: tenant_id.to_string(),
+            root_account_id: chart.cash.clone(),
+            as_of_date: "2026-01-15".to_string(),
+        })
+        .await
+        .expect("Failed to get account subtree")
+        .into_inner();
+    assert_eq!(subtree.roots.len(), 1);
+    assert_eq!(subtree.roots[0].rollup_balance, "700");
+    assert_eq!(subtree.roots[0].children.len(), 2);
+
+    let status = client
+        .get_account_tree(GetAccountTreeRequest {
+            tenant_id: tenant_id.to_string(),
+            root_account_id: Uuid::new_v4().to_string(),
+            as_of_date: String::new(),
+        })
+        .await
+        .expect_err("Unknown root should be not found");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
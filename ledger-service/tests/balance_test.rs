@@ -207,16 +207,19 @@ async fn get_balance_expense_account() {
                 account_id: expense_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
     client.post_transaction(request).await.unwrap();
 
@@ -328,6 +331,7 @@ async fn get_balance_not_found() {
         tenant_id: tenant_id.to_string(),
         account_id: Uuid::new_v4().to_string(),
         as_of_date: String::new(),
+        include_descendants: false,
     };
 
     let result = client.get_balance(request).await;
@@ -395,16 +399,19 @@ async fn get_balances_multiple_accounts() {
                 account_id: expense_id.clone(),
                 amount: "30.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_id.clone(),
                 amount: "30.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
     client.post_transaction(request).await.unwrap();
 
@@ -413,6 +420,7 @@ async fn get_balances_multiple_accounts() {
         tenant_id: tenant_id.to_string(),
         account_ids: vec![cash_id.clone(), revenue_id.clone(), expense_id.clone()],
         as_of_date: String::new(),
+        include_descendants: false,
     };
 
     let response = client.get_balances(request).await.unwrap().into_inner();
@@ -461,6 +469,7 @@ async fn get_balances_skips_non_existent() {
         tenant_id: tenant_id.to_string(),
         account_ids: vec![cash_id.clone(), Uuid::new_v4().to_string()],
         as_of_date: String::new(),
+        include_descendants: false,
     };
 
     let response = client.get_balances(request).await.unwrap().into_inner();
@@ -562,16 +571,19 @@ async fn balance_reflects_account_type() {
                 account_id: expense_id.clone(),
                 amount: "200.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: asset_id.clone(),
                 amount: "200.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
     client.post_transaction(request).await.unwrap();
 
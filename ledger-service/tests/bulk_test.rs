@@ -0,0 +1,260 @@
+//! Bulk Import/Export Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    Direction as ProtoDirection, ExportFormat as ProtoExportFormat, ExportTransactionsRequest,
+    ImportTransactionsRequest, ImportTransactionsResponse, PostTransactionEntry,
+    PostTransactionRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// Create a cash account and a revenue account.
+async fn setup_accounts(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+) -> (String, String) {
+    let cash = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let revenue = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "4000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    (cash, revenue)
+}
+
+fn journal(
+    tenant_id: Uuid,
+    debit_account_id: &str,
+    credit_account_id: &str,
+    amount: &str,
+    effective_date: &str,
+    idempotency_key: &str,
+) -> PostTransactionRequest {
+    PostTransactionRequest {
+        tenant_id: tenant_id.to_string(),
+        entries: vec![
+            PostTransactionEntry {
+                account_id: debit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
+            },
+            PostTransactionEntry {
+                account_id: credit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
+            },
+        ],
+        effective_date: effective_date.to_string(),
+        idempotency_key: idempotency_key.to_string(),
+        metadata: String::new(),
+        base_currency: String::new(),
+    }
+}
+
+async fn import(
+    client: &mut LedgerServiceClient<Channel>,
+    batches: Vec<Vec<PostTransactionRequest>>,
+) -> ImportTransactionsResponse {
+    let requests = batches
+        .into_iter()
+        .map(|transactions| ImportTransactionsRequest { transactions });
+    client
+        .import_transactions(tokio_stream::iter(requests))
+        .await
+        .expect("Failed to import transactions")
+        .into_inner()
+}
+
+async fn export(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    format: ProtoExportFormat,
+) -> String {
+    let mut stream = client
+        .export_transactions(ExportTransactionsRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-12-31".to_string(),
+            format: format as i32,
+        })
+        .await
+        .expect("Failed to export transactions")
+        .into_inner();
+
+    let mut data = String::new();
+    while let Some(message) = stream.message().await.expect("Export stream failed") {
+        data.push_str(&message.data);
+    }
+    data
+}
+
+/// Imports post valid journals, report bad rows and skip replays
+#[tokio::test]
+async fn import_reports_row_errors_and_replays() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, revenue) = setup_accounts(&mut client, tenant_id).await;
+
+    let mut unbalanced = journal(tenant_id, &cash, &revenue, "10.00", "2026-03-01", "");
+    unbalanced.entries[1].amount = "9.00".to_string();
+    let mut bad_amount = journal(tenant_id, &cash, &revenue, "10.00", "2026-03-01", "bad");
+    bad_amount.entries[0].amount = "ten".to_string();
+
+    let response = import(
+        &mut client,
+        vec![
+            vec![
+                journal(tenant_id, &cash, &revenue, "100.00", "2026-03-01", "mig-1"),
+                unbalanced,
+            ],
+            vec![
+                journal(tenant_id, &cash, &revenue, "50.00", "2026-03-02", "mig-2"),
+                bad_amount,
+            ],
+        ],
+    )
+    .await;
+    assert_eq!(response.rows_received, 4);
+    assert_eq!(response.rows_posted, 2);
+    assert_eq!(response.rows_replayed, 0);
+    assert_eq!(response.rows_failed, 2);
+    assert_eq!(response.errors.len(), 2);
+    assert_eq!(response.errors[0].row_number, 2);
+    assert_eq!(response.errors[0].code, "invalid_argument");
+    assert_eq!(response.errors[1].row_number, 4);
+    assert_eq!(response.errors[1].idempotency_key, "bad");
+
+    // Re-running the migration only posts what is new
+    let response = import(
+        &mut client,
+        vec![vec![
+            journal(tenant_id, &cash, &revenue, "100.00", "2026-03-01", "mig-1"),
+            journal(tenant_id, &cash, &revenue, "50.00", "2026-03-02", "mig-2"),
+            journal(tenant_id, &cash, &revenue, "25.00", "2026-03-03", "mig-3"),
+        ]],
+    )
+    .await;
+    assert_eq!(response.rows_posted, 1);
+    assert_eq!(response.rows_replayed, 2);
+    assert_eq!(response.rows_failed, 0);
+
+    let balance = get_balance(&mut client, tenant_id, &cash, Some("2026-03-31")).await;
+    assert_eq!(balance.balance, "175");
+}
+
+/// CSV exports one quoted row per entry under a fixed header
+#[tokio::test]
+async fn export_csv_layout() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, revenue) = setup_accounts(&mut client, tenant_id).await;
+
+    let mut with_metadata = journal(tenant_id, &cash, &revenue, "12.50", "2026-04-02", "inv-7");
+    with_metadata.metadata = r#"{"description":"Rent, April"}"#.to_string();
+    import(
+        &mut client,
+        vec![vec![
+            with_metadata,
+            journal(tenant_id, &cash, &revenue, "30.00", "2026-04-01", ""),
+        ]],
+    )
+    .await;
+
+    let csv = export(&mut client, tenant_id, ProtoExportFormat::Csv).await;
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "journal_id,effective_date,posted_at,entry_id,account_id,account_code,direction,amount,currency,base_currency,base_amount,exchange_rate,idempotency_key,reverses_journal_id,metadata"
+    );
+    assert_eq!(lines.len(), 5);
+
+    // Ordered by effective date: the 2026-04-01 journal comes first
+    assert!(lines[1].contains(",2026-04-01,"));
+    assert!(lines[3].contains(",2026-04-02,"));
+    assert!(lines[3].contains(",12.5,USD,"));
+    assert!(lines[3].ends_with(r#","{""description"":""Rent, April""}""#));
+    assert!(lines[3..].iter().any(|l| l.contains(",inv-7,,")));
+
+    let status = client
+        .export_transactions(ExportTransactionsRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-12-31".to_string(),
+            end_date: "2026-01-01".to_string(),
+            format: ProtoExportFormat::Csv as i32,
+        })
+        .await
+        .expect_err("Inverted date range should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// JSONL exports one whole journal per line, even across database pages
+#[tokio::test]
+async fn export_jsonl_keeps_journals_whole() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, revenue) = setup_accounts(&mut client, tenant_id).await;
+
+    // 260 journals = 520 entries, more than one export page
+    let journals = (0..260)
+        .map(|i| {
+            journal(
+                tenant_id,
+                &cash,
+                &revenue,
+                "1.00",
+                "2026-05-01",
+                &format!("bulk-{}", i),
+            )
+        })
+        .collect::<Vec<_>>();
+    let response = import(
+        &mut client,
+        journals.chunks(100).map(|c| c.to_vec()).collect(),
+    )
+    .await;
+    assert_eq!(response.rows_posted, 260);
+
+    let jsonl = export(&mut client, tenant_id, ProtoExportFormat::Jsonl).await;
+    let lines: Vec<serde_json::Value> = jsonl
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("Invalid JSON line"))
+        .collect();
+    assert_eq!(lines.len(), 260);
+    assert!(lines
+        .iter()
+        .all(|j| j["entries"].as_array().map(Vec::len) == Some(2)));
+    assert_eq!(lines[0]["effective_date"], "2026-05-01");
+    assert_eq!(lines[0]["entries"][0]["amount"], "1");
+    assert_eq!(lines[0]["entries"][0]["currency"], "USD");
+
+    let mut keys: Vec<&str> = lines
+        .iter()
+        .map(|j| j["idempotency_key"].as_str().unwrap())
+        .collect();
+    keys.sort();
+    keys.dedup();
+    assert_eq!(keys.len(), 260);
+}
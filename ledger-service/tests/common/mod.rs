@@ -1,6 +1,6 @@
 //! Common test utilities for ledger-service integration tests.
 
-use ledger_service::config::{DatabaseConfig, LedgerConfig};
+use ledger_service::config::{DatabaseConfig, HoldConfig, IdempotencyConfig, LedgerConfig};
 use ledger_service::grpc::proto::{
     ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
     CreateAccountRequest, CreateAccountResponse, Direction as ProtoDirection, GetBalanceRequest,
@@ -43,6 +43,14 @@ pub async fn spawn_app() -> (LedgerServiceClient<Channel>, Uuid) {
             max_connections: 2,
             min_connections: 1,
         },
+        holds: HoldConfig {
+            default_ttl_secs: 3600,
+            expiry_interval_secs: 0,
+        },
+        idempotency: IdempotencyConfig {
+            retention_secs: 3600,
+            purge_interval_secs: 0,
+        },
     };
 
     // Use build_without_migrations since integ-tests.sh already ran migrations
@@ -93,6 +101,7 @@ pub async fn create_test_account(
         currency: currency.to_string(),
         allow_negative,
         metadata: String::new(),
+        parent_account_id: String::new(),
     };
 
     client
@@ -119,16 +128,19 @@ pub async fn post_test_transaction(
                 account_id: debit_account_id.to_string(),
                 amount: amount.to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: credit_account_id.to_string(),
                 amount: amount.to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: effective_date.unwrap_or("").to_string(),
         idempotency_key: idempotency_key.unwrap_or("").to_string(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     client
@@ -149,6 +161,7 @@ pub async fn get_balance(
         tenant_id: tenant_id.to_string(),
         account_id: account_id.to_string(),
         as_of_date: as_of_date.unwrap_or("").to_string(),
+        include_descendants: false,
     };
 
     client
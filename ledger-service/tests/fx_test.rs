@@ -0,0 +1,731 @@
+//! Multi-Currency and FX Revaluation Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    ClosePeriodRequest, Direction as ProtoDirection, ExchangeRate, GetBalanceSheetRequest,
+    GetBaseCurrencyRequest, GetExchangeRateRequest, GetIncomeStatementRequest,
+    GetTrialBalanceRequest, OpenPeriodRequest, PostTransactionEntry, PostTransactionRequest,
+    RevalueAccountsRequest, RevalueAccountsResponse, ReverseTransactionRequest,
+    SetBaseCurrencyRequest, UpsertExchangeRateRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+async fn upsert_rate(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    from_currency: &str,
+    to_currency: &str,
+    rate_date: &str,
+    rate: &str,
+) {
+    client
+        .upsert_exchange_rate(UpsertExchangeRateRequest {
+            tenant_id: tenant_id.to_string(),
+            from_currency: from_currency.to_string(),
+            to_currency: to_currency.to_string(),
+            rate_date: rate_date.to_string(),
+            rate: rate.to_string(),
+        })
+        .await
+        .expect("Failed to upsert exchange rate");
+}
+
+async fn set_base_currency(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    base_currency: &str,
+) -> Result<i64, tonic::Status> {
+    client
+        .set_base_currency(SetBaseCurrencyRequest {
+            tenant_id: tenant_id.to_string(),
+            base_currency: base_currency.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().entries_converted)
+}
+
+async fn get_rate(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    as_of_date: &str,
+) -> Result<ExchangeRate, tonic::Status> {
+    client
+        .get_exchange_rate(GetExchangeRateRequest {
+            tenant_id: tenant_id.to_string(),
+            from_currency: "EUR".to_string(),
+            to_currency: "USD".to_string(),
+            as_of_date: as_of_date.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().exchange_rate.unwrap())
+}
+
+async fn revalue(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    gain_loss_account_id: &str,
+    as_of_date: &str,
+) -> RevalueAccountsResponse {
+    client
+        .revalue_accounts(RevalueAccountsRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: as_of_date.to_string(),
+            base_currency: "USD".to_string(),
+            gain_loss_account_id: gain_loss_account_id.to_string(),
+        })
+        .await
+        .expect("Failed to revalue")
+        .into_inner()
+}
+
+async fn create_account(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_type: ProtoAccountType,
+    account_code: &str,
+    currency: &str,
+) -> String {
+    create_test_account(
+        client,
+        tenant_id,
+        account_type,
+        account_code,
+        currency,
+        true,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id
+}
+
+fn entry(account_id: &str, amount: &str, direction: ProtoDirection) -> PostTransactionEntry {
+    PostTransactionEntry {
+        account_id: account_id.to_string(),
+        amount: amount.to_string(),
+        direction: direction as i32,
+        exchange_rate: String::new(),
+    }
+}
+
+fn fx_request(
+    tenant_id: Uuid,
+    entries: Vec<PostTransactionEntry>,
+    base_currency: &str,
+) -> PostTransactionRequest {
+    PostTransactionRequest {
+        tenant_id: tenant_id.to_string(),
+        entries,
+        effective_date: "2026-01-10".to_string(),
+        idempotency_key: String::new(),
+        metadata: String::new(),
+        base_currency: base_currency.to_string(),
+    }
+}
+
+/// Rates are looked up by the latest rate date on or before the requested date
+#[tokio::test]
+async fn upsert_and_get_exchange_rate() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    upsert_rate(&mut client, tenant_id, "eur", "usd", "2026-01-01", "1.10").await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-31", "1.20").await;
+    // Replaces the rate of the same day
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-31", "1.25").await;
+
+    let rate = get_rate(&mut client, tenant_id, "2026-01-15")
+        .await
+        .expect("Failed to get exchange rate");
+    assert_eq!(rate.rate, "1.1");
+    assert_eq!(rate.rate_date, "2026-01-01");
+    assert_eq!(rate.from_currency, "EUR");
+
+    let rate = get_rate(&mut client, tenant_id, "2026-02-01")
+        .await
+        .expect("Failed to get exchange rate");
+    assert_eq!(rate.rate, "1.25");
+
+    let status = get_rate(&mut client, tenant_id, "2025-12-31")
+        .await
+        .expect_err("No rate before the first rate date");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    let status = client
+        .upsert_exchange_rate(UpsertExchangeRateRequest {
+            tenant_id: tenant_id.to_string(),
+            from_currency: "EUR".to_string(),
+            to_currency: "USD".to_string(),
+            rate_date: String::new(),
+            rate: "0".to_string(),
+        })
+        .await
+        .expect_err("Zero rate should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Cross-currency journals balance in the base currency
+#[tokio::test]
+async fn post_cross_currency_transaction() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+    )
+    .await;
+    let capital = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+    )
+    .await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-01", "1.10").await;
+
+    // Mixed currencies require a tenant base currency
+    let status = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&eur_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "110.00", ProtoDirection::Credit),
+            ],
+            "USD",
+        ))
+        .await
+        .expect_err("Mixed currencies without base currency should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("base currency"));
+
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+
+    let transaction = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&eur_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "110.00", ProtoDirection::Credit),
+            ],
+            "USD",
+        ))
+        .await
+        .expect("Failed to post cross-currency transaction")
+        .into_inner()
+        .transaction
+        .unwrap();
+    assert_eq!(transaction.base_currency, "USD");
+    let eur_entry = &transaction.entries[0];
+    assert_eq!(eur_entry.amount, "100");
+    assert_eq!(eur_entry.base_amount, "110");
+    assert_eq!(eur_entry.exchange_rate, "1.1");
+    assert_eq!(transaction.entries[1].base_amount, "110");
+
+    // Balances stay in each account's own currency
+    let balance = get_balance(&mut client, tenant_id, &eur_bank, None).await;
+    assert_eq!(balance.balance, "100");
+    assert_eq!(balance.currency, "EUR");
+
+    // An explicit rate overrides the stored one
+    let mut request = fx_request(
+        tenant_id,
+        vec![
+            entry(&eur_bank, "100.00", ProtoDirection::Debit),
+            entry(&capital, "120.00", ProtoDirection::Credit),
+        ],
+        "USD",
+    );
+    request.entries[0].exchange_rate = "1.2".to_string();
+    client
+        .post_transaction(request)
+        .await
+        .expect("Failed to post with explicit rate");
+
+    // Not balanced in the base currency at the stored rate
+    let status = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&eur_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "100.00", ProtoDirection::Credit),
+            ],
+            "USD",
+        ))
+        .await
+        .expect_err("Unbalanced base amounts should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    // The tenant base currency applies when the request names none
+    let transaction = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&eur_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "110.00", ProtoDirection::Credit),
+            ],
+            "",
+        ))
+        .await
+        .expect("Failed to post with the tenant base currency")
+        .into_inner()
+        .transaction
+        .unwrap();
+    assert_eq!(transaction.base_currency, "USD");
+
+    // A request naming another base currency is rejected
+    let status = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&eur_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "110.00", ProtoDirection::Credit),
+            ],
+            "EUR",
+        ))
+        .await
+        .expect_err("A different base currency should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Entries without a stored or explicit rate are rejected
+#[tokio::test]
+async fn reject_missing_exchange_rate() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let gbp_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1300",
+        "GBP",
+    )
+    .await;
+    let capital = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+    )
+    .await;
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+
+    let status = client
+        .post_transaction(fx_request(
+            tenant_id,
+            vec![
+                entry(&gbp_bank, "100.00", ProtoDirection::Debit),
+                entry(&capital, "127.00", ProtoDirection::Credit),
+            ],
+            "USD",
+        ))
+        .await
+        .expect_err("Missing rate should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Revaluation posts unrealised gains and losses incrementally
+#[tokio::test]
+async fn revalue_foreign_currency_accounts() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+    )
+    .await;
+    let eur_capital = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3100",
+        "EUR",
+    )
+    .await;
+    let fx_gain_loss = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "4900",
+        "USD",
+    )
+    .await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-01", "1.10").await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-31", "1.20").await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-02-28", "1.15").await;
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+
+    // Single-currency EUR journal, carried at the rate of its effective date
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &eur_bank,
+        &eur_capital,
+        "100.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+
+    // 100 EUR carried at 110 USD, revalued at 1.20 to 120 USD
+    let response = revalue(&mut client, tenant_id, &fx_gain_loss, "2026-01-31").await;
+    let eur_bank_revaluation = response
+        .revaluations
+        .iter()
+        .find(|r| r.account_id == eur_bank)
+        .unwrap();
+    assert_eq!(eur_bank_revaluation.carrying_base_amount, "110");
+    assert_eq!(eur_bank_revaluation.revalued_base_amount, "120");
+    assert_eq!(eur_bank_revaluation.gain_loss, "10");
+    let journal = response.transaction.expect("Revaluation journal expected");
+    assert_eq!(journal.base_currency, "USD");
+
+    let gain = get_balance(&mut client, tenant_id, &fx_gain_loss, None).await;
+    assert_eq!(gain.balance, "10");
+    // The foreign balance itself is unchanged
+    let bank = get_balance(&mut client, tenant_id, &eur_bank, None).await;
+    assert_eq!(bank.balance, "100");
+
+    // Reports aggregate base amounts, so they stay balanced after revaluation
+    let trial_balances = client
+        .get_trial_balance(GetTrialBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get trial balance")
+        .into_inner()
+        .trial_balances;
+    assert_eq!(trial_balances.len(), 1);
+    assert_eq!(trial_balances[0].currency, "USD");
+    assert_eq!(trial_balances[0].total_debits, "120");
+    assert!(trial_balances[0].balanced);
+
+    let balance_sheets = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner()
+        .balance_sheets;
+    assert_eq!(balance_sheets.len(), 1);
+    assert_eq!(balance_sheets[0].total_assets, "120");
+    assert_eq!(balance_sheets[0].total_equity, "110");
+    assert_eq!(balance_sheets[0].net_income, "10");
+    assert!(balance_sheets[0].balanced);
+
+    // Rate falls to 1.15: only the difference to the last revaluation is posted
+    let response = revalue(&mut client, tenant_id, &fx_gain_loss, "2026-02-28").await;
+    let eur_bank_revaluation = response
+        .revaluations
+        .iter()
+        .find(|r| r.account_id == eur_bank)
+        .unwrap();
+    assert_eq!(eur_bank_revaluation.carrying_base_amount, "120");
+    assert_eq!(eur_bank_revaluation.gain_loss, "-5");
+    assert!(response.transaction.is_some());
+
+    let gain = get_balance(&mut client, tenant_id, &fx_gain_loss, None).await;
+    assert_eq!(gain.balance, "5");
+
+    // Nothing left to adjust at the same rate
+    let response = revalue(&mut client, tenant_id, &fx_gain_loss, "2026-02-28").await;
+    assert!(response.transaction.is_none());
+    assert!(response.revaluations.iter().all(|r| r.gain_loss == "0"));
+
+    // Revaluation journals cannot be reversed
+    let status = client
+        .reverse_transaction(ReverseTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            journal_id: journal.journal_id,
+            effective_date: String::new(),
+            reason: String::new(),
+            metadata: String::new(),
+        })
+        .await
+        .expect_err("Reversing a revaluation should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// The gain/loss account must be in the base currency
+#[tokio::test]
+async fn reject_revaluation_with_foreign_gain_loss_account() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_gain_loss = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "4900",
+        "EUR",
+    )
+    .await;
+    let request = RevalueAccountsRequest {
+        tenant_id: tenant_id.to_string(),
+        as_of_date: String::new(),
+        base_currency: String::new(),
+        gain_loss_account_id: eur_gain_loss,
+    };
+
+    // Revaluation needs a tenant base currency
+    let status = client
+        .revalue_accounts(request.clone())
+        .await
+        .expect_err("Revaluation without base currency should be rejected");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+    let status = client
+        .revalue_accounts(request)
+        .await
+        .expect_err("Foreign gain/loss account should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Base amounts that balance before rounding carry the residual on one entry
+#[tokio::test]
+async fn place_base_rounding_residual_on_one_entry() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+    )
+    .await;
+    let capital = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+    )
+    .await;
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+
+    // 0.33335 + 0.33335 + 0.3333 = 1, but rounds to 0.3334 + 0.3334 + 0.3333
+    let mut request = fx_request(
+        tenant_id,
+        vec![
+            entry(&eur_bank, "1", ProtoDirection::Debit),
+            entry(&eur_bank, "1", ProtoDirection::Debit),
+            entry(&eur_bank, "1", ProtoDirection::Debit),
+            entry(&capital, "1", ProtoDirection::Credit),
+        ],
+        "",
+    );
+    request.entries[0].exchange_rate = "0.33335".to_string();
+    request.entries[1].exchange_rate = "0.33335".to_string();
+    request.entries[2].exchange_rate = "0.3333".to_string();
+
+    let transaction = client
+        .post_transaction(request)
+        .await
+        .expect("Journal balanced before rounding should be accepted")
+        .into_inner()
+        .transaction
+        .unwrap();
+    let base_amounts: Vec<&str> = transaction
+        .entries
+        .iter()
+        .map(|e| e.base_amount.as_str())
+        .collect();
+    assert_eq!(base_amounts, vec!["0.3334", "0.3333", "0.3333", "1"]);
+}
+
+/// Setting the base currency values existing entries and cannot be changed
+#[tokio::test]
+async fn set_base_currency_converts_existing_entries() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+    )
+    .await;
+    let eur_capital = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3100",
+        "EUR",
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &eur_bank,
+        &eur_capital,
+        "100.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+
+    // No stored rate for the existing entries
+    let status = set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect_err("Missing rate should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-01", "1.10").await;
+    let converted = set_base_currency(&mut client, tenant_id, "usd")
+        .await
+        .expect("Failed to set base currency");
+    assert_eq!(converted, 2);
+
+    let base_currency = client
+        .get_base_currency(GetBaseCurrencyRequest {
+            tenant_id: tenant_id.to_string(),
+        })
+        .await
+        .expect("Failed to get base currency")
+        .into_inner()
+        .base_currency;
+    assert_eq!(base_currency, "USD");
+
+    let balance_sheets = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner()
+        .balance_sheets;
+    assert_eq!(balance_sheets.len(), 1);
+    assert_eq!(balance_sheets[0].currency, "USD");
+    assert_eq!(balance_sheets[0].total_assets, "110");
+
+    // Setting the same base again is a no-op; another base is rejected
+    let converted = set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Setting the same base currency should succeed");
+    assert_eq!(converted, 0);
+    let status = set_base_currency(&mut client, tenant_id, "EUR")
+        .await
+        .expect_err("Changing the base currency should be rejected");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// Closing a period zeroes foreign revenue in both its currency and the base
+#[tokio::test]
+async fn close_period_with_foreign_revenue() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let eur_bank = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1200",
+        "EUR",
+    )
+    .await;
+    let eur_revenue = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "4100",
+        "EUR",
+    )
+    .await;
+    let retained_earnings = create_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3200",
+        "USD",
+    )
+    .await;
+    upsert_rate(&mut client, tenant_id, "EUR", "USD", "2026-01-01", "1.10").await;
+    set_base_currency(&mut client, tenant_id, "USD")
+        .await
+        .expect("Failed to set base currency");
+
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &eur_bank,
+        &eur_revenue,
+        "100.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+
+    let period = client
+        .open_period(OpenPeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            name: "2026-01".to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-01-31".to_string(),
+            retained_earnings_account_id: retained_earnings.clone(),
+        })
+        .await
+        .expect("Failed to open period")
+        .into_inner()
+        .period
+        .unwrap();
+    client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id,
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect("Failed to close period with foreign revenue");
+
+    let revenue = get_balance(&mut client, tenant_id, &eur_revenue, None).await;
+    assert_eq!(revenue.balance, "0");
+    let earnings = get_balance(&mut client, tenant_id, &retained_earnings, None).await;
+    assert_eq!(earnings.balance, "110");
+
+    let income_statements = client
+        .get_income_statement(GetIncomeStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get income statement")
+        .into_inner()
+        .income_statements;
+    assert_eq!(income_statements.len(), 1);
+    assert_eq!(income_statements[0].net_income, "110");
+
+    let balance_sheets = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner()
+        .balance_sheets;
+    assert_eq!(balance_sheets.len(), 1);
+    assert_eq!(balance_sheets[0].total_equity, "110");
+    assert_eq!(balance_sheets[0].net_income, "0");
+    assert!(balance_sheets[0].balanced);
+}
@@ -0,0 +1,428 @@
+//! Hold (Authorization) Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    CaptureHoldRequest, CreateHoldRequest, Direction as ProtoDirection, GetHoldRequest,
+    Hold as ProtoHold, HoldStatus as ProtoHoldStatus, ListHoldsRequest, PostTransactionEntry,
+    PostTransactionRequest, ReleaseHoldRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// A funded wallet (asset, no overdraft) and a purchases expense account.
+struct Accounts {
+    wallet: String,
+    merchant: String,
+}
+
+/// Fund a wallet with 100.00 from capital.
+async fn setup_accounts(client: &mut LedgerServiceClient<Channel>, tenant_id: Uuid) -> Accounts {
+    let wallet = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let capital = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let merchant = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Expense,
+        "5000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+
+    post_test_transaction(client, tenant_id, &wallet, &capital, "100.00", None, None).await;
+
+    Accounts { wallet, merchant }
+}
+
+/// Place a hold moving `amount` from the wallet to the merchant.
+async fn create_hold(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    accounts: &Accounts,
+    amount: &str,
+    expires_in_seconds: i64,
+    idempotency_key: &str,
+) -> Result<ProtoHold, tonic::Status> {
+    client
+        .create_hold(CreateHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            debit_account_id: accounts.merchant.clone(),
+            credit_account_id: accounts.wallet.clone(),
+            amount: amount.to_string(),
+            expires_in_seconds,
+            idempotency_key: idempotency_key.to_string(),
+            metadata: String::new(),
+        })
+        .await
+        .map(|r| r.into_inner().hold.unwrap())
+}
+
+async fn capture_hold(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    hold_id: &str,
+    amount: &str,
+) -> Result<ProtoHold, tonic::Status> {
+    client
+        .capture_hold(CaptureHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold_id.to_string(),
+            amount: amount.to_string(),
+            effective_date: String::new(),
+            metadata: String::new(),
+        })
+        .await
+        .map(|r| r.into_inner().hold.unwrap())
+}
+
+async fn release_hold(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    hold_id: &str,
+) -> Result<ProtoHold, tonic::Status> {
+    client
+        .release_hold(ReleaseHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold_id.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner().hold.unwrap())
+}
+
+/// Holds reduce the available balance but not the posted balance
+#[tokio::test]
+async fn hold_reduces_available_balance() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let hold = create_hold(&mut client, tenant_id, &accounts, "30.00", 0, "")
+        .await
+        .expect("Failed to create hold");
+    assert_eq!(hold.status(), ProtoHoldStatus::Pending);
+    assert_eq!(hold.amount, "30");
+    assert_eq!(hold.currency, "USD");
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.balance, "100");
+    assert_eq!(balance.available_balance, "70");
+
+    // The receiving side is not reduced
+    let merchant = get_balance(&mut client, tenant_id, &accounts.merchant, None).await;
+    assert_eq!(merchant.available_balance, "0");
+
+    // Point-in-time queries report posted balances only
+    let today = chrono::Utc::now().date_naive().to_string();
+    let historical = get_balance(&mut client, tenant_id, &accounts.wallet, Some(&today)).await;
+    assert!(historical.available_balance.is_empty());
+}
+
+/// Holds and postings cannot overdraw the available balance
+#[tokio::test]
+async fn reject_insufficient_available_balance() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    create_hold(&mut client, tenant_id, &accounts, "80.00", 0, "")
+        .await
+        .expect("Failed to create hold");
+
+    let status = create_hold(&mut client, tenant_id, &accounts, "30.00", 0, "")
+        .await
+        .expect_err("Hold beyond the available balance should fail");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("Insufficient available balance"));
+
+    // A direct posting of the unheld balance plus one cent is rejected too
+    let result = client
+        .post_transaction(PostTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            entries: vec![
+                PostTransactionEntry {
+                    account_id: accounts.merchant.clone(),
+                    amount: "20.01".to_string(),
+                    direction: ProtoDirection::Debit as i32,
+                    exchange_rate: String::new(),
+                },
+                PostTransactionEntry {
+                    account_id: accounts.wallet.clone(),
+                    amount: "20.01".to_string(),
+                    direction: ProtoDirection::Credit as i32,
+                    exchange_rate: String::new(),
+                },
+            ],
+            effective_date: String::new(),
+            idempotency_key: String::new(),
+            metadata: String::new(),
+            base_currency: String::new(),
+        })
+        .await;
+    let status = result.expect_err("Posting into held funds should fail");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// A posting and a hold racing for the same funds cannot both succeed
+#[tokio::test]
+async fn concurrent_posting_and_hold_cannot_overdraw() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let mut hold_client = client.clone();
+    let mut post_client = client.clone();
+    let (hold, posting) = tokio::join!(
+        create_hold(&mut hold_client, tenant_id, &accounts, "60.00", 0, ""),
+        post_client.post_transaction(PostTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            entries: vec![
+                PostTransactionEntry {
+                    account_id: accounts.merchant.clone(),
+                    amount: "60.00".to_string(),
+                    direction: ProtoDirection::Debit as i32,
+                    exchange_rate: String::new(),
+                },
+                PostTransactionEntry {
+                    account_id: accounts.wallet.clone(),
+                    amount: "60.00".to_string(),
+                    direction: ProtoDirection::Credit as i32,
+                    exchange_rate: String::new(),
+                },
+            ],
+            effective_date: String::new(),
+            idempotency_key: String::new(),
+            metadata: String::new(),
+            base_currency: String::new(),
+        }),
+    );
+    assert!(
+        hold.is_ok() != posting.is_ok(),
+        "Exactly one of the hold and the posting should succeed"
+    );
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.available_balance, "40");
+}
+
+/// Capture posts a journal; partial captures release the remainder
+#[tokio::test]
+async fn capture_hold_posts_journal() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let hold = create_hold(&mut client, tenant_id, &accounts, "40.00", 0, "")
+        .await
+        .expect("Failed to create hold");
+
+    let response = client
+        .capture_hold(CaptureHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold.hold_id.clone(),
+            amount: "25.00".to_string(),
+            effective_date: String::new(),
+            metadata: String::new(),
+        })
+        .await
+        .expect("Failed to capture hold")
+        .into_inner();
+    let captured = response.hold.unwrap();
+    let transaction = response.transaction.unwrap();
+    assert_eq!(captured.status(), ProtoHoldStatus::Captured);
+    assert_eq!(captured.captured_amount, "25");
+    assert_eq!(captured.journal_id, transaction.journal_id);
+    assert_eq!(transaction.entries.len(), 2);
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.balance, "75");
+    assert_eq!(balance.available_balance, "75");
+    let merchant = get_balance(&mut client, tenant_id, &accounts.merchant, None).await;
+    assert_eq!(merchant.balance, "25");
+
+    // A captured hold cannot be captured or released again
+    let status = capture_hold(&mut client, tenant_id, &hold.hold_id, "")
+        .await
+        .expect_err("Double capture should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    let status = release_hold(&mut client, tenant_id, &hold.hold_id)
+        .await
+        .expect_err("Release after capture should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    // Full capture defaults to the held amount; over-capture is rejected
+    let hold = create_hold(&mut client, tenant_id, &accounts, "10.00", 0, "")
+        .await
+        .expect("Failed to create hold");
+    let status = capture_hold(&mut client, tenant_id, &hold.hold_id, "10.01")
+        .await
+        .expect_err("Capturing more than held should fail");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    let captured = capture_hold(&mut client, tenant_id, &hold.hold_id, "")
+        .await
+        .expect("Failed to capture hold");
+    assert_eq!(captured.captured_amount, "10");
+}
+
+/// Released and expired holds stop reserving funds
+#[tokio::test]
+async fn release_and_expiry_free_funds() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let hold = create_hold(&mut client, tenant_id, &accounts, "60.00", 0, "")
+        .await
+        .expect("Failed to create hold");
+    let released = release_hold(&mut client, tenant_id, &hold.hold_id)
+        .await
+        .expect("Failed to release hold");
+    assert_eq!(released.status(), ProtoHoldStatus::Released);
+    assert!(released.resolved_at.is_some());
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.available_balance, "100");
+
+    // A one-second hold lapses without the sweeper
+    let hold = create_hold(&mut client, tenant_id, &accounts, "90.00", 1, "")
+        .await
+        .expect("Failed to create hold");
+    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.available_balance, "100");
+
+    let status = capture_hold(&mut client, tenant_id, &hold.hold_id, "")
+        .await
+        .expect_err("Capturing an expired hold should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    let expired = client
+        .get_hold(GetHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold.hold_id.clone(),
+        })
+        .await
+        .expect("Failed to get hold")
+        .into_inner()
+        .hold
+        .unwrap();
+    assert_eq!(expired.status(), ProtoHoldStatus::Expired);
+
+    let status = release_hold(&mut client, tenant_id, &Uuid::new_v4().to_string())
+        .await
+        .expect_err("Unknown hold should be not found");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
+/// Idempotent creation and listing by account and status
+#[tokio::test]
+async fn idempotent_create_and_list_holds() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let first = create_hold(&mut client, tenant_id, &accounts, "15.00", 0, "auth-1")
+        .await
+        .expect("Failed to create hold");
+    let replay = create_hold(&mut client, tenant_id, &accounts, "15.00", 0, "auth-1")
+        .await
+        .expect("Replay should return the original hold");
+    assert_eq!(first.hold_id, replay.hold_id);
+
+    let second = create_hold(&mut client, tenant_id, &accounts, "5.00", 0, "auth-2")
+        .await
+        .expect("Failed to create hold");
+    release_hold(&mut client, tenant_id, &second.hold_id)
+        .await
+        .expect("Failed to release hold");
+
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.available_balance, "85");
+
+    let pending = client
+        .list_holds(ListHoldsRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: accounts.wallet.clone(),
+            status: ProtoHoldStatus::Pending as i32,
+            page_size: 0,
+            page_token: String::new(),
+        })
+        .await
+        .expect("Failed to list holds")
+        .into_inner()
+        .holds;
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].hold_id, first.hold_id);
+
+    let all = client
+        .list_holds(ListHoldsRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: String::new(),
+            status: 0,
+            page_size: 0,
+            page_token: String::new(),
+        })
+        .await
+        .expect("Failed to list holds")
+        .into_inner()
+        .holds;
+    assert_eq!(all.len(), 2);
+}
+
+/// Replaying a hold idempotency key with a different request is rejected
+#[tokio::test]
+async fn reject_replayed_hold_key_with_different_request() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let accounts = setup_accounts(&mut client, tenant_id).await;
+
+    let first = create_hold(&mut client, tenant_id, &accounts, "15.00", 0, "auth-1")
+        .await
+        .expect("Failed to create hold");
+
+    // Trailing zeros do not make a different request
+    let replay = create_hold(&mut client, tenant_id, &accounts, "15", 0, "auth-1")
+        .await
+        .expect("Replay should return the original hold");
+    assert_eq!(replay.hold_id, first.hold_id);
+
+    let status = create_hold(&mut client, tenant_id, &accounts, "25.00", 0, "auth-1")
+        .await
+        .expect_err("Replay with a different amount should fail");
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains(&first.hold_id));
+    assert!(status.message().contains("amount 25 was originally 15"));
+
+    let status = create_hold(&mut client, tenant_id, &accounts, "15.00", 3600, "auth-1")
+        .await
+        .expect_err("Replay with a different expiry should fail");
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains("expires_in_seconds differs"));
+
+    // Only the original hold reserves funds
+    let balance = get_balance(&mut client, tenant_id, &accounts.wallet, None).await;
+    assert_eq!(balance.available_balance, "85");
+}
@@ -0,0 +1,276 @@
+//! Idempotency Key Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    Direction as ProtoDirection, ImportTransactionsRequest, PostTransactionEntry,
+    PostTransactionRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// Create a cash account and a revenue account.
+async fn setup_accounts(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+) -> (String, String) {
+    let cash = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let revenue = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "4000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    (cash, revenue)
+}
+
+fn transfer(
+    tenant_id: Uuid,
+    debit_account_id: &str,
+    credit_account_id: &str,
+    amount: &str,
+    effective_date: &str,
+    idempotency_key: &str,
+) -> PostTransactionRequest {
+    PostTransactionRequest {
+        tenant_id: tenant_id.to_string(),
+        entries: vec![
+            PostTransactionEntry {
+                account_id: debit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
+            },
+            PostTransactionEntry {
+                account_id: credit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
+            },
+        ],
+        effective_date: effective_date.to_string(),
+        idempotency_key: idempotency_key.to_string(),
+        metadata: String::new(),
+        base_currency: String::new(),
+    }
+}
+
+/// The same key can be used by different tenants
+#[tokio::test]
+async fn idempotency_keys_are_scoped_per_tenant() {
+    let (mut client, tenant_a) = spawn_app().await;
+    let tenant_b = Uuid::new_v4();
+    let (cash_a, revenue_a) = setup_accounts(&mut client, tenant_a).await;
+    let (cash_b, revenue_b) = setup_accounts(&mut client, tenant_b).await;
+
+    let first = post_test_transaction(
+        &mut client,
+        tenant_a,
+        &cash_a,
+        &revenue_a,
+        "100.00",
+        None,
+        Some("order-1"),
+    )
+    .await;
+    let second = post_test_transaction(
+        &mut client,
+        tenant_b,
+        &cash_b,
+        &revenue_b,
+        "40.00",
+        None,
+        Some("order-1"),
+    )
+    .await;
+
+    assert_ne!(
+        first.transaction.unwrap().journal_id,
+        second.transaction.unwrap().journal_id
+    );
+    assert_eq!(
+        get_balance(&mut client, tenant_b, &cash_b, None)
+            .await
+            .balance,
+        "40"
+    );
+}
+
+/// Replays with an equivalent request return the original journal;
+/// replays with a different request are rejected
+#[tokio::test]
+async fn mismatched_replay_is_rejected() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, revenue) = setup_accounts(&mut client, tenant_id).await;
+
+    let original = client
+        .post_transaction(transfer(
+            tenant_id,
+            &cash,
+            &revenue,
+            "100.00",
+            "2026-03-01",
+            "order-1",
+        ))
+        .await
+        .expect("Failed to post transaction")
+        .into_inner()
+        .transaction
+        .unwrap();
+
+    // Entry order and trailing zeros do not make a request different
+    let mut equivalent = transfer(tenant_id, &cash, &revenue, "100", "2026-03-01", "order-1");
+    equivalent.entries.reverse();
+    let replay = client
+        .post_transaction(equivalent)
+        .await
+        .expect("Equivalent replay should succeed")
+        .into_inner()
+        .transaction
+        .unwrap();
+    assert_eq!(replay.journal_id, original.journal_id);
+
+    let status = client
+        .post_transaction(transfer(
+            tenant_id,
+            &cash,
+            &revenue,
+            "250.00",
+            "2026-03-02",
+            "order-1",
+        ))
+        .await
+        .expect_err("Mismatched replay should be rejected");
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains(&original.journal_id));
+    assert!(status
+        .message()
+        .contains("effective_date 2026-03-02 was originally 2026-03-01"));
+    assert!(status.message().contains("entries differ"));
+
+    let mut with_metadata = transfer(
+        tenant_id,
+        &cash,
+        &revenue,
+        "100.00",
+        "2026-03-01",
+        "order-1",
+    );
+    with_metadata.metadata = r#"{"note":"changed"}"#.to_string();
+    let status = client
+        .post_transaction(with_metadata)
+        .await
+        .expect_err("Replay with new metadata should be rejected");
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+    assert!(status.message().contains("metadata differs"));
+
+    // Imports report the conflict per row
+    let response = client
+        .import_transactions(tokio_stream::iter(vec![ImportTransactionsRequest {
+            transactions: vec![
+                transfer(
+                    tenant_id,
+                    &cash,
+                    &revenue,
+                    "100.00",
+                    "2026-03-01",
+                    "order-1",
+                ),
+                transfer(tenant_id, &cash, &revenue, "5.00", "2026-03-01", "order-1"),
+            ],
+        }]))
+        .await
+        .expect("Failed to import transactions")
+        .into_inner();
+    assert_eq!(response.rows_replayed, 1);
+    assert_eq!(response.rows_failed, 1);
+    assert_eq!(response.errors[0].row_number, 2);
+    assert_eq!(response.errors[0].code, "already_exists");
+
+    let balance = get_balance(&mut client, tenant_id, &cash, None).await;
+    assert_eq!(balance.balance, "100");
+}
+
+/// Keys past their retention can be used again
+#[tokio::test]
+async fn expired_key_can_be_reused() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, revenue) = setup_accounts(&mut client, tenant_id).await;
+
+    let first = post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &revenue,
+        "100.00",
+        None,
+        Some("order-1"),
+    )
+    .await
+    .transaction
+    .unwrap();
+
+    // Age the key past its retention behind the service's back
+    let database_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    let pool = sqlx::PgPool::connect(&database_url)
+        .await
+        .expect("Failed to connect to database");
+    sqlx::query("UPDATE idempotency_keys SET expires_utc = NOW() - INTERVAL '1 second' WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to expire key");
+
+    let second = post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &revenue,
+        "30.00",
+        None,
+        Some("order-1"),
+    )
+    .await
+    .transaction
+    .unwrap();
+    assert_ne!(second.journal_id, first.journal_id);
+
+    // The key now replays the new journal
+    let replay = post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &revenue,
+        "30.00",
+        None,
+        Some("order-1"),
+    )
+    .await
+    .transaction
+    .unwrap();
+    assert_eq!(replay.journal_id, second.journal_id);
+
+    let balance = get_balance(&mut client, tenant_id, &cash, None).await;
+    assert_eq!(balance.balance, "130");
+}
@@ -0,0 +1,577 @@
+//! Accounting Period Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    ClosePeriodRequest, Direction as ProtoDirection, GetIncomeStatementRequest, ListPeriodsRequest,
+    OpenPeriodRequest, OpenPeriodResponse, PeriodStatus as ProtoPeriodStatus, PostTransactionEntry,
+    PostTransactionRequest, ReopenPeriodRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+async fn open_period(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    name: &str,
+    start_date: &str,
+    end_date: &str,
+    retained_earnings_account_id: &str,
+) -> Result<OpenPeriodResponse, tonic::Status> {
+    client
+        .open_period(OpenPeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            name: name.to_string(),
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            retained_earnings_account_id: retained_earnings_account_id.to_string(),
+        })
+        .await
+        .map(|r| r.into_inner())
+}
+
+fn transfer_request(
+    tenant_id: Uuid,
+    debit_account_id: &str,
+    credit_account_id: &str,
+    amount: &str,
+    effective_date: &str,
+) -> PostTransactionRequest {
+    PostTransactionRequest {
+        tenant_id: tenant_id.to_string(),
+        entries: vec![
+            PostTransactionEntry {
+                account_id: debit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
+            },
+            PostTransactionEntry {
+                account_id: credit_account_id.to_string(),
+                amount: amount.to_string(),
+                direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
+            },
+        ],
+        effective_date: effective_date.to_string(),
+        idempotency_key: String::new(),
+        metadata: String::new(),
+        base_currency: String::new(),
+    }
+}
+
+/// Open periods and list them with a status filter
+#[tokio::test]
+async fn open_and_list_periods() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let jan = open_period(
+        &mut client,
+        tenant_id,
+        "2026-01",
+        "2026-01-01",
+        "2026-01-31",
+        "",
+    )
+    .await
+    .expect("Failed to open January")
+    .period
+    .unwrap();
+    assert_eq!(jan.status, ProtoPeriodStatus::Open as i32);
+    assert_eq!(jan.start_date, "2026-01-01");
+    assert_eq!(jan.end_date, "2026-01-31");
+
+    open_period(
+        &mut client,
+        tenant_id,
+        "2026-02",
+        "2026-02-01",
+        "2026-02-28",
+        "",
+    )
+    .await
+    .expect("Failed to open February");
+
+    let periods = client
+        .list_periods(ListPeriodsRequest {
+            tenant_id: tenant_id.to_string(),
+            status: ProtoPeriodStatus::Open as i32,
+        })
+        .await
+        .expect("Failed to list periods")
+        .into_inner()
+        .periods;
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].name, "2026-01");
+    assert_eq!(periods[1].name, "2026-02");
+
+    let closed = client
+        .list_periods(ListPeriodsRequest {
+            tenant_id: tenant_id.to_string(),
+            status: ProtoPeriodStatus::Closed as i32,
+        })
+        .await
+        .expect("Failed to list periods")
+        .into_inner()
+        .periods;
+    assert!(closed.is_empty());
+}
+
+/// Overlapping periods are rejected
+#[tokio::test]
+async fn reject_overlapping_period() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    open_period(
+        &mut client,
+        tenant_id,
+        "2026-01",
+        "2026-01-01",
+        "2026-01-31",
+        "",
+    )
+    .await
+    .expect("Failed to open January");
+
+    let status = open_period(
+        &mut client,
+        tenant_id,
+        "Mid-January",
+        "2026-01-15",
+        "2026-02-15",
+        "",
+    )
+    .await
+    .expect_err("Overlapping period should be rejected");
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+}
+
+/// Concurrent overlapping periods with different start dates cannot both open
+#[tokio::test]
+async fn reject_concurrent_overlapping_periods() {
+    let (client, tenant_id) = spawn_app().await;
+
+    let mut first_client = client.clone();
+    let mut second_client = client.clone();
+    let (first, second) = tokio::join!(
+        open_period(
+            &mut first_client,
+            tenant_id,
+            "2026-01",
+            "2026-01-01",
+            "2026-01-31",
+            "",
+        ),
+        open_period(
+            &mut second_client,
+            tenant_id,
+            "2026-01b",
+            "2026-01-15",
+            "2026-02-14",
+            "",
+        ),
+    );
+
+    let status = match (first, second) {
+        (Ok(_), Err(status)) | (Err(status), Ok(_)) => status,
+        _ => panic!("Exactly one of the overlapping periods should open"),
+    };
+    assert_eq!(status.code(), tonic::Code::AlreadyExists);
+}
+
+/// Closing rolls revenue/expense into retained earnings and locks back-dated postings
+#[tokio::test]
+async fn close_period_rolls_into_retained_earnings_and_locks_postings() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let cash = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "CASH",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let revenue = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "REVENUE",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let expense = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Expense,
+        "RENT",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let retained = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "RETAINED_EARNINGS",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+
+    let period = open_period(
+        &mut client,
+        tenant_id,
+        "2026-01",
+        "2026-01-01",
+        "2026-01-31",
+        &retained,
+    )
+    .await
+    .expect("Failed to open period")
+    .period
+    .unwrap();
+
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &revenue,
+        "500.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &expense,
+        &cash,
+        "200.00",
+        Some("2026-01-20"),
+        None,
+    )
+    .await;
+
+    let closed = client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id.clone(),
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect("Failed to close period")
+        .into_inner();
+
+    let closed_period = closed.period.unwrap();
+    assert_eq!(closed_period.status, ProtoPeriodStatus::Closed as i32);
+    let closing = closed
+        .closing_transaction
+        .expect("Should post closing journal");
+    assert_eq!(closing.effective_date, "2026-01-31");
+    assert_eq!(closed_period.closing_journal_id, closing.journal_id);
+
+    // Revenue and expense are zeroed, profit lands in retained earnings
+    let revenue_balance = get_balance(&mut client, tenant_id, &revenue, Some("2026-01-31")).await;
+    assert_eq!(revenue_balance.balance, "0");
+    let expense_balance = get_balance(&mut client, tenant_id, &expense, Some("2026-01-31")).await;
+    assert_eq!(expense_balance.balance, "0");
+    let retained_balance = get_balance(&mut client, tenant_id, &retained, Some("2026-01-31")).await;
+    assert_eq!(retained_balance.balance, "300");
+
+    // Back-dated posting into the closed period fails
+    let status = client
+        .post_transaction(transfer_request(
+            tenant_id,
+            &cash,
+            &revenue,
+            "10.00",
+            "2026-01-15",
+        ))
+        .await
+        .expect_err("Posting into closed period should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    assert!(status.message().contains("closed"));
+
+    // Posting after the period is still allowed
+    client
+        .post_transaction(transfer_request(
+            tenant_id,
+            &cash,
+            &revenue,
+            "10.00",
+            "2026-02-01",
+        ))
+        .await
+        .expect("Posting after the closed period should succeed");
+
+    // Closing twice is rejected
+    let status = client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id,
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect_err("Second close should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// Reopening a period accepts postings again
+#[tokio::test]
+async fn reopen_period_allows_postings() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let cash = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "CASH",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let equity = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "CAPITAL",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+
+    let period = open_period(
+        &mut client,
+        tenant_id,
+        "2026-01",
+        "2026-01-01",
+        "2026-01-31",
+        &equity,
+    )
+    .await
+    .expect("Failed to open period")
+    .period
+    .unwrap();
+
+    let closed = client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id.clone(),
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect("Failed to close period")
+        .into_inner();
+    assert!(
+        closed.closing_transaction.is_none(),
+        "Nothing to roll without revenue/expense activity"
+    );
+
+    let reopened = client
+        .reopen_period(ReopenPeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id.clone(),
+        })
+        .await
+        .expect("Failed to reopen period")
+        .into_inner()
+        .period
+        .unwrap();
+    assert_eq!(reopened.status, ProtoPeriodStatus::Open as i32);
+    assert!(reopened.closed_at.is_none());
+
+    client
+        .post_transaction(transfer_request(
+            tenant_id,
+            &cash,
+            &equity,
+            "1000.00",
+            "2026-01-05",
+        ))
+        .await
+        .expect("Posting into reopened period should succeed");
+
+    let status = client
+        .reopen_period(ReopenPeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id,
+        })
+        .await
+        .expect_err("Reopening an open period should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// Closing requires a retained earnings equity account
+#[tokio::test]
+async fn close_period_requires_retained_earnings_account() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let cash = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "CASH",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+
+    let period = open_period(
+        &mut client,
+        tenant_id,
+        "2026-01",
+        "2026-01-01",
+        "2026-01-31",
+        "",
+    )
+    .await
+    .expect("Failed to open period")
+    .period
+    .unwrap();
+
+    let status = client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id.clone(),
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect_err("Close without retained earnings account should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+
+    let status = client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id,
+            retained_earnings_account_id: cash,
+        })
+        .await
+        .expect_err("Non-equity retained earnings account should fail");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
+/// Periods close in date order, and only ClosePeriod can mark closing entries
+#[tokio::test]
+async fn periods_close_in_date_order() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let mut ids = Vec::new();
+    for (account_type, code) in [
+        (ProtoAccountType::Asset, "CASH"),
+        (ProtoAccountType::Revenue, "REVENUE"),
+        (ProtoAccountType::Equity, "RETAINED_EARNINGS"),
+    ] {
+        let account = create_test_account(&mut client, tenant_id, account_type, code, "USD", false)
+            .await
+            .account
+            .unwrap();
+        ids.push(account.account_id);
+    }
+    let (cash, revenue, retained) = (&ids[0], &ids[1], &ids[2]);
+
+    let mut periods = Vec::new();
+    for (name, start, end) in [
+        ("2026-01", "2026-01-01", "2026-01-31"),
+        ("2026-02", "2026-02-01", "2026-02-28"),
+    ] {
+        let period = open_period(&mut client, tenant_id, name, start, end, retained)
+            .await
+            .expect("Failed to open period")
+            .period
+            .unwrap();
+        periods.push(period.period_id);
+    }
+
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        cash,
+        revenue,
+        "100.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        cash,
+        revenue,
+        "50.00",
+        Some("2026-02-10"),
+        None,
+    )
+    .await;
+
+    let close = |period_id: &String| ClosePeriodRequest {
+        tenant_id: tenant_id.to_string(),
+        period_id: period_id.clone(),
+        retained_earnings_account_id: String::new(),
+    };
+
+    // February cannot close while January is open
+    let status = client
+        .close_period(close(&periods[1]))
+        .await
+        .expect_err("Closing out of order should fail");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    assert!(status.message().contains("2026-01"));
+
+    // In order, each close sweeps only its own period's activity
+    for period_id in &periods {
+        client
+            .close_period(close(period_id))
+            .await
+            .expect("Failed to close period");
+    }
+    let retained_balance = get_balance(&mut client, tenant_id, retained, None).await;
+    assert_eq!(retained_balance.balance, "150");
+
+    // Metadata claiming to be a period close does not hide a journal from reports
+    let mut request = transfer_request(tenant_id, cash, revenue, "25.00", "2026-03-05");
+    request.metadata = r#"{"source": "period_close"}"#.to_string();
+    client
+        .post_transaction(request)
+        .await
+        .expect("Failed to post transaction");
+    let statement = client
+        .get_income_statement(GetIncomeStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-03-01".to_string(),
+            end_date: "2026-03-31".to_string(),
+        })
+        .await
+        .expect("Failed to get income statement")
+        .into_inner()
+        .income_statements
+        .remove(0);
+    assert_eq!(statement.total_revenue, "25");
+}
@@ -0,0 +1,339 @@
+//! Financial Report Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    ClosePeriodRequest, GetBalanceSheetRequest, GetIncomeStatementRequest, GetTrialBalanceRequest,
+    OpenPeriodRequest,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// Accounts used by the report tests, keyed by role.
+struct Books {
+    cash: String,
+    loan: String,
+    capital: String,
+    retained: String,
+    sales: String,
+    rent: String,
+}
+
+/// Create a small USD chart of accounts and post a month of activity:
+/// capital 1000, loan 500, sales 300, rent 120.
+async fn setup_books(client: &mut LedgerServiceClient<Channel>, tenant_id: Uuid) -> Books {
+    let mut ids = Vec::new();
+    for (account_type, code) in [
+        (ProtoAccountType::Asset, "CASH"),
+        (ProtoAccountType::Liability, "LOAN"),
+        (ProtoAccountType::Equity, "CAPITAL"),
+        (ProtoAccountType::Equity, "RETAINED_EARNINGS"),
+        (ProtoAccountType::Revenue, "SALES"),
+        (ProtoAccountType::Expense, "RENT"),
+    ] {
+        let account = create_test_account(client, tenant_id, account_type, code, "USD", false)
+            .await
+            .account
+            .unwrap();
+        ids.push(account.account_id);
+    }
+    let books = Books {
+        rent: ids.pop().unwrap(),
+        sales: ids.pop().unwrap(),
+        retained: ids.pop().unwrap(),
+        capital: ids.pop().unwrap(),
+        loan: ids.pop().unwrap(),
+        cash: ids.pop().unwrap(),
+    };
+
+    for (debit, credit, amount, date) in [
+        (&books.cash, &books.capital, "1000.00", "2026-01-02"),
+        (&books.cash, &books.loan, "500.00", "2026-01-05"),
+        (&books.cash, &books.sales, "300.00", "2026-01-10"),
+        (&books.rent, &books.cash, "120.00", "2026-01-20"),
+    ] {
+        post_test_transaction(client, tenant_id, debit, credit, amount, Some(date), None).await;
+    }
+
+    books
+}
+
+/// Trial balance lists each account's net balance and debits equal credits
+#[tokio::test]
+async fn trial_balance_balances() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let books = setup_books(&mut client, tenant_id).await;
+
+    let response = client
+        .get_trial_balance(GetTrialBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get trial balance")
+        .into_inner();
+
+    assert_eq!(response.as_of_date, "2026-01-31");
+    assert!(response.generated_at.is_some());
+    assert_eq!(response.trial_balances.len(), 1);
+
+    let tb = &response.trial_balances[0];
+    assert_eq!(tb.currency, "USD");
+    assert_eq!(tb.total_debits, "1800");
+    assert_eq!(tb.total_credits, "1800");
+    assert!(tb.balanced);
+
+    // Retained earnings has no activity yet and is not listed
+    assert_eq!(tb.lines.len(), 5);
+    assert!(tb.lines.iter().all(|l| l.account_id != books.retained));
+
+    let cash = tb
+        .lines
+        .iter()
+        .find(|l| l.account_id == books.cash)
+        .unwrap();
+    assert_eq!(cash.debit_balance, "1680");
+    assert_eq!(cash.credit_balance, "0");
+    let sales = tb
+        .lines
+        .iter()
+        .find(|l| l.account_id == books.sales)
+        .unwrap();
+    assert_eq!(sales.debit_balance, "0");
+    assert_eq!(sales.credit_balance, "300");
+    assert_eq!(sales.balance, "300");
+
+    let equity = tb
+        .type_totals
+        .iter()
+        .find(|t| t.account_type == ProtoAccountType::Equity as i32)
+        .unwrap();
+    assert_eq!(equity.credit_balance, "1000");
+
+    // Earlier as_of_date only includes entries up to that date
+    let early = client
+        .get_trial_balance(GetTrialBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-05".to_string(),
+        })
+        .await
+        .expect("Failed to get trial balance")
+        .into_inner();
+    assert_eq!(early.trial_balances[0].total_debits, "1500");
+    assert!(early.trial_balances[0].balanced);
+}
+
+/// Balance sheet satisfies assets = liabilities + equity + net income
+#[tokio::test]
+async fn balance_sheet_includes_unclosed_net_income() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let books = setup_books(&mut client, tenant_id).await;
+
+    let sheet = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner()
+        .balance_sheets
+        .remove(0);
+
+    assert_eq!(sheet.currency, "USD");
+    assert_eq!(sheet.assets.len(), 1);
+    assert_eq!(sheet.assets[0].account_id, books.cash);
+    assert_eq!(sheet.liabilities[0].account_id, books.loan);
+    assert_eq!(sheet.total_assets, "1680");
+    assert_eq!(sheet.total_liabilities, "500");
+    assert_eq!(sheet.total_equity, "1000");
+    assert_eq!(sheet.net_income, "180");
+    assert!(sheet.balanced);
+}
+
+/// Income statement covers the date range and survives a period close
+#[tokio::test]
+async fn income_statement_reports_period_results() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let books = setup_books(&mut client, tenant_id).await;
+
+    // February sale outside the reporting range
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &books.cash,
+        &books.sales,
+        "50.00",
+        Some("2026-02-03"),
+        None,
+    )
+    .await;
+
+    let statement = client
+        .get_income_statement(GetIncomeStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get income statement")
+        .into_inner()
+        .income_statements
+        .remove(0);
+
+    assert_eq!(statement.revenue.len(), 1);
+    assert_eq!(statement.expenses.len(), 1);
+    assert_eq!(statement.total_revenue, "300");
+    assert_eq!(statement.total_expenses, "120");
+    assert_eq!(statement.net_income, "180");
+
+    // Closing January moves the result into retained earnings
+    let period = client
+        .open_period(OpenPeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            name: "2026-01".to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-01-31".to_string(),
+            retained_earnings_account_id: books.retained.clone(),
+        })
+        .await
+        .expect("Failed to open period")
+        .into_inner()
+        .period
+        .unwrap();
+    client
+        .close_period(ClosePeriodRequest {
+            tenant_id: tenant_id.to_string(),
+            period_id: period.period_id,
+            retained_earnings_account_id: String::new(),
+        })
+        .await
+        .expect("Failed to close period");
+
+    let closed = client
+        .get_income_statement(GetIncomeStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-01-01".to_string(),
+            end_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get income statement")
+        .into_inner()
+        .income_statements
+        .remove(0);
+    assert_eq!(closed.net_income, "180");
+
+    let sheet = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner()
+        .balance_sheets
+        .remove(0);
+    assert_eq!(sheet.total_equity, "1180");
+    assert_eq!(sheet.net_income, "0");
+    assert!(sheet.balanced);
+}
+
+/// Reports are grouped by currency
+#[tokio::test]
+async fn reports_group_by_currency() {
+    let (mut client, tenant_id) = spawn_app().await;
+    setup_books(&mut client, tenant_id).await;
+
+    let eur_cash = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "CASH_EUR",
+        "EUR",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let eur_capital = create_test_account(
+        &mut client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "CAPITAL_EUR",
+        "EUR",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &eur_cash,
+        &eur_capital,
+        "75.00",
+        Some("2026-01-15"),
+        None,
+    )
+    .await;
+
+    let response = client
+        .get_trial_balance(GetTrialBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get trial balance")
+        .into_inner();
+
+    let currencies: Vec<_> = response
+        .trial_balances
+        .iter()
+        .map(|tb| tb.currency.as_str())
+        .collect();
+    assert_eq!(currencies, vec!["EUR", "USD"]);
+    assert_eq!(response.trial_balances[0].total_debits, "75");
+    assert!(response.trial_balances.iter().all(|tb| tb.balanced));
+}
+
+/// Invalid report requests are rejected
+#[tokio::test]
+async fn reject_invalid_report_requests() {
+    let (mut client, tenant_id) = spawn_app().await;
+
+    let status = client
+        .get_income_statement(GetIncomeStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            start_date: "2026-02-01".to_string(),
+            end_date: "2026-01-01".to_string(),
+        })
+        .await
+        .expect_err("Inverted range should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = client
+        .get_trial_balance(GetTrialBalanceRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: "31/01/2026".to_string(),
+        })
+        .await
+        .expect_err("Bad date should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    // Empty ledger yields no currency sections
+    let response = client
+        .get_balance_sheet(GetBalanceSheetRequest {
+            tenant_id: tenant_id.to_string(),
+            as_of_date: String::new(),
+        })
+        .await
+        .expect("Failed to get balance sheet")
+        .into_inner();
+    assert!(response.balance_sheets.is_empty());
+}
@@ -0,0 +1,208 @@
+//! Transaction Reversal Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    Direction as ProtoDirection, GetTransactionRequest, ReverseTransactionRequest,
+    ReverseTransactionResponse,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// Create a cash/revenue pair and post 100.00 to it, returning (cash_id, revenue_id, journal_id).
+async fn setup_posted_journal(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+) -> (String, String, String) {
+    let cash = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "CASH",
+        "USD",
+        false,
+    )
+    .await;
+    let revenue = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Revenue,
+        "REVENUE",
+        "USD",
+        false,
+    )
+    .await;
+
+    let cash_id = cash.account.unwrap().account_id;
+    let revenue_id = revenue.account.unwrap().account_id;
+
+    let posted = post_test_transaction(
+        client,
+        tenant_id,
+        &cash_id,
+        &revenue_id,
+        "100.00",
+        Some("2026-01-15"),
+        None,
+    )
+    .await;
+
+    (cash_id, revenue_id, posted.transaction.unwrap().journal_id)
+}
+
+async fn reverse(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    journal_id: &str,
+    effective_date: &str,
+) -> Result<ReverseTransactionResponse, tonic::Status> {
+    client
+        .reverse_transaction(ReverseTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            journal_id: journal_id.to_string(),
+            effective_date: effective_date.to_string(),
+            reason: "Posted to wrong customer".to_string(),
+            metadata: String::new(),
+        })
+        .await
+        .map(|r| r.into_inner())
+}
+
+/// Reversal posts mirror-image entries under a new journal and nets balances to zero
+#[tokio::test]
+async fn reverse_transaction_posts_opposite_entries() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash_id, revenue_id, journal_id) = setup_posted_journal(&mut client, tenant_id).await;
+
+    let reversal = reverse(&mut client, tenant_id, &journal_id, "2026-01-20")
+        .await
+        .expect("Failed to reverse transaction")
+        .transaction
+        .expect("Should return reversal transaction");
+
+    assert_ne!(reversal.journal_id, journal_id);
+    assert_eq!(reversal.reverses_journal_id, journal_id);
+    assert_eq!(reversal.effective_date, "2026-01-20");
+    assert_eq!(reversal.entries.len(), 2);
+
+    let cash_entry = reversal
+        .entries
+        .iter()
+        .find(|e| e.account_id == cash_id)
+        .expect("Reversal should touch cash");
+    assert_eq!(cash_entry.direction, ProtoDirection::Credit as i32);
+    assert_eq!(cash_entry.amount, "100");
+
+    let revenue_entry = reversal
+        .entries
+        .iter()
+        .find(|e| e.account_id == revenue_id)
+        .expect("Reversal should touch revenue");
+    assert_eq!(revenue_entry.direction, ProtoDirection::Debit as i32);
+
+    // Balances before the reversal date are untouched, after it they net to zero
+    let before = get_balance(&mut client, tenant_id, &cash_id, Some("2026-01-19")).await;
+    assert_eq!(before.balance, "100");
+    let after = get_balance(&mut client, tenant_id, &cash_id, Some("2026-01-20")).await;
+    assert_eq!(after.balance, "0");
+    let revenue = get_balance(&mut client, tenant_id, &revenue_id, Some("2026-01-20")).await;
+    assert_eq!(revenue.balance, "0");
+}
+
+/// GetTransaction links original and reversal in both directions
+#[tokio::test]
+async fn get_transaction_shows_reversal_status() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (_, _, journal_id) = setup_posted_journal(&mut client, tenant_id).await;
+
+    let reversal_id = reverse(&mut client, tenant_id, &journal_id, "2026-01-20")
+        .await
+        .expect("Failed to reverse transaction")
+        .transaction
+        .unwrap()
+        .journal_id;
+
+    let original = client
+        .get_transaction(GetTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            journal_id: journal_id.clone(),
+        })
+        .await
+        .expect("Failed to get original")
+        .into_inner()
+        .transaction
+        .unwrap();
+    assert_eq!(original.reversed_by_journal_id, reversal_id);
+    assert!(original.reverses_journal_id.is_empty());
+    assert_eq!(original.reversal_reason, "Posted to wrong customer");
+
+    let reversal = client
+        .get_transaction(GetTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            journal_id: reversal_id,
+        })
+        .await
+        .expect("Failed to get reversal")
+        .into_inner()
+        .transaction
+        .unwrap();
+    assert_eq!(reversal.reverses_journal_id, journal_id);
+    assert!(reversal.reversed_by_journal_id.is_empty());
+}
+
+/// A journal can only be reversed once, and a reversal cannot itself be reversed
+#[tokio::test]
+async fn reject_second_reversal() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (_, _, journal_id) = setup_posted_journal(&mut client, tenant_id).await;
+
+    let reversal_id = reverse(&mut client, tenant_id, &journal_id, "2026-01-20")
+        .await
+        .expect("First reversal should succeed")
+        .transaction
+        .unwrap()
+        .journal_id;
+
+    let status = reverse(&mut client, tenant_id, &journal_id, "2026-01-21")
+        .await
+        .expect_err("Second reversal should be rejected");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    assert!(status.message().contains("already been reversed"));
+
+    let status = reverse(&mut client, tenant_id, &reversal_id, "2026-01-21")
+        .await
+        .expect_err("Reversing a reversal should be rejected");
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+/// Reversal must not be dated before the original and the journal must exist
+#[tokio::test]
+async fn reject_invalid_reversal_requests() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (_, _, journal_id) = setup_posted_journal(&mut client, tenant_id).await;
+
+    let status = reverse(&mut client, tenant_id, &journal_id, "2026-01-01")
+        .await
+        .expect_err("Backdated reversal should be rejected");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    let status = reverse(
+        &mut client,
+        tenant_id,
+        &Uuid::new_v4().to_string(),
+        "2026-01-20",
+    )
+    .await
+    .expect_err("Unknown journal should be rejected");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    // Another tenant cannot reverse the journal
+    let status = reverse(&mut client, Uuid::new_v4(), &journal_id, "2026-01-20")
+        .await
+        .expect_err("Other tenant should not see the journal");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
@@ -0,0 +1,222 @@
+//! Balance Snapshot Integration Tests
+//!
+//! Run with: ./scripts/integ-tests.sh -p ledger-service
+
+mod common;
+
+use common::{create_test_account, get_balance, post_test_transaction, spawn_app};
+use ledger_service::grpc::proto::{
+    ledger_service_client::LedgerServiceClient, AccountType as ProtoAccountType,
+    GetStatementRequest, VerifyBalanceSnapshotsRequest, VerifyBalanceSnapshotsResponse,
+};
+use tonic::transport::Channel;
+use uuid::Uuid;
+
+/// Create a cash account and a capital account to fund it from.
+async fn setup_accounts(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+) -> (String, String) {
+    let cash = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Asset,
+        "1000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    let capital = create_test_account(
+        client,
+        tenant_id,
+        ProtoAccountType::Equity,
+        "3000",
+        "USD",
+        false,
+    )
+    .await
+    .account
+    .unwrap()
+    .account_id;
+    (cash, capital)
+}
+
+async fn verify(
+    client: &mut LedgerServiceClient<Channel>,
+    tenant_id: Uuid,
+    account_id: &str,
+    rebuild: bool,
+) -> Result<VerifyBalanceSnapshotsResponse, tonic::Status> {
+    client
+        .verify_balance_snapshots(VerifyBalanceSnapshotsRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            rebuild,
+        })
+        .await
+        .map(|r| r.into_inner())
+}
+
+/// Backdated postings move every later snapshot
+#[tokio::test]
+async fn backdated_posting_updates_later_balances() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, capital) = setup_accounts(&mut client, tenant_id).await;
+
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &capital,
+        "100.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &capital,
+        "50.00",
+        Some("2026-01-20"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &capital,
+        "25.00",
+        Some("2026-01-05"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &capital,
+        &cash,
+        "10.00",
+        Some("2026-01-10"),
+        None,
+    )
+    .await;
+
+    let balance = |date: &'static str| {
+        let mut client = client.clone();
+        let cash = cash.clone();
+        async move {
+            get_balance(&mut client, tenant_id, &cash, Some(date))
+                .await
+                .balance
+        }
+    };
+    assert_eq!(balance("2026-01-04").await, "0");
+    assert_eq!(balance("2026-01-05").await, "25");
+    assert_eq!(balance("2026-01-15").await, "115");
+    assert_eq!(balance("2026-01-31").await, "165");
+
+    // Credit-normal side mirrors the asset
+    let equity = get_balance(&mut client, tenant_id, &capital, Some("2026-01-31")).await;
+    assert_eq!(equity.balance, "165");
+
+    let statement = client
+        .get_statement(GetStatementRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: cash.clone(),
+            start_date: "2026-01-11".to_string(),
+            end_date: "2026-01-31".to_string(),
+        })
+        .await
+        .expect("Failed to get statement")
+        .into_inner();
+    assert_eq!(statement.opening_balance, "115");
+    assert_eq!(statement.closing_balance, "165");
+
+    let report = verify(&mut client, tenant_id, "", false)
+        .await
+        .expect("Failed to verify snapshots");
+    assert_eq!(report.accounts_checked, 2);
+    // Cash and capital each have postings on three days
+    assert_eq!(report.snapshots_checked, 6);
+    assert!(report.mismatches.is_empty());
+    assert!(!report.rebuilt);
+}
+
+/// Drifted snapshots are reported and repaired by a rebuild
+#[tokio::test]
+async fn rebuild_repairs_drifted_snapshots() {
+    let (mut client, tenant_id) = spawn_app().await;
+    let (cash, capital) = setup_accounts(&mut client, tenant_id).await;
+
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &capital,
+        "100.00",
+        Some("2026-02-01"),
+        None,
+    )
+    .await;
+    post_test_transaction(
+        &mut client,
+        tenant_id,
+        &cash,
+        &capital,
+        "40.00",
+        Some("2026-02-03"),
+        None,
+    )
+    .await;
+
+    // Corrupt one snapshot and drop another behind the service's back
+    let database_url = std::env::var("TEST_DATABASE_URL").expect("TEST_DATABASE_URL must be set");
+    let pool = sqlx::PgPool::connect(&database_url)
+        .await
+        .expect("Failed to connect to database");
+    let cash_id = Uuid::parse_str(&cash).unwrap();
+    sqlx::query("UPDATE account_balance_snapshots SET balance = 999 WHERE account_id = $1 AND snapshot_date = '2026-02-01'")
+        .bind(cash_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to corrupt snapshot");
+    sqlx::query("DELETE FROM account_balance_snapshots WHERE account_id = $1 AND snapshot_date = '2026-02-03'")
+        .bind(cash_id)
+        .execute(&pool)
+        .await
+        .expect("Failed to drop snapshot");
+
+    let drifted = get_balance(&mut client, tenant_id, &cash, Some("2026-02-28")).await;
+    assert_eq!(drifted.balance, "999");
+
+    let report = verify(&mut client, tenant_id, &cash, true)
+        .await
+        .expect("Failed to rebuild snapshots");
+    assert_eq!(report.accounts_checked, 1);
+    assert!(report.rebuilt);
+    assert_eq!(report.mismatches.len(), 2);
+    assert_eq!(report.mismatches[0].snapshot_date, "2026-02-01");
+    assert_eq!(report.mismatches[0].snapshot_balance, "999");
+    assert_eq!(report.mismatches[0].entries_balance, "100");
+    assert_eq!(report.mismatches[1].snapshot_date, "2026-02-03");
+    assert!(report.mismatches[1].snapshot_balance.is_empty());
+    assert_eq!(report.mismatches[1].entries_balance, "140");
+
+    let repaired = get_balance(&mut client, tenant_id, &cash, Some("2026-02-28")).await;
+    assert_eq!(repaired.balance, "140");
+    let report = verify(&mut client, tenant_id, "", false)
+        .await
+        .expect("Failed to verify snapshots");
+    assert!(report.mismatches.is_empty());
+
+    let status = verify(&mut client, tenant_id, &Uuid::new_v4().to_string(), false)
+        .await
+        .expect_err("Unknown account should be not found");
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
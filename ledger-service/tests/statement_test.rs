@@ -227,16 +227,19 @@ async fn get_statement_with_running_balance() {
                 account_id: expense_id.clone(),
                 amount: "300.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_id.clone(),
                 amount: "300.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: "2026-01-15".to_string(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
     client.post_transaction(request).await.unwrap();
 
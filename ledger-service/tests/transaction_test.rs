@@ -88,16 +88,19 @@ async fn reject_unbalanced_transaction() {
                 account_id: cash.account.unwrap().account_id,
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue.account.unwrap().account_id,
                 amount: "90.00".to_string(), // Doesn't match!
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -128,10 +131,12 @@ async fn reject_single_entry_transaction() {
             account_id: cash.account.unwrap().account_id,
             amount: "100.00".to_string(),
             direction: ProtoDirection::Debit as i32,
+            exchange_rate: String::new(),
         }],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -171,16 +176,19 @@ async fn reject_zero_amount_transaction() {
                 account_id: cash.account.unwrap().account_id,
                 amount: "0".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue.account.unwrap().account_id,
                 amount: "0".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -220,16 +228,19 @@ async fn reject_negative_amount_transaction() {
                 account_id: cash.account.unwrap().account_id,
                 amount: "-100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue.account.unwrap().account_id,
                 amount: "-100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -275,16 +286,19 @@ async fn reject_cross_tenant_account_reference() {
                 account_id: cash.account.unwrap().account_id,
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue.account.unwrap().account_id, // Wrong tenant!
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -318,16 +332,19 @@ async fn reject_non_existent_account() {
                 account_id: cash.account.unwrap().account_id,
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: Uuid::new_v4().to_string(), // Non-existent!
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -367,16 +384,19 @@ async fn reject_mismatched_currencies() {
                 account_id: cash_usd.account.unwrap().account_id,
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_eur.account.unwrap().account_id,
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -487,16 +507,19 @@ async fn reject_negative_balance_asset_account() {
                 account_id: expense_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
@@ -542,16 +565,19 @@ async fn allow_negative_balance_when_enabled() {
                 account_id: expense_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: cash_id.clone(),
                 amount: "100.00".to_string(),
                 direction: ProtoDirection::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: String::new(),
         idempotency_key: String::new(),
         metadata: String::new(),
+        base_currency: String::new(),
     };
 
     let result = client.post_transaction(request).await;
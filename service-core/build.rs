@@ -9,6 +9,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo:rerun-if-changed=../proto/micros/document/v1/");
     println!("cargo:rerun-if-changed=../proto/micros/payment/v1/");
     println!("cargo:rerun-if-changed=../proto/micros/ledger/v1/");
+    println!("cargo:rerun-if-changed=../proto/micros/invoicing/v1/");
     println!("cargo:rerun-if-changed=../proto/micros/genai/v1/");
     println!("cargo:rerun-if-changed=../proto/micros/common/");
 
@@ -65,6 +66,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build_client(true) // Build clients for calling ledger-service
         .compile_protos(&["../proto/micros/ledger/v1/ledger.proto"], &[&proto_root])?;
 
+    // Compile invoicing service protos (client-side)
+    tonic_build::configure()
+        .build_server(false) // No server code in service-core
+        .build_client(true) // Build clients for calling invoicing-service
+        .compile_protos(
+            &["../proto/micros/invoicing/v1/invoicing.proto"],
+            &[&proto_root],
+        )?;
+
     // Compile genai service protos (client-side)
     tonic_build::configure()
         .build_server(false) // No server code in service-core
@@ -0,0 +1,260 @@
+//! Invoicing service gRPC client for service-to-service communication.
+//!
+//! Provides a high-level client for calling invoicing-service with built-in retry support.
+//! Calls that create records (`create_invoice`, `add_line_item`) are not retried, since a
+//! retry after a lost response would create a duplicate; callers reconcile instead.
+
+use std::time::Duration;
+use tonic::Request;
+use tonic::transport::{Channel, Endpoint};
+
+use super::proto::invoicing::invoicing_service_client::InvoicingServiceClient;
+use super::proto::invoicing::{
+    AddLineItemRequest, AddLineItemResponse, CreateInvoiceRequest, CreateInvoiceResponse,
+    GetInvoiceRequest, GetInvoiceResponse, InvoiceStatus, InvoiceType, IssueInvoiceRequest,
+    IssueInvoiceResponse, ListInvoicesRequest, ListInvoicesResponse,
+};
+use super::retry::{RetryConfig, retry_grpc_call};
+
+/// Configuration for the invoicing service client.
+#[derive(Clone, Debug)]
+pub struct InvoicingClientConfig {
+    /// The gRPC endpoint of the invoicing service.
+    pub endpoint: String,
+    /// Connection timeout.
+    pub connect_timeout: Duration,
+    /// Request timeout.
+    pub request_timeout: Duration,
+    /// Retry configuration.
+    pub retry_config: RetryConfig,
+}
+
+impl Default for InvoicingClientConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:50053".to_string(),
+            connect_timeout: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(30),
+            retry_config: RetryConfig::default(),
+        }
+    }
+}
+
+/// Invoicing service client with retry support.
+#[derive(Clone)]
+pub struct InvoicingClient {
+    client: InvoicingServiceClient<Channel>,
+    retry_config: RetryConfig,
+}
+
+impl InvoicingClient {
+    /// Create a new invoicing client with the given configuration.
+    pub async fn new(config: InvoicingClientConfig) -> Result<Self, tonic::transport::Error> {
+        let channel = Endpoint::from_shared(config.endpoint)?
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .connect()
+            .await?;
+
+        Ok(Self {
+            client: InvoicingServiceClient::new(channel),
+            retry_config: config.retry_config,
+        })
+    }
+
+    /// Create a new invoicing client connecting to the specified endpoint.
+    pub async fn connect(endpoint: &str) -> Result<Self, tonic::transport::Error> {
+        Self::new(InvoicingClientConfig {
+            endpoint: endpoint.to_string(),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Create a new invoicing client with custom retry configuration.
+    pub async fn with_retry(
+        endpoint: &str,
+        retry_config: RetryConfig,
+    ) -> Result<Self, tonic::transport::Error> {
+        Self::new(InvoicingClientConfig {
+            endpoint: endpoint.to_string(),
+            retry_config,
+            ..Default::default()
+        })
+        .await
+    }
+
+    // =========================================================================
+    // Invoices
+    // =========================================================================
+
+    /// Create a standard draft invoice. Not retried.
+    pub async fn create_invoice(
+        &self,
+        tenant_id: &str,
+        customer_id: &str,
+        currency: &str,
+        due_date: Option<&str>,
+        notes: Option<&str>,
+        metadata: Option<&str>,
+    ) -> Result<CreateInvoiceResponse, tonic::Status> {
+        let mut client = self.client.clone();
+        let request = CreateInvoiceRequest {
+            tenant_id: tenant_id.to_string(),
+            invoice_type: InvoiceType::Standard.into(),
+            customer_id: customer_id.to_string(),
+            customer_name: String::new(),
+            billing_address: None,
+            currency: currency.to_string(),
+            due_date: due_date.unwrap_or("").to_string(),
+            notes: notes.unwrap_or("").to_string(),
+            reference_invoice_id: String::new(),
+            metadata: metadata.unwrap_or("").to_string(),
+        };
+
+        let response = client.create_invoice(Request::new(request)).await?;
+        Ok(response.into_inner())
+    }
+
+    /// Get an invoice with its line items.
+    pub async fn get_invoice(
+        &self,
+        tenant_id: &str,
+        invoice_id: &str,
+    ) -> Result<GetInvoiceResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = GetInvoiceRequest {
+            tenant_id: tenant_id.to_string(),
+            invoice_id: invoice_id.to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "get_invoice", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.get_invoice(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// List a customer's invoices, optionally filtered by status.
+    pub async fn list_invoices(
+        &self,
+        tenant_id: &str,
+        customer_id: &str,
+        status: Option<InvoiceStatus>,
+        page_size: i32,
+        page_token: Option<&str>,
+    ) -> Result<ListInvoicesResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = ListInvoicesRequest {
+            tenant_id: tenant_id.to_string(),
+            status: status.map(|s| s.into()).unwrap_or(0),
+            customer_id: customer_id.to_string(),
+            start_date: String::new(),
+            end_date: String::new(),
+            page_size,
+            page_token: page_token.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "list_invoices", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.list_invoices(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Issue a draft invoice, assigning its number.
+    ///
+    /// Fails with `FailedPrecondition` if the invoice is no longer a draft.
+    pub async fn issue_invoice(
+        &self,
+        tenant_id: &str,
+        invoice_id: &str,
+        issue_date: Option<&str>,
+    ) -> Result<IssueInvoiceResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = IssueInvoiceRequest {
+            tenant_id: tenant_id.to_string(),
+            invoice_id: invoice_id.to_string(),
+            issue_date: issue_date.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "issue_invoice", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.issue_invoice(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    // =========================================================================
+    // Line Items
+    // =========================================================================
+
+    /// Add a line item to a draft invoice. Not retried.
+    pub async fn add_line_item(
+        &self,
+        tenant_id: &str,
+        invoice_id: &str,
+        item: LineItemInput,
+    ) -> Result<AddLineItemResponse, tonic::Status> {
+        let mut client = self.client.clone();
+        let request = AddLineItemRequest {
+            tenant_id: tenant_id.to_string(),
+            invoice_id: invoice_id.to_string(),
+            description: item.description,
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+            tax_rate_id: item.tax_rate_id.unwrap_or_default(),
+            ledger_account_id: item.ledger_account_id.unwrap_or_default(),
+            sort_order: item.sort_order,
+            tax_group_id: item.tax_group_id.unwrap_or_default(),
+            line_type: item.line_type.map(|t| t as i32).unwrap_or_default(),
+        };
+
+        let response = client.add_line_item(Request::new(request)).await?;
+        Ok(response.into_inner())
+    }
+}
+
+/// Helper struct for building invoice line items.
+#[derive(Clone, Debug, Default)]
+pub struct LineItemInput {
+    pub description: String,
+    pub quantity: String,
+    pub unit_price: String,
+    pub tax_rate_id: Option<String>,
+    pub tax_group_id: Option<String>,
+    pub ledger_account_id: Option<String>,
+    pub sort_order: i32,
+    pub line_type: Option<LineItemTypeProto>,
+}
+
+// Re-export useful types from proto
+pub use super::proto::invoicing::{
+    Invoice as InvoiceProto, InvoiceStatus as InvoiceStatusProto, LineItem as LineItemProto,
+    LineItemType as LineItemTypeProto,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invoicing_client_config_default() {
+        let config = InvoicingClientConfig::default();
+        assert_eq!(config.endpoint, "http://localhost:50053");
+        assert_eq!(config.connect_timeout, Duration::from_secs(5));
+        assert_eq!(config.request_timeout, Duration::from_secs(30));
+    }
+}
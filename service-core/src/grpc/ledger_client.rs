@@ -8,12 +8,16 @@ use tonic::transport::{Channel, Endpoint};
 
 use super::proto::ledger::ledger_service_client::LedgerServiceClient;
 use super::proto::ledger::{
-    AccountType, CreateAccountRequest, CreateAccountResponse, Direction, GetAccountRequest,
-    GetAccountResponse, GetBalanceRequest, GetBalanceResponse, GetBalancesRequest,
-    GetBalancesResponse, GetStatementRequest, GetStatementResponse, GetTransactionRequest,
-    GetTransactionResponse, ListAccountsRequest, ListAccountsResponse, ListTransactionsRequest,
-    ListTransactionsResponse, PostTransactionEntry, PostTransactionRequest,
-    PostTransactionResponse,
+    AccountType, CaptureHoldRequest, CaptureHoldResponse, CloseAccountRequest,
+    CloseAccountResponse, CreateAccountRequest, CreateAccountResponse, CreateHoldRequest,
+    CreateHoldResponse, Direction, FreezeAccountRequest, FreezeAccountResponse, GetAccountRequest,
+    GetAccountResponse, GetAccountTreeRequest, GetAccountTreeResponse, GetBalanceRequest,
+    GetBalanceResponse, GetBalancesRequest, GetBalancesResponse, GetStatementRequest,
+    GetStatementResponse, GetTransactionRequest, GetTransactionResponse, ListAccountsRequest,
+    ListAccountsResponse, ListTransactionsRequest, ListTransactionsResponse, PostTransactionEntry,
+    PostTransactionRequest, PostTransactionResponse, ReleaseHoldRequest, ReleaseHoldResponse,
+    ReopenAccountRequest, ReopenAccountResponse, ReverseTransactionRequest,
+    ReverseTransactionResponse,
 };
 use super::retry::{RetryConfig, retry_grpc_call};
 
@@ -107,6 +111,7 @@ impl LedgerClient {
             currency: currency.to_string(),
             allow_negative,
             metadata: metadata.unwrap_or("").to_string(),
+            parent_account_id: String::new(),
         };
 
         retry_grpc_call(&self.retry_config, "create_account", || {
@@ -159,6 +164,7 @@ impl LedgerClient {
             currency: currency.unwrap_or("").to_string(),
             page_size,
             page_token: page_token.unwrap_or("").to_string(),
+            status: 0,
         };
 
         retry_grpc_call(&self.retry_config, "list_accounts", || {
@@ -172,6 +178,114 @@ impl LedgerClient {
         .await
     }
 
+    /// Get the chart-of-accounts tree with own and roll-up balances.
+    ///
+    /// Without `root_account_id` all top-level accounts of the tenant are returned.
+    pub async fn get_account_tree(
+        &self,
+        tenant_id: &str,
+        root_account_id: Option<&str>,
+        as_of_date: Option<&str>,
+    ) -> Result<GetAccountTreeResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = GetAccountTreeRequest {
+            tenant_id: tenant_id.to_string(),
+            root_account_id: root_account_id.unwrap_or("").to_string(),
+            as_of_date: as_of_date.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "get_account_tree", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.get_account_tree(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Freeze an account so it rejects new postings.
+    pub async fn freeze_account(
+        &self,
+        tenant_id: &str,
+        account_id: &str,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<FreezeAccountResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = FreezeAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: actor.to_string(),
+            reason: reason.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "freeze_account", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.freeze_account(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Close an account. The account must have a zero balance.
+    pub async fn close_account(
+        &self,
+        tenant_id: &str,
+        account_id: &str,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<CloseAccountResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = CloseAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: actor.to_string(),
+            reason: reason.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "close_account", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.close_account(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Reopen a frozen or closed account.
+    pub async fn reopen_account(
+        &self,
+        tenant_id: &str,
+        account_id: &str,
+        actor: &str,
+        reason: Option<&str>,
+    ) -> Result<ReopenAccountResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = ReopenAccountRequest {
+            tenant_id: tenant_id.to_string(),
+            account_id: account_id.to_string(),
+            actor: actor.to_string(),
+            reason: reason.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "reopen_account", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.reopen_account(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
     // =========================================================================
     // Transaction Operations
     // =========================================================================
@@ -194,6 +308,7 @@ impl LedgerClient {
             effective_date: effective_date.unwrap_or("").to_string(),
             idempotency_key: idempotency_key.to_string(),
             metadata: metadata.unwrap_or("").to_string(),
+            base_currency: String::new(),
         };
 
         retry_grpc_call(&self.retry_config, "post_transaction", || {
@@ -207,6 +322,37 @@ impl LedgerClient {
         .await
     }
 
+    /// Reverse a posted transaction by posting its mirror-image entries.
+    ///
+    /// Fails with `FailedPrecondition` if the journal has already been reversed.
+    pub async fn reverse_transaction(
+        &self,
+        tenant_id: &str,
+        journal_id: &str,
+        effective_date: Option<&str>,
+        reason: &str,
+        metadata: Option<&str>,
+    ) -> Result<ReverseTransactionResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = ReverseTransactionRequest {
+            tenant_id: tenant_id.to_string(),
+            journal_id: journal_id.to_string(),
+            effective_date: effective_date.unwrap_or("").to_string(),
+            reason: reason.to_string(),
+            metadata: metadata.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "reverse_transaction", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.reverse_transaction(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
     /// Get a transaction by journal ID.
     pub async fn get_transaction(
         &self,
@@ -261,6 +407,100 @@ impl LedgerClient {
         .await
     }
 
+    // =========================================================================
+    // Hold Operations
+    // =========================================================================
+
+    /// Reserve `amount` for a future debit/credit pair without posting it.
+    ///
+    /// Pass an idempotency key so retries return the original hold.
+    /// `expires_in_seconds` of 0 uses the ledger's default hold lifetime.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_hold(
+        &self,
+        tenant_id: &str,
+        debit_account_id: &str,
+        credit_account_id: &str,
+        amount: &str,
+        expires_in_seconds: i64,
+        idempotency_key: &str,
+        metadata: Option<&str>,
+    ) -> Result<CreateHoldResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = CreateHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            debit_account_id: debit_account_id.to_string(),
+            credit_account_id: credit_account_id.to_string(),
+            amount: amount.to_string(),
+            expires_in_seconds,
+            idempotency_key: idempotency_key.to_string(),
+            metadata: metadata.unwrap_or("").to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "create_hold", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.create_hold(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Capture a pending hold, posting `amount` (the full hold when `None`).
+    ///
+    /// Fails with `FailedPrecondition` if the hold is no longer pending.
+    pub async fn capture_hold(
+        &self,
+        tenant_id: &str,
+        hold_id: &str,
+        amount: Option<&str>,
+        effective_date: Option<&str>,
+    ) -> Result<CaptureHoldResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = CaptureHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold_id.to_string(),
+            amount: amount.unwrap_or("").to_string(),
+            effective_date: effective_date.unwrap_or("").to_string(),
+            metadata: String::new(),
+        };
+
+        retry_grpc_call(&self.retry_config, "capture_hold", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.capture_hold(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
+    /// Release a pending hold without posting anything.
+    pub async fn release_hold(
+        &self,
+        tenant_id: &str,
+        hold_id: &str,
+    ) -> Result<ReleaseHoldResponse, tonic::Status> {
+        let client = self.client.clone();
+        let request = ReleaseHoldRequest {
+            tenant_id: tenant_id.to_string(),
+            hold_id: hold_id.to_string(),
+        };
+
+        retry_grpc_call(&self.retry_config, "release_hold", || {
+            let mut c = client.clone();
+            let req = request.clone();
+            async move {
+                let response = c.release_hold(Request::new(req)).await?;
+                Ok(response.into_inner())
+            }
+        })
+        .await
+    }
+
     // =========================================================================
     // Balance Queries
     // =========================================================================
@@ -277,6 +517,7 @@ impl LedgerClient {
             tenant_id: tenant_id.to_string(),
             account_id: account_id.to_string(),
             as_of_date: as_of_date.unwrap_or("").to_string(),
+            include_descendants: false,
         };
 
         retry_grpc_call(&self.retry_config, "get_balance", || {
@@ -302,6 +543,7 @@ impl LedgerClient {
             tenant_id: tenant_id.to_string(),
             account_ids,
             as_of_date: as_of_date.unwrap_or("").to_string(),
+            include_descendants: false,
         };
 
         retry_grpc_call(&self.retry_config, "get_balances", || {
@@ -381,6 +623,7 @@ impl From<TransactionEntry> for PostTransactionEntry {
             account_id: entry.account_id,
             amount: entry.amount,
             direction: entry.direction.into(),
+            exchange_rate: String::new(),
         }
     }
 }
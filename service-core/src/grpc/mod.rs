@@ -11,6 +11,7 @@
 //! - Document service client for service-to-service communication
 //! - Payment service client for service-to-service communication
 //! - Ledger service client for service-to-service communication
+//! - Invoicing service client for service-to-service communication
 //! - Capability checking infrastructure for authorization
 
 pub mod auth_client;
@@ -20,6 +21,7 @@ pub mod error;
 pub mod genai_client;
 pub mod health;
 pub mod interceptors;
+pub mod invoicing_client;
 pub mod ledger_client;
 pub mod notification_client;
 pub mod payment_client;
@@ -37,6 +39,9 @@ pub mod proto {
     pub mod genai {
         tonic::include_proto!("micros.genai.v1");
     }
+    pub mod invoicing {
+        tonic::include_proto!("micros.invoicing.v1");
+    }
     pub mod ledger {
         tonic::include_proto!("micros.ledger.v1");
     }
@@ -66,6 +71,10 @@ pub use interceptors::{
     inject_trace_context, inject_trace_context_with_request_id, metrics_interceptor,
     trace_context_interceptor,
 };
+pub use invoicing_client::{
+    InvoiceProto, InvoiceStatusProto, InvoicingClient, InvoicingClientConfig, LineItemInput,
+    LineItemProto, LineItemTypeProto,
+};
 pub use ledger_client::{LedgerClient, LedgerClientConfig, TransactionEntry};
 pub use notification_client::{
     BatchNotification, BatchNotificationResult, NotificationChannelProto, NotificationClient,
@@ -36,6 +36,7 @@ async fn valid_capability_allows_access() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     // Add auth headers
@@ -76,6 +77,7 @@ async fn unauthenticated_request_rejected() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     let response = ledger_client.create_account(request).await;
@@ -121,6 +123,7 @@ async fn tenant_isolation_enforced() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     create_request.metadata_mut().insert(
@@ -191,6 +194,7 @@ async fn list_respects_tenant_boundary() {
             currency: "USD".to_string(),
             allow_negative: false,
             metadata: "{}".to_string(),
+            parent_account_id: String::new(),
         });
 
         request.metadata_mut().insert(
@@ -217,6 +221,7 @@ async fn list_respects_tenant_boundary() {
             currency: "USD".to_string(),
             allow_negative: false,
             metadata: "{}".to_string(),
+            parent_account_id: String::new(),
         });
 
         request.metadata_mut().insert(
@@ -238,6 +243,7 @@ async fn list_respects_tenant_boundary() {
         currency: String::new(),
         page_size: 100,
         page_token: String::new(),
+        status: 0,
     });
 
     list_request.metadata_mut().insert(
@@ -285,6 +291,7 @@ async fn service_to_service_auth_works() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     cash_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -306,6 +313,7 @@ async fn service_to_service_auth_works() {
         currency: "USD".to_string(),
         allow_negative: true,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     revenue_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -327,16 +335,19 @@ async fn service_to_service_auth_works() {
                 account_id: cash_account.account_id.clone(),
                 amount: "500.00".to_string(),
                 direction: Direction::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue_account.account_id.clone(),
                 amount: "500.00".to_string(),
                 direction: Direction::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: "2024-01-15".to_string(),
         idempotency_key: Uuid::new_v4().to_string(),
         metadata: r#"{"type": "service-to-service-test"}"#.to_string(),
+        base_currency: String::new(),
     });
 
     post_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -40,6 +40,7 @@ async fn try_create_test_plan(tenant_id: &str, user_id: &str) -> Option<String>
                 unit_name: "calls".to_string(),
                 unit_price: "0.01".to_string(),
                 included_units: 1000,
+                ..Default::default()
             },
         ],
         metadata: "{}".to_string(),
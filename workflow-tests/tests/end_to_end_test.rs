@@ -52,12 +52,14 @@ async fn full_billing_cycle() {
                 unit_name: "requests".to_string(),
                 unit_price: "0.001".to_string(),
                 included_units: 10000,
+                ..Default::default()
             },
             CreateUsageComponentInput {
                 name: "Storage".to_string(),
                 unit_name: "GB".to_string(),
                 unit_price: "0.10".to_string(),
                 included_units: 100,
+                ..Default::default()
             },
         ],
         metadata: "{}".to_string(),
@@ -228,6 +230,7 @@ async fn ledger_double_entry_balance() {
         currency: "USD".to_string(),
         allow_negative: false,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     cash_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -249,6 +252,7 @@ async fn ledger_double_entry_balance() {
         currency: "USD".to_string(),
         allow_negative: true,
         metadata: "{}".to_string(),
+        parent_account_id: String::new(),
     });
 
     revenue_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -270,16 +274,19 @@ async fn ledger_double_entry_balance() {
                 account_id: cash_account.account_id.clone(),
                 amount: "1000.00".to_string(),
                 direction: Direction::Debit as i32,
+                exchange_rate: String::new(),
             },
             PostTransactionEntry {
                 account_id: revenue_account.account_id.clone(),
                 amount: "1000.00".to_string(),
                 direction: Direction::Credit as i32,
+                exchange_rate: String::new(),
             },
         ],
         effective_date: "2024-01-15".to_string(),
         idempotency_key: Uuid::new_v4().to_string(),
         metadata: r#"{"type": "sale"}"#.to_string(),
+        base_currency: String::new(),
     });
 
     post_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -301,6 +308,7 @@ async fn ledger_double_entry_balance() {
         tenant_id: tenant_id.clone(),
         account_id: cash_account.account_id.clone(),
         as_of_date: String::new(),
+        include_descendants: false,
     });
 
     cash_balance_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
@@ -320,6 +328,7 @@ async fn ledger_double_entry_balance() {
         tenant_id: tenant_id.clone(),
         account_id: revenue_account.account_id.clone(),
         as_of_date: String::new(),
+        include_descendants: false,
     });
 
     revenue_balance_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());
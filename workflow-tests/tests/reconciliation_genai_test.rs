@@ -37,6 +37,7 @@ async fn try_setup_bank_account() -> Option<(String, String, String, String)> {
         currency: "USD".to_string(),
         allow_negative: true,
         metadata: r#"{"type": "bank_account"}"#.to_string(),
+        parent_account_id: String::new(),
     });
 
     ledger_request.metadata_mut().insert("x-tenant-id", tenant_id.parse().unwrap());